@@ -0,0 +1,27 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+export type Binder = {
+  id: string;
+  name: string;
+  /**
+   * Copies of a tradable binder are visible to other users and can be asked for in a trade
+   */
+  tradable: boolean;
+  /**
+   * Copies of each entry kept out of the trades, unless the entry sets its own tradable
+   * quantity
+   */
+  keep_quantity: number;
+  /**
+   * Binder the imports and manual adds go to when none is given
+   */
+  is_default: boolean;
+  /**
+   * Number of copies in the binder, 0 right after creation
+   */
+  card_count: number;
+  /**
+   * RFC 3339 timestamp
+   */
+  created_at: string;
+};
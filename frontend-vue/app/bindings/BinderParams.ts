@@ -0,0 +1,6 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Binder targeted by an import, the default binder when omitted.
+ */
+export type BinderParams = { binder_id?: string };
@@ -0,0 +1,24 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * EDHREC statistics of the card, shared by all its printings.
+ */
+export type CardEdhRec = {
+  name: string;
+  /**
+   * Decks playing the card
+   */
+  inclusion: number;
+  /**
+   * Decks whose colour identity allows the card
+   */
+  potential_decks: number;
+  /**
+   * `null` for cards EDHREC does not rank
+   */
+  rank: number | null;
+  /**
+   * RFC 3339 timestamp of the last EDHREC fetch
+   */
+  fetched_at: string;
+};
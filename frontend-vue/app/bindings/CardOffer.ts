@@ -1,3 +1,15 @@
 // This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
 
-export type CardOffer = { owner_username: string; quantity: number; selling_price: number | null };
+/**
+ * An offer of another user. The printing fields tell which printing is offered when the offers
+ * of any printing of the card are requested.
+ */
+export type CardOffer = {
+  set_code: string;
+  collector_number: string;
+  language_code: string;
+  foil: boolean;
+  owner_username: string;
+  quantity: number;
+  selling_price: number | null;
+};
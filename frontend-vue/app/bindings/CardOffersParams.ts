@@ -1,11 +1,19 @@
 // This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
 import type { CardOffersSortBy } from './CardOffersSortBy';
 
+/**
+ * Either `oracle_id`, for the offers of any printing of the card, or the four fields of the
+ * printing (`set_code`, `collector_number`, `language_code` and `foil`).
+ */
 export type CardOffersParams = {
-  set_code: string;
-  collector_number: string;
-  language_code: string;
-  foil: boolean;
+  set_code?: string;
+  collector_number?: string;
+  language_code?: string;
+  foil?: boolean;
+  /**
+   * Takes precedence over the printing fields
+   */
+  oracle_id?: string;
   sort_by: CardOffersSortBy;
   page: number;
   page_size: number;
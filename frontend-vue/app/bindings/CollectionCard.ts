@@ -11,6 +11,25 @@ export type CollectionCard = {
   rarity_code: string;
   scryfall_id: string;
   the_gatherer_id: string | null;
+  /**
+   * Gatherer id of the back face, only for transform and modal double-faced cards.
+   */
+  the_gatherer_back_id: string | null;
+  /**
+   * Shared by every printing of the card, absent until the card is enriched.
+   */
+  oracle_id: string | null;
+  mana_cost: string | null;
+  type_line: string | null;
+  /**
+   * Colour symbols in the WUBRG order, empty for a colourless or not yet enriched card.
+   */
+  colors: Array<string>;
+  color_identity: Array<string>;
+  oracle_text: string | null;
+  image_small_url: string | null;
+  image_normal_url: string | null;
+  image_large_url: string | null;
   /**
    * Present only when the card is owned by the authenticated user.
    */
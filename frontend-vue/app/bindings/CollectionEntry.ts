@@ -7,4 +7,18 @@ export type CollectionEntry = {
    * RFC 3339 timestamp
    */
   added_at: string;
+  /**
+   * Binder holding the copies
+   */
+  binder_id: string | null;
+  /**
+   * Copies other users can ask for, 0 outside of a tradable binder
+   */
+  tradable_quantity: number | null;
+  tags: Array<string>;
+  notes: string | null;
+  /**
+   * Where the copies are stored
+   */
+  location: string | null;
 };
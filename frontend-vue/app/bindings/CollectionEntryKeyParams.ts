@@ -0,0 +1,12 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+export type CollectionEntryKeyParams = {
+  set_code: string;
+  collector_number: string;
+  language_code: string;
+  foil: boolean;
+  /**
+   * Binder of the entry, the default binder when omitted
+   */
+  binder_id?: string;
+};
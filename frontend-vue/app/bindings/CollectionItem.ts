@@ -0,0 +1,29 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { CardCondition } from './CardCondition';
+
+export type CollectionItem = {
+  set_code: string;
+  collector_number: string;
+  language_code: string;
+  foil: boolean;
+  quantity: number;
+  /**
+   * Price in cents
+   */
+  purchase_price: number;
+  condition: CardCondition;
+  notes: string | null;
+  tags: Array<string>;
+  /**
+   * Where the copies are stored
+   */
+  location: string | null;
+  /**
+   * Copies offered for trade, `null` when the keep rule of the binder applies
+   */
+  tradable_quantity: number | null;
+  /**
+   * RFC 3339 timestamp
+   */
+  added_at: string;
+};
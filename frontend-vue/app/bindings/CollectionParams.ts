@@ -1,4 +1,5 @@
 // This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { Color } from './Color';
 import type { RarityCode } from './RarityCode';
 import type { SortBy } from './SortBy';
 import type { SortDir } from './SortDir';
@@ -25,4 +26,24 @@ export type CollectionParams = {
    * Maximum trend price in cents
    */
   price_max?: number;
+  /**
+   * Colours the card must all have, repeated for multiple values (e.g. `?color=W&color=U`)
+   */
+  color: Array<Color>;
+  /**
+   * Part of the type line (e.g. `Creature`, `Goblin`)
+   */
+  type_line?: string;
+  /**
+   * Only the entries of this binder
+   */
+  binder_id?: string;
+  /**
+   * Tags the entries must all carry, repeated for multiple values (e.g. `?tag=cube&tag=foil`)
+   */
+  tag: Array<string>;
+  /**
+   * Part of the storage location of the entries
+   */
+  location?: string;
 };
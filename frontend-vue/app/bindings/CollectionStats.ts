@@ -7,4 +7,8 @@ export type CollectionStats = {
   price_trend_min: number | null;
   price_trend_max: number | null;
   sets: Array<SetInfo>;
+  /**
+   * Tags of the entries, to filter on
+   */
+  tags: Array<string>;
 };
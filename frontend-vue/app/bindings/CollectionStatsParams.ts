@@ -0,0 +1,12 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Entries described by the stats, every entry of every binder by default.
+ */
+export type CollectionStatsParams = {
+  binder_id?: string;
+  /**
+   * Tags the entries must all carry, repeated for multiple values
+   */
+  tag: Array<string>;
+};
@@ -0,0 +1,17 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { MissingRecommendation } from './MissingRecommendation';
+import type { OwnedRecommendation } from './OwnedRecommendation';
+
+/**
+ * EDHREC high synergy and top cards of a commander, split between the ones the user owns and
+ * the ones they miss.
+ */
+export type CommanderSynergy = {
+  commander: string;
+  /**
+   * Value of every owned recommended copy in cents
+   */
+  owned_value: number;
+  owned: Array<OwnedRecommendation>;
+  missing: Array<MissingRecommendation>;
+};
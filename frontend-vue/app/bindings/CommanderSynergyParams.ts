@@ -0,0 +1,8 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+export type CommanderSynergyParams = {
+  /**
+   * Commander name, as written on the card (e.g. `Atraxa, Praetors' Voice`)
+   */
+  commander: string;
+};
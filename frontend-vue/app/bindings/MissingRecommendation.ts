@@ -0,0 +1,17 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { RecommendationList } from './RecommendationList';
+
+export type MissingRecommendation = {
+  name: string;
+  list: RecommendationList;
+  /**
+   * EDHREC synergy score, between -1 and 1
+   */
+  synergy: number;
+  inclusion: number;
+  potential_decks: number;
+  /**
+   * Other users owning a printing of the card
+   */
+  owner_count: number;
+};
@@ -0,0 +1,33 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Owned printings of a card grouped under their Scryfall `oracle_id`, shown through one of
+ * them (English and non-foil first).
+ */
+export type OracleCard = {
+  oracle_id: string;
+  name: string;
+  mana_cost: string | null;
+  type_line: string | null;
+  /**
+   * Colour symbols in the WUBRG order, empty for a colourless card.
+   */
+  colors: Array<string>;
+  color_identity: Array<string>;
+  oracle_text: string | null;
+  image_small_url: string | null;
+  image_normal_url: string | null;
+  image_large_url: string | null;
+  /**
+   * Number of distinct printings (set, collector number, language, foil) owned by someone
+   */
+  printing_count: number;
+  /**
+   * Number of distinct users owning at least one printing
+   */
+  owner_count: number;
+  /**
+   * Cheapest trend among the printings, in cents
+   */
+  lowest_trend: number | null;
+};
@@ -0,0 +1,14 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+export type OwnedPrinting = {
+  set_code: string;
+  collector_number: string;
+  language_code: string;
+  foil: boolean;
+  scryfall_id: string;
+  quantity: number;
+  /**
+   * Price in cents, null when the printing has no price
+   */
+  trend: number | null;
+};
@@ -0,0 +1,19 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { OwnedPrinting } from './OwnedPrinting';
+import type { RecommendationList } from './RecommendationList';
+
+export type OwnedRecommendation = {
+  name: string;
+  list: RecommendationList;
+  /**
+   * EDHREC synergy score, between -1 and 1
+   */
+  synergy: number;
+  inclusion: number;
+  potential_decks: number;
+  /**
+   * Value of the owned copies in cents
+   */
+  value: number;
+  printings: Array<OwnedPrinting>;
+};
@@ -0,0 +1,9 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { OracleCard } from './OracleCard';
+
+export type PaginatedOracleCards = {
+  items: Array<OracleCard>;
+  total: number;
+  page: number;
+  page_size: number;
+};
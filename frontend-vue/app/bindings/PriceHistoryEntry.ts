@@ -1,11 +1,26 @@
 // This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
 
+/**
+ * One day, week or month of history. `low`, `trend` and `avg` are the last known prices of the
+ * period, `trend` being its close.
+ */
 export type PriceHistoryEntry = {
   /**
-   * ISO 8601 date string (YYYY-MM-DD)
+   * ISO 8601 date string (YYYY-MM-DD), first day of the period
    */
   date: string;
   low: number;
   trend: number;
   avg: number;
+  trend_open: number;
+  trend_high: number;
+  trend_low: number;
+  /**
+   * Only set when moving averages are requested
+   */
+  trend_ma7: number | null;
+  /**
+   * Only set when moving averages are requested
+   */
+  trend_ma30: number | null;
 };
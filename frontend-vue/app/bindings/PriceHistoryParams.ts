@@ -1,4 +1,5 @@
 // This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { PriceHistoryGranularity } from './PriceHistoryGranularity';
 
 /**
  * Shared by `/collection/price-history` and `/card/{scryfall_id}/price-history`; both dates are
@@ -13,4 +14,12 @@ export type PriceHistoryParams = {
    * ISO 8601 date string (YYYY-MM-DD)
    */
   end_date?: string;
+  /**
+   * Defaults to `day`
+   */
+  granularity?: PriceHistoryGranularity;
+  /**
+   * Adds the 7 and 30-day moving averages of the trend
+   */
+  moving_averages?: boolean;
 };
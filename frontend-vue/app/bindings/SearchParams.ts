@@ -1,4 +1,5 @@
 // This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { Color } from './Color';
 import type { RarityCode } from './RarityCode';
 import type { SortBy } from './SortBy';
 import type { SortDir } from './SortDir';
@@ -25,6 +26,18 @@ export type SearchParams = {
    * Maximum trend price in cents
    */
   price_max?: number;
+  /**
+   * Colours the card must all have, repeated for multiple values (e.g. `?color=W&color=U`)
+   */
+  color: Array<Color>;
+  /**
+   * Part of the type line (e.g. `Creature`, `Goblin`)
+   */
+  type_line?: string;
+  /**
+   * Only the printings of this oracle card
+   */
+  oracle_id?: string;
   /**
    * Exact username of the owner to filter by (case-insensitive, no partial match)
    */
@@ -0,0 +1,8 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { SetPriceIndexEntry } from './SetPriceIndexEntry';
+
+export type SetPriceIndex = {
+  set_code: string;
+  set_name: string;
+  entries: Array<SetPriceIndexEntry>;
+};
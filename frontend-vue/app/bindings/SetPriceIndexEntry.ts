@@ -0,0 +1,25 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Value of the tracked printings of a set on one day, based on their trend price.
+ */
+export type SetPriceIndexEntry = {
+  /**
+   * ISO 8601 date string (YYYY-MM-DD)
+   */
+  date: string;
+  /**
+   * Printings (card and finish) with a trend price that day
+   */
+  printings: number;
+  total: number;
+  median: number;
+  /**
+   * Value of the printings owned by the user, each counted once
+   */
+  owned: number;
+  /**
+   * `owned / total`, between 0 and 1
+   */
+  owned_share: number;
+};
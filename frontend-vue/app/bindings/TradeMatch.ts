@@ -0,0 +1,31 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { TradeMatchCard } from './TradeMatchCard';
+
+/**
+ * Another user with whom a trade would go both ways, the cards of `wanted` being ready to
+ * propose with `POST /trades`.
+ */
+export type TradeMatch = {
+  respondent_user_id: string;
+  respondent_username: string;
+  /**
+   * Cards of the other user matching the wishlist
+   */
+  wanted: Array<TradeMatchCard>;
+  /**
+   * Cards of the user matching the other user's wishlist
+   */
+  offered: Array<TradeMatchCard>;
+  /**
+   * In cents
+   */
+  wanted_value: number;
+  /**
+   * In cents
+   */
+  offered_value: number;
+  /**
+   * Smaller value over larger one, 1 for an even trade
+   */
+  balance: number;
+};
@@ -0,0 +1,17 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Copies of one printing a side of a match can give.
+ */
+export type TradeMatchCard = {
+  set_code: string;
+  collector_number: string;
+  language_code: string;
+  foil: boolean;
+  name: string;
+  quantity: number;
+  /**
+   * Current trend of one copy, in cents
+   */
+  trend: number | null;
+};
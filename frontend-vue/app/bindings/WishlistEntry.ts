@@ -0,0 +1,43 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { Finish } from './Finish';
+import type { WishPriority } from './WishPriority';
+
+/**
+ * A wish with the offers of other users matching it. The printing fields are set for a wish of
+ * one printing, `oracle_id` for a wish of any printing of the card.
+ */
+export type WishlistEntry = {
+  id: string;
+  name: string;
+  oracle_id: string | null;
+  set_code: string | null;
+  collector_number: string | null;
+  language_code: string | null;
+  foil: boolean | null;
+  quantity: number;
+  /**
+   * Highest trend price accepted, in cents
+   */
+  max_price: number | null;
+  /**
+   * Languages accepted, empty for every language
+   */
+  languages: Array<string>;
+  /**
+   * Finishes accepted, empty for both
+   */
+  finishes: Array<Finish>;
+  priority: WishPriority;
+  /**
+   * RFC 3339 timestamp
+   */
+  created_at: string;
+  /**
+   * Number of other users offering a matching printing for trade
+   */
+  offer_count: number;
+  /**
+   * Lowest trend among the matching offers, in cents
+   */
+  cheapest_offer: number | null;
+};
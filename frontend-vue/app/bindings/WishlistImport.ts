@@ -0,0 +1,12 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+export type WishlistImport = {
+  /**
+   * Number of cards added or whose wished quantity was raised
+   */
+  imported: number;
+  /**
+   * Names of the decklist matching no known card, as written
+   */
+  unknown_names: Array<string>;
+};
@@ -1,3 +1,4 @@
+import type { CardEdhRec } from '~/bindings/CardEdhRec';
 import type { CardOffersParams } from '~/bindings/CardOffersParams';
 import type { PaginatedCardOffers } from '~/bindings/PaginatedCardOffers';
 import type { PriceHistoryEntry } from '~/bindings/PriceHistoryEntry';
@@ -6,7 +7,8 @@ import type { PriceHistoryParams } from '~/bindings/PriceHistoryParams';
 export const useCardsService = () => {
   const { apiCall } = useApi();
 
-  const getCardInfo = () => apiCall('/card/card-info', { method: 'POST' });
+  const getCardEdhRec = (scryfallId: string) =>
+    apiCall<CardEdhRec>(`/card/${scryfallId}/edhrec`);
 
   const getCardPriceHistory = (scryfallId: string, params?: PriceHistoryParams) =>
     apiCall<PriceHistoryEntry[]>(`/card/${scryfallId}/price-history`, { query: params });
@@ -15,7 +17,7 @@ export const useCardsService = () => {
     apiCall<PaginatedCardOffers>('/card/offers', { query: params });
 
   return {
-    getCardInfo,
+    getCardEdhRec,
     getCardPriceHistory,
     getCardOffers,
   };
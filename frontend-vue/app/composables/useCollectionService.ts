@@ -1,5 +1,6 @@
 import type { CollectionParams } from '~/bindings/CollectionParams';
 import type { CollectionStats } from '~/bindings/CollectionStats';
+import type { CommanderSynergy } from '~/bindings/CommanderSynergy';
 import type { Message } from '~/bindings/Message';
 import type { PaginatedCollection } from '~/bindings/PaginatedCollection';
 import type { PriceHistoryEntry } from '~/bindings/PriceHistoryEntry';
@@ -34,10 +35,14 @@ export const useCollectionService = () => {
       { lazy: true },
     );
 
+  const getCommanderSynergy = (commander: string) =>
+    apiCall<CommanderSynergy>('/collection/commander-synergy', { query: { commander } });
+
   return {
     getCollection,
     importCards,
     getCollectionStats,
     getPriceHistory,
+    getCommanderSynergy,
   };
 };
@@ -0,0 +1,55 @@
+ALTER TABLE cardmarket_price
+    ADD COLUMN quarantined BOOLEAN NOT NULL DEFAULT FALSE;
+
+CREATE TABLE price_anomaly
+(
+    id_produit  INTEGER     NOT NULL,
+    date        DATE        NOT NULL,
+    foil        BOOLEAN     NOT NULL,
+    kind        VARCHAR(10) NOT NULL,
+    reference   INTEGER     NOT NULL,
+    observed    INTEGER     NOT NULL,
+    status      VARCHAR(10) NOT NULL DEFAULT 'PENDING',
+    detected_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    reviewed_at TIMESTAMPTZ,
+
+    CONSTRAINT price_anomaly_pk PRIMARY KEY (id_produit, date, foil),
+    CONSTRAINT price_anomaly_price_fk FOREIGN KEY (id_produit, date) REFERENCES cardmarket_price (id_produit, date) ON DELETE CASCADE,
+    CONSTRAINT price_anomaly_kind_check CHECK (kind IN ('SPIKE', 'DROP', 'ZERO')),
+    CONSTRAINT price_anomaly_status_check CHECK (status IN ('PENDING', 'CONFIRMED', 'REJECTED'))
+);
+
+CREATE INDEX price_anomaly_pending_idx ON price_anomaly (date) WHERE status = 'PENDING';
+
+DROP MATERIALIZED VIEW IF EXISTS mv_card_prices;
+
+CREATE MATERIALIZED VIEW mv_card_prices AS
+WITH last_price AS (SELECT id_produit, MAX(date) AS last_date
+                    FROM cardmarket_price
+                    WHERE NOT quarantined
+                    GROUP BY id_produit)
+SELECT c.set_code,
+       c.collector_number,
+       c.language_code,
+       c.foil,
+       c.name,
+       c.rarity,
+       c.scryfall_id,
+       c.the_gatherer_id,
+       ce.user_id,
+       ce.quantity,
+       ce.purchase_price,
+       ce.added_at,
+       CASE WHEN c.foil THEN cmp.low_foil ELSE cmp.low END     AS low,
+       CASE WHEN c.foil THEN cmp.trend_foil ELSE cmp.trend END AS trend,
+       CASE WHEN c.foil THEN cmp.avg_foil ELSE cmp.avg END     AS avg
+FROM card c
+         JOIN collection_entry ce ON c.set_code = ce.set_code
+    AND c.collector_number = ce.collector_number
+    AND c.language_code = ce.language_code
+    AND c.foil = ce.foil
+         LEFT JOIN last_price lp ON c.cardmarket_id = lp.id_produit
+         LEFT JOIN cardmarket_price cmp ON c.cardmarket_id = cmp.id_produit
+    AND cmp.date = lp.last_date;
+
+CREATE UNIQUE INDEX mv_card_prices_unique ON mv_card_prices (set_code, collector_number, language_code, foil, user_id);
@@ -0,0 +1,14 @@
+-- Prix du guide en cours de téléchargement, publiés dans cardmarket_price en une seule
+-- transaction une fois le fichier entièrement lu
+CREATE UNLOGGED TABLE cardmarket_price_staging
+(
+    id_produit INTEGER NOT NULL,
+    low        INTEGER,
+    trend      INTEGER,
+    avg        INTEGER,
+    low_foil   INTEGER,
+    trend_foil INTEGER,
+    avg_foil   INTEGER,
+
+    CONSTRAINT cardmarket_price_staging_pk PRIMARY KEY (id_produit)
+);
@@ -0,0 +1,22 @@
+ALTER TABLE cardmarket_price
+    ADD COLUMN id_category INTEGER;
+
+ALTER TABLE cardmarket_price_staging
+    ADD COLUMN id_category INTEGER;
+
+-- Dernier guide publié, tous produits confondus quel que soit le périmètre d'import : source du
+-- rattrapage des produits dont une carte reçoit son cardmarket_id après l'import
+CREATE TABLE cardmarket_price_guide_latest
+(
+    id_produit  INTEGER NOT NULL,
+    date        DATE    NOT NULL,
+    id_category INTEGER,
+    low         INTEGER,
+    trend       INTEGER,
+    avg         INTEGER,
+    low_foil    INTEGER,
+    trend_foil  INTEGER,
+    avg_foil    INTEGER,
+
+    CONSTRAINT cardmarket_price_guide_latest_pk PRIMARY KEY (id_produit)
+);
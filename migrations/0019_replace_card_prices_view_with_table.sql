@@ -0,0 +1,66 @@
+DROP MATERIALIZED VIEW IF EXISTS mv_card_prices;
+
+-- Lignes de card_prices telles qu'elles doivent être, colonnes dans le même ordre : les mises à
+-- jour incrémentales en recopient le sous-ensemble touché (un utilisateur, des produits ou une carte)
+CREATE VIEW card_prices_source AS
+SELECT c.set_code,
+       c.collector_number,
+       c.language_code,
+       c.foil,
+       c.name,
+       c.rarity,
+       c.scryfall_id,
+       c.the_gatherer_id,
+       c.cardmarket_id,
+       ce.user_id,
+       ce.quantity,
+       ce.purchase_price,
+       ce.added_at,
+       CASE WHEN c.foil THEN cmp.low_foil ELSE cmp.low END     AS low,
+       CASE WHEN c.foil THEN cmp.trend_foil ELSE cmp.trend END AS trend,
+       CASE WHEN c.foil THEN cmp.avg_foil ELSE cmp.avg END     AS avg
+FROM card c
+         JOIN collection_entry ce ON c.set_code = ce.set_code
+    AND c.collector_number = ce.collector_number
+    AND c.language_code = ce.language_code
+    AND c.foil = ce.foil
+         LEFT JOIN LATERAL (SELECT low, trend, avg, low_foil, trend_foil, avg_foil
+                            FROM cardmarket_price
+                            WHERE id_produit = c.cardmarket_id
+                              AND NOT quarantined
+                            ORDER BY date DESC
+                            LIMIT 1) cmp ON TRUE;
+
+CREATE TABLE card_prices
+(
+    set_code         VARCHAR(5)  NOT NULL,
+    collector_number VARCHAR(10) NOT NULL,
+    language_code    VARCHAR(2)  NOT NULL,
+    foil             BOOLEAN     NOT NULL,
+    name             VARCHAR(255) NOT NULL,
+    rarity           VARCHAR(1)  NOT NULL,
+    scryfall_id      UUID        NOT NULL,
+    the_gatherer_id  VARCHAR(64),
+    cardmarket_id    INTEGER,
+    user_id          VARCHAR(50) NOT NULL,
+    quantity         INTEGER     NOT NULL,
+    purchase_price   INTEGER     NOT NULL,
+    added_at         TIMESTAMPTZ,
+    low              INTEGER,
+    trend            INTEGER,
+    avg              INTEGER,
+
+    CONSTRAINT card_prices_pk PRIMARY KEY (set_code, collector_number, language_code, foil, user_id)
+);
+
+CREATE INDEX card_prices_user_idx ON card_prices (user_id);
+CREATE INDEX card_prices_cardmarket_id_idx ON card_prices (cardmarket_id);
+CREATE INDEX card_prices_name_trgm_idx ON card_prices USING GIN (name gin_trgm_ops);
+
+-- Utilisés pour recalculer les lignes d'un utilisateur ou d'un produit
+CREATE INDEX collection_entry_user_idx ON collection_entry (user_id);
+CREATE INDEX card_cardmarket_id_idx ON card (cardmarket_id);
+
+INSERT INTO card_prices
+SELECT *
+FROM card_prices_source;
@@ -0,0 +1,22 @@
+CREATE TABLE enrichment_job
+(
+    id               BIGSERIAL PRIMARY KEY,
+    kind             VARCHAR(20) NOT NULL,
+    set_code         VARCHAR(5)  NOT NULL,
+    collector_number VARCHAR(10) NOT NULL,
+    language_code    VARCHAR(2)  NOT NULL,
+    foil             BOOLEAN     NOT NULL,
+    status           VARCHAR(10) NOT NULL DEFAULT 'PENDING',
+    attempts         INTEGER     NOT NULL DEFAULT 0,
+    run_after        TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    locked_until     TIMESTAMPTZ,
+    last_error       TEXT,
+    created_at       TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+
+    CONSTRAINT enrichment_job_card_unique UNIQUE (kind, set_code, collector_number, language_code, foil),
+    CONSTRAINT enrichment_job_card_fk FOREIGN KEY (set_code, collector_number, language_code, foil) REFERENCES card (set_code, collector_number, language_code, foil) ON DELETE CASCADE,
+    CONSTRAINT enrichment_job_kind_check CHECK (kind IN ('CARDMARKET_ID', 'GATHERER_ID')),
+    CONSTRAINT enrichment_job_status_check CHECK (status IN ('PENDING', 'RUNNING', 'DEAD'))
+);
+
+CREATE INDEX enrichment_job_claim_idx ON enrichment_job (kind, run_after) WHERE status <> 'DEAD';
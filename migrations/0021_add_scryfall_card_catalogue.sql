@@ -0,0 +1,12 @@
+CREATE TABLE scryfall_card
+(
+    scryfall_id      UUID PRIMARY KEY,
+    name             TEXT        NOT NULL,
+    set_code         VARCHAR(10) NOT NULL,
+    collector_number VARCHAR(20) NOT NULL,
+    lang             VARCHAR(5)  NOT NULL,
+    cardmarket_id    INTEGER,
+    updated_at       TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX card_scryfall_id_idx ON card (scryfall_id) WHERE cardmarket_id IS NULL;
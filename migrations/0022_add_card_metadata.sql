@@ -0,0 +1,26 @@
+-- Données de jeu et images de Scryfall, vides tant que la carte n'a pas été enrichie
+ALTER TABLE card
+    ADD COLUMN oracle_id        UUID,
+    ADD COLUMN mana_cost        TEXT,
+    ADD COLUMN type_line        TEXT,
+    ADD COLUMN colors           TEXT[] NOT NULL DEFAULT '{}',
+    ADD COLUMN color_identity   TEXT[] NOT NULL DEFAULT '{}',
+    ADD COLUMN oracle_text      TEXT,
+    ADD COLUMN image_small_url  TEXT,
+    ADD COLUMN image_normal_url TEXT,
+    ADD COLUMN image_large_url  TEXT;
+
+ALTER TABLE scryfall_card
+    ADD COLUMN oracle_id        UUID,
+    ADD COLUMN mana_cost        TEXT,
+    ADD COLUMN type_line        TEXT,
+    ADD COLUMN colors           TEXT[] NOT NULL DEFAULT '{}',
+    ADD COLUMN color_identity   TEXT[] NOT NULL DEFAULT '{}',
+    ADD COLUMN oracle_text      TEXT,
+    ADD COLUMN image_small_url  TEXT,
+    ADD COLUMN image_normal_url TEXT,
+    ADD COLUMN image_large_url  TEXT;
+
+-- Filtres par couleur et par type de la collection et de la recherche
+CREATE INDEX card_colors_idx ON card USING GIN (colors);
+CREATE INDEX card_type_line_trgm_idx ON card USING GIN (type_line gin_trgm_ops);
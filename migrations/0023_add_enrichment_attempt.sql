@@ -0,0 +1,20 @@
+-- Dernière tentative d'enrichissement de chaque carte, conservée après la suppression de son job
+CREATE TABLE enrichment_attempt
+(
+    kind             VARCHAR(20) NOT NULL,
+    set_code         VARCHAR(5)  NOT NULL,
+    collector_number VARCHAR(10) NOT NULL,
+    language_code    VARCHAR(2)  NOT NULL,
+    foil             BOOLEAN     NOT NULL,
+    attempts         INTEGER     NOT NULL,
+    last_attempt_at  TIMESTAMPTZ NOT NULL,
+    outcome          VARCHAR(10) NOT NULL,
+    reason           TEXT,
+    -- NULL : la carte n'est plus recherchée
+    next_attempt_at  TIMESTAMPTZ,
+
+    CONSTRAINT enrichment_attempt_pk PRIMARY KEY (kind, set_code, collector_number, language_code, foil),
+    CONSTRAINT enrichment_attempt_card_fk FOREIGN KEY (set_code, collector_number, language_code, foil) REFERENCES card (set_code, collector_number, language_code, foil) ON DELETE CASCADE,
+    CONSTRAINT enrichment_attempt_kind_check CHECK (kind IN ('CARDMARKET_ID', 'GATHERER_ID')),
+    CONSTRAINT enrichment_attempt_outcome_check CHECK (outcome IN ('RESOLVED', 'NOT_FOUND', 'FAILED'))
+);
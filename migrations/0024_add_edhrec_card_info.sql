@@ -0,0 +1,10 @@
+-- Statistiques EDHREC d'une carte, partagées par toutes ses impressions
+CREATE TABLE edhrec_card_info
+(
+    name            TEXT PRIMARY KEY,
+    inclusion       INTEGER     NOT NULL,
+    potential_decks INTEGER     NOT NULL,
+    -- NULL : carte non classée par EDHREC
+    rank            INTEGER,
+    fetched_at      TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
@@ -0,0 +1,2 @@
+-- Recherche des cartes recommandées par EDHREC par nom, sans tenir compte de la casse
+CREATE INDEX card_prices_lower_name_idx ON card_prices (LOWER(name));
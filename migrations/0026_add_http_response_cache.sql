@@ -0,0 +1,14 @@
+-- Réponses des appels sortants (Scryfall, Gatherer, EDHREC) mises en cache par URL
+CREATE TABLE http_response_cache
+(
+    url           TEXT PRIMARY KEY,
+    source        TEXT        NOT NULL,
+    body          TEXT        NOT NULL,
+    -- Validateurs renvoyés par le serveur, NULL s'il n'en donne pas
+    etag          TEXT,
+    last_modified TEXT,
+    -- Date de la dernière réponse ou revalidation du serveur
+    fetched_at    TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX http_response_cache_source_idx ON http_response_cache (source);
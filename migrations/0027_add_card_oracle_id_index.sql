@@ -0,0 +1,2 @@
+-- Recherche, offres et souhaits sur « n'importe quelle impression » d'une carte
+CREATE INDEX card_oracle_id_idx ON card (oracle_id);
@@ -0,0 +1,20 @@
+-- Identifiant Gatherer de la face arrière des cartes double face (transform, modal DFC)
+ALTER TABLE card ADD COLUMN the_gatherer_back_id VARCHAR(64);
+
+-- Les cartes à plusieurs faces n'ont eu que leur première face, ou une mauvaise page :
+-- on les remet en attente pour que l'enrichissement Gatherer les reprenne
+UPDATE card SET the_gatherer_id = NULL WHERE name LIKE '%//%';
+
+-- Leurs tentatives en échec (la page de la première face renvoyait 404) les excluraient de la reprise
+DELETE FROM enrichment_attempt ea
+    USING card c
+    WHERE ea.kind = 'GATHERER_ID'
+      AND c.set_code = ea.set_code AND c.collector_number = ea.collector_number
+      AND c.language_code = ea.language_code AND c.foil = ea.foil
+      AND c.name LIKE '%//%';
+DELETE FROM enrichment_job ej
+    USING card c
+    WHERE ej.kind = 'GATHERER_ID' AND ej.status = 'DEAD'
+      AND c.set_code = ej.set_code AND c.collector_number = ej.collector_number
+      AND c.language_code = ej.language_code AND c.foil = ej.foil
+      AND c.name LIKE '%//%';
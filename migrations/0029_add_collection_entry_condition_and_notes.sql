@@ -0,0 +1,4 @@
+-- État et notes saisis à la main sur une entrée de collection ; l'import ManaBox garde les valeurs par défaut
+ALTER TABLE collection_entry
+    ADD COLUMN condition VARCHAR(20) NOT NULL DEFAULT 'NEAR_MINT',
+    ADD COLUMN notes     TEXT;
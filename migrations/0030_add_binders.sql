@@ -0,0 +1,100 @@
+-- Classeurs nommés d'un utilisateur : chaque entrée de collection vit dans un classeur, seuls les
+-- classeurs échangeables sont visibles des autres utilisateurs (recherche, offres, échanges)
+CREATE TABLE binder
+(
+    id         UUID PRIMARY KEY,
+    user_id    VARCHAR(50)  NOT NULL,
+    name       VARCHAR(100) NOT NULL,
+    tradable   BOOLEAN      NOT NULL DEFAULT FALSE,
+    -- Classeur utilisé par l'import et l'ajout manuel quand aucun n'est précisé
+    is_default BOOLEAN      NOT NULL DEFAULT FALSE,
+    created_at TIMESTAMPTZ  NOT NULL DEFAULT NOW(),
+
+    CONSTRAINT binder_user_name_uq UNIQUE (user_id, name),
+    -- Cible de la clé étrangère de collection_entry, qui garantit que l'entrée et son classeur
+    -- appartiennent au même utilisateur
+    CONSTRAINT binder_id_user_uq UNIQUE (id, user_id)
+);
+
+CREATE UNIQUE INDEX binder_default_uq ON binder (user_id) WHERE is_default;
+
+-- Les collections existantes deviennent le classeur par défaut de leur propriétaire, échangeable
+-- pour qu'elles restent visibles comme avant
+INSERT INTO binder (id, user_id, name, tradable, is_default)
+SELECT gen_random_uuid(), user_id, 'Collection', TRUE, TRUE
+FROM (SELECT DISTINCT user_id FROM collection_entry) AS owners;
+
+ALTER TABLE collection_entry
+    ADD COLUMN binder_id UUID;
+
+UPDATE collection_entry ce
+SET binder_id = b.id
+FROM binder b
+WHERE b.user_id = ce.user_id
+  AND b.is_default;
+
+ALTER TABLE collection_entry
+    ALTER COLUMN binder_id SET NOT NULL,
+    DROP CONSTRAINT collection_entry_pk,
+    ADD CONSTRAINT collection_entry_pk
+        PRIMARY KEY (binder_id, set_code, collector_number, language_code, foil),
+    ADD CONSTRAINT collection_entry_binder_fk FOREIGN KEY (binder_id, user_id)
+        REFERENCES binder (id, user_id) ON DELETE CASCADE;
+
+-- Le classeur et son caractère échangeable sont recopiés dans card_prices, colonnes ajoutées en
+-- fin de vue et de table pour garder le même ordre
+CREATE OR REPLACE VIEW card_prices_source AS
+SELECT c.set_code,
+       c.collector_number,
+       c.language_code,
+       c.foil,
+       c.name,
+       c.rarity,
+       c.scryfall_id,
+       c.the_gatherer_id,
+       c.cardmarket_id,
+       ce.user_id,
+       ce.quantity,
+       ce.purchase_price,
+       ce.added_at,
+       CASE WHEN c.foil THEN cmp.low_foil ELSE cmp.low END     AS low,
+       CASE WHEN c.foil THEN cmp.trend_foil ELSE cmp.trend END AS trend,
+       CASE WHEN c.foil THEN cmp.avg_foil ELSE cmp.avg END     AS avg,
+       ce.binder_id,
+       b.tradable
+FROM card c
+         JOIN collection_entry ce ON c.set_code = ce.set_code
+    AND c.collector_number = ce.collector_number
+    AND c.language_code = ce.language_code
+    AND c.foil = ce.foil
+         JOIN binder b ON b.id = ce.binder_id
+         LEFT JOIN LATERAL (SELECT low, trend, avg, low_foil, trend_foil, avg_foil
+                            FROM cardmarket_price
+                            WHERE id_produit = c.cardmarket_id
+                              AND NOT quarantined
+                            ORDER BY date DESC
+                            LIMIT 1) cmp ON TRUE;
+
+ALTER TABLE card_prices
+    ADD COLUMN binder_id UUID,
+    ADD COLUMN tradable  BOOLEAN;
+
+UPDATE card_prices cp
+SET binder_id = ce.binder_id,
+    tradable  = TRUE
+FROM collection_entry ce
+WHERE ce.set_code = cp.set_code
+  AND ce.collector_number = cp.collector_number
+  AND ce.language_code = cp.language_code
+  AND ce.foil = cp.foil
+  AND ce.user_id = cp.user_id;
+
+ALTER TABLE card_prices
+    ALTER COLUMN binder_id SET NOT NULL,
+    ALTER COLUMN tradable SET NOT NULL,
+    DROP CONSTRAINT card_prices_pk,
+    ADD CONSTRAINT card_prices_pk
+        PRIMARY KEY (set_code, collector_number, language_code, foil, user_id, binder_id);
+
+-- L'ancienne clé primaire servait aussi aux jointures depuis card
+CREATE INDEX collection_entry_card_idx ON collection_entry (set_code, collector_number, language_code, foil);
@@ -0,0 +1,59 @@
+-- Nombre d'exemplaires proposés à l'échange : règle « garder N exemplaires » par classeur et
+-- surcharge par entrée, NULL pour suivre la règle du classeur
+ALTER TABLE binder
+    ADD COLUMN keep_quantity INTEGER NOT NULL DEFAULT 0,
+    ADD CONSTRAINT binder_keep_quantity_ck CHECK (keep_quantity >= 0);
+
+ALTER TABLE collection_entry
+    ADD COLUMN tradable_quantity INTEGER,
+    ADD CONSTRAINT collection_entry_tradable_quantity_ck CHECK (tradable_quantity >= 0);
+
+-- Exemplaires échangeables effectifs, recopiés dans card_prices : aucun hors d'un classeur
+-- échangeable, jamais plus que la quantité possédée
+CREATE OR REPLACE VIEW card_prices_source AS
+SELECT c.set_code,
+       c.collector_number,
+       c.language_code,
+       c.foil,
+       c.name,
+       c.rarity,
+       c.scryfall_id,
+       c.the_gatherer_id,
+       c.cardmarket_id,
+       ce.user_id,
+       ce.quantity,
+       ce.purchase_price,
+       ce.added_at,
+       CASE WHEN c.foil THEN cmp.low_foil ELSE cmp.low END     AS low,
+       CASE WHEN c.foil THEN cmp.trend_foil ELSE cmp.trend END AS trend,
+       CASE WHEN c.foil THEN cmp.avg_foil ELSE cmp.avg END     AS avg,
+       ce.binder_id,
+       b.tradable,
+       CASE
+           WHEN b.tradable
+               THEN LEAST(COALESCE(ce.tradable_quantity, GREATEST(ce.quantity - b.keep_quantity, 0)),
+                          ce.quantity)
+           ELSE 0
+           END                                                 AS tradable_quantity
+FROM card c
+         JOIN collection_entry ce ON c.set_code = ce.set_code
+    AND c.collector_number = ce.collector_number
+    AND c.language_code = ce.language_code
+    AND c.foil = ce.foil
+         JOIN binder b ON b.id = ce.binder_id
+         LEFT JOIN LATERAL (SELECT low, trend, avg, low_foil, trend_foil, avg_foil
+                            FROM cardmarket_price
+                            WHERE id_produit = c.cardmarket_id
+                              AND NOT quarantined
+                            ORDER BY date DESC
+                            LIMIT 1) cmp ON TRUE;
+
+-- Sans règle ni surcharge, tous les exemplaires d'un classeur échangeable le sont
+ALTER TABLE card_prices
+    ADD COLUMN tradable_quantity INTEGER;
+
+UPDATE card_prices
+SET tradable_quantity = CASE WHEN tradable THEN quantity ELSE 0 END;
+
+ALTER TABLE card_prices
+    ALTER COLUMN tradable_quantity SET NOT NULL;
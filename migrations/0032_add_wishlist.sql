@@ -0,0 +1,39 @@
+-- Liste de souhaits d'un utilisateur : une impression précise, ou n'importe quelle impression d'une
+-- carte désignée par son oracle_id
+CREATE TABLE wishlist_entry
+(
+    id               UUID PRIMARY KEY,
+    user_id          VARCHAR(50)  NOT NULL,
+    -- Impression précise, toutes à NULL quand la carte est désignée par son oracle_id
+    set_code         VARCHAR(5),
+    collector_number VARCHAR(10),
+    language_code    VARCHAR(2),
+    foil             BOOLEAN,
+    oracle_id        UUID,
+    -- Nom de la carte au moment de l'ajout, pour l'affichage
+    name             VARCHAR(255) NOT NULL,
+    quantity         INTEGER      NOT NULL,
+    -- Prix maximum en centimes, comparé à la tendance des offres
+    max_price        INTEGER,
+    -- Langues et finitions acceptées pour une carte désignée par son oracle_id, vides pour toutes
+    languages        VARCHAR(2)[] NOT NULL DEFAULT '{}',
+    finishes         VARCHAR(10)[] NOT NULL DEFAULT '{}',
+    priority         VARCHAR(10)  NOT NULL DEFAULT 'NORMAL',
+    created_at       TIMESTAMPTZ  NOT NULL DEFAULT NOW(),
+
+    CONSTRAINT wishlist_entry_quantity_ck CHECK (quantity > 0),
+    CONSTRAINT wishlist_entry_max_price_ck CHECK (max_price >= 0),
+    CONSTRAINT wishlist_entry_card_ck CHECK (
+        (oracle_id IS NOT NULL AND set_code IS NULL AND collector_number IS NULL
+            AND language_code IS NULL AND foil IS NULL)
+            OR (oracle_id IS NULL AND set_code IS NOT NULL AND collector_number IS NOT NULL
+            AND language_code IS NOT NULL AND foil IS NOT NULL)),
+    -- Les NULL étant distincts, chaque contrainte ne porte que sur son type d'entrée
+    CONSTRAINT wishlist_entry_printing_uq UNIQUE (user_id, set_code, collector_number, language_code, foil),
+    CONSTRAINT wishlist_entry_oracle_uq UNIQUE (user_id, oracle_id)
+);
+
+-- Résolution des noms d'une liste de cartes importée, nom complet ou première face
+CREATE INDEX scryfall_card_lower_name_idx ON scryfall_card (LOWER(name));
+CREATE INDEX scryfall_card_lower_front_face_idx ON scryfall_card (LOWER(split_part(name, ' // ', 1)));
+CREATE INDEX scryfall_card_oracle_id_idx ON scryfall_card (oracle_id);
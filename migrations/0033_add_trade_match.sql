@@ -0,0 +1,19 @@
+-- Exemplaires échangeables d'un utilisateur correspondant à un souhait d'un autre, recalculés pour un
+-- utilisateur après chaque changement de sa collection ou de sa liste de souhaits
+CREATE TABLE trade_match_card
+(
+    -- Utilisateur qui souhaite la carte
+    user_id          VARCHAR(50) NOT NULL,
+    -- Utilisateur qui propose la carte à l'échange
+    owner_user_id    VARCHAR(50) NOT NULL,
+    set_code         VARCHAR(5)  NOT NULL,
+    collector_number VARCHAR(10) NOT NULL,
+    language_code    VARCHAR(2)  NOT NULL,
+    foil             BOOLEAN     NOT NULL,
+    quantity         INTEGER     NOT NULL,
+
+    CONSTRAINT trade_match_card_pk PRIMARY KEY (user_id, owner_user_id, set_code, collector_number, language_code, foil),
+    CONSTRAINT trade_match_card_quantity_ck CHECK (quantity > 0)
+);
+
+CREATE INDEX trade_match_card_owner_idx ON trade_match_card (owner_user_id, user_id);
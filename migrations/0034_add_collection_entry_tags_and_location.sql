@@ -0,0 +1,64 @@
+-- Étiquettes libres et emplacement de rangement saisis à la main sur une entrée de collection ;
+-- l'import ne les touche pas, pas plus que l'état et les notes
+ALTER TABLE collection_entry
+    ADD COLUMN tags     VARCHAR(50)[] NOT NULL DEFAULT '{}',
+    ADD COLUMN location VARCHAR(100);
+
+-- Étiquettes, notes et emplacement sont recopiés dans card_prices pour filtrer et chercher dans la
+-- collection, colonnes ajoutées en fin de vue et de table pour garder le même ordre
+CREATE OR REPLACE VIEW card_prices_source AS
+SELECT c.set_code,
+       c.collector_number,
+       c.language_code,
+       c.foil,
+       c.name,
+       c.rarity,
+       c.scryfall_id,
+       c.the_gatherer_id,
+       c.cardmarket_id,
+       ce.user_id,
+       ce.quantity,
+       ce.purchase_price,
+       ce.added_at,
+       CASE WHEN c.foil THEN cmp.low_foil ELSE cmp.low END     AS low,
+       CASE WHEN c.foil THEN cmp.trend_foil ELSE cmp.trend END AS trend,
+       CASE WHEN c.foil THEN cmp.avg_foil ELSE cmp.avg END     AS avg,
+       ce.binder_id,
+       b.tradable,
+       CASE
+           WHEN b.tradable
+               THEN LEAST(COALESCE(ce.tradable_quantity, GREATEST(ce.quantity - b.keep_quantity, 0)),
+                          ce.quantity)
+           ELSE 0
+           END                                                 AS tradable_quantity,
+       ce.tags,
+       ce.notes,
+       ce.location
+FROM card c
+         JOIN collection_entry ce ON c.set_code = ce.set_code
+    AND c.collector_number = ce.collector_number
+    AND c.language_code = ce.language_code
+    AND c.foil = ce.foil
+         JOIN binder b ON b.id = ce.binder_id
+         LEFT JOIN LATERAL (SELECT low, trend, avg, low_foil, trend_foil, avg_foil
+                            FROM cardmarket_price
+                            WHERE id_produit = c.cardmarket_id
+                              AND NOT quarantined
+                            ORDER BY date DESC
+                            LIMIT 1) cmp ON TRUE;
+
+-- Aucune étiquette ni emplacement n'existe encore, seules les notes sont à recopier
+ALTER TABLE card_prices
+    ADD COLUMN tags     VARCHAR(50)[] NOT NULL DEFAULT '{}',
+    ADD COLUMN notes    TEXT,
+    ADD COLUMN location VARCHAR(100);
+
+UPDATE card_prices cp
+SET notes = ce.notes
+FROM collection_entry ce
+WHERE ce.set_code = cp.set_code
+  AND ce.collector_number = cp.collector_number
+  AND ce.language_code = cp.language_code
+  AND ce.foil = cp.foil
+  AND ce.binder_id = cp.binder_id
+  AND ce.notes IS NOT NULL;
@@ -0,0 +1,3 @@
+-- Rareté de l'impression, pour créer une carte encore jamais importée depuis le catalogue ;
+-- renseignée au prochain import du fichier Scryfall, NULL pour une rareté inconnue (bonus)
+ALTER TABLE scryfall_card ADD COLUMN rarity VARCHAR(1);
@@ -0,0 +1,2 @@
+-- Plus aucun appel à Scryfall ne passe par le cache HTTP : la source n'existe plus
+DELETE FROM http_response_cache WHERE source = 'SCRYFALL';
@@ -0,0 +1,70 @@
+-- Une anomalie ne vise qu'une finition : le prix normal et le prix foil sont mis en quarantaine
+-- séparément, quarantined ne porte plus que sur le prix normal
+ALTER TABLE cardmarket_price
+    ADD COLUMN quarantined_foil BOOLEAN NOT NULL DEFAULT FALSE;
+
+UPDATE cardmarket_price cmp
+SET quarantined      = EXISTS (SELECT 1
+                               FROM price_anomaly pa
+                               WHERE pa.id_produit = cmp.id_produit
+                                 AND pa.date = cmp.date
+                                 AND NOT pa.foil
+                                 AND pa.status <> 'CONFIRMED'),
+    quarantined_foil = EXISTS (SELECT 1
+                               FROM price_anomaly pa
+                               WHERE pa.id_produit = cmp.id_produit
+                                 AND pa.date = cmp.date
+                                 AND pa.foil
+                                 AND pa.status <> 'CONFIRMED')
+WHERE cmp.quarantined;
+
+CREATE OR REPLACE VIEW card_prices_source AS
+SELECT c.set_code,
+       c.collector_number,
+       c.language_code,
+       c.foil,
+       c.name,
+       c.rarity,
+       c.scryfall_id,
+       c.the_gatherer_id,
+       c.cardmarket_id,
+       ce.user_id,
+       ce.quantity,
+       ce.purchase_price,
+       ce.added_at,
+       CASE WHEN c.foil THEN cmp.low_foil ELSE cmp.low END     AS low,
+       CASE WHEN c.foil THEN cmp.trend_foil ELSE cmp.trend END AS trend,
+       CASE WHEN c.foil THEN cmp.avg_foil ELSE cmp.avg END     AS avg,
+       ce.binder_id,
+       b.tradable,
+       CASE
+           WHEN b.tradable
+               THEN LEAST(COALESCE(ce.tradable_quantity, GREATEST(ce.quantity - b.keep_quantity, 0)),
+                          ce.quantity)
+           ELSE 0
+           END                                                 AS tradable_quantity,
+       ce.tags,
+       ce.notes,
+       ce.location
+FROM card c
+         JOIN collection_entry ce ON c.set_code = ce.set_code
+    AND c.collector_number = ce.collector_number
+    AND c.language_code = ce.language_code
+    AND c.foil = ce.foil
+         JOIN binder b ON b.id = ce.binder_id
+         LEFT JOIN LATERAL (SELECT low, trend, avg, low_foil, trend_foil, avg_foil
+                            FROM cardmarket_price
+                            WHERE id_produit = c.cardmarket_id
+                              AND NOT CASE WHEN c.foil THEN quarantined_foil ELSE quarantined END
+                            ORDER BY date DESC
+                            LIMIT 1) cmp ON TRUE;
+
+-- La finition saine d'un produit en quarantaine retrouve son dernier prix
+DELETE
+FROM card_prices
+WHERE cardmarket_id IN (SELECT id_produit FROM price_anomaly WHERE status <> 'CONFIRMED');
+
+INSERT INTO card_prices
+SELECT *
+FROM card_prices_source
+WHERE cardmarket_id IN (SELECT id_produit FROM price_anomaly WHERE status <> 'CONFIRMED');
@@ -1,10 +1,14 @@
 use crate::application::error::AppError;
-use crate::domain::card::CardInfo;
+use crate::domain::card::{CardInfo, GathererIds};
+use crate::domain::commander_synergy::CommanderRecommendation;
 use crate::domain::language_code::LanguageCode;
-use crate::domain::price::FullPriceGuide;
+use crate::domain::price::PriceGuideChunk;
+use crate::domain::scryfall_card::ScryfallCard;
 use crate::domain::set_name::SetCode;
 use async_trait::async_trait;
 use chrono::NaiveDate;
+use std::collections::HashMap;
+use tokio::sync::mpsc::Sender;
 use uuid::Uuid;
 
 #[cfg(test)]
@@ -13,29 +17,53 @@ use mockall::automock;
 #[async_trait]
 #[cfg_attr(test, automock)]
 pub trait CardMarketCaller: Send + Sync {
-    async fn get_price_guides(&self) -> Result<(NaiveDate, Vec<FullPriceGuide>), AppError>;
+    /// Downloads the price guide file and sends its price guides in chunks while it is parsed.
+    /// Returns the date of the file once every chunk is sent.
+    async fn stream_price_guides(
+        &self,
+        sender: Sender<PriceGuideChunk>,
+    ) -> Result<NaiveDate, AppError>;
 }
 
 #[async_trait]
 #[cfg_attr(test, automock)]
 pub trait EdhRecCaller: Send + Sync {
     async fn get_card_info(&self, card_name: String) -> Result<CardInfo, AppError>;
+    /// High synergy and top cards of the commander's page, `None` when EDHREC has no page for it.
+    async fn get_commander_recommendations(
+        &self,
+        commander_name: String,
+    ) -> Result<Option<Vec<CommanderRecommendation>>, AppError>;
 }
 
 #[async_trait]
 #[cfg_attr(test, automock)]
 pub trait ScryfallCaller: Send + Sync {
-    async fn get_card_market_id(&self, id: Uuid) -> Result<Option<u32>, AppError>;
+    /// Looks these cards up in as few calls as possible. Cards unknown to Scryfall are absent
+    /// from the result.
+    async fn get_cards(&self, ids: Vec<Uuid>) -> Result<HashMap<Uuid, ScryfallCard>, AppError>;
+}
+
+#[async_trait]
+#[cfg_attr(test, automock)]
+pub trait ScryfallBulkCaller: Send + Sync {
+    /// Reads the `default-cards` bulk data file and sends its cards in chunks while it is parsed.
+    /// Returns the number of cards read once every chunk is sent.
+    async fn stream_default_cards(
+        &self,
+        sender: Sender<Vec<ScryfallCard>>,
+    ) -> Result<usize, AppError>;
 }
 
 #[async_trait]
 #[cfg_attr(test, automock)]
 pub trait GathererCaller: Send + Sync {
+    /// Looks up the card page and returns the image ids of its faces, `None` when no page matches.
     async fn get_gatherer_id(
         &self,
         set_code: SetCode,
         collector_number: String,
         language_code: LanguageCode,
         name: String,
-    ) -> Result<Option<String>, AppError>;
+    ) -> Result<Option<GathererIds>, AppError>;
 }
@@ -1,16 +1,34 @@
 use crate::application::error::AppError;
-use crate::domain::card::{Card, CardId};
+use crate::domain::binder::{Binder, BinderId, BinderSummary, BinderUpdate};
+use crate::domain::card::{Card, CardId, CardSelector, EdhRecCardInfo, GathererIds};
+use crate::domain::card_metadata::CardMetadata;
 use crate::domain::card_offer::{CardOfferSortField, PaginatedCardOffers};
 use crate::domain::collection::{CollectionQuery, PaginatedCollection, SearchQuery};
+use crate::domain::collection_item::{CollectionItem, CollectionItemUpdate};
 use crate::domain::collection_stats::CollectionStats;
-use crate::domain::price::{FullPriceGuide, PriceHistoryEntry};
+use crate::domain::commander_synergy::OwnedPrinting;
+use crate::domain::enrichment_job::{
+    EnrichmentJob, EnrichmentKind, EnrichmentOutcome, UnresolvedCard,
+};
+use crate::domain::http_cache::{CachedHttpResponse, HttpCacheSource, HttpCacheStats};
+use crate::domain::oracle_card::PaginatedOracleCards;
+use crate::domain::price::{
+    FullPriceGuide, PriceHistoryEntry, PriceHistoryOptions, PriceImportScope,
+};
+use crate::domain::price_anomaly::{PriceAnomaly, PriceAnomalyReview, ReferencePrice};
+use crate::domain::scryfall_card::ScryfallCard;
 use crate::domain::set_name::{SetCode, SetName};
+use crate::domain::set_price_index::SetPriceIndexEntry;
 use crate::domain::trade::{Trade, TradeCard, TradeId, TradeStatus};
+use crate::domain::trade_match::TradeMatch;
 use crate::domain::user::{User, UserId, UserSuggestion};
+use crate::domain::wishlist::{WishlistEntry, WishlistEntryId, WishlistEntryUpdate, WishlistItem};
+use crate::domain::worker_status::EnrichmentQueueDepth;
 use async_trait::async_trait;
-use chrono::NaiveDate;
+use chrono::{DateTime, NaiveDate, Utc};
 #[cfg(test)]
 use mockall::automock;
+use std::collections::HashMap;
 
 #[derive(Debug, PartialEq, Eq)]
 pub enum PersistenceError {
@@ -37,7 +55,12 @@ pub trait CardRepository: Send + Sync {
         &self,
         scryfall_id: uuid::Uuid,
     ) -> Result<Option<(Option<u32>, bool)>, AppError>;
-    async fn save(&self, user: User, card: Card) -> Result<(), AppError>;
+    /// Name of the card matching `scryfall_id`, if any.
+    async fn find_name_by_scryfall_id(
+        &self,
+        scryfall_id: uuid::Uuid,
+    ) -> Result<Option<String>, AppError>;
+    async fn save(&self, user: User, binder_id: BinderId, card: Card) -> Result<(), AppError>;
     async fn update_cardmarket_id(
         &self,
         id: CardId,
@@ -46,15 +69,49 @@ pub trait CardRepository: Send + Sync {
     async fn update_gatherer_id(
         &self,
         id: CardId,
-        gatherer_id: Option<String>,
+        gatherer_ids: Option<GathererIds>,
+    ) -> Result<(), AppError>;
+    async fn update_metadata(&self, id: CardId, metadata: CardMetadata) -> Result<(), AppError>;
+    /// Removes the entries of one of the user's binders whose printing is not in `kept`. The
+    /// other entries keep their condition, notes, tags and location.
+    async fn delete_missing(
+        &self,
+        user: User,
+        binder_id: BinderId,
+        kept: Vec<CardId>,
     ) -> Result<(), AppError>;
-    async fn delete_all(&self, user: User) -> Result<(), AppError>;
+    /// Makes sure the printing exists, creating it from another finish of the same printing or
+    /// else from the Scryfall catalogue. `false` when neither knows the printing.
+    async fn ensure_printing(&self, id: CardId) -> Result<bool, AppError>;
+    /// Adds the entry to one of the user's binders, `false` if the printing is already in it.
+    async fn add_entry(
+        &self,
+        user_id: &UserId,
+        binder_id: BinderId,
+        item: CollectionItem,
+    ) -> Result<bool, AppError>;
+    /// Applies the changes to the user's entry, `None` if the printing is not in the binder.
+    async fn update_entry(
+        &self,
+        user_id: &UserId,
+        binder_id: BinderId,
+        id: CardId,
+        update: CollectionItemUpdate,
+    ) -> Result<Option<CollectionItem>, AppError>;
+    /// Removes the user's entry, `false` if the printing is not in the binder.
+    async fn delete_entry(
+        &self,
+        user_id: &UserId,
+        binder_id: BinderId,
+        id: CardId,
+    ) -> Result<bool, AppError>;
 }
 
 #[async_trait]
 #[cfg_attr(test, automock)]
 pub trait SetNameRepository: Send + Sync {
     async fn exists_by_code(&self, code: SetCode) -> Result<bool, AppError>;
+    async fn find_by_code(&self, code: SetCode) -> Result<Option<SetName>, AppError>;
     async fn save(&self, set: SetName) -> Result<(), AppError>;
 }
 
@@ -63,65 +120,235 @@ pub trait SetNameRepository: Send + Sync {
 pub trait CollectionPriceHistoryRepository: Send + Sync {
     async fn get_date_and_user_to_update(&self) -> Result<Vec<(NaiveDate, User)>, AppError>;
     async fn update_for_date_and_user(&self, date: NaiveDate, user: User) -> Result<(), AppError>;
+    /// Collection value between both dates, aggregated by `options.granularity`.
     async fn get_price_history(
         &self,
         user_id: &UserId,
         start_date: NaiveDate,
         end_date: NaiveDate,
+        options: PriceHistoryOptions,
     ) -> Result<Vec<PriceHistoryEntry>, AppError>;
 }
 
+#[async_trait]
+#[cfg_attr(test, automock)]
+pub trait SetPriceIndexRepository: Send + Sync {
+    /// Daily value of the set between both dates, quarantined prices left out. The owned value
+    /// is computed from `user_id`'s collection.
+    async fn find_by_set_and_date_range(
+        &self,
+        set_code: SetCode,
+        user_id: &UserId,
+        start_date: NaiveDate,
+        end_date: NaiveDate,
+    ) -> Result<Vec<SetPriceIndexEntry>, AppError>;
+}
+
 #[async_trait]
 #[cfg_attr(test, automock)]
 pub trait CardMarketPriceRepository: Send + Sync {
-    async fn save(
+    /// Takes the lock of the staging area until `unlock_staging`, across every running instance.
+    /// Returns `false` if another import holds it.
+    async fn lock_staging(&self) -> Result<bool, AppError>;
+
+    /// Releases the lock taken by `lock_staging`.
+    async fn unlock_staging(&self) -> Result<(), AppError>;
+
+    /// Adds price guides to the import in progress. They stay out of `cardmarket_price` until
+    /// `publish_staged` is called.
+    async fn stage(&self, price_guides: Vec<FullPriceGuide>) -> Result<(), AppError>;
+
+    /// Saves the staged price guides within `scope` at `date` in one transaction and empties the
+    /// staging area. Every staged price guide is kept aside as the latest guide. The pending
+    /// anomalies of `date` are replaced with `anomalies` (reviewed ones are kept, unless rejected
+    /// and no longer detected) and the matching finishes quarantined in the same transaction.
+    async fn publish_staged(
         &self,
         date: NaiveDate,
-        price_guides: Vec<FullPriceGuide>,
+        scope: PriceImportScope,
+        anomalies: Vec<PriceAnomaly>,
     ) -> Result<(), AppError>;
 
+    /// Drops the staged price guides of an interrupted import.
+    async fn discard_staged(&self) -> Result<(), AppError>;
+
+    /// Saves the price of `id_product` from the latest guide, for a product left out of the
+    /// import because no card referenced it yet. Existing prices are left untouched.
+    async fn backfill_from_latest_guide(&self, id_product: u32) -> Result<(), AppError>;
+
+    /// Products among `id_products` referenced by a card `cardmarket_id`.
+    async fn find_referenced_products(&self, id_products: Vec<u32>) -> Result<Vec<u32>, AppError>;
+
     async fn find_by_id_and_date(
         &self,
         id_product: u32,
         date: NaiveDate,
     ) -> Result<Option<FullPriceGuide>, AppError>;
 
+    /// Non quarantined prices between both dates, aggregated by `options.granularity`.
     async fn find_by_id_and_date_range(
         &self,
         id_product: u32,
         foil: bool,
         start_date: NaiveDate,
         end_date: NaiveDate,
+        options: PriceHistoryOptions,
     ) -> Result<Vec<PriceHistoryEntry>, AppError>;
+
+    /// Median normal and foil trends of the given products over the `window_days` days before
+    /// `date`. Quarantined prices are left out.
+    async fn find_reference_trends(
+        &self,
+        date: NaiveDate,
+        window_days: u32,
+        id_products: Vec<u32>,
+    ) -> Result<Vec<ReferencePrice>, AppError>;
+}
+
+#[async_trait]
+#[cfg_attr(test, automock)]
+pub trait PriceAnomalyRepository: Send + Sync {
+    /// Anomalies waiting for review, most recent first.
+    async fn find_pending(&self) -> Result<Vec<PriceAnomaly>, AppError>;
+    /// Records the `review` of the pending anomaly of a product finish for `date`, releasing the
+    /// quarantine of that finish on confirmation. Returns `false` if nothing was pending.
+    async fn review(
+        &self,
+        id_product: u32,
+        date: NaiveDate,
+        foil: bool,
+        review: PriceAnomalyReview,
+    ) -> Result<bool, AppError>;
+}
+
+#[async_trait]
+#[cfg_attr(test, automock)]
+pub trait EnrichmentJobRepository: Send + Sync {
+    /// Queues a `kind` job for each card not queued yet. Returns the number of jobs created.
+    async fn enqueue(&self, kind: EnrichmentKind, card_ids: Vec<CardId>)
+    -> Result<usize, AppError>;
+    /// Locks up to `limit` due jobs of `kind` for `lease`, skipping the ones claimed by another
+    /// worker. Jobs whose lease expired (crashed worker) are claimed again.
+    async fn claim(
+        &self,
+        kind: EnrichmentKind,
+        limit: u32,
+        lease: std::time::Duration,
+    ) -> Result<Vec<EnrichmentJob>, AppError>;
+    /// Removes a job that ran successfully.
+    async fn complete(&self, id: i64) -> Result<(), AppError>;
+    /// Records the failure of a job: it runs again at `retry_at`, or is dead-lettered if `None`.
+    async fn fail(
+        &self,
+        id: i64,
+        error: String,
+        retry_at: Option<DateTime<Utc>>,
+    ) -> Result<(), AppError>;
+    /// Records the outcome of an attempt on the card, which is not queued again before
+    /// `next_attempt_at` (never if `None`) while its id is missing.
+    async fn record_attempt(
+        &self,
+        kind: EnrichmentKind,
+        card_id: CardId,
+        outcome: EnrichmentOutcome,
+        next_attempt_at: Option<DateTime<Utc>>,
+    ) -> Result<(), AppError>;
+    /// Cards whose last attempt did not find their id, most recent attempt first.
+    async fn find_unresolved(&self) -> Result<Vec<UnresolvedCard>, AppError>;
+    /// Jobs of `kind` in the queue, by status.
+    async fn count_by_status(&self, kind: EnrichmentKind)
+    -> Result<EnrichmentQueueDepth, AppError>;
+}
+
+#[async_trait]
+#[cfg_attr(test, automock)]
+pub trait EdhRecCardInfoRepository: Send + Sync {
+    async fn find_by_name(&self, name: &str) -> Result<Option<EdhRecCardInfo>, AppError>;
+    /// Inserts or replaces the statistics of the card.
+    async fn save(&self, info: &EdhRecCardInfo) -> Result<(), AppError>;
+}
+
+#[async_trait]
+#[cfg_attr(test, automock)]
+pub trait HttpResponseCacheRepository: Send + Sync {
+    async fn find_by_url(&self, url: &str) -> Result<Option<CachedHttpResponse>, AppError>;
+    /// Inserts or replaces the response cached for its URL.
+    async fn save(&self, response: &CachedHttpResponse) -> Result<(), AppError>;
+    /// Records that the server confirmed the cached body is still current.
+    async fn mark_revalidated(&self, url: &str, fetched_at: DateTime<Utc>) -> Result<(), AppError>;
+    /// Content of the cache, for the sources having at least one entry.
+    async fn get_stats(&self) -> Result<Vec<HttpCacheStats>, AppError>;
+    /// Deletes the responses of `source`, or all of them, and returns how many were deleted.
+    async fn purge(&self, source: Option<HttpCacheSource>) -> Result<u64, AppError>;
+}
+
+#[async_trait]
+#[cfg_attr(test, automock)]
+pub trait ScryfallCatalogRepository: Send + Sync {
+    /// Inserts or updates these printings of the local Scryfall catalogue.
+    async fn save_all(&self, cards: Vec<ScryfallCard>) -> Result<(), AppError>;
+    /// Gives the cards without `cardmarket_id` the one of their printing in the catalogue and
+    /// drops their pending `CARDMARKET_ID` jobs. Returns the resolved cards and their id.
+    async fn resolve_cardmarket_ids(&self) -> Result<Vec<(CardId, u32)>, AppError>;
+    /// Copies the metadata of their printing in the catalogue onto the cards where it differs.
+    /// Returns the number of updated cards.
+    async fn sync_metadata(&self) -> Result<usize, AppError>;
 }
 
 #[async_trait]
 #[cfg_attr(test, automock)]
 pub trait CardPricesViewRepository: Send + Sync {
-    async fn refresh(&self) -> Result<(), AppError>;
-    /// The authenticated user's private collection. Always filtered by `user_id`.
+    /// Recomputes the rows of `user_id`, after their collection was imported.
+    async fn refresh_user(&self, user_id: &UserId) -> Result<(), AppError>;
+    /// Recomputes the prices of the cards of these Cardmarket products, after a price import.
+    async fn refresh_products(&self, id_products: Vec<u32>) -> Result<(), AppError>;
+    /// Recomputes the rows of `card_id` for every owner, after its ids were resolved.
+    async fn refresh_card(&self, card_id: &CardId) -> Result<(), AppError>;
+    /// The authenticated user's private collection. Always filtered by `user_id`, and by
+    /// `query.binder_id` when set; a printing kept in several binders comes once per binder.
     async fn get_paginated(
         &self,
         user_id: &UserId,
         query: CollectionQuery,
     ) -> Result<PaginatedCollection, AppError>;
-    /// Public search across every user's cards. No `user_id` filter — rows are
-    /// grouped by card, each returned as `CollectionEntry::Public { owner_count }`
-    /// where `owner_count` is the number of distinct users owning that card. When
+    /// Public search across the cards other users offer for trade. No `user_id`
+    /// filter — rows are grouped by card, each returned as `CollectionEntry::Public { owner_count }`
+    /// where `owner_count` is the number of distinct users offering that card. When
     /// `query.player_username` is set, results are restricted to that player's cards
     /// (exact match, case-insensitive) and `owner_count` is always `1`.
     async fn search_paginated(&self, query: SearchQuery) -> Result<PaginatedCollection, AppError>;
-    /// Whether any user owns a card matching `card_id`, regardless of who.
-    async fn exists(&self, card_id: &CardId) -> Result<bool, AppError>;
-    /// Other users' offers for `card_id` (the caller's own entry, if any, is excluded).
+    /// Same search as [`Self::search_paginated`], the printings being grouped by `oracle_id`.
+    /// Cards not enriched yet have no `oracle_id` and are left out.
+    async fn search_oracle_paginated(
+        &self,
+        query: SearchQuery,
+    ) -> Result<PaginatedOracleCards, AppError>;
+    /// Whether any user offers a card matching `selector` for trade, regardless of who.
+    async fn exists(&self, selector: &CardSelector) -> Result<bool, AppError>;
+    /// Other users' offers for `selector`, one per owner and printing summing the copies they
+    /// offer for trade (the caller's own entries, if any, are excluded).
     async fn get_offers(
         &self,
         user_id: &UserId,
-        card_id: &CardId,
+        selector: &CardSelector,
         sort_by: CardOfferSortField,
         page: u32,
         page_size: u32,
     ) -> Result<PaginatedCardOffers, AppError>;
+    /// The user's printings of the cards named `names` (lowercase, compared case-insensitively),
+    /// the copies of every binder summed up.
+    async fn get_owned_printings_by_names(
+        &self,
+        user_id: &UserId,
+        names: Vec<String>,
+    ) -> Result<Vec<OwnedPrinting>, AppError>;
+    /// Number of other users offering a printing of each of the cards named `names` (lowercase)
+    /// for trade, keyed by lowercase name. Cards nobody else offers are absent.
+    async fn count_other_owners_by_names(
+        &self,
+        user_id: &UserId,
+        names: Vec<String>,
+    ) -> Result<HashMap<String, u32>, AppError>;
 }
 
 #[async_trait]
@@ -145,7 +372,14 @@ pub trait CollectionRepository: Send + Sync {
 #[async_trait]
 #[cfg_attr(test, automock)]
 pub trait CollectionStatsRepository: Send + Sync {
-    async fn get_collection_stats(&self, user_id: &UserId) -> Result<CollectionStats, AppError>;
+    /// Stats of the user's collection, or of one of their binders, restricted to the entries
+    /// carrying every tag of `tags`. The listed tags are those of the binder, whatever `tags`.
+    async fn get_collection_stats(
+        &self,
+        user_id: &UserId,
+        binder_id: Option<BinderId>,
+        tags: Vec<String>,
+    ) -> Result<CollectionStats, AppError>;
 }
 
 #[async_trait]
@@ -162,6 +396,7 @@ pub trait UserRepository: Send + Sync {
 #[async_trait]
 #[cfg_attr(test, automock)]
 pub trait TradeRepository: Send + Sync {
+    /// Copies of `card_id` the user offers for trade, `None` when they offer none.
     async fn find_collection_entry_quantity(
         &self,
         user_id: &UserId,
@@ -236,3 +471,88 @@ pub trait TradeRepository: Send + Sync {
         rating: u8,
     ) -> Result<Option<TradeStatus>, AppError>;
 }
+
+#[async_trait]
+#[cfg_attr(test, automock)]
+pub trait BinderRepository: Send + Sync {
+    /// The user's binders with the number of copies they hold, default binder first, then by
+    /// name.
+    async fn find_all(&self, user_id: &UserId) -> Result<Vec<BinderSummary>, AppError>;
+    /// The binder, if it belongs to the user.
+    async fn find_by_id(&self, user_id: &UserId, id: BinderId) -> Result<Option<Binder>, AppError>;
+    /// The user's default binder, created as [`DEFAULT_BINDER_NAME`] on first use.
+    ///
+    /// [`DEFAULT_BINDER_NAME`]: crate::domain::binder::DEFAULT_BINDER_NAME
+    async fn find_or_create_default(&self, user_id: &UserId) -> Result<Binder, AppError>;
+    /// Whether the user has a binder named `name`, other than `except`.
+    async fn name_exists(
+        &self,
+        user_id: &UserId,
+        name: &str,
+        except: Option<BinderId>,
+    ) -> Result<bool, AppError>;
+    async fn create(&self, binder: Binder) -> Result<(), AppError>;
+    /// Applies the changes, `None` if the binder does not belong to the user.
+    async fn update(
+        &self,
+        user_id: &UserId,
+        id: BinderId,
+        update: BinderUpdate,
+    ) -> Result<Option<Binder>, AppError>;
+    /// Deletes the binder and its entries, `false` if it does not belong to the user or is
+    /// their default binder.
+    async fn delete(&self, user_id: &UserId, id: BinderId) -> Result<bool, AppError>;
+}
+
+#[async_trait]
+#[cfg_attr(test, automock)]
+pub trait WishlistRepository: Send + Sync {
+    /// The user's wishes with the offers of other users matching them, highest priority first,
+    /// then by name.
+    async fn find_all(&self, user_id: &UserId) -> Result<Vec<WishlistItem>, AppError>;
+    /// The wish with its matching offers, if it belongs to the user.
+    async fn find_by_id(
+        &self,
+        user_id: &UserId,
+        id: WishlistEntryId,
+    ) -> Result<Option<WishlistItem>, AppError>;
+    /// Name of the card, from the known printings or the Scryfall catalogue, `None` for an
+    /// unknown card.
+    async fn find_card_name(&self, card: &CardSelector) -> Result<Option<String>, AppError>;
+    /// The `oracle_id` and name of the cards named `names` (lowercase), full name or front
+    /// face, keyed by the name looked up.
+    async fn find_oracle_ids_by_names(
+        &self,
+        names: Vec<String>,
+    ) -> Result<HashMap<String, (uuid::Uuid, String)>, AppError>;
+    /// Saves the wish, `false` if the user already wishes for the same card.
+    async fn create(&self, entry: WishlistEntry) -> Result<bool, AppError>;
+    /// Saves the wishes, raising the quantity of the ones the user already has when lower and
+    /// leaving their other settings as they are. Returns the number of wishes added or raised.
+    async fn merge_all(&self, entries: Vec<WishlistEntry>) -> Result<u32, AppError>;
+    /// Applies the changes, `false` if the wish does not belong to the user.
+    async fn update(
+        &self,
+        user_id: &UserId,
+        id: WishlistEntryId,
+        update: WishlistEntryUpdate,
+    ) -> Result<bool, AppError>;
+    /// Deletes the wish, `false` if it does not belong to the user.
+    async fn delete(&self, user_id: &UserId, id: WishlistEntryId) -> Result<bool, AppError>;
+}
+
+#[async_trait]
+#[cfg_attr(test, automock)]
+pub trait TradeMatchRepository: Send + Sync {
+    /// Recomputes the copies matching a wish between `user_id` and every other user, in both
+    /// directions, from the tradable `card_prices` rows and the wishlists.
+    async fn refresh_user(&self, user_id: &UserId) -> Result<(), AppError>;
+    /// Other users with copies to trade both ways with `user_id`, valued at the current trends,
+    /// in no particular order.
+    async fn find_mutual(&self, user_id: &UserId) -> Result<Vec<TradeMatch>, AppError>;
+    /// Users with a wish capped by a `max_price` that a card of `id_products` can serve.
+    async fn find_price_capped_wishers(
+        &self,
+        id_products: Vec<u32>,
+    ) -> Result<Vec<UserId>, AppError>;
+}
@@ -1,19 +1,32 @@
 pub mod auth_service;
 pub mod autocomplete_user_service;
+pub mod binder_service;
 pub mod card_collection_service;
+pub mod card_edhrec_service;
 pub mod card_offer_service;
 pub mod card_price_history_service;
+pub mod card_prices_refresh_service;
 pub mod cardmarket_id_enqueue_service;
+pub mod collection_entry_service;
 pub mod collection_price_history_service;
 pub mod collection_service;
 pub mod collection_stats_service;
+pub mod commander_synergy_service;
 pub mod gatherer_id_enqueue_service;
+pub mod http_cache_service;
 pub mod import_card_service;
 pub mod import_price_service;
 mod parse_service;
+pub mod price_anomaly_service;
 pub mod register_user_service;
+pub mod scryfall_catalog_service;
 pub mod search_service;
+pub mod set_price_index_service;
 pub mod stats_service;
+pub mod trade_match_service;
 pub mod trade_service;
+pub mod unresolved_card_service;
 pub mod update_card_market_service;
 pub mod update_gatherer_service;
+pub mod wishlist_service;
+pub mod worker_monitor_service;
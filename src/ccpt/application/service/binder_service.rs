@@ -0,0 +1,443 @@
+use crate::application::error::AppError;
+use crate::application::repository::BinderRepository;
+use crate::application::use_case::{ManageBindersUseCase, ScheduleCardPricesRefreshUseCase};
+use crate::domain::binder::{Binder, BinderId, BinderSummary, BinderUpdate};
+use crate::domain::card_prices_refresh::CardPricesRefresh;
+use crate::domain::error::FunctionalError;
+use crate::domain::user::UserId;
+use async_trait::async_trait;
+use chrono::Utc;
+use std::sync::Arc;
+
+const MAX_BINDER_NAME_LENGTH: usize = 100;
+
+/// The binder `binder_id` of the user, or their default binder (created on first use) when
+/// `None`.
+pub(crate) async fn resolve_binder(
+    binder_repository: &dyn BinderRepository,
+    user_id: &UserId,
+    binder_id: Option<BinderId>,
+) -> Result<Binder, AppError> {
+    match binder_id {
+        Some(id) => binder_repository
+            .find_by_id(user_id, id)
+            .await?
+            .ok_or_else(|| FunctionalError::BinderNotFound.into()),
+        None => binder_repository.find_or_create_default(user_id).await,
+    }
+}
+
+fn validate_name(name: &str) -> Result<String, AppError> {
+    let name = name.trim();
+    if name.is_empty() {
+        return Err(FunctionalError::WrongFormat("Binder name is empty".to_string()).into());
+    }
+    if name.chars().count() > MAX_BINDER_NAME_LENGTH {
+        return Err(FunctionalError::WrongFormat(format!(
+            "Binder name is longer than {MAX_BINDER_NAME_LENGTH} characters"
+        ))
+        .into());
+    }
+    Ok(name.to_string())
+}
+
+pub struct BinderService {
+    binder_repository: Arc<dyn BinderRepository>,
+    card_prices_refresh: Arc<dyn ScheduleCardPricesRefreshUseCase>,
+}
+
+impl BinderService {
+    pub fn new(
+        binder_repository: Arc<dyn BinderRepository>,
+        card_prices_refresh: Arc<dyn ScheduleCardPricesRefreshUseCase>,
+    ) -> Self {
+        Self {
+            binder_repository,
+            card_prices_refresh,
+        }
+    }
+
+    async fn ensure_name_free(
+        &self,
+        user_id: &UserId,
+        name: &str,
+        except: Option<BinderId>,
+    ) -> Result<(), AppError> {
+        if self
+            .binder_repository
+            .name_exists(user_id, name, except)
+            .await?
+        {
+            return Err(FunctionalError::BinderNameAlreadyUsed.into());
+        }
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl ManageBindersUseCase for BinderService {
+    async fn list_binders(&self, user_id: &UserId) -> Result<Vec<BinderSummary>, AppError> {
+        self.binder_repository.find_all(user_id).await
+    }
+
+    async fn create_binder(
+        &self,
+        user_id: &UserId,
+        name: String,
+        tradable: bool,
+    ) -> Result<Binder, AppError> {
+        let name = validate_name(&name)?;
+        self.ensure_name_free(user_id, &name, None).await?;
+
+        let binder = Binder {
+            id: BinderId::new(),
+            user_id: user_id.clone(),
+            name,
+            tradable,
+            keep_quantity: 0,
+            is_default: false,
+            created_at: Utc::now(),
+        };
+        self.binder_repository.create(binder.clone()).await?;
+        Ok(binder)
+    }
+
+    async fn update_binder(
+        &self,
+        user_id: &UserId,
+        binder_id: BinderId,
+        update: BinderUpdate,
+    ) -> Result<Binder, AppError> {
+        let name = update.name.as_deref().map(validate_name).transpose()?;
+        if let Some(name) = &name {
+            self.ensure_name_free(user_id, name, Some(binder_id))
+                .await?;
+        }
+
+        let binder = self
+            .binder_repository
+            .update(user_id, binder_id, BinderUpdate { name, ..update })
+            .await?
+            .ok_or(FunctionalError::BinderNotFound)?;
+
+        // Les exemplaires échangeables sont recopiés dans card_prices
+        if update.tradable.is_some() || update.keep_quantity.is_some() {
+            self.card_prices_refresh
+                .request(CardPricesRefresh::User(user_id.clone()));
+        }
+        Ok(binder)
+    }
+
+    async fn delete_binder(&self, user_id: &UserId, binder_id: BinderId) -> Result<(), AppError> {
+        let binder = self
+            .binder_repository
+            .find_by_id(user_id, binder_id)
+            .await?
+            .ok_or(FunctionalError::BinderNotFound)?;
+        // Ses entrées partiraient en cascade et le prochain import recréerait un classeur vide
+        if binder.is_default {
+            return Err(FunctionalError::DefaultBinderNotDeletable.into());
+        }
+        if !self.binder_repository.delete(user_id, binder_id).await? {
+            return Err(FunctionalError::BinderNotFound.into());
+        }
+
+        self.card_prices_refresh
+            .request(CardPricesRefresh::User(user_id.clone()));
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::application::repository::MockBinderRepository;
+    use crate::application::use_case::MockScheduleCardPricesRefreshUseCase;
+    use mockall::predicate::eq;
+
+    fn binder(name: &str) -> Binder {
+        Binder {
+            id: BinderId(uuid::Uuid::nil()),
+            user_id: UserId::new("user1"),
+            name: name.to_string(),
+            tradable: false,
+            keep_quantity: 0,
+            is_default: false,
+            created_at: Utc::now(),
+        }
+    }
+
+    /// Service expecting `times` refreshes of the card prices of `user1`.
+    fn service(binder_repository: MockBinderRepository, times: usize) -> BinderService {
+        let mut card_prices_refresh = MockScheduleCardPricesRefreshUseCase::new();
+        card_prices_refresh
+            .expect_request()
+            .with(eq(CardPricesRefresh::User(UserId::new("user1"))))
+            .times(times)
+            .return_const(());
+
+        BinderService::new(Arc::new(binder_repository), Arc::new(card_prices_refresh))
+    }
+
+    #[tokio::test]
+    async fn resolve_binder_falls_back_to_the_default_binder() {
+        let mut binder_repository = MockBinderRepository::new();
+        binder_repository
+            .expect_find_or_create_default()
+            .times(1)
+            .returning(|_| Box::pin(async { Ok(binder("Collection")) }));
+        binder_repository.expect_find_by_id().never();
+
+        let result = resolve_binder(&binder_repository, &UserId::new("user1"), None)
+            .await
+            .unwrap();
+
+        assert_eq!(result.name, "Collection");
+    }
+
+    #[tokio::test]
+    async fn resolve_binder_returns_not_found_for_an_unknown_binder() {
+        let mut binder_repository = MockBinderRepository::new();
+        binder_repository
+            .expect_find_by_id()
+            .returning(|_, _| Box::pin(async { Ok(None) }));
+
+        let result = resolve_binder(
+            &binder_repository,
+            &UserId::new("user1"),
+            Some(BinderId::new()),
+        )
+        .await;
+
+        assert!(matches!(
+            result,
+            Err(AppError::Functional(FunctionalError::BinderNotFound))
+        ));
+    }
+
+    #[tokio::test]
+    async fn create_binder_saves_a_trimmed_name() {
+        let mut binder_repository = MockBinderRepository::new();
+        binder_repository
+            .expect_name_exists()
+            .withf(|_, name, except| name == "Trades" && except.is_none())
+            .returning(|_, _, _| Box::pin(async { Ok(false) }));
+        binder_repository
+            .expect_create()
+            .withf(|binder| binder.name == "Trades" && binder.tradable && !binder.is_default)
+            .times(1)
+            .returning(|_| Box::pin(async { Ok(()) }));
+
+        let result = service(binder_repository, 0)
+            .create_binder(&UserId::new("user1"), "  Trades ".to_string(), true)
+            .await
+            .unwrap();
+
+        assert_eq!(result.name, "Trades");
+    }
+
+    #[tokio::test]
+    async fn create_binder_rejects_an_empty_name() {
+        let mut binder_repository = MockBinderRepository::new();
+        binder_repository.expect_create().never();
+
+        let result = service(binder_repository, 0)
+            .create_binder(&UserId::new("user1"), "   ".to_string(), false)
+            .await;
+
+        assert!(matches!(
+            result,
+            Err(AppError::Functional(FunctionalError::WrongFormat(_)))
+        ));
+    }
+
+    #[tokio::test]
+    async fn create_binder_rejects_a_name_already_used() {
+        let mut binder_repository = MockBinderRepository::new();
+        binder_repository
+            .expect_name_exists()
+            .returning(|_, _, _| Box::pin(async { Ok(true) }));
+        binder_repository.expect_create().never();
+
+        let result = service(binder_repository, 0)
+            .create_binder(&UserId::new("user1"), "Trades".to_string(), false)
+            .await;
+
+        assert!(matches!(
+            result,
+            Err(AppError::Functional(FunctionalError::BinderNameAlreadyUsed))
+        ));
+    }
+
+    #[tokio::test]
+    async fn update_binder_refreshes_the_prices_when_tradable_changes() {
+        let mut binder_repository = MockBinderRepository::new();
+        binder_repository.expect_name_exists().never();
+        binder_repository
+            .expect_update()
+            .withf(|_, _, update| update.name.is_none() && update.tradable == Some(true))
+            .returning(|_, _, _| {
+                Box::pin(async {
+                    Ok(Some(Binder {
+                        tradable: true,
+                        ..binder("Trades")
+                    }))
+                })
+            });
+
+        let result = service(binder_repository, 1)
+            .update_binder(
+                &UserId::new("user1"),
+                BinderId::new(),
+                BinderUpdate {
+                    tradable: Some(true),
+                    ..BinderUpdate::default()
+                },
+            )
+            .await
+            .unwrap();
+
+        assert!(result.tradable);
+    }
+
+    #[tokio::test]
+    async fn update_binder_refreshes_the_prices_when_the_keep_rule_changes() {
+        let mut binder_repository = MockBinderRepository::new();
+        binder_repository
+            .expect_update()
+            .withf(|_, _, update| update.keep_quantity == Some(4) && update.tradable.is_none())
+            .returning(|_, _, _| {
+                Box::pin(async {
+                    Ok(Some(Binder {
+                        keep_quantity: 4,
+                        ..binder("Trades")
+                    }))
+                })
+            });
+
+        let result = service(binder_repository, 1)
+            .update_binder(
+                &UserId::new("user1"),
+                BinderId::new(),
+                BinderUpdate {
+                    keep_quantity: Some(4),
+                    ..BinderUpdate::default()
+                },
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(result.keep_quantity, 4);
+    }
+
+    #[tokio::test]
+    async fn update_binder_checks_the_new_name_against_the_other_binders() {
+        let id = BinderId::new();
+        let mut binder_repository = MockBinderRepository::new();
+        binder_repository
+            .expect_name_exists()
+            .withf(move |_, name, except| name == "Deck box" && *except == Some(id))
+            .returning(|_, _, _| Box::pin(async { Ok(false) }));
+        binder_repository
+            .expect_update()
+            .returning(|_, _, _| Box::pin(async { Ok(Some(binder("Deck box"))) }));
+
+        let result = service(binder_repository, 0)
+            .update_binder(
+                &UserId::new("user1"),
+                id,
+                BinderUpdate {
+                    name: Some(" Deck box".to_string()),
+                    ..BinderUpdate::default()
+                },
+            )
+            .await;
+
+        assert!(result.is_ok());
+    }
+
+    #[tokio::test]
+    async fn update_binder_returns_not_found_for_an_unknown_binder() {
+        let mut binder_repository = MockBinderRepository::new();
+        binder_repository
+            .expect_update()
+            .returning(|_, _, _| Box::pin(async { Ok(None) }));
+
+        let result = service(binder_repository, 0)
+            .update_binder(
+                &UserId::new("user1"),
+                BinderId::new(),
+                BinderUpdate {
+                    tradable: Some(false),
+                    ..BinderUpdate::default()
+                },
+            )
+            .await;
+
+        assert!(matches!(
+            result,
+            Err(AppError::Functional(FunctionalError::BinderNotFound))
+        ));
+    }
+
+    #[tokio::test]
+    async fn delete_binder_refreshes_the_prices() {
+        let mut binder_repository = MockBinderRepository::new();
+        binder_repository
+            .expect_find_by_id()
+            .returning(|_, _| Box::pin(async { Ok(Some(binder("Trades"))) }));
+        binder_repository
+            .expect_delete()
+            .times(1)
+            .returning(|_, _| Box::pin(async { Ok(true) }));
+
+        let result = service(binder_repository, 1)
+            .delete_binder(&UserId::new("user1"), BinderId::new())
+            .await;
+
+        assert!(result.is_ok());
+    }
+
+    #[tokio::test]
+    async fn delete_binder_returns_not_found_for_an_unknown_binder() {
+        let mut binder_repository = MockBinderRepository::new();
+        binder_repository
+            .expect_find_by_id()
+            .returning(|_, _| Box::pin(async { Ok(None) }));
+        binder_repository.expect_delete().never();
+
+        let result = service(binder_repository, 0)
+            .delete_binder(&UserId::new("user1"), BinderId::new())
+            .await;
+
+        assert!(matches!(
+            result,
+            Err(AppError::Functional(FunctionalError::BinderNotFound))
+        ));
+    }
+
+    #[tokio::test]
+    async fn delete_binder_refuses_the_default_binder() {
+        let mut binder_repository = MockBinderRepository::new();
+        binder_repository.expect_find_by_id().returning(|_, _| {
+            Box::pin(async {
+                Ok(Some(Binder {
+                    is_default: true,
+                    ..binder("Collection")
+                }))
+            })
+        });
+        binder_repository.expect_delete().never();
+
+        let result = service(binder_repository, 0)
+            .delete_binder(&UserId::new("user1"), BinderId::new())
+            .await;
+
+        assert!(matches!(
+            result,
+            Err(AppError::Functional(
+                FunctionalError::DefaultBinderNotDeletable
+            ))
+        ));
+    }
+}
@@ -0,0 +1,215 @@
+use crate::application::caller::EdhRecCaller;
+use crate::application::error::AppError;
+use crate::application::repository::{CardRepository, EdhRecCardInfoRepository};
+use crate::application::use_case::GetCardEdhRecUseCase;
+use crate::domain::card::EdhRecCardInfo;
+use crate::domain::error::FunctionalError;
+use async_trait::async_trait;
+use chrono::{Duration, Utc};
+use std::sync::Arc;
+
+pub struct CardEdhRecService {
+    card_repository: Arc<dyn CardRepository>,
+    edhrec_card_info_repository: Arc<dyn EdhRecCardInfoRepository>,
+    edh_rec_caller: Arc<dyn EdhRecCaller>,
+    cache_ttl: Duration,
+}
+
+impl CardEdhRecService {
+    pub fn new(
+        card_repository: Arc<dyn CardRepository>,
+        edhrec_card_info_repository: Arc<dyn EdhRecCardInfoRepository>,
+        edh_rec_caller: Arc<dyn EdhRecCaller>,
+        cache_ttl: Duration,
+    ) -> Self {
+        Self {
+            card_repository,
+            edhrec_card_info_repository,
+            edh_rec_caller,
+            cache_ttl,
+        }
+    }
+}
+
+#[async_trait]
+impl GetCardEdhRecUseCase for CardEdhRecService {
+    async fn get_card_edhrec(&self, scryfall_id: uuid::Uuid) -> Result<EdhRecCardInfo, AppError> {
+        let Some(name) = self
+            .card_repository
+            .find_name_by_scryfall_id(scryfall_id)
+            .await?
+        else {
+            return Err(FunctionalError::CardNotFound.into());
+        };
+
+        let now = Utc::now();
+        if let Some(cached) = self.edhrec_card_info_repository.find_by_name(&name).await?
+            && cached.is_fresh(self.cache_ttl, now)
+        {
+            return Ok(cached);
+        }
+
+        let info = EdhRecCardInfo {
+            info: self.edh_rec_caller.get_card_info(name.clone()).await?,
+            name,
+            fetched_at: now,
+        };
+        self.edhrec_card_info_repository.save(&info).await?;
+
+        Ok(info)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::application::caller::MockEdhRecCaller;
+    use crate::application::error::InfraError;
+    use crate::application::repository::{MockCardRepository, MockEdhRecCardInfoRepository};
+    use crate::domain::card::CardInfo;
+    use uuid::Uuid;
+
+    fn card_info() -> CardInfo {
+        CardInfo {
+            inclusion: 85_000,
+            total_decks: 100_000,
+            rank: Some(1),
+        }
+    }
+
+    fn card_repository_returning(name: Option<&'static str>) -> MockCardRepository {
+        let mut mock = MockCardRepository::new();
+        mock.expect_find_name_by_scryfall_id()
+            .returning(move |_| Box::pin(async move { Ok(name.map(str::to_string)) }));
+        mock
+    }
+
+    fn create_service(
+        card_repository: MockCardRepository,
+        edhrec_card_info_repository: MockEdhRecCardInfoRepository,
+        edh_rec_caller: MockEdhRecCaller,
+    ) -> CardEdhRecService {
+        CardEdhRecService::new(
+            Arc::new(card_repository),
+            Arc::new(edhrec_card_info_repository),
+            Arc::new(edh_rec_caller),
+            Duration::hours(24),
+        )
+    }
+
+    #[tokio::test]
+    async fn returns_card_not_found_when_scryfall_id_unknown() {
+        let service = create_service(
+            card_repository_returning(None),
+            MockEdhRecCardInfoRepository::new(),
+            MockEdhRecCaller::new(),
+        );
+
+        let result = service.get_card_edhrec(Uuid::new_v4()).await;
+
+        assert!(matches!(
+            result,
+            Err(AppError::Functional(FunctionalError::CardNotFound))
+        ));
+    }
+
+    #[tokio::test]
+    async fn returns_cached_info_without_calling_edhrec_when_fresh() {
+        let cached = EdhRecCardInfo {
+            name: "Sol Ring".to_string(),
+            info: card_info(),
+            fetched_at: Utc::now() - Duration::hours(1),
+        };
+        let expected = cached.clone();
+
+        let mut mock_info_repo = MockEdhRecCardInfoRepository::new();
+        mock_info_repo
+            .expect_find_by_name()
+            .withf(|name| name == "Sol Ring")
+            .returning(move |_| {
+                let cached = cached.clone();
+                Box::pin(async move { Ok(Some(cached)) })
+            });
+        mock_info_repo.expect_save().never();
+
+        let mut mock_caller = MockEdhRecCaller::new();
+        mock_caller.expect_get_card_info().never();
+
+        let service = create_service(
+            card_repository_returning(Some("Sol Ring")),
+            mock_info_repo,
+            mock_caller,
+        );
+
+        let result = service.get_card_edhrec(Uuid::new_v4()).await.unwrap();
+
+        assert_eq!(result, expected);
+    }
+
+    #[tokio::test]
+    async fn fetches_and_saves_info_when_cache_is_stale() {
+        let stale = EdhRecCardInfo {
+            name: "Sol Ring".to_string(),
+            info: CardInfo {
+                inclusion: 1,
+                total_decks: 2,
+                rank: None,
+            },
+            fetched_at: Utc::now() - Duration::hours(25),
+        };
+
+        let mut mock_info_repo = MockEdhRecCardInfoRepository::new();
+        mock_info_repo.expect_find_by_name().returning(move |_| {
+            let stale = stale.clone();
+            Box::pin(async move { Ok(Some(stale)) })
+        });
+        mock_info_repo
+            .expect_save()
+            .times(1)
+            .withf(|info| info.name == "Sol Ring" && info.info == card_info())
+            .returning(|_| Box::pin(async { Ok(()) }));
+
+        let mut mock_caller = MockEdhRecCaller::new();
+        mock_caller
+            .expect_get_card_info()
+            .withf(|name| name == "Sol Ring")
+            .returning(|_| Box::pin(async { Ok(card_info()) }));
+
+        let service = create_service(
+            card_repository_returning(Some("Sol Ring")),
+            mock_info_repo,
+            mock_caller,
+        );
+
+        let result = service.get_card_edhrec(Uuid::new_v4()).await.unwrap();
+
+        assert_eq!(result.info, card_info());
+    }
+
+    #[tokio::test]
+    async fn propagates_call_error_without_saving() {
+        let mut mock_info_repo = MockEdhRecCardInfoRepository::new();
+        mock_info_repo
+            .expect_find_by_name()
+            .returning(|_| Box::pin(async { Ok(None) }));
+        mock_info_repo.expect_save().never();
+
+        let mut mock_caller = MockEdhRecCaller::new();
+        mock_caller.expect_get_card_info().returning(|_| {
+            Box::pin(async { Err(InfraError::CallError("edhrec unavailable".to_string()).into()) })
+        });
+
+        let service = create_service(
+            card_repository_returning(Some("Sol Ring")),
+            mock_info_repo,
+            mock_caller,
+        );
+
+        let result = service.get_card_edhrec(Uuid::new_v4()).await;
+
+        assert!(matches!(
+            result,
+            Err(AppError::Infra(InfraError::CallError(_)))
+        ));
+    }
+}
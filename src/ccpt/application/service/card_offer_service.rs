@@ -1,7 +1,7 @@
 use crate::application::error::AppError;
 use crate::application::repository::CardPricesViewRepository;
 use crate::application::use_case::GetCardOffersUseCase;
-use crate::domain::card::CardId;
+use crate::domain::card::CardSelector;
 use crate::domain::card_offer::{CardOfferSortField, PaginatedCardOffers};
 use crate::domain::error::FunctionalError;
 use crate::domain::user::UserId;
@@ -23,17 +23,17 @@ impl GetCardOffersUseCase for CardOfferService {
     async fn get_card_offers(
         &self,
         user_id: &UserId,
-        card_id: CardId,
+        selector: CardSelector,
         sort_by: CardOfferSortField,
         page: u32,
         page_size: u32,
     ) -> Result<PaginatedCardOffers, AppError> {
-        if !self.repository.exists(&card_id).await? {
+        if !self.repository.exists(&selector).await? {
             return Err(FunctionalError::CardNotFound.into());
         }
 
         self.repository
-            .get_offers(user_id, &card_id, sort_by, page, page_size)
+            .get_offers(user_id, &selector, sort_by, page, page_size)
             .await
     }
 }
@@ -43,10 +43,12 @@ mod tests {
     use super::*;
     use crate::application::error::InfraError;
     use crate::application::repository::MockCardPricesViewRepository;
+    use crate::domain::card::CardId;
     use crate::domain::language_code::LanguageCode;
+    use uuid::Uuid;
 
-    fn card_id() -> CardId {
-        CardId::new("FDN", "1", LanguageCode::EN, false)
+    fn card_id() -> CardSelector {
+        CardSelector::Printing(CardId::new("FDN", "1", LanguageCode::EN, false))
     }
 
     #[tokio::test]
@@ -82,6 +84,46 @@ mod tests {
         assert!(result.is_ok());
     }
 
+    #[tokio::test]
+    async fn looks_up_offers_of_any_printing_with_the_given_selector() {
+        let selector = CardSelector::AnyPrinting {
+            oracle_id: Uuid::from_u128(1),
+        };
+        let mut mock_repo = MockCardPricesViewRepository::new();
+        let expected = selector.clone();
+        mock_repo
+            .expect_exists()
+            .withf(move |s| *s == expected)
+            .returning(|_| Box::pin(async { Ok(true) }));
+        let expected = selector.clone();
+        mock_repo
+            .expect_get_offers()
+            .withf(move |_, s, _, _, _| *s == expected)
+            .returning(|_, _, _, page, page_size| {
+                Box::pin(async move {
+                    Ok(PaginatedCardOffers {
+                        items: vec![],
+                        total: 0,
+                        page,
+                        page_size,
+                    })
+                })
+            });
+
+        let service = CardOfferService::new(Arc::new(mock_repo));
+        let result = service
+            .get_card_offers(
+                &UserId::new("user-1"),
+                selector,
+                CardOfferSortField::SellingPrice,
+                0,
+                20,
+            )
+            .await;
+
+        assert!(result.is_ok());
+    }
+
     #[tokio::test]
     async fn returns_card_not_found_when_card_does_not_exist() {
         let mut mock_repo = MockCardPricesViewRepository::new();
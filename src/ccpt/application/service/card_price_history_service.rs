@@ -3,7 +3,7 @@ use crate::application::error::AppError;
 use crate::application::repository::{CardMarketPriceRepository, CardRepository};
 use crate::application::use_case::GetCardPriceHistoryUseCase;
 use crate::domain::error::FunctionalError;
-use crate::domain::price::PriceHistoryEntry;
+use crate::domain::price::{PriceHistoryEntry, PriceHistoryOptions};
 use async_trait::async_trait;
 use chrono::NaiveDate;
 use std::sync::Arc;
@@ -32,6 +32,7 @@ impl GetCardPriceHistoryUseCase for CardPriceHistoryService {
         scryfall_id: uuid::Uuid,
         start_date: Option<NaiveDate>,
         end_date: Option<NaiveDate>,
+        options: PriceHistoryOptions,
     ) -> Result<Vec<PriceHistoryEntry>, AppError> {
         let (start_date, end_date) = resolve_date_range(start_date, end_date)?;
 
@@ -48,7 +49,7 @@ impl GetCardPriceHistoryUseCase for CardPriceHistoryService {
         };
 
         self.cardmarket_price_repository
-            .find_by_id_and_date_range(cardmarket_id, foil, start_date, end_date)
+            .find_by_id_and_date_range(cardmarket_id, foil, start_date, end_date, options)
             .await
     }
 }
@@ -58,7 +59,7 @@ mod tests {
     use super::*;
     use crate::application::error::InfraError;
     use crate::application::repository::{MockCardMarketPriceRepository, MockCardRepository};
-    use crate::domain::price::{Price, PriceGuide};
+    use crate::domain::price::{Price, PriceGuide, PriceHistoryGranularity};
     use chrono::NaiveDate;
     use uuid::Uuid;
 
@@ -79,13 +80,13 @@ mod tests {
         let mut mock_price_repo = MockCardMarketPriceRepository::new();
         mock_price_repo
             .expect_find_by_id_and_date_range()
-            .withf(|id_product, foil, start, end| {
+            .withf(|id_product, foil, start, end, _| {
                 *id_product == 42
                     && !*foil
                     && *start == date(2025, 1, 1)
                     && *end == date(2025, 1, 31)
             })
-            .returning(|_, _, _, _| {
+            .returning(|_, _, _, _, _| {
                 Box::pin(async {
                     Ok(vec![PriceHistoryEntry {
                         date: date(2025, 1, 15),
@@ -94,6 +95,11 @@ mod tests {
                             trend: Price { value: Some(150) },
                             avg: Price { value: Some(130) },
                         },
+                        trend_open: Price { value: Some(120) },
+                        trend_high: Price { value: Some(160) },
+                        trend_low: Price { value: Some(110) },
+                        trend_ma7: Price::empty(),
+                        trend_ma30: Price::empty(),
                     }])
                 })
             });
@@ -102,7 +108,12 @@ mod tests {
             CardPriceHistoryService::new(Arc::new(mock_card_repo), Arc::new(mock_price_repo));
 
         let result = service
-            .get_card_price_history(scryfall_id, Some(date(2025, 1, 1)), Some(date(2025, 1, 31)))
+            .get_card_price_history(
+                scryfall_id,
+                Some(date(2025, 1, 1)),
+                Some(date(2025, 1, 31)),
+                PriceHistoryOptions::default(),
+            )
             .await;
 
         assert!(result.is_ok());
@@ -128,6 +139,7 @@ mod tests {
                 Uuid::new_v4(),
                 Some(date(2025, 1, 1)),
                 Some(date(2025, 1, 31)),
+                PriceHistoryOptions::default(),
             )
             .await;
 
@@ -157,6 +169,7 @@ mod tests {
                 Uuid::new_v4(),
                 Some(date(2025, 1, 1)),
                 Some(date(2025, 1, 31)),
+                PriceHistoryOptions::default(),
             )
             .await;
 
@@ -177,6 +190,7 @@ mod tests {
                 Uuid::new_v4(),
                 Some(date(2025, 2, 1)),
                 Some(date(2025, 1, 1)),
+                PriceHistoryOptions::default(),
             )
             .await;
 
@@ -204,14 +218,14 @@ mod tests {
         let mut mock_price_repo = MockCardMarketPriceRepository::new();
         mock_price_repo
             .expect_find_by_id_and_date_range()
-            .withf(move |_, _, start, end| *start == expected_start && *end == today)
-            .returning(|_, _, _, _| Box::pin(async { Ok(vec![]) }));
+            .withf(move |_, _, start, end, _| *start == expected_start && *end == today)
+            .returning(|_, _, _, _, _| Box::pin(async { Ok(vec![]) }));
 
         let service =
             CardPriceHistoryService::new(Arc::new(mock_card_repo), Arc::new(mock_price_repo));
 
         let result = service
-            .get_card_price_history(Uuid::new_v4(), None, None)
+            .get_card_price_history(Uuid::new_v4(), None, None, PriceHistoryOptions::default())
             .await;
 
         assert!(result.is_ok());
@@ -227,7 +241,7 @@ mod tests {
         let mut mock_price_repo = MockCardMarketPriceRepository::new();
         mock_price_repo
             .expect_find_by_id_and_date_range()
-            .returning(|_, _, _, _| {
+            .returning(|_, _, _, _, _| {
                 Box::pin(async {
                     Err(AppError::Infra(InfraError::RepositoryError(
                         "db error".to_string(),
@@ -243,6 +257,7 @@ mod tests {
                 Uuid::new_v4(),
                 Some(date(2025, 1, 1)),
                 Some(date(2025, 1, 31)),
+                PriceHistoryOptions::default(),
             )
             .await;
 
@@ -252,4 +267,33 @@ mod tests {
             other => panic!("Expected RepositoryError, got {:?}", other),
         }
     }
+
+    #[tokio::test]
+    async fn passes_options_through_to_repository() {
+        let options = PriceHistoryOptions {
+            granularity: PriceHistoryGranularity::Week,
+            moving_averages: true,
+        };
+
+        let mut mock_card_repo = MockCardRepository::new();
+        mock_card_repo
+            .expect_find_by_scryfall_id()
+            .returning(|_| Box::pin(async { Ok(Some((Some(1), true))) }));
+
+        let mut mock_price_repo = MockCardMarketPriceRepository::new();
+        mock_price_repo
+            .expect_find_by_id_and_date_range()
+            .withf(move |_, foil, _, _, o| *foil && *o == options)
+            .times(1)
+            .returning(|_, _, _, _, _| Box::pin(async { Ok(vec![]) }));
+
+        let service =
+            CardPriceHistoryService::new(Arc::new(mock_card_repo), Arc::new(mock_price_repo));
+
+        let result = service
+            .get_card_price_history(Uuid::new_v4(), None, None, options)
+            .await;
+
+        assert!(result.is_ok());
+    }
 }
@@ -0,0 +1,261 @@
+use crate::application::error::AppError;
+use crate::application::repository::{CardPricesViewRepository, TradeMatchRepository};
+use crate::application::use_case::ScheduleCardPricesRefreshUseCase;
+use crate::domain::card_prices_refresh::{CardPricesRefresh, PendingCardPricesRefresh};
+use crate::domain::user::UserId;
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use std::collections::HashSet;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+use tokio::sync::Notify;
+
+/// Coalesces the card prices refresh requests and runs them in the background, one at a time.
+/// The trade matches of the users whose rows or wishlist changed are rebuilt afterwards, along
+/// with those of the users whose price-capped wishes a repriced product can serve.
+pub struct CardPricesRefreshCoordinator {
+    card_prices_view_repository: Arc<dyn CardPricesViewRepository>,
+    trade_match_repository: Arc<dyn TradeMatchRepository>,
+    debounce: Duration,
+    pending: Mutex<PendingCardPricesRefresh>,
+    requested: Notify,
+    last_refreshed_at: Mutex<Option<DateTime<Utc>>>,
+}
+
+impl CardPricesRefreshCoordinator {
+    pub fn new(
+        card_prices_view_repository: Arc<dyn CardPricesViewRepository>,
+        trade_match_repository: Arc<dyn TradeMatchRepository>,
+        debounce: Duration,
+    ) -> Self {
+        Self {
+            card_prices_view_repository,
+            trade_match_repository,
+            debounce,
+            pending: Mutex::new(PendingCardPricesRefresh::default()),
+            requested: Notify::new(),
+            last_refreshed_at: Mutex::new(None),
+        }
+    }
+
+    /// Single loop running the refreshes, so two of them never overlap.
+    pub async fn run(self: Arc<Self>) {
+        tracing::info!("Card prices refresh coordinator started.");
+
+        loop {
+            self.requested.notified().await;
+            // Les demandes arrivées pendant l'attente partent dans le même rafraîchissement
+            tokio::time::sleep(self.debounce).await;
+
+            if let Err(e) = self.flush().await {
+                tracing::error!("Failed to refresh card prices: {:?}", e);
+            }
+        }
+    }
+
+    /// Runs the pending requests. On failure they are queued again and retried with the next
+    /// request.
+    async fn flush(&self) -> Result<(), AppError> {
+        let pending = std::mem::take(&mut *self.pending.lock().unwrap());
+        if pending.is_empty() {
+            return Ok(());
+        }
+
+        if let Err(e) = self.refresh(&pending).await {
+            self.pending.lock().unwrap().merge(pending);
+            return Err(e);
+        }
+
+        *self.last_refreshed_at.lock().unwrap() = Some(Utc::now());
+        Ok(())
+    }
+
+    async fn refresh(&self, pending: &PendingCardPricesRefresh) -> Result<(), AppError> {
+        for user_id in &pending.users {
+            self.card_prices_view_repository
+                .refresh_user(user_id)
+                .await?;
+        }
+        if !pending.products.is_empty() {
+            self.card_prices_view_repository
+                .refresh_products(pending.products.iter().copied().collect())
+                .await?;
+        }
+        for card_id in &pending.cards {
+            self.card_prices_view_repository
+                .refresh_card(card_id)
+                .await?;
+        }
+        // Les correspondances se lisent dans card_prices : elles passent après
+        let mut trade_match_users: HashSet<UserId> =
+            pending.users.union(&pending.wishlists).cloned().collect();
+        // Une nouvelle tendance peut faire passer une copie sous le prix maximum d'un souhait, ou au-dessus
+        if !pending.products.is_empty() {
+            trade_match_users.extend(
+                self.trade_match_repository
+                    .find_price_capped_wishers(pending.products.iter().copied().collect())
+                    .await?,
+            );
+        }
+        for user_id in &trade_match_users {
+            self.trade_match_repository.refresh_user(user_id).await?;
+        }
+
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl ScheduleCardPricesRefreshUseCase for CardPricesRefreshCoordinator {
+    fn request(&self, refresh: CardPricesRefresh) {
+        self.pending.lock().unwrap().add(refresh);
+        self.requested.notify_one();
+    }
+
+    fn last_refreshed_at(&self) -> Option<DateTime<Utc>> {
+        *self.last_refreshed_at.lock().unwrap()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::application::error::InfraError;
+    use crate::application::repository::{MockCardPricesViewRepository, MockTradeMatchRepository};
+    use crate::domain::card::CardId;
+    use crate::domain::language_code::LanguageCode;
+    use crate::domain::set_name::SetCode;
+    use mockall::predicate::eq;
+
+    fn card_id() -> CardId {
+        CardId::new(SetCode::new("FDN"), "87", LanguageCode::FR, false)
+    }
+
+    #[tokio::test]
+    async fn flush_runs_each_pending_refresh_once() {
+        let mut repository = MockCardPricesViewRepository::new();
+        repository
+            .expect_refresh_user()
+            .withf(|user_id| *user_id == UserId::new("user1"))
+            .times(1)
+            .returning(|_| Box::pin(async { Ok(()) }));
+        repository
+            .expect_refresh_products()
+            .withf(|id_products| {
+                let mut id_products = id_products.clone();
+                id_products.sort();
+                id_products == vec![1, 2, 3]
+            })
+            .times(1)
+            .returning(|_| Box::pin(async { Ok(()) }));
+        repository
+            .expect_refresh_card()
+            .withf(|id| *id == card_id())
+            .times(1)
+            .returning(|_| Box::pin(async { Ok(()) }));
+        let mut trade_match_repository = MockTradeMatchRepository::new();
+        trade_match_repository
+            .expect_find_price_capped_wishers()
+            .withf(|id_products| {
+                let mut id_products = id_products.clone();
+                id_products.sort();
+                id_products == vec![1, 2, 3]
+            })
+            .times(1)
+            .returning(|_| {
+                Box::pin(async { Ok(vec![UserId::new("user2"), UserId::new("user3")]) })
+            });
+        for user in ["user1", "user2", "user3"] {
+            trade_match_repository
+                .expect_refresh_user()
+                .withf(move |user_id| *user_id == UserId::new(user))
+                .times(1)
+                .returning(|_| Box::pin(async { Ok(()) }));
+        }
+        let coordinator = CardPricesRefreshCoordinator::new(
+            Arc::new(repository),
+            Arc::new(trade_match_repository),
+            Duration::ZERO,
+        );
+
+        coordinator.request(CardPricesRefresh::User(UserId::new("user1")));
+        coordinator.request(CardPricesRefresh::User(UserId::new("user1")));
+        coordinator.request(CardPricesRefresh::Wishlist(UserId::new("user1")));
+        coordinator.request(CardPricesRefresh::Wishlist(UserId::new("user2")));
+        coordinator.request(CardPricesRefresh::Products(vec![1, 2]));
+        coordinator.request(CardPricesRefresh::Products(vec![2, 3]));
+        coordinator.request(CardPricesRefresh::Card(card_id()));
+        coordinator.request(CardPricesRefresh::Card(card_id()));
+        coordinator.flush().await.unwrap();
+        coordinator.flush().await.unwrap();
+
+        assert!(coordinator.last_refreshed_at().is_some());
+    }
+
+    #[tokio::test]
+    async fn flush_queues_requests_again_when_refresh_fails() {
+        let mut repository = MockCardPricesViewRepository::new();
+        let mut attempts = 0;
+        repository
+            .expect_refresh_products()
+            .with(eq(vec![42]))
+            .times(2)
+            .returning(move |_| {
+                attempts += 1;
+                let attempt = attempts;
+                Box::pin(async move {
+                    if attempt == 1 {
+                        Err(AppError::Infra(InfraError::RepositoryError(
+                            "connection lost".to_string(),
+                        )))
+                    } else {
+                        Ok(())
+                    }
+                })
+            });
+        let mut trade_match_repository = MockTradeMatchRepository::new();
+        trade_match_repository
+            .expect_find_price_capped_wishers()
+            .with(eq(vec![42]))
+            .times(1)
+            .returning(|_| Box::pin(async { Ok(vec![]) }));
+        let coordinator = CardPricesRefreshCoordinator::new(
+            Arc::new(repository),
+            Arc::new(trade_match_repository),
+            Duration::ZERO,
+        );
+
+        coordinator.request(CardPricesRefresh::Products(vec![42]));
+
+        assert!(coordinator.flush().await.is_err());
+        assert!(coordinator.last_refreshed_at().is_none());
+        coordinator.flush().await.unwrap();
+        assert!(coordinator.last_refreshed_at().is_some());
+    }
+
+    #[tokio::test]
+    async fn run_coalesces_requests_received_while_debouncing() {
+        let mut repository = MockCardPricesViewRepository::new();
+        repository
+            .expect_refresh_card()
+            .times(1)
+            .returning(|_| Box::pin(async { Ok(()) }));
+        let coordinator = Arc::new(CardPricesRefreshCoordinator::new(
+            Arc::new(repository),
+            Arc::new(MockTradeMatchRepository::new()),
+            Duration::from_millis(50),
+        ));
+        tokio::spawn(coordinator.clone().run());
+
+        coordinator.request(CardPricesRefresh::Card(card_id()));
+        coordinator.request(CardPricesRefresh::Card(card_id()));
+
+        tokio::time::timeout(Duration::from_secs(5), async {
+            while coordinator.last_refreshed_at().is_none() {
+                tokio::time::sleep(Duration::from_millis(10)).await;
+            }
+        })
+        .await
+        .unwrap();
+    }
+}
@@ -1,29 +1,26 @@
-use crate::application::error::{AppError, InfraError};
-use crate::application::repository::CardRepository;
-use crate::application::use_case::EnqueueCardMarketIdUpdateUseCase;
-use crate::domain::card::CardId;
+use crate::application::error::AppError;
+use crate::application::repository::{CardRepository, EnrichmentJobRepository};
+use crate::application::use_case::{EnqueueCardMarketIdUpdateUseCase, ScryfallCatalogUseCase};
+use crate::domain::enrichment_job::EnrichmentKind;
 use async_trait::async_trait;
-use std::collections::HashSet;
-use std::sync::{Arc, Mutex};
-use tokio::sync::mpsc::UnboundedSender;
-use uuid::Uuid;
+use std::sync::Arc;
 
 pub struct CardMarketIdEnqueueService {
     card_repository: Arc<dyn CardRepository>,
-    sender: UnboundedSender<(CardId, Uuid)>,
-    dedup_set: Arc<Mutex<HashSet<CardId>>>,
+    enrichment_job_repository: Arc<dyn EnrichmentJobRepository>,
+    scryfall_catalog: Arc<dyn ScryfallCatalogUseCase>,
 }
 
 impl CardMarketIdEnqueueService {
     pub fn new(
         card_repository: Arc<dyn CardRepository>,
-        sender: UnboundedSender<(CardId, Uuid)>,
-        dedup_set: Arc<Mutex<HashSet<CardId>>>,
+        enrichment_job_repository: Arc<dyn EnrichmentJobRepository>,
+        scryfall_catalog: Arc<dyn ScryfallCatalogUseCase>,
     ) -> Self {
         Self {
             card_repository,
-            sender,
-            dedup_set,
+            enrichment_job_repository,
+            scryfall_catalog,
         }
     }
 }
@@ -31,122 +28,108 @@ impl CardMarketIdEnqueueService {
 #[async_trait]
 impl EnqueueCardMarketIdUpdateUseCase for CardMarketIdEnqueueService {
     async fn enqueue_pending_updates(&self) -> Result<usize, AppError> {
-        let cards = self.card_repository.get_all_without_cardmarket_id().await?;
-        let mut enqueued = 0;
-        let mut set = self.dedup_set.lock().unwrap();
-        for (card_id, scryfall_id) in cards {
-            if set.insert(card_id.clone()) {
-                if self.sender.send((card_id, scryfall_id)).is_err() {
-                    return Err(InfraError::QueueError(
-                        "Worker channel closed, cannot enqueue card".into(),
-                    )
-                    .into());
-                } else {
-                    enqueued += 1;
-                }
-            }
-        }
-        Ok(enqueued)
+        // Seules les cartes absentes du catalogue Scryfall local sont mises en file : le worker les
+        // cherche par lots de 75 identifiants dans `/cards/collection`
+        self.scryfall_catalog.resolve_cardmarket_ids().await?;
+
+        let card_ids = self
+            .card_repository
+            .get_all_without_cardmarket_id()
+            .await?
+            .into_iter()
+            .map(|(card_id, _)| card_id)
+            .collect();
+
+        // Les cartes déjà en file sont ignorées par la contrainte d'unicité de enrichment_job
+        self.enrichment_job_repository
+            .enqueue(EnrichmentKind::CardMarketId, card_ids)
+            .await
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::application::repository::MockCardRepository;
+    use crate::application::error::InfraError;
+    use crate::application::repository::{MockCardRepository, MockEnrichmentJobRepository};
+    use crate::application::use_case::MockScryfallCatalogUseCase;
+    use crate::domain::card::CardId;
     use crate::domain::language_code::LanguageCode;
     use crate::domain::set_name::SetCode;
-    use tokio::sync::mpsc::unbounded_channel;
+    use mockall::predicate::eq;
 
     fn make_card_id(n: &str) -> CardId {
         CardId::new(SetCode::new("FDN"), n, LanguageCode::FR, false)
     }
 
-    #[tokio::test]
-    async fn enqueue_returns_count_of_newly_enqueued_cards() {
-        let mut card_repository = MockCardRepository::new();
-        card_repository
-            .expect_get_all_without_cardmarket_id()
-            .returning(|| {
-                Box::pin(async {
-                    Ok(vec![
-                        (
-                            CardId::new(SetCode::new("FDN"), "0", LanguageCode::FR, false),
-                            Uuid::default(),
-                        ),
-                        (
-                            CardId::new(SetCode::new("FDN"), "1", LanguageCode::FR, false),
-                            Uuid::default(),
-                        ),
-                    ])
-                })
-            });
-
-        let (tx, _rx) = unbounded_channel();
-        let dedup_set = Arc::new(Mutex::new(HashSet::new()));
-        let service = CardMarketIdEnqueueService::new(Arc::new(card_repository), tx, dedup_set);
+    fn scryfall_catalog() -> MockScryfallCatalogUseCase {
+        let mut r = MockScryfallCatalogUseCase::new();
+        r.expect_resolve_cardmarket_ids()
+            .returning(|| Box::pin(async { Ok(0) }));
+        r
+    }
 
-        let result = service.enqueue_pending_updates().await;
-        assert!(result.is_ok());
-        assert_eq!(result.unwrap(), 2);
+    fn service(
+        card_repository: MockCardRepository,
+        job_repository: MockEnrichmentJobRepository,
+    ) -> CardMarketIdEnqueueService {
+        CardMarketIdEnqueueService::new(
+            Arc::new(card_repository),
+            Arc::new(job_repository),
+            Arc::new(scryfall_catalog()),
+        )
     }
 
     #[tokio::test]
-    async fn enqueue_deduplicates_already_enqueued_cards() {
+    async fn enqueue_queues_a_cardmarket_id_job_per_card() {
         let mut card_repository = MockCardRepository::new();
         card_repository
             .expect_get_all_without_cardmarket_id()
             .returning(|| {
                 Box::pin(async {
                     Ok(vec![
-                        (
-                            CardId::new(SetCode::new("FDN"), "0", LanguageCode::FR, false),
-                            Uuid::default(),
-                        ),
-                        (
-                            CardId::new(SetCode::new("FDN"), "1", LanguageCode::FR, false),
-                            Uuid::default(),
-                        ),
+                        (make_card_id("0"), uuid::Uuid::default()),
+                        (make_card_id("1"), uuid::Uuid::default()),
                     ])
                 })
             });
-
-        let (tx, _rx) = unbounded_channel();
-        let dedup_set = Arc::new(Mutex::new(HashSet::new()));
-        // Pré-insérer "0" dans le set de déduplication
-        dedup_set.lock().unwrap().insert(make_card_id("0"));
-
-        let service = CardMarketIdEnqueueService::new(Arc::new(card_repository), tx, dedup_set);
-
-        let result = service.enqueue_pending_updates().await;
-        assert!(result.is_ok());
-        // Seule "1" est nouvelle → 1 seule enfilée
-        assert_eq!(result.unwrap(), 1);
+        let mut job_repository = MockEnrichmentJobRepository::new();
+        job_repository
+            .expect_enqueue()
+            .with(
+                eq(EnrichmentKind::CardMarketId),
+                eq(vec![make_card_id("0"), make_card_id("1")]),
+            )
+            .times(1)
+            .returning(|_, _| Box::pin(async { Ok(2) }));
+
+        let service = service(card_repository, job_repository);
+
+        assert_eq!(service.enqueue_pending_updates().await.unwrap(), 2);
     }
 
     #[tokio::test]
-    async fn enqueue_same_card_twice_enqueues_only_once() {
+    async fn enqueue_returns_count_of_jobs_actually_created() {
         let mut card_repository = MockCardRepository::new();
         card_repository
             .expect_get_all_without_cardmarket_id()
-            .times(2)
             .returning(|| {
                 Box::pin(async {
-                    Ok(vec![(
-                        CardId::new(SetCode::new("FDN"), "0", LanguageCode::FR, false),
-                        Uuid::default(),
-                    )])
+                    Ok(vec![
+                        (make_card_id("0"), uuid::Uuid::default()),
+                        (make_card_id("1"), uuid::Uuid::default()),
+                    ])
                 })
             });
+        let mut job_repository = MockEnrichmentJobRepository::new();
+        // "0" est déjà en file → 1 seul job créé
+        job_repository
+            .expect_enqueue()
+            .returning(|_, _| Box::pin(async { Ok(1) }));
 
-        let (tx, _rx) = unbounded_channel();
-        let dedup_set = Arc::new(Mutex::new(HashSet::new()));
-        let service = CardMarketIdEnqueueService::new(Arc::new(card_repository), tx, dedup_set);
+        let service = service(card_repository, job_repository);
 
-        let first = service.enqueue_pending_updates().await.unwrap();
-        let second = service.enqueue_pending_updates().await.unwrap();
-        assert_eq!(first, 1);
-        assert_eq!(second, 0); // déjà dans le set
+        assert_eq!(service.enqueue_pending_updates().await.unwrap(), 1);
     }
 
     #[tokio::test]
@@ -161,28 +144,41 @@ mod tests {
                     )))
                 })
             });
+        let mut job_repository = MockEnrichmentJobRepository::new();
+        job_repository.expect_enqueue().never();
 
-        let (tx, _rx) = unbounded_channel();
-        let dedup_set = Arc::new(Mutex::new(HashSet::new()));
-        let service = CardMarketIdEnqueueService::new(Arc::new(card_repository), tx, dedup_set);
+        let service = service(card_repository, job_repository);
 
-        let result = service.enqueue_pending_updates().await;
-        assert!(result.is_err());
+        assert!(service.enqueue_pending_updates().await.is_err());
     }
 
     #[tokio::test]
-    async fn enqueue_returns_zero_for_empty_card_list() {
+    async fn enqueue_resolves_from_the_catalogue_before_listing_cards() {
+        let mut sequence = mockall::Sequence::new();
+        let mut scryfall_catalog = MockScryfallCatalogUseCase::new();
+        scryfall_catalog
+            .expect_resolve_cardmarket_ids()
+            .times(1)
+            .in_sequence(&mut sequence)
+            .returning(|| Box::pin(async { Ok(1) }));
         let mut card_repository = MockCardRepository::new();
         card_repository
             .expect_get_all_without_cardmarket_id()
+            .times(1)
+            .in_sequence(&mut sequence)
             .returning(|| Box::pin(async { Ok(vec![]) }));
-
-        let (tx, _rx) = unbounded_channel();
-        let dedup_set = Arc::new(Mutex::new(HashSet::new()));
-        let service = CardMarketIdEnqueueService::new(Arc::new(card_repository), tx, dedup_set);
-
-        let result = service.enqueue_pending_updates().await;
-        assert!(result.is_ok());
-        assert_eq!(result.unwrap(), 0);
+        let mut job_repository = MockEnrichmentJobRepository::new();
+        job_repository
+            .expect_enqueue()
+            .with(eq(EnrichmentKind::CardMarketId), eq(Vec::<CardId>::new()))
+            .returning(|_, _| Box::pin(async { Ok(0) }));
+
+        let service = CardMarketIdEnqueueService::new(
+            Arc::new(card_repository),
+            Arc::new(job_repository),
+            Arc::new(scryfall_catalog),
+        );
+
+        assert_eq!(service.enqueue_pending_updates().await.unwrap(), 0);
     }
 }
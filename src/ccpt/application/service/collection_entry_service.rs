@@ -0,0 +1,378 @@
+use crate::application::error::AppError;
+use crate::application::repository::{BinderRepository, CardRepository};
+use crate::application::service::binder_service::resolve_binder;
+use crate::application::use_case::{
+    EnqueueCardMarketIdUpdateUseCase, EnqueueGathererIdUpdateUseCase, ManageCollectionEntryUseCase,
+    ScheduleCardPricesRefreshUseCase,
+};
+use crate::domain::binder::BinderId;
+use crate::domain::card::CardId;
+use crate::domain::card_prices_refresh::CardPricesRefresh;
+use crate::domain::collection_item::{CollectionItem, CollectionItemUpdate};
+use crate::domain::error::FunctionalError;
+use crate::domain::user::UserId;
+use async_trait::async_trait;
+use std::sync::Arc;
+
+pub struct CollectionEntryService {
+    card_repository: Arc<dyn CardRepository>,
+    binder_repository: Arc<dyn BinderRepository>,
+    enqueue_cardmarket_ids: Arc<dyn EnqueueCardMarketIdUpdateUseCase>,
+    enqueue_gatherer_ids: Arc<dyn EnqueueGathererIdUpdateUseCase>,
+    card_prices_refresh: Arc<dyn ScheduleCardPricesRefreshUseCase>,
+}
+
+impl CollectionEntryService {
+    pub fn new(
+        card_repository: Arc<dyn CardRepository>,
+        binder_repository: Arc<dyn BinderRepository>,
+        enqueue_cardmarket_ids: Arc<dyn EnqueueCardMarketIdUpdateUseCase>,
+        enqueue_gatherer_ids: Arc<dyn EnqueueGathererIdUpdateUseCase>,
+        card_prices_refresh: Arc<dyn ScheduleCardPricesRefreshUseCase>,
+    ) -> Self {
+        Self {
+            card_repository,
+            binder_repository,
+            enqueue_cardmarket_ids,
+            enqueue_gatherer_ids,
+            card_prices_refresh,
+        }
+    }
+
+    /// Same follow-up as an import: enrichment of the new printings and refresh of the user's
+    /// rows in the card prices read model.
+    async fn collection_changed(&self, user_id: &UserId) -> Result<(), AppError> {
+        self.enqueue_cardmarket_ids
+            .enqueue_pending_updates()
+            .await?;
+        self.enqueue_gatherer_ids.enqueue_pending_updates().await?;
+        self.card_prices_refresh
+            .request(CardPricesRefresh::User(user_id.clone()));
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl ManageCollectionEntryUseCase for CollectionEntryService {
+    async fn add_entry(
+        &self,
+        user_id: &UserId,
+        binder_id: Option<BinderId>,
+        item: CollectionItem,
+    ) -> Result<(), AppError> {
+        let binder = resolve_binder(self.binder_repository.as_ref(), user_id, binder_id).await?;
+        if !self
+            .card_repository
+            .ensure_printing(item.card_id.clone())
+            .await?
+        {
+            return Err(FunctionalError::CardNotFound.into());
+        }
+        if !self
+            .card_repository
+            .add_entry(user_id, binder.id, item)
+            .await?
+        {
+            return Err(FunctionalError::CollectionEntryAlreadyExists.into());
+        }
+
+        self.collection_changed(user_id).await
+    }
+
+    async fn update_entry(
+        &self,
+        user_id: &UserId,
+        binder_id: Option<BinderId>,
+        card_id: CardId,
+        update: CollectionItemUpdate,
+    ) -> Result<CollectionItem, AppError> {
+        let binder = resolve_binder(self.binder_repository.as_ref(), user_id, binder_id).await?;
+        let item = self
+            .card_repository
+            .update_entry(user_id, binder.id, card_id, update)
+            .await?
+            .ok_or(FunctionalError::CollectionEntryNotFound)?;
+
+        self.collection_changed(user_id).await?;
+        Ok(item)
+    }
+
+    async fn remove_entry(
+        &self,
+        user_id: &UserId,
+        binder_id: Option<BinderId>,
+        card_id: CardId,
+    ) -> Result<(), AppError> {
+        let binder = resolve_binder(self.binder_repository.as_ref(), user_id, binder_id).await?;
+        if !self
+            .card_repository
+            .delete_entry(user_id, binder.id, card_id)
+            .await?
+        {
+            return Err(FunctionalError::CollectionEntryNotFound.into());
+        }
+
+        self.collection_changed(user_id).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::application::repository::{MockBinderRepository, MockCardRepository};
+    use crate::application::use_case::{
+        MockEnqueueCardMarketIdUpdateUseCase, MockEnqueueGathererIdUpdateUseCase,
+        MockScheduleCardPricesRefreshUseCase,
+    };
+    use crate::domain::binder::Binder;
+    use crate::domain::collection_item::CardCondition;
+    use crate::domain::language_code::LanguageCode;
+    use chrono::Utc;
+    use mockall::predicate::eq;
+
+    fn card_id() -> CardId {
+        CardId::new("FDN", "87", LanguageCode::FR, false)
+    }
+
+    fn item() -> CollectionItem {
+        CollectionItem {
+            card_id: card_id(),
+            quantity: 2,
+            purchase_price: 15,
+            condition: CardCondition::NearMint,
+            notes: None,
+            tags: vec![],
+            location: None,
+            tradable_quantity: None,
+            added_at: Utc::now(),
+        }
+    }
+
+    fn default_binder_id() -> BinderId {
+        BinderId(uuid::Uuid::nil())
+    }
+
+    /// Binder repository resolving `user1`'s default binder only.
+    fn binder_repository() -> MockBinderRepository {
+        let mut binder_repository = MockBinderRepository::new();
+        binder_repository
+            .expect_find_or_create_default()
+            .returning(|user_id| {
+                let binder = Binder {
+                    id: default_binder_id(),
+                    user_id: user_id.clone(),
+                    name: "Collection".to_string(),
+                    tradable: true,
+                    keep_quantity: 0,
+                    is_default: true,
+                    created_at: Utc::now(),
+                };
+                Box::pin(async move { Ok(binder) })
+            });
+        binder_repository
+            .expect_find_by_id()
+            .returning(|_, _| Box::pin(async { Ok(None) }));
+        binder_repository
+    }
+
+    /// Service expecting the follow-up of `times` collection changes of `user1`.
+    fn service(card_repository: MockCardRepository, times: usize) -> CollectionEntryService {
+        let mut enqueue_cardmarket_ids = MockEnqueueCardMarketIdUpdateUseCase::new();
+        enqueue_cardmarket_ids
+            .expect_enqueue_pending_updates()
+            .times(times)
+            .returning(|| Box::pin(async { Ok(1) }));
+        let mut enqueue_gatherer_ids = MockEnqueueGathererIdUpdateUseCase::new();
+        enqueue_gatherer_ids
+            .expect_enqueue_pending_updates()
+            .times(times)
+            .returning(|| Box::pin(async { Ok(1) }));
+        let mut card_prices_refresh = MockScheduleCardPricesRefreshUseCase::new();
+        card_prices_refresh
+            .expect_request()
+            .with(eq(CardPricesRefresh::User(UserId::new("user1"))))
+            .times(times)
+            .return_const(());
+
+        CollectionEntryService::new(
+            Arc::new(card_repository),
+            Arc::new(binder_repository()),
+            Arc::new(enqueue_cardmarket_ids),
+            Arc::new(enqueue_gatherer_ids),
+            Arc::new(card_prices_refresh),
+        )
+    }
+
+    #[tokio::test]
+    async fn add_entry_saves_the_entry_and_refreshes_the_users_prices() {
+        let mut card_repository = MockCardRepository::new();
+        card_repository
+            .expect_ensure_printing()
+            .with(eq(card_id()))
+            .returning(|_| Box::pin(async { Ok(true) }));
+        card_repository
+            .expect_add_entry()
+            .withf(|user_id, binder_id, item| {
+                user_id == &UserId::new("user1")
+                    && *binder_id == default_binder_id()
+                    && item.quantity == 2
+            })
+            .times(1)
+            .returning(|_, _, _| Box::pin(async { Ok(true) }));
+
+        let result = service(card_repository, 1)
+            .add_entry(&UserId::new("user1"), None, item())
+            .await;
+
+        assert!(result.is_ok());
+    }
+
+    #[tokio::test]
+    async fn add_entry_returns_binder_not_found_for_an_unknown_binder() {
+        let mut card_repository = MockCardRepository::new();
+        card_repository.expect_add_entry().never();
+
+        let result = service(card_repository, 0)
+            .add_entry(&UserId::new("user1"), Some(BinderId::new()), item())
+            .await;
+
+        assert!(matches!(
+            result,
+            Err(AppError::Functional(FunctionalError::BinderNotFound))
+        ));
+    }
+
+    #[tokio::test]
+    async fn add_entry_returns_card_not_found_for_an_unknown_printing() {
+        let mut card_repository = MockCardRepository::new();
+        card_repository
+            .expect_ensure_printing()
+            .returning(|_| Box::pin(async { Ok(false) }));
+        card_repository.expect_add_entry().never();
+
+        let result = service(card_repository, 0)
+            .add_entry(&UserId::new("user1"), None, item())
+            .await;
+
+        assert!(matches!(
+            result,
+            Err(AppError::Functional(FunctionalError::CardNotFound))
+        ));
+    }
+
+    #[tokio::test]
+    async fn add_entry_returns_conflict_when_the_printing_is_already_owned() {
+        let mut card_repository = MockCardRepository::new();
+        card_repository
+            .expect_ensure_printing()
+            .returning(|_| Box::pin(async { Ok(true) }));
+        card_repository
+            .expect_add_entry()
+            .returning(|_, _, _| Box::pin(async { Ok(false) }));
+
+        let result = service(card_repository, 0)
+            .add_entry(&UserId::new("user1"), None, item())
+            .await;
+
+        assert!(matches!(
+            result,
+            Err(AppError::Functional(
+                FunctionalError::CollectionEntryAlreadyExists
+            ))
+        ));
+    }
+
+    #[tokio::test]
+    async fn update_entry_returns_the_updated_entry() {
+        let mut card_repository = MockCardRepository::new();
+        card_repository
+            .expect_update_entry()
+            .withf(|_, _, id, update| *id == card_id() && update.quantity == Some(3))
+            .returning(|_, _, _, _| {
+                Box::pin(async {
+                    Ok(Some(CollectionItem {
+                        quantity: 3,
+                        ..item()
+                    }))
+                })
+            });
+
+        let result = service(card_repository, 1)
+            .update_entry(
+                &UserId::new("user1"),
+                None,
+                card_id(),
+                CollectionItemUpdate {
+                    quantity: Some(3),
+                    ..CollectionItemUpdate::default()
+                },
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(result.quantity, 3);
+    }
+
+    #[tokio::test]
+    async fn update_entry_returns_not_found_when_the_printing_is_not_owned() {
+        let mut card_repository = MockCardRepository::new();
+        card_repository
+            .expect_update_entry()
+            .returning(|_, _, _, _| Box::pin(async { Ok(None) }));
+
+        let result = service(card_repository, 0)
+            .update_entry(
+                &UserId::new("user1"),
+                None,
+                card_id(),
+                CollectionItemUpdate::default(),
+            )
+            .await;
+
+        assert!(matches!(
+            result,
+            Err(AppError::Functional(
+                FunctionalError::CollectionEntryNotFound
+            ))
+        ));
+    }
+
+    #[tokio::test]
+    async fn remove_entry_deletes_the_entry_and_refreshes_the_users_prices() {
+        let mut card_repository = MockCardRepository::new();
+        card_repository
+            .expect_delete_entry()
+            .withf(|user_id, binder_id, id| {
+                user_id == &UserId::new("user1")
+                    && *binder_id == default_binder_id()
+                    && *id == card_id()
+            })
+            .times(1)
+            .returning(|_, _, _| Box::pin(async { Ok(true) }));
+
+        let result = service(card_repository, 1)
+            .remove_entry(&UserId::new("user1"), None, card_id())
+            .await;
+
+        assert!(result.is_ok());
+    }
+
+    #[tokio::test]
+    async fn remove_entry_returns_not_found_when_the_printing_is_not_owned() {
+        let mut card_repository = MockCardRepository::new();
+        card_repository
+            .expect_delete_entry()
+            .returning(|_, _, _| Box::pin(async { Ok(false) }));
+
+        let result = service(card_repository, 0)
+            .remove_entry(&UserId::new("user1"), None, card_id())
+            .await;
+
+        assert!(matches!(
+            result,
+            Err(AppError::Functional(
+                FunctionalError::CollectionEntryNotFound
+            ))
+        ));
+    }
+}
@@ -2,7 +2,7 @@ use crate::application::date_range::resolve_date_range;
 use crate::application::error::AppError;
 use crate::application::repository::CollectionPriceHistoryRepository;
 use crate::application::use_case::GetCollectionPriceHistoryUseCase;
-use crate::domain::price::PriceHistoryEntry;
+use crate::domain::price::{PriceHistoryEntry, PriceHistoryOptions};
 use crate::domain::user::UserId;
 use async_trait::async_trait;
 use chrono::NaiveDate;
@@ -25,10 +25,11 @@ impl GetCollectionPriceHistoryUseCase for CollectionPriceHistoryService {
         user_id: &UserId,
         start_date: Option<NaiveDate>,
         end_date: Option<NaiveDate>,
+        options: PriceHistoryOptions,
     ) -> Result<Vec<PriceHistoryEntry>, AppError> {
         let (start_date, end_date) = resolve_date_range(start_date, end_date)?;
         self.repository
-            .get_price_history(user_id, start_date, end_date)
+            .get_price_history(user_id, start_date, end_date, options)
             .await
     }
 }
@@ -39,7 +40,7 @@ mod tests {
     use crate::application::error::InfraError;
     use crate::application::repository::MockCollectionPriceHistoryRepository;
     use crate::domain::error::FunctionalError;
-    use crate::domain::price::{Price, PriceGuide};
+    use crate::domain::price::{Price, PriceGuide, PriceHistoryGranularity};
     use chrono::NaiveDate;
 
     fn date(y: i32, m: u32, d: u32) -> NaiveDate {
@@ -50,12 +51,12 @@ mod tests {
     async fn returns_history_from_repository() {
         let mut mock = MockCollectionPriceHistoryRepository::new();
         mock.expect_get_price_history()
-            .withf(|uid, s, e| {
+            .withf(|uid, s, e, _| {
                 uid == &UserId::new("user1")
                     && *s == NaiveDate::from_ymd_opt(2025, 1, 1).unwrap()
                     && *e == NaiveDate::from_ymd_opt(2025, 1, 31).unwrap()
             })
-            .returning(|_, _, _| {
+            .returning(|_, _, _, _| {
                 Box::pin(async {
                     Ok(vec![PriceHistoryEntry {
                         date: NaiveDate::from_ymd_opt(2025, 1, 15).unwrap(),
@@ -64,6 +65,11 @@ mod tests {
                             trend: Price { value: Some(150) },
                             avg: Price { value: Some(130) },
                         },
+                        trend_open: Price { value: Some(120) },
+                        trend_high: Price { value: Some(160) },
+                        trend_low: Price { value: Some(110) },
+                        trend_ma7: Price::empty(),
+                        trend_ma30: Price::empty(),
                     }])
                 })
             });
@@ -74,6 +80,7 @@ mod tests {
                 &UserId::new("user1"),
                 Some(date(2025, 1, 1)),
                 Some(date(2025, 1, 31)),
+                PriceHistoryOptions::default(),
             )
             .await;
 
@@ -95,6 +102,7 @@ mod tests {
                 &UserId::new("user1"),
                 Some(date(2025, 2, 1)),
                 Some(date(2025, 1, 1)),
+                PriceHistoryOptions::default(),
             )
             .await;
 
@@ -111,7 +119,7 @@ mod tests {
     async fn accepts_same_start_and_end_date() {
         let mut mock = MockCollectionPriceHistoryRepository::new();
         mock.expect_get_price_history()
-            .returning(|_, _, _| Box::pin(async { Ok(vec![]) }));
+            .returning(|_, _, _, _| Box::pin(async { Ok(vec![]) }));
 
         let service = CollectionPriceHistoryService::new(Arc::new(mock));
         let result = service
@@ -119,6 +127,7 @@ mod tests {
                 &UserId::new("user1"),
                 Some(date(2025, 6, 1)),
                 Some(date(2025, 6, 1)),
+                PriceHistoryOptions::default(),
             )
             .await;
 
@@ -128,7 +137,7 @@ mod tests {
     #[tokio::test]
     async fn propagates_repository_error() {
         let mut mock = MockCollectionPriceHistoryRepository::new();
-        mock.expect_get_price_history().returning(|_, _, _| {
+        mock.expect_get_price_history().returning(|_, _, _, _| {
             Box::pin(async {
                 Err(AppError::Infra(InfraError::RepositoryError(
                     "db error".to_string(),
@@ -142,6 +151,7 @@ mod tests {
                 &UserId::new("user1"),
                 Some(date(2025, 1, 1)),
                 Some(date(2025, 1, 31)),
+                PriceHistoryOptions::default(),
             )
             .await;
 
@@ -161,12 +171,38 @@ mod tests {
 
         let mut mock = MockCollectionPriceHistoryRepository::new();
         mock.expect_get_price_history()
-            .withf(move |_, s, e| *s == expected_start && *e == today)
-            .returning(|_, _, _| Box::pin(async { Ok(vec![]) }));
+            .withf(move |_, s, e, _| *s == expected_start && *e == today)
+            .returning(|_, _, _, _| Box::pin(async { Ok(vec![]) }));
 
         let service = CollectionPriceHistoryService::new(Arc::new(mock));
         let result = service
-            .get_collection_price_history(&UserId::new("user1"), None, None)
+            .get_collection_price_history(
+                &UserId::new("user1"),
+                None,
+                None,
+                PriceHistoryOptions::default(),
+            )
+            .await;
+
+        assert!(result.is_ok());
+    }
+
+    #[tokio::test]
+    async fn passes_options_through_to_repository() {
+        let options = PriceHistoryOptions {
+            granularity: PriceHistoryGranularity::Month,
+            moving_averages: true,
+        };
+
+        let mut mock = MockCollectionPriceHistoryRepository::new();
+        mock.expect_get_price_history()
+            .withf(move |_, _, _, o| *o == options)
+            .times(1)
+            .returning(|_, _, _, _| Box::pin(async { Ok(vec![]) }));
+
+        let service = CollectionPriceHistoryService::new(Arc::new(mock));
+        let result = service
+            .get_collection_price_history(&UserId::new("user1"), None, None, options)
             .await;
 
         assert!(result.is_ok());
@@ -47,6 +47,12 @@ mod tests {
             sets: Vec::new(),
             price_min: None,
             price_max: None,
+            colors: Vec::new(),
+            type_line: None,
+            oracle_id: None,
+            binder_id: None,
+            tags: Vec::new(),
+            location: None,
         };
         let expected_result = PaginatedCollection {
             items: vec![],
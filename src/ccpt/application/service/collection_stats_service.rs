@@ -1,6 +1,7 @@
 use crate::application::error::AppError;
 use crate::application::repository::CollectionStatsRepository;
 use crate::application::use_case::GetCollectionStatsUseCase;
+use crate::domain::binder::BinderId;
 use crate::domain::collection_stats::CollectionStats;
 use crate::domain::user::UserId;
 use async_trait::async_trait;
@@ -18,8 +19,15 @@ impl CollectionStatsService {
 
 #[async_trait]
 impl GetCollectionStatsUseCase for CollectionStatsService {
-    async fn get_collection_stats(&self, user_id: &UserId) -> Result<CollectionStats, AppError> {
-        self.repository.get_collection_stats(user_id).await
+    async fn get_collection_stats(
+        &self,
+        user_id: &UserId,
+        binder_id: Option<BinderId>,
+        tags: Vec<String>,
+    ) -> Result<CollectionStats, AppError> {
+        self.repository
+            .get_collection_stats(user_id, binder_id, tags)
+            .await
     }
 }
 
@@ -34,8 +42,10 @@ mod tests {
     async fn delegates_to_repository() {
         let mut mock = MockCollectionStatsRepository::new();
         mock.expect_get_collection_stats()
-            .withf(|uid| uid == &UserId::new("user-1"))
-            .returning(|_| {
+            .withf(|uid, binder_id, tags| {
+                uid == &UserId::new("user-1") && binder_id.is_none() && tags == &["cube"]
+            })
+            .returning(|_, _, _| {
                 Box::pin(async {
                     Ok(CollectionStats {
                         total_cards: 10,
@@ -43,12 +53,15 @@ mod tests {
                         price_trend_min: Price::from_cents(100),
                         price_trend_max: Price::from_cents(1000),
                         sets: vec![],
+                        tags: vec!["cube".to_string()],
                     })
                 })
             });
 
         let service = CollectionStatsService::new(Arc::new(mock));
-        let result = service.get_collection_stats(&UserId::new("user-1")).await;
+        let result = service
+            .get_collection_stats(&UserId::new("user-1"), None, vec!["cube".to_string()])
+            .await;
         assert!(result.is_ok());
         let stats = result.unwrap();
         assert_eq!(stats.total_cards, 10);
@@ -58,7 +71,7 @@ mod tests {
     #[tokio::test]
     async fn propagates_repository_error() {
         let mut mock = MockCollectionStatsRepository::new();
-        mock.expect_get_collection_stats().returning(|_| {
+        mock.expect_get_collection_stats().returning(|_, _, _| {
             Box::pin(async {
                 Err(AppError::Infra(InfraError::RepositoryError(
                     "db error".to_string(),
@@ -67,7 +80,9 @@ mod tests {
         });
 
         let service = CollectionStatsService::new(Arc::new(mock));
-        let result = service.get_collection_stats(&UserId::new("user-1")).await;
+        let result = service
+            .get_collection_stats(&UserId::new("user-1"), None, vec![])
+            .await;
         assert!(result.is_err());
     }
 }
@@ -0,0 +1,189 @@
+use crate::application::caller::EdhRecCaller;
+use crate::application::error::AppError;
+use crate::application::repository::CardPricesViewRepository;
+use crate::application::use_case::GetCommanderSynergyUseCase;
+use crate::domain::commander_synergy::CommanderSynergyReport;
+use crate::domain::error::FunctionalError;
+use crate::domain::user::UserId;
+use async_trait::async_trait;
+use std::sync::Arc;
+
+pub struct CommanderSynergyService {
+    edh_rec_caller: Arc<dyn EdhRecCaller>,
+    card_prices_view_repository: Arc<dyn CardPricesViewRepository>,
+}
+
+impl CommanderSynergyService {
+    pub fn new(
+        edh_rec_caller: Arc<dyn EdhRecCaller>,
+        card_prices_view_repository: Arc<dyn CardPricesViewRepository>,
+    ) -> Self {
+        Self {
+            edh_rec_caller,
+            card_prices_view_repository,
+        }
+    }
+}
+
+#[async_trait]
+impl GetCommanderSynergyUseCase for CommanderSynergyService {
+    async fn get_commander_synergy(
+        &self,
+        user_id: &UserId,
+        commander: &str,
+    ) -> Result<CommanderSynergyReport, AppError> {
+        let Some(recommendations) = self
+            .edh_rec_caller
+            .get_commander_recommendations(commander.to_string())
+            .await?
+        else {
+            return Err(FunctionalError::CommanderNotFound.into());
+        };
+
+        let names: Vec<String> = recommendations
+            .iter()
+            .map(|r| r.name.to_lowercase())
+            .collect();
+
+        let printings = self
+            .card_prices_view_repository
+            .get_owned_printings_by_names(user_id, names.clone())
+            .await?;
+        let owner_counts = self
+            .card_prices_view_repository
+            .count_other_owners_by_names(user_id, names)
+            .await?;
+
+        Ok(CommanderSynergyReport::build(
+            commander.to_string(),
+            recommendations,
+            printings,
+            &owner_counts,
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::application::caller::MockEdhRecCaller;
+    use crate::application::error::InfraError;
+    use crate::application::repository::MockCardPricesViewRepository;
+    use crate::domain::card::CardId;
+    use crate::domain::commander_synergy::{
+        CommanderRecommendation, OwnedPrinting, RecommendationList,
+    };
+    use crate::domain::language_code::LanguageCode;
+    use crate::domain::price::Price;
+    use std::collections::HashMap;
+    use uuid::Uuid;
+
+    fn recommendation(name: &str) -> CommanderRecommendation {
+        CommanderRecommendation {
+            name: name.to_string(),
+            list: RecommendationList::HighSynergy,
+            synergy: 0.4,
+            inclusion: 500,
+            potential_decks: 1000,
+        }
+    }
+
+    #[tokio::test]
+    async fn builds_report_from_recommendations_and_collections() {
+        let mut mock_caller = MockEdhRecCaller::new();
+        mock_caller
+            .expect_get_commander_recommendations()
+            .withf(|name| name == "Atraxa, Praetors' Voice")
+            .returning(|_| {
+                Box::pin(async {
+                    Ok(Some(vec![
+                        recommendation("Sol Ring"),
+                        recommendation("Doubling Season"),
+                    ]))
+                })
+            });
+
+        let mut mock_repo = MockCardPricesViewRepository::new();
+        mock_repo
+            .expect_get_owned_printings_by_names()
+            .withf(|user_id, names| {
+                user_id.as_str() == "user-1"
+                    && names == &vec!["sol ring".to_string(), "doubling season".to_string()]
+            })
+            .returning(|_, _| {
+                Box::pin(async {
+                    Ok(vec![OwnedPrinting {
+                        card_id: CardId::new("FDN", "1", LanguageCode::EN, false),
+                        name: "Sol Ring".to_string(),
+                        scryfall_id: Uuid::new_v4(),
+                        quantity: 1,
+                        trend: Price { value: Some(150) },
+                    }])
+                })
+            });
+        mock_repo
+            .expect_count_other_owners_by_names()
+            .returning(|_, _| {
+                Box::pin(async { Ok(HashMap::from([("doubling season".to_string(), 2)])) })
+            });
+
+        let service = CommanderSynergyService::new(Arc::new(mock_caller), Arc::new(mock_repo));
+        let report = service
+            .get_commander_synergy(&UserId::new("user-1"), "Atraxa, Praetors' Voice")
+            .await
+            .unwrap();
+
+        assert_eq!(report.commander, "Atraxa, Praetors' Voice");
+        assert_eq!(report.owned.len(), 1);
+        assert_eq!(report.owned[0].recommendation.name, "Sol Ring");
+        assert_eq!(report.missing.len(), 1);
+        assert_eq!(report.missing[0].recommendation.name, "Doubling Season");
+        assert_eq!(report.missing[0].owner_count, 2);
+    }
+
+    #[tokio::test]
+    async fn returns_commander_not_found_when_edhrec_has_no_page() {
+        let mut mock_caller = MockEdhRecCaller::new();
+        mock_caller
+            .expect_get_commander_recommendations()
+            .returning(|_| Box::pin(async { Ok(None) }));
+
+        let service = CommanderSynergyService::new(
+            Arc::new(mock_caller),
+            Arc::new(MockCardPricesViewRepository::new()),
+        );
+        let result = service
+            .get_commander_synergy(&UserId::new("user-1"), "Unknown")
+            .await;
+
+        assert!(matches!(
+            result,
+            Err(AppError::Functional(FunctionalError::CommanderNotFound))
+        ));
+    }
+
+    #[tokio::test]
+    async fn propagates_edhrec_call_error() {
+        let mut mock_caller = MockEdhRecCaller::new();
+        mock_caller
+            .expect_get_commander_recommendations()
+            .returning(|_| {
+                Box::pin(async {
+                    Err(InfraError::CallError("edhrec unavailable".to_string()).into())
+                })
+            });
+
+        let service = CommanderSynergyService::new(
+            Arc::new(mock_caller),
+            Arc::new(MockCardPricesViewRepository::new()),
+        );
+        let result = service
+            .get_commander_synergy(&UserId::new("user-1"), "Atraxa, Praetors' Voice")
+            .await;
+
+        assert!(matches!(
+            result,
+            Err(AppError::Infra(InfraError::CallError(_)))
+        ));
+    }
+}
@@ -1,28 +1,23 @@
-use crate::application::error::{AppError, InfraError};
-use crate::application::repository::CardRepository;
+use crate::application::error::AppError;
+use crate::application::repository::{CardRepository, EnrichmentJobRepository};
 use crate::application::use_case::EnqueueGathererIdUpdateUseCase;
-use crate::domain::card::CardId;
+use crate::domain::enrichment_job::EnrichmentKind;
 use async_trait::async_trait;
-use std::collections::HashSet;
-use std::sync::{Arc, Mutex};
-use tokio::sync::mpsc::UnboundedSender;
+use std::sync::Arc;
 
 pub struct GathererIdEnqueueService {
     card_repository: Arc<dyn CardRepository>,
-    sender: UnboundedSender<(CardId, String)>,
-    dedup_set: Arc<Mutex<HashSet<CardId>>>,
+    enrichment_job_repository: Arc<dyn EnrichmentJobRepository>,
 }
 
 impl GathererIdEnqueueService {
     pub fn new(
         card_repository: Arc<dyn CardRepository>,
-        sender: UnboundedSender<(CardId, String)>,
-        dedup_set: Arc<Mutex<HashSet<CardId>>>,
+        enrichment_job_repository: Arc<dyn EnrichmentJobRepository>,
     ) -> Self {
         Self {
             card_repository,
-            sender,
-            dedup_set,
+            enrichment_job_repository,
         }
     }
 }
@@ -30,95 +25,87 @@ impl GathererIdEnqueueService {
 #[async_trait]
 impl EnqueueGathererIdUpdateUseCase for GathererIdEnqueueService {
     async fn enqueue_pending_updates(&self) -> Result<usize, AppError> {
-        let cards = self.card_repository.get_all_without_gatherer_id().await?;
-        let mut enqueued = 0;
-        let mut set = self.dedup_set.lock().unwrap();
-        for (card_id, name) in cards {
-            if set.insert(card_id.clone()) {
-                if self.sender.send((card_id, name)).is_err() {
-                    return Err(InfraError::QueueError(
-                        "Worker channel closed, cannot enqueue card".into(),
-                    )
-                    .into());
-                } else {
-                    enqueued += 1;
-                }
-            }
-        }
-        Ok(enqueued)
+        let card_ids = self
+            .card_repository
+            .get_all_without_gatherer_id()
+            .await?
+            .into_iter()
+            .map(|(card_id, _)| card_id)
+            .collect();
+
+        // Les cartes déjà en file sont ignorées par la contrainte d'unicité de enrichment_job
+        self.enrichment_job_repository
+            .enqueue(EnrichmentKind::GathererId, card_ids)
+            .await
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::application::repository::MockCardRepository;
+    use crate::application::error::InfraError;
+    use crate::application::repository::{MockCardRepository, MockEnrichmentJobRepository};
+    use crate::domain::card::CardId;
     use crate::domain::language_code::LanguageCode;
     use crate::domain::set_name::SetCode;
-    use tokio::sync::mpsc::unbounded_channel;
+    use mockall::predicate::eq;
 
     fn make_card_id(n: &str) -> CardId {
         CardId::new(SetCode::new("FDN"), n, LanguageCode::FR, false)
     }
 
     #[tokio::test]
-    async fn enqueue_returns_count_of_newly_enqueued_cards() {
+    async fn enqueue_queues_a_gatherer_id_job_per_card() {
         let mut card_repository = MockCardRepository::new();
         card_repository
             .expect_get_all_without_gatherer_id()
             .returning(|| {
                 Box::pin(async {
                     Ok(vec![
-                        (
-                            CardId::new(SetCode::new("FDN"), "0", LanguageCode::FR, false),
-                            "Card A".to_string(),
-                        ),
-                        (
-                            CardId::new(SetCode::new("FDN"), "1", LanguageCode::FR, false),
-                            "Card B".to_string(),
-                        ),
+                        (make_card_id("0"), "Name".to_string()),
+                        (make_card_id("1"), "Name".to_string()),
                     ])
                 })
             });
-
-        let (tx, _rx) = unbounded_channel();
-        let dedup_set = Arc::new(Mutex::new(HashSet::new()));
-        let service = GathererIdEnqueueService::new(Arc::new(card_repository), tx, dedup_set);
-
-        let result = service.enqueue_pending_updates().await;
-        assert!(result.is_ok());
-        assert_eq!(result.unwrap(), 2);
+        let mut job_repository = MockEnrichmentJobRepository::new();
+        job_repository
+            .expect_enqueue()
+            .with(
+                eq(EnrichmentKind::GathererId),
+                eq(vec![make_card_id("0"), make_card_id("1")]),
+            )
+            .times(1)
+            .returning(|_, _| Box::pin(async { Ok(2) }));
+
+        let service =
+            GathererIdEnqueueService::new(Arc::new(card_repository), Arc::new(job_repository));
+
+        assert_eq!(service.enqueue_pending_updates().await.unwrap(), 2);
     }
 
     #[tokio::test]
-    async fn enqueue_deduplicates_already_enqueued_cards() {
+    async fn enqueue_returns_count_of_jobs_actually_created() {
         let mut card_repository = MockCardRepository::new();
         card_repository
             .expect_get_all_without_gatherer_id()
             .returning(|| {
                 Box::pin(async {
                     Ok(vec![
-                        (
-                            CardId::new(SetCode::new("FDN"), "0", LanguageCode::FR, false),
-                            "Card A".to_string(),
-                        ),
-                        (
-                            CardId::new(SetCode::new("FDN"), "1", LanguageCode::FR, false),
-                            "Card B".to_string(),
-                        ),
+                        (make_card_id("0"), "Name".to_string()),
+                        (make_card_id("1"), "Name".to_string()),
                     ])
                 })
             });
+        let mut job_repository = MockEnrichmentJobRepository::new();
+        // "0" est déjà en file → 1 seul job créé
+        job_repository
+            .expect_enqueue()
+            .returning(|_, _| Box::pin(async { Ok(1) }));
 
-        let (tx, _rx) = unbounded_channel();
-        let dedup_set = Arc::new(Mutex::new(HashSet::new()));
-        dedup_set.lock().unwrap().insert(make_card_id("0"));
+        let service =
+            GathererIdEnqueueService::new(Arc::new(card_repository), Arc::new(job_repository));
 
-        let service = GathererIdEnqueueService::new(Arc::new(card_repository), tx, dedup_set);
-
-        let result = service.enqueue_pending_updates().await;
-        assert!(result.is_ok());
-        assert_eq!(result.unwrap(), 1);
+        assert_eq!(service.enqueue_pending_updates().await.unwrap(), 1);
     }
 
     #[tokio::test]
@@ -133,28 +120,12 @@ mod tests {
                     )))
                 })
             });
+        let mut job_repository = MockEnrichmentJobRepository::new();
+        job_repository.expect_enqueue().never();
 
-        let (tx, _rx) = unbounded_channel();
-        let dedup_set = Arc::new(Mutex::new(HashSet::new()));
-        let service = GathererIdEnqueueService::new(Arc::new(card_repository), tx, dedup_set);
-
-        let result = service.enqueue_pending_updates().await;
-        assert!(result.is_err());
-    }
-
-    #[tokio::test]
-    async fn enqueue_returns_zero_for_empty_card_list() {
-        let mut card_repository = MockCardRepository::new();
-        card_repository
-            .expect_get_all_without_gatherer_id()
-            .returning(|| Box::pin(async { Ok(vec![]) }));
-
-        let (tx, _rx) = unbounded_channel();
-        let dedup_set = Arc::new(Mutex::new(HashSet::new()));
-        let service = GathererIdEnqueueService::new(Arc::new(card_repository), tx, dedup_set);
+        let service =
+            GathererIdEnqueueService::new(Arc::new(card_repository), Arc::new(job_repository));
 
-        let result = service.enqueue_pending_updates().await;
-        assert!(result.is_ok());
-        assert_eq!(result.unwrap(), 0);
+        assert!(service.enqueue_pending_updates().await.is_err());
     }
 }
@@ -0,0 +1,92 @@
+use crate::application::error::AppError;
+use crate::application::repository::HttpResponseCacheRepository;
+use crate::application::use_case::HttpCacheUseCase;
+use crate::domain::http_cache::{HttpCacheSource, HttpCacheStats};
+use async_trait::async_trait;
+use std::sync::Arc;
+
+pub struct HttpCacheService {
+    repository: Arc<dyn HttpResponseCacheRepository>,
+}
+
+impl HttpCacheService {
+    pub fn new(repository: Arc<dyn HttpResponseCacheRepository>) -> Self {
+        Self { repository }
+    }
+}
+
+#[async_trait]
+impl HttpCacheUseCase for HttpCacheService {
+    async fn get_stats(&self) -> Result<Vec<HttpCacheStats>, AppError> {
+        let stats = self.repository.get_stats().await?;
+
+        // Les sources sans aucune réponse en cache sont absentes du résultat du repository
+        Ok(HttpCacheSource::ALL
+            .into_iter()
+            .map(|source| {
+                stats
+                    .iter()
+                    .find(|stats| stats.source == source)
+                    .cloned()
+                    .unwrap_or_else(|| HttpCacheStats::empty(source))
+            })
+            .collect())
+    }
+
+    async fn purge(&self, source: Option<HttpCacheSource>) -> Result<u64, AppError> {
+        let purged = self.repository.purge(source).await?;
+        tracing::info!("Purged {} cached HTTP responses", purged);
+        Ok(purged)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::application::repository::MockHttpResponseCacheRepository;
+    use chrono::Utc;
+    use mockall::predicate::eq;
+
+    #[tokio::test]
+    async fn get_stats_lists_every_source_even_without_entries() {
+        let mut repository = MockHttpResponseCacheRepository::new();
+        repository.expect_get_stats().times(1).returning(|| {
+            Box::pin(async {
+                Ok(vec![HttpCacheStats {
+                    source: HttpCacheSource::Gatherer,
+                    entries: 3,
+                    size_bytes: 1024,
+                    oldest_fetched_at: Some(Utc::now()),
+                    newest_fetched_at: Some(Utc::now()),
+                }])
+            })
+        });
+
+        let stats = HttpCacheService::new(Arc::new(repository))
+            .get_stats()
+            .await
+            .unwrap();
+
+        assert_eq!(stats.len(), 3);
+        assert_eq!(stats[0], HttpCacheStats::empty(HttpCacheSource::Scryfall));
+        assert_eq!(stats[1].entries, 3);
+        assert_eq!(stats[2], HttpCacheStats::empty(HttpCacheSource::EdhRec));
+    }
+
+    #[tokio::test]
+    async fn purge_returns_the_number_of_deleted_responses() {
+        let mut repository = MockHttpResponseCacheRepository::new();
+        repository
+            .expect_purge()
+            .with(eq(Some(HttpCacheSource::EdhRec)))
+            .times(1)
+            .returning(|_| Box::pin(async { Ok(12) }));
+
+        let purged = HttpCacheService::new(Arc::new(repository))
+            .purge(Some(HttpCacheSource::EdhRec))
+            .await
+            .unwrap();
+
+        assert_eq!(purged, 12);
+    }
+}
@@ -1,46 +1,60 @@
 use crate::application::error::AppError;
-use crate::application::repository::{CardPricesViewRepository, CardRepository, SetNameRepository};
+use crate::application::repository::{BinderRepository, CardRepository, SetNameRepository};
+use crate::application::service::binder_service::resolve_binder;
 use crate::application::service::parse_service::parse_cards;
 use crate::application::use_case::{
     EnqueueCardMarketIdUpdateUseCase, EnqueueGathererIdUpdateUseCase, ImportCardUseCase,
+    ScheduleCardPricesRefreshUseCase,
 };
+use crate::domain::binder::BinderId;
+use crate::domain::card_prices_refresh::CardPricesRefresh;
 use crate::domain::user::User;
 use async_trait::async_trait;
 use std::sync::Arc;
 
 pub struct ImportCardService {
     card_repository: Arc<dyn CardRepository>,
+    binder_repository: Arc<dyn BinderRepository>,
     set_name_repository: Arc<dyn SetNameRepository>,
     enqueue_cardmarket_ids: Arc<dyn EnqueueCardMarketIdUpdateUseCase>,
     enqueue_gatherer_ids: Arc<dyn EnqueueGathererIdUpdateUseCase>,
-    card_prices_view_repository: Arc<dyn CardPricesViewRepository>,
+    card_prices_refresh: Arc<dyn ScheduleCardPricesRefreshUseCase>,
 }
 
 impl ImportCardService {
     pub fn new(
         card_repository: Arc<dyn CardRepository>,
+        binder_repository: Arc<dyn BinderRepository>,
         set_name_repository: Arc<dyn SetNameRepository>,
         enqueue_cardmarket_ids: Arc<dyn EnqueueCardMarketIdUpdateUseCase>,
         enqueue_gatherer_ids: Arc<dyn EnqueueGathererIdUpdateUseCase>,
-        card_prices_view_repository: Arc<dyn CardPricesViewRepository>,
+        card_prices_refresh: Arc<dyn ScheduleCardPricesRefreshUseCase>,
     ) -> Self {
         Self {
             card_repository,
+            binder_repository,
             set_name_repository,
             enqueue_cardmarket_ids,
             enqueue_gatherer_ids,
-            card_prices_view_repository,
+            card_prices_refresh,
         }
     }
 }
 
 #[async_trait]
 impl ImportCardUseCase for ImportCardService {
-    async fn import_cards(&self, csv: &str, user: User) -> Result<(), AppError> {
+    async fn import_cards(
+        &self,
+        csv: &str,
+        user: User,
+        binder_id: Option<BinderId>,
+    ) -> Result<(), AppError> {
         let cards = parse_cards(csv)?;
+        let binder = resolve_binder(self.binder_repository.as_ref(), &user.id, binder_id).await?;
 
-        self.card_repository.delete_all(user.clone()).await?;
-
+        // Les entrées toujours présentes sont mises à jour plutôt que recréées, pour garder ce
+        // qui a été saisi à la main (état, notes, étiquettes, emplacement)
+        let kept = cards.iter().map(|card| card.id.clone()).collect();
         for card in cards {
             if !self
                 .set_name_repository
@@ -51,14 +65,20 @@ impl ImportCardUseCase for ImportCardService {
                 self.set_name_repository.save(set_name).await?;
             }
 
-            self.card_repository.save(user.clone(), card).await?;
+            self.card_repository
+                .save(user.clone(), binder.id, card)
+                .await?;
         }
+        self.card_repository
+            .delete_missing(user.clone(), binder.id, kept)
+            .await?;
 
         self.enqueue_cardmarket_ids
             .enqueue_pending_updates()
             .await?;
         self.enqueue_gatherer_ids.enqueue_pending_updates().await?;
-        self.card_prices_view_repository.refresh().await?;
+        self.card_prices_refresh
+            .request(CardPricesRefresh::User(user.id));
 
         Ok(())
     }
@@ -69,12 +89,15 @@ mod tests {
     use super::*;
     use crate::application::error::InfraError;
     use crate::application::repository::{
-        MockCardPricesViewRepository, MockCardRepository, MockSetNameRepository,
+        MockBinderRepository, MockCardRepository, MockSetNameRepository,
     };
     use crate::application::use_case::{
         MockEnqueueCardMarketIdUpdateUseCase, MockEnqueueGathererIdUpdateUseCase,
+        MockScheduleCardPricesRefreshUseCase,
     };
+    use crate::domain::binder::Binder;
     use crate::domain::card::{Card, CollectionEntry};
+    use crate::domain::error::FunctionalError;
     use crate::domain::language_code::LanguageCode;
     use crate::domain::rarity_code::RarityCode;
     use crate::domain::set_name::{SetCode, SetName};
@@ -82,6 +105,33 @@ mod tests {
     use mockall::predicate::eq;
     use uuid::Uuid;
 
+    fn default_binder_id() -> BinderId {
+        BinderId(Uuid::nil())
+    }
+
+    /// Binder repository resolving the test user's default binder only.
+    fn binder_repository() -> MockBinderRepository {
+        let mut binder_repository = MockBinderRepository::new();
+        binder_repository
+            .expect_find_or_create_default()
+            .returning(|user_id| {
+                let binder = Binder {
+                    id: default_binder_id(),
+                    user_id: user_id.clone(),
+                    name: "Collection".to_string(),
+                    tradable: true,
+                    keep_quantity: 0,
+                    is_default: true,
+                    created_at: Utc::now(),
+                };
+                Box::pin(async move { Ok(binder) })
+            });
+        binder_repository
+            .expect_find_by_id()
+            .returning(|_, _| Box::pin(async { Ok(None) }));
+        binder_repository
+    }
+
     #[tokio::test]
     async fn import_cards_saves_cards_and_set_names_successfully() {
         let mut card_repository = MockCardRepository::new();
@@ -112,13 +162,23 @@ mod tests {
                     .unwrap()
                     .with_timezone(&Utc),
                 reserved: false,
+                binder_id: None,
+                tradable_quantity: None,
+                tags: vec![],
+                notes: None,
+                location: None,
             },
         );
 
         card_repository
-            .expect_delete_all()
-            .with(eq(User::for_testing()))
-            .returning(|_| Box::pin(async { Ok(()) }));
+            .expect_delete_missing()
+            .with(
+                eq(User::for_testing()),
+                eq(default_binder_id()),
+                eq(vec![card.id.clone()]),
+            )
+            .times(1)
+            .returning(|_, _, _| Box::pin(async { Ok(()) }));
         set_name_repository
             .expect_exists_by_code()
             .with(eq(set_code.clone()))
@@ -129,8 +189,12 @@ mod tests {
             .returning(|_| Box::pin(async { Ok(()) }));
         card_repository
             .expect_save()
-            .with(eq(User::for_testing()), eq(card.clone()))
-            .returning(|_, _| Box::pin(async { Ok(()) }));
+            .with(
+                eq(User::for_testing()),
+                eq(default_binder_id()),
+                eq(card.clone()),
+            )
+            .returning(|_, _, _| Box::pin(async { Ok(()) }));
         enqueue_use_case
             .expect_enqueue_pending_updates()
             .returning(|| Box::pin(async { Ok(2) }));
@@ -138,22 +202,25 @@ mod tests {
             .expect_enqueue_pending_updates()
             .returning(|| Box::pin(async { Ok(2) }));
 
-        let mut card_prices_view_repository = MockCardPricesViewRepository::new();
-        card_prices_view_repository
-            .expect_refresh()
-            .returning(|| Box::pin(async { Ok(()) }));
+        let mut card_prices_refresh = MockScheduleCardPricesRefreshUseCase::new();
+        card_prices_refresh
+            .expect_request()
+            .with(eq(CardPricesRefresh::User(User::for_testing().id)))
+            .times(1)
+            .return_const(());
 
         let service = ImportCardService::new(
             Arc::new(card_repository),
+            Arc::new(binder_repository()),
             Arc::new(set_name_repository),
             Arc::new(enqueue_use_case),
             Arc::new(enqueue_gatherer_use_case),
-            Arc::new(card_prices_view_repository),
+            Arc::new(card_prices_refresh),
         );
 
         let csv = "Binder Name,Binder Type,Name,Set code,Set name,Collector number,Foil,Rarity,Quantity,ManaBox ID,Scryfall ID,Purchase price,Misprint,Altered,Condition,Language,Purchase price currency,Added\n\
         bulk,binder,Goblin Boarders,FDN,Foundations,87,normal,common,3,101506,4409a063-bf2a-4a49-803e-3ce6bd474353,0.08,false,false,near_mint,fr,EUR,2026-02-05T20:44:45.815Z";
-        let result = service.import_cards(csv, User::for_testing()).await;
+        let result = service.import_cards(csv, User::for_testing(), None).await;
 
         assert!(result.is_ok());
     }
@@ -186,13 +253,15 @@ mod tests {
                     .unwrap()
                     .with_timezone(&Utc),
                 reserved: false,
+                binder_id: None,
+                tradable_quantity: None,
+                tags: vec![],
+                notes: None,
+                location: None,
             },
         );
 
-        card_repository
-            .expect_delete_all()
-            .with(eq(User::for_testing()))
-            .returning(|_| Box::pin(async { Ok(()) }));
+        card_repository.expect_delete_missing().never();
         set_name_repository
             .expect_exists_by_code()
             .with(eq(set_name.code.clone()))
@@ -203,8 +272,12 @@ mod tests {
             .returning(|_| Box::pin(async { Ok(()) }));
         card_repository
             .expect_save()
-            .with(eq(User::for_testing()), eq(card.clone()))
-            .returning(|_, _| {
+            .with(
+                eq(User::for_testing()),
+                eq(default_binder_id()),
+                eq(card.clone()),
+            )
+            .returning(|_, _, _| {
                 Box::pin(async {
                     Err(AppError::Infra(InfraError::RepositoryError(
                         "Save failed".to_string(),
@@ -214,19 +287,20 @@ mod tests {
 
         let mock_enqueue = MockEnqueueCardMarketIdUpdateUseCase::new();
         let mock_enqueue_gatherer = MockEnqueueGathererIdUpdateUseCase::new();
-        let card_prices_view_repository = MockCardPricesViewRepository::new();
+        let card_prices_refresh = MockScheduleCardPricesRefreshUseCase::new();
 
         let service = ImportCardService::new(
             Arc::new(card_repository),
+            Arc::new(binder_repository()),
             Arc::new(set_name_repository),
             Arc::new(mock_enqueue),
             Arc::new(mock_enqueue_gatherer),
-            Arc::new(card_prices_view_repository),
+            Arc::new(card_prices_refresh),
         );
 
         let csv = "Binder Name,Binder Type,Name,Set code,Set name,Collector number,Foil,Rarity,Quantity,ManaBox ID,Scryfall ID,Purchase price,Misprint,Altered,Condition,Language,Purchase price currency,Added\n\
         bulk,binder,Goblin Boarders,FDN,Foundations,0,normal,common,0,101506,4409a063-bf2a-4a49-803e-3ce6bd474353,0.00,false,false,near_mint,fr,EUR,2026-02-05T20:44:45.815Z";
-        let result = service.import_cards(csv, User::for_testing()).await;
+        let result = service.import_cards(csv, User::for_testing(), None).await;
 
         assert!(result.is_err());
     }
@@ -257,20 +331,29 @@ mod tests {
                     .unwrap()
                     .with_timezone(&Utc),
                 reserved: false,
+                binder_id: None,
+                tradable_quantity: None,
+                tags: vec![],
+                notes: None,
+                location: None,
             },
         );
 
         card_repository
-            .expect_delete_all()
-            .returning(|_| Box::pin(async { Ok(()) }));
+            .expect_delete_missing()
+            .returning(|_, _, _| Box::pin(async { Ok(()) }));
         set_name_repository
             .expect_exists_by_code()
             .with(eq(set_code.clone()))
             .returning(|_| Box::pin(async { Ok(true) }));
         card_repository
             .expect_save()
-            .with(eq(User::for_testing()), eq(card.clone()))
-            .returning(|_, _| Box::pin(async { Ok(()) }));
+            .with(
+                eq(User::for_testing()),
+                eq(default_binder_id()),
+                eq(card.clone()),
+            )
+            .returning(|_, _, _| Box::pin(async { Ok(()) }));
         enqueue_use_case
             .expect_enqueue_pending_updates()
             .returning(|| Box::pin(async { Ok(1) }));
@@ -278,22 +361,25 @@ mod tests {
             .expect_enqueue_pending_updates()
             .returning(|| Box::pin(async { Ok(1) }));
 
-        let mut card_prices_view_repository = MockCardPricesViewRepository::new();
-        card_prices_view_repository
-            .expect_refresh()
-            .returning(|| Box::pin(async { Ok(()) }));
+        let mut card_prices_refresh = MockScheduleCardPricesRefreshUseCase::new();
+        card_prices_refresh
+            .expect_request()
+            .with(eq(CardPricesRefresh::User(User::for_testing().id)))
+            .times(1)
+            .return_const(());
 
         let service = ImportCardService::new(
             Arc::new(card_repository),
+            Arc::new(binder_repository()),
             Arc::new(set_name_repository),
             Arc::new(enqueue_use_case),
             Arc::new(enqueue_gatherer_use_case),
-            Arc::new(card_prices_view_repository),
+            Arc::new(card_prices_refresh),
         );
 
         let csv = "Binder Name,Binder Type,Name,Set code,Set name,Collector number,Foil,Rarity,Quantity,ManaBox ID,Scryfall ID,Purchase price,Misprint,Altered,Condition,Language,Purchase price currency,Added\n\
         bulk,binder,Goblin Boarders,FDN,Foundations,87,normal,common,3,101506,4409a063-bf2a-4a49-803e-3ce6bd474353,0.08,false,false,near_mint,fr,EUR,2026-02-05T20:44:45.815Z";
-        let result = service.import_cards(csv, User::for_testing()).await;
+        let result = service.import_cards(csv, User::for_testing(), None).await;
 
         assert!(result.is_ok());
     }
@@ -304,19 +390,49 @@ mod tests {
         let set_name_repository = MockSetNameRepository::new();
         let mock_enqueue = MockEnqueueCardMarketIdUpdateUseCase::new();
         let mock_enqueue_gatherer = MockEnqueueGathererIdUpdateUseCase::new();
-        let card_prices_view_repository = MockCardPricesViewRepository::new();
+        let card_prices_refresh = MockScheduleCardPricesRefreshUseCase::new();
 
         let service = ImportCardService::new(
             Arc::new(card_repository),
+            Arc::new(binder_repository()),
             Arc::new(set_name_repository),
             Arc::new(mock_enqueue),
             Arc::new(mock_enqueue_gatherer),
-            Arc::new(card_prices_view_repository),
+            Arc::new(card_prices_refresh),
         );
 
         let invalid_csv = "Invalid,Data";
-        let result = service.import_cards(invalid_csv, User::for_testing()).await;
+        let result = service
+            .import_cards(invalid_csv, User::for_testing(), None)
+            .await;
 
         assert!(result.is_err());
     }
+
+    #[tokio::test]
+    async fn import_cards_into_an_unknown_binder_keeps_the_collection() {
+        let mut card_repository = MockCardRepository::new();
+        card_repository.expect_delete_missing().never();
+        card_repository.expect_save().never();
+
+        let service = ImportCardService::new(
+            Arc::new(card_repository),
+            Arc::new(binder_repository()),
+            Arc::new(MockSetNameRepository::new()),
+            Arc::new(MockEnqueueCardMarketIdUpdateUseCase::new()),
+            Arc::new(MockEnqueueGathererIdUpdateUseCase::new()),
+            Arc::new(MockScheduleCardPricesRefreshUseCase::new()),
+        );
+
+        let csv = "Binder Name,Binder Type,Name,Set code,Set name,Collector number,Foil,Rarity,Quantity,ManaBox ID,Scryfall ID,Purchase price,Misprint,Altered,Condition,Language,Purchase price currency,Added\n\
+        bulk,binder,Goblin Boarders,FDN,Foundations,87,normal,common,3,101506,4409a063-bf2a-4a49-803e-3ce6bd474353,0.08,false,false,near_mint,fr,EUR,2026-02-05T20:44:45.815Z";
+        let result = service
+            .import_cards(csv, User::for_testing(), Some(BinderId::new()))
+            .await;
+
+        assert!(matches!(
+            result,
+            Err(AppError::Functional(FunctionalError::BinderNotFound))
+        ));
+    }
 }
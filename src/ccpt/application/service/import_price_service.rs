@@ -1,45 +1,525 @@
 use crate::application::caller::CardMarketCaller;
 use crate::application::error::AppError;
-use crate::application::repository::{CardMarketPriceRepository, CardPricesViewRepository};
-use crate::application::use_case::{CardCollectionPriceCalculationUseCase, ImportPriceUseCase};
+use crate::application::repository::CardMarketPriceRepository;
+use crate::application::use_case::{
+    CardCollectionPriceCalculationUseCase, ImportPriceUseCase, ScheduleCardPricesRefreshUseCase,
+};
+use crate::domain::card_prices_refresh::CardPricesRefresh;
+use crate::domain::error::FunctionalError;
+use crate::domain::price::{FullPriceGuide, PriceGuideChunk, PriceImportScope};
+use crate::domain::price_anomaly::PriceAnomaly;
 use async_trait::async_trait;
+use chrono::NaiveDate;
+use std::collections::{HashMap, HashSet};
 use std::sync::Arc;
+use tokio::sync::mpsc::{Receiver, channel};
+
+/// Chunks waiting to be staged while the download goes on
+const CHUNK_BUFFER: usize = 4;
 
 pub struct ImportPriceService {
     cardmarket_caller: Arc<dyn CardMarketCaller>,
     cardmarket_repository: Arc<dyn CardMarketPriceRepository>,
-    card_prices_view_repository: Arc<dyn CardPricesViewRepository>,
+    card_prices_refresh: Arc<dyn ScheduleCardPricesRefreshUseCase>,
     price_calculation: Arc<dyn CardCollectionPriceCalculationUseCase>,
+    anomaly_window_days: u32,
+    anomaly_max_ratio: u32,
+    scope: PriceImportScope,
 }
 
 impl ImportPriceService {
+    #[allow(clippy::too_many_arguments)]
     pub fn new(
         cardmarket_caller: Arc<dyn CardMarketCaller>,
         cardmarket_repository: Arc<dyn CardMarketPriceRepository>,
-        card_prices_view_repository: Arc<dyn CardPricesViewRepository>,
+        card_prices_refresh: Arc<dyn ScheduleCardPricesRefreshUseCase>,
         price_calculation: Arc<dyn CardCollectionPriceCalculationUseCase>,
+        anomaly_window_days: u32,
+        anomaly_max_ratio: u32,
+        scope: PriceImportScope,
     ) -> Self {
         Self {
             cardmarket_caller,
             cardmarket_repository,
-            card_prices_view_repository,
+            card_prices_refresh,
             price_calculation,
+            anomaly_window_days,
+            anomaly_max_ratio,
+            scope,
+        }
+    }
+
+    /// Keeps the price guides that will be published with the configured scope.
+    async fn in_scope<'a>(
+        &self,
+        price_guides: &'a [FullPriceGuide],
+    ) -> Result<Vec<&'a FullPriceGuide>, AppError> {
+        match self.scope {
+            PriceImportScope::All => Ok(price_guides.iter().collect()),
+            PriceImportScope::Singles => Ok(price_guides
+                .iter()
+                .filter(|guide| guide.is_single())
+                .collect()),
+            PriceImportScope::Referenced => {
+                let referenced: HashSet<u32> = self
+                    .cardmarket_repository
+                    .find_referenced_products(
+                        price_guides.iter().map(|guide| guide.id_product).collect(),
+                    )
+                    .await?
+                    .into_iter()
+                    .collect();
+
+                Ok(price_guides
+                    .iter()
+                    .filter(|guide| referenced.contains(&guide.id_product))
+                    .collect())
+            }
+        }
+    }
+
+    /// Compares every trend with the median of the previous days.
+    async fn detect_anomalies(
+        &self,
+        date: NaiveDate,
+        price_guides: &[&FullPriceGuide],
+    ) -> Result<Vec<PriceAnomaly>, AppError> {
+        if price_guides.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let id_products = price_guides.iter().map(|guide| guide.id_product).collect();
+        let references: HashMap<u32, _> = self
+            .cardmarket_repository
+            .find_reference_trends(date, self.anomaly_window_days, id_products)
+            .await?
+            .into_iter()
+            .map(|reference| (reference.id_product, reference))
+            .collect();
+
+        Ok(price_guides
+            .iter()
+            .filter_map(|guide| {
+                references.get(&guide.id_product).map(|reference| {
+                    PriceAnomaly::detect(date, guide, reference, self.anomaly_max_ratio)
+                })
+            })
+            .flatten()
+            .collect())
+    }
+
+    /// Stages the chunks as they are downloaded. Only the products that will be published are
+    /// checked: an anomaly references the published price.
+    async fn stage_chunks(
+        &self,
+        mut receiver: Receiver<PriceGuideChunk>,
+    ) -> Result<StagedPrices, AppError> {
+        let mut staged = StagedPrices::default();
+
+        while let Some(chunk) = receiver.recv().await {
+            let price_guides = self.in_scope(&chunk.price_guides).await?;
+            staged
+                .anomalies
+                .extend(self.detect_anomalies(chunk.date, &price_guides).await?);
+            staged
+                .published_products
+                .extend(price_guides.iter().map(|guide| guide.id_product));
+
+            staged.price_count += chunk.price_guides.len();
+            self.cardmarket_repository.stage(chunk.price_guides).await?;
         }
+
+        Ok(staged)
+    }
+
+    /// Downloads the price guide of the day, stages it and publishes it.
+    async fn import_prices(&self) -> Result<(), AppError> {
+        // Reste d'un import interrompu sans avoir pu être nettoyé
+        self.cardmarket_repository.discard_staged().await?;
+
+        let (sender, receiver) = channel(CHUNK_BUFFER);
+        let (download, staging) = tokio::join!(
+            self.cardmarket_caller.stream_price_guides(sender),
+            self.stage_chunks(receiver),
+        );
+
+        // Un échec du staging ferme le canal : son erreur est plus parlante que celle du téléchargement
+        let (date, staged) = match (download, staging) {
+            (Ok(date), Ok(staged)) => (date, staged),
+            (_, Err(e)) | (Err(e), _) => {
+                self.cardmarket_repository.discard_staged().await?;
+                return Err(e);
+            }
+        };
+        let anomaly_count = staged.anomalies.len();
+        let price_count = staged.price_count;
+
+        self.cardmarket_repository
+            .publish_staged(date, self.scope, staged.anomalies)
+            .await?;
+        self.card_prices_refresh
+            .request(CardPricesRefresh::Products(staged.published_products));
+        self.price_calculation.calculate_total_price().await?;
+
+        if anomaly_count > 0 {
+            tracing::warn!(anomaly_count, %date, "Suspicious prices quarantined");
+        }
+        tracing::info!(price_count, %date, "Prices imported successfully");
+
+        Ok(())
     }
 }
 
+#[derive(Default)]
+struct StagedPrices {
+    price_count: usize,
+    /// Products kept by the import scope, whose cards get a new price
+    published_products: Vec<u32>,
+    anomalies: Vec<PriceAnomaly>,
+}
+
 #[async_trait]
 impl ImportPriceUseCase for ImportPriceService {
     #[tracing::instrument(skip(self))]
     async fn import_prices_for_current_date(&self) -> Result<(), AppError> {
-        let (date, price_guides) = self.cardmarket_caller.get_price_guides().await?;
-        let price_count = price_guides.len();
-        self.cardmarket_repository.save(date, price_guides).await?;
-        self.card_prices_view_repository.refresh().await?;
-        self.price_calculation.calculate_total_price().await?;
+        // La zone de transit est unique : deux imports simultanés y mélangeraient leurs guides
+        if !self.cardmarket_repository.lock_staging().await? {
+            return Err(FunctionalError::PriceImportInProgress.into());
+        }
 
-        tracing::info!(price_count, %date, "Prices imported successfully");
+        let imported = self.import_prices().await;
+        let unlocked = self.cardmarket_repository.unlock_staging().await;
+        imported.and(unlocked)
+    }
+}
 
-        Ok(())
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::application::caller::MockCardMarketCaller;
+    use crate::application::error::InfraError;
+    use crate::application::repository::MockCardMarketPriceRepository;
+    use crate::application::use_case::{
+        MockCardCollectionPriceCalculationUseCase, MockScheduleCardPricesRefreshUseCase,
+    };
+    use crate::domain::price::{Price, PriceGuide, SINGLES_CATEGORY};
+    use crate::domain::price_anomaly::{PriceAnomalyKind, ReferencePrice};
+    use mockall::predicate::eq;
+
+    const SEALED_CATEGORY: u32 = 6;
+
+    fn date() -> NaiveDate {
+        NaiveDate::from_ymd_opt(2025, 3, 1).unwrap()
+    }
+
+    fn guide(id_product: u32, trend: u32) -> FullPriceGuide {
+        FullPriceGuide {
+            id_product,
+            id_category: Some(SINGLES_CATEGORY),
+            normal: PriceGuide::new(Price::empty(), Price::from_cents(trend), Price::empty()),
+            foil: PriceGuide::new(Price::empty(), Price::empty(), Price::empty()),
+        }
+    }
+
+    fn sealed_guide(id_product: u32, trend: u32) -> FullPriceGuide {
+        FullPriceGuide {
+            id_product,
+            id_category: Some(SEALED_CATEGORY),
+            normal: PriceGuide::new(Price::empty(), Price::from_cents(trend), Price::empty()),
+            foil: PriceGuide::new(Price::empty(), Price::empty(), Price::empty()),
+        }
+    }
+
+    fn reference(id_product: u32, trend: u32) -> ReferencePrice {
+        ReferencePrice {
+            id_product,
+            normal_trend: Price::from_cents(trend),
+            foil_trend: Price::empty(),
+        }
+    }
+
+    fn expect_staging_lock(repository: &mut MockCardMarketPriceRepository) {
+        repository
+            .expect_lock_staging()
+            .times(1)
+            .returning(|| Box::pin(async { Ok(true) }));
+        repository
+            .expect_unlock_staging()
+            .times(1)
+            .returning(|| Box::pin(async { Ok(()) }));
+    }
+
+    fn service(
+        scope: PriceImportScope,
+        published_products: Vec<u32>,
+        references: Vec<ReferencePrice>,
+        expected_anomalies: fn(&Vec<PriceAnomaly>) -> bool,
+    ) -> ImportPriceService {
+        let mut caller = MockCardMarketCaller::new();
+        caller
+            .expect_stream_price_guides()
+            .times(1)
+            .returning(|sender| {
+                Box::pin(async move {
+                    sender
+                        .send(PriceGuideChunk {
+                            date: date(),
+                            price_guides: vec![
+                                guide(1, 1000),
+                                guide(2, 100_000),
+                                sealed_guide(3, 100_000),
+                            ],
+                        })
+                        .await
+                        .unwrap();
+                    Ok(date())
+                })
+            });
+        let mut repository = MockCardMarketPriceRepository::new();
+        expect_staging_lock(&mut repository);
+        repository
+            .expect_discard_staged()
+            .times(1)
+            .returning(|| Box::pin(async { Ok(()) }));
+        repository
+            .expect_find_referenced_products()
+            .returning(|_| Box::pin(async { Ok(vec![1, 3]) }));
+        repository
+            .expect_find_reference_trends()
+            .with(eq(date()), eq(7), eq(published_products.clone()))
+            .times(1)
+            .returning(move |_, _, _| {
+                let references = references.clone();
+                Box::pin(async move { Ok(references) })
+            });
+        repository
+            .expect_stage()
+            .withf(|price_guides| price_guides.len() == 3)
+            .times(1)
+            .returning(|_| Box::pin(async { Ok(()) }));
+        repository
+            .expect_publish_staged()
+            .withf(move |date_arg, scope_arg, anomalies| {
+                *date_arg == date() && *scope_arg == scope && expected_anomalies(anomalies)
+            })
+            .times(1)
+            .returning(|_, _, _| Box::pin(async { Ok(()) }));
+        let mut refresh = MockScheduleCardPricesRefreshUseCase::new();
+        refresh
+            .expect_request()
+            .with(eq(CardPricesRefresh::Products(published_products)))
+            .times(1)
+            .return_const(());
+        let mut calculation = MockCardCollectionPriceCalculationUseCase::new();
+        calculation
+            .expect_calculate_total_price()
+            .times(1)
+            .returning(|| Box::pin(async { Ok(()) }));
+
+        ImportPriceService::new(
+            Arc::new(caller),
+            Arc::new(repository),
+            Arc::new(refresh),
+            Arc::new(calculation),
+            7,
+            5,
+            scope,
+        )
+    }
+
+    #[tokio::test]
+    async fn import_quarantines_prices_far_from_their_reference() {
+        let service = service(
+            PriceImportScope::All,
+            vec![1, 2, 3],
+            vec![reference(1, 1100), reference(2, 1200)],
+            |anomalies| {
+                anomalies.len() == 1
+                    && anomalies[0].id_product == 2
+                    && anomalies[0].kind == PriceAnomalyKind::Spike
+                    && anomalies[0].reference == 1200
+            },
+        );
+
+        assert!(service.import_prices_for_current_date().await.is_ok());
+    }
+
+    #[tokio::test]
+    async fn import_does_not_flag_products_without_history() {
+        let service = service(PriceImportScope::All, vec![1, 2, 3], vec![], |anomalies| {
+            anomalies.is_empty()
+        });
+
+        assert!(service.import_prices_for_current_date().await.is_ok());
+    }
+
+    #[tokio::test]
+    async fn import_only_checks_singles_with_singles_scope() {
+        let service = service(
+            PriceImportScope::Singles,
+            vec![1, 2],
+            vec![reference(2, 1200), reference(3, 1200)],
+            |anomalies| anomalies.len() == 1 && anomalies[0].id_product == 2,
+        );
+
+        assert!(service.import_prices_for_current_date().await.is_ok());
+    }
+
+    #[tokio::test]
+    async fn import_only_checks_referenced_products_with_referenced_scope() {
+        let service = service(
+            PriceImportScope::Referenced,
+            vec![1, 3],
+            vec![reference(2, 1200), reference(3, 1200)],
+            |anomalies| anomalies.len() == 1 && anomalies[0].id_product == 3,
+        );
+
+        assert!(service.import_prices_for_current_date().await.is_ok());
+    }
+
+    #[tokio::test]
+    async fn import_discards_staged_prices_when_download_fails() {
+        let mut caller = MockCardMarketCaller::new();
+        caller
+            .expect_stream_price_guides()
+            .times(1)
+            .returning(|sender| {
+                Box::pin(async move {
+                    sender
+                        .send(PriceGuideChunk {
+                            date: date(),
+                            price_guides: vec![guide(1, 1000)],
+                        })
+                        .await
+                        .unwrap();
+                    Err(AppError::Infra(InfraError::CallError(
+                        "connection reset".to_string(),
+                    )))
+                })
+            });
+        let mut repository = MockCardMarketPriceRepository::new();
+        expect_staging_lock(&mut repository);
+        repository
+            .expect_discard_staged()
+            .times(2)
+            .returning(|| Box::pin(async { Ok(()) }));
+        repository
+            .expect_find_reference_trends()
+            .returning(|_, _, _| Box::pin(async { Ok(vec![]) }));
+        repository
+            .expect_stage()
+            .times(1)
+            .returning(|_| Box::pin(async { Ok(()) }));
+        repository.expect_publish_staged().never();
+        let mut refresh = MockScheduleCardPricesRefreshUseCase::new();
+        refresh.expect_request().never();
+        let mut calculation = MockCardCollectionPriceCalculationUseCase::new();
+        calculation.expect_calculate_total_price().never();
+
+        let service = ImportPriceService::new(
+            Arc::new(caller),
+            Arc::new(repository),
+            Arc::new(refresh),
+            Arc::new(calculation),
+            7,
+            5,
+            PriceImportScope::All,
+        );
+
+        let result = service.import_prices_for_current_date().await;
+
+        assert!(matches!(
+            result,
+            Err(AppError::Infra(InfraError::CallError(_)))
+        ));
+    }
+
+    #[tokio::test]
+    async fn import_reports_staging_error_over_interrupted_download() {
+        let mut caller = MockCardMarketCaller::new();
+        caller
+            .expect_stream_price_guides()
+            .times(1)
+            .returning(|sender| {
+                Box::pin(async move {
+                    for id_product in 1..=10 {
+                        let chunk = PriceGuideChunk {
+                            date: date(),
+                            price_guides: vec![guide(id_product, 1000)],
+                        };
+                        if sender.send(chunk).await.is_err() {
+                            return Err(AppError::Infra(InfraError::CallError(
+                                "receiver dropped".to_string(),
+                            )));
+                        }
+                    }
+                    Ok(date())
+                })
+            });
+        let mut repository = MockCardMarketPriceRepository::new();
+        expect_staging_lock(&mut repository);
+        repository
+            .expect_discard_staged()
+            .times(2)
+            .returning(|| Box::pin(async { Ok(()) }));
+        repository
+            .expect_find_reference_trends()
+            .returning(|_, _, _| Box::pin(async { Ok(vec![]) }));
+        repository.expect_stage().times(1).returning(|_| {
+            Box::pin(async {
+                Err(AppError::Infra(InfraError::RepositoryError(
+                    "disk full".to_string(),
+                )))
+            })
+        });
+        repository.expect_publish_staged().never();
+
+        let service = ImportPriceService::new(
+            Arc::new(caller),
+            Arc::new(repository),
+            Arc::new(MockScheduleCardPricesRefreshUseCase::new()),
+            Arc::new(MockCardCollectionPriceCalculationUseCase::new()),
+            7,
+            5,
+            PriceImportScope::All,
+        );
+
+        let result = service.import_prices_for_current_date().await;
+
+        assert!(matches!(
+            result,
+            Err(AppError::Infra(InfraError::RepositoryError(_)))
+        ));
+    }
+
+    #[tokio::test]
+    async fn import_is_refused_while_another_import_holds_the_staging_lock() {
+        let mut caller = MockCardMarketCaller::new();
+        caller.expect_stream_price_guides().never();
+        let mut repository = MockCardMarketPriceRepository::new();
+        repository
+            .expect_lock_staging()
+            .times(1)
+            .returning(|| Box::pin(async { Ok(false) }));
+        repository.expect_unlock_staging().never();
+        repository.expect_discard_staged().never();
+        repository.expect_publish_staged().never();
+
+        let service = ImportPriceService::new(
+            Arc::new(caller),
+            Arc::new(repository),
+            Arc::new(MockScheduleCardPricesRefreshUseCase::new()),
+            Arc::new(MockCardCollectionPriceCalculationUseCase::new()),
+            7,
+            5,
+            PriceImportScope::All,
+        );
+
+        let result = service.import_prices_for_current_date().await;
+
+        assert!(matches!(
+            result,
+            Err(AppError::Functional(FunctionalError::PriceImportInProgress))
+        ));
     }
 }
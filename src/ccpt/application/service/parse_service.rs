@@ -147,6 +147,11 @@ pub fn parse_cards(csv: &str) -> Result<Vec<Card>, AppError> {
                 purchase_price,
                 added_at,
                 reserved: false,
+                binder_id: None,
+                tradable_quantity: None,
+                tags: vec![],
+                notes: None,
+                location: None,
             },
         );
         cards.push(card);
@@ -185,6 +190,11 @@ pub fn parse_cards(csv: &str) -> Result<Vec<Card>, AppError> {
                 purchase_price: total_cost / new_qty,
                 added_at,
                 reserved: false,
+                binder_id: None,
+                tradable_quantity: None,
+                tags: vec![],
+                notes: None,
+                location: None,
             };
         } else {
             order.push(card.id.clone());
@@ -488,6 +498,11 @@ mod tests {
                     .unwrap()
                     .with_timezone(&Utc),
                 reserved: false,
+                binder_id: None,
+                tradable_quantity: None,
+                tags: vec![],
+                notes: None,
+                location: None,
             },
         );
 
@@ -0,0 +1,193 @@
+use crate::application::error::AppError;
+use crate::application::repository::PriceAnomalyRepository;
+use crate::application::use_case::{
+    CardCollectionPriceCalculationUseCase, ReviewPriceAnomalyUseCase,
+    ScheduleCardPricesRefreshUseCase,
+};
+use crate::domain::card_prices_refresh::CardPricesRefresh;
+use crate::domain::error::FunctionalError;
+use crate::domain::price_anomaly::{PriceAnomaly, PriceAnomalyReview};
+use async_trait::async_trait;
+use chrono::NaiveDate;
+use std::sync::Arc;
+
+pub struct PriceAnomalyService {
+    price_anomaly_repository: Arc<dyn PriceAnomalyRepository>,
+    card_prices_refresh: Arc<dyn ScheduleCardPricesRefreshUseCase>,
+    price_calculation: Arc<dyn CardCollectionPriceCalculationUseCase>,
+}
+
+impl PriceAnomalyService {
+    pub fn new(
+        price_anomaly_repository: Arc<dyn PriceAnomalyRepository>,
+        card_prices_refresh: Arc<dyn ScheduleCardPricesRefreshUseCase>,
+        price_calculation: Arc<dyn CardCollectionPriceCalculationUseCase>,
+    ) -> Self {
+        Self {
+            price_anomaly_repository,
+            card_prices_refresh,
+            price_calculation,
+        }
+    }
+}
+
+#[async_trait]
+impl ReviewPriceAnomalyUseCase for PriceAnomalyService {
+    async fn get_pending_anomalies(&self) -> Result<Vec<PriceAnomaly>, AppError> {
+        self.price_anomaly_repository.find_pending().await
+    }
+
+    #[tracing::instrument(skip(self))]
+    async fn confirm(&self, id_product: u32, date: NaiveDate, foil: bool) -> Result<(), AppError> {
+        if !self
+            .price_anomaly_repository
+            .review(id_product, date, foil, PriceAnomalyReview::Confirmed)
+            .await?
+        {
+            return Err(FunctionalError::PriceAnomalyNotFound.into());
+        }
+
+        // Le prix sort de quarantaine : il doit maintenant compter dans les agrégats
+        self.card_prices_refresh
+            .request(CardPricesRefresh::Products(vec![id_product]));
+        self.price_calculation.calculate_total_price().await?;
+
+        Ok(())
+    }
+
+    #[tracing::instrument(skip(self))]
+    async fn reject(&self, id_product: u32, date: NaiveDate, foil: bool) -> Result<(), AppError> {
+        if !self
+            .price_anomaly_repository
+            .review(id_product, date, foil, PriceAnomalyReview::Rejected)
+            .await?
+        {
+            return Err(FunctionalError::PriceAnomalyNotFound.into());
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::application::repository::MockPriceAnomalyRepository;
+    use crate::application::use_case::{
+        MockCardCollectionPriceCalculationUseCase, MockScheduleCardPricesRefreshUseCase,
+    };
+    use mockall::predicate::eq;
+
+    fn date() -> NaiveDate {
+        NaiveDate::from_ymd_opt(2025, 3, 1).unwrap()
+    }
+
+    #[tokio::test]
+    async fn confirm_refreshes_aggregates_when_an_anomaly_was_pending() {
+        let mut repository = MockPriceAnomalyRepository::new();
+        repository
+            .expect_review()
+            .with(
+                eq(42),
+                eq(date()),
+                eq(true),
+                eq(PriceAnomalyReview::Confirmed),
+            )
+            .times(1)
+            .returning(|_, _, _, _| Box::pin(async { Ok(true) }));
+        let mut refresh = MockScheduleCardPricesRefreshUseCase::new();
+        refresh
+            .expect_request()
+            .with(eq(CardPricesRefresh::Products(vec![42])))
+            .times(1)
+            .return_const(());
+        let mut calculation = MockCardCollectionPriceCalculationUseCase::new();
+        calculation
+            .expect_calculate_total_price()
+            .times(1)
+            .returning(|| Box::pin(async { Ok(()) }));
+
+        let service = PriceAnomalyService::new(
+            Arc::new(repository),
+            Arc::new(refresh),
+            Arc::new(calculation),
+        );
+
+        assert!(service.confirm(42, date(), true).await.is_ok());
+    }
+
+    #[tokio::test]
+    async fn confirm_returns_not_found_when_nothing_was_pending() {
+        let mut repository = MockPriceAnomalyRepository::new();
+        repository
+            .expect_review()
+            .times(1)
+            .returning(|_, _, _, _| Box::pin(async { Ok(false) }));
+        let mut refresh = MockScheduleCardPricesRefreshUseCase::new();
+        refresh.expect_request().never();
+        let mut calculation = MockCardCollectionPriceCalculationUseCase::new();
+        calculation.expect_calculate_total_price().never();
+
+        let service = PriceAnomalyService::new(
+            Arc::new(repository),
+            Arc::new(refresh),
+            Arc::new(calculation),
+        );
+
+        let result = service.confirm(42, date(), true).await;
+
+        assert!(matches!(
+            result,
+            Err(AppError::Functional(FunctionalError::PriceAnomalyNotFound))
+        ));
+    }
+
+    #[tokio::test]
+    async fn reject_keeps_aggregates_untouched() {
+        let mut repository = MockPriceAnomalyRepository::new();
+        repository
+            .expect_review()
+            .with(
+                eq(42),
+                eq(date()),
+                eq(true),
+                eq(PriceAnomalyReview::Rejected),
+            )
+            .times(1)
+            .returning(|_, _, _, _| Box::pin(async { Ok(true) }));
+        let mut refresh = MockScheduleCardPricesRefreshUseCase::new();
+        refresh.expect_request().never();
+        let mut calculation = MockCardCollectionPriceCalculationUseCase::new();
+        calculation.expect_calculate_total_price().never();
+
+        let service = PriceAnomalyService::new(
+            Arc::new(repository),
+            Arc::new(refresh),
+            Arc::new(calculation),
+        );
+
+        assert!(service.reject(42, date(), true).await.is_ok());
+    }
+
+    #[tokio::test]
+    async fn reject_returns_not_found_when_nothing_was_pending() {
+        let mut repository = MockPriceAnomalyRepository::new();
+        repository
+            .expect_review()
+            .times(1)
+            .returning(|_, _, _, _| Box::pin(async { Ok(false) }));
+
+        let service = PriceAnomalyService::new(
+            Arc::new(repository),
+            Arc::new(MockScheduleCardPricesRefreshUseCase::new()),
+            Arc::new(MockCardCollectionPriceCalculationUseCase::new()),
+        );
+
+        let result = service.reject(42, date(), true).await;
+
+        assert!(matches!(
+            result,
+            Err(AppError::Functional(FunctionalError::PriceAnomalyNotFound))
+        ));
+    }
+}
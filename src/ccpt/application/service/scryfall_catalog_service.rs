@@ -0,0 +1,300 @@
+use crate::application::caller::ScryfallBulkCaller;
+use crate::application::error::AppError;
+use crate::application::repository::{CardMarketPriceRepository, ScryfallCatalogRepository};
+use crate::application::use_case::{
+    CardCollectionPriceCalculationUseCase, ScheduleCardPricesRefreshUseCase, ScryfallCatalogUseCase,
+};
+use crate::domain::card_prices_refresh::CardPricesRefresh;
+use crate::domain::scryfall_card::{ScryfallCard, ScryfallCatalogImport};
+use async_trait::async_trait;
+use std::sync::Arc;
+use tokio::sync::mpsc::{Receiver, channel};
+
+/// Chunks waiting to be saved while the file is read
+const CHUNK_BUFFER: usize = 4;
+
+pub struct ScryfallCatalogService {
+    scryfall_bulk_caller: Arc<dyn ScryfallBulkCaller>,
+    scryfall_catalog_repository: Arc<dyn ScryfallCatalogRepository>,
+    cardmarket_price_repository: Arc<dyn CardMarketPriceRepository>,
+    card_prices_refresh: Arc<dyn ScheduleCardPricesRefreshUseCase>,
+    price_calculation: Arc<dyn CardCollectionPriceCalculationUseCase>,
+}
+
+impl ScryfallCatalogService {
+    pub fn new(
+        scryfall_bulk_caller: Arc<dyn ScryfallBulkCaller>,
+        scryfall_catalog_repository: Arc<dyn ScryfallCatalogRepository>,
+        cardmarket_price_repository: Arc<dyn CardMarketPriceRepository>,
+        card_prices_refresh: Arc<dyn ScheduleCardPricesRefreshUseCase>,
+        price_calculation: Arc<dyn CardCollectionPriceCalculationUseCase>,
+    ) -> Self {
+        Self {
+            scryfall_bulk_caller,
+            scryfall_catalog_repository,
+            cardmarket_price_repository,
+            card_prices_refresh,
+            price_calculation,
+        }
+    }
+
+    async fn save_chunks(&self, mut receiver: Receiver<Vec<ScryfallCard>>) -> Result<(), AppError> {
+        while let Some(cards) = receiver.recv().await {
+            self.scryfall_catalog_repository.save_all(cards).await?;
+        }
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl ScryfallCatalogUseCase for ScryfallCatalogService {
+    #[tracing::instrument(skip(self))]
+    async fn import_catalog(&self) -> Result<ScryfallCatalogImport, AppError> {
+        let (sender, receiver) = channel(CHUNK_BUFFER);
+        let (read, saving) = tokio::join!(
+            self.scryfall_bulk_caller.stream_default_cards(sender),
+            self.save_chunks(receiver),
+        );
+
+        // Un échec de l'enregistrement ferme le canal : son erreur est plus parlante que celle de la lecture
+        saving?;
+        let card_count = read?;
+        tracing::info!("{} Scryfall cards saved in the catalogue", card_count);
+
+        Ok(ScryfallCatalogImport {
+            card_count,
+            resolved: self.resolve_cardmarket_ids().await?,
+        })
+    }
+
+    async fn resolve_cardmarket_ids(&self) -> Result<usize, AppError> {
+        let synced = self.scryfall_catalog_repository.sync_metadata().await?;
+        if synced > 0 {
+            tracing::info!("{} card metadata updated from the catalogue", synced);
+        }
+
+        let resolved = self
+            .scryfall_catalog_repository
+            .resolve_cardmarket_ids()
+            .await?;
+
+        for (card_id, cardmarket_id) in &resolved {
+            // Le produit a pu être ignoré par l'import des prix tant qu'aucune carte ne le référençait
+            if let Err(e) = self
+                .cardmarket_price_repository
+                .backfill_from_latest_guide(*cardmarket_id)
+                .await
+            {
+                tracing::error!(
+                    "Failed to backfill prices of product {}: {:?}",
+                    cardmarket_id,
+                    e
+                );
+            }
+            self.card_prices_refresh
+                .request(CardPricesRefresh::Card(card_id.clone()));
+        }
+
+        if !resolved.is_empty() {
+            tracing::info!(
+                "{} CardMarket IDs resolved from the catalogue",
+                resolved.len()
+            );
+            if let Err(e) = self.price_calculation.calculate_total_price().await {
+                tracing::error!("Failed to calculate total price: {:?}", e);
+            }
+        }
+
+        Ok(resolved.len())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::application::caller::MockScryfallBulkCaller;
+    use crate::application::error::InfraError;
+    use crate::application::repository::{
+        MockCardMarketPriceRepository, MockScryfallCatalogRepository,
+    };
+    use crate::application::use_case::{
+        MockCardCollectionPriceCalculationUseCase, MockScheduleCardPricesRefreshUseCase,
+    };
+    use crate::domain::card::CardId;
+    use crate::domain::card_metadata::CardMetadata;
+    use crate::domain::language_code::LanguageCode;
+    use crate::domain::set_name::SetCode;
+    use mockall::predicate::eq;
+    use uuid::Uuid;
+
+    fn card_id() -> CardId {
+        CardId::new(SetCode::new("FDN"), "87", LanguageCode::FR, false)
+    }
+
+    fn scryfall_card(cardmarket_id: Option<u32>) -> ScryfallCard {
+        ScryfallCard {
+            scryfall_id: Uuid::new_v4(),
+            name: "Goblin Boarders".to_string(),
+            set_code: "fdn".to_string(),
+            collector_number: "87".to_string(),
+            lang: "fr".to_string(),
+            cardmarket_id,
+            rarity: None,
+            metadata: CardMetadata::default(),
+        }
+    }
+
+    fn bulk_caller(chunks: usize) -> MockScryfallBulkCaller {
+        let mut caller = MockScryfallBulkCaller::new();
+        caller
+            .expect_stream_default_cards()
+            .times(1)
+            .returning(move |sender| {
+                Box::pin(async move {
+                    for _ in 0..chunks {
+                        sender
+                            .send(vec![scryfall_card(Some(1)), scryfall_card(None)])
+                            .await
+                            .unwrap();
+                    }
+                    Ok(chunks * 2)
+                })
+            });
+        caller
+    }
+
+    fn service(
+        bulk_caller: MockScryfallBulkCaller,
+        catalog_repository: MockScryfallCatalogRepository,
+        price_calculation: MockCardCollectionPriceCalculationUseCase,
+        card_prices_refresh: MockScheduleCardPricesRefreshUseCase,
+    ) -> ScryfallCatalogService {
+        let mut price_repository = MockCardMarketPriceRepository::new();
+        price_repository
+            .expect_backfill_from_latest_guide()
+            .returning(|_| Box::pin(async { Ok(()) }));
+
+        ScryfallCatalogService::new(
+            Arc::new(bulk_caller),
+            Arc::new(catalog_repository),
+            Arc::new(price_repository),
+            Arc::new(card_prices_refresh),
+            Arc::new(price_calculation),
+        )
+    }
+
+    #[tokio::test]
+    async fn import_catalog_saves_every_chunk_then_resolves_cardmarket_ids() {
+        let mut catalog_repository = MockScryfallCatalogRepository::new();
+        catalog_repository
+            .expect_save_all()
+            .withf(|cards| cards.len() == 2)
+            .times(3)
+            .returning(|_| Box::pin(async { Ok(()) }));
+        catalog_repository
+            .expect_sync_metadata()
+            .times(1)
+            .returning(|| Box::pin(async { Ok(3) }));
+        catalog_repository
+            .expect_resolve_cardmarket_ids()
+            .times(1)
+            .returning(|| Box::pin(async { Ok(vec![(card_id(), 42)]) }));
+        let mut price_calculation = MockCardCollectionPriceCalculationUseCase::new();
+        price_calculation
+            .expect_calculate_total_price()
+            .times(1)
+            .returning(|| Box::pin(async { Ok(()) }));
+        let mut card_prices_refresh = MockScheduleCardPricesRefreshUseCase::new();
+        card_prices_refresh
+            .expect_request()
+            .with(eq(CardPricesRefresh::Card(card_id())))
+            .times(1)
+            .return_const(());
+
+        let result = service(
+            bulk_caller(3),
+            catalog_repository,
+            price_calculation,
+            card_prices_refresh,
+        )
+        .import_catalog()
+        .await
+        .unwrap();
+
+        assert_eq!(
+            result,
+            ScryfallCatalogImport {
+                card_count: 6,
+                resolved: 1,
+            }
+        );
+    }
+
+    #[tokio::test]
+    async fn import_catalog_does_not_resolve_when_saving_fails() {
+        let mut catalog_repository = MockScryfallCatalogRepository::new();
+        catalog_repository.expect_save_all().returning(|_| {
+            Box::pin(async {
+                Err(AppError::Infra(InfraError::RepositoryError(
+                    "DB error".to_string(),
+                )))
+            })
+        });
+        catalog_repository.expect_sync_metadata().never();
+        catalog_repository.expect_resolve_cardmarket_ids().never();
+        let mut bulk_caller = MockScryfallBulkCaller::new();
+        bulk_caller
+            .expect_stream_default_cards()
+            .returning(|sender| {
+                Box::pin(async move {
+                    for _ in 0..10 {
+                        if sender.send(vec![scryfall_card(Some(1))]).await.is_err() {
+                            return Err(AppError::Infra(InfraError::CallError(
+                                "receiver dropped".to_string(),
+                            )));
+                        }
+                    }
+                    Ok(10)
+                })
+            });
+
+        let result = service(
+            bulk_caller,
+            catalog_repository,
+            MockCardCollectionPriceCalculationUseCase::new(),
+            MockScheduleCardPricesRefreshUseCase::new(),
+        )
+        .import_catalog()
+        .await;
+
+        match result {
+            Err(AppError::Infra(InfraError::RepositoryError(msg))) => assert_eq!(msg, "DB error"),
+            other => panic!("Expected RepositoryError, got {:?}", other),
+        }
+    }
+
+    #[tokio::test]
+    async fn resolve_cardmarket_ids_does_not_recalculate_prices_when_nothing_resolved() {
+        let mut catalog_repository = MockScryfallCatalogRepository::new();
+        catalog_repository
+            .expect_sync_metadata()
+            .returning(|| Box::pin(async { Ok(0) }));
+        catalog_repository
+            .expect_resolve_cardmarket_ids()
+            .returning(|| Box::pin(async { Ok(vec![]) }));
+        let mut price_calculation = MockCardCollectionPriceCalculationUseCase::new();
+        price_calculation.expect_calculate_total_price().never();
+
+        let resolved = service(
+            MockScryfallBulkCaller::new(),
+            catalog_repository,
+            price_calculation,
+            MockScheduleCardPricesRefreshUseCase::new(),
+        )
+        .resolve_cardmarket_ids()
+        .await
+        .unwrap();
+
+        assert_eq!(resolved, 0);
+    }
+}
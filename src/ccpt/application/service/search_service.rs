@@ -2,6 +2,7 @@ use crate::application::error::AppError;
 use crate::application::repository::CardPricesViewRepository;
 use crate::application::use_case::SearchCardsUseCase;
 use crate::domain::collection::{PaginatedCollection, SearchQuery};
+use crate::domain::oracle_card::PaginatedOracleCards;
 use async_trait::async_trait;
 use std::sync::Arc;
 
@@ -20,6 +21,13 @@ impl SearchCardsUseCase for SearchService {
     async fn search_cards(&self, query: SearchQuery) -> Result<PaginatedCollection, AppError> {
         self.repository.search_paginated(query).await
     }
+
+    async fn search_oracle_cards(
+        &self,
+        query: SearchQuery,
+    ) -> Result<PaginatedOracleCards, AppError> {
+        self.repository.search_oracle_paginated(query).await
+    }
 }
 
 #[cfg(test)]
@@ -43,6 +51,12 @@ mod tests {
                 sets: Vec::new(),
                 price_min: None,
                 price_max: None,
+                colors: Vec::new(),
+                type_line: None,
+                oracle_id: None,
+                binder_id: None,
+                tags: Vec::new(),
+                location: None,
             },
             player_username: None,
         };
@@ -119,4 +133,31 @@ mod tests {
         let result = service.search_cards(SearchQuery::default()).await;
         assert!(result.is_err());
     }
+
+    #[tokio::test]
+    async fn search_oracle_cards_delegates_to_repository() {
+        let mut mock_repo = MockCardPricesViewRepository::new();
+        mock_repo
+            .expect_search_oracle_paginated()
+            .withf(|q| q.collection_query.page_size == 20)
+            .returning(|q| {
+                Box::pin(async move {
+                    Ok(PaginatedOracleCards {
+                        items: vec![],
+                        total: 0,
+                        page: q.collection_query.page,
+                        page_size: q.collection_query.page_size,
+                    })
+                })
+            });
+
+        let service = SearchService::new(Arc::new(mock_repo));
+        let result = service
+            .search_oracle_cards(SearchQuery::default())
+            .await
+            .unwrap();
+
+        assert_eq!(result.page_size, 20);
+        assert_eq!(result.total, 0);
+    }
 }
@@ -0,0 +1,158 @@
+use crate::application::date_range::resolve_date_range;
+use crate::application::error::AppError;
+use crate::application::repository::{SetNameRepository, SetPriceIndexRepository};
+use crate::application::use_case::GetSetPriceIndexUseCase;
+use crate::domain::error::FunctionalError;
+use crate::domain::set_name::SetCode;
+use crate::domain::set_price_index::SetPriceIndex;
+use crate::domain::user::UserId;
+use async_trait::async_trait;
+use chrono::NaiveDate;
+use std::sync::Arc;
+
+pub struct SetPriceIndexService {
+    set_name_repository: Arc<dyn SetNameRepository>,
+    set_price_index_repository: Arc<dyn SetPriceIndexRepository>,
+}
+
+impl SetPriceIndexService {
+    pub fn new(
+        set_name_repository: Arc<dyn SetNameRepository>,
+        set_price_index_repository: Arc<dyn SetPriceIndexRepository>,
+    ) -> Self {
+        Self {
+            set_name_repository,
+            set_price_index_repository,
+        }
+    }
+}
+
+#[async_trait]
+impl GetSetPriceIndexUseCase for SetPriceIndexService {
+    async fn get_set_price_index(
+        &self,
+        user_id: &UserId,
+        set_code: SetCode,
+        start_date: Option<NaiveDate>,
+        end_date: Option<NaiveDate>,
+    ) -> Result<SetPriceIndex, AppError> {
+        let (start_date, end_date) = resolve_date_range(start_date, end_date)?;
+
+        let Some(set) = self.set_name_repository.find_by_code(set_code).await? else {
+            return Err(FunctionalError::SetNotFound.into());
+        };
+
+        let entries = self
+            .set_price_index_repository
+            .find_by_set_and_date_range(set.code.clone(), user_id, start_date, end_date)
+            .await?;
+
+        Ok(SetPriceIndex { set, entries })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::application::repository::{MockSetNameRepository, MockSetPriceIndexRepository};
+    use crate::domain::price::Price;
+    use crate::domain::set_name::SetName;
+    use crate::domain::set_price_index::SetPriceIndexEntry;
+
+    fn date(y: i32, m: u32, d: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(y, m, d).unwrap()
+    }
+
+    #[tokio::test]
+    async fn returns_the_index_of_an_existing_set() {
+        let mut set_name_repository = MockSetNameRepository::new();
+        set_name_repository
+            .expect_find_by_code()
+            .withf(|code| *code == SetCode::new("FDN"))
+            .returning(|_| Box::pin(async { Ok(Some(SetName::new("FDN", "Foundations"))) }));
+
+        let mut index_repository = MockSetPriceIndexRepository::new();
+        index_repository
+            .expect_find_by_set_and_date_range()
+            .withf(|code, user_id, start, end| {
+                *code == SetCode::new("FDN")
+                    && user_id == &UserId::new("user1")
+                    && *start == date(2025, 1, 1)
+                    && *end == date(2025, 1, 31)
+            })
+            .times(1)
+            .returning(|_, _, _, _| {
+                Box::pin(async {
+                    Ok(vec![SetPriceIndexEntry {
+                        date: date(2025, 1, 15),
+                        printings: 2,
+                        total: Price { value: Some(1000) },
+                        median: Price { value: Some(500) },
+                        owned: Price { value: Some(400) },
+                    }])
+                })
+            });
+
+        let service =
+            SetPriceIndexService::new(Arc::new(set_name_repository), Arc::new(index_repository));
+
+        let index = service
+            .get_set_price_index(
+                &UserId::new("user1"),
+                SetCode::new("FDN"),
+                Some(date(2025, 1, 1)),
+                Some(date(2025, 1, 31)),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(index.set.name, "Foundations");
+        assert_eq!(index.entries.len(), 1);
+        assert_eq!(index.entries[0].owned.value, Some(400));
+    }
+
+    #[tokio::test]
+    async fn returns_set_not_found_for_an_unknown_set() {
+        let mut set_name_repository = MockSetNameRepository::new();
+        set_name_repository
+            .expect_find_by_code()
+            .returning(|_| Box::pin(async { Ok(None) }));
+
+        let mut index_repository = MockSetPriceIndexRepository::new();
+        index_repository.expect_find_by_set_and_date_range().never();
+
+        let service =
+            SetPriceIndexService::new(Arc::new(set_name_repository), Arc::new(index_repository));
+
+        let result = service
+            .get_set_price_index(&UserId::new("user1"), SetCode::new("XYZ"), None, None)
+            .await;
+
+        assert!(matches!(
+            result,
+            Err(AppError::Functional(FunctionalError::SetNotFound))
+        ));
+    }
+
+    #[tokio::test]
+    async fn returns_error_when_start_after_end() {
+        let service = SetPriceIndexService::new(
+            Arc::new(MockSetNameRepository::new()),
+            Arc::new(MockSetPriceIndexRepository::new()),
+        );
+
+        let result = service
+            .get_set_price_index(
+                &UserId::new("user1"),
+                SetCode::new("FDN"),
+                Some(date(2025, 2, 1)),
+                Some(date(2025, 1, 1)),
+            )
+            .await;
+
+        assert!(matches!(
+            result,
+            Err(AppError::Functional(FunctionalError::WrongFormat(_)))
+        ));
+    }
+}
@@ -0,0 +1,74 @@
+use crate::application::error::AppError;
+use crate::application::repository::TradeMatchRepository;
+use crate::application::use_case::GetTradeMatchesUseCase;
+use crate::domain::trade_match::TradeMatch;
+use crate::domain::user::UserId;
+use async_trait::async_trait;
+use std::sync::Arc;
+
+pub struct TradeMatchService {
+    repository: Arc<dyn TradeMatchRepository>,
+}
+
+impl TradeMatchService {
+    pub fn new(repository: Arc<dyn TradeMatchRepository>) -> Self {
+        Self { repository }
+    }
+}
+
+#[async_trait]
+impl GetTradeMatchesUseCase for TradeMatchService {
+    async fn get_matches(&self, user_id: &UserId) -> Result<Vec<TradeMatch>, AppError> {
+        let mut matches = self.repository.find_mutual(user_id).await?;
+        matches.sort_by(TradeMatch::rank);
+        Ok(matches)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::application::repository::MockTradeMatchRepository;
+    use crate::domain::card::CardId;
+    use crate::domain::language_code::LanguageCode;
+    use crate::domain::trade_match::TradeMatchCard;
+
+    fn trade_match(username: &str, wanted_trend: u32, offered_trend: u32) -> TradeMatch {
+        let card = |trend| TradeMatchCard {
+            card_id: CardId::new("FDN", "87", LanguageCode::FR, false),
+            name: "Goblin Boarders".to_string(),
+            quantity: 1,
+            trend: Some(trend),
+        };
+        TradeMatch {
+            other_user_id: UserId::new(username),
+            other_username: username.to_string(),
+            wanted: vec![card(wanted_trend)],
+            offered: vec![card(offered_trend)],
+        }
+    }
+
+    #[tokio::test]
+    async fn get_matches_ranks_the_best_balanced_first() {
+        let mut repository = MockTradeMatchRepository::new();
+        repository
+            .expect_find_mutual()
+            .withf(|user_id| *user_id == UserId::new("user1"))
+            .returning(|_| {
+                Box::pin(async {
+                    Ok(vec![
+                        trade_match("bob", 1000, 100),
+                        trade_match("alice", 300, 250),
+                    ])
+                })
+            });
+
+        let matches = TradeMatchService::new(Arc::new(repository))
+            .get_matches(&UserId::new("user1"))
+            .await
+            .unwrap();
+
+        assert_eq!(matches[0].other_username, "alice");
+        assert_eq!(matches[1].other_username, "bob");
+    }
+}
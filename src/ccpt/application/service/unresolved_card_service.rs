@@ -0,0 +1,79 @@
+use crate::application::error::AppError;
+use crate::application::repository::EnrichmentJobRepository;
+use crate::application::use_case::GetUnresolvedCardsUseCase;
+use crate::domain::enrichment_job::UnresolvedCard;
+use async_trait::async_trait;
+use std::sync::Arc;
+
+pub struct UnresolvedCardService {
+    repository: Arc<dyn EnrichmentJobRepository>,
+}
+
+impl UnresolvedCardService {
+    pub fn new(repository: Arc<dyn EnrichmentJobRepository>) -> Self {
+        Self { repository }
+    }
+}
+
+#[async_trait]
+impl GetUnresolvedCardsUseCase for UnresolvedCardService {
+    async fn get_unresolved_cards(&self) -> Result<Vec<UnresolvedCard>, AppError> {
+        self.repository.find_unresolved().await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::application::error::InfraError;
+    use crate::application::repository::MockEnrichmentJobRepository;
+    use crate::domain::card::CardId;
+    use crate::domain::enrichment_job::{EnrichmentKind, EnrichmentOutcome};
+    use crate::domain::language_code::LanguageCode;
+    use crate::domain::set_name::SetCode;
+    use chrono::Utc;
+
+    #[tokio::test]
+    async fn get_unresolved_cards_returns_the_cards_of_the_repository() {
+        let mut repository = MockEnrichmentJobRepository::new();
+        repository.expect_find_unresolved().times(1).returning(|| {
+            Box::pin(async {
+                Ok(vec![UnresolvedCard {
+                    card_id: CardId::new(SetCode::new("FDN"), "87", LanguageCode::FR, false),
+                    name: "Goblin Boarders".to_string(),
+                    kind: EnrichmentKind::GathererId,
+                    attempts: 2,
+                    last_attempt_at: Utc::now(),
+                    outcome: EnrichmentOutcome::NotFound("No Gatherer page found".to_string()),
+                    next_attempt_at: None,
+                }])
+            })
+        });
+
+        let cards = UnresolvedCardService::new(Arc::new(repository))
+            .get_unresolved_cards()
+            .await
+            .unwrap();
+
+        assert_eq!(cards.len(), 1);
+        assert_eq!(cards[0].name, "Goblin Boarders");
+    }
+
+    #[tokio::test]
+    async fn get_unresolved_cards_propagates_repository_errors() {
+        let mut repository = MockEnrichmentJobRepository::new();
+        repository.expect_find_unresolved().returning(|| {
+            Box::pin(async {
+                Err(AppError::Infra(InfraError::RepositoryError(
+                    "DB error".to_string(),
+                )))
+            })
+        });
+
+        let result = UnresolvedCardService::new(Arc::new(repository))
+            .get_unresolved_cards()
+            .await;
+
+        assert!(result.is_err());
+    }
+}
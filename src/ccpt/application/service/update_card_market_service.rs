@@ -1,249 +1,785 @@
 use crate::application::caller::ScryfallCaller;
-use crate::application::error::{AppError, InfraError};
-use crate::application::repository::{CardPricesViewRepository, CardRepository};
-use crate::application::use_case::CardCollectionPriceCalculationUseCase;
-use crate::domain::card::CardId;
-use std::collections::HashSet;
-use std::sync::{Arc, Mutex};
-use tokio::sync::mpsc::UnboundedReceiver;
+use crate::application::error::AppError;
+use crate::application::repository::{
+    CardMarketPriceRepository, CardRepository, EnrichmentJobRepository,
+};
+use crate::application::service::worker_monitor_service::WorkerTracker;
+use crate::application::use_case::{
+    CardCollectionPriceCalculationUseCase, ScheduleCardPricesRefreshUseCase,
+};
+use crate::domain::card_prices_refresh::CardPricesRefresh;
+use crate::domain::enrichment_job::{
+    EnrichmentJob, EnrichmentKind, EnrichmentOutcome, EnrichmentRetryPolicy,
+};
+use crate::domain::scryfall_card::ScryfallCard;
+use chrono::Utc;
+use std::collections::{HashMap, HashSet};
+use std::sync::Arc;
+use std::time::Duration;
 use uuid::Uuid;
 
+/// Jobs claimed at once, the most Scryfall resolves in a single call.
+const BATCH_SIZE: u32 = 75;
+/// Time a claimed job stays locked, after which another worker may take it back.
+const JOB_LEASE: Duration = Duration::from_secs(600);
+
 pub struct CardMarketIdWorker {
     card_repository: Arc<dyn CardRepository>,
+    cardmarket_price_repository: Arc<dyn CardMarketPriceRepository>,
+    enrichment_job_repository: Arc<dyn EnrichmentJobRepository>,
     scryfall_caller: Arc<dyn ScryfallCaller>,
     price_calculation: Arc<dyn CardCollectionPriceCalculationUseCase>,
-    card_prices_view_repository: Arc<dyn CardPricesViewRepository>,
-    dedup_set: Arc<Mutex<HashSet<CardId>>>,
+    card_prices_refresh: Arc<dyn ScheduleCardPricesRefreshUseCase>,
+    retry_policy: EnrichmentRetryPolicy,
+    tracker: Arc<WorkerTracker>,
 }
 
 impl CardMarketIdWorker {
+    #[allow(clippy::too_many_arguments)]
     pub fn new(
         card_repository: Arc<dyn CardRepository>,
+        cardmarket_price_repository: Arc<dyn CardMarketPriceRepository>,
+        enrichment_job_repository: Arc<dyn EnrichmentJobRepository>,
         scryfall_caller: Arc<dyn ScryfallCaller>,
         price_calculation: Arc<dyn CardCollectionPriceCalculationUseCase>,
-        card_prices_view_repository: Arc<dyn CardPricesViewRepository>,
-        dedup_set: Arc<Mutex<HashSet<CardId>>>,
+        card_prices_refresh: Arc<dyn ScheduleCardPricesRefreshUseCase>,
+        retry_policy: EnrichmentRetryPolicy,
+        tracker: Arc<WorkerTracker>,
     ) -> Self {
         Self {
             card_repository,
+            cardmarket_price_repository,
+            enrichment_job_repository,
             scryfall_caller,
             price_calculation,
-            card_prices_view_repository,
-            dedup_set,
+            card_prices_refresh,
+            retry_policy,
+            tracker,
         }
     }
 
-    pub async fn run(
-        self,
-        mut receiver: UnboundedReceiver<(CardId, Uuid)>,
-    ) -> Result<(), AppError> {
+    /// Polls the job queue every `poll_interval` once it is drained.
+    pub async fn run(self, poll_interval: Duration) {
         tracing::info!("Card market id updater started.");
 
-        while let Some((card_id, scryfall_id)) = receiver.recv().await {
-            let cardmarket_id = self.scryfall_caller.get_card_market_id(scryfall_id).await;
-            if let Ok(id) = cardmarket_id {
-                if let Err(e) = self
-                    .card_repository
-                    .update_cardmarket_id(card_id.clone(), id)
-                    .await
-                {
-                    tracing::error!("Failed to update card with CardMarket ID: {:?}", e);
-                } else {
-                    tracing::info!("{} -> {:?}", card_id, id);
-                }
-            } else if let Err(e) = cardmarket_id {
-                tracing::error!(
-                    "Failed to fetch CardMarket ID for card {}: {:?}",
-                    card_id,
-                    e
-                );
+        loop {
+            if let Err(e) = self.drain().await {
+                self.tracker.error(e.to_string());
+                tracing::error!("Failed to process CardMarket ID jobs: {:?}", e);
             }
+            tokio::time::sleep(poll_interval).await;
+        }
+    }
 
-            {
-                let mut set = self
-                    .dedup_set
-                    .lock()
-                    .map_err(|_| InfraError::QueueError("Mutex poisoned".into()))?;
-                set.remove(&card_id);
-            }
+    /// Processes the due jobs until none is left, then recalculates the collection prices if
+    /// any job ran. Returns the number of jobs processed.
+    async fn drain(&self) -> Result<usize, AppError> {
+        let mut processed = 0;
 
-            if receiver.is_empty()
-                && let Err(e) = self.price_calculation.calculate_total_price().await
-            {
-                tracing::error!("Failed to calculate total price: {:?}", e);
+        loop {
+            let jobs = self
+                .enrichment_job_repository
+                .claim(EnrichmentKind::CardMarketId, BATCH_SIZE, JOB_LEASE)
+                .await?;
+            if jobs.is_empty() {
+                break;
             }
 
-            if receiver.is_empty()
-                && let Err(e) = self.card_prices_view_repository.refresh().await
-            {
-                tracing::error!("Failed to refresh card price view: {:?}", e);
+            processed += jobs.len();
+            self.process_batch(jobs).await?;
+        }
+
+        if processed > 0
+            && let Err(e) = self.price_calculation.calculate_total_price().await
+        {
+            tracing::error!("Failed to calculate total price: {:?}", e);
+        }
+
+        Ok(processed)
+    }
+
+    /// Resolves the jobs with a single lookup. When it fails, every job of the batch is retried.
+    async fn process_batch(&self, jobs: Vec<EnrichmentJob>) -> Result<(), AppError> {
+        // Les versions foil et non foil d'une carte partagent leur scryfall_id
+        let scryfall_ids: HashSet<Uuid> = jobs.iter().map(|job| job.scryfall_id).collect();
+
+        match self
+            .scryfall_caller
+            .get_cards(scryfall_ids.into_iter().collect())
+            .await
+        {
+            Ok(cards) => {
+                for job in jobs {
+                    let result = self.process(&job, &cards).await;
+                    self.settle(job, result).await?;
+                }
+            }
+            Err(e) => {
+                for job in jobs {
+                    self.settle(job, Err(e.clone())).await?;
+                }
             }
         }
 
         Ok(())
     }
+
+    async fn process(
+        &self,
+        job: &EnrichmentJob,
+        cards: &HashMap<Uuid, ScryfallCard>,
+    ) -> Result<EnrichmentOutcome, AppError> {
+        let card = cards.get(&job.scryfall_id);
+        let cardmarket_id = card.and_then(|card| card.cardmarket_id);
+        self.card_repository
+            .update_cardmarket_id(job.card_id.clone(), cardmarket_id)
+            .await?;
+        if let Some(card) = card {
+            self.card_repository
+                .update_metadata(job.card_id.clone(), card.metadata.clone())
+                .await?;
+        }
+
+        tracing::info!("{} -> {:?}", job.card_id, cardmarket_id);
+        self.backfill_prices(cardmarket_id).await;
+        self.card_prices_refresh
+            .request(CardPricesRefresh::Card(job.card_id.clone()));
+
+        Ok(match (card, cardmarket_id) {
+            (None, _) => EnrichmentOutcome::NotFound("Unknown to Scryfall".to_string()),
+            (Some(_), None) => EnrichmentOutcome::NotFound("No Cardmarket product".to_string()),
+            (Some(_), Some(_)) => EnrichmentOutcome::Resolved,
+        })
+    }
+
+    /// Records the attempt on the card, then removes the job once done, otherwise schedules its
+    /// retry or dead-letters it.
+    async fn settle(
+        &self,
+        job: EnrichmentJob,
+        result: Result<EnrichmentOutcome, AppError>,
+    ) -> Result<(), AppError> {
+        match result {
+            Ok(outcome) => {
+                let next_attempt_at = match outcome {
+                    EnrichmentOutcome::Resolved => None,
+                    _ => self
+                        .retry_policy
+                        .next_lookup_at(job.card_attempts + 1, Utc::now()),
+                };
+                self.tracker.processed(job.card_id.clone());
+                self.enrichment_job_repository
+                    .record_attempt(
+                        EnrichmentKind::CardMarketId,
+                        job.card_id,
+                        outcome,
+                        next_attempt_at,
+                    )
+                    .await?;
+                self.enrichment_job_repository.complete(job.id).await
+            }
+            Err(e) => {
+                let retry_at = self.retry_policy.next_attempt_at(job.attempts, Utc::now());
+                tracing::error!(
+                    "Failed to resolve CardMarket ID for card {} (attempt {}, retry at {:?}): {:?}",
+                    job.card_id,
+                    job.attempts,
+                    retry_at,
+                    e
+                );
+                self.tracker.failed(job.card_id.clone(), e.to_string());
+                self.enrichment_job_repository
+                    .record_attempt(
+                        EnrichmentKind::CardMarketId,
+                        job.card_id,
+                        EnrichmentOutcome::Failed(e.to_string()),
+                        retry_at,
+                    )
+                    .await?;
+                self.enrichment_job_repository
+                    .fail(job.id, e.to_string(), retry_at)
+                    .await
+            }
+        }
+    }
+
+    /// Saves the latest prices of a product the import skipped while no card referenced it.
+    async fn backfill_prices(&self, cardmarket_id: Option<u32>) {
+        if let Some(id) = cardmarket_id
+            && let Err(e) = self
+                .cardmarket_price_repository
+                .backfill_from_latest_guide(id)
+                .await
+        {
+            tracing::error!("Failed to backfill prices of product {}: {:?}", id, e);
+        }
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
     use crate::application::caller::MockScryfallCaller;
-    use crate::application::error::{AppError, InfraError};
-    use crate::application::repository::{MockCardPricesViewRepository, MockCardRepository};
-    use crate::application::use_case::MockCardCollectionPriceCalculationUseCase;
+    use crate::application::error::InfraError;
+    use crate::application::repository::{
+        MockCardMarketPriceRepository, MockCardRepository, MockEnrichmentJobRepository,
+    };
+    use crate::application::use_case::{
+        MockCardCollectionPriceCalculationUseCase, MockScheduleCardPricesRefreshUseCase,
+    };
+    use crate::domain::card::CardId;
+    use crate::domain::card_metadata::{CardMetadata, Color};
     use crate::domain::language_code::LanguageCode;
     use crate::domain::set_name::SetCode;
-    use tokio::sync::mpsc::unbounded_channel;
+    use mockall::predicate::eq;
 
     fn make_card_id(n: &str) -> CardId {
         CardId::new(SetCode::new("FDN"), n, LanguageCode::FR, false)
     }
 
-    fn mock_prices_view() -> MockCardPricesViewRepository {
-        let mut r = MockCardPricesViewRepository::new();
-        r.expect_refresh().returning(|| Box::pin(async { Ok(()) }));
+    fn job(id: i64, attempts: u32) -> EnrichmentJob {
+        EnrichmentJob {
+            id,
+            card_id: make_card_id("0"),
+            scryfall_id: Uuid::default(),
+            name: "Goblin Boarders".to_string(),
+            attempts,
+            card_attempts: 0,
+        }
+    }
+
+    fn retry_policy() -> EnrichmentRetryPolicy {
+        EnrichmentRetryPolicy {
+            max_attempts: 3,
+            base_delay: chrono::Duration::seconds(60),
+            not_found_max_attempts: 3,
+            not_found_base_delay: chrono::Duration::hours(24),
+        }
+    }
+
+    /// Hands out `jobs` on the first claim, then nothing.
+    fn claiming(jobs: Vec<EnrichmentJob>) -> MockEnrichmentJobRepository {
+        let mut r = MockEnrichmentJobRepository::new();
+        let mut jobs = Some(jobs);
+        r.expect_claim()
+            .withf(|kind, _, _| *kind == EnrichmentKind::CardMarketId)
+            .returning(move |_, _, _| {
+                let jobs = jobs.take().unwrap_or_default();
+                Box::pin(async move { Ok(jobs) })
+            });
+        r
+    }
+
+    /// Hands out `jobs` on the first claim, then nothing, and records every attempt.
+    fn job_repository(jobs: Vec<EnrichmentJob>) -> MockEnrichmentJobRepository {
+        let mut r = claiming(jobs);
+        r.expect_record_attempt()
+            .returning(|_, _, _, _| Box::pin(async { Ok(()) }));
+        r
+    }
+
+    fn mock_price_repository() -> MockCardMarketPriceRepository {
+        let mut r = MockCardMarketPriceRepository::new();
+        r.expect_backfill_from_latest_guide()
+            .returning(|_| Box::pin(async { Ok(()) }));
+        r
+    }
+
+    fn mock_price_calc() -> MockCardCollectionPriceCalculationUseCase {
+        let mut r = MockCardCollectionPriceCalculationUseCase::new();
+        r.expect_calculate_total_price()
+            .returning(|| Box::pin(async { Ok(()) }));
+        r
+    }
+
+    fn mock_prices_refresh() -> MockScheduleCardPricesRefreshUseCase {
+        let mut r = MockScheduleCardPricesRefreshUseCase::new();
+        r.expect_request().return_const(());
+        r
+    }
+
+    fn scryfall_card(scryfall_id: Uuid, cardmarket_id: Option<u32>) -> ScryfallCard {
+        ScryfallCard {
+            scryfall_id,
+            name: "Goblin Boarders".to_string(),
+            set_code: "fdn".to_string(),
+            collector_number: "87".to_string(),
+            lang: "fr".to_string(),
+            cardmarket_id,
+            rarity: None,
+            metadata: CardMetadata {
+                type_line: Some("Creature — Goblin Pirate".to_string()),
+                colors: vec![Color::R],
+                ..CardMetadata::default()
+            },
+        }
+    }
+
+    /// Finds every requested card, with this `cardmarket_id`.
+    fn scryfall_returning(cardmarket_id: Option<u32>) -> MockScryfallCaller {
+        let mut r = MockScryfallCaller::new();
+        r.expect_get_cards().returning(move |ids| {
+            let found = ids
+                .into_iter()
+                .map(|id| (id, scryfall_card(id, cardmarket_id)))
+                .collect();
+            Box::pin(async move { Ok(found) })
+        });
+        r
+    }
+
+    fn updating_card_repository() -> MockCardRepository {
+        let mut r = MockCardRepository::new();
+        r.expect_update_cardmarket_id()
+            .returning(|_, _| Box::pin(async { Ok(()) }));
+        r.expect_update_metadata()
+            .returning(|_, _| Box::pin(async { Ok(()) }));
         r
     }
 
     #[tokio::test]
-    async fn worker_updates_card_and_triggers_price_calc_when_queue_empty() {
+    async fn worker_updates_card_completes_job_and_triggers_price_calc_when_queue_empty() {
         let mut card_repository = MockCardRepository::new();
-        let mut scryfall_caller = MockScryfallCaller::new();
+        let mut job_repository = job_repository(vec![job(7, 1)]);
         let mut price_calc = MockCardCollectionPriceCalculationUseCase::new();
 
-        scryfall_caller
-            .expect_get_card_market_id()
-            .returning(|_| Box::pin(async { Ok(Some(42)) }));
         card_repository
             .expect_update_cardmarket_id()
+            .with(eq(make_card_id("0")), eq(Some(42)))
+            .times(1)
             .returning(|_, _| Box::pin(async { Ok(()) }));
+        card_repository
+            .expect_update_metadata()
+            .withf(|card_id, metadata| {
+                *card_id == make_card_id("0") && metadata.colors == vec![Color::R]
+            })
+            .times(1)
+            .returning(|_, _| Box::pin(async { Ok(()) }));
+        job_repository
+            .expect_complete()
+            .with(eq(7))
+            .times(1)
+            .returning(|_| Box::pin(async { Ok(()) }));
         price_calc
             .expect_calculate_total_price()
             .times(1)
             .returning(|| Box::pin(async { Ok(()) }));
 
-        let dedup_set = Arc::new(Mutex::new(HashSet::new()));
-        let (tx, rx) = unbounded_channel::<(CardId, Uuid)>();
-
         let worker = CardMarketIdWorker::new(
             Arc::new(card_repository),
-            Arc::new(scryfall_caller),
+            Arc::new(mock_price_repository()),
+            Arc::new(job_repository),
+            Arc::new(scryfall_returning(Some(42))),
             Arc::new(price_calc),
-            Arc::new(mock_prices_view()),
-            dedup_set,
+            Arc::new(mock_prices_refresh()),
+            retry_policy(),
+            Arc::new(WorkerTracker::default()),
         );
 
-        let card_id = make_card_id("0");
-        tx.send((card_id, Uuid::default())).unwrap();
-        drop(tx); // ferme le canal pour que run() se termine
+        assert_eq!(worker.drain().await.unwrap(), 1);
+    }
 
-        worker.run(rx).await.unwrap();
+    #[tokio::test]
+    async fn worker_does_not_trigger_price_calc_when_no_job_ran() {
+        let mut price_calc = MockCardCollectionPriceCalculationUseCase::new();
+        price_calc.expect_calculate_total_price().never();
+
+        let worker = CardMarketIdWorker::new(
+            Arc::new(MockCardRepository::new()),
+            Arc::new(mock_price_repository()),
+            Arc::new(job_repository(vec![])),
+            Arc::new(MockScryfallCaller::new()),
+            Arc::new(price_calc),
+            Arc::new(mock_prices_refresh()),
+            retry_policy(),
+            Arc::new(WorkerTracker::default()),
+        );
+
+        assert_eq!(worker.drain().await.unwrap(), 0);
     }
 
     #[tokio::test]
-    async fn worker_removes_card_id_from_dedup_set_after_processing() {
+    async fn worker_schedules_a_retry_on_scryfall_error() {
         let mut card_repository = MockCardRepository::new();
+        let mut job_repository = job_repository(vec![job(7, 1)]);
         let mut scryfall_caller = MockScryfallCaller::new();
-        let mut price_calc = MockCardCollectionPriceCalculationUseCase::new();
 
-        scryfall_caller
-            .expect_get_card_market_id()
-            .returning(|_| Box::pin(async { Ok(Some(1)) }));
+        scryfall_caller.expect_get_cards().returning(|_| {
+            Box::pin(async {
+                Err(AppError::Infra(InfraError::CallError(
+                    "Scryfall error".to_string(),
+                )))
+            })
+        });
+        card_repository.expect_update_cardmarket_id().times(0);
+        card_repository.expect_update_metadata().never();
+        job_repository.expect_complete().never();
+        job_repository
+            .expect_fail()
+            .withf(|id, error, retry_at| {
+                *id == 7 && error.contains("Scryfall error") && retry_at.is_some()
+            })
+            .times(1)
+            .returning(|_, _, _| Box::pin(async { Ok(()) }));
+
+        let worker = CardMarketIdWorker::new(
+            Arc::new(card_repository),
+            Arc::new(mock_price_repository()),
+            Arc::new(job_repository),
+            Arc::new(scryfall_caller),
+            Arc::new(mock_price_calc()),
+            Arc::new(mock_prices_refresh()),
+            retry_policy(),
+            Arc::new(WorkerTracker::default()),
+        );
+
+        assert_eq!(worker.drain().await.unwrap(), 1);
+    }
+
+    #[tokio::test]
+    async fn worker_dead_letters_a_job_out_of_attempts() {
+        let mut job_repository = job_repository(vec![job(7, 3)]);
+        let mut card_repository = MockCardRepository::new();
+
         card_repository
             .expect_update_cardmarket_id()
-            .returning(|_, _| Box::pin(async { Ok(()) }));
+            .returning(|_, _| {
+                Box::pin(async {
+                    Err(AppError::Infra(InfraError::RepositoryError(
+                        "DB error".to_string(),
+                    )))
+                })
+            });
+        job_repository
+            .expect_fail()
+            .withf(|id, _, retry_at| *id == 7 && retry_at.is_none())
+            .times(1)
+            .returning(|_, _, _| Box::pin(async { Ok(()) }));
+
+        let worker = CardMarketIdWorker::new(
+            Arc::new(card_repository),
+            Arc::new(mock_price_repository()),
+            Arc::new(job_repository),
+            Arc::new(scryfall_returning(Some(42))),
+            Arc::new(mock_price_calc()),
+            Arc::new(mock_prices_refresh()),
+            retry_policy(),
+            Arc::new(WorkerTracker::default()),
+        );
+
+        worker.drain().await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn worker_triggers_price_calc_after_batch_and_refreshes_the_card() {
+        let mut job_repository = job_repository(vec![job(1, 1), job(2, 1)]);
+        let mut price_calc = MockCardCollectionPriceCalculationUseCase::new();
+        let mut prices_refresh = MockScheduleCardPricesRefreshUseCase::new();
+
+        job_repository
+            .expect_complete()
+            .times(2)
+            .returning(|_| Box::pin(async { Ok(()) }));
         price_calc
             .expect_calculate_total_price()
+            .times(1)
             .returning(|| Box::pin(async { Ok(()) }));
+        prices_refresh
+            .expect_request()
+            .with(eq(CardPricesRefresh::Card(make_card_id("0"))))
+            .times(2)
+            .return_const(());
 
-        let dedup_set = Arc::new(Mutex::new(HashSet::new()));
-        let card_id = make_card_id("0");
-        dedup_set.lock().unwrap().insert(card_id.clone());
+        let worker = CardMarketIdWorker::new(
+            Arc::new(updating_card_repository()),
+            Arc::new(mock_price_repository()),
+            Arc::new(job_repository),
+            Arc::new(scryfall_returning(Some(99))),
+            Arc::new(price_calc),
+            Arc::new(prices_refresh),
+            retry_policy(),
+            Arc::new(WorkerTracker::default()),
+        );
+
+        assert_eq!(worker.drain().await.unwrap(), 2);
+    }
 
-        let (tx, rx) = unbounded_channel::<(CardId, Uuid)>();
-        tx.send((card_id.clone(), Uuid::default())).unwrap();
-        drop(tx);
+    #[tokio::test]
+    async fn worker_backfills_prices_of_the_new_cardmarket_id() {
+        let mut price_repository = MockCardMarketPriceRepository::new();
+        let mut job_repository = job_repository(vec![job(1, 1)]);
+
+        price_repository
+            .expect_backfill_from_latest_guide()
+            .with(eq(42))
+            .times(1)
+            .returning(|_| Box::pin(async { Ok(()) }));
+        job_repository
+            .expect_complete()
+            .returning(|_| Box::pin(async { Ok(()) }));
+
+        let worker = CardMarketIdWorker::new(
+            Arc::new(updating_card_repository()),
+            Arc::new(price_repository),
+            Arc::new(job_repository),
+            Arc::new(scryfall_returning(Some(42))),
+            Arc::new(mock_price_calc()),
+            Arc::new(mock_prices_refresh()),
+            retry_policy(),
+            Arc::new(WorkerTracker::default()),
+        );
+        worker.drain().await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn worker_does_not_backfill_cards_without_cardmarket_id() {
+        let mut price_repository = MockCardMarketPriceRepository::new();
+        let mut job_repository = job_repository(vec![job(1, 1)]);
+
+        price_repository.expect_backfill_from_latest_guide().never();
+        job_repository
+            .expect_complete()
+            .with(eq(1))
+            .times(1)
+            .returning(|_| Box::pin(async { Ok(()) }));
+
+        let worker = CardMarketIdWorker::new(
+            Arc::new(updating_card_repository()),
+            Arc::new(price_repository),
+            Arc::new(job_repository),
+            Arc::new(scryfall_returning(None)),
+            Arc::new(mock_price_calc()),
+            Arc::new(mock_prices_refresh()),
+            retry_policy(),
+            Arc::new(WorkerTracker::default()),
+        );
+        worker.drain().await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn worker_resolves_a_batch_with_a_single_scryfall_call() {
+        let first = Uuid::new_v4();
+        let second = Uuid::new_v4();
+        let jobs = vec![
+            EnrichmentJob {
+                scryfall_id: first,
+                ..job(1, 1)
+            },
+            EnrichmentJob {
+                card_id: CardId::new(SetCode::new("FDN"), "0", LanguageCode::FR, true),
+                scryfall_id: first,
+                ..job(2, 1)
+            },
+            EnrichmentJob {
+                card_id: make_card_id("1"),
+                scryfall_id: second,
+                ..job(3, 1)
+            },
+        ];
+        let mut job_repository = job_repository(jobs);
+        let mut scryfall_caller = MockScryfallCaller::new();
+        let mut card_repository = MockCardRepository::new();
+
+        scryfall_caller
+            .expect_get_cards()
+            .withf(move |ids| ids.len() == 2 && ids.contains(&first) && ids.contains(&second))
+            .times(1)
+            .returning(move |_| {
+                Box::pin(
+                    async move { Ok(HashMap::from([(first, scryfall_card(first, Some(42)))])) },
+                )
+            });
+        card_repository
+            .expect_update_cardmarket_id()
+            .withf(|card_id, cardmarket_id| {
+                card_id.collector_number == "0" && *cardmarket_id == Some(42)
+            })
+            .times(2)
+            .returning(|_, _| Box::pin(async { Ok(()) }));
+        card_repository
+            .expect_update_cardmarket_id()
+            .with(eq(make_card_id("1")), eq(None))
+            .times(1)
+            .returning(|_, _| Box::pin(async { Ok(()) }));
+        // La carte inconnue de Scryfall garde ses données actuelles
+        card_repository
+            .expect_update_metadata()
+            .withf(|card_id, _| card_id.collector_number == "0")
+            .times(2)
+            .returning(|_, _| Box::pin(async { Ok(()) }));
+        job_repository
+            .expect_complete()
+            .times(3)
+            .returning(|_| Box::pin(async { Ok(()) }));
 
         let worker = CardMarketIdWorker::new(
             Arc::new(card_repository),
+            Arc::new(mock_price_repository()),
+            Arc::new(job_repository),
             Arc::new(scryfall_caller),
-            Arc::new(price_calc),
-            Arc::new(mock_prices_view()),
-            dedup_set.clone(),
+            Arc::new(mock_price_calc()),
+            Arc::new(mock_prices_refresh()),
+            retry_policy(),
+            Arc::new(WorkerTracker::default()),
         );
-        worker.run(rx).await.unwrap();
 
-        assert!(!dedup_set.lock().unwrap().contains(&card_id));
+        assert_eq!(worker.drain().await.unwrap(), 3);
     }
 
     #[tokio::test]
-    async fn worker_continues_on_scryfall_error() {
-        let mut card_repository = MockCardRepository::new();
+    async fn worker_schedules_a_retry_of_the_whole_batch_on_scryfall_error() {
+        let mut job_repository = job_repository(vec![job(1, 1), job(2, 3)]);
         let mut scryfall_caller = MockScryfallCaller::new();
-        let mut price_calc = MockCardCollectionPriceCalculationUseCase::new();
 
-        scryfall_caller.expect_get_card_market_id().returning(|_| {
+        scryfall_caller.expect_get_cards().times(1).returning(|_| {
             Box::pin(async {
                 Err(AppError::Infra(InfraError::CallError(
-                    "Scryfall error".to_string(),
+                    "429 Too Many Requests".to_string(),
                 )))
             })
         });
-        // update_cardmarket_id should not be called
-        card_repository.expect_update_cardmarket_id().times(0);
-        price_calc
-            .expect_calculate_total_price()
-            .returning(|| Box::pin(async { Ok(()) }));
-
-        let dedup_set = Arc::new(Mutex::new(HashSet::new()));
-        let (tx, rx) = unbounded_channel::<(CardId, Uuid)>();
-        tx.send((make_card_id("0"), Uuid::default())).unwrap();
-        drop(tx);
+        job_repository
+            .expect_fail()
+            .withf(|id, _, retry_at| *id == 1 && retry_at.is_some())
+            .times(1)
+            .returning(|_, _, _| Box::pin(async { Ok(()) }));
+        job_repository
+            .expect_fail()
+            .withf(|id, _, retry_at| *id == 2 && retry_at.is_none())
+            .times(1)
+            .returning(|_, _, _| Box::pin(async { Ok(()) }));
 
         let worker = CardMarketIdWorker::new(
-            Arc::new(card_repository),
+            Arc::new(MockCardRepository::new()),
+            Arc::new(mock_price_repository()),
+            Arc::new(job_repository),
             Arc::new(scryfall_caller),
-            Arc::new(price_calc),
-            Arc::new(mock_prices_view()),
-            dedup_set,
+            Arc::new(mock_price_calc()),
+            Arc::new(mock_prices_refresh()),
+            retry_policy(),
+            Arc::new(WorkerTracker::default()),
         );
-        worker.run(rx).await.unwrap();
+
+        assert_eq!(worker.drain().await.unwrap(), 2);
     }
 
     #[tokio::test]
-    async fn worker_triggers_price_calc_and_refresh_after_batch() {
-        let mut card_repository = MockCardRepository::new();
+    async fn worker_records_the_attempt_of_each_job() {
+        let unknown = Uuid::new_v4();
+        let jobs = vec![
+            job(1, 1),
+            EnrichmentJob {
+                card_id: make_card_id("1"),
+                scryfall_id: unknown,
+                card_attempts: 2,
+                ..job(2, 1)
+            },
+        ];
+        let mut job_repository = claiming(jobs);
         let mut scryfall_caller = MockScryfallCaller::new();
-        let mut price_calc = MockCardCollectionPriceCalculationUseCase::new();
-        let mut prices_view = MockCardPricesViewRepository::new();
 
-        scryfall_caller
-            .expect_get_card_market_id()
-            .returning(|_| Box::pin(async { Ok(Some(99)) }));
-        card_repository
-            .expect_update_cardmarket_id()
-            .returning(|_, _| Box::pin(async { Ok(()) }));
-        price_calc
-            .expect_calculate_total_price()
+        scryfall_caller.expect_get_cards().returning(|_| {
+            let found = HashMap::from([(Uuid::default(), scryfall_card(Uuid::default(), None))]);
+            Box::pin(async move { Ok(found) })
+        });
+        job_repository
+            .expect_record_attempt()
+            .withf(|kind, card_id, outcome, next_attempt_at| {
+                *kind == EnrichmentKind::CardMarketId
+                    && *card_id == make_card_id("0")
+                    && *outcome == EnrichmentOutcome::NotFound("No Cardmarket product".to_string())
+                    && next_attempt_at.is_some()
+            })
             .times(1)
-            .returning(|| Box::pin(async { Ok(()) }));
-        prices_view
-            .expect_refresh()
+            .returning(|_, _, _, _| Box::pin(async { Ok(()) }));
+        // Troisième recherche infructueuse : la carte n'est plus recherchée
+        job_repository
+            .expect_record_attempt()
+            .withf(|_, card_id, outcome, next_attempt_at| {
+                *card_id == make_card_id("1")
+                    && *outcome == EnrichmentOutcome::NotFound("Unknown to Scryfall".to_string())
+                    && next_attempt_at.is_none()
+            })
             .times(1)
-            .returning(|| Box::pin(async { Ok(()) }));
+            .returning(|_, _, _, _| Box::pin(async { Ok(()) }));
+        job_repository
+            .expect_complete()
+            .times(2)
+            .returning(|_| Box::pin(async { Ok(()) }));
 
-        let dedup_set = Arc::new(Mutex::new(HashSet::new()));
-        let (tx, rx) = unbounded_channel::<(CardId, Uuid)>();
-        tx.send((make_card_id("0"), Uuid::default())).unwrap();
-        drop(tx);
+        let worker = CardMarketIdWorker::new(
+            Arc::new(updating_card_repository()),
+            Arc::new(mock_price_repository()),
+            Arc::new(job_repository),
+            Arc::new(scryfall_caller),
+            Arc::new(mock_price_calc()),
+            Arc::new(mock_prices_refresh()),
+            retry_policy(),
+            Arc::new(WorkerTracker::default()),
+        );
+
+        assert_eq!(worker.drain().await.unwrap(), 2);
+    }
+
+    #[tokio::test]
+    async fn worker_records_a_resolved_attempt_and_a_failed_one() {
+        let mut job_repository = claiming(vec![job(1, 1)]);
+
+        job_repository
+            .expect_record_attempt()
+            .withf(|_, _, outcome, next_attempt_at| {
+                *outcome == EnrichmentOutcome::Resolved && next_attempt_at.is_none()
+            })
+            .times(1)
+            .returning(|_, _, _, _| Box::pin(async { Ok(()) }));
+        job_repository
+            .expect_complete()
+            .returning(|_| Box::pin(async { Ok(()) }));
 
         let worker = CardMarketIdWorker::new(
-            Arc::new(card_repository),
+            Arc::new(updating_card_repository()),
+            Arc::new(mock_price_repository()),
+            Arc::new(job_repository),
+            Arc::new(scryfall_returning(Some(42))),
+            Arc::new(mock_price_calc()),
+            Arc::new(mock_prices_refresh()),
+            retry_policy(),
+            Arc::new(WorkerTracker::default()),
+        );
+        worker.drain().await.unwrap();
+
+        let mut job_repository = claiming(vec![job(1, 1)]);
+        let mut scryfall_caller = MockScryfallCaller::new();
+
+        scryfall_caller.expect_get_cards().returning(|_| {
+            Box::pin(async {
+                Err(AppError::Infra(InfraError::CallError(
+                    "timeout".to_string(),
+                )))
+            })
+        });
+        job_repository
+            .expect_record_attempt()
+            .withf(|_, _, outcome, next_attempt_at| {
+                matches!(outcome, EnrichmentOutcome::Failed(e) if e.contains("timeout"))
+                    && next_attempt_at.is_some()
+            })
+            .times(1)
+            .returning(|_, _, _, _| Box::pin(async { Ok(()) }));
+        job_repository
+            .expect_fail()
+            .returning(|_, _, _| Box::pin(async { Ok(()) }));
+
+        let worker = CardMarketIdWorker::new(
+            Arc::new(MockCardRepository::new()),
+            Arc::new(mock_price_repository()),
+            Arc::new(job_repository),
             Arc::new(scryfall_caller),
-            Arc::new(price_calc),
-            Arc::new(prices_view),
-            dedup_set,
+            Arc::new(mock_price_calc()),
+            Arc::new(mock_prices_refresh()),
+            retry_policy(),
+            Arc::new(WorkerTracker::default()),
         );
-        worker.run(rx).await.unwrap();
+        worker.drain().await.unwrap();
     }
 }
@@ -1,86 +1,165 @@
 use crate::application::caller::GathererCaller;
-use crate::application::error::{AppError, InfraError};
-use crate::application::repository::{CardPricesViewRepository, CardRepository};
-use crate::domain::card::CardId;
-use std::collections::HashSet;
-use std::sync::{Arc, Mutex};
-use tokio::sync::mpsc::UnboundedReceiver;
+use crate::application::error::AppError;
+use crate::application::repository::{CardRepository, EnrichmentJobRepository};
+use crate::application::service::worker_monitor_service::WorkerTracker;
+use crate::application::use_case::ScheduleCardPricesRefreshUseCase;
+use crate::domain::card_prices_refresh::CardPricesRefresh;
+use crate::domain::enrichment_job::{
+    EnrichmentJob, EnrichmentKind, EnrichmentOutcome, EnrichmentRetryPolicy,
+};
+use chrono::Utc;
+use std::sync::Arc;
+use std::time::Duration;
+
+/// Jobs claimed at once.
+const BATCH_SIZE: u32 = 20;
+/// Time a claimed job stays locked, after which another worker may take it back.
+const JOB_LEASE: Duration = Duration::from_secs(600);
 
 pub struct GathererIdWorker {
     card_repository: Arc<dyn CardRepository>,
+    enrichment_job_repository: Arc<dyn EnrichmentJobRepository>,
     gatherer_caller: Arc<dyn GathererCaller>,
-    card_prices_view_repository: Arc<dyn CardPricesViewRepository>,
-    dedup_set: Arc<Mutex<HashSet<CardId>>>,
+    card_prices_refresh: Arc<dyn ScheduleCardPricesRefreshUseCase>,
+    retry_policy: EnrichmentRetryPolicy,
+    tracker: Arc<WorkerTracker>,
 }
 
 impl GathererIdWorker {
     pub fn new(
         card_repository: Arc<dyn CardRepository>,
+        enrichment_job_repository: Arc<dyn EnrichmentJobRepository>,
         gatherer_caller: Arc<dyn GathererCaller>,
-        card_prices_view_repository: Arc<dyn CardPricesViewRepository>,
-        dedup_set: Arc<Mutex<HashSet<CardId>>>,
+        card_prices_refresh: Arc<dyn ScheduleCardPricesRefreshUseCase>,
+        retry_policy: EnrichmentRetryPolicy,
+        tracker: Arc<WorkerTracker>,
     ) -> Self {
         Self {
             card_repository,
+            enrichment_job_repository,
             gatherer_caller,
-            card_prices_view_repository,
-            dedup_set,
+            card_prices_refresh,
+            retry_policy,
+            tracker,
         }
     }
 
-    pub async fn run(
-        self,
-        mut receiver: UnboundedReceiver<(CardId, String)>,
-    ) -> Result<(), AppError> {
+    /// Polls the job queue every `poll_interval` once it is drained.
+    pub async fn run(self, poll_interval: Duration) {
         tracing::info!("Gatherer id updater started.");
 
-        while let Some((card_id, name)) = receiver.recv().await {
-            let gatherer_id = self
-                .gatherer_caller
-                .get_gatherer_id(
-                    card_id.set_code.clone(),
-                    card_id.collector_number.clone(),
-                    card_id.language_code.clone(),
-                    name,
-                )
-                .await;
-
-            match gatherer_id {
-                Ok(Some(id)) => {
-                    if let Err(e) = self
-                        .card_repository
-                        .update_gatherer_id(card_id.clone(), Some(id))
-                        .await
-                    {
-                        tracing::error!("Failed to update card with Gatherer ID: {:?}", e);
-                    } else {
-                        tracing::info!("{} ✓", card_id);
-                    }
-                }
-                Ok(None) => {
-                    tracing::trace!("No Gatherer ID found for card {}, leaving empty", card_id);
-                }
-                Err(e) => {
-                    tracing::error!("Failed to fetch Gatherer ID for card {}: {:?}", card_id, e);
-                }
+        loop {
+            if let Err(e) = self.drain().await {
+                self.tracker.error(e.to_string());
+                tracing::error!("Failed to process Gatherer ID jobs: {:?}", e);
             }
+            tokio::time::sleep(poll_interval).await;
+        }
+    }
+
+    /// Processes the due jobs until none is left. Returns the number of jobs processed.
+    async fn drain(&self) -> Result<usize, AppError> {
+        let mut processed = 0;
 
-            {
-                let mut set = self
-                    .dedup_set
-                    .lock()
-                    .map_err(|_| InfraError::QueueError("Mutex poisoned".into()))?;
-                set.remove(&card_id);
+        loop {
+            let jobs = self
+                .enrichment_job_repository
+                .claim(EnrichmentKind::GathererId, BATCH_SIZE, JOB_LEASE)
+                .await?;
+            if jobs.is_empty() {
+                break;
             }
 
-            if receiver.is_empty()
-                && let Err(e) = self.card_prices_view_repository.refresh().await
-            {
-                tracing::error!("Failed to refresh card price view: {:?}", e);
+            for job in jobs {
+                let result = self.process(&job).await;
+                self.settle(job, result).await?;
+                processed += 1;
+            }
+        }
+
+        Ok(processed)
+    }
+
+    async fn process(&self, job: &EnrichmentJob) -> Result<EnrichmentOutcome, AppError> {
+        let card_id = &job.card_id;
+        let gatherer_ids = self
+            .gatherer_caller
+            .get_gatherer_id(
+                card_id.set_code.clone(),
+                card_id.collector_number.clone(),
+                card_id.language_code.clone(),
+                job.name.clone(),
+            )
+            .await?;
+
+        match gatherer_ids {
+            Some(ids) => {
+                self.card_repository
+                    .update_gatherer_id(card_id.clone(), Some(ids))
+                    .await?;
+                tracing::info!("{} ✓", card_id);
+                self.card_prices_refresh
+                    .request(CardPricesRefresh::Card(card_id.clone()));
+                Ok(EnrichmentOutcome::Resolved)
+            }
+            None => {
+                tracing::trace!("No Gatherer ID found for card {}, leaving empty", card_id);
+                Ok(EnrichmentOutcome::NotFound(
+                    "No Gatherer page found".to_string(),
+                ))
             }
         }
+    }
 
-        Ok(())
+    /// Records the attempt on the card, then removes the job once done, otherwise schedules its
+    /// retry or dead-letters it.
+    async fn settle(
+        &self,
+        job: EnrichmentJob,
+        result: Result<EnrichmentOutcome, AppError>,
+    ) -> Result<(), AppError> {
+        match result {
+            Ok(outcome) => {
+                let next_attempt_at = match outcome {
+                    EnrichmentOutcome::Resolved => None,
+                    _ => self
+                        .retry_policy
+                        .next_lookup_at(job.card_attempts + 1, Utc::now()),
+                };
+                self.tracker.processed(job.card_id.clone());
+                self.enrichment_job_repository
+                    .record_attempt(
+                        EnrichmentKind::GathererId,
+                        job.card_id,
+                        outcome,
+                        next_attempt_at,
+                    )
+                    .await?;
+                self.enrichment_job_repository.complete(job.id).await
+            }
+            Err(e) => {
+                let retry_at = self.retry_policy.next_attempt_at(job.attempts, Utc::now());
+                tracing::error!(
+                    "Failed to resolve Gatherer ID for card {} (attempt {}, retry at {:?}): {:?}",
+                    job.card_id,
+                    job.attempts,
+                    retry_at,
+                    e
+                );
+                self.tracker.failed(job.card_id.clone(), e.to_string());
+                self.enrichment_job_repository
+                    .record_attempt(
+                        EnrichmentKind::GathererId,
+                        job.card_id,
+                        EnrichmentOutcome::Failed(e.to_string()),
+                        retry_at,
+                    )
+                    .await?;
+                self.enrichment_job_repository
+                    .fail(job.id, e.to_string(), retry_at)
+                    .await
+            }
+        }
     }
 }
 
@@ -88,110 +167,178 @@ impl GathererIdWorker {
 mod tests {
     use super::*;
     use crate::application::caller::MockGathererCaller;
-    use crate::application::error::{AppError, InfraError};
-    use crate::application::repository::{MockCardPricesViewRepository, MockCardRepository};
+    use crate::application::error::InfraError;
+    use crate::application::repository::{MockCardRepository, MockEnrichmentJobRepository};
+    use crate::application::use_case::MockScheduleCardPricesRefreshUseCase;
+    use crate::domain::card::{CardId, GathererIds};
     use crate::domain::language_code::LanguageCode;
     use crate::domain::set_name::SetCode;
-    use tokio::sync::mpsc::unbounded_channel;
+    use mockall::predicate::eq;
+    use uuid::Uuid;
 
     fn make_card_id(n: &str) -> CardId {
         CardId::new(SetCode::new("FDN"), n, LanguageCode::FR, false)
     }
 
-    fn mock_prices_view() -> MockCardPricesViewRepository {
-        let mut r = MockCardPricesViewRepository::new();
-        r.expect_refresh().returning(|| Box::pin(async { Ok(()) }));
+    fn job(id: i64, attempts: u32) -> EnrichmentJob {
+        EnrichmentJob {
+            id,
+            card_id: make_card_id("0"),
+            scryfall_id: Uuid::default(),
+            name: "Goblin Boarders".to_string(),
+            attempts,
+            card_attempts: 0,
+        }
+    }
+
+    fn delver_ids() -> GathererIds {
+        GathererIds {
+            front: "abc123".to_string(),
+            back: Some("def456".to_string()),
+        }
+    }
+
+    fn retry_policy() -> EnrichmentRetryPolicy {
+        EnrichmentRetryPolicy {
+            max_attempts: 3,
+            base_delay: chrono::Duration::seconds(60),
+            not_found_max_attempts: 3,
+            not_found_base_delay: chrono::Duration::hours(24),
+        }
+    }
+
+    /// Hands out `jobs` on the first claim, then nothing.
+    fn claiming(jobs: Vec<EnrichmentJob>) -> MockEnrichmentJobRepository {
+        let mut r = MockEnrichmentJobRepository::new();
+        let mut jobs = Some(jobs);
+        r.expect_claim()
+            .withf(|kind, _, _| *kind == EnrichmentKind::GathererId)
+            .returning(move |_, _, _| {
+                let jobs = jobs.take().unwrap_or_default();
+                Box::pin(async move { Ok(jobs) })
+            });
+        r
+    }
+
+    /// Hands out `jobs` on the first claim, then nothing, and records every attempt.
+    fn job_repository(jobs: Vec<EnrichmentJob>) -> MockEnrichmentJobRepository {
+        let mut r = claiming(jobs);
+        r.expect_record_attempt()
+            .returning(|_, _, _, _| Box::pin(async { Ok(()) }));
+        r
+    }
+
+    fn mock_prices_refresh() -> MockScheduleCardPricesRefreshUseCase {
+        let mut r = MockScheduleCardPricesRefreshUseCase::new();
+        r.expect_request().return_const(());
         r
     }
 
     #[tokio::test]
-    async fn worker_updates_card_and_refreshes_view_when_queue_empty() {
+    async fn worker_updates_card_and_refreshes_its_prices() {
         let mut card_repository = MockCardRepository::new();
+        let mut job_repository = job_repository(vec![job(7, 1)]);
         let mut gatherer_caller = MockGathererCaller::new();
+        let mut prices_refresh = MockScheduleCardPricesRefreshUseCase::new();
 
         gatherer_caller
             .expect_get_gatherer_id()
-            .returning(|_, _, _, _| Box::pin(async { Ok(Some("abc123".to_string())) }));
+            .withf(|_, _, _, name| name == "Goblin Boarders")
+            .returning(|_, _, _, _| Box::pin(async { Ok(Some(delver_ids())) }));
         card_repository
             .expect_update_gatherer_id()
+            .with(eq(make_card_id("0")), eq(Some(delver_ids())))
+            .times(1)
             .returning(|_, _| Box::pin(async { Ok(()) }));
-
-        let dedup_set = Arc::new(Mutex::new(HashSet::new()));
-        let (tx, rx) = unbounded_channel::<(CardId, String)>();
+        job_repository
+            .expect_complete()
+            .with(eq(7))
+            .times(1)
+            .returning(|_| Box::pin(async { Ok(()) }));
+        prices_refresh
+            .expect_request()
+            .with(eq(CardPricesRefresh::Card(make_card_id("0"))))
+            .times(1)
+            .return_const(());
 
         let worker = GathererIdWorker::new(
             Arc::new(card_repository),
+            Arc::new(job_repository),
             Arc::new(gatherer_caller),
-            Arc::new(mock_prices_view()),
-            dedup_set,
+            Arc::new(prices_refresh),
+            retry_policy(),
+            Arc::new(WorkerTracker::default()),
         );
 
-        let card_id = make_card_id("0");
-        tx.send((card_id, "Goblin Boarders".to_string())).unwrap();
-        drop(tx);
-
-        worker.run(rx).await.unwrap();
+        assert_eq!(worker.drain().await.unwrap(), 1);
     }
 
     #[tokio::test]
-    async fn worker_removes_card_id_from_dedup_set_after_processing() {
+    async fn worker_completes_job_and_leaves_column_empty_when_gatherer_returns_none() {
         let mut card_repository = MockCardRepository::new();
+        let mut job_repository = job_repository(vec![job(7, 1)]);
         let mut gatherer_caller = MockGathererCaller::new();
 
         gatherer_caller
             .expect_get_gatherer_id()
-            .returning(|_, _, _, _| Box::pin(async { Ok(Some("abc".to_string())) }));
-        card_repository
-            .expect_update_gatherer_id()
-            .returning(|_, _| Box::pin(async { Ok(()) }));
-
-        let dedup_set = Arc::new(Mutex::new(HashSet::new()));
-        let card_id = make_card_id("0");
-        dedup_set.lock().unwrap().insert(card_id.clone());
-
-        let (tx, rx) = unbounded_channel::<(CardId, String)>();
-        tx.send((card_id.clone(), "Name".to_string())).unwrap();
-        drop(tx);
+            .returning(|_, _, _, _| Box::pin(async { Ok(None) }));
+        card_repository.expect_update_gatherer_id().times(0);
+        job_repository
+            .expect_complete()
+            .with(eq(7))
+            .times(1)
+            .returning(|_| Box::pin(async { Ok(()) }));
 
         let worker = GathererIdWorker::new(
             Arc::new(card_repository),
+            Arc::new(job_repository),
             Arc::new(gatherer_caller),
-            Arc::new(mock_prices_view()),
-            dedup_set.clone(),
+            Arc::new(mock_prices_refresh()),
+            retry_policy(),
+            Arc::new(WorkerTracker::default()),
         );
-        worker.run(rx).await.unwrap();
-
-        assert!(!dedup_set.lock().unwrap().contains(&card_id));
+        worker.drain().await.unwrap();
     }
 
     #[tokio::test]
-    async fn worker_continues_and_leaves_column_empty_when_gatherer_returns_none() {
+    async fn worker_schedules_a_retry_on_gatherer_call_error() {
         let mut card_repository = MockCardRepository::new();
+        let mut job_repository = job_repository(vec![job(7, 1)]);
         let mut gatherer_caller = MockGathererCaller::new();
 
         gatherer_caller
             .expect_get_gatherer_id()
-            .returning(|_, _, _, _| Box::pin(async { Ok(None) }));
+            .returning(|_, _, _, _| {
+                Box::pin(async {
+                    Err(AppError::Infra(InfraError::CallError(
+                        "Gatherer error".to_string(),
+                    )))
+                })
+            });
         card_repository.expect_update_gatherer_id().times(0);
-
-        let dedup_set = Arc::new(Mutex::new(HashSet::new()));
-        let (tx, rx) = unbounded_channel::<(CardId, String)>();
-        tx.send((make_card_id("0"), "Unknown Card".to_string()))
-            .unwrap();
-        drop(tx);
+        job_repository.expect_complete().never();
+        job_repository
+            .expect_fail()
+            .withf(|id, error, retry_at| {
+                *id == 7 && error.contains("Gatherer error") && retry_at.is_some()
+            })
+            .times(1)
+            .returning(|_, _, _| Box::pin(async { Ok(()) }));
 
         let worker = GathererIdWorker::new(
             Arc::new(card_repository),
+            Arc::new(job_repository),
             Arc::new(gatherer_caller),
-            Arc::new(mock_prices_view()),
-            dedup_set,
+            Arc::new(mock_prices_refresh()),
+            retry_policy(),
+            Arc::new(WorkerTracker::default()),
         );
-        worker.run(rx).await.unwrap();
+        worker.drain().await.unwrap();
     }
 
     #[tokio::test]
-    async fn worker_continues_on_gatherer_call_error() {
-        let mut card_repository = MockCardRepository::new();
+    async fn worker_dead_letters_a_job_out_of_attempts() {
+        let mut job_repository = job_repository(vec![job(7, 3)]);
         let mut gatherer_caller = MockGathererCaller::new();
 
         gatherer_caller
@@ -203,19 +350,106 @@ mod tests {
                     )))
                 })
             });
-        card_repository.expect_update_gatherer_id().times(0);
+        job_repository
+            .expect_fail()
+            .withf(|id, _, retry_at| *id == 7 && retry_at.is_none())
+            .times(1)
+            .returning(|_, _, _| Box::pin(async { Ok(()) }));
+
+        let worker = GathererIdWorker::new(
+            Arc::new(MockCardRepository::new()),
+            Arc::new(job_repository),
+            Arc::new(gatherer_caller),
+            Arc::new(mock_prices_refresh()),
+            retry_policy(),
+            Arc::new(WorkerTracker::default()),
+        );
+        worker.drain().await.unwrap();
+    }
 
-        let dedup_set = Arc::new(Mutex::new(HashSet::new()));
-        let (tx, rx) = unbounded_channel::<(CardId, String)>();
-        tx.send((make_card_id("0"), "Name".to_string())).unwrap();
-        drop(tx);
+    #[tokio::test]
+    async fn worker_backs_off_a_card_without_gatherer_page() {
+        let mut job_repository = claiming(vec![EnrichmentJob {
+            card_attempts: 1,
+            ..job(7, 1)
+        }]);
+        let mut gatherer_caller = MockGathererCaller::new();
+
+        gatherer_caller
+            .expect_get_gatherer_id()
+            .returning(|_, _, _, _| Box::pin(async { Ok(None) }));
+        job_repository
+            .expect_record_attempt()
+            .withf(|kind, card_id, outcome, next_attempt_at| {
+                *kind == EnrichmentKind::GathererId
+                    && *card_id == make_card_id("0")
+                    && *outcome == EnrichmentOutcome::NotFound("No Gatherer page found".to_string())
+                    && next_attempt_at
+                        .is_some_and(|at| at > Utc::now() + chrono::Duration::hours(47))
+            })
+            .times(1)
+            .returning(|_, _, _, _| Box::pin(async { Ok(()) }));
+        job_repository
+            .expect_complete()
+            .returning(|_| Box::pin(async { Ok(()) }));
 
         let worker = GathererIdWorker::new(
-            Arc::new(card_repository),
+            Arc::new(MockCardRepository::new()),
+            Arc::new(job_repository),
             Arc::new(gatherer_caller),
-            Arc::new(mock_prices_view()),
-            dedup_set,
+            Arc::new(mock_prices_refresh()),
+            retry_policy(),
+            Arc::new(WorkerTracker::default()),
         );
-        worker.run(rx).await.unwrap();
+        worker.drain().await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn worker_tracks_its_processed_and_failed_jobs() {
+        let mut job_repository = job_repository(vec![
+            job(1, 1),
+            EnrichmentJob {
+                card_id: make_card_id("1"),
+                ..job(2, 1)
+            },
+        ]);
+        let mut gatherer_caller = MockGathererCaller::new();
+
+        gatherer_caller
+            .expect_get_gatherer_id()
+            .withf(|_, collector_number, _, _| collector_number == "0")
+            .returning(|_, _, _, _| Box::pin(async { Ok(None) }));
+        gatherer_caller
+            .expect_get_gatherer_id()
+            .returning(|_, _, _, _| {
+                Box::pin(async {
+                    Err(AppError::Infra(InfraError::CallError(
+                        "Gatherer error".to_string(),
+                    )))
+                })
+            });
+        job_repository
+            .expect_complete()
+            .returning(|_| Box::pin(async { Ok(()) }));
+        job_repository
+            .expect_fail()
+            .returning(|_, _, _| Box::pin(async { Ok(()) }));
+        let tracker = Arc::new(WorkerTracker::default());
+
+        let worker = GathererIdWorker::new(
+            Arc::new(MockCardRepository::new()),
+            Arc::new(job_repository),
+            Arc::new(gatherer_caller),
+            Arc::new(mock_prices_refresh()),
+            retry_policy(),
+            tracker.clone(),
+        );
+        worker.drain().await.unwrap();
+
+        let activity = tracker.activity();
+        assert_eq!(activity.processed, 2);
+        assert_eq!(activity.failed, 1);
+        assert_eq!(activity.last_processed, Some(make_card_id("1")));
+        assert!(activity.last_error.unwrap().contains("Gatherer error"));
     }
 }
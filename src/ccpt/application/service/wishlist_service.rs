@@ -0,0 +1,711 @@
+use crate::application::error::AppError;
+use crate::application::repository::WishlistRepository;
+use crate::application::use_case::{ManageWishlistUseCase, ScheduleCardPricesRefreshUseCase};
+use crate::domain::card::CardSelector;
+use crate::domain::card_prices_refresh::CardPricesRefresh;
+use crate::domain::error::FunctionalError;
+use crate::domain::user::UserId;
+use crate::domain::wishlist::{
+    NewWishlistEntry, WishPriority, WishlistEntry, WishlistEntryId, WishlistEntryUpdate,
+    WishlistImport, WishlistItem,
+};
+use async_trait::async_trait;
+use chrono::Utc;
+use std::collections::HashMap;
+use std::sync::Arc;
+
+/// Section headers of the decklist formats exported by Arena, Moxfield and the like.
+const DECKLIST_SECTIONS: [&str; 6] = [
+    "deck",
+    "mainboard",
+    "sideboard",
+    "commander",
+    "companion",
+    "maybeboard",
+];
+
+/// A card of a decklist, its copies summed over the sections.
+#[derive(Debug, PartialEq, Eq)]
+struct DecklistCard {
+    quantity: u8,
+    /// Name as written, without the set and collector number
+    name: String,
+}
+
+/// Name of a decklist line without its trailing `(SET) 123` printing and `*F*` foil marker.
+fn strip_printing(name: &str) -> &str {
+    let name = name.trim_end_matches("*F*").trim_end();
+    match name.rfind(" (") {
+        Some(start) => {
+            let set_code = name[start + 2..].split(')').next().unwrap_or_default();
+            let is_set_code = (2..=6).contains(&set_code.len())
+                && set_code.chars().all(|c| c.is_ascii_alphanumeric())
+                && name[start + 2..].contains(')');
+            if is_set_code {
+                name[..start].trim_end()
+            } else {
+                name
+            }
+        }
+        None => name,
+    }
+}
+
+/// Cards of a plain text decklist, one `4 Lightning Bolt` (or `4x`) per line. A line without
+/// quantity counts one copy; blank lines, comments and section headers are skipped.
+fn parse_decklist(decklist: &str) -> Result<Vec<DecklistCard>, FunctionalError> {
+    let mut cards: Vec<DecklistCard> = Vec::new();
+    for (index, line) in decklist.lines().enumerate() {
+        let line = line.trim();
+        let section = line.trim_end_matches(':').to_lowercase();
+        if line.is_empty()
+            || line.starts_with("//")
+            || line.starts_with('#')
+            || DECKLIST_SECTIONS.contains(&section.as_str())
+        {
+            continue;
+        }
+
+        let digits = line.chars().take_while(char::is_ascii_digit).count();
+        let (quantity, name) = if digits == 0 {
+            (1, line)
+        } else {
+            let rest = line[digits..]
+                .strip_prefix(['x', 'X'])
+                .unwrap_or(&line[digits..]);
+            let quantity = line[..digits]
+                .parse::<u8>()
+                .ok()
+                .filter(|q| *q > 0 && rest.starts_with(char::is_whitespace))
+                .ok_or_else(|| FunctionalError::ParseError {
+                    line: index + 1,
+                    field: "quantity",
+                    value: line.to_string(),
+                })?;
+            (quantity, rest)
+        };
+
+        let name = strip_printing(name.trim());
+        if name.is_empty() {
+            return Err(FunctionalError::ParseError {
+                line: index + 1,
+                field: "name",
+                value: line.to_string(),
+            });
+        }
+        match cards
+            .iter_mut()
+            .find(|card| card.name.eq_ignore_ascii_case(name))
+        {
+            Some(card) => card.quantity = card.quantity.saturating_add(quantity),
+            None => cards.push(DecklistCard {
+                quantity,
+                name: name.to_string(),
+            }),
+        }
+    }
+    Ok(cards)
+}
+
+pub struct WishlistService {
+    wishlist_repository: Arc<dyn WishlistRepository>,
+    card_prices_refresh: Arc<dyn ScheduleCardPricesRefreshUseCase>,
+}
+
+impl WishlistService {
+    pub fn new(
+        wishlist_repository: Arc<dyn WishlistRepository>,
+        card_prices_refresh: Arc<dyn ScheduleCardPricesRefreshUseCase>,
+    ) -> Self {
+        Self {
+            wishlist_repository,
+            card_prices_refresh,
+        }
+    }
+
+    /// The trade matches of the user are rebuilt with the next card prices refresh.
+    fn request_matches_refresh(&self, user_id: &UserId) {
+        self.card_prices_refresh
+            .request(CardPricesRefresh::Wishlist(user_id.clone()));
+    }
+
+    async fn find_item(
+        &self,
+        user_id: &UserId,
+        id: WishlistEntryId,
+    ) -> Result<WishlistItem, AppError> {
+        self.wishlist_repository
+            .find_by_id(user_id, id)
+            .await?
+            .ok_or_else(|| FunctionalError::WishlistEntryNotFound.into())
+    }
+}
+
+fn filters_on_a_printing() -> AppError {
+    FunctionalError::WrongFormat(
+        "languages and finishes only apply to a wish for any printing of a card".to_string(),
+    )
+    .into()
+}
+
+#[async_trait]
+impl ManageWishlistUseCase for WishlistService {
+    async fn list_entries(&self, user_id: &UserId) -> Result<Vec<WishlistItem>, AppError> {
+        self.wishlist_repository.find_all(user_id).await
+    }
+
+    async fn add_entry(
+        &self,
+        user_id: &UserId,
+        entry: NewWishlistEntry,
+    ) -> Result<WishlistItem, AppError> {
+        if matches!(entry.card, CardSelector::Printing(_))
+            && (!entry.languages.is_empty() || !entry.finishes.is_empty())
+        {
+            return Err(filters_on_a_printing());
+        }
+        let name = self
+            .wishlist_repository
+            .find_card_name(&entry.card)
+            .await?
+            .ok_or(FunctionalError::CardNotFound)?;
+
+        let id = WishlistEntryId::new();
+        let created = self
+            .wishlist_repository
+            .create(WishlistEntry {
+                id,
+                user_id: user_id.clone(),
+                card: entry.card,
+                name,
+                quantity: entry.quantity,
+                max_price: entry.max_price,
+                languages: entry.languages,
+                finishes: entry.finishes,
+                priority: entry.priority,
+                created_at: Utc::now(),
+            })
+            .await?;
+        if !created {
+            return Err(FunctionalError::WishlistEntryAlreadyExists.into());
+        }
+
+        self.request_matches_refresh(user_id);
+        self.find_item(user_id, id).await
+    }
+
+    async fn update_entry(
+        &self,
+        user_id: &UserId,
+        id: WishlistEntryId,
+        update: WishlistEntryUpdate,
+    ) -> Result<WishlistItem, AppError> {
+        let item = self.find_item(user_id, id).await?;
+        if matches!(item.entry.card, CardSelector::Printing(_)) && update.sets_filters() {
+            return Err(filters_on_a_printing());
+        }
+        if !self.wishlist_repository.update(user_id, id, update).await? {
+            return Err(FunctionalError::WishlistEntryNotFound.into());
+        }
+
+        self.request_matches_refresh(user_id);
+        self.find_item(user_id, id).await
+    }
+
+    async fn remove_entry(&self, user_id: &UserId, id: WishlistEntryId) -> Result<(), AppError> {
+        if !self.wishlist_repository.delete(user_id, id).await? {
+            return Err(FunctionalError::WishlistEntryNotFound.into());
+        }
+
+        self.request_matches_refresh(user_id);
+        Ok(())
+    }
+
+    async fn import_decklist(
+        &self,
+        user_id: &UserId,
+        decklist: &str,
+    ) -> Result<WishlistImport, AppError> {
+        let cards = parse_decklist(decklist)?;
+        if cards.is_empty() {
+            return Err(
+                FunctionalError::WrongFormat("The decklist has no card".to_string()).into(),
+            );
+        }
+
+        let known = self
+            .wishlist_repository
+            .find_oracle_ids_by_names(cards.iter().map(|c| c.name.to_lowercase()).collect())
+            .await?;
+
+        // Un nom complet et sa première face désignent la même carte : une seule entrée
+        let mut entries: Vec<WishlistEntry> = Vec::new();
+        let mut positions: HashMap<uuid::Uuid, usize> = HashMap::new();
+        let mut unknown_names = Vec::new();
+        for card in cards {
+            let Some((oracle_id, name)) = known.get(&card.name.to_lowercase()) else {
+                unknown_names.push(card.name);
+                continue;
+            };
+            match positions.get(oracle_id) {
+                Some(&position) => {
+                    let entry = &mut entries[position];
+                    entry.quantity = entry.quantity.saturating_add(card.quantity);
+                }
+                None => {
+                    positions.insert(*oracle_id, entries.len());
+                    entries.push(WishlistEntry {
+                        id: WishlistEntryId::new(),
+                        user_id: user_id.clone(),
+                        card: CardSelector::AnyPrinting {
+                            oracle_id: *oracle_id,
+                        },
+                        name: name.clone(),
+                        quantity: card.quantity,
+                        max_price: None,
+                        languages: vec![],
+                        finishes: vec![],
+                        priority: WishPriority::default(),
+                        created_at: Utc::now(),
+                    });
+                }
+            }
+        }
+
+        let imported = if entries.is_empty() {
+            0
+        } else {
+            self.wishlist_repository.merge_all(entries).await?
+        };
+        // Des souhaits déjà assez élevés ne changent pas les correspondances
+        if imported > 0 {
+            self.request_matches_refresh(user_id);
+        }
+        Ok(WishlistImport {
+            imported,
+            unknown_names,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::application::repository::MockWishlistRepository;
+    use crate::application::use_case::MockScheduleCardPricesRefreshUseCase;
+    use crate::domain::card::CardId;
+    use crate::domain::language_code::LanguageCode;
+    use crate::domain::wishlist::Finish;
+    use mockall::predicate::eq;
+    use uuid::Uuid;
+
+    /// Service expecting `times` refreshes of the trade matches of `user1`.
+    fn service(wishlist_repository: MockWishlistRepository, times: usize) -> WishlistService {
+        let mut card_prices_refresh = MockScheduleCardPricesRefreshUseCase::new();
+        card_prices_refresh
+            .expect_request()
+            .with(eq(CardPricesRefresh::Wishlist(UserId::new("user1"))))
+            .times(times)
+            .return_const(());
+
+        WishlistService::new(Arc::new(wishlist_repository), Arc::new(card_prices_refresh))
+    }
+
+    fn oracle_id() -> Uuid {
+        Uuid::parse_str("44623693-51d6-49ad-8cd7-140505caf02f").unwrap()
+    }
+
+    fn item(card: CardSelector) -> WishlistItem {
+        WishlistItem {
+            entry: WishlistEntry {
+                id: WishlistEntryId(Uuid::nil()),
+                user_id: UserId::new("user1"),
+                card,
+                name: "Goblin Boarders".to_string(),
+                quantity: 1,
+                max_price: None,
+                languages: vec![],
+                finishes: vec![],
+                priority: WishPriority::Normal,
+                created_at: Utc::now(),
+            },
+            offer_count: 2,
+            cheapest_offer: Some(15),
+        }
+    }
+
+    fn new_entry(card: CardSelector) -> NewWishlistEntry {
+        NewWishlistEntry {
+            card,
+            quantity: 2,
+            max_price: Some(50),
+            languages: vec![LanguageCode::FR],
+            finishes: vec![],
+            priority: WishPriority::High,
+        }
+    }
+
+    #[test]
+    fn parse_decklist_reads_quantities_and_skips_sections_and_comments() {
+        let cards = parse_decklist(
+            "Deck\n4 Lightning Bolt\n2x Counterspell\n\n// Side\nSideboard:\nSol Ring\n",
+        )
+        .unwrap();
+
+        assert_eq!(
+            cards,
+            vec![
+                DecklistCard {
+                    quantity: 4,
+                    name: "Lightning Bolt".to_string()
+                },
+                DecklistCard {
+                    quantity: 2,
+                    name: "Counterspell".to_string()
+                },
+                DecklistCard {
+                    quantity: 1,
+                    name: "Sol Ring".to_string()
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn parse_decklist_strips_the_printing_and_sums_repeated_cards() {
+        let cards =
+            parse_decklist("1 Sol Ring (C21) 263 *F*\n2 sol ring\n1 B.F.M. (Big Furry Monster)")
+                .unwrap();
+
+        assert_eq!(
+            cards,
+            vec![
+                DecklistCard {
+                    quantity: 3,
+                    name: "Sol Ring".to_string()
+                },
+                DecklistCard {
+                    quantity: 1,
+                    name: "B.F.M. (Big Furry Monster)".to_string()
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn parse_decklist_rejects_a_zero_or_oversized_quantity() {
+        for decklist in ["Deck\n0 Sol Ring", "Deck\n300 Sol Ring"] {
+            assert_eq!(
+                parse_decklist(decklist),
+                Err(FunctionalError::ParseError {
+                    line: 2,
+                    field: "quantity",
+                    value: decklist.lines().nth(1).unwrap().to_string(),
+                })
+            );
+        }
+    }
+
+    #[test]
+    fn strip_printing_keeps_a_name_without_printing() {
+        assert_eq!(strip_printing("Fire // Ice"), "Fire // Ice");
+        assert_eq!(strip_printing("Sol Ring (C21)"), "Sol Ring");
+    }
+
+    #[tokio::test]
+    async fn add_entry_names_the_wish_after_its_card() {
+        let mut repository = MockWishlistRepository::new();
+        repository
+            .expect_find_card_name()
+            .withf(|card| {
+                *card
+                    == CardSelector::AnyPrinting {
+                        oracle_id: oracle_id(),
+                    }
+            })
+            .returning(|_| Box::pin(async { Ok(Some("Goblin Boarders".to_string())) }));
+        repository
+            .expect_create()
+            .withf(|entry| {
+                entry.name == "Goblin Boarders"
+                    && entry.quantity == 2
+                    && entry.max_price == Some(50)
+                    && entry.priority == WishPriority::High
+                    && entry.user_id == UserId::new("user1")
+            })
+            .times(1)
+            .returning(|_| Box::pin(async { Ok(true) }));
+        repository.expect_find_by_id().returning(|_, _| {
+            Box::pin(async {
+                Ok(Some(item(CardSelector::AnyPrinting {
+                    oracle_id: oracle_id(),
+                })))
+            })
+        });
+
+        let result = service(repository, 1)
+            .add_entry(
+                &UserId::new("user1"),
+                new_entry(CardSelector::AnyPrinting {
+                    oracle_id: oracle_id(),
+                }),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(result.offer_count, 2);
+    }
+
+    #[tokio::test]
+    async fn add_entry_rejects_filters_on_a_printing() {
+        let mut repository = MockWishlistRepository::new();
+        repository.expect_create().never();
+
+        let result = service(repository, 0)
+            .add_entry(
+                &UserId::new("user1"),
+                new_entry(CardSelector::Printing(CardId::new(
+                    "FDN",
+                    "87",
+                    LanguageCode::FR,
+                    false,
+                ))),
+            )
+            .await;
+
+        assert!(matches!(
+            result,
+            Err(AppError::Functional(FunctionalError::WrongFormat(_)))
+        ));
+    }
+
+    #[tokio::test]
+    async fn add_entry_returns_not_found_for_an_unknown_card() {
+        let mut repository = MockWishlistRepository::new();
+        repository
+            .expect_find_card_name()
+            .returning(|_| Box::pin(async { Ok(None) }));
+        repository.expect_create().never();
+
+        let result = service(repository, 0)
+            .add_entry(
+                &UserId::new("user1"),
+                new_entry(CardSelector::AnyPrinting {
+                    oracle_id: oracle_id(),
+                }),
+            )
+            .await;
+
+        assert!(matches!(
+            result,
+            Err(AppError::Functional(FunctionalError::CardNotFound))
+        ));
+    }
+
+    #[tokio::test]
+    async fn add_entry_refuses_a_card_already_wished() {
+        let mut repository = MockWishlistRepository::new();
+        repository
+            .expect_find_card_name()
+            .returning(|_| Box::pin(async { Ok(Some("Goblin Boarders".to_string())) }));
+        repository
+            .expect_create()
+            .returning(|_| Box::pin(async { Ok(false) }));
+
+        let result = service(repository, 0)
+            .add_entry(
+                &UserId::new("user1"),
+                new_entry(CardSelector::AnyPrinting {
+                    oracle_id: oracle_id(),
+                }),
+            )
+            .await;
+
+        assert!(matches!(
+            result,
+            Err(AppError::Functional(
+                FunctionalError::WishlistEntryAlreadyExists
+            ))
+        ));
+    }
+
+    #[tokio::test]
+    async fn update_entry_rejects_filters_on_a_printing() {
+        let mut repository = MockWishlistRepository::new();
+        repository.expect_find_by_id().returning(|_, _| {
+            Box::pin(async {
+                Ok(Some(item(CardSelector::Printing(CardId::new(
+                    "FDN",
+                    "87",
+                    LanguageCode::FR,
+                    false,
+                )))))
+            })
+        });
+        repository.expect_update().never();
+
+        let result = service(repository, 0)
+            .update_entry(
+                &UserId::new("user1"),
+                WishlistEntryId::new(),
+                WishlistEntryUpdate {
+                    finishes: Some(vec![Finish::Foil]),
+                    ..WishlistEntryUpdate::default()
+                },
+            )
+            .await;
+
+        assert!(matches!(
+            result,
+            Err(AppError::Functional(FunctionalError::WrongFormat(_)))
+        ));
+    }
+
+    #[tokio::test]
+    async fn update_entry_returns_not_found_for_an_unknown_wish() {
+        let mut repository = MockWishlistRepository::new();
+        repository
+            .expect_find_by_id()
+            .returning(|_, _| Box::pin(async { Ok(None) }));
+        repository.expect_update().never();
+
+        let result = service(repository, 0)
+            .update_entry(
+                &UserId::new("user1"),
+                WishlistEntryId::new(),
+                WishlistEntryUpdate::default(),
+            )
+            .await;
+
+        assert!(matches!(
+            result,
+            Err(AppError::Functional(FunctionalError::WishlistEntryNotFound))
+        ));
+    }
+
+    #[tokio::test]
+    async fn remove_entry_returns_not_found_for_an_unknown_wish() {
+        let mut repository = MockWishlistRepository::new();
+        repository
+            .expect_delete()
+            .returning(|_, _| Box::pin(async { Ok(false) }));
+
+        let result = service(repository, 0)
+            .remove_entry(&UserId::new("user1"), WishlistEntryId::new())
+            .await;
+
+        assert!(matches!(
+            result,
+            Err(AppError::Functional(FunctionalError::WishlistEntryNotFound))
+        ));
+    }
+
+    #[tokio::test]
+    async fn remove_entry_refreshes_the_trade_matches() {
+        let mut repository = MockWishlistRepository::new();
+        repository
+            .expect_delete()
+            .returning(|_, _| Box::pin(async { Ok(true) }));
+
+        let result = service(repository, 1)
+            .remove_entry(&UserId::new("user1"), WishlistEntryId::new())
+            .await;
+
+        assert!(result.is_ok());
+    }
+
+    #[tokio::test]
+    async fn import_decklist_merges_the_known_cards_and_reports_the_unknown_ones() {
+        let mut repository = MockWishlistRepository::new();
+        repository
+            .expect_find_oracle_ids_by_names()
+            .withf(|names| {
+                names
+                    == &vec![
+                        "delver of secrets".to_string(),
+                        "delver of secrets // insectile aberration".to_string(),
+                        "not a card".to_string(),
+                    ]
+            })
+            .returning(|_| {
+                Box::pin(async {
+                    let name = "Delver of Secrets // Insectile Aberration".to_string();
+                    Ok(HashMap::from([
+                        ("delver of secrets".to_string(), (oracle_id(), name.clone())),
+                        (
+                            "delver of secrets // insectile aberration".to_string(),
+                            (oracle_id(), name),
+                        ),
+                    ]))
+                })
+            });
+        repository
+            .expect_merge_all()
+            .withf(|entries| {
+                entries.len() == 1
+                    && entries[0].quantity == 4
+                    && entries[0].card
+                        == CardSelector::AnyPrinting {
+                            oracle_id: oracle_id(),
+                        }
+                    && entries[0].name == "Delver of Secrets // Insectile Aberration"
+            })
+            .times(1)
+            .returning(|_| Box::pin(async { Ok(1) }));
+
+        let result = service(repository, 1)
+            .import_decklist(
+                &UserId::new("user1"),
+                "3 Delver of Secrets\n1 Delver of Secrets // Insectile Aberration\n2 Not a card",
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(
+            result,
+            WishlistImport {
+                imported: 1,
+                unknown_names: vec!["Not a card".to_string()],
+            }
+        );
+    }
+
+    #[tokio::test]
+    async fn import_decklist_does_not_count_the_wishes_left_as_they_are() {
+        let mut repository = MockWishlistRepository::new();
+        repository.expect_find_oracle_ids_by_names().returning(|_| {
+            Box::pin(async {
+                Ok(HashMap::from([(
+                    "delver of secrets".to_string(),
+                    (oracle_id(), "Delver of Secrets".to_string()),
+                )]))
+            })
+        });
+        repository
+            .expect_merge_all()
+            .times(1)
+            .returning(|_| Box::pin(async { Ok(0) }));
+
+        let result = service(repository, 0)
+            .import_decklist(&UserId::new("user1"), "1 Delver of Secrets")
+            .await
+            .unwrap();
+
+        assert_eq!(result.imported, 0);
+    }
+
+    #[tokio::test]
+    async fn import_decklist_rejects_an_empty_decklist() {
+        let mut repository = MockWishlistRepository::new();
+        repository.expect_merge_all().never();
+
+        let result = service(repository, 0)
+            .import_decklist(&UserId::new("user1"), "Deck\n\n")
+            .await;
+
+        assert!(matches!(
+            result,
+            Err(AppError::Functional(FunctionalError::WrongFormat(_)))
+        ));
+    }
+}
@@ -0,0 +1,172 @@
+use crate::application::error::AppError;
+use crate::application::repository::EnrichmentJobRepository;
+use crate::application::use_case::GetWorkersStatusUseCase;
+use crate::domain::card::CardId;
+use crate::domain::enrichment_job::EnrichmentKind;
+use crate::domain::worker_status::{WorkerActivity, WorkerStatus};
+use async_trait::async_trait;
+use chrono::Utc;
+use std::sync::{Arc, Mutex};
+
+/// Activity of a worker, updated by the worker itself and read by the monitor.
+#[derive(Default)]
+pub struct WorkerTracker {
+    activity: Mutex<WorkerActivity>,
+}
+
+impl WorkerTracker {
+    pub fn processed(&self, card_id: CardId) {
+        self.activity
+            .lock()
+            .unwrap()
+            .record_processed(card_id, Utc::now());
+    }
+
+    pub fn failed(&self, card_id: CardId, error: String) {
+        self.activity
+            .lock()
+            .unwrap()
+            .record_failed(card_id, error, Utc::now());
+    }
+
+    pub fn error(&self, error: String) {
+        self.activity
+            .lock()
+            .unwrap()
+            .record_error(error, Utc::now());
+    }
+
+    /// The task of the worker ended, it will not process anything until the next restart.
+    pub fn stopped(&self) {
+        self.activity.lock().unwrap().alive = false;
+    }
+
+    pub fn activity(&self) -> WorkerActivity {
+        self.activity.lock().unwrap().clone()
+    }
+}
+
+pub struct WorkerMonitorService {
+    enrichment_job_repository: Arc<dyn EnrichmentJobRepository>,
+    workers: Vec<(EnrichmentKind, Arc<WorkerTracker>)>,
+}
+
+impl WorkerMonitorService {
+    pub fn new(
+        enrichment_job_repository: Arc<dyn EnrichmentJobRepository>,
+        workers: Vec<(EnrichmentKind, Arc<WorkerTracker>)>,
+    ) -> Self {
+        Self {
+            enrichment_job_repository,
+            workers,
+        }
+    }
+}
+
+#[async_trait]
+impl GetWorkersStatusUseCase for WorkerMonitorService {
+    async fn get_workers_status(&self) -> Result<Vec<WorkerStatus>, AppError> {
+        let mut statuses = Vec::with_capacity(self.workers.len());
+
+        for (kind, tracker) in &self.workers {
+            let queue = self
+                .enrichment_job_repository
+                .count_by_status(*kind)
+                .await?;
+            statuses.push(WorkerStatus {
+                kind: *kind,
+                queue,
+                activity: tracker.activity(),
+            });
+        }
+
+        Ok(statuses)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::application::error::InfraError;
+    use crate::application::repository::MockEnrichmentJobRepository;
+    use crate::domain::language_code::LanguageCode;
+    use crate::domain::set_name::SetCode;
+    use crate::domain::worker_status::EnrichmentQueueDepth;
+
+    fn card_id() -> CardId {
+        CardId::new(SetCode::new("FDN"), "87", LanguageCode::FR, false)
+    }
+
+    #[tokio::test]
+    async fn get_workers_status_combines_the_queue_and_the_activity_of_each_worker() {
+        let mut repository = MockEnrichmentJobRepository::new();
+        repository
+            .expect_count_by_status()
+            .withf(|kind| *kind == EnrichmentKind::CardMarketId)
+            .returning(|_| {
+                Box::pin(async {
+                    Ok(EnrichmentQueueDepth {
+                        pending: 12,
+                        running: 3,
+                        dead: 1,
+                    })
+                })
+            });
+        repository
+            .expect_count_by_status()
+            .withf(|kind| *kind == EnrichmentKind::GathererId)
+            .returning(|_| Box::pin(async { Ok(EnrichmentQueueDepth::default()) }));
+        let cardmarket_id = Arc::new(WorkerTracker::default());
+        let gatherer_id = Arc::new(WorkerTracker::default());
+        cardmarket_id.processed(card_id());
+        gatherer_id.failed(card_id(), "Gatherer error".to_string());
+        gatherer_id.stopped();
+
+        let statuses = WorkerMonitorService::new(
+            Arc::new(repository),
+            vec![
+                (EnrichmentKind::CardMarketId, cardmarket_id),
+                (EnrichmentKind::GathererId, gatherer_id),
+            ],
+        )
+        .get_workers_status()
+        .await
+        .unwrap();
+
+        assert_eq!(statuses.len(), 2);
+        assert_eq!(statuses[0].kind, EnrichmentKind::CardMarketId);
+        assert_eq!(statuses[0].queue.pending, 12);
+        assert_eq!(statuses[0].activity.processed, 1);
+        assert!(statuses[0].activity.alive);
+        assert_eq!(statuses[1].activity.failed, 1);
+        assert_eq!(
+            statuses[1].activity.last_error.as_deref(),
+            Some("Gatherer error")
+        );
+        assert!(!statuses[1].activity.alive);
+    }
+
+    #[tokio::test]
+    async fn get_workers_status_propagates_repository_errors() {
+        let mut repository = MockEnrichmentJobRepository::new();
+        repository.expect_count_by_status().returning(|_| {
+            Box::pin(async {
+                Err(AppError::Infra(InfraError::RepositoryError(
+                    "DB error".to_string(),
+                )))
+            })
+        });
+
+        let result = WorkerMonitorService::new(
+            Arc::new(repository),
+            vec![(
+                EnrichmentKind::CardMarketId,
+                Arc::new(WorkerTracker::default()),
+            )],
+        )
+        .get_workers_status()
+        .await;
+
+        assert!(result.is_err());
+    }
+}
@@ -1,21 +1,118 @@
 use crate::application::error::AppError;
 use async_trait::async_trait;
 
-use crate::domain::card::CardId;
+use crate::domain::binder::{Binder, BinderId, BinderSummary, BinderUpdate};
+use crate::domain::card::{CardId, CardSelector, EdhRecCardInfo};
 use crate::domain::card_offer::{CardOfferSortField, PaginatedCardOffers};
+use crate::domain::card_prices_refresh::CardPricesRefresh;
 use crate::domain::collection::{CollectionQuery, PaginatedCollection, SearchQuery};
+use crate::domain::collection_item::{CollectionItem, CollectionItemUpdate};
 use crate::domain::collection_stats::CollectionStats;
-use crate::domain::price::PriceHistoryEntry;
+use crate::domain::commander_synergy::CommanderSynergyReport;
+use crate::domain::enrichment_job::UnresolvedCard;
+use crate::domain::http_cache::{HttpCacheSource, HttpCacheStats};
+use crate::domain::oracle_card::PaginatedOracleCards;
+use crate::domain::price::{PriceHistoryEntry, PriceHistoryOptions};
+use crate::domain::price_anomaly::PriceAnomaly;
+use crate::domain::scryfall_card::ScryfallCatalogImport;
+use crate::domain::set_name::SetCode;
+use crate::domain::set_price_index::SetPriceIndex;
 use crate::domain::stats::Stats;
 use crate::domain::trade::TradeId;
+use crate::domain::trade_match::TradeMatch;
 use crate::domain::user::{User, UserId, UserSuggestion};
+use crate::domain::wishlist::{
+    NewWishlistEntry, WishlistEntryId, WishlistEntryUpdate, WishlistImport, WishlistItem,
+};
+use crate::domain::worker_status::WorkerStatus;
 #[cfg(test)]
 use mockall::automock;
 
 #[async_trait]
 #[cfg_attr(test, automock)]
 pub trait ImportCardUseCase: Send + Sync {
-    async fn import_cards(&self, csv: &str, user: User) -> Result<(), AppError>;
+    /// Replaces the content of the binder, the user's default binder when `binder_id` is `None`.
+    async fn import_cards(
+        &self,
+        csv: &str,
+        user: User,
+        binder_id: Option<BinderId>,
+    ) -> Result<(), AppError>;
+}
+
+/// Entries of one of the user's binders, the default one when `binder_id` is `None`.
+#[async_trait]
+#[cfg_attr(test, automock)]
+pub trait ManageCollectionEntryUseCase: Send + Sync {
+    async fn add_entry(
+        &self,
+        user_id: &UserId,
+        binder_id: Option<BinderId>,
+        item: CollectionItem,
+    ) -> Result<(), AppError>;
+    async fn update_entry(
+        &self,
+        user_id: &UserId,
+        binder_id: Option<BinderId>,
+        card_id: CardId,
+        update: CollectionItemUpdate,
+    ) -> Result<CollectionItem, AppError>;
+    async fn remove_entry(
+        &self,
+        user_id: &UserId,
+        binder_id: Option<BinderId>,
+        card_id: CardId,
+    ) -> Result<(), AppError>;
+}
+
+#[async_trait]
+#[cfg_attr(test, automock)]
+pub trait ManageBindersUseCase: Send + Sync {
+    async fn list_binders(&self, user_id: &UserId) -> Result<Vec<BinderSummary>, AppError>;
+    async fn create_binder(
+        &self,
+        user_id: &UserId,
+        name: String,
+        tradable: bool,
+    ) -> Result<Binder, AppError>;
+    async fn update_binder(
+        &self,
+        user_id: &UserId,
+        binder_id: BinderId,
+        update: BinderUpdate,
+    ) -> Result<Binder, AppError>;
+    async fn delete_binder(&self, user_id: &UserId, binder_id: BinderId) -> Result<(), AppError>;
+}
+
+#[async_trait]
+#[cfg_attr(test, automock)]
+pub trait ManageWishlistUseCase: Send + Sync {
+    async fn list_entries(&self, user_id: &UserId) -> Result<Vec<WishlistItem>, AppError>;
+    async fn add_entry(
+        &self,
+        user_id: &UserId,
+        entry: NewWishlistEntry,
+    ) -> Result<WishlistItem, AppError>;
+    async fn update_entry(
+        &self,
+        user_id: &UserId,
+        id: WishlistEntryId,
+        update: WishlistEntryUpdate,
+    ) -> Result<WishlistItem, AppError>;
+    async fn remove_entry(&self, user_id: &UserId, id: WishlistEntryId) -> Result<(), AppError>;
+    /// Wishes for any printing of each card of a plain text decklist (`4 Lightning Bolt`).
+    async fn import_decklist(
+        &self,
+        user_id: &UserId,
+        decklist: &str,
+    ) -> Result<WishlistImport, AppError>;
+}
+
+#[async_trait]
+#[cfg_attr(test, automock)]
+pub trait GetTradeMatchesUseCase: Send + Sync {
+    /// Other users to trade with both ways, the best balanced first.
+    async fn get_matches(&self, user_id: &UserId) -> Result<Vec<TradeMatch>, AppError>;
 }
 
 #[async_trait]
@@ -36,6 +133,50 @@ pub trait EnqueueGathererIdUpdateUseCase: Send + Sync {
     async fn enqueue_pending_updates(&self) -> Result<usize, AppError>;
 }
 
+#[async_trait]
+#[cfg_attr(test, automock)]
+pub trait GetUnresolvedCardsUseCase: Send + Sync {
+    /// Cards whose CardMarket or Gatherer id could not be found, with the reason.
+    async fn get_unresolved_cards(&self) -> Result<Vec<UnresolvedCard>, AppError>;
+}
+
+#[async_trait]
+#[cfg_attr(test, automock)]
+pub trait GetWorkersStatusUseCase: Send + Sync {
+    /// State of the enrichment workers of this instance and of their queues.
+    async fn get_workers_status(&self) -> Result<Vec<WorkerStatus>, AppError>;
+}
+
+#[async_trait]
+#[cfg_attr(test, automock)]
+pub trait HttpCacheUseCase: Send + Sync {
+    /// Content of the HTTP response cache, for every source.
+    async fn get_stats(&self) -> Result<Vec<HttpCacheStats>, AppError>;
+    /// Deletes the cached responses of `source`, or all of them, and returns how many were
+    /// deleted.
+    async fn purge(&self, source: Option<HttpCacheSource>) -> Result<u64, AppError>;
+}
+
+#[async_trait]
+#[cfg_attr(test, automock)]
+pub trait ScryfallCatalogUseCase: Send + Sync {
+    /// Refreshes the local catalogue from the Scryfall bulk data file, then resolves the
+    /// `cardmarket_id` and metadata of the cards found in it.
+    async fn import_catalog(&self) -> Result<ScryfallCatalogImport, AppError>;
+    /// Copies the metadata of the local catalogue onto the cards, then resolves from it the
+    /// `cardmarket_id` of the cards missing one. Returns the number of cards resolved.
+    async fn resolve_cardmarket_ids(&self) -> Result<usize, AppError>;
+}
+
+#[async_trait]
+#[cfg_attr(test, automock)]
+pub trait ScheduleCardPricesRefreshUseCase: Send + Sync {
+    /// Queues the refresh, coalesced with the ones not run yet. Does not wait for it.
+    fn request(&self, refresh: CardPricesRefresh);
+    /// Time the last refresh ended successfully, `None` since startup.
+    fn last_refreshed_at(&self) -> Option<chrono::DateTime<chrono::Utc>>;
+}
+
 #[async_trait]
 #[cfg_attr(test, automock)]
 pub trait CardCollectionPriceCalculationUseCase: Send + Sync {
@@ -48,6 +189,24 @@ pub trait ImportPriceUseCase: Send + Sync {
     async fn import_prices_for_current_date(&self) -> Result<(), AppError>;
 }
 
+#[async_trait]
+#[cfg_attr(test, automock)]
+pub trait ReviewPriceAnomalyUseCase: Send + Sync {
+    async fn get_pending_anomalies(&self) -> Result<Vec<PriceAnomaly>, AppError>;
+    async fn confirm(
+        &self,
+        id_product: u32,
+        date: chrono::NaiveDate,
+        foil: bool,
+    ) -> Result<(), AppError>;
+    async fn reject(
+        &self,
+        id_product: u32,
+        date: chrono::NaiveDate,
+        foil: bool,
+    ) -> Result<(), AppError>;
+}
+
 #[async_trait]
 #[cfg_attr(test, automock)]
 pub trait StatsUseCase: Send + Sync {
@@ -68,6 +227,11 @@ pub trait GetCollectionUseCase: Send + Sync {
 #[cfg_attr(test, automock)]
 pub trait SearchCardsUseCase: Send + Sync {
     async fn search_cards(&self, query: SearchQuery) -> Result<PaginatedCollection, AppError>;
+    /// Same search, the printings being grouped under their oracle card.
+    async fn search_oracle_cards(
+        &self,
+        query: SearchQuery,
+    ) -> Result<PaginatedOracleCards, AppError>;
 }
 
 #[async_trait]
@@ -78,9 +242,16 @@ pub trait GetCollectionPriceHistoryUseCase: Send + Sync {
         user_id: &UserId,
         start_date: Option<chrono::NaiveDate>,
         end_date: Option<chrono::NaiveDate>,
+        options: PriceHistoryOptions,
     ) -> Result<Vec<PriceHistoryEntry>, AppError>;
 }
 
+#[async_trait]
+#[cfg_attr(test, automock)]
+pub trait GetCardEdhRecUseCase: Send + Sync {
+    async fn get_card_edhrec(&self, scryfall_id: uuid::Uuid) -> Result<EdhRecCardInfo, AppError>;
+}
+
 #[async_trait]
 #[cfg_attr(test, automock)]
 pub trait GetCardPriceHistoryUseCase: Send + Sync {
@@ -89,13 +260,31 @@ pub trait GetCardPriceHistoryUseCase: Send + Sync {
         scryfall_id: uuid::Uuid,
         start_date: Option<chrono::NaiveDate>,
         end_date: Option<chrono::NaiveDate>,
+        options: PriceHistoryOptions,
     ) -> Result<Vec<PriceHistoryEntry>, AppError>;
 }
 
+#[async_trait]
+#[cfg_attr(test, automock)]
+pub trait GetSetPriceIndexUseCase: Send + Sync {
+    async fn get_set_price_index(
+        &self,
+        user_id: &UserId,
+        set_code: SetCode,
+        start_date: Option<chrono::NaiveDate>,
+        end_date: Option<chrono::NaiveDate>,
+    ) -> Result<SetPriceIndex, AppError>;
+}
+
 #[async_trait]
 #[cfg_attr(test, automock)]
 pub trait GetCollectionStatsUseCase: Send + Sync {
-    async fn get_collection_stats(&self, user_id: &UserId) -> Result<CollectionStats, AppError>;
+    async fn get_collection_stats(
+        &self,
+        user_id: &UserId,
+        binder_id: Option<BinderId>,
+        tags: Vec<String>,
+    ) -> Result<CollectionStats, AppError>;
 }
 
 #[async_trait]
@@ -104,13 +293,23 @@ pub trait GetCardOffersUseCase: Send + Sync {
     async fn get_card_offers(
         &self,
         user_id: &UserId,
-        card_id: CardId,
+        selector: CardSelector,
         sort_by: CardOfferSortField,
         page: u32,
         page_size: u32,
     ) -> Result<PaginatedCardOffers, AppError>;
 }
 
+#[async_trait]
+#[cfg_attr(test, automock)]
+pub trait GetCommanderSynergyUseCase: Send + Sync {
+    async fn get_commander_synergy(
+        &self,
+        user_id: &UserId,
+        commander: &str,
+    ) -> Result<CommanderSynergyReport, AppError>;
+}
+
 #[async_trait]
 #[cfg_attr(test, automock)]
 pub trait CreateTradeUseCase: Send + Sync {
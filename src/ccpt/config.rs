@@ -1,3 +1,4 @@
+use crate::domain::price::PriceImportScope;
 use std::env;
 use std::str::FromStr;
 
@@ -14,6 +15,29 @@ pub struct Config {
     pub scryfall_base_url: String,
     pub gatherer_base_url: String,
     pub clerk_frontend_api_url: String,
+    pub price_anomaly_window_days: u32,
+    pub price_anomaly_max_ratio: u32,
+    pub price_import_scope: PriceImportScope,
+    pub card_prices_refresh_debounce_ms: u64,
+    pub enrichment_poll_interval_ms: u64,
+    pub enrichment_max_attempts: u32,
+    pub enrichment_retry_base_delay_secs: i64,
+    pub enrichment_not_found_max_attempts: u32,
+    pub enrichment_not_found_base_delay_hours: i64,
+    pub scryfall_bulk_data_source: Option<String>,
+    pub edhrec_cache_ttl_hours: i64,
+    pub gatherer_rate_limit_tokens: u32,
+    pub edhrec_rate_limit_tokens: u32,
+    pub http_connect_timeout_ms: u64,
+    pub http_read_timeout_ms: u64,
+    pub http_max_retries: u32,
+    pub http_retry_base_delay_ms: u64,
+    pub http_retry_max_delay_ms: u64,
+    pub http_circuit_breaker_threshold: u32,
+    pub http_circuit_breaker_cooldown_secs: u64,
+    pub http_cache_scryfall_ttl_hours: i64,
+    pub http_cache_gatherer_ttl_hours: i64,
+    pub http_cache_edhrec_ttl_hours: i64,
 }
 
 impl Config {
@@ -40,6 +64,35 @@ impl Config {
                 .unwrap_or_else(|_| "https://gatherer.wizards.com".to_string()),
             clerk_frontend_api_url: env::var("CLERK_FRONTEND_API_URL")
                 .expect("CLERK_FRONTEND_API_URL must be set in environment variables"),
+            price_anomaly_window_days: parse_env_or("PRICE_ANOMALY_WINDOW_DAYS", 7),
+            price_anomaly_max_ratio: parse_env_or("PRICE_ANOMALY_MAX_RATIO", 5),
+            price_import_scope: parse_env_or("PRICE_IMPORT_SCOPE", PriceImportScope::default()),
+            card_prices_refresh_debounce_ms: parse_env_or("CARD_PRICES_REFRESH_DEBOUNCE_MS", 2000),
+            enrichment_poll_interval_ms: parse_env_or("ENRICHMENT_POLL_INTERVAL_MS", 5000),
+            enrichment_max_attempts: parse_env_or("ENRICHMENT_MAX_ATTEMPTS", 5),
+            enrichment_retry_base_delay_secs: parse_env_or("ENRICHMENT_RETRY_BASE_DELAY_SECS", 60),
+            enrichment_not_found_max_attempts: parse_env_or("ENRICHMENT_NOT_FOUND_MAX_ATTEMPTS", 8),
+            enrichment_not_found_base_delay_hours: parse_env_or(
+                "ENRICHMENT_NOT_FOUND_BASE_DELAY_HOURS",
+                24,
+            ),
+            scryfall_bulk_data_source: env::var("SCRYFALL_BULK_DATA_SOURCE").ok(),
+            edhrec_cache_ttl_hours: parse_env_or("EDHREC_CACHE_TTL_HOURS", 168),
+            gatherer_rate_limit_tokens: parse_env_or("GATHERER_RATE_LIMIT_TOKENS", 2),
+            edhrec_rate_limit_tokens: parse_env_or("EDHREC_RATE_LIMIT_TOKENS", 4),
+            http_connect_timeout_ms: parse_env_or("HTTP_CONNECT_TIMEOUT_MS", 10_000),
+            http_read_timeout_ms: parse_env_or("HTTP_READ_TIMEOUT_MS", 30_000),
+            http_max_retries: parse_env_or("HTTP_MAX_RETRIES", 3),
+            http_retry_base_delay_ms: parse_env_or("HTTP_RETRY_BASE_DELAY_MS", 500),
+            http_retry_max_delay_ms: parse_env_or("HTTP_RETRY_MAX_DELAY_MS", 30_000),
+            http_circuit_breaker_threshold: parse_env_or("HTTP_CIRCUIT_BREAKER_THRESHOLD", 5),
+            http_circuit_breaker_cooldown_secs: parse_env_or(
+                "HTTP_CIRCUIT_BREAKER_COOLDOWN_SECS",
+                60,
+            ),
+            http_cache_scryfall_ttl_hours: parse_env_or("HTTP_CACHE_SCRYFALL_TTL_HOURS", 168),
+            http_cache_gatherer_ttl_hours: parse_env_or("HTTP_CACHE_GATHERER_TTL_HOURS", 720),
+            http_cache_edhrec_ttl_hours: parse_env_or("HTTP_CACHE_EDHREC_TTL_HOURS", 24),
         }
     }
 }
@@ -77,6 +130,29 @@ mod tests {
         "SCRYFALL_BASE_URL",
         "GATHERER_BASE_URL",
         "CLERK_FRONTEND_API_URL",
+        "PRICE_ANOMALY_WINDOW_DAYS",
+        "PRICE_ANOMALY_MAX_RATIO",
+        "PRICE_IMPORT_SCOPE",
+        "CARD_PRICES_REFRESH_DEBOUNCE_MS",
+        "ENRICHMENT_POLL_INTERVAL_MS",
+        "ENRICHMENT_MAX_ATTEMPTS",
+        "ENRICHMENT_RETRY_BASE_DELAY_SECS",
+        "ENRICHMENT_NOT_FOUND_MAX_ATTEMPTS",
+        "ENRICHMENT_NOT_FOUND_BASE_DELAY_HOURS",
+        "SCRYFALL_BULK_DATA_SOURCE",
+        "EDHREC_CACHE_TTL_HOURS",
+        "GATHERER_RATE_LIMIT_TOKENS",
+        "EDHREC_RATE_LIMIT_TOKENS",
+        "HTTP_CONNECT_TIMEOUT_MS",
+        "HTTP_READ_TIMEOUT_MS",
+        "HTTP_MAX_RETRIES",
+        "HTTP_RETRY_BASE_DELAY_MS",
+        "HTTP_RETRY_MAX_DELAY_MS",
+        "HTTP_CIRCUIT_BREAKER_THRESHOLD",
+        "HTTP_CIRCUIT_BREAKER_COOLDOWN_SECS",
+        "HTTP_CACHE_SCRYFALL_TTL_HOURS",
+        "HTTP_CACHE_GATHERER_TTL_HOURS",
+        "HTTP_CACHE_EDHREC_TTL_HOURS",
     ];
 
     // Les variables d'env sont un état global du process : chaque test repart d'un état propre
@@ -119,6 +195,29 @@ mod tests {
         assert_eq!(config.scryfall_base_url, "https://api.scryfall.com");
         assert_eq!(config.gatherer_base_url, "https://gatherer.wizards.com");
         assert_eq!(config.clerk_frontend_api_url, "https://clerk.example.com");
+        assert_eq!(config.price_anomaly_window_days, 7);
+        assert_eq!(config.price_anomaly_max_ratio, 5);
+        assert_eq!(config.price_import_scope, PriceImportScope::All);
+        assert_eq!(config.card_prices_refresh_debounce_ms, 2000);
+        assert_eq!(config.enrichment_poll_interval_ms, 5000);
+        assert_eq!(config.enrichment_max_attempts, 5);
+        assert_eq!(config.enrichment_retry_base_delay_secs, 60);
+        assert_eq!(config.enrichment_not_found_max_attempts, 8);
+        assert_eq!(config.enrichment_not_found_base_delay_hours, 24);
+        assert_eq!(config.scryfall_bulk_data_source, None);
+        assert_eq!(config.edhrec_cache_ttl_hours, 168);
+        assert_eq!(config.gatherer_rate_limit_tokens, 2);
+        assert_eq!(config.edhrec_rate_limit_tokens, 4);
+        assert_eq!(config.http_connect_timeout_ms, 10_000);
+        assert_eq!(config.http_read_timeout_ms, 30_000);
+        assert_eq!(config.http_max_retries, 3);
+        assert_eq!(config.http_retry_base_delay_ms, 500);
+        assert_eq!(config.http_retry_max_delay_ms, 30_000);
+        assert_eq!(config.http_circuit_breaker_threshold, 5);
+        assert_eq!(config.http_circuit_breaker_cooldown_secs, 60);
+        assert_eq!(config.http_cache_scryfall_ttl_hours, 168);
+        assert_eq!(config.http_cache_gatherer_ttl_hours, 720);
+        assert_eq!(config.http_cache_edhrec_ttl_hours, 24);
 
         reset_env();
         set("DATABASE_MAX_CONNECTIONS", "42");
@@ -126,6 +225,29 @@ mod tests {
         set("SCRYFALL_RATE_LIMIT_TOKENS", "16");
         set("MAX_PAGE_SIZE", "50");
         set("MAX_PAGE_NUMBER", "5");
+        set("PRICE_ANOMALY_WINDOW_DAYS", "14");
+        set("PRICE_ANOMALY_MAX_RATIO", "3");
+        set("PRICE_IMPORT_SCOPE", "referenced");
+        set("CARD_PRICES_REFRESH_DEBOUNCE_MS", "500");
+        set("ENRICHMENT_POLL_INTERVAL_MS", "1000");
+        set("ENRICHMENT_MAX_ATTEMPTS", "8");
+        set("ENRICHMENT_RETRY_BASE_DELAY_SECS", "30");
+        set("ENRICHMENT_NOT_FOUND_MAX_ATTEMPTS", "4");
+        set("ENRICHMENT_NOT_FOUND_BASE_DELAY_HOURS", "12");
+        set("SCRYFALL_BULK_DATA_SOURCE", "/data/default-cards.json");
+        set("EDHREC_CACHE_TTL_HOURS", "24");
+        set("GATHERER_RATE_LIMIT_TOKENS", "1");
+        set("EDHREC_RATE_LIMIT_TOKENS", "2");
+        set("HTTP_CONNECT_TIMEOUT_MS", "5000");
+        set("HTTP_READ_TIMEOUT_MS", "60000");
+        set("HTTP_MAX_RETRIES", "0");
+        set("HTTP_RETRY_BASE_DELAY_MS", "250");
+        set("HTTP_RETRY_MAX_DELAY_MS", "10000");
+        set("HTTP_CIRCUIT_BREAKER_THRESHOLD", "10");
+        set("HTTP_CIRCUIT_BREAKER_COOLDOWN_SECS", "120");
+        set("HTTP_CACHE_SCRYFALL_TTL_HOURS", "24");
+        set("HTTP_CACHE_GATHERER_TTL_HOURS", "0");
+        set("HTTP_CACHE_EDHREC_TTL_HOURS", "48");
         set("CLERK_FRONTEND_API_URL", "https://clerk.example.com");
 
         let config = Config::from_env();
@@ -135,6 +257,32 @@ mod tests {
         assert_eq!(config.scryfall_rate_limit_tokens, 16);
         assert_eq!(config.max_page_size, 50);
         assert_eq!(config.max_page_number, 5);
+        assert_eq!(config.price_anomaly_window_days, 14);
+        assert_eq!(config.price_anomaly_max_ratio, 3);
+        assert_eq!(config.price_import_scope, PriceImportScope::Referenced);
+        assert_eq!(config.card_prices_refresh_debounce_ms, 500);
+        assert_eq!(config.enrichment_poll_interval_ms, 1000);
+        assert_eq!(config.enrichment_max_attempts, 8);
+        assert_eq!(config.enrichment_retry_base_delay_secs, 30);
+        assert_eq!(config.enrichment_not_found_max_attempts, 4);
+        assert_eq!(config.enrichment_not_found_base_delay_hours, 12);
+        assert_eq!(
+            config.scryfall_bulk_data_source.as_deref(),
+            Some("/data/default-cards.json")
+        );
+        assert_eq!(config.edhrec_cache_ttl_hours, 24);
+        assert_eq!(config.gatherer_rate_limit_tokens, 1);
+        assert_eq!(config.edhrec_rate_limit_tokens, 2);
+        assert_eq!(config.http_connect_timeout_ms, 5000);
+        assert_eq!(config.http_read_timeout_ms, 60000);
+        assert_eq!(config.http_max_retries, 0);
+        assert_eq!(config.http_retry_base_delay_ms, 250);
+        assert_eq!(config.http_retry_max_delay_ms, 10000);
+        assert_eq!(config.http_circuit_breaker_threshold, 10);
+        assert_eq!(config.http_circuit_breaker_cooldown_secs, 120);
+        assert_eq!(config.http_cache_scryfall_ttl_hours, 24);
+        assert_eq!(config.http_cache_gatherer_ttl_hours, 0);
+        assert_eq!(config.http_cache_edhrec_ttl_hours, 48);
 
         reset_env();
         let result = std::panic::catch_unwind(Config::from_env);
@@ -1,12 +1,26 @@
+pub mod binder;
 pub mod card;
+pub mod card_metadata;
 pub mod card_offer;
+pub mod card_prices_refresh;
 pub mod collection;
+pub mod collection_item;
 pub mod collection_stats;
+pub mod commander_synergy;
+pub mod enrichment_job;
 pub mod error;
+pub mod http_cache;
 pub mod language_code;
+pub mod oracle_card;
 pub mod price;
+pub mod price_anomaly;
 pub mod rarity_code;
+pub mod scryfall_card;
 pub mod set_name;
+pub mod set_price_index;
 pub mod stats;
 pub mod trade;
+pub mod trade_match;
 pub mod user;
+pub mod wishlist;
+pub mod worker_status;
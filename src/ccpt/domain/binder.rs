@@ -0,0 +1,69 @@
+use crate::domain::user::UserId;
+use chrono::{DateTime, Utc};
+use std::fmt::{Display, Formatter};
+
+/// Name given to the binder created for a user on their first import or manual add.
+pub const DEFAULT_BINDER_NAME: &str = "Collection";
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct BinderId(pub uuid::Uuid);
+
+impl BinderId {
+    pub fn new() -> Self {
+        Self(uuid::Uuid::new_v4())
+    }
+}
+
+impl Default for BinderId {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Display for BinderId {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+/// A named list of a user's collection entries (trade binder, deck box, ...).
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Binder {
+    pub id: BinderId,
+    pub user_id: UserId,
+    pub name: String,
+    /// Entries of tradable binders are the only ones other users see, in the card search and
+    /// offers, and can ask for in a trade.
+    pub tradable: bool,
+    /// Copies of each entry kept out of the trades, unless the entry sets its own tradable
+    /// quantity. 0 offers every copy.
+    pub keep_quantity: u8,
+    /// Binder the imports and manual adds go to when none is given.
+    pub is_default: bool,
+    pub created_at: DateTime<Utc>,
+}
+
+/// A binder with the number of copies it holds.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct BinderSummary {
+    pub binder: Binder,
+    pub card_count: u64,
+}
+
+/// Changes to a binder, `None` fields being left as they are.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct BinderUpdate {
+    pub name: Option<String>,
+    pub tradable: Option<bool>,
+    pub keep_quantity: Option<u8>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn binder_id_new_produces_different_ids() {
+        assert_ne!(BinderId::new(), BinderId::new());
+    }
+}
@@ -1,9 +1,13 @@
+use crate::domain::binder::BinderId;
+use crate::domain::card_metadata::CardMetadata;
 use crate::domain::error::FunctionalError;
 use crate::domain::language_code::LanguageCode;
 use crate::domain::price::PriceGuide;
 use crate::domain::rarity_code::RarityCode;
 use crate::domain::set_name::{SetCode, SetName};
+use chrono::{DateTime, Duration, Utc};
 use std::fmt::{Display, Formatter};
+use uuid::Uuid;
 
 #[derive(Clone, Debug, PartialEq, Eq, Hash)]
 pub struct CardId {
@@ -60,6 +64,23 @@ impl Display for CardId {
     }
 }
 
+/// The card targeted by an offer lookup, a search or a wish: either one exact printing, or any
+/// printing sharing the same Scryfall `oracle_id` (all sets, languages and finishes of the card).
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum CardSelector {
+    Printing(CardId),
+    AnyPrinting { oracle_id: Uuid },
+}
+
+impl Display for CardSelector {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            CardSelector::Printing(card_id) => write!(f, "{}", card_id),
+            CardSelector::AnyPrinting { oracle_id } => write!(f, "any printing of {}", oracle_id),
+        }
+    }
+}
+
 /// A card's presence in a collection: fully visible to its owner (`Mine`),
 /// reduced to just the seller's name for an individual sale listing (`Owned`,
 /// used by `/card/offers`), or reduced to an aggregate owner count for public
@@ -74,8 +95,20 @@ pub enum CollectionEntry {
         /// `true` if this card is engaged in one of the owner's trades in `ONE_ACCEPTED` or
         /// `FULLY_ACCEPTED` status.
         reserved: bool,
+        /// Binder holding the copies, `None` for a parsed import row not saved yet.
+        binder_id: Option<BinderId>,
+        /// Copies other users can ask for, once the binder and the entry's tradable quantity
+        /// are applied. `None` when not read from `card_prices` (parsed import row, ...).
+        tradable_quantity: Option<u8>,
+        /// Labels, notes and storage location set by hand, left untouched by an import.
+        tags: Vec<String>,
+        notes: Option<String>,
+        location: Option<String>,
     },
     Owned {
+        /// Printing offered, which differs from the requested one when offers are looked up for
+        /// any printing of the card.
+        card_id: CardId,
         owner_username: String,
         quantity: u8,
         /// Price in cents, derived from the card's `trend` price guide.
@@ -97,7 +130,10 @@ pub struct Card {
     pub scryfall_id: uuid::Uuid,
     pub cardmarket_id: Option<u32>,
     pub the_gatherer_id: Option<String>,
+    /// Gatherer id of the back face, only for transform and modal double-faced cards.
+    pub the_gatherer_back_id: Option<String>,
     pub price_guide: Option<PriceGuide>,
+    pub metadata: CardMetadata,
 }
 
 impl Card {
@@ -126,11 +162,18 @@ impl Card {
                 purchase_price,
                 added_at: chrono::Utc::now(),
                 reserved: false,
+                binder_id: None,
+                tradable_quantity: None,
+                tags: vec![],
+                notes: None,
+                location: None,
             },
             scryfall_id: uuid::Uuid::default(),
             cardmarket_id: None,
             the_gatherer_id: None,
+            the_gatherer_back_id: None,
             price_guide: None,
+            metadata: CardMetadata::default(),
         }
     }
 
@@ -159,15 +202,44 @@ impl Card {
             scryfall_id,
             cardmarket_id,
             the_gatherer_id,
+            the_gatherer_back_id: None,
             price_guide: None,
+            metadata: CardMetadata::default(),
         }
     }
 }
 
+/// Gatherer image ids of a card, one per face shown on its Gatherer page.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct GathererIds {
+    pub front: String,
+    /// `None` for single-faced and split cards, whose faces share one image.
+    pub back: Option<String>,
+}
+
+/// Commander statistics of a card on EDHREC.
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct CardInfo {
+    /// Decks playing the card
     pub inclusion: u32,
+    /// Decks whose colour identity allows the card
     pub total_decks: u32,
+    /// Popularity rank, `None` for unranked cards
+    pub rank: Option<u32>,
+}
+
+/// EDHREC statistics of a card, kept until they are older than the TTL.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct EdhRecCardInfo {
+    pub name: String,
+    pub info: CardInfo,
+    pub fetched_at: DateTime<Utc>,
+}
+
+impl EdhRecCardInfo {
+    pub fn is_fresh(&self, ttl: Duration, now: DateTime<Utc>) -> bool {
+        now - self.fetched_at < ttl
+    }
 }
 
 #[cfg(test)]
@@ -216,6 +288,20 @@ mod tests {
         assert_eq!(card_id.to_string(), "  456 FDN · FR");
     }
 
+    #[test]
+    fn display_card_selector() {
+        let printing = CardSelector::Printing(CardId::new("FDN", "456", LanguageCode::FR, false));
+        let any_printing = CardSelector::AnyPrinting {
+            oracle_id: Uuid::parse_str("44623693-51d6-49ad-8cd7-140505caf02f").unwrap(),
+        };
+
+        assert_eq!(printing.to_string(), "  456 FDN · FR");
+        assert_eq!(
+            any_printing.to_string(),
+            "any printing of 44623693-51d6-49ad-8cd7-140505caf02f"
+        );
+    }
+
     #[test]
     fn card_equality_same_values() {
         let card1 = Card::new(
@@ -262,4 +348,21 @@ mod tests {
 
         assert_ne!(card1, card2);
     }
+
+    #[test]
+    fn edhrec_card_info_is_fresh_until_the_ttl_elapsed() {
+        let now = Utc::now();
+        let info = EdhRecCardInfo {
+            name: "Sol Ring".to_string(),
+            info: CardInfo {
+                inclusion: 1,
+                total_decks: 2,
+                rank: Some(1),
+            },
+            fetched_at: now - Duration::hours(23),
+        };
+
+        assert!(info.is_fresh(Duration::hours(24), now));
+        assert!(!info.is_fresh(Duration::hours(23), now));
+    }
 }
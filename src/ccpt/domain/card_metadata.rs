@@ -0,0 +1,93 @@
+use std::fmt::Display;
+use uuid::Uuid;
+
+/// One of the five colours of Magic, in the WUBRG order.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Color {
+    W,
+    U,
+    B,
+    R,
+    G,
+}
+
+impl Color {
+    /// Parses a colour symbol (`W`, `U`, `B`, `R` or `G`), `None` for anything else.
+    pub fn from_symbol<S: AsRef<str>>(s: S) -> Option<Self> {
+        match s.as_ref().to_uppercase().as_str() {
+            "W" => Some(Color::W),
+            "U" => Some(Color::U),
+            "B" => Some(Color::B),
+            "R" => Some(Color::R),
+            "G" => Some(Color::G),
+            _ => None,
+        }
+    }
+
+    /// Parses the colour symbols of a card, ignoring the unknown ones, sorted in the WUBRG order.
+    pub fn parse_all<S: AsRef<str>>(symbols: impl IntoIterator<Item = S>) -> Vec<Self> {
+        let mut colors: Vec<Color> = symbols.into_iter().filter_map(Color::from_symbol).collect();
+        colors.sort();
+        colors.dedup();
+        colors
+    }
+}
+
+impl Display for Color {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Color::W => write!(f, "W"),
+            Color::U => write!(f, "U"),
+            Color::B => write!(f, "B"),
+            Color::R => write!(f, "R"),
+            Color::G => write!(f, "G"),
+        }
+    }
+}
+
+/// Gameplay data and images of a card, copied from Scryfall. Empty until the card is enriched.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct CardMetadata {
+    /// Shared by every printing of the same card
+    pub oracle_id: Option<Uuid>,
+    /// e.g. `{2}{W}{W}`, the faces separated by ` // ` for multi-faced cards
+    pub mana_cost: Option<String>,
+    pub type_line: Option<String>,
+    pub colors: Vec<Color>,
+    pub color_identity: Vec<Color>,
+    pub oracle_text: Option<String>,
+    pub image_small_url: Option<String>,
+    pub image_normal_url: Option<String>,
+    pub image_large_url: Option<String>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn from_symbol_is_case_insensitive() {
+        assert_eq!(Color::from_symbol("w"), Some(Color::W));
+        assert_eq!(Color::from_symbol("G"), Some(Color::G));
+    }
+
+    #[test]
+    fn from_symbol_returns_none_for_unknown_symbol() {
+        assert_eq!(Color::from_symbol("C"), None);
+        assert_eq!(Color::from_symbol(""), None);
+    }
+
+    #[test]
+    fn parse_all_sorts_in_wubrg_order_and_ignores_unknown_symbols() {
+        assert_eq!(
+            Color::parse_all(["G", "W", "X", "U", "G"]),
+            vec![Color::W, Color::U, Color::G]
+        );
+    }
+
+    #[test]
+    fn display_formats_the_symbol() {
+        assert_eq!(Color::B.to_string(), "B");
+        assert_eq!(Color::R.to_string(), "R");
+    }
+}
@@ -0,0 +1,105 @@
+use crate::domain::card::CardId;
+use crate::domain::user::UserId;
+use std::collections::HashSet;
+
+/// Rows of the card prices read model to recompute.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CardPricesRefresh {
+    /// Every row of a user, after their collection was imported
+    User(UserId),
+    /// The cards of these Cardmarket products, after a price import or review
+    Products(Vec<u32>),
+    /// Every owner of a card, after its ids were resolved
+    Card(CardId),
+    /// Only the trade matches of a user, after their wishlist changed
+    Wishlist(UserId),
+}
+
+/// Refresh requests coalesced until the next refresh runs.
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct PendingCardPricesRefresh {
+    pub users: HashSet<UserId>,
+    pub products: HashSet<u32>,
+    pub cards: HashSet<CardId>,
+    pub wishlists: HashSet<UserId>,
+}
+
+impl PendingCardPricesRefresh {
+    pub fn add(&mut self, refresh: CardPricesRefresh) {
+        match refresh {
+            CardPricesRefresh::User(user_id) => {
+                self.users.insert(user_id);
+            }
+            CardPricesRefresh::Products(id_products) => self.products.extend(id_products),
+            CardPricesRefresh::Card(card_id) => {
+                self.cards.insert(card_id);
+            }
+            CardPricesRefresh::Wishlist(user_id) => {
+                self.wishlists.insert(user_id);
+            }
+        }
+    }
+
+    pub fn merge(&mut self, other: PendingCardPricesRefresh) {
+        self.users.extend(other.users);
+        self.products.extend(other.products);
+        self.cards.extend(other.cards);
+        self.wishlists.extend(other.wishlists);
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.users.is_empty()
+            && self.products.is_empty()
+            && self.cards.is_empty()
+            && self.wishlists.is_empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::language_code::LanguageCode;
+    use crate::domain::set_name::SetCode;
+
+    #[test]
+    fn add_coalesces_duplicate_requests() {
+        let card_id = CardId::new(SetCode::new("FDN"), "87", LanguageCode::FR, false);
+        let mut pending = PendingCardPricesRefresh::default();
+
+        pending.add(CardPricesRefresh::User(UserId::new("user1")));
+        pending.add(CardPricesRefresh::User(UserId::new("user1")));
+        pending.add(CardPricesRefresh::Products(vec![1, 2]));
+        pending.add(CardPricesRefresh::Products(vec![2, 3]));
+        pending.add(CardPricesRefresh::Card(card_id.clone()));
+        pending.add(CardPricesRefresh::Card(card_id));
+
+        assert_eq!(pending.users.len(), 1);
+        assert_eq!(pending.products, HashSet::from([1, 2, 3]));
+        assert_eq!(pending.cards.len(), 1);
+    }
+
+    #[test]
+    fn a_wishlist_change_alone_is_a_pending_refresh() {
+        let mut pending = PendingCardPricesRefresh::default();
+
+        pending.add(CardPricesRefresh::Wishlist(UserId::new("user1")));
+
+        assert!(!pending.is_empty());
+        assert!(pending.users.is_empty());
+        assert_eq!(pending.wishlists, HashSet::from([UserId::new("user1")]));
+    }
+
+    #[test]
+    fn merge_keeps_requests_of_both() {
+        let mut pending = PendingCardPricesRefresh::default();
+        pending.add(CardPricesRefresh::Products(vec![1]));
+        let mut other = PendingCardPricesRefresh::default();
+        other.add(CardPricesRefresh::User(UserId::new("user1")));
+
+        pending.merge(other);
+
+        assert!(!pending.is_empty());
+        assert_eq!(pending.products, HashSet::from([1]));
+        assert_eq!(pending.users, HashSet::from([UserId::new("user1")]));
+    }
+}
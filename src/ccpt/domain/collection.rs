@@ -1,6 +1,9 @@
+use crate::domain::binder::BinderId;
 use crate::domain::card::Card;
+use crate::domain::card_metadata::Color;
 use crate::domain::rarity_code::RarityCode;
 use std::fmt;
+use uuid::Uuid;
 
 #[derive(Default, Clone, Debug, PartialEq, Eq)]
 pub enum CollectionSortField {
@@ -49,6 +52,18 @@ pub struct CollectionQuery {
     pub sets: Vec<String>,
     pub price_min: Option<u32>,
     pub price_max: Option<u32>,
+    /// Colours the card must all have
+    pub colors: Vec<Color>,
+    /// Part of the type line (e.g. `Creature`, `Goblin`)
+    pub type_line: Option<String>,
+    /// Restricts the results to the printings of one oracle card
+    pub oracle_id: Option<Uuid>,
+    /// Restricts the user's collection to one of their binders
+    pub binder_id: Option<BinderId>,
+    /// Tags the user's entries must all carry
+    pub tags: Vec<String>,
+    /// Part of the storage location of the user's entries
+    pub location: Option<String>,
 }
 
 impl Default for CollectionQuery {
@@ -63,6 +78,12 @@ impl Default for CollectionQuery {
             sets: Vec::new(),
             price_min: None,
             price_max: None,
+            colors: Vec::new(),
+            type_line: None,
+            oracle_id: None,
+            binder_id: None,
+            tags: Vec::new(),
+            location: None,
         }
     }
 }
@@ -130,6 +151,10 @@ mod tests {
         assert_eq!(q.sort_by, CollectionSortField::Trend);
         assert_eq!(q.sort_dir, SortDirection::Desc);
         assert_eq!(q.search_query, None);
+        assert_eq!(q.oracle_id, None);
+        assert_eq!(q.binder_id, None);
+        assert!(q.tags.is_empty());
+        assert_eq!(q.location, None);
     }
 
     #[test]
@@ -0,0 +1,99 @@
+use crate::domain::card::CardId;
+use chrono::{DateTime, Utc};
+
+/// Physical condition of the copies of a collection entry, using ManaBox's grades.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum CardCondition {
+    Mint,
+    #[default]
+    NearMint,
+    Excellent,
+    Good,
+    LightPlayed,
+    Played,
+    Poor,
+}
+
+impl CardCondition {
+    pub const ALL: [CardCondition; 7] = [
+        CardCondition::Mint,
+        CardCondition::NearMint,
+        CardCondition::Excellent,
+        CardCondition::Good,
+        CardCondition::LightPlayed,
+        CardCondition::Played,
+        CardCondition::Poor,
+    ];
+
+    pub fn as_db_str(&self) -> &'static str {
+        match self {
+            CardCondition::Mint => "MINT",
+            CardCondition::NearMint => "NEAR_MINT",
+            CardCondition::Excellent => "EXCELLENT",
+            CardCondition::Good => "GOOD",
+            CardCondition::LightPlayed => "LIGHT_PLAYED",
+            CardCondition::Played => "PLAYED",
+            CardCondition::Poor => "POOR",
+        }
+    }
+
+    pub fn from_db_str(s: &str) -> Self {
+        Self::ALL
+            .into_iter()
+            .find(|condition| condition.as_db_str() == s)
+            .unwrap_or_else(|| panic!("invalid card condition from database: {}", s))
+    }
+}
+
+/// The copies of one printing in one of a user's binders, as added or edited by hand.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CollectionItem {
+    pub card_id: CardId,
+    pub quantity: u8,
+    /// Price in cents
+    pub purchase_price: u32,
+    pub condition: CardCondition,
+    pub notes: Option<String>,
+    /// Free-form labels (e.g. `cube`), lowercase and sorted
+    pub tags: Vec<String>,
+    /// Where the copies are stored (e.g. `Box 3, row 2`)
+    pub location: Option<String>,
+    /// Copies offered for trade, `None` to follow the keep rule of the binder. Never more than
+    /// `quantity` are offered.
+    pub tradable_quantity: Option<u8>,
+    pub added_at: DateTime<Utc>,
+}
+
+/// Changes to a collection entry, `None` fields being left as they are.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct CollectionItemUpdate {
+    pub quantity: Option<u8>,
+    /// Price in cents
+    pub purchase_price: Option<u32>,
+    pub condition: Option<CardCondition>,
+    /// `Some(None)` clears the notes
+    pub notes: Option<Option<String>>,
+    /// Replaces every tag, an empty list removing them
+    pub tags: Option<Vec<String>>,
+    /// `Some(None)` clears the location
+    pub location: Option<Option<String>>,
+    /// `Some(None)` goes back to the keep rule of the binder
+    pub tradable_quantity: Option<Option<u8>>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn card_condition_db_str_round_trips() {
+        for condition in CardCondition::ALL {
+            assert_eq!(CardCondition::from_db_str(condition.as_db_str()), condition);
+        }
+    }
+
+    #[test]
+    fn card_condition_defaults_to_near_mint() {
+        assert_eq!(CardCondition::default(), CardCondition::NearMint);
+    }
+}
@@ -7,4 +7,6 @@ pub struct CollectionStats {
     pub price_trend_min: Price,
     pub price_trend_max: Price,
     pub sets: Vec<SetName>,
+    /// Tags carried by the entries, sorted
+    pub tags: Vec<String>,
 }
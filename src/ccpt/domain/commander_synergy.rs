@@ -0,0 +1,191 @@
+use crate::domain::card::CardId;
+use crate::domain::price::Price;
+use std::collections::HashMap;
+use uuid::Uuid;
+
+/// EDHREC list of a commander page a recommendation comes from.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum RecommendationList {
+    HighSynergy,
+    Top,
+}
+
+/// A card EDHREC recommends for a commander.
+#[derive(Clone, Debug, PartialEq)]
+pub struct CommanderRecommendation {
+    pub name: String,
+    pub list: RecommendationList,
+    /// Share of the commander's decks playing the card minus its share in the decks of its colours
+    pub synergy: f64,
+    /// Commander decks playing the card
+    pub inclusion: u32,
+    /// Commander decks whose colour identity allows the card
+    pub potential_decks: u32,
+}
+
+/// One printing of a recommended card in the user's collection.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct OwnedPrinting {
+    pub card_id: CardId,
+    pub name: String,
+    pub scryfall_id: Uuid,
+    pub quantity: u8,
+    /// Price in cents
+    pub trend: Price,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct OwnedRecommendation {
+    pub recommendation: CommanderRecommendation,
+    pub printings: Vec<OwnedPrinting>,
+}
+
+impl OwnedRecommendation {
+    /// Value of the owned copies, printings without a price counting for nothing.
+    pub fn value(&self) -> u32 {
+        self.printings
+            .iter()
+            .map(|p| p.trend.value.unwrap_or(0) * p.quantity as u32)
+            .sum()
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct MissingRecommendation {
+    pub recommendation: CommanderRecommendation,
+    /// Other users owning a printing of the card
+    pub owner_count: u32,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct CommanderSynergyReport {
+    pub commander: String,
+    pub owned: Vec<OwnedRecommendation>,
+    pub missing: Vec<MissingRecommendation>,
+}
+
+impl CommanderSynergyReport {
+    /// Splits the recommendations between the owned and missing cards, keeping EDHREC's order.
+    /// Names are compared case-insensitively; `owner_counts` is keyed by lowercase name.
+    pub fn build(
+        commander: String,
+        recommendations: Vec<CommanderRecommendation>,
+        printings: Vec<OwnedPrinting>,
+        owner_counts: &HashMap<String, u32>,
+    ) -> Self {
+        let mut printings_by_name: HashMap<String, Vec<OwnedPrinting>> = HashMap::new();
+        for printing in printings {
+            printings_by_name
+                .entry(printing.name.to_lowercase())
+                .or_default()
+                .push(printing);
+        }
+
+        let mut owned = Vec::new();
+        let mut missing = Vec::new();
+        for recommendation in recommendations {
+            let key = recommendation.name.to_lowercase();
+            match printings_by_name.remove(&key) {
+                Some(printings) => owned.push(OwnedRecommendation {
+                    recommendation,
+                    printings,
+                }),
+                None => missing.push(MissingRecommendation {
+                    recommendation,
+                    owner_count: owner_counts.get(&key).copied().unwrap_or(0),
+                }),
+            }
+        }
+
+        Self {
+            commander,
+            owned,
+            missing,
+        }
+    }
+
+    /// Value of every owned recommended copy.
+    pub fn owned_value(&self) -> u32 {
+        self.owned.iter().map(OwnedRecommendation::value).sum()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::language_code::LanguageCode;
+
+    fn recommendation(name: &str, list: RecommendationList) -> CommanderRecommendation {
+        CommanderRecommendation {
+            name: name.to_string(),
+            list,
+            synergy: 0.5,
+            inclusion: 100,
+            potential_decks: 200,
+        }
+    }
+
+    fn printing(
+        name: &str,
+        collector_number: &str,
+        quantity: u8,
+        trend: Option<u32>,
+    ) -> OwnedPrinting {
+        OwnedPrinting {
+            card_id: CardId::new("FDN", collector_number, LanguageCode::EN, false),
+            name: name.to_string(),
+            scryfall_id: Uuid::new_v4(),
+            quantity,
+            trend: Price { value: trend },
+        }
+    }
+
+    #[test]
+    fn build_splits_owned_and_missing_recommendations_in_edhrec_order() {
+        let report = CommanderSynergyReport::build(
+            "Atraxa, Praetors' Voice".to_string(),
+            vec![
+                recommendation("Doubling Season", RecommendationList::HighSynergy),
+                recommendation("Sol Ring", RecommendationList::Top),
+                recommendation("Deepglow Skate", RecommendationList::HighSynergy),
+            ],
+            vec![
+                printing("sol ring", "1", 1, Some(100)),
+                printing("Sol Ring", "2", 2, None),
+            ],
+            &HashMap::from([("doubling season".to_string(), 3)]),
+        );
+
+        assert_eq!(report.owned.len(), 1);
+        assert_eq!(report.owned[0].recommendation.name, "Sol Ring");
+        assert_eq!(report.owned[0].printings.len(), 2);
+        assert_eq!(
+            report
+                .missing
+                .iter()
+                .map(|m| (m.recommendation.name.as_str(), m.owner_count))
+                .collect::<Vec<_>>(),
+            vec![("Doubling Season", 3), ("Deepglow Skate", 0)]
+        );
+    }
+
+    #[test]
+    fn owned_value_sums_trend_times_quantity_ignoring_unpriced_printings() {
+        let report = CommanderSynergyReport::build(
+            "Atraxa, Praetors' Voice".to_string(),
+            vec![
+                recommendation("Sol Ring", RecommendationList::Top),
+                recommendation("Doubling Season", RecommendationList::HighSynergy),
+            ],
+            vec![
+                printing("Sol Ring", "1", 2, Some(150)),
+                printing("Sol Ring", "2", 1, None),
+                printing("Doubling Season", "3", 1, Some(3000)),
+            ],
+            &HashMap::new(),
+        );
+
+        assert_eq!(report.owned[0].value(), 300);
+        assert_eq!(report.owned_value(), 3300);
+    }
+}
@@ -0,0 +1,224 @@
+use crate::domain::card::CardId;
+use chrono::{DateTime, Duration, Utc};
+use uuid::Uuid;
+
+/// Beyond this the delay between two attempts stops doubling.
+const MAX_BACKOFF_EXPONENT: u32 = 10;
+
+/// Id resolved for a card by an enrichment job.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum EnrichmentKind {
+    CardMarketId,
+    GathererId,
+}
+
+impl EnrichmentKind {
+    pub fn as_db_str(&self) -> &'static str {
+        match self {
+            EnrichmentKind::CardMarketId => "CARDMARKET_ID",
+            EnrichmentKind::GathererId => "GATHERER_ID",
+        }
+    }
+
+    pub fn from_db_str(s: &str) -> Self {
+        match s {
+            "CARDMARKET_ID" => EnrichmentKind::CardMarketId,
+            "GATHERER_ID" => EnrichmentKind::GathererId,
+            _ => panic!("invalid enrichment kind from database: {}", s),
+        }
+    }
+}
+
+/// Result of an enrichment attempt of a card.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum EnrichmentOutcome {
+    Resolved,
+    /// The service answered without an id for the card, with why.
+    NotFound(String),
+    /// The call failed, with its error.
+    Failed(String),
+}
+
+impl EnrichmentOutcome {
+    pub fn as_db_str(&self) -> &'static str {
+        match self {
+            EnrichmentOutcome::Resolved => "RESOLVED",
+            EnrichmentOutcome::NotFound(_) => "NOT_FOUND",
+            EnrichmentOutcome::Failed(_) => "FAILED",
+        }
+    }
+
+    pub fn from_db_str(s: &str, reason: Option<String>) -> Self {
+        let reason = reason.unwrap_or_default();
+        match s {
+            "RESOLVED" => EnrichmentOutcome::Resolved,
+            "NOT_FOUND" => EnrichmentOutcome::NotFound(reason),
+            "FAILED" => EnrichmentOutcome::Failed(reason),
+            _ => panic!("invalid enrichment outcome from database: {}", s),
+        }
+    }
+
+    pub fn reason(&self) -> Option<&str> {
+        match self {
+            EnrichmentOutcome::Resolved => None,
+            EnrichmentOutcome::NotFound(reason) | EnrichmentOutcome::Failed(reason) => Some(reason),
+        }
+    }
+}
+
+/// A job claimed by a worker, with what it needs to call the external service.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct EnrichmentJob {
+    pub id: i64,
+    pub card_id: CardId,
+    pub scryfall_id: Uuid,
+    pub name: String,
+    /// Attempts including the current one.
+    pub attempts: u32,
+    /// Attempts recorded for the card by the previous jobs of the same kind.
+    pub card_attempts: u32,
+}
+
+/// A card whose id is still missing, with its last enrichment attempt.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct UnresolvedCard {
+    pub card_id: CardId,
+    pub name: String,
+    pub kind: EnrichmentKind,
+    pub attempts: u32,
+    pub last_attempt_at: DateTime<Utc>,
+    pub outcome: EnrichmentOutcome,
+    /// `None` once the card is no longer looked up.
+    pub next_attempt_at: Option<DateTime<Utc>>,
+}
+
+/// Exponential backoff applied to failed jobs before they are dead-lettered, and to the cards
+/// the services have no id for before they are no longer looked up.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct EnrichmentRetryPolicy {
+    pub max_attempts: u32,
+    pub base_delay: Duration,
+    pub not_found_max_attempts: u32,
+    pub not_found_base_delay: Duration,
+}
+
+impl EnrichmentRetryPolicy {
+    /// When to run a job again after its `attempts`-th attempt failed, `None` once it ran out of
+    /// attempts.
+    pub fn next_attempt_at(&self, attempts: u32, now: DateTime<Utc>) -> Option<DateTime<Utc>> {
+        backoff(attempts, self.max_attempts, self.base_delay, now)
+    }
+
+    /// When to look a card up again after its `card_attempts`-th lookup found nothing, `None`
+    /// once it ran out of attempts.
+    pub fn next_lookup_at(&self, card_attempts: u32, now: DateTime<Utc>) -> Option<DateTime<Utc>> {
+        backoff(
+            card_attempts,
+            self.not_found_max_attempts,
+            self.not_found_base_delay,
+            now,
+        )
+    }
+}
+
+fn backoff(
+    attempts: u32,
+    max_attempts: u32,
+    base_delay: Duration,
+    now: DateTime<Utc>,
+) -> Option<DateTime<Utc>> {
+    if attempts >= max_attempts {
+        return None;
+    }
+
+    let exponent = attempts.saturating_sub(1).min(MAX_BACKOFF_EXPONENT);
+    Some(now + base_delay * 2i32.pow(exponent))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    fn policy() -> EnrichmentRetryPolicy {
+        EnrichmentRetryPolicy {
+            max_attempts: 4,
+            base_delay: Duration::seconds(60),
+            not_found_max_attempts: 3,
+            not_found_base_delay: Duration::hours(24),
+        }
+    }
+
+    #[test]
+    fn next_attempt_at_doubles_the_delay_after_each_failure() {
+        let now = Utc.with_ymd_and_hms(2025, 3, 1, 12, 0, 0).unwrap();
+
+        assert_eq!(
+            policy().next_attempt_at(1, now),
+            Some(now + Duration::seconds(60))
+        );
+        assert_eq!(
+            policy().next_attempt_at(2, now),
+            Some(now + Duration::seconds(120))
+        );
+        assert_eq!(
+            policy().next_attempt_at(3, now),
+            Some(now + Duration::seconds(240))
+        );
+    }
+
+    #[test]
+    fn next_attempt_at_gives_up_after_max_attempts() {
+        let now = Utc::now();
+
+        assert_eq!(policy().next_attempt_at(4, now), None);
+        assert_eq!(policy().next_attempt_at(5, now), None);
+    }
+
+    #[test]
+    fn next_attempt_at_caps_the_delay() {
+        let now = Utc.with_ymd_and_hms(2025, 3, 1, 12, 0, 0).unwrap();
+        let policy = EnrichmentRetryPolicy {
+            max_attempts: 100,
+            base_delay: Duration::seconds(1),
+            ..policy()
+        };
+
+        assert_eq!(
+            policy.next_attempt_at(50, now),
+            Some(now + Duration::seconds(1024))
+        );
+    }
+
+    #[test]
+    fn next_lookup_at_doubles_the_delay_until_the_card_is_given_up() {
+        let now = Utc.with_ymd_and_hms(2025, 3, 1, 12, 0, 0).unwrap();
+
+        assert_eq!(
+            policy().next_lookup_at(1, now),
+            Some(now + Duration::hours(24))
+        );
+        assert_eq!(
+            policy().next_lookup_at(2, now),
+            Some(now + Duration::hours(48))
+        );
+        assert_eq!(policy().next_lookup_at(3, now), None);
+    }
+
+    #[test]
+    fn outcome_round_trips_through_the_database_representation() {
+        let outcomes = [
+            EnrichmentOutcome::Resolved,
+            EnrichmentOutcome::NotFound("No Cardmarket product".to_string()),
+            EnrichmentOutcome::Failed("timeout".to_string()),
+        ];
+
+        for outcome in outcomes {
+            let reason = outcome.reason().map(str::to_string);
+            assert_eq!(
+                EnrichmentOutcome::from_db_str(outcome.as_db_str(), reason),
+                outcome
+            );
+        }
+    }
+}
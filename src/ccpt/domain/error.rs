@@ -23,6 +23,17 @@ pub enum FunctionalError {
     TradeAlreadyConfirmed,
     TradeNotCompleted,
     TradeAlreadyRated,
+    PriceAnomalyNotFound,
+    SetNotFound,
+    CommanderNotFound,
+    CollectionEntryNotFound,
+    CollectionEntryAlreadyExists,
+    BinderNotFound,
+    BinderNameAlreadyUsed,
+    DefaultBinderNotDeletable,
+    WishlistEntryNotFound,
+    WishlistEntryAlreadyExists,
+    PriceImportInProgress,
 }
 
 impl From<FunctionalError> for String {
@@ -65,6 +76,31 @@ impl From<FunctionalError> for String {
                 "This trade must be completed before it can be rated".to_string()
             }
             FunctionalError::TradeAlreadyRated => "You have already rated this trade".to_string(),
+            FunctionalError::PriceAnomalyNotFound => {
+                "No pending price anomaly for this product and date".to_string()
+            }
+            FunctionalError::SetNotFound => "Set not found".to_string(),
+            FunctionalError::CommanderNotFound => "Commander not found on EDHREC".to_string(),
+            FunctionalError::CollectionEntryNotFound => {
+                "This printing is not in your collection".to_string()
+            }
+            FunctionalError::CollectionEntryAlreadyExists => {
+                "This printing is already in your collection".to_string()
+            }
+            FunctionalError::BinderNotFound => "Binder not found".to_string(),
+            FunctionalError::BinderNameAlreadyUsed => {
+                "You already have a binder with this name".to_string()
+            }
+            FunctionalError::DefaultBinderNotDeletable => {
+                "The default binder cannot be deleted".to_string()
+            }
+            FunctionalError::WishlistEntryNotFound => "Wishlist entry not found".to_string(),
+            FunctionalError::WishlistEntryAlreadyExists => {
+                "This card is already in your wishlist".to_string()
+            }
+            FunctionalError::PriceImportInProgress => {
+                "A price import is already in progress".to_string()
+            }
         }
     }
 }
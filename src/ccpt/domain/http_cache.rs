@@ -0,0 +1,106 @@
+use chrono::{DateTime, Duration, Utc};
+
+/// External service whose responses are kept in the HTTP response cache.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum HttpCacheSource {
+    Scryfall,
+    Gatherer,
+    EdhRec,
+}
+
+impl HttpCacheSource {
+    pub const ALL: [HttpCacheSource; 3] = [
+        HttpCacheSource::Scryfall,
+        HttpCacheSource::Gatherer,
+        HttpCacheSource::EdhRec,
+    ];
+
+    pub fn as_db_str(&self) -> &'static str {
+        match self {
+            HttpCacheSource::Scryfall => "SCRYFALL",
+            HttpCacheSource::Gatherer => "GATHERER",
+            HttpCacheSource::EdhRec => "EDHREC",
+        }
+    }
+
+    pub fn from_db_str(s: &str) -> Self {
+        match s {
+            "SCRYFALL" => HttpCacheSource::Scryfall,
+            "GATHERER" => HttpCacheSource::Gatherer,
+            "EDHREC" => HttpCacheSource::EdhRec,
+            _ => panic!("invalid http cache source from database: {}", s),
+        }
+    }
+}
+
+/// Body of a successful response, with the validators used to revalidate it once stale.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CachedHttpResponse {
+    pub url: String,
+    pub source: HttpCacheSource,
+    pub body: String,
+    pub etag: Option<String>,
+    pub last_modified: Option<String>,
+    /// Last time the server sent or revalidated the body
+    pub fetched_at: DateTime<Utc>,
+}
+
+impl CachedHttpResponse {
+    pub fn is_fresh(&self, ttl: Duration, now: DateTime<Utc>) -> bool {
+        now - self.fetched_at < ttl
+    }
+}
+
+/// Content of the cache for one source.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct HttpCacheStats {
+    pub source: HttpCacheSource,
+    pub entries: u64,
+    pub size_bytes: u64,
+    pub oldest_fetched_at: Option<DateTime<Utc>>,
+    pub newest_fetched_at: Option<DateTime<Utc>>,
+}
+
+impl HttpCacheStats {
+    pub fn empty(source: HttpCacheSource) -> Self {
+        Self {
+            source,
+            entries: 0,
+            size_bytes: 0,
+            oldest_fetched_at: None,
+            newest_fetched_at: None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn cached() -> CachedHttpResponse {
+        CachedHttpResponse {
+            url: "https://api.scryfall.com/cards/1".to_string(),
+            source: HttpCacheSource::Scryfall,
+            body: "{}".to_string(),
+            etag: Some("\"v1\"".to_string()),
+            last_modified: None,
+            fetched_at: Utc::now() - Duration::hours(23),
+        }
+    }
+
+    #[test]
+    fn source_round_trips_through_its_db_string() {
+        for source in HttpCacheSource::ALL {
+            assert_eq!(HttpCacheSource::from_db_str(source.as_db_str()), source);
+        }
+    }
+
+    #[test]
+    fn cached_response_is_fresh_until_the_ttl_elapsed() {
+        let response = cached();
+        let now = Utc::now();
+
+        assert!(response.is_fresh(Duration::hours(24), now));
+        assert!(!response.is_fresh(Duration::hours(23), now));
+    }
+}
@@ -0,0 +1,27 @@
+use crate::domain::card_metadata::CardMetadata;
+use crate::domain::price::Price;
+use uuid::Uuid;
+
+/// Every owned printing of a card found by a search, grouped under their shared Scryfall
+/// `oracle_id`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct OracleCard {
+    pub oracle_id: Uuid,
+    pub name: String,
+    /// Metadata of one of the printings, the gameplay data being the same for all of them
+    pub metadata: CardMetadata,
+    /// Number of distinct printings (set, collector number, language, foil) owned by someone
+    pub printing_count: u64,
+    /// Number of distinct users owning at least one printing
+    pub owner_count: u64,
+    /// Cheapest trend among the printings
+    pub lowest_trend: Price,
+}
+
+#[derive(Clone, Debug)]
+pub struct PaginatedOracleCards {
+    pub items: Vec<OracleCard>,
+    pub total: u64,
+    pub page: u32,
+    pub page_size: u32,
+}
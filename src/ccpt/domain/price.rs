@@ -1,5 +1,9 @@
 use chrono::NaiveDate;
 use std::ops::AddAssign;
+use std::str::FromStr;
+
+/// Cardmarket category of Magic singles, as opposed to sealed products and accessories.
+pub const SINGLES_CATEGORY: u32 = 1;
 
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct Price {
@@ -33,19 +37,106 @@ pub struct PriceGuide {
     pub trend: Price,
 }
 
-/// Represents one day's aggregated price for a user's collection.
+/// Size of the buckets a price history is aggregated into.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum PriceHistoryGranularity {
+    #[default]
+    Day,
+    /// ISO weeks, starting on Monday
+    Week,
+    Month,
+}
+
+impl PriceHistoryGranularity {
+    /// Unit understood by Postgres' `date_trunc`.
+    pub fn as_date_trunc_unit(&self) -> &'static str {
+        match self {
+            PriceHistoryGranularity::Day => "day",
+            PriceHistoryGranularity::Week => "week",
+            PriceHistoryGranularity::Month => "month",
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct PriceHistoryOptions {
+    pub granularity: PriceHistoryGranularity,
+    /// Also compute the 7 and 30-day moving averages of the trend
+    pub moving_averages: bool,
+}
+
+/// One bucket (day, week or month) of a price history.
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct PriceHistoryEntry {
+    /// First day of the bucket, which may precede the requested start date
     pub date: NaiveDate,
+    /// Last known prices of the bucket
     pub price_guide: PriceGuide,
+    /// First trend of the bucket, the close being `price_guide.trend`
+    pub trend_open: Price,
+    pub trend_high: Price,
+    pub trend_low: Price,
+    /// Trend averaged over the 7 days ending on the last day of the bucket
+    pub trend_ma7: Price,
+    /// Trend averaged over the 30 days ending on the last day of the bucket
+    pub trend_ma30: Price,
 }
 
 pub struct FullPriceGuide {
     pub id_product: u32,
+    pub id_category: Option<u32>,
     pub normal: PriceGuide,
     pub foil: PriceGuide,
 }
 
+impl FullPriceGuide {
+    pub fn is_single(&self) -> bool {
+        self.id_category == Some(SINGLES_CATEGORY)
+    }
+}
+
+/// Products of the Cardmarket price guide saved by the import.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum PriceImportScope {
+    /// Every product, sealed products included
+    #[default]
+    All,
+    /// Magic singles only
+    Singles,
+    /// Products referenced by a card `cardmarket_id` at import time
+    Referenced,
+}
+
+impl PriceImportScope {
+    pub fn as_db_str(&self) -> &'static str {
+        match self {
+            PriceImportScope::All => "ALL",
+            PriceImportScope::Singles => "SINGLES",
+            PriceImportScope::Referenced => "REFERENCED",
+        }
+    }
+}
+
+impl FromStr for PriceImportScope {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "all" => Ok(PriceImportScope::All),
+            "singles" => Ok(PriceImportScope::Singles),
+            "referenced" => Ok(PriceImportScope::Referenced),
+            _ => Err(format!("expected all, singles or referenced (got {})", s)),
+        }
+    }
+}
+
+/// Consecutive price guides of a Cardmarket file, sent while the file is downloaded.
+pub struct PriceGuideChunk {
+    /// Date of the file
+    pub date: NaiveDate,
+    pub price_guides: Vec<FullPriceGuide>,
+}
+
 impl PriceGuide {
     pub fn new(low: impl Into<Price>, trend: impl Into<Price>, avg: impl Into<Price>) -> Self {
         Self {
@@ -105,4 +196,26 @@ mod tests {
 
         assert_eq!(price1, create_price(100, 200, 200));
     }
+
+    #[test]
+    fn price_import_scope_parses_case_insensitively() {
+        assert_eq!("all".parse(), Ok(PriceImportScope::All));
+        assert_eq!("Singles".parse(), Ok(PriceImportScope::Singles));
+        assert_eq!("REFERENCED".parse(), Ok(PriceImportScope::Referenced));
+        assert!("sealed".parse::<PriceImportScope>().is_err());
+    }
+
+    #[test]
+    fn is_single_checks_the_singles_category() {
+        let guide = |id_category| FullPriceGuide {
+            id_product: 1,
+            id_category,
+            normal: create_price(1, 1, 1),
+            foil: create_price(1, 1, 1),
+        };
+
+        assert!(guide(Some(SINGLES_CATEGORY)).is_single());
+        assert!(!guide(Some(3)).is_single());
+        assert!(!guide(None).is_single());
+    }
 }
@@ -0,0 +1,239 @@
+use crate::domain::price::{FullPriceGuide, Price};
+use chrono::{DateTime, NaiveDate, Utc};
+
+/// Below this move (in cents) a trend change is never suspicious, whatever the ratio: bulk
+/// cards routinely go from 0.02 € to 0.10 €.
+const MIN_SUSPICIOUS_DELTA: u32 = 200;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum PriceAnomalyKind {
+    /// The new trend is at least `max_ratio` times the reference.
+    Spike,
+    /// The reference is at least `max_ratio` times the new trend.
+    Drop,
+    /// The new trend fell to zero.
+    Zero,
+}
+
+impl PriceAnomalyKind {
+    pub fn as_db_str(&self) -> &'static str {
+        match self {
+            PriceAnomalyKind::Spike => "SPIKE",
+            PriceAnomalyKind::Drop => "DROP",
+            PriceAnomalyKind::Zero => "ZERO",
+        }
+    }
+
+    pub fn from_db_str(s: &str) -> Self {
+        match s {
+            "SPIKE" => PriceAnomalyKind::Spike,
+            "DROP" => PriceAnomalyKind::Drop,
+            "ZERO" => PriceAnomalyKind::Zero,
+            _ => panic!("invalid price anomaly kind from database: {}", s),
+        }
+    }
+
+    /// Compares an `observed` trend with its `reference` (both in cents).
+    pub fn detect(reference: u32, observed: u32, max_ratio: u32) -> Option<Self> {
+        if reference == 0 || observed.abs_diff(reference) < MIN_SUSPICIOUS_DELTA {
+            return None;
+        }
+
+        if observed == 0 {
+            Some(PriceAnomalyKind::Zero)
+        } else if observed >= reference.saturating_mul(max_ratio) {
+            Some(PriceAnomalyKind::Spike)
+        } else if reference >= observed.saturating_mul(max_ratio) {
+            Some(PriceAnomalyKind::Drop)
+        } else {
+            None
+        }
+    }
+}
+
+/// Outcome of the manual review of a pending anomaly.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum PriceAnomalyReview {
+    /// Genuine move, the price is used again.
+    Confirmed,
+    /// Bogus price, it stays out of aggregates.
+    Rejected,
+}
+
+impl PriceAnomalyReview {
+    pub fn as_db_str(&self) -> &'static str {
+        match self {
+            PriceAnomalyReview::Confirmed => "CONFIRMED",
+            PriceAnomalyReview::Rejected => "REJECTED",
+        }
+    }
+}
+
+/// Median trend of a product over the days preceding an import.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ReferencePrice {
+    pub id_product: u32,
+    pub normal_trend: Price,
+    pub foil_trend: Price,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PriceAnomaly {
+    pub id_product: u32,
+    pub date: NaiveDate,
+    pub foil: bool,
+    pub kind: PriceAnomalyKind,
+    /// Reference trend in cents
+    pub reference: u32,
+    /// Imported trend in cents
+    pub observed: u32,
+    pub detected_at: DateTime<Utc>,
+}
+
+impl PriceAnomaly {
+    /// Checks the normal and foil trends of `guide` against `reference`. A finish without
+    /// a trend on either side is skipped.
+    pub fn detect(
+        date: NaiveDate,
+        guide: &FullPriceGuide,
+        reference: &ReferencePrice,
+        max_ratio: u32,
+    ) -> Vec<PriceAnomaly> {
+        [
+            (false, &reference.normal_trend, &guide.normal.trend),
+            (true, &reference.foil_trend, &guide.foil.trend),
+        ]
+        .into_iter()
+        .filter_map(|(foil, reference, observed)| {
+            let (reference, observed) = (reference.value?, observed.value?);
+            PriceAnomalyKind::detect(reference, observed, max_ratio).map(|kind| PriceAnomaly {
+                id_product: guide.id_product,
+                date,
+                foil,
+                kind,
+                reference,
+                observed,
+                detected_at: Utc::now(),
+            })
+        })
+        .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::price::PriceGuide;
+
+    fn guide(normal_trend: Option<u32>, foil_trend: Option<u32>) -> FullPriceGuide {
+        FullPriceGuide {
+            id_product: 42,
+            id_category: None,
+            normal: PriceGuide::new(
+                Price::empty(),
+                Price {
+                    value: normal_trend,
+                },
+                Price::empty(),
+            ),
+            foil: PriceGuide::new(Price::empty(), Price { value: foil_trend }, Price::empty()),
+        }
+    }
+
+    fn reference(normal_trend: Option<u32>, foil_trend: Option<u32>) -> ReferencePrice {
+        ReferencePrice {
+            id_product: 42,
+            normal_trend: Price {
+                value: normal_trend,
+            },
+            foil_trend: Price { value: foil_trend },
+        }
+    }
+
+    #[test]
+    fn detect_kind_flags_a_spike() {
+        assert_eq!(
+            PriceAnomalyKind::detect(1000, 5000, 5),
+            Some(PriceAnomalyKind::Spike)
+        );
+    }
+
+    #[test]
+    fn detect_kind_flags_a_drop() {
+        assert_eq!(
+            PriceAnomalyKind::detect(5000, 1000, 5),
+            Some(PriceAnomalyKind::Drop)
+        );
+    }
+
+    #[test]
+    fn detect_kind_flags_a_fall_to_zero() {
+        assert_eq!(
+            PriceAnomalyKind::detect(1000, 0, 5),
+            Some(PriceAnomalyKind::Zero)
+        );
+    }
+
+    #[test]
+    fn detect_kind_ignores_moves_below_the_ratio() {
+        assert_eq!(PriceAnomalyKind::detect(1000, 4999, 5), None);
+        assert_eq!(PriceAnomalyKind::detect(4999, 1000, 5), None);
+    }
+
+    #[test]
+    fn detect_kind_ignores_small_absolute_moves_on_bulk_cards() {
+        assert_eq!(PriceAnomalyKind::detect(2, 150, 5), None);
+        assert_eq!(PriceAnomalyKind::detect(150, 0, 5), None);
+    }
+
+    #[test]
+    fn detect_kind_ignores_a_zero_reference() {
+        assert_eq!(PriceAnomalyKind::detect(0, 100_000, 5), None);
+    }
+
+    #[test]
+    fn detect_checks_both_finishes() {
+        let date = NaiveDate::from_ymd_opt(2025, 3, 1).unwrap();
+
+        let anomalies = PriceAnomaly::detect(
+            date,
+            &guide(Some(100_000), Some(0)),
+            &reference(Some(1000), Some(3000)),
+            5,
+        );
+
+        assert_eq!(anomalies.len(), 2);
+        assert!(!anomalies[0].foil);
+        assert_eq!(anomalies[0].kind, PriceAnomalyKind::Spike);
+        assert_eq!(anomalies[0].reference, 1000);
+        assert_eq!(anomalies[0].observed, 100_000);
+        assert_eq!(anomalies[0].date, date);
+        assert!(anomalies[1].foil);
+        assert_eq!(anomalies[1].kind, PriceAnomalyKind::Zero);
+    }
+
+    #[test]
+    fn detect_skips_finishes_without_trend() {
+        let date = NaiveDate::from_ymd_opt(2025, 3, 1).unwrap();
+
+        let anomalies = PriceAnomaly::detect(
+            date,
+            &guide(None, Some(100_000)),
+            &reference(Some(1000), None),
+            5,
+        );
+
+        assert!(anomalies.is_empty());
+    }
+
+    #[test]
+    fn kind_db_str_round_trips() {
+        for kind in [
+            PriceAnomalyKind::Spike,
+            PriceAnomalyKind::Drop,
+            PriceAnomalyKind::Zero,
+        ] {
+            assert_eq!(PriceAnomalyKind::from_db_str(kind.as_db_str()), kind);
+        }
+    }
+}
@@ -0,0 +1,27 @@
+use crate::domain::card_metadata::CardMetadata;
+use crate::domain::rarity_code::RarityCode;
+use uuid::Uuid;
+
+/// Printing of the Scryfall catalogue, read from the bulk data file or the API.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ScryfallCard {
+    pub scryfall_id: Uuid,
+    pub name: String,
+    pub set_code: String,
+    pub collector_number: String,
+    /// Scryfall language code (`en`, `fr`, `zhs`…)
+    pub lang: String,
+    pub cardmarket_id: Option<u32>,
+    /// `None` for a rarity the collection does not know (e.g. `bonus`)
+    pub rarity: Option<RarityCode>,
+    pub metadata: CardMetadata,
+}
+
+/// Outcome of an import of the bulk data file.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct ScryfallCatalogImport {
+    /// Printings saved in the catalogue
+    pub card_count: usize,
+    /// Cards given their `cardmarket_id` from the catalogue
+    pub resolved: usize,
+}
@@ -0,0 +1,59 @@
+use crate::domain::price::Price;
+use crate::domain::set_name::SetName;
+use chrono::NaiveDate;
+
+/// Value of every known printing of a set on one day.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SetPriceIndexEntry {
+    pub date: NaiveDate,
+    /// Printings (card and finish) of the set with a trend that day
+    pub printings: u32,
+    /// Sum of the trends of the printings
+    pub total: Price,
+    /// Median trend of the printings
+    pub median: Price,
+    /// Sum of the trends of the printings the user owns, each counted once
+    pub owned: Price,
+}
+
+impl SetPriceIndexEntry {
+    /// Share of the set value owned by the user, between 0 and 1.
+    pub fn owned_share(&self) -> f64 {
+        match (self.total.value, self.owned.value) {
+            (Some(total), Some(owned)) if total > 0 => owned as f64 / total as f64,
+            _ => 0.0,
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SetPriceIndex {
+    pub set: SetName,
+    pub entries: Vec<SetPriceIndexEntry>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn entry(total: Option<u32>, owned: Option<u32>) -> SetPriceIndexEntry {
+        SetPriceIndexEntry {
+            date: NaiveDate::from_ymd_opt(2025, 3, 1).unwrap(),
+            printings: 3,
+            total: Price { value: total },
+            median: Price { value: Some(300) },
+            owned: Price { value: owned },
+        }
+    }
+
+    #[test]
+    fn owned_share_is_owned_value_over_total_value() {
+        assert_eq!(entry(Some(1000), Some(250)).owned_share(), 0.25);
+    }
+
+    #[test]
+    fn owned_share_is_zero_without_set_value() {
+        assert_eq!(entry(Some(0), Some(0)).owned_share(), 0.0);
+        assert_eq!(entry(None, None).owned_share(), 0.0);
+    }
+}
@@ -0,0 +1,138 @@
+use crate::domain::card::CardId;
+use crate::domain::user::UserId;
+use std::cmp::Ordering;
+
+/// Copies of one printing a side of a match owns and the other side wishes for.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TradeMatchCard {
+    pub card_id: CardId,
+    pub name: String,
+    /// Copies offered for trade that a wish asks for
+    pub quantity: u8,
+    /// Current trend of one copy, in cents
+    pub trend: Option<u32>,
+}
+
+impl TradeMatchCard {
+    /// Trend of every copy, a card without price counting for nothing.
+    pub fn value(&self) -> u64 {
+        u64::from(self.trend.unwrap_or(0)) * u64::from(self.quantity)
+    }
+}
+
+/// Another user owning cards the user wishes for and wishing for cards the user owns.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TradeMatch {
+    pub other_user_id: UserId,
+    pub other_username: String,
+    /// Copies of the other user matching the user's wishlist
+    pub wanted: Vec<TradeMatchCard>,
+    /// Copies of the user matching the other user's wishlist
+    pub offered: Vec<TradeMatchCard>,
+}
+
+impl TradeMatch {
+    pub fn wanted_value(&self) -> u64 {
+        self.wanted.iter().map(TradeMatchCard::value).sum()
+    }
+
+    pub fn offered_value(&self) -> u64 {
+        self.offered.iter().map(TradeMatchCard::value).sum()
+    }
+
+    /// Share of the larger side covered by the smaller one, from 0 to 1 when both are worth the
+    /// same (including nothing).
+    pub fn balance(&self) -> f64 {
+        let (wanted, offered) = (self.wanted_value(), self.offered_value());
+        match wanted.max(offered) {
+            0 => 1.0,
+            max => wanted.min(offered) as f64 / max as f64,
+        }
+    }
+
+    /// Best balanced first, then the larger trade, then by username.
+    pub fn rank(&self, other: &Self) -> Ordering {
+        other
+            .balance()
+            .total_cmp(&self.balance())
+            .then_with(|| {
+                (other.wanted_value() + other.offered_value())
+                    .cmp(&(self.wanted_value() + self.offered_value()))
+            })
+            .then_with(|| self.other_username.cmp(&other.other_username))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::language_code::LanguageCode;
+
+    fn card(quantity: u8, trend: Option<u32>) -> TradeMatchCard {
+        TradeMatchCard {
+            card_id: CardId::new("FDN", "87", LanguageCode::FR, false),
+            name: "Goblin Boarders".to_string(),
+            quantity,
+            trend,
+        }
+    }
+
+    fn trade_match(
+        username: &str,
+        wanted: Vec<TradeMatchCard>,
+        offered: Vec<TradeMatchCard>,
+    ) -> TradeMatch {
+        TradeMatch {
+            other_user_id: UserId::new(username),
+            other_username: username.to_string(),
+            wanted,
+            offered,
+        }
+    }
+
+    #[test]
+    fn values_sum_every_copy_and_ignore_cards_without_price() {
+        let m = trade_match(
+            "bob",
+            vec![card(3, Some(100)), card(1, None)],
+            vec![card(2, Some(50))],
+        );
+
+        assert_eq!(m.wanted_value(), 300);
+        assert_eq!(m.offered_value(), 100);
+        assert_eq!(m.balance(), 100.0 / 300.0);
+    }
+
+    #[test]
+    fn balance_is_full_when_both_sides_are_worth_nothing() {
+        let m = trade_match("bob", vec![card(1, None)], vec![card(1, Some(0))]);
+
+        assert_eq!(m.balance(), 1.0);
+    }
+
+    #[test]
+    fn rank_puts_the_best_balanced_then_the_larger_trade_first() {
+        let mut matches = [
+            trade_match(
+                "unbalanced",
+                vec![card(1, Some(1000))],
+                vec![card(1, Some(100))],
+            ),
+            trade_match("small", vec![card(1, Some(100))], vec![card(1, Some(100))]),
+            trade_match("large", vec![card(1, Some(500))], vec![card(1, Some(500))]),
+            trade_match(
+                "also-small",
+                vec![card(1, Some(100))],
+                vec![card(1, Some(100))],
+            ),
+        ];
+
+        matches.sort_by(TradeMatch::rank);
+
+        let usernames: Vec<&str> = matches.iter().map(|m| m.other_username.as_str()).collect();
+        assert_eq!(
+            usernames,
+            vec!["large", "also-small", "small", "unbalanced"]
+        );
+    }
+}
@@ -0,0 +1,193 @@
+use crate::domain::card::CardSelector;
+use crate::domain::language_code::LanguageCode;
+use crate::domain::user::UserId;
+use chrono::{DateTime, Utc};
+use std::fmt::{Display, Formatter};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct WishlistEntryId(pub uuid::Uuid);
+
+impl WishlistEntryId {
+    pub fn new() -> Self {
+        Self(uuid::Uuid::new_v4())
+    }
+}
+
+impl Default for WishlistEntryId {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Display for WishlistEntryId {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+/// Finish of the copies a wish accepts.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Finish {
+    NonFoil,
+    Foil,
+}
+
+impl Finish {
+    pub const ALL: [Finish; 2] = [Finish::NonFoil, Finish::Foil];
+
+    pub fn as_db_str(&self) -> &'static str {
+        match self {
+            Finish::NonFoil => "NON_FOIL",
+            Finish::Foil => "FOIL",
+        }
+    }
+
+    pub fn from_db_str(s: &str) -> Self {
+        Self::ALL
+            .into_iter()
+            .find(|finish| finish.as_db_str() == s)
+            .unwrap_or_else(|| panic!("invalid finish from database: {}", s))
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum WishPriority {
+    Low,
+    #[default]
+    Normal,
+    High,
+}
+
+impl WishPriority {
+    pub const ALL: [WishPriority; 3] =
+        [WishPriority::Low, WishPriority::Normal, WishPriority::High];
+
+    pub fn as_db_str(&self) -> &'static str {
+        match self {
+            WishPriority::Low => "LOW",
+            WishPriority::Normal => "NORMAL",
+            WishPriority::High => "HIGH",
+        }
+    }
+
+    pub fn from_db_str(s: &str) -> Self {
+        Self::ALL
+            .into_iter()
+            .find(|priority| priority.as_db_str() == s)
+            .unwrap_or_else(|| panic!("invalid wish priority from database: {}", s))
+    }
+}
+
+/// A card a user wants: one exact printing, or any printing of the card that passes the
+/// filters.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct WishlistEntry {
+    pub id: WishlistEntryId,
+    pub user_id: UserId,
+    pub card: CardSelector,
+    /// Name of the card when it was wished, for display
+    pub name: String,
+    pub quantity: u8,
+    /// Highest trend price accepted, in cents
+    pub max_price: Option<u32>,
+    /// Languages accepted for any printing of the card, empty for every language
+    pub languages: Vec<LanguageCode>,
+    /// Finishes accepted for any printing of the card, empty for both
+    pub finishes: Vec<Finish>,
+    pub priority: WishPriority,
+    pub created_at: DateTime<Utc>,
+}
+
+/// A wish as asked for by a user, before the name of its card is looked up.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct NewWishlistEntry {
+    pub card: CardSelector,
+    pub quantity: u8,
+    /// Highest trend price accepted, in cents
+    pub max_price: Option<u32>,
+    pub languages: Vec<LanguageCode>,
+    pub finishes: Vec<Finish>,
+    pub priority: WishPriority,
+}
+
+/// A wish with the offers of other users currently matching it.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct WishlistItem {
+    pub entry: WishlistEntry,
+    /// Number of other users offering a matching printing for trade
+    pub offer_count: u64,
+    /// Lowest trend among the matching offers, in cents
+    pub cheapest_offer: Option<u32>,
+}
+
+/// Changes to a wish, `None` fields being left as they are.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct WishlistEntryUpdate {
+    pub quantity: Option<u8>,
+    /// `Some(None)` removes the price limit
+    pub max_price: Option<Option<u32>>,
+    pub languages: Option<Vec<LanguageCode>>,
+    pub finishes: Option<Vec<Finish>>,
+    pub priority: Option<WishPriority>,
+}
+
+impl WishlistEntryUpdate {
+    /// Whether the update narrows the printings accepted, which only makes sense for any
+    /// printing of a card.
+    pub fn sets_filters(&self) -> bool {
+        self.languages.as_ref().is_some_and(|l| !l.is_empty())
+            || self.finishes.as_ref().is_some_and(|f| !f.is_empty())
+    }
+}
+
+/// Outcome of a decklist import into the wishlist.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct WishlistImport {
+    /// Number of cards added or whose wished quantity was raised
+    pub imported: u32,
+    /// Names of the decklist matching no known card, as written
+    pub unknown_names: Vec<String>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn wishlist_entry_id_new_produces_different_ids() {
+        assert_ne!(WishlistEntryId::new(), WishlistEntryId::new());
+    }
+
+    #[test]
+    fn finish_db_str_round_trips() {
+        for finish in Finish::ALL {
+            assert_eq!(Finish::from_db_str(finish.as_db_str()), finish);
+        }
+    }
+
+    #[test]
+    fn wish_priority_db_str_round_trips() {
+        for priority in WishPriority::ALL {
+            assert_eq!(WishPriority::from_db_str(priority.as_db_str()), priority);
+        }
+    }
+
+    #[test]
+    fn update_sets_filters_only_with_a_non_empty_list() {
+        assert!(!WishlistEntryUpdate::default().sets_filters());
+        assert!(
+            !WishlistEntryUpdate {
+                languages: Some(vec![]),
+                ..WishlistEntryUpdate::default()
+            }
+            .sets_filters()
+        );
+        assert!(
+            WishlistEntryUpdate {
+                finishes: Some(vec![Finish::Foil]),
+                ..WishlistEntryUpdate::default()
+            }
+            .sets_filters()
+        );
+    }
+}
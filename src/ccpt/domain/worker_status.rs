@@ -0,0 +1,135 @@
+use crate::domain::card::CardId;
+use crate::domain::enrichment_job::EnrichmentKind;
+use chrono::{DateTime, Utc};
+
+/// Jobs of a kind in the enrichment queue, by status.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct EnrichmentQueueDepth {
+    /// Waiting for a worker, including the ones scheduled for a retry
+    pub pending: u64,
+    /// Claimed by a worker
+    pub running: u64,
+    /// Out of attempts
+    pub dead: u64,
+}
+
+impl EnrichmentQueueDepth {
+    /// Cards a new enqueue skips because their job is still waiting, running or dead lettered.
+    pub fn dedup_set_size(&self) -> u64 {
+        self.pending + self.running + self.dead
+    }
+}
+
+/// What an enrichment worker of this instance did since startup.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct WorkerActivity {
+    pub alive: bool,
+    pub processed: u64,
+    pub failed: u64,
+    pub last_error: Option<String>,
+    pub last_error_at: Option<DateTime<Utc>>,
+    pub last_processed: Option<CardId>,
+    pub last_processed_at: Option<DateTime<Utc>>,
+}
+
+impl Default for WorkerActivity {
+    fn default() -> Self {
+        Self {
+            alive: true,
+            processed: 0,
+            failed: 0,
+            last_error: None,
+            last_error_at: None,
+            last_processed: None,
+            last_processed_at: None,
+        }
+    }
+}
+
+impl WorkerActivity {
+    pub fn record_processed(&mut self, card_id: CardId, at: DateTime<Utc>) {
+        self.processed += 1;
+        self.last_processed = Some(card_id);
+        self.last_processed_at = Some(at);
+    }
+
+    /// A job that failed counts as processed too.
+    pub fn record_failed(&mut self, card_id: CardId, error: String, at: DateTime<Utc>) {
+        self.failed += 1;
+        self.record_processed(card_id, at);
+        self.record_error(error, at);
+    }
+
+    /// An error outside of a job, e.g. the queue could not be read.
+    pub fn record_error(&mut self, error: String, at: DateTime<Utc>) {
+        self.last_error = Some(error);
+        self.last_error_at = Some(at);
+    }
+}
+
+/// State of an enrichment worker and of its queue.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct WorkerStatus {
+    pub kind: EnrichmentKind,
+    pub queue: EnrichmentQueueDepth,
+    pub activity: WorkerActivity,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::language_code::LanguageCode;
+    use crate::domain::set_name::SetCode;
+    use chrono::TimeZone;
+
+    fn card_id(n: &str) -> CardId {
+        CardId::new(SetCode::new("FDN"), n, LanguageCode::FR, false)
+    }
+
+    #[test]
+    fn dedup_set_size_counts_the_pending_running_and_dead_jobs() {
+        let queue = EnrichmentQueueDepth {
+            pending: 12,
+            running: 3,
+            dead: 1,
+        };
+
+        assert_eq!(queue.dedup_set_size(), 16);
+    }
+
+    #[test]
+    fn a_new_worker_is_alive_and_idle() {
+        let activity = WorkerActivity::default();
+
+        assert!(activity.alive);
+        assert_eq!(activity.processed, 0);
+        assert_eq!(activity.last_error, None);
+    }
+
+    #[test]
+    fn record_failed_counts_the_job_as_processed_and_keeps_the_error() {
+        let at = Utc.with_ymd_and_hms(2025, 3, 1, 12, 0, 0).unwrap();
+        let mut activity = WorkerActivity::default();
+
+        activity.record_processed(card_id("1"), at);
+        activity.record_failed(card_id("2"), "timeout".to_string(), at);
+
+        assert_eq!(activity.processed, 2);
+        assert_eq!(activity.failed, 1);
+        assert_eq!(activity.last_processed, Some(card_id("2")));
+        assert_eq!(activity.last_error.as_deref(), Some("timeout"));
+        assert_eq!(activity.last_error_at, Some(at));
+    }
+
+    #[test]
+    fn record_error_does_not_count_a_job() {
+        let at = Utc.with_ymd_and_hms(2025, 3, 1, 12, 0, 0).unwrap();
+        let mut activity = WorkerActivity::default();
+
+        activity.record_error("connection refused".to_string(), at);
+
+        assert_eq!(activity.processed, 0);
+        assert_eq!(activity.failed, 0);
+        assert_eq!(activity.last_error.as_deref(), Some("connection refused"));
+    }
+}
@@ -1,48 +1,79 @@
-use crate::application::caller::EdhRecCaller;
 use crate::application::service::auth_service::AuthService;
 use crate::application::service::autocomplete_user_service::AutocompleteUserService;
+use crate::application::service::binder_service::BinderService;
 use crate::application::service::card_collection_service::CardCollectionService;
+use crate::application::service::card_edhrec_service::CardEdhRecService;
 use crate::application::service::card_offer_service::CardOfferService;
 use crate::application::service::card_price_history_service::CardPriceHistoryService;
+use crate::application::service::card_prices_refresh_service::CardPricesRefreshCoordinator;
 use crate::application::service::cardmarket_id_enqueue_service::CardMarketIdEnqueueService;
+use crate::application::service::collection_entry_service::CollectionEntryService;
 use crate::application::service::collection_price_history_service::CollectionPriceHistoryService;
 use crate::application::service::collection_service::CollectionService;
 use crate::application::service::collection_stats_service::CollectionStatsService;
+use crate::application::service::commander_synergy_service::CommanderSynergyService;
 use crate::application::service::gatherer_id_enqueue_service::GathererIdEnqueueService;
+use crate::application::service::http_cache_service::HttpCacheService;
 use crate::application::service::import_card_service::ImportCardService;
 use crate::application::service::import_price_service::ImportPriceService;
+use crate::application::service::price_anomaly_service::PriceAnomalyService;
 use crate::application::service::register_user_service::RegisterUserService;
+use crate::application::service::scryfall_catalog_service::ScryfallCatalogService;
 use crate::application::service::search_service::SearchService;
+use crate::application::service::set_price_index_service::SetPriceIndexService;
 use crate::application::service::stats_service::StatsService;
+use crate::application::service::trade_match_service::TradeMatchService;
 use crate::application::service::trade_service::{
     AbandonTradeService, AcceptTradeService, ConfirmTradeService, CreateTradeService,
     RateTradeService,
 };
+use crate::application::service::unresolved_card_service::UnresolvedCardService;
 use crate::application::service::update_card_market_service::CardMarketIdWorker;
 use crate::application::service::update_gatherer_service::GathererIdWorker;
+use crate::application::service::wishlist_service::WishlistService;
+use crate::application::service::worker_monitor_service::{WorkerMonitorService, WorkerTracker};
 use crate::application::use_case::{
     AbandonTradeUseCase, AcceptTradeUseCase, AutocompleteUsersUseCase, ConfirmTradeUseCase,
     CreateTradeUseCase, EnqueueCardMarketIdUpdateUseCase, EnqueueGathererIdUpdateUseCase,
-    GetCardOffersUseCase, GetCardPriceHistoryUseCase, GetCollectionPriceHistoryUseCase,
-    GetCollectionStatsUseCase, GetCollectionUseCase, ImportCardUseCase, ImportPriceUseCase,
-    RateTradeUseCase, RegisterUserUseCase, SearchCardsUseCase, StatsUseCase,
+    GetCardEdhRecUseCase, GetCardOffersUseCase, GetCardPriceHistoryUseCase,
+    GetCollectionPriceHistoryUseCase, GetCollectionStatsUseCase, GetCollectionUseCase,
+    GetCommanderSynergyUseCase, GetSetPriceIndexUseCase, GetTradeMatchesUseCase,
+    GetUnresolvedCardsUseCase, GetWorkersStatusUseCase, HttpCacheUseCase, ImportCardUseCase,
+    ImportPriceUseCase, ManageBindersUseCase, ManageCollectionEntryUseCase, ManageWishlistUseCase,
+    RateTradeUseCase, RegisterUserUseCase, ReviewPriceAnomalyUseCase,
+    ScheduleCardPricesRefreshUseCase, ScryfallCatalogUseCase, SearchCardsUseCase, StatsUseCase,
 };
 use crate::config::Config;
-use crate::domain::card::CardId;
+use crate::domain::enrichment_job::{EnrichmentKind, EnrichmentRetryPolicy};
+use crate::domain::http_cache::HttpCacheSource;
 use crate::infrastructure::adapter_in::autocomplete::controller::create_autocomplete_router;
+use crate::infrastructure::adapter_in::binder::controller::create_binder_router;
 use crate::infrastructure::adapter_in::card::controller::create_card_router;
 use crate::infrastructure::adapter_in::collection::controller::create_collection_router;
 use crate::infrastructure::adapter_in::search::controller::create_search_router;
+use crate::infrastructure::adapter_in::set::controller::create_set_router;
 use crate::infrastructure::adapter_in::trade::controller::create_trade_router;
 use crate::infrastructure::adapter_in::user::controller::create_user_router;
+use crate::infrastructure::adapter_in::wishlist::controller::create_wishlist_router;
 use crate::infrastructure::adapter_out::caller::cardmarket_caller_adapter::CardMarketCallerAdapter;
 use crate::infrastructure::adapter_out::caller::edhrec_caller_adapter::EdhRecCallerAdapter;
+use crate::infrastructure::adapter_out::caller::http_client::{HttpClient, HttpClientSettings};
+use crate::infrastructure::adapter_out::caller::scryfall_bulk_caller_adapter::ScryfallBulkCallerAdapter;
+use crate::infrastructure::adapter_out::repository::binder_repository_adapter::BinderRepositoryAdapter;
 use crate::infrastructure::adapter_out::repository::card_prices_view_repository_adapter::CardPricesViewRepositoryAdapter;
 use crate::infrastructure::adapter_out::repository::cardmarket_price_repository_adapter::CardMarketPriceRepositoryAdapter;
 use crate::infrastructure::adapter_out::repository::collection_price_history_repository_adapter::CollectionPriceHistoryRepositoryAdapter;
 use crate::infrastructure::adapter_out::repository::collection_stats_repository_adapter::CollectionStatsRepositoryAdapter;
+use crate::infrastructure::adapter_out::repository::edhrec_card_info_repository_adapter::EdhRecCardInfoRepositoryAdapter;
+use crate::infrastructure::adapter_out::repository::enrichment_job_repository_adapter::EnrichmentJobRepositoryAdapter;
+use crate::infrastructure::adapter_out::repository::http_response_cache_repository_adapter::HttpResponseCacheRepositoryAdapter;
+use crate::infrastructure::adapter_out::repository::price_anomaly_repository_adapter::PriceAnomalyRepositoryAdapter;
+use crate::infrastructure::adapter_out::repository::scryfall_catalog_repository_adapter::ScryfallCatalogRepositoryAdapter;
+use crate::infrastructure::adapter_out::repository::set_price_index_repository_adapter::SetPriceIndexRepositoryAdapter;
 use crate::infrastructure::adapter_out::repository::stats_repository_adapter::StatsRepositoryAdapter;
+use crate::infrastructure::adapter_out::repository::trade_match_repository_adapter::TradeMatchRepositoryAdapter;
 use crate::infrastructure::adapter_out::repository::trade_repository_adapter::TradeRepositoryAdapter;
+use crate::infrastructure::adapter_out::repository::wishlist_repository_adapter::WishlistRepositoryAdapter;
 use adapter_in::maintenance::controller::create_maintenance_router;
 use adapter_out::caller::gatherer_caller_adapter::GathererCallerAdapter;
 use adapter_out::caller::scryfall_caller_adapter::ScryfallCallerAdapter;
@@ -56,9 +87,9 @@ use chrono::Utc;
 use cron_tab::AsyncCron;
 use sentry::integrations::tower::{NewSentryLayer, SentryHttpLayer};
 use sqlx::{Pool, Postgres};
-use std::collections::HashSet;
-use std::sync::{Arc, Mutex};
-use uuid::Uuid;
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::time::Duration;
 
 pub mod adapter_in;
 pub mod adapter_out;
@@ -67,7 +98,9 @@ pub mod adapter_out;
 #[derive(Clone)]
 pub struct AppState {
     pub import_card_use_case: Arc<dyn ImportCardUseCase>,
-    pub edh_rec_caller_adapter: Arc<dyn EdhRecCaller>,
+    pub manage_collection_entry_use_case: Arc<dyn ManageCollectionEntryUseCase>,
+    pub manage_binders_use_case: Arc<dyn ManageBindersUseCase>,
+    pub manage_wishlist_use_case: Arc<dyn ManageWishlistUseCase>,
     pub stats_use_case: Arc<dyn StatsUseCase>,
     pub auth_service: Arc<dyn AuthService>,
     pub get_collection_use_case: Arc<dyn GetCollectionUseCase>,
@@ -77,6 +110,8 @@ pub struct AppState {
     pub enqueue_gatherer_id_use_case: Arc<dyn EnqueueGathererIdUpdateUseCase>,
     pub get_collection_price_history_use_case: Arc<dyn GetCollectionPriceHistoryUseCase>,
     pub get_card_price_history_use_case: Arc<dyn GetCardPriceHistoryUseCase>,
+    pub get_card_edhrec_use_case: Arc<dyn GetCardEdhRecUseCase>,
+    pub get_set_price_index_use_case: Arc<dyn GetSetPriceIndexUseCase>,
     pub get_collection_stats_use_case: Arc<dyn GetCollectionStatsUseCase>,
     pub register_user_use_case: Arc<dyn RegisterUserUseCase>,
     pub create_trade_use_case: Arc<dyn CreateTradeUseCase>,
@@ -85,7 +120,15 @@ pub struct AppState {
     pub confirm_trade_use_case: Arc<dyn ConfirmTradeUseCase>,
     pub rate_trade_use_case: Arc<dyn RateTradeUseCase>,
     pub get_card_offers_use_case: Arc<dyn GetCardOffersUseCase>,
+    pub get_trade_matches_use_case: Arc<dyn GetTradeMatchesUseCase>,
+    pub get_commander_synergy_use_case: Arc<dyn GetCommanderSynergyUseCase>,
     pub autocomplete_users_use_case: Arc<dyn AutocompleteUsersUseCase>,
+    pub review_price_anomaly_use_case: Arc<dyn ReviewPriceAnomalyUseCase>,
+    pub card_prices_refresh_use_case: Arc<dyn ScheduleCardPricesRefreshUseCase>,
+    pub scryfall_catalog_use_case: Arc<dyn ScryfallCatalogUseCase>,
+    pub get_unresolved_cards_use_case: Arc<dyn GetUnresolvedCardsUseCase>,
+    pub get_workers_status_use_case: Arc<dyn GetWorkersStatusUseCase>,
+    pub http_cache_use_case: Arc<dyn HttpCacheUseCase>,
     pub max_page_size: u32,
     pub max_page_number: u32,
 }
@@ -93,12 +136,21 @@ pub struct AppState {
 // ---- Repositories ----
 struct Repositories {
     card: Arc<CardRepositoryAdapter>,
+    binder: Arc<BinderRepositoryAdapter>,
     set_name: Arc<SetNameRepositoryAdapter>,
+    set_price_index: Arc<SetPriceIndexRepositoryAdapter>,
     card_market: Arc<CardMarketPriceRepositoryAdapter>,
+    price_anomaly: Arc<PriceAnomalyRepositoryAdapter>,
     card_prices_view: Arc<CardPricesViewRepositoryAdapter>,
+    edhrec_card_info: Arc<EdhRecCardInfoRepositoryAdapter>,
+    enrichment_job: Arc<EnrichmentJobRepositoryAdapter>,
+    http_response_cache: Arc<HttpResponseCacheRepositoryAdapter>,
+    scryfall_catalog: Arc<ScryfallCatalogRepositoryAdapter>,
     stats: Arc<StatsRepositoryAdapter>,
     user: Arc<UserRepositoryAdapter>,
     trade: Arc<TradeRepositoryAdapter>,
+    trade_match: Arc<TradeMatchRepositoryAdapter>,
+    wishlist: Arc<WishlistRepositoryAdapter>,
     collection_price_history: Arc<CollectionPriceHistoryRepositoryAdapter>,
     collection_stats: Arc<CollectionStatsRepositoryAdapter>,
 }
@@ -106,12 +158,21 @@ struct Repositories {
 fn create_repositories(pool: &Pool<Postgres>) -> Repositories {
     Repositories {
         card: Arc::new(CardRepositoryAdapter::new(pool.clone())),
+        binder: Arc::new(BinderRepositoryAdapter::new(pool.clone())),
         set_name: Arc::new(SetNameRepositoryAdapter::new(pool.clone())),
+        set_price_index: Arc::new(SetPriceIndexRepositoryAdapter::new(pool.clone())),
         card_market: Arc::new(CardMarketPriceRepositoryAdapter::new(pool.clone())),
+        price_anomaly: Arc::new(PriceAnomalyRepositoryAdapter::new(pool.clone())),
         card_prices_view: Arc::new(CardPricesViewRepositoryAdapter::new(pool.clone())),
+        edhrec_card_info: Arc::new(EdhRecCardInfoRepositoryAdapter::new(pool.clone())),
+        enrichment_job: Arc::new(EnrichmentJobRepositoryAdapter::new(pool.clone())),
+        http_response_cache: Arc::new(HttpResponseCacheRepositoryAdapter::new(pool.clone())),
+        scryfall_catalog: Arc::new(ScryfallCatalogRepositoryAdapter::new(pool.clone())),
         stats: Arc::new(StatsRepositoryAdapter::new(pool.clone())),
         user: Arc::new(UserRepositoryAdapter::new(pool.clone())),
         trade: Arc::new(TradeRepositoryAdapter::new(pool.clone())),
+        trade_match: Arc::new(TradeMatchRepositoryAdapter::new(pool.clone())),
+        wishlist: Arc::new(WishlistRepositoryAdapter::new(pool.clone())),
         collection_price_history: Arc::new(CollectionPriceHistoryRepositoryAdapter::new(
             pool.clone(),
         )),
@@ -124,20 +185,69 @@ struct Callers {
     card_market: Arc<CardMarketCallerAdapter>,
     edh_rec: Arc<EdhRecCallerAdapter>,
     scryfall: Arc<ScryfallCallerAdapter>,
+    scryfall_bulk: Arc<ScryfallBulkCallerAdapter>,
     gatherer: Arc<GathererCallerAdapter>,
 }
 
-fn create_callers(config: &Config) -> Callers {
+fn create_http_client(repos: &Repositories, config: &Config) -> Arc<HttpClient> {
+    let settings = HttpClientSettings {
+        connect_timeout: Duration::from_millis(config.http_connect_timeout_ms),
+        read_timeout: Duration::from_millis(config.http_read_timeout_ms),
+        max_retries: config.http_max_retries,
+        retry_base_delay: Duration::from_millis(config.http_retry_base_delay_ms),
+        retry_max_delay: Duration::from_millis(config.http_retry_max_delay_ms),
+        circuit_breaker_threshold: config.http_circuit_breaker_threshold,
+        circuit_breaker_cooldown: Duration::from_secs(config.http_circuit_breaker_cooldown_secs),
+    };
+    Arc::new(
+        HttpClient::new(settings)
+            .with_rate_limit(&config.scryfall_base_url, config.scryfall_rate_limit_tokens)
+            .with_rate_limit(&config.gatherer_base_url, config.gatherer_rate_limit_tokens)
+            .with_rate_limit(&config.edh_rec_base_url, config.edhrec_rate_limit_tokens)
+            .with_cache(
+                repos.http_response_cache.clone(),
+                HashMap::from([
+                    (
+                        HttpCacheSource::Scryfall,
+                        chrono::Duration::hours(config.http_cache_scryfall_ttl_hours),
+                    ),
+                    (
+                        HttpCacheSource::Gatherer,
+                        chrono::Duration::hours(config.http_cache_gatherer_ttl_hours),
+                    ),
+                    (
+                        HttpCacheSource::EdhRec,
+                        chrono::Duration::hours(config.http_cache_edhrec_ttl_hours),
+                    ),
+                ]),
+            ),
+    )
+}
+
+fn create_callers(repos: &Repositories, config: &Config) -> Callers {
+    let http_client = create_http_client(repos, config);
     Callers {
         card_market: Arc::new(CardMarketCallerAdapter::new(
             config.cardmarket_price_guides_url.clone(),
+            http_client.clone(),
+        )),
+        edh_rec: Arc::new(EdhRecCallerAdapter::new(
+            config.edh_rec_base_url.clone(),
+            http_client.clone(),
         )),
-        edh_rec: Arc::new(EdhRecCallerAdapter::new(config.edh_rec_base_url.clone())),
         scryfall: Arc::new(ScryfallCallerAdapter::new(
             config.scryfall_base_url.clone(),
-            config.scryfall_rate_limit_tokens,
+            http_client.clone(),
+        )),
+        scryfall_bulk: Arc::new(ScryfallBulkCallerAdapter::new(
+            config.scryfall_base_url.clone(),
+            config.scryfall_bulk_data_source.clone(),
+            http_client.clone(),
+        )),
+        gatherer: Arc::new(GathererCallerAdapter::new(
+            config.gatherer_base_url.clone(),
+            http_client,
         )),
-        gatherer: Arc::new(GathererCallerAdapter::new(config.gatherer_base_url.clone())),
     }
 }
 
@@ -153,62 +263,125 @@ async fn create_auth_service(config: &Config) -> Arc<dyn AuthService> {
 }
 
 // ---- Background workers ----
-// Canal non borné + HashSet de déduplication partagé entre enqueue service et worker
+// Une seule tâche exécute les rafraîchissements de card_prices, regroupés le temps du debounce
+fn spawn_card_prices_refresh_coordinator(
+    repos: &Repositories,
+    config: &Config,
+) -> Arc<CardPricesRefreshCoordinator> {
+    let coordinator = Arc::new(CardPricesRefreshCoordinator::new(
+        repos.card_prices_view.clone(),
+        repos.trade_match.clone(),
+        Duration::from_millis(config.card_prices_refresh_debounce_ms),
+    ));
+    tokio::spawn(coordinator.clone().run());
+
+    coordinator
+}
+
+fn create_scryfall_catalog_service(
+    repos: &Repositories,
+    callers: &Callers,
+    card_collection_service: Arc<CardCollectionService>,
+    card_prices_refresh: Arc<CardPricesRefreshCoordinator>,
+) -> Arc<ScryfallCatalogService> {
+    Arc::new(ScryfallCatalogService::new(
+        callers.scryfall_bulk.clone(),
+        repos.scryfall_catalog.clone(),
+        repos.card_market.clone(),
+        card_prices_refresh,
+        card_collection_service,
+    ))
+}
+
+fn enrichment_retry_policy(config: &Config) -> EnrichmentRetryPolicy {
+    EnrichmentRetryPolicy {
+        max_attempts: config.enrichment_max_attempts,
+        base_delay: chrono::Duration::seconds(config.enrichment_retry_base_delay_secs),
+        not_found_max_attempts: config.enrichment_not_found_max_attempts,
+        not_found_base_delay: chrono::Duration::hours(config.enrichment_not_found_base_delay_hours),
+    }
+}
+
+// Un worker qui s'arrête (panic) est marqué mort pour /maintenance/workers
+fn spawn_supervised<F>(name: &'static str, tracker: Arc<WorkerTracker>, task: F)
+where
+    F: Future<Output = ()> + Send + 'static,
+{
+    let handle = tokio::spawn(task);
+    tokio::spawn(async move {
+        let result = handle.await;
+        tracker.stopped();
+        match result {
+            Ok(()) => tracing::error!("{} worker stopped", name),
+            Err(e) => {
+                tracker.error(e.to_string());
+                tracing::error!("{} worker terminated with error: {:?}", name, e);
+            }
+        }
+    });
+}
+
+// File persistée dans enrichment_job : plusieurs instances peuvent se partager les jobs
 fn spawn_cardmarket_id_worker(
     repos: &Repositories,
     scryfall_caller_adapter: Arc<ScryfallCallerAdapter>,
     card_collection_service: Arc<CardCollectionService>,
+    card_prices_refresh: Arc<CardPricesRefreshCoordinator>,
+    scryfall_catalog: Arc<ScryfallCatalogService>,
+    tracker: Arc<WorkerTracker>,
+    config: &Config,
 ) -> Arc<CardMarketIdEnqueueService> {
-    let (sender, receiver) = tokio::sync::mpsc::unbounded_channel::<(CardId, Uuid)>();
-    let dedup_set = Arc::new(Mutex::new(HashSet::<CardId>::new()));
-
     let enqueue_service = Arc::new(CardMarketIdEnqueueService::new(
         repos.card.clone(),
-        sender,
-        dedup_set.clone(),
+        repos.enrichment_job.clone(),
+        scryfall_catalog,
     ));
 
     let worker = CardMarketIdWorker::new(
         repos.card.clone(),
+        repos.card_market.clone(),
+        repos.enrichment_job.clone(),
         scryfall_caller_adapter,
         card_collection_service,
-        repos.card_prices_view.clone(),
-        dedup_set,
+        card_prices_refresh,
+        enrichment_retry_policy(config),
+        tracker.clone(),
+    );
+    spawn_supervised(
+        "CardMarket ID",
+        tracker,
+        worker.run(Duration::from_millis(config.enrichment_poll_interval_ms)),
     );
-    tokio::spawn(async move {
-        if let Err(e) = worker.run(receiver).await {
-            tracing::error!("CardMarket worker terminated with error: {:?}", e);
-        }
-    });
 
     enqueue_service
 }
 
-// Canal + HashSet de déduplication dédiés à l'enrichissement the_gatherer_id
+// Même file, jobs de type GATHERER_ID
 fn spawn_gatherer_id_worker(
     repos: &Repositories,
     gatherer_caller_adapter: Arc<GathererCallerAdapter>,
+    card_prices_refresh: Arc<CardPricesRefreshCoordinator>,
+    tracker: Arc<WorkerTracker>,
+    config: &Config,
 ) -> Arc<GathererIdEnqueueService> {
-    let (sender, receiver) = tokio::sync::mpsc::unbounded_channel::<(CardId, String)>();
-    let dedup_set = Arc::new(Mutex::new(HashSet::<CardId>::new()));
-
     let enqueue_service = Arc::new(GathererIdEnqueueService::new(
         repos.card.clone(),
-        sender,
-        dedup_set.clone(),
+        repos.enrichment_job.clone(),
     ));
 
     let worker = GathererIdWorker::new(
         repos.card.clone(),
+        repos.enrichment_job.clone(),
         gatherer_caller_adapter,
-        repos.card_prices_view.clone(),
-        dedup_set,
+        card_prices_refresh,
+        enrichment_retry_policy(config),
+        tracker.clone(),
+    );
+    spawn_supervised(
+        "Gatherer ID",
+        tracker,
+        worker.run(Duration::from_millis(config.enrichment_poll_interval_ms)),
     );
-    tokio::spawn(async move {
-        if let Err(e) = worker.run(receiver).await {
-            tracing::error!("Gatherer worker terminated with error: {:?}", e);
-        }
-    });
 
     enqueue_service
 }
@@ -222,22 +395,51 @@ fn create_app_state(
     card_collection_service: Arc<CardCollectionService>,
     enqueue_cardmarket_id_use_case: Arc<CardMarketIdEnqueueService>,
     enqueue_gatherer_id_use_case: Arc<GathererIdEnqueueService>,
+    card_prices_refresh: Arc<CardPricesRefreshCoordinator>,
+    scryfall_catalog: Arc<ScryfallCatalogService>,
+    worker_monitor: Arc<WorkerMonitorService>,
     config: &Config,
 ) -> AppState {
     let import_card_service = Arc::new(ImportCardService::new(
         repos.card.clone(),
-        repos.set_name,
+        repos.binder.clone(),
+        repos.set_name.clone(),
         enqueue_cardmarket_id_use_case.clone(),
         enqueue_gatherer_id_use_case.clone(),
-        repos.card_prices_view.clone(),
+        card_prices_refresh.clone(),
+    ));
+    let collection_entry_service: Arc<dyn ManageCollectionEntryUseCase> =
+        Arc::new(CollectionEntryService::new(
+            repos.card.clone(),
+            repos.binder.clone(),
+            enqueue_cardmarket_id_use_case.clone(),
+            enqueue_gatherer_id_use_case.clone(),
+            card_prices_refresh.clone(),
+        ));
+    let binder_service: Arc<dyn ManageBindersUseCase> = Arc::new(BinderService::new(
+        repos.binder,
+        card_prices_refresh.clone(),
+    ));
+    let wishlist_service: Arc<dyn ManageWishlistUseCase> = Arc::new(WishlistService::new(
+        repos.wishlist,
+        card_prices_refresh.clone(),
     ));
 
     let import_price_use_case: Arc<dyn ImportPriceUseCase> = Arc::new(ImportPriceService::new(
         callers.card_market,
         repos.card_market.clone(),
-        repos.card_prices_view.clone(),
+        card_prices_refresh.clone(),
         card_collection_service.clone(),
+        config.price_anomaly_window_days,
+        config.price_anomaly_max_ratio,
+        config.price_import_scope,
     ));
+    let review_price_anomaly_service: Arc<dyn ReviewPriceAnomalyUseCase> =
+        Arc::new(PriceAnomalyService::new(
+            repos.price_anomaly,
+            card_prices_refresh.clone(),
+            card_collection_service.clone(),
+        ));
 
     let stats_service = Arc::new(StatsService::new(repos.stats));
     let collection_service = Arc::new(CollectionService::new(repos.card_prices_view.clone()));
@@ -249,6 +451,18 @@ fn create_app_state(
     let card_price_history_service: Arc<dyn GetCardPriceHistoryUseCase> = Arc::new(
         CardPriceHistoryService::new(repos.card.clone(), repos.card_market),
     );
+    let card_edhrec_service: Arc<dyn GetCardEdhRecUseCase> = Arc::new(CardEdhRecService::new(
+        repos.card.clone(),
+        repos.edhrec_card_info,
+        callers.edh_rec.clone(),
+        chrono::Duration::hours(config.edhrec_cache_ttl_hours),
+    ));
+    let commander_synergy_service: Arc<dyn GetCommanderSynergyUseCase> = Arc::new(
+        CommanderSynergyService::new(callers.edh_rec, repos.card_prices_view.clone()),
+    );
+    let set_price_index_service: Arc<dyn GetSetPriceIndexUseCase> = Arc::new(
+        SetPriceIndexService::new(repos.set_name, repos.set_price_index),
+    );
     let collection_stats_service: Arc<dyn GetCollectionStatsUseCase> =
         Arc::new(CollectionStatsService::new(repos.collection_stats));
     let register_user_service: Arc<dyn RegisterUserUseCase> =
@@ -265,12 +479,20 @@ fn create_app_state(
         Arc::new(RateTradeService::new(repos.trade));
     let card_offer_service: Arc<dyn GetCardOffersUseCase> =
         Arc::new(CardOfferService::new(repos.card_prices_view));
+    let trade_match_service: Arc<dyn GetTradeMatchesUseCase> =
+        Arc::new(TradeMatchService::new(repos.trade_match));
     let autocomplete_users_service: Arc<dyn AutocompleteUsersUseCase> =
         Arc::new(AutocompleteUserService::new(repos.user));
+    let unresolved_card_service: Arc<dyn GetUnresolvedCardsUseCase> =
+        Arc::new(UnresolvedCardService::new(repos.enrichment_job));
+    let http_cache_service: Arc<dyn HttpCacheUseCase> =
+        Arc::new(HttpCacheService::new(repos.http_response_cache));
 
     AppState {
         import_card_use_case: import_card_service,
-        edh_rec_caller_adapter: callers.edh_rec,
+        manage_collection_entry_use_case: collection_entry_service,
+        manage_binders_use_case: binder_service,
+        manage_wishlist_use_case: wishlist_service,
         stats_use_case: stats_service,
         auth_service,
         get_collection_use_case: collection_service,
@@ -280,6 +502,8 @@ fn create_app_state(
         enqueue_gatherer_id_use_case,
         get_collection_price_history_use_case: collection_price_history_service,
         get_card_price_history_use_case: card_price_history_service,
+        get_card_edhrec_use_case: card_edhrec_service,
+        get_set_price_index_use_case: set_price_index_service,
         get_collection_stats_use_case: collection_stats_service,
         register_user_use_case: register_user_service,
         create_trade_use_case: create_trade_service,
@@ -288,7 +512,15 @@ fn create_app_state(
         confirm_trade_use_case: confirm_trade_service,
         rate_trade_use_case: rate_trade_service,
         get_card_offers_use_case: card_offer_service,
+        get_trade_matches_use_case: trade_match_service,
+        get_commander_synergy_use_case: commander_synergy_service,
         autocomplete_users_use_case: autocomplete_users_service,
+        review_price_anomaly_use_case: review_price_anomaly_service,
+        card_prices_refresh_use_case: card_prices_refresh,
+        scryfall_catalog_use_case: scryfall_catalog,
+        get_unresolved_cards_use_case: unresolved_card_service,
+        get_workers_status_use_case: worker_monitor,
+        http_cache_use_case: http_cache_service,
         max_page_size: config.max_page_size,
         max_page_number: config.max_page_number,
     }
@@ -312,15 +544,38 @@ async fn schedule_price_import_job(import_price_use_case: Arc<dyn ImportPriceUse
     cron.start().await;
 }
 
+// Le fichier bulk de Scryfall est régénéré chaque jour : un import quotidien suffit
+async fn schedule_scryfall_catalog_import_job(
+    scryfall_catalog_use_case: Arc<dyn ScryfallCatalogUseCase>,
+) {
+    let mut cron = AsyncCron::new(Utc);
+
+    cron.add_fn("0 0 4 * * *", move || {
+        let service = scryfall_catalog_use_case.clone();
+        async move {
+            if let Err(e) = service.import_catalog().await {
+                tracing::error!("Failed to import the Scryfall catalogue: {:?}", e);
+            }
+        }
+    })
+    .await
+    .unwrap();
+
+    cron.start().await;
+}
+
 fn create_router(app_state: AppState) -> Router {
     Router::new()
         .nest("/autocomplete", create_autocomplete_router())
+        .nest("/binders", create_binder_router())
         .nest("/card", create_card_router())
         .nest("/collection", create_collection_router())
         .nest("/search", create_search_router())
+        .nest("/set", create_set_router())
         .nest("/maintenance", create_maintenance_router())
         .nest("/user", create_user_router())
         .nest("/trades", create_trade_router())
+        .nest("/wishlist", create_wishlist_router())
         .with_state(app_state)
         .layer(NewSentryLayer::<Request<Body>>::new_from_top())
         .layer(SentryHttpLayer::new().enable_transaction())
@@ -328,19 +583,45 @@ fn create_router(app_state: AppState) -> Router {
 
 pub async fn create_infra(pool: Pool<Postgres>, config: &Config) -> Router {
     let repos = create_repositories(&pool);
-    let callers = create_callers(config);
+    let callers = create_callers(&repos, config);
     let auth_service = create_auth_service(config).await;
 
     let card_collection_service = Arc::new(CardCollectionService::new(
         repos.collection_price_history.clone(),
     ));
 
+    let card_prices_refresh = spawn_card_prices_refresh_coordinator(&repos, config);
+    let scryfall_catalog = create_scryfall_catalog_service(
+        &repos,
+        &callers,
+        card_collection_service.clone(),
+        card_prices_refresh.clone(),
+    );
+    let cardmarket_id_tracker = Arc::new(WorkerTracker::default());
     let enqueue_cardmarket_id_use_case = spawn_cardmarket_id_worker(
         &repos,
         callers.scryfall.clone(),
         card_collection_service.clone(),
+        card_prices_refresh.clone(),
+        scryfall_catalog.clone(),
+        cardmarket_id_tracker.clone(),
+        config,
     );
-    let enqueue_gatherer_id_use_case = spawn_gatherer_id_worker(&repos, callers.gatherer.clone());
+    let gatherer_id_tracker = Arc::new(WorkerTracker::default());
+    let enqueue_gatherer_id_use_case = spawn_gatherer_id_worker(
+        &repos,
+        callers.gatherer.clone(),
+        card_prices_refresh.clone(),
+        gatherer_id_tracker.clone(),
+        config,
+    );
+    let worker_monitor = Arc::new(WorkerMonitorService::new(
+        repos.enrichment_job.clone(),
+        vec![
+            (EnrichmentKind::CardMarketId, cardmarket_id_tracker),
+            (EnrichmentKind::GathererId, gatherer_id_tracker),
+        ],
+    ));
 
     let app_state = create_app_state(
         repos,
@@ -349,10 +630,14 @@ pub async fn create_infra(pool: Pool<Postgres>, config: &Config) -> Router {
         card_collection_service,
         enqueue_cardmarket_id_use_case,
         enqueue_gatherer_id_use_case,
+        card_prices_refresh,
+        scryfall_catalog,
+        worker_monitor,
         config,
     );
 
     schedule_price_import_job(app_state.import_price_use_case.clone()).await;
+    schedule_scryfall_catalog_import_job(app_state.scryfall_catalog_use_case.clone()).await;
 
     create_router(app_state)
 }
@@ -372,33 +657,27 @@ impl AppState {
         stats_use_case: Arc<dyn StatsUseCase>,
         import_price_use_case: Arc<dyn ImportPriceUseCase>,
     ) -> Self {
-        use crate::application::caller::MockEdhRecCaller;
         use crate::application::service::auth_service::MockAuthService;
         use crate::application::use_case::{
             MockAbandonTradeUseCase, MockAcceptTradeUseCase, MockAutocompleteUsersUseCase,
             MockConfirmTradeUseCase, MockCreateTradeUseCase, MockEnqueueCardMarketIdUpdateUseCase,
-            MockEnqueueGathererIdUpdateUseCase, MockGetCardOffersUseCase,
+            MockEnqueueGathererIdUpdateUseCase, MockGetCardEdhRecUseCase, MockGetCardOffersUseCase,
             MockGetCardPriceHistoryUseCase, MockGetCollectionPriceHistoryUseCase,
-            MockGetCollectionStatsUseCase, MockGetCollectionUseCase, MockImportCardUseCase,
-            MockRateTradeUseCase, MockRegisterUserUseCase, MockSearchCardsUseCase,
+            MockGetCollectionStatsUseCase, MockGetCollectionUseCase,
+            MockGetCommanderSynergyUseCase, MockGetSetPriceIndexUseCase,
+            MockGetTradeMatchesUseCase, MockGetUnresolvedCardsUseCase, MockGetWorkersStatusUseCase,
+            MockHttpCacheUseCase, MockImportCardUseCase, MockManageBindersUseCase,
+            MockManageCollectionEntryUseCase, MockManageWishlistUseCase, MockRateTradeUseCase,
+            MockRegisterUserUseCase, MockReviewPriceAnomalyUseCase,
+            MockScheduleCardPricesRefreshUseCase, MockScryfallCatalogUseCase,
+            MockSearchCardsUseCase,
         };
-        use crate::domain::card::CardInfo;
         use crate::domain::user::User;
 
         let mut mock_import_card = MockImportCardUseCase::new();
         mock_import_card
             .expect_import_cards()
-            .returning(|_, _| Box::pin(async { Ok(()) }));
-
-        let mut mock_edh_rec = MockEdhRecCaller::new();
-        mock_edh_rec.expect_get_card_info().returning(|_| {
-            Box::pin(async {
-                Ok(CardInfo {
-                    inclusion: 0,
-                    total_decks: 0,
-                })
-            })
-        });
+            .returning(|_, _, _| Box::pin(async { Ok(()) }));
 
         let mut mock_auth = MockAuthService::new();
         mock_auth
@@ -407,7 +686,9 @@ impl AppState {
 
         Self {
             import_card_use_case: Arc::new(mock_import_card),
-            edh_rec_caller_adapter: Arc::new(mock_edh_rec),
+            manage_collection_entry_use_case: Arc::new(MockManageCollectionEntryUseCase::new()),
+            manage_binders_use_case: Arc::new(MockManageBindersUseCase::new()),
+            manage_wishlist_use_case: Arc::new(MockManageWishlistUseCase::new()),
             stats_use_case,
             auth_service: Arc::new(mock_auth),
             get_collection_use_case: Arc::new(MockGetCollectionUseCase::new()),
@@ -419,6 +700,8 @@ impl AppState {
                 MockGetCollectionPriceHistoryUseCase::new(),
             ),
             get_card_price_history_use_case: Arc::new(MockGetCardPriceHistoryUseCase::new()),
+            get_card_edhrec_use_case: Arc::new(MockGetCardEdhRecUseCase::new()),
+            get_set_price_index_use_case: Arc::new(MockGetSetPriceIndexUseCase::new()),
             get_collection_stats_use_case: Arc::new(MockGetCollectionStatsUseCase::new()),
             register_user_use_case: Arc::new(MockRegisterUserUseCase::new()),
             create_trade_use_case: Arc::new(MockCreateTradeUseCase::new()),
@@ -427,7 +710,15 @@ impl AppState {
             confirm_trade_use_case: Arc::new(MockConfirmTradeUseCase::new()),
             rate_trade_use_case: Arc::new(MockRateTradeUseCase::new()),
             get_card_offers_use_case: Arc::new(MockGetCardOffersUseCase::new()),
+            get_trade_matches_use_case: Arc::new(MockGetTradeMatchesUseCase::new()),
+            get_commander_synergy_use_case: Arc::new(MockGetCommanderSynergyUseCase::new()),
             autocomplete_users_use_case: Arc::new(MockAutocompleteUsersUseCase::new()),
+            review_price_anomaly_use_case: Arc::new(MockReviewPriceAnomalyUseCase::new()),
+            card_prices_refresh_use_case: Arc::new(MockScheduleCardPricesRefreshUseCase::new()),
+            scryfall_catalog_use_case: Arc::new(MockScryfallCatalogUseCase::new()),
+            get_unresolved_cards_use_case: Arc::new(MockGetUnresolvedCardsUseCase::new()),
+            get_workers_status_use_case: Arc::new(MockGetWorkersStatusUseCase::new()),
+            http_cache_use_case: Arc::new(MockHttpCacheUseCase::new()),
             max_page_size: 100,
             max_page_number: 10,
         }
@@ -538,6 +829,21 @@ impl AppState {
         base
     }
 
+    pub fn for_testing_with_trade_matches(
+        stats_use_case: Arc<dyn StatsUseCase>,
+        get_trade_matches_use_case: Arc<dyn GetTradeMatchesUseCase>,
+    ) -> Self {
+        use crate::application::use_case::MockImportPriceUseCase;
+        let mut mock_import_price = MockImportPriceUseCase::new();
+        mock_import_price
+            .expect_import_prices_for_current_date()
+            .returning(|| Box::pin(async { Ok(()) }));
+        let mut base =
+            Self::for_testing_with_import_price(stats_use_case, Arc::new(mock_import_price));
+        base.get_trade_matches_use_case = get_trade_matches_use_case;
+        base
+    }
+
     pub fn for_testing_with_enqueue_gatherer_id(
         stats_use_case: Arc<dyn StatsUseCase>,
         enqueue_gatherer_id_use_case: Arc<dyn EnqueueGathererIdUpdateUseCase>,
@@ -552,4 +858,169 @@ impl AppState {
         base.enqueue_gatherer_id_use_case = enqueue_gatherer_id_use_case;
         base
     }
+
+    pub fn for_testing_with_review_price_anomaly(
+        stats_use_case: Arc<dyn StatsUseCase>,
+        review_price_anomaly_use_case: Arc<dyn ReviewPriceAnomalyUseCase>,
+    ) -> Self {
+        use crate::application::use_case::MockImportPriceUseCase;
+        let mut mock_import_price = MockImportPriceUseCase::new();
+        mock_import_price
+            .expect_import_prices_for_current_date()
+            .returning(|| Box::pin(async { Ok(()) }));
+        let mut base =
+            Self::for_testing_with_import_price(stats_use_case, Arc::new(mock_import_price));
+        base.review_price_anomaly_use_case = review_price_anomaly_use_case;
+        base
+    }
+
+    pub fn for_testing_with_card_prices_refresh(
+        stats_use_case: Arc<dyn StatsUseCase>,
+        card_prices_refresh_use_case: Arc<dyn ScheduleCardPricesRefreshUseCase>,
+    ) -> Self {
+        use crate::application::use_case::MockImportPriceUseCase;
+        let mut mock_import_price = MockImportPriceUseCase::new();
+        mock_import_price
+            .expect_import_prices_for_current_date()
+            .returning(|| Box::pin(async { Ok(()) }));
+        let mut base =
+            Self::for_testing_with_import_price(stats_use_case, Arc::new(mock_import_price));
+        base.card_prices_refresh_use_case = card_prices_refresh_use_case;
+        base
+    }
+
+    pub fn for_testing_with_scryfall_catalog(
+        stats_use_case: Arc<dyn StatsUseCase>,
+        scryfall_catalog_use_case: Arc<dyn ScryfallCatalogUseCase>,
+    ) -> Self {
+        use crate::application::use_case::MockImportPriceUseCase;
+        let mut mock_import_price = MockImportPriceUseCase::new();
+        mock_import_price
+            .expect_import_prices_for_current_date()
+            .returning(|| Box::pin(async { Ok(()) }));
+        let mut base =
+            Self::for_testing_with_import_price(stats_use_case, Arc::new(mock_import_price));
+        base.scryfall_catalog_use_case = scryfall_catalog_use_case;
+        base
+    }
+
+    pub fn for_testing_with_unresolved_cards(
+        stats_use_case: Arc<dyn StatsUseCase>,
+        get_unresolved_cards_use_case: Arc<dyn GetUnresolvedCardsUseCase>,
+    ) -> Self {
+        use crate::application::use_case::MockImportPriceUseCase;
+        let mut mock_import_price = MockImportPriceUseCase::new();
+        mock_import_price
+            .expect_import_prices_for_current_date()
+            .returning(|| Box::pin(async { Ok(()) }));
+        let mut base =
+            Self::for_testing_with_import_price(stats_use_case, Arc::new(mock_import_price));
+        base.get_unresolved_cards_use_case = get_unresolved_cards_use_case;
+        base
+    }
+
+    pub fn for_testing_with_workers_status(
+        stats_use_case: Arc<dyn StatsUseCase>,
+        get_workers_status_use_case: Arc<dyn GetWorkersStatusUseCase>,
+    ) -> Self {
+        use crate::application::use_case::MockImportPriceUseCase;
+        let mut mock_import_price = MockImportPriceUseCase::new();
+        mock_import_price
+            .expect_import_prices_for_current_date()
+            .returning(|| Box::pin(async { Ok(()) }));
+        let mut base =
+            Self::for_testing_with_import_price(stats_use_case, Arc::new(mock_import_price));
+        base.get_workers_status_use_case = get_workers_status_use_case;
+        base
+    }
+
+    pub fn for_testing_with_card_edhrec(
+        stats_use_case: Arc<dyn StatsUseCase>,
+        get_card_edhrec_use_case: Arc<dyn GetCardEdhRecUseCase>,
+    ) -> Self {
+        use crate::application::use_case::MockImportPriceUseCase;
+        let mut mock_import_price = MockImportPriceUseCase::new();
+        mock_import_price
+            .expect_import_prices_for_current_date()
+            .returning(|| Box::pin(async { Ok(()) }));
+        let mut base =
+            Self::for_testing_with_import_price(stats_use_case, Arc::new(mock_import_price));
+        base.get_card_edhrec_use_case = get_card_edhrec_use_case;
+        base
+    }
+
+    pub fn for_testing_with_commander_synergy(
+        stats_use_case: Arc<dyn StatsUseCase>,
+        get_commander_synergy_use_case: Arc<dyn GetCommanderSynergyUseCase>,
+    ) -> Self {
+        use crate::application::use_case::MockImportPriceUseCase;
+        let mut mock_import_price = MockImportPriceUseCase::new();
+        mock_import_price
+            .expect_import_prices_for_current_date()
+            .returning(|| Box::pin(async { Ok(()) }));
+        let mut base =
+            Self::for_testing_with_import_price(stats_use_case, Arc::new(mock_import_price));
+        base.get_commander_synergy_use_case = get_commander_synergy_use_case;
+        base
+    }
+
+    pub fn for_testing_with_manage_collection_entry(
+        stats_use_case: Arc<dyn StatsUseCase>,
+        manage_collection_entry_use_case: Arc<dyn ManageCollectionEntryUseCase>,
+    ) -> Self {
+        use crate::application::use_case::MockImportPriceUseCase;
+        let mut mock_import_price = MockImportPriceUseCase::new();
+        mock_import_price
+            .expect_import_prices_for_current_date()
+            .returning(|| Box::pin(async { Ok(()) }));
+        let mut base =
+            Self::for_testing_with_import_price(stats_use_case, Arc::new(mock_import_price));
+        base.manage_collection_entry_use_case = manage_collection_entry_use_case;
+        base
+    }
+
+    pub fn for_testing_with_manage_binders(
+        stats_use_case: Arc<dyn StatsUseCase>,
+        manage_binders_use_case: Arc<dyn ManageBindersUseCase>,
+    ) -> Self {
+        use crate::application::use_case::MockImportPriceUseCase;
+        let mut mock_import_price = MockImportPriceUseCase::new();
+        mock_import_price
+            .expect_import_prices_for_current_date()
+            .returning(|| Box::pin(async { Ok(()) }));
+        let mut base =
+            Self::for_testing_with_import_price(stats_use_case, Arc::new(mock_import_price));
+        base.manage_binders_use_case = manage_binders_use_case;
+        base
+    }
+
+    pub fn for_testing_with_manage_wishlist(
+        stats_use_case: Arc<dyn StatsUseCase>,
+        manage_wishlist_use_case: Arc<dyn ManageWishlistUseCase>,
+    ) -> Self {
+        use crate::application::use_case::MockImportPriceUseCase;
+        let mut mock_import_price = MockImportPriceUseCase::new();
+        mock_import_price
+            .expect_import_prices_for_current_date()
+            .returning(|| Box::pin(async { Ok(()) }));
+        let mut base =
+            Self::for_testing_with_import_price(stats_use_case, Arc::new(mock_import_price));
+        base.manage_wishlist_use_case = manage_wishlist_use_case;
+        base
+    }
+
+    pub fn for_testing_with_http_cache(
+        stats_use_case: Arc<dyn StatsUseCase>,
+        http_cache_use_case: Arc<dyn HttpCacheUseCase>,
+    ) -> Self {
+        use crate::application::use_case::MockImportPriceUseCase;
+        let mut mock_import_price = MockImportPriceUseCase::new();
+        mock_import_price
+            .expect_import_prices_for_current_date()
+            .returning(|| Box::pin(async { Ok(()) }));
+        let mut base =
+            Self::for_testing_with_import_price(stats_use_case, Arc::new(mock_import_price));
+        base.http_cache_use_case = http_cache_use_case;
+        base
+    }
 }
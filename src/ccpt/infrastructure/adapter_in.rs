@@ -7,13 +7,16 @@ use serde_json::json;
 
 pub mod auth_extractor;
 pub mod autocomplete;
+pub mod binder;
 pub mod card;
 pub mod collection;
 pub mod maintenance;
 pub mod openapi;
 pub mod search;
+pub mod set;
 pub mod trade;
 pub mod user;
+pub mod wishlist;
 
 impl IntoResponse for AppError {
     fn into_response(self) -> Response {
@@ -28,7 +31,13 @@ impl IntoResponse for AppError {
                 | FunctionalError::SelfTrade => StatusCode::BAD_REQUEST,
                 FunctionalError::PriceNotFound
                 | FunctionalError::CardNotFound
-                | FunctionalError::TradeNotFound => StatusCode::NOT_FOUND,
+                | FunctionalError::TradeNotFound
+                | FunctionalError::PriceAnomalyNotFound
+                | FunctionalError::SetNotFound
+                | FunctionalError::CommanderNotFound
+                | FunctionalError::CollectionEntryNotFound
+                | FunctionalError::BinderNotFound
+                | FunctionalError::WishlistEntryNotFound => StatusCode::NOT_FOUND,
                 FunctionalError::TradeAccessDenied => StatusCode::FORBIDDEN,
                 FunctionalError::TradeNotModifiable
                 | FunctionalError::TradeNotAcceptable
@@ -37,7 +46,12 @@ impl IntoResponse for AppError {
                 | FunctionalError::TradeNotFullyAccepted
                 | FunctionalError::TradeAlreadyConfirmed
                 | FunctionalError::TradeNotCompleted
-                | FunctionalError::TradeAlreadyRated => StatusCode::CONFLICT,
+                | FunctionalError::TradeAlreadyRated
+                | FunctionalError::CollectionEntryAlreadyExists
+                | FunctionalError::BinderNameAlreadyUsed
+                | FunctionalError::DefaultBinderNotDeletable
+                | FunctionalError::WishlistEntryAlreadyExists
+                | FunctionalError::PriceImportInProgress => StatusCode::CONFLICT,
             },
             AppError::Authentication(_) => StatusCode::UNAUTHORIZED,
             AppError::Infra(e) => match e {
@@ -125,6 +139,62 @@ mod tests {
         assert_eq!(response.status(), StatusCode::CONFLICT);
     }
 
+    #[test]
+    fn collection_entry_not_found_returns_not_found_status() {
+        let error = AppError::Functional(FunctionalError::CollectionEntryNotFound);
+        let response = error.into_response();
+        assert_eq!(response.status(), StatusCode::NOT_FOUND);
+    }
+
+    #[test]
+    fn collection_entry_already_exists_returns_conflict_status() {
+        let error = AppError::Functional(FunctionalError::CollectionEntryAlreadyExists);
+        let response = error.into_response();
+        assert_eq!(response.status(), StatusCode::CONFLICT);
+    }
+
+    #[test]
+    fn binder_not_found_returns_not_found_status() {
+        let error = AppError::Functional(FunctionalError::BinderNotFound);
+        let response = error.into_response();
+        assert_eq!(response.status(), StatusCode::NOT_FOUND);
+    }
+
+    #[test]
+    fn binder_name_already_used_returns_conflict_status() {
+        let error = AppError::Functional(FunctionalError::BinderNameAlreadyUsed);
+        let response = error.into_response();
+        assert_eq!(response.status(), StatusCode::CONFLICT);
+    }
+
+    #[test]
+    fn default_binder_not_deletable_returns_conflict_status() {
+        let error = AppError::Functional(FunctionalError::DefaultBinderNotDeletable);
+        let response = error.into_response();
+        assert_eq!(response.status(), StatusCode::CONFLICT);
+    }
+
+    #[test]
+    fn wishlist_entry_not_found_returns_not_found_status() {
+        let error = AppError::Functional(FunctionalError::WishlistEntryNotFound);
+        let response = error.into_response();
+        assert_eq!(response.status(), StatusCode::NOT_FOUND);
+    }
+
+    #[test]
+    fn wishlist_entry_already_exists_returns_conflict_status() {
+        let error = AppError::Functional(FunctionalError::WishlistEntryAlreadyExists);
+        let response = error.into_response();
+        assert_eq!(response.status(), StatusCode::CONFLICT);
+    }
+
+    #[test]
+    fn price_import_in_progress_returns_conflict_status() {
+        let error = AppError::Functional(FunctionalError::PriceImportInProgress);
+        let response = error.into_response();
+        assert_eq!(response.status(), StatusCode::CONFLICT);
+    }
+
     #[test]
     fn trade_not_found_returns_not_found_status() {
         let error = AppError::Functional(FunctionalError::TradeNotFound);
@@ -132,6 +202,27 @@ mod tests {
         assert_eq!(response.status(), StatusCode::NOT_FOUND);
     }
 
+    #[test]
+    fn price_anomaly_not_found_returns_not_found_status() {
+        let error = AppError::Functional(FunctionalError::PriceAnomalyNotFound);
+        let response = error.into_response();
+        assert_eq!(response.status(), StatusCode::NOT_FOUND);
+    }
+
+    #[test]
+    fn set_not_found_returns_not_found_status() {
+        let error = AppError::Functional(FunctionalError::SetNotFound);
+        let response = error.into_response();
+        assert_eq!(response.status(), StatusCode::NOT_FOUND);
+    }
+
+    #[test]
+    fn commander_not_found_returns_not_found_status() {
+        let error = AppError::Functional(FunctionalError::CommanderNotFound);
+        let response = error.into_response();
+        assert_eq!(response.status(), StatusCode::NOT_FOUND);
+    }
+
     #[test]
     fn trade_access_denied_returns_forbidden_status() {
         let error = AppError::Functional(FunctionalError::TradeAccessDenied);
@@ -54,7 +54,6 @@ impl FromRequestParts<AppState> for AuthenticatedUser {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::application::caller::MockEdhRecCaller;
     use crate::application::service::auth_service::{AuthService, MockAuthService};
     use crate::application::use_case::{MockImportCardUseCase, MockStatsUseCase};
     use crate::domain::user::UserId;
@@ -65,14 +64,22 @@ mod tests {
         use crate::application::use_case::{
             MockAbandonTradeUseCase, MockAcceptTradeUseCase, MockAutocompleteUsersUseCase,
             MockConfirmTradeUseCase, MockCreateTradeUseCase, MockEnqueueCardMarketIdUpdateUseCase,
-            MockEnqueueGathererIdUpdateUseCase, MockGetCardOffersUseCase,
+            MockEnqueueGathererIdUpdateUseCase, MockGetCardEdhRecUseCase, MockGetCardOffersUseCase,
             MockGetCardPriceHistoryUseCase, MockGetCollectionPriceHistoryUseCase,
-            MockGetCollectionStatsUseCase, MockGetCollectionUseCase, MockImportPriceUseCase,
-            MockRateTradeUseCase, MockRegisterUserUseCase, MockSearchCardsUseCase,
+            MockGetCollectionStatsUseCase, MockGetCollectionUseCase,
+            MockGetCommanderSynergyUseCase, MockGetSetPriceIndexUseCase,
+            MockGetTradeMatchesUseCase, MockGetUnresolvedCardsUseCase, MockGetWorkersStatusUseCase,
+            MockHttpCacheUseCase, MockImportPriceUseCase, MockManageBindersUseCase,
+            MockManageCollectionEntryUseCase, MockManageWishlistUseCase, MockRateTradeUseCase,
+            MockRegisterUserUseCase, MockReviewPriceAnomalyUseCase,
+            MockScheduleCardPricesRefreshUseCase, MockScryfallCatalogUseCase,
+            MockSearchCardsUseCase,
         };
         AppState {
             import_card_use_case: Arc::new(MockImportCardUseCase::new()),
-            edh_rec_caller_adapter: Arc::new(MockEdhRecCaller::new()),
+            manage_collection_entry_use_case: Arc::new(MockManageCollectionEntryUseCase::new()),
+            manage_binders_use_case: Arc::new(MockManageBindersUseCase::new()),
+            manage_wishlist_use_case: Arc::new(MockManageWishlistUseCase::new()),
             stats_use_case: Arc::new(MockStatsUseCase::new()),
             auth_service,
             get_collection_use_case: Arc::new(MockGetCollectionUseCase::new()),
@@ -84,6 +91,8 @@ mod tests {
                 MockGetCollectionPriceHistoryUseCase::new(),
             ),
             get_card_price_history_use_case: Arc::new(MockGetCardPriceHistoryUseCase::new()),
+            get_card_edhrec_use_case: Arc::new(MockGetCardEdhRecUseCase::new()),
+            get_set_price_index_use_case: Arc::new(MockGetSetPriceIndexUseCase::new()),
             get_collection_stats_use_case: Arc::new(MockGetCollectionStatsUseCase::new()),
             register_user_use_case: Arc::new(MockRegisterUserUseCase::new()),
             create_trade_use_case: Arc::new(MockCreateTradeUseCase::new()),
@@ -92,7 +101,15 @@ mod tests {
             confirm_trade_use_case: Arc::new(MockConfirmTradeUseCase::new()),
             rate_trade_use_case: Arc::new(MockRateTradeUseCase::new()),
             get_card_offers_use_case: Arc::new(MockGetCardOffersUseCase::new()),
+            get_trade_matches_use_case: Arc::new(MockGetTradeMatchesUseCase::new()),
+            get_commander_synergy_use_case: Arc::new(MockGetCommanderSynergyUseCase::new()),
             autocomplete_users_use_case: Arc::new(MockAutocompleteUsersUseCase::new()),
+            review_price_anomaly_use_case: Arc::new(MockReviewPriceAnomalyUseCase::new()),
+            card_prices_refresh_use_case: Arc::new(MockScheduleCardPricesRefreshUseCase::new()),
+            scryfall_catalog_use_case: Arc::new(MockScryfallCatalogUseCase::new()),
+            get_unresolved_cards_use_case: Arc::new(MockGetUnresolvedCardsUseCase::new()),
+            get_workers_status_use_case: Arc::new(MockGetWorkersStatusUseCase::new()),
+            http_cache_use_case: Arc::new(MockHttpCacheUseCase::new()),
             max_page_size: 100,
             max_page_number: 10,
         }
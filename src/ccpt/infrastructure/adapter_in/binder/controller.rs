@@ -0,0 +1,124 @@
+use super::dto::{BinderResponse, CreateBinderRequest, UpdateBinderRequest};
+use crate::application::error::AppError;
+use crate::domain::binder::{BinderId, BinderUpdate};
+use crate::infrastructure::AppState;
+use crate::infrastructure::adapter_in::auth_extractor::AuthenticatedUser;
+use axum::extract::{Path, State};
+use axum::http::StatusCode;
+use axum::routing::{get, patch};
+
+pub fn create_binder_router() -> axum::Router<AppState> {
+    axum::Router::new()
+        .route("/", get(list_binders).post(create_binder))
+        .route("/{binder_id}", patch(update_binder).delete(delete_binder))
+}
+
+#[utoipa::path(
+    get,
+    path = "/binders",
+    responses(
+        (status = 200, description = "Binders of the caller, the default one first", body = Vec<BinderResponse>),
+        (status = 401, description = "Missing or invalid token"),
+    ),
+    security(("bearer_auth" = [])),
+    tag = "binders",
+)]
+pub(crate) async fn list_binders(
+    AuthenticatedUser(user): AuthenticatedUser,
+    State(state): State<AppState>,
+) -> Result<axum::Json<Vec<BinderResponse>>, AppError> {
+    let binders = state.manage_binders_use_case.list_binders(&user.id).await?;
+
+    Ok(axum::Json(
+        binders.into_iter().map(BinderResponse::from).collect(),
+    ))
+}
+
+#[utoipa::path(
+    post,
+    path = "/binders",
+    request_body = CreateBinderRequest,
+    responses(
+        (status = 201, description = "Binder created", body = BinderResponse),
+        (status = 400, description = "Empty or too long name"),
+        (status = 401, description = "Missing or invalid token"),
+        (status = 409, description = "The caller already has a binder with this name"),
+    ),
+    security(("bearer_auth" = [])),
+    tag = "binders",
+)]
+pub(crate) async fn create_binder(
+    AuthenticatedUser(user): AuthenticatedUser,
+    State(state): State<AppState>,
+    axum::Json(payload): axum::Json<CreateBinderRequest>,
+) -> Result<(StatusCode, axum::Json<BinderResponse>), AppError> {
+    let binder = state
+        .manage_binders_use_case
+        .create_binder(&user.id, payload.name, payload.tradable)
+        .await?;
+
+    Ok((StatusCode::CREATED, axum::Json(binder.into())))
+}
+
+#[utoipa::path(
+    patch,
+    path = "/binders/{binder_id}",
+    params(("binder_id" = uuid::Uuid, Path, description = "Binder id")),
+    request_body = UpdateBinderRequest,
+    responses(
+        (status = 200, description = "Binder updated, without its card count", body = BinderResponse),
+        (status = 400, description = "Empty or too long name"),
+        (status = 401, description = "Missing or invalid token"),
+        (status = 404, description = "Binder not found"),
+        (status = 409, description = "The caller already has another binder with this name"),
+    ),
+    security(("bearer_auth" = [])),
+    tag = "binders",
+)]
+pub(crate) async fn update_binder(
+    AuthenticatedUser(user): AuthenticatedUser,
+    State(state): State<AppState>,
+    Path(binder_id): Path<uuid::Uuid>,
+    axum::Json(payload): axum::Json<UpdateBinderRequest>,
+) -> Result<axum::Json<BinderResponse>, AppError> {
+    let binder = state
+        .manage_binders_use_case
+        .update_binder(
+            &user.id,
+            BinderId(binder_id),
+            BinderUpdate {
+                name: payload.name,
+                tradable: payload.tradable,
+                keep_quantity: payload.keep_quantity,
+            },
+        )
+        .await?;
+
+    Ok(axum::Json(binder.into()))
+}
+
+#[utoipa::path(
+    delete,
+    path = "/binders/{binder_id}",
+    params(("binder_id" = uuid::Uuid, Path, description = "Binder id")),
+    responses(
+        (status = 204, description = "Binder deleted with all its entries"),
+        (status = 401, description = "Missing or invalid token"),
+        (status = 404, description = "Binder not found"),
+        (status = 409, description = "The default binder cannot be deleted"),
+    ),
+    security(("bearer_auth" = [])),
+    tag = "binders",
+)]
+pub(crate) async fn delete_binder(
+    AuthenticatedUser(user): AuthenticatedUser,
+    State(state): State<AppState>,
+    Path(binder_id): Path<uuid::Uuid>,
+) -> Result<StatusCode, AppError> {
+    state
+        .manage_binders_use_case
+        .delete_binder(&user.id, BinderId(binder_id))
+        .await?;
+
+    Ok(StatusCode::NO_CONTENT)
+}
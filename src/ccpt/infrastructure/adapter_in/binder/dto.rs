@@ -0,0 +1,64 @@
+use crate::domain::binder::{Binder, BinderSummary};
+use serde::{Deserialize, Serialize};
+use ts_rs::TS;
+use utoipa::ToSchema;
+
+#[derive(Serialize, Debug, TS, ToSchema)]
+#[serde(rename = "Binder")]
+#[ts(export, export_to = "Binder.ts")]
+pub struct BinderResponse {
+    pub id: String,
+    pub name: String,
+    /// Copies of a tradable binder are visible to other users and can be asked for in a trade
+    pub tradable: bool,
+    /// Copies of each entry kept out of the trades, unless the entry sets its own tradable
+    /// quantity
+    pub keep_quantity: u8,
+    /// Binder the imports and manual adds go to when none is given
+    pub is_default: bool,
+    /// Number of copies in the binder, 0 right after creation
+    pub card_count: u64,
+    /// RFC 3339 timestamp
+    pub created_at: String,
+}
+
+impl From<Binder> for BinderResponse {
+    fn from(binder: Binder) -> Self {
+        BinderSummary {
+            binder,
+            card_count: 0,
+        }
+        .into()
+    }
+}
+
+impl From<BinderSummary> for BinderResponse {
+    fn from(summary: BinderSummary) -> Self {
+        Self {
+            id: summary.binder.id.to_string(),
+            name: summary.binder.name,
+            tradable: summary.binder.tradable,
+            keep_quantity: summary.binder.keep_quantity,
+            is_default: summary.binder.is_default,
+            card_count: summary.card_count,
+            created_at: summary.binder.created_at.to_rfc3339(),
+        }
+    }
+}
+
+#[derive(Deserialize, ToSchema)]
+pub(crate) struct CreateBinderRequest {
+    pub(crate) name: String,
+    /// False when omitted
+    #[serde(default)]
+    pub(crate) tradable: bool,
+}
+
+#[derive(Deserialize, ToSchema)]
+pub(crate) struct UpdateBinderRequest {
+    pub(crate) name: Option<String>,
+    pub(crate) tradable: Option<bool>,
+    /// Copies of each entry to keep, e.g. 4 to offer only what exceeds a playset. 0 offers every
+    /// copy
+    pub(crate) keep_quantity: Option<u8>,
+}
@@ -0,0 +1,179 @@
+use super::controller::*;
+use super::dto::{CreateBinderRequest, UpdateBinderRequest};
+use crate::application::error::AppError;
+use crate::application::use_case::{MockManageBindersUseCase, MockStatsUseCase};
+use crate::domain::binder::{Binder, BinderId, BinderSummary};
+use crate::domain::error::FunctionalError;
+use crate::domain::user::{User, UserId};
+use crate::infrastructure::AppState;
+use crate::infrastructure::adapter_in::auth_extractor::AuthenticatedUser;
+use axum::extract::{Path, State};
+use axum::http::StatusCode;
+use chrono::{TimeZone, Utc};
+use std::sync::Arc;
+use uuid::Uuid;
+
+fn make_app_state(manage_binders_use_case: MockManageBindersUseCase) -> AppState {
+    AppState::for_testing_with_manage_binders(
+        Arc::new(MockStatsUseCase::new()),
+        Arc::new(manage_binders_use_case),
+    )
+}
+
+fn binder(name: &str, tradable: bool) -> Binder {
+    Binder {
+        id: BinderId(Uuid::nil()),
+        user_id: UserId::new("test-user-id"),
+        name: name.to_string(),
+        tradable,
+        keep_quantity: 0,
+        is_default: false,
+        created_at: Utc.with_ymd_and_hms(2025, 3, 1, 10, 0, 0).unwrap(),
+    }
+}
+
+#[tokio::test]
+async fn list_binders_returns_the_binders_with_their_card_count() {
+    let mut mock_use_case = MockManageBindersUseCase::new();
+    mock_use_case
+        .expect_list_binders()
+        .withf(|user_id| user_id.as_str() == "test-user-id")
+        .returning(|_| {
+            Box::pin(async {
+                Ok(vec![BinderSummary {
+                    binder: binder("Trades", true),
+                    card_count: 12,
+                }])
+            })
+        });
+
+    let result = list_binders(
+        AuthenticatedUser(User::for_testing()),
+        State(make_app_state(mock_use_case)),
+    )
+    .await
+    .unwrap();
+
+    assert_eq!(result.0.len(), 1);
+    assert_eq!(result.0[0].id, Uuid::nil().to_string());
+    assert_eq!(result.0[0].name, "Trades");
+    assert!(result.0[0].tradable);
+    assert_eq!(result.0[0].card_count, 12);
+    assert_eq!(result.0[0].created_at, "2025-03-01T10:00:00+00:00");
+}
+
+#[tokio::test]
+async fn create_binder_returns_created_with_the_binder() {
+    let mut mock_use_case = MockManageBindersUseCase::new();
+    mock_use_case
+        .expect_create_binder()
+        .withf(|_, name, tradable| name == "Trades" && *tradable)
+        .times(1)
+        .returning(|_, _, _| Box::pin(async { Ok(binder("Trades", true)) }));
+
+    let (status, body) = create_binder(
+        AuthenticatedUser(User::for_testing()),
+        State(make_app_state(mock_use_case)),
+        axum::Json(CreateBinderRequest {
+            name: "Trades".to_string(),
+            tradable: true,
+        }),
+    )
+    .await
+    .unwrap();
+
+    assert_eq!(status, StatusCode::CREATED);
+    assert_eq!(body.0.name, "Trades");
+    assert_eq!(body.0.card_count, 0);
+}
+
+#[tokio::test]
+async fn create_binder_propagates_name_already_used() {
+    let mut mock_use_case = MockManageBindersUseCase::new();
+    mock_use_case.expect_create_binder().returning(|_, _, _| {
+        Box::pin(async { Err(AppError::Functional(FunctionalError::BinderNameAlreadyUsed)) })
+    });
+
+    let result = create_binder(
+        AuthenticatedUser(User::for_testing()),
+        State(make_app_state(mock_use_case)),
+        axum::Json(CreateBinderRequest {
+            name: "Trades".to_string(),
+            tradable: false,
+        }),
+    )
+    .await;
+
+    assert!(matches!(
+        result,
+        Err(AppError::Functional(FunctionalError::BinderNameAlreadyUsed))
+    ));
+}
+
+#[tokio::test]
+async fn update_binder_passes_the_changes_to_use_case() {
+    let id = Uuid::new_v4();
+    let mut mock_use_case = MockManageBindersUseCase::new();
+    mock_use_case
+        .expect_update_binder()
+        .withf(move |_, binder_id, update| {
+            *binder_id == BinderId(id) && update.name.is_none() && update.tradable == Some(false)
+        })
+        .times(1)
+        .returning(|_, _, _| Box::pin(async { Ok(binder("Deck box", false)) }));
+
+    let result = update_binder(
+        AuthenticatedUser(User::for_testing()),
+        State(make_app_state(mock_use_case)),
+        Path(id),
+        axum::Json(UpdateBinderRequest {
+            name: None,
+            tradable: Some(false),
+            keep_quantity: None,
+        }),
+    )
+    .await
+    .unwrap();
+
+    assert!(!result.0.tradable);
+}
+
+#[tokio::test]
+async fn delete_binder_returns_no_content() {
+    let id = Uuid::new_v4();
+    let mut mock_use_case = MockManageBindersUseCase::new();
+    mock_use_case
+        .expect_delete_binder()
+        .withf(move |_, binder_id| *binder_id == BinderId(id))
+        .times(1)
+        .returning(|_, _| Box::pin(async { Ok(()) }));
+
+    let result = delete_binder(
+        AuthenticatedUser(User::for_testing()),
+        State(make_app_state(mock_use_case)),
+        Path(id),
+    )
+    .await;
+
+    assert_eq!(result.unwrap(), StatusCode::NO_CONTENT);
+}
+
+#[tokio::test]
+async fn delete_binder_propagates_not_found() {
+    let mut mock_use_case = MockManageBindersUseCase::new();
+    mock_use_case.expect_delete_binder().returning(|_, _| {
+        Box::pin(async { Err(AppError::Functional(FunctionalError::BinderNotFound)) })
+    });
+
+    let result = delete_binder(
+        AuthenticatedUser(User::for_testing()),
+        State(make_app_state(mock_use_case)),
+        Path(Uuid::new_v4()),
+    )
+    .await;
+
+    assert!(matches!(
+        result,
+        Err(AppError::Functional(FunctionalError::BinderNotFound))
+    ));
+}
@@ -1,47 +1,47 @@
 use super::dto::{
-    CardOfferResponse, CardOffersParams, PaginatedCardOffersResponse, PriceHistoryEntryResponse,
-    PriceHistoryParams,
+    CardEdhRecResponse, CardOfferResponse, CardOffersParams, PaginatedCardOffersResponse,
+    PriceHistoryEntryResponse, PriceHistoryParams,
 };
 use crate::application::error::AppError;
-use crate::domain::card::CardId;
-use crate::domain::language_code::LanguageCode;
 use crate::infrastructure::AppState;
 use crate::infrastructure::adapter_in::auth_extractor::AuthenticatedUser;
 use axum::extract::{Path, Query, State};
-use axum::http::StatusCode;
-use axum::routing::{get, post};
+use axum::routing::get;
 use uuid::Uuid;
 
 pub fn create_card_router() -> axum::Router<AppState> {
     axum::Router::new()
-        .route("/card-info", post(get_card_info))
+        .route("/{scryfall_id}/edhrec", get(get_card_edhrec))
         .route("/{scryfall_id}/price-history", get(get_card_price_history))
         .route("/offers", get(get_card_offers))
 }
 
 #[utoipa::path(
-    post,
-    path = "/card/card-info",
+    get,
+    path = "/card/{scryfall_id}/edhrec",
+    params(
+        ("scryfall_id" = Uuid, Path, description = "Card's Scryfall identifier"),
+    ),
     responses(
-        (status = 200, description = "Card info from EDHRec"),
+        (status = 200, description = "EDHREC statistics of the card", body = CardEdhRecResponse),
         (status = 401, description = "Missing or invalid token"),
+        (status = 404, description = "No card found for this scryfall_id"),
+        (status = 502, description = "EDHREC could not be reached"),
     ),
     security(("bearer_auth" = [])),
     tag = "card",
 )]
-pub(crate) async fn get_card_info(
-    AuthenticatedUser(user): AuthenticatedUser,
+pub(crate) async fn get_card_edhrec(
+    AuthenticatedUser(_user): AuthenticatedUser,
     State(state): State<AppState>,
-) -> Result<String, (StatusCode, String)> {
-    tracing::info!("Getting card info for user: {}", user.id);
-
-    state
-        .edh_rec_caller_adapter
-        .get_card_info("Sol Ring".to_string())
-        .await
-        .expect("panic message");
+    Path(scryfall_id): Path<Uuid>,
+) -> Result<axum::Json<CardEdhRecResponse>, AppError> {
+    let info = state
+        .get_card_edhrec_use_case
+        .get_card_edhrec(scryfall_id)
+        .await?;
 
-    Ok("card Info".to_string())
+    Ok(axum::Json(CardEdhRecResponse::from(info)))
 }
 
 #[utoipa::path(
@@ -51,6 +51,8 @@ pub(crate) async fn get_card_info(
         ("scryfall_id" = Uuid, Path, description = "Card's Scryfall identifier"),
         ("start_date" = Option<String>, Query, description = "Start date (ISO 8601: YYYY-MM-DD, inclusive). Defaults to end_date minus 30 days"),
         ("end_date" = Option<String>, Query, description = "End date (ISO 8601: YYYY-MM-DD, inclusive). Defaults to today"),
+        ("granularity" = Option<super::dto::PriceHistoryGranularityParam>, Query, description = "Period aggregated in each entry (day, week or month). Defaults to day"),
+        ("moving_averages" = Option<bool>, Query, description = "Adds the 7 and 30-day moving averages of the trend. Defaults to false"),
     ),
     responses(
         (status = 200, description = "Card price history", body = Vec<PriceHistoryEntryResponse>),
@@ -69,18 +71,18 @@ pub(crate) async fn get_card_price_history(
 ) -> Result<axum::Json<Vec<PriceHistoryEntryResponse>>, AppError> {
     let entries = state
         .get_card_price_history_use_case
-        .get_card_price_history(scryfall_id, params.start_date, params.end_date)
+        .get_card_price_history(
+            scryfall_id,
+            params.start_date,
+            params.end_date,
+            params.options(),
+        )
         .await?;
 
     Ok(axum::Json(
         entries
             .into_iter()
-            .map(|e| PriceHistoryEntryResponse {
-                date: e.date.to_string(),
-                low: e.price_guide.low.value.unwrap_or(0) as i64,
-                trend: e.price_guide.trend.value.unwrap_or(0) as i64,
-                avg: e.price_guide.avg.value.unwrap_or(0) as i64,
-            })
+            .map(PriceHistoryEntryResponse::from)
             .collect(),
     ))
 }
@@ -89,10 +91,11 @@ pub(crate) async fn get_card_price_history(
     get,
     path = "/card/offers",
     params(
-        ("set_code" = String, Query, description = "Card's set code"),
-        ("collector_number" = String, Query, description = "Card's collector number"),
-        ("language_code" = String, Query, description = "Card's language code"),
-        ("foil" = bool, Query, description = "Whether the card is foil"),
+        ("set_code" = Option<String>, Query, description = "Card's set code, required without oracle_id"),
+        ("collector_number" = Option<String>, Query, description = "Card's collector number, required without oracle_id"),
+        ("language_code" = Option<String>, Query, description = "Card's language code, required without oracle_id"),
+        ("foil" = Option<bool>, Query, description = "Whether the card is foil, required without oracle_id"),
+        ("oracle_id" = Option<Uuid>, Query, description = "Offers of any printing of this oracle card, instead of the given printing"),
         ("sort_by" = Option<super::dto::CardOffersSortByParam>, Query, description = "Sort field (only selling_price supported for now)"),
         ("page" = Option<u32>, Query, description = "Page number (starts at 0, max 10)"),
         ("page_size" = Option<u32>, Query, description = "Items per page (1 to 100)"),
@@ -101,7 +104,7 @@ pub(crate) async fn get_card_price_history(
         (status = 200, description = "Paginated list of sale offers for this card", body = PaginatedCardOffersResponse),
         (status = 400, description = "Invalid or missing query params"),
         (status = 401, description = "Missing or invalid token"),
-        (status = 404, description = "No card found for this CardId"),
+        (status = 404, description = "No card found for this CardId or oracle_id"),
     ),
     security(("bearer_auth" = [])),
     tag = "card",
@@ -111,19 +114,13 @@ pub(crate) async fn get_card_offers(
     State(state): State<AppState>,
     Query(params): Query<CardOffersParams>,
 ) -> Result<axum::Json<PaginatedCardOffersResponse>, AppError> {
-    let language_code = LanguageCode::try_new(&params.language_code)?;
-    let card_id = CardId::try_new(
-        params.set_code.as_str(),
-        params.collector_number,
-        language_code,
-        params.foil,
-    )?;
+    let selector = params.selector()?;
     let page_size = params.page_size.clamp(1, state.max_page_size);
     let page = params.page.min(state.max_page_number);
 
     let result = state
         .get_card_offers_use_case
-        .get_card_offers(&user.id, card_id, params.sort_by.into(), page, page_size)
+        .get_card_offers(&user.id, selector, params.sort_by.into(), page, page_size)
         .await?;
 
     Ok(axum::Json(PaginatedCardOffersResponse {
@@ -1,9 +1,13 @@
-use crate::domain::card::CollectionEntry;
+use crate::domain::card::{CardId, CardSelector, CollectionEntry, EdhRecCardInfo};
 use crate::domain::card_offer::CardOfferSortField;
+use crate::domain::error::FunctionalError;
+use crate::domain::language_code::LanguageCode;
+use crate::domain::price::{PriceHistoryEntry, PriceHistoryGranularity, PriceHistoryOptions};
 use chrono::NaiveDate;
 use serde::{Deserialize, Serialize};
 use ts_rs::TS;
 use utoipa::ToSchema;
+use uuid::Uuid;
 
 // --- Price history ---
 /// Shared by `/collection/price-history` and `/card/{scryfall_id}/price-history`; both dates are
@@ -17,17 +21,106 @@ pub(crate) struct PriceHistoryParams {
     /// ISO 8601 date string (YYYY-MM-DD)
     #[ts(optional, type = "string")]
     pub(crate) end_date: Option<NaiveDate>,
+    /// Defaults to `day`
+    #[ts(optional)]
+    pub(crate) granularity: Option<PriceHistoryGranularityParam>,
+    /// Adds the 7 and 30-day moving averages of the trend
+    #[ts(optional)]
+    pub(crate) moving_averages: Option<bool>,
 }
 
+impl PriceHistoryParams {
+    pub(crate) fn options(&self) -> PriceHistoryOptions {
+        PriceHistoryOptions {
+            granularity: self.granularity.map(Into::into).unwrap_or_default(),
+            moving_averages: self.moving_averages.unwrap_or(false),
+        }
+    }
+}
+
+#[derive(Deserialize, Clone, Copy, Debug, PartialEq, TS, ToSchema)]
+#[serde(rename = "PriceHistoryGranularity", rename_all = "snake_case")]
+#[ts(export, export_to = "PriceHistoryGranularity.ts")]
+pub(crate) enum PriceHistoryGranularityParam {
+    Day,
+    Week,
+    Month,
+}
+
+impl From<PriceHistoryGranularityParam> for PriceHistoryGranularity {
+    fn from(p: PriceHistoryGranularityParam) -> Self {
+        match p {
+            PriceHistoryGranularityParam::Day => PriceHistoryGranularity::Day,
+            PriceHistoryGranularityParam::Week => PriceHistoryGranularity::Week,
+            PriceHistoryGranularityParam::Month => PriceHistoryGranularity::Month,
+        }
+    }
+}
+
+/// One day, week or month of history. `low`, `trend` and `avg` are the last known prices of the
+/// period, `trend` being its close.
 #[derive(Serialize, Debug, TS, ToSchema)]
 #[serde(rename = "PriceHistoryEntry")]
 #[ts(export, export_to = "PriceHistoryEntry.ts")]
 pub struct PriceHistoryEntryResponse {
-    /// ISO 8601 date string (YYYY-MM-DD)
+    /// ISO 8601 date string (YYYY-MM-DD), first day of the period
     pub date: String,
     pub low: i64,
     pub trend: i64,
     pub avg: i64,
+    pub trend_open: i64,
+    pub trend_high: i64,
+    pub trend_low: i64,
+    /// Only set when moving averages are requested
+    pub trend_ma7: Option<i64>,
+    /// Only set when moving averages are requested
+    pub trend_ma30: Option<i64>,
+}
+
+impl From<PriceHistoryEntry> for PriceHistoryEntryResponse {
+    fn from(e: PriceHistoryEntry) -> Self {
+        Self {
+            date: e.date.to_string(),
+            low: e.price_guide.low.value.unwrap_or(0) as i64,
+            trend: e.price_guide.trend.value.unwrap_or(0) as i64,
+            avg: e.price_guide.avg.value.unwrap_or(0) as i64,
+            trend_open: e.trend_open.value.unwrap_or(0) as i64,
+            trend_high: e.trend_high.value.unwrap_or(0) as i64,
+            trend_low: e.trend_low.value.unwrap_or(0) as i64,
+            trend_ma7: e.trend_ma7.value.map(i64::from),
+            trend_ma30: e.trend_ma30.value.map(i64::from),
+        }
+    }
+}
+
+// --- EDHREC ---
+
+/// EDHREC statistics of the card, shared by all its printings.
+#[derive(Serialize, Debug, TS, ToSchema)]
+#[serde(rename = "CardEdhRec")]
+#[ts(export, export_to = "CardEdhRec.ts")]
+pub struct CardEdhRecResponse {
+    pub name: String,
+    /// Decks playing the card
+    pub inclusion: u32,
+    /// Decks whose colour identity allows the card
+    pub potential_decks: u32,
+    /// `null` for cards EDHREC does not rank
+    pub rank: Option<u32>,
+    /// RFC 3339 timestamp of the last EDHREC fetch
+    pub fetched_at: String,
+}
+
+impl From<EdhRecCardInfo> for CardEdhRecResponse {
+    fn from(info: EdhRecCardInfo) -> Self {
+        Self {
+            name: info.name,
+            inclusion: info.info.inclusion,
+            potential_decks: info.info.total_decks,
+            rank: info.info.rank,
+            fetched_at: info.fetched_at.to_rfc3339(),
+        }
+    }
 }
 
 // --- Offers ---
@@ -52,13 +145,22 @@ impl From<CardOffersSortByParam> for CardOfferSortField {
     }
 }
 
+/// Either `oracle_id`, for the offers of any printing of the card, or the four fields of the
+/// printing (`set_code`, `collector_number`, `language_code` and `foil`).
 #[derive(Deserialize, TS)]
 #[ts(export, export_to = "CardOffersParams.ts")]
 pub(crate) struct CardOffersParams {
-    pub(crate) set_code: String,
-    pub(crate) collector_number: String,
-    pub(crate) language_code: String,
-    pub(crate) foil: bool,
+    #[ts(optional)]
+    pub(crate) set_code: Option<String>,
+    #[ts(optional)]
+    pub(crate) collector_number: Option<String>,
+    #[ts(optional)]
+    pub(crate) language_code: Option<String>,
+    #[ts(optional)]
+    pub(crate) foil: Option<bool>,
+    /// Takes precedence over the printing fields
+    #[ts(optional, type = "string")]
+    pub(crate) oracle_id: Option<Uuid>,
     #[serde(default)]
     pub(crate) sort_by: CardOffersSortByParam,
     #[serde(default)]
@@ -67,10 +169,56 @@ pub(crate) struct CardOffersParams {
     pub(crate) page_size: u32,
 }
 
+impl CardOffersParams {
+    pub(crate) fn selector(&self) -> Result<CardSelector, FunctionalError> {
+        card_selector(
+            self.oracle_id,
+            self.set_code.as_deref(),
+            self.collector_number.as_deref(),
+            self.language_code.as_deref(),
+            self.foil,
+        )
+    }
+}
+
+/// A card given either by its oracle id, taking precedence, or by every field of one printing.
+pub(crate) fn card_selector(
+    oracle_id: Option<Uuid>,
+    set_code: Option<&str>,
+    collector_number: Option<&str>,
+    language_code: Option<&str>,
+    foil: Option<bool>,
+) -> Result<CardSelector, FunctionalError> {
+    if let Some(oracle_id) = oracle_id {
+        return Ok(CardSelector::AnyPrinting { oracle_id });
+    }
+
+    match (set_code, collector_number, language_code, foil) {
+        (Some(set_code), Some(collector_number), Some(language_code), Some(foil)) => {
+            Ok(CardSelector::Printing(CardId::try_new(
+                set_code,
+                collector_number.to_string(),
+                LanguageCode::try_new(language_code)?,
+                foil,
+            )?))
+        }
+        _ => Err(FunctionalError::WrongFormat(
+            "Either oracle_id or set_code, collector_number, language_code and foil are required"
+                .to_string(),
+        )),
+    }
+}
+
+/// An offer of another user. The printing fields tell which printing is offered when the offers
+/// of any printing of the card are requested.
 #[derive(Serialize, Debug, TS, ToSchema)]
 #[serde(rename = "CardOffer")]
 #[ts(export, export_to = "CardOffer.ts")]
 pub struct CardOfferResponse {
+    pub set_code: String,
+    pub collector_number: String,
+    pub language_code: String,
+    pub foil: bool,
     pub owner_username: String,
     pub quantity: u8,
     pub selling_price: Option<u32>,
@@ -80,10 +228,15 @@ impl From<CollectionEntry> for CardOfferResponse {
     fn from(entry: CollectionEntry) -> Self {
         match entry {
             CollectionEntry::Owned {
+                card_id,
                 owner_username,
                 quantity,
                 selling_price,
             } => Self {
+                set_code: card_id.set_code.to_string(),
+                collector_number: card_id.collector_number,
+                language_code: card_id.language_code.to_string(),
+                foil: card_id.foil,
                 owner_username,
                 quantity,
                 selling_price,
@@ -1,9 +1,10 @@
 use super::controller::*;
 use super::dto::*;
 use crate::application::error::AppError;
-use crate::domain::card::CollectionEntry;
+use crate::domain::card::{CardId, CardSelector, CollectionEntry};
 use crate::domain::card_offer::CardOfferSortField;
 use crate::domain::error::FunctionalError;
+use crate::domain::language_code::LanguageCode;
 use crate::domain::user::User;
 use crate::infrastructure::AppState;
 use crate::infrastructure::adapter_in::auth_extractor::AuthenticatedUser;
@@ -23,6 +24,15 @@ fn make_app_state_with_card_price_history(
     }
 }
 
+fn make_app_state_with_card_edhrec(
+    mock: crate::application::use_case::MockGetCardEdhRecUseCase,
+) -> AppState {
+    AppState::for_testing_with_card_edhrec(
+        Arc::new(crate::application::use_case::MockStatsUseCase::new()),
+        Arc::new(mock),
+    )
+}
+
 fn make_app_state_with_card_offers(
     mock: crate::application::use_case::MockGetCardOffersUseCase,
 ) -> AppState {
@@ -34,10 +44,11 @@ fn make_app_state_with_card_offers(
 
 fn valid_offers_params() -> CardOffersParams {
     CardOffersParams {
-        set_code: "FDN".to_string(),
-        collector_number: "87".to_string(),
-        language_code: "FR".to_string(),
-        foil: false,
+        set_code: Some("FDN".to_string()),
+        collector_number: Some("87".to_string()),
+        language_code: Some("FR".to_string()),
+        foil: Some(false),
+        oracle_id: None,
         sort_by: CardOffersSortByParam::default(),
         page: 0,
         page_size: 20,
@@ -55,23 +66,31 @@ async fn get_card_price_history_returns_entries() {
     let scryfall_id = Uuid::new_v4();
 
     let mut mock = MockGetCardPriceHistoryUseCase::new();
-    mock.expect_get_card_price_history().returning(|_, _, _| {
-        Box::pin(async {
-            Ok(vec![PriceHistoryEntry {
-                date: NaiveDate::from_ymd_opt(2025, 1, 15).unwrap(),
-                price_guide: PriceGuide {
-                    low: Price { value: Some(100) },
-                    trend: Price { value: Some(150) },
-                    avg: Price { value: Some(130) },
-                },
-            }])
-        })
-    });
+    mock.expect_get_card_price_history()
+        .returning(|_, _, _, _| {
+            Box::pin(async {
+                Ok(vec![PriceHistoryEntry {
+                    date: NaiveDate::from_ymd_opt(2025, 1, 15).unwrap(),
+                    price_guide: PriceGuide {
+                        low: Price { value: Some(100) },
+                        trend: Price { value: Some(150) },
+                        avg: Price { value: Some(130) },
+                    },
+                    trend_open: Price { value: Some(120) },
+                    trend_high: Price { value: Some(160) },
+                    trend_low: Price { value: Some(110) },
+                    trend_ma7: Price::empty(),
+                    trend_ma30: Price::empty(),
+                }])
+            })
+        });
 
     let app_state = make_app_state_with_card_price_history(mock);
     let params = PriceHistoryParams {
         start_date: Some(NaiveDate::from_ymd_opt(2025, 1, 1).unwrap()),
         end_date: Some(NaiveDate::from_ymd_opt(2025, 1, 31).unwrap()),
+        granularity: None,
+        moving_averages: None,
     };
 
     let result = get_card_price_history(
@@ -88,6 +107,11 @@ async fn get_card_price_history_returns_entries() {
     assert_eq!(entries[0].low, 100);
     assert_eq!(entries[0].trend, 150);
     assert_eq!(entries[0].avg, 130);
+    assert_eq!(entries[0].trend_open, 120);
+    assert_eq!(entries[0].trend_high, 160);
+    assert_eq!(entries[0].trend_low, 110);
+    assert_eq!(entries[0].trend_ma7, None);
+    assert_eq!(entries[0].trend_ma30, None);
 }
 
 #[tokio::test]
@@ -96,9 +120,10 @@ async fn get_card_price_history_returns_404_when_card_not_found() {
     use uuid::Uuid;
 
     let mut mock = MockGetCardPriceHistoryUseCase::new();
-    mock.expect_get_card_price_history().returning(|_, _, _| {
-        Box::pin(async { Err(AppError::Functional(FunctionalError::CardNotFound)) })
-    });
+    mock.expect_get_card_price_history()
+        .returning(|_, _, _, _| {
+            Box::pin(async { Err(AppError::Functional(FunctionalError::CardNotFound)) })
+        });
 
     let app_state = make_app_state_with_card_price_history(mock);
 
@@ -109,6 +134,8 @@ async fn get_card_price_history_returns_404_when_card_not_found() {
         Query(PriceHistoryParams {
             start_date: None,
             end_date: None,
+            granularity: None,
+            moving_averages: None,
         }),
     )
     .await;
@@ -126,18 +153,21 @@ async fn get_card_price_history_propagates_wrong_format_error() {
     use uuid::Uuid;
 
     let mut mock = MockGetCardPriceHistoryUseCase::new();
-    mock.expect_get_card_price_history().returning(|_, _, _| {
-        Box::pin(async {
-            Err(AppError::Functional(FunctionalError::WrongFormat(
-                "start_date must be before or equal to end_date".to_string(),
-            )))
-        })
-    });
+    mock.expect_get_card_price_history()
+        .returning(|_, _, _, _| {
+            Box::pin(async {
+                Err(AppError::Functional(FunctionalError::WrongFormat(
+                    "start_date must be before or equal to end_date".to_string(),
+                )))
+            })
+        });
 
     let app_state = make_app_state_with_card_price_history(mock);
     let params = PriceHistoryParams {
         start_date: Some(NaiveDate::from_ymd_opt(2025, 2, 1).unwrap()),
         end_date: Some(NaiveDate::from_ymd_opt(2025, 1, 1).unwrap()),
+        granularity: None,
+        moving_averages: None,
     };
 
     let result = get_card_price_history(
@@ -164,7 +194,7 @@ async fn get_card_price_history_returns_empty_list() {
 
     let mut mock = MockGetCardPriceHistoryUseCase::new();
     mock.expect_get_card_price_history()
-        .returning(|_, _, _| Box::pin(async { Ok(vec![]) }));
+        .returning(|_, _, _, _| Box::pin(async { Ok(vec![]) }));
 
     let app_state = make_app_state_with_card_price_history(mock);
 
@@ -175,6 +205,8 @@ async fn get_card_price_history_returns_empty_list() {
         Query(PriceHistoryParams {
             start_date: None,
             end_date: None,
+            granularity: None,
+            moving_averages: None,
         }),
     )
     .await;
@@ -184,6 +216,105 @@ async fn get_card_price_history_returns_empty_list() {
     assert!(entries.is_empty());
 }
 
+// --- Tests for get_card_edhrec ---
+
+#[tokio::test]
+async fn get_card_edhrec_returns_card_stats() {
+    use crate::application::use_case::MockGetCardEdhRecUseCase;
+    use crate::domain::card::{CardInfo, EdhRecCardInfo};
+    use chrono::{TimeZone, Utc};
+    use uuid::Uuid;
+
+    let scryfall_id = Uuid::new_v4();
+
+    let mut mock = MockGetCardEdhRecUseCase::new();
+    mock.expect_get_card_edhrec()
+        .withf(move |id| *id == scryfall_id)
+        .returning(|_| {
+            Box::pin(async {
+                Ok(EdhRecCardInfo {
+                    name: "Sol Ring".to_string(),
+                    info: CardInfo {
+                        inclusion: 85_000,
+                        total_decks: 100_000,
+                        rank: Some(1),
+                    },
+                    fetched_at: Utc.with_ymd_and_hms(2025, 3, 1, 12, 0, 0).unwrap(),
+                })
+            })
+        });
+
+    let result = get_card_edhrec(
+        AuthenticatedUser(User::for_testing()),
+        State(make_app_state_with_card_edhrec(mock)),
+        axum::extract::Path(scryfall_id),
+    )
+    .await;
+
+    let axum::Json(response) = result.unwrap();
+    assert_eq!(
+        serde_json::to_value(&response).unwrap(),
+        json!({
+            "name": "Sol Ring",
+            "inclusion": 85000,
+            "potential_decks": 100000,
+            "rank": 1,
+            "fetched_at": "2025-03-01T12:00:00+00:00"
+        })
+    );
+}
+
+#[tokio::test]
+async fn get_card_edhrec_returns_404_when_card_not_found() {
+    use crate::application::use_case::MockGetCardEdhRecUseCase;
+    use uuid::Uuid;
+
+    let mut mock = MockGetCardEdhRecUseCase::new();
+    mock.expect_get_card_edhrec().returning(|_| {
+        Box::pin(async { Err(AppError::Functional(FunctionalError::CardNotFound)) })
+    });
+
+    let result = get_card_edhrec(
+        AuthenticatedUser(User::for_testing()),
+        State(make_app_state_with_card_edhrec(mock)),
+        axum::extract::Path(Uuid::new_v4()),
+    )
+    .await;
+
+    match result.unwrap_err() {
+        AppError::Functional(FunctionalError::CardNotFound) => {}
+        other => panic!("Expected CardNotFound, got {:?}", other),
+    }
+}
+
+#[tokio::test]
+async fn get_card_edhrec_propagates_call_error() {
+    use crate::application::error::InfraError;
+    use crate::application::use_case::MockGetCardEdhRecUseCase;
+    use uuid::Uuid;
+
+    let mut mock = MockGetCardEdhRecUseCase::new();
+    mock.expect_get_card_edhrec().returning(|_| {
+        Box::pin(async {
+            Err(AppError::Infra(InfraError::CallError(
+                "edhrec unavailable".to_string(),
+            )))
+        })
+    });
+
+    let result = get_card_edhrec(
+        AuthenticatedUser(User::for_testing()),
+        State(make_app_state_with_card_edhrec(mock)),
+        axum::extract::Path(Uuid::new_v4()),
+    )
+    .await;
+
+    match result.unwrap_err() {
+        AppError::Infra(InfraError::CallError(_)) => {}
+        other => panic!("Expected CallError, got {:?}", other),
+    }
+}
+
 // --- Tests for get_card_offers ---
 
 #[tokio::test]
@@ -198,6 +329,7 @@ async fn get_card_offers_returns_paginated_offers() {
             Box::pin(async move {
                 Ok(PaginatedCardOffers {
                     items: vec![CollectionEntry::Owned {
+                        card_id: CardId::new("FDN", "87", LanguageCode::FR, false),
                         owner_username: "Bob".to_string(),
                         quantity: 3,
                         selling_price: Some(1500),
@@ -222,6 +354,10 @@ async fn get_card_offers_returns_paginated_offers() {
     let axum::Json(offers) = result.unwrap();
     assert_eq!(offers.total, 1);
     assert_eq!(offers.items.len(), 1);
+    assert_eq!(offers.items[0].set_code, "FDN");
+    assert_eq!(offers.items[0].collector_number, "87");
+    assert_eq!(offers.items[0].language_code, "FR");
+    assert!(!offers.items[0].foil);
     assert_eq!(offers.items[0].owner_username, "Bob");
     assert_eq!(offers.items[0].quantity, 3);
     assert_eq!(offers.items[0].selling_price, Some(1500));
@@ -260,7 +396,7 @@ async fn get_card_offers_returns_400_for_invalid_language_code() {
     let app_state = make_app_state_with_card_offers(mock);
 
     let mut params = valid_offers_params();
-    params.language_code = "XX".to_string();
+    params.language_code = Some("XX".to_string());
 
     let result = get_card_offers(
         AuthenticatedUser(User::for_testing()),
@@ -286,7 +422,7 @@ async fn get_card_offers_returns_400_for_collector_number_too_long() {
     let app_state = make_app_state_with_card_offers(mock);
 
     let mut params = valid_offers_params();
-    params.collector_number = "12345678901".to_string();
+    params.collector_number = Some("12345678901".to_string());
 
     let result = get_card_offers(
         AuthenticatedUser(User::for_testing()),
@@ -302,6 +438,68 @@ async fn get_card_offers_returns_400_for_collector_number_too_long() {
     }
 }
 
+#[tokio::test]
+async fn get_card_offers_looks_up_any_printing_when_oracle_id_is_given() {
+    use crate::application::use_case::MockGetCardOffersUseCase;
+    use crate::domain::card_offer::PaginatedCardOffers;
+    use uuid::Uuid;
+
+    let oracle_id = Uuid::from_u128(42);
+    let mut mock = MockGetCardOffersUseCase::new();
+    mock.expect_get_card_offers()
+        .withf(move |_, selector, _, _, _| *selector == CardSelector::AnyPrinting { oracle_id })
+        .returning(|_, _, _, page, page_size| {
+            Box::pin(async move {
+                Ok(PaginatedCardOffers {
+                    items: vec![],
+                    total: 0,
+                    page,
+                    page_size,
+                })
+            })
+        });
+
+    let params = CardOffersParams {
+        set_code: None,
+        collector_number: None,
+        language_code: None,
+        foil: None,
+        oracle_id: Some(oracle_id),
+        ..valid_offers_params()
+    };
+
+    let result = get_card_offers(
+        AuthenticatedUser(User::for_testing()),
+        State(make_app_state_with_card_offers(mock)),
+        Query(params),
+    )
+    .await;
+
+    assert!(result.is_ok());
+}
+
+#[tokio::test]
+async fn get_card_offers_returns_400_without_oracle_id_nor_complete_printing() {
+    use crate::application::use_case::MockGetCardOffersUseCase;
+
+    let mut params = valid_offers_params();
+    params.foil = None;
+
+    let result = get_card_offers(
+        AuthenticatedUser(User::for_testing()),
+        State(make_app_state_with_card_offers(
+            MockGetCardOffersUseCase::new(),
+        )),
+        Query(params),
+    )
+    .await;
+
+    match result.unwrap_err() {
+        AppError::Functional(FunctionalError::WrongFormat(_)) => {}
+        other => panic!("Expected WrongFormat, got {:?}", other),
+    }
+}
+
 #[tokio::test]
 async fn get_card_offers_caps_page_size_at_max() {
     use crate::application::use_case::MockGetCardOffersUseCase;
@@ -425,10 +623,10 @@ fn card_offers_params_deserializes_with_all_fields() {
     });
 
     let params: CardOffersParams = serde_json::from_value(json).unwrap();
-    assert_eq!(params.set_code, "FDN");
-    assert_eq!(params.collector_number, "87");
-    assert_eq!(params.language_code, "FR");
-    assert!(params.foil);
+    assert_eq!(params.set_code.as_deref(), Some("FDN"));
+    assert_eq!(params.collector_number.as_deref(), Some("87"));
+    assert_eq!(params.language_code.as_deref(), Some("FR"));
+    assert_eq!(params.foil, Some(true));
     // sort_by deserialized from "selling_price" via serde(rename)
     let sorted: CardOfferSortField = params.sort_by.into();
     assert!(matches!(sorted, CardOfferSortField::SellingPrice));
@@ -494,12 +692,15 @@ fn card_offers_params_deserializes_with_default_page() {
 #[test]
 fn from_collection_entry_owned_converts_correctly() {
     let entry = CollectionEntry::Owned {
+        card_id: CardId::new("FDN", "87", LanguageCode::EN, true),
         owner_username: "alice".to_string(),
         quantity: 5,
         selling_price: Some(2500),
     };
 
     let response: CardOfferResponse = entry.into();
+    assert_eq!(response.set_code, "FDN");
+    assert!(response.foil);
     assert_eq!(response.owner_username, "alice");
     assert_eq!(response.quantity, 5);
     assert_eq!(response.selling_price, Some(2500));
@@ -508,6 +709,7 @@ fn from_collection_entry_owned_converts_correctly() {
 #[test]
 fn from_collection_entry_owned_with_none_selling_price() {
     let entry = CollectionEntry::Owned {
+        card_id: CardId::new("FDN", "87", LanguageCode::EN, false),
         owner_username: "bob".to_string(),
         quantity: 1,
         selling_price: None,
@@ -527,6 +729,11 @@ fn from_collection_entry_mine_panics() {
         purchase_price: 100,
         added_at: chrono::Utc::now(),
         reserved: false,
+        binder_id: None,
+        tradable_quantity: None,
+        tags: vec![],
+        notes: None,
+        location: None,
     };
     let _response: CardOfferResponse = entry.into();
 }
@@ -597,6 +804,10 @@ fn price_history_params_deserializes_with_end_date_only() {
 fn paginated_card_offers_response_serializes_correctly() {
     let response = PaginatedCardOffersResponse {
         items: vec![CardOfferResponse {
+            set_code: "FDN".to_string(),
+            collector_number: "123".to_string(),
+            language_code: "FR".to_string(),
+            foil: false,
             owner_username: "alice".to_string(),
             quantity: 3,
             selling_price: Some(1500),
@@ -622,6 +833,11 @@ fn price_history_entry_response_serializes_correctly() {
         low: 1000,
         trend: 1500,
         avg: 1300,
+        trend_open: 1400,
+        trend_high: 1600,
+        trend_low: 1200,
+        trend_ma7: None,
+        trend_ma30: None,
     };
 
     let json_str = serde_json::to_string(&entry).unwrap();
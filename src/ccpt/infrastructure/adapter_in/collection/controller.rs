@@ -1,15 +1,22 @@
 use super::dto::{
-    CollectionCardResponse, CollectionParams, CollectionStatsResponse, MessageResponse,
-    PaginatedCollectionResponse,
+    BinderParams, CollectionCardResponse, CollectionEntryKeyParams, CollectionItemResponse,
+    CollectionParams, CollectionStatsParams, CollectionStatsResponse, CommanderSynergyParams,
+    CommanderSynergyResponse, CreateCollectionEntryRequest, MessageResponse,
+    PaginatedCollectionResponse, UpdateCollectionEntryRequest,
 };
 use crate::application::error::AppError;
+use crate::domain::binder::BinderId;
+use crate::domain::card::CardId;
 use crate::domain::collection::CollectionQuery;
+use crate::domain::collection_item::{CollectionItem, CollectionItemUpdate};
 use crate::domain::error::FunctionalError;
+use crate::domain::language_code::LanguageCode;
 use crate::infrastructure::AppState;
 use crate::infrastructure::adapter_in::auth_extractor::AuthenticatedUser;
 use crate::infrastructure::adapter_in::card::dto::{PriceHistoryEntryResponse, PriceHistoryParams};
 use axum::body::to_bytes;
 use axum::extract::State;
+use axum::http::StatusCode;
 use axum::routing::{get, post};
 use axum_extra::extract::Query;
 
@@ -17,13 +24,23 @@ pub fn create_collection_router() -> axum::Router<AppState> {
     axum::Router::new()
         .route("/", get(get_collection))
         .route("/import", post(import_cards))
+        .route(
+            "/entries",
+            post(add_collection_entry)
+                .patch(update_collection_entry)
+                .delete(remove_collection_entry),
+        )
         .route("/stats", get(get_collection_stats))
         .route("/price-history", get(get_collection_price_history))
+        .route("/commander-synergy", get(get_commander_synergy))
 }
 
 #[utoipa::path(
     post,
     path = "/collection/import",
+    params(
+        ("binder_id" = Option<String>, Query, description = "Binder replaced by the import, the default binder when omitted"),
+    ),
     request_body(
         content = String,
         content_type = "text/plain",
@@ -33,6 +50,7 @@ pub fn create_collection_router() -> axum::Router<AppState> {
         (status = 200, description = "Import successful", body = MessageResponse),
         (status = 400, description = "Invalid body (non UTF-8, ...)"),
         (status = 401, description = "Missing or invalid token"),
+        (status = 404, description = "Unknown binder"),
     ),
     security(("bearer_auth" = [])),
     tag = "collection",
@@ -40,6 +58,7 @@ pub fn create_collection_router() -> axum::Router<AppState> {
 pub(crate) async fn import_cards(
     AuthenticatedUser(user): AuthenticatedUser,
     State(state): State<AppState>,
+    Query(params): Query<BinderParams>,
     body: axum::body::Body,
 ) -> Result<axum::Json<MessageResponse>, AppError> {
     let bytes = to_bytes(body, 10 * 1024 * 1024)
@@ -54,7 +73,7 @@ pub(crate) async fn import_cards(
     state
         .import_card_use_case
         .clone()
-        .import_cards(&csv, user)
+        .import_cards(&csv, user, params.binder_id.map(BinderId))
         .await?;
 
     Ok(axum::Json(MessageResponse {
@@ -75,6 +94,11 @@ pub(crate) async fn import_cards(
         ("sets" = Option<String>, Query, description = "Comma-separated set codes"),
         ("price_min" = Option<u32>, Query, description = "Minimum trend price in cents"),
         ("price_max" = Option<u32>, Query, description = "Maximum trend price in cents"),
+        ("color" = Option<Vec<super::dto::ColorParam>>, Query, description = "Colours the card must all have, repeated for multiple values (e.g. color=W&color=U)"),
+        ("type_line" = Option<String>, Query, description = "Part of the type line (e.g. Creature, Goblin)"),
+        ("binder_id" = Option<String>, Query, description = "Only the entries of this binder"),
+        ("tag" = Option<Vec<String>>, Query, description = "Tags the entries must all carry, repeated for multiple values (e.g. tag=cube&tag=foil)"),
+        ("location" = Option<String>, Query, description = "Part of the storage location of the entries"),
     ),
     responses(
         (status = 200, description = "Paginated card collection", body = PaginatedCollectionResponse),
@@ -112,6 +136,19 @@ pub(crate) async fn get_collection(
         sets,
         price_min: params.price_min,
         price_max: params.price_max,
+        colors: params.color.into_iter().map(Into::into).collect(),
+        type_line: params
+            .type_line
+            .map(|t| t.trim().to_string())
+            .filter(|t| !t.is_empty()),
+        oracle_id: None,
+        binder_id: params.binder_id.map(BinderId),
+        tags: normalize_tags(params.tag)?,
+        location: params
+            .location
+            .map(normalize_location)
+            .transpose()?
+            .flatten(),
     };
 
     let result = state
@@ -134,6 +171,10 @@ pub(crate) async fn get_collection(
 #[utoipa::path(
     get,
     path = "/collection/stats",
+    params(
+        ("binder_id" = Option<String>, Query, description = "Stats of this binder only, of every binder when omitted"),
+        ("tag" = Option<Vec<String>>, Query, description = "Stats of the entries carrying all these tags, repeated for multiple values"),
+    ),
     responses(
         (status = 200, description = "Collection stats for the authenticated user", body = CollectionStatsResponse),
         (status = 401, description = "Missing or invalid token"),
@@ -144,10 +185,15 @@ pub(crate) async fn get_collection(
 pub(crate) async fn get_collection_stats(
     AuthenticatedUser(user): AuthenticatedUser,
     State(state): State<AppState>,
+    Query(params): Query<CollectionStatsParams>,
 ) -> Result<axum::Json<CollectionStatsResponse>, AppError> {
     let stats = state
         .get_collection_stats_use_case
-        .get_collection_stats(&user.id)
+        .get_collection_stats(
+            &user.id,
+            params.binder_id.map(BinderId),
+            normalize_tags(params.tag)?,
+        )
         .await?;
     Ok(axum::Json(CollectionStatsResponse::from(stats)))
 }
@@ -158,6 +204,8 @@ pub(crate) async fn get_collection_stats(
     params(
         ("start_date" = Option<String>, Query, description = "Start date (ISO 8601: YYYY-MM-DD, inclusive). Defaults to end_date minus 30 days"),
         ("end_date" = Option<String>, Query, description = "End date (ISO 8601: YYYY-MM-DD, inclusive). Defaults to today"),
+        ("granularity" = Option<crate::infrastructure::adapter_in::card::dto::PriceHistoryGranularityParam>, Query, description = "Period aggregated in each entry (day, week or month). Defaults to day"),
+        ("moving_averages" = Option<bool>, Query, description = "Adds the 7 and 30-day moving averages of the trend. Defaults to false"),
     ),
     responses(
         (status = 200, description = "Collection price history", body = Vec<PriceHistoryEntryResponse>),
@@ -174,18 +222,274 @@ pub(crate) async fn get_collection_price_history(
 ) -> Result<axum::Json<Vec<PriceHistoryEntryResponse>>, AppError> {
     let entries = state
         .get_collection_price_history_use_case
-        .get_collection_price_history(&user.id, params.start_date, params.end_date)
+        .get_collection_price_history(
+            &user.id,
+            params.start_date,
+            params.end_date,
+            params.options(),
+        )
         .await?;
 
     Ok(axum::Json(
         entries
             .into_iter()
-            .map(|e| PriceHistoryEntryResponse {
-                date: e.date.to_string(),
-                low: e.price_guide.low.value.unwrap_or(0) as i64,
-                trend: e.price_guide.trend.value.unwrap_or(0) as i64,
-                avg: e.price_guide.avg.value.unwrap_or(0) as i64,
-            })
+            .map(PriceHistoryEntryResponse::from)
             .collect(),
     ))
 }
+
+#[utoipa::path(
+    get,
+    path = "/collection/commander-synergy",
+    params(
+        ("commander" = String, Query, description = "Commander name (e.g. Atraxa, Praetors' Voice)"),
+    ),
+    responses(
+        (status = 200, description = "EDHREC recommendations of the commander the user owns and misses", body = CommanderSynergyResponse),
+        (status = 400, description = "Missing or empty commander"),
+        (status = 401, description = "Missing or invalid token"),
+        (status = 404, description = "EDHREC has no page for this commander"),
+        (status = 502, description = "EDHREC could not be reached"),
+    ),
+    security(("bearer_auth" = [])),
+    tag = "collection",
+)]
+pub(crate) async fn get_commander_synergy(
+    AuthenticatedUser(user): AuthenticatedUser,
+    State(state): State<AppState>,
+    Query(params): Query<CommanderSynergyParams>,
+) -> Result<axum::Json<CommanderSynergyResponse>, AppError> {
+    let commander = params.commander.trim();
+    if commander.is_empty() {
+        return Err(FunctionalError::WrongFormat("commander must not be empty".to_string()).into());
+    }
+
+    let report = state
+        .get_commander_synergy_use_case
+        .get_commander_synergy(&user.id, commander)
+        .await?;
+
+    Ok(axum::Json(CommanderSynergyResponse::from(report)))
+}
+
+/// Longest notes accepted on a collection entry, in characters.
+const MAX_NOTES_LENGTH: usize = 1000;
+
+/// Longest tag accepted on a collection entry, in characters.
+const MAX_TAG_LENGTH: usize = 50;
+
+/// Most tags accepted on a collection entry.
+const MAX_TAGS: usize = 20;
+
+/// Longest storage location accepted on a collection entry, in characters.
+const MAX_LOCATION_LENGTH: usize = 100;
+
+fn entry_card_id(
+    set_code: &str,
+    collector_number: String,
+    language_code: &str,
+    foil: bool,
+) -> Result<CardId, AppError> {
+    let language_code = LanguageCode::try_new(language_code).map_err(AppError::from)?;
+    CardId::try_new(set_code, collector_number, language_code, foil).map_err(AppError::from)
+}
+
+fn check_quantity(quantity: u8) -> Result<(), AppError> {
+    if quantity == 0 {
+        return Err(AppError::Functional(FunctionalError::WrongFormat(
+            "quantity must be at least 1".to_string(),
+        )));
+    }
+    Ok(())
+}
+
+/// Trimmed notes, `None` when blank.
+fn normalize_notes(notes: String) -> Result<Option<String>, AppError> {
+    let notes = notes.trim();
+    if notes.chars().count() > MAX_NOTES_LENGTH {
+        return Err(AppError::Functional(FunctionalError::WrongFormat(format!(
+            "notes must be {MAX_NOTES_LENGTH} characters or less"
+        ))));
+    }
+    Ok(Some(notes.to_string()).filter(|n| !n.is_empty()))
+}
+
+/// Trimmed and lowercased tags, without blanks nor duplicates, sorted.
+fn normalize_tags(tags: Vec<String>) -> Result<Vec<String>, AppError> {
+    let mut tags: Vec<String> = tags
+        .iter()
+        .map(|tag| tag.trim().to_lowercase())
+        .filter(|tag| !tag.is_empty())
+        .collect();
+    if tags.iter().any(|tag| tag.chars().count() > MAX_TAG_LENGTH) {
+        return Err(AppError::Functional(FunctionalError::WrongFormat(format!(
+            "tags must be {MAX_TAG_LENGTH} characters or less"
+        ))));
+    }
+    tags.sort();
+    tags.dedup();
+    if tags.len() > MAX_TAGS {
+        return Err(AppError::Functional(FunctionalError::WrongFormat(format!(
+            "an entry accepts {MAX_TAGS} tags or less"
+        ))));
+    }
+    Ok(tags)
+}
+
+/// Trimmed storage location, `None` when blank.
+fn normalize_location(location: String) -> Result<Option<String>, AppError> {
+    let location = location.trim();
+    if location.chars().count() > MAX_LOCATION_LENGTH {
+        return Err(AppError::Functional(FunctionalError::WrongFormat(format!(
+            "location must be {MAX_LOCATION_LENGTH} characters or less"
+        ))));
+    }
+    Ok(Some(location.to_string()).filter(|l| !l.is_empty()))
+}
+
+#[utoipa::path(
+    post,
+    path = "/collection/entries",
+    request_body = CreateCollectionEntryRequest,
+    responses(
+        (status = 201, description = "Printing added to the collection", body = CollectionItemResponse),
+        (status = 400, description = "Invalid payload"),
+        (status = 401, description = "Missing or invalid token"),
+        (status = 404, description = "Unknown printing or binder"),
+        (status = 409, description = "The printing is already in the binder"),
+    ),
+    security(("bearer_auth" = [])),
+    tag = "collection",
+)]
+pub(crate) async fn add_collection_entry(
+    AuthenticatedUser(user): AuthenticatedUser,
+    State(state): State<AppState>,
+    axum::Json(payload): axum::Json<CreateCollectionEntryRequest>,
+) -> Result<(StatusCode, axum::Json<CollectionItemResponse>), AppError> {
+    let card_id = entry_card_id(
+        &payload.set_code,
+        payload.collector_number,
+        &payload.language_code,
+        payload.foil,
+    )?;
+    check_quantity(payload.quantity)?;
+    if payload
+        .tradable_quantity
+        .is_some_and(|tradable| tradable > payload.quantity)
+    {
+        return Err(AppError::Functional(FunctionalError::WrongFormat(
+            "tradable_quantity must not exceed quantity".to_string(),
+        )));
+    }
+
+    let item = CollectionItem {
+        card_id,
+        quantity: payload.quantity,
+        purchase_price: payload.purchase_price,
+        condition: payload.condition.into(),
+        notes: payload.notes.map(normalize_notes).transpose()?.flatten(),
+        tags: normalize_tags(payload.tags)?,
+        location: payload
+            .location
+            .map(normalize_location)
+            .transpose()?
+            .flatten(),
+        tradable_quantity: payload.tradable_quantity,
+        added_at: chrono::Utc::now(),
+    };
+
+    state
+        .manage_collection_entry_use_case
+        .add_entry(&user.id, payload.binder_id.map(BinderId), item.clone())
+        .await?;
+
+    Ok((
+        StatusCode::CREATED,
+        axum::Json(CollectionItemResponse::from(item)),
+    ))
+}
+
+#[utoipa::path(
+    patch,
+    path = "/collection/entries",
+    request_body = UpdateCollectionEntryRequest,
+    responses(
+        (status = 200, description = "Entry updated", body = CollectionItemResponse),
+        (status = 400, description = "Invalid payload"),
+        (status = 401, description = "Missing or invalid token"),
+        (status = 404, description = "Unknown binder, or the printing is not in the binder"),
+    ),
+    security(("bearer_auth" = [])),
+    tag = "collection",
+)]
+pub(crate) async fn update_collection_entry(
+    AuthenticatedUser(user): AuthenticatedUser,
+    State(state): State<AppState>,
+    axum::Json(payload): axum::Json<UpdateCollectionEntryRequest>,
+) -> Result<axum::Json<CollectionItemResponse>, AppError> {
+    let card_id = entry_card_id(
+        &payload.set_code,
+        payload.collector_number,
+        &payload.language_code,
+        payload.foil,
+    )?;
+    if let Some(quantity) = payload.quantity {
+        check_quantity(quantity)?;
+    }
+
+    let update = CollectionItemUpdate {
+        quantity: payload.quantity,
+        purchase_price: payload.purchase_price,
+        condition: payload.condition.map(Into::into),
+        notes: payload.notes.map(normalize_notes).transpose()?,
+        tags: payload.tags.map(normalize_tags).transpose()?,
+        location: payload.location.map(normalize_location).transpose()?,
+        tradable_quantity: payload.tradable_quantity,
+    };
+
+    let item = state
+        .manage_collection_entry_use_case
+        .update_entry(&user.id, payload.binder_id.map(BinderId), card_id, update)
+        .await?;
+
+    Ok(axum::Json(CollectionItemResponse::from(item)))
+}
+
+#[utoipa::path(
+    delete,
+    path = "/collection/entries",
+    params(
+        ("set_code" = String, Query, description = "Card's set code"),
+        ("collector_number" = String, Query, description = "Card's collector number"),
+        ("language_code" = String, Query, description = "Card's language code"),
+        ("foil" = bool, Query, description = "Whether the card is foil"),
+        ("binder_id" = Option<String>, Query, description = "Binder of the entry, the default binder when omitted"),
+    ),
+    responses(
+        (status = 204, description = "Entry removed from the collection"),
+        (status = 400, description = "Invalid card id"),
+        (status = 401, description = "Missing or invalid token"),
+        (status = 404, description = "Unknown binder, or the printing is not in the binder"),
+    ),
+    security(("bearer_auth" = [])),
+    tag = "collection",
+)]
+pub(crate) async fn remove_collection_entry(
+    AuthenticatedUser(user): AuthenticatedUser,
+    State(state): State<AppState>,
+    Query(params): Query<CollectionEntryKeyParams>,
+) -> Result<StatusCode, AppError> {
+    let card_id = entry_card_id(
+        &params.set_code,
+        params.collector_number,
+        &params.language_code,
+        params.foil,
+    )?;
+
+    state
+        .manage_collection_entry_use_case
+        .remove_entry(&user.id, params.binder_id.map(BinderId), card_id)
+        .await?;
+
+    Ok(StatusCode::NO_CONTENT)
+}
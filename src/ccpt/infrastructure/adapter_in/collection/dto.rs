@@ -1,10 +1,17 @@
 use crate::domain::card::{Card, CollectionEntry};
+use crate::domain::card_metadata::Color;
 use crate::domain::collection::{CollectionSortField, SortDirection};
+use crate::domain::collection_item::{CardCondition, CollectionItem};
 use crate::domain::collection_stats::CollectionStats;
+use crate::domain::commander_synergy::{
+    CommanderRecommendation, CommanderSynergyReport, MissingRecommendation, OwnedPrinting,
+    OwnedRecommendation, RecommendationList,
+};
 use crate::domain::rarity_code::RarityCode;
 use serde::{Deserialize, Serialize};
 use ts_rs::TS;
 use utoipa::ToSchema;
+use uuid::Uuid;
 
 #[derive(Serialize, Debug, TS, ToSchema)]
 #[serde(rename = "Message")]
@@ -31,6 +38,8 @@ pub struct CollectionStatsResponse {
     pub price_trend_min: Option<u32>,
     pub price_trend_max: Option<u32>,
     pub sets: Vec<SetInfoResponse>,
+    /// Tags of the entries, to filter on
+    pub tags: Vec<String>,
 }
 
 impl From<CollectionStats> for CollectionStatsResponse {
@@ -48,6 +57,7 @@ impl From<CollectionStats> for CollectionStatsResponse {
                     name: sn.name,
                 })
                 .collect(),
+            tags: s.tags,
         }
     }
 }
@@ -116,6 +126,29 @@ impl From<RarityCodeParam> for RarityCode {
     }
 }
 
+#[derive(Deserialize, Debug, PartialEq, TS, ToSchema)]
+#[serde(rename = "Color")]
+#[ts(export, export_to = "Color.ts")]
+pub enum ColorParam {
+    W,
+    U,
+    B,
+    R,
+    G,
+}
+
+impl From<ColorParam> for Color {
+    fn from(p: ColorParam) -> Self {
+        match p {
+            ColorParam::W => Color::W,
+            ColorParam::U => Color::U,
+            ColorParam::B => Color::B,
+            ColorParam::R => Color::R,
+            ColorParam::G => Color::G,
+        }
+    }
+}
+
 pub(crate) fn default_page_size() -> u32 {
     20
 }
@@ -145,6 +178,21 @@ pub(crate) struct CollectionParams {
     /// Maximum trend price in cents
     #[ts(optional)]
     pub(crate) price_max: Option<u32>,
+    /// Colours the card must all have, repeated for multiple values (e.g. `?color=W&color=U`)
+    #[serde(default)]
+    pub(crate) color: Vec<ColorParam>,
+    /// Part of the type line (e.g. `Creature`, `Goblin`)
+    #[ts(optional)]
+    pub(crate) type_line: Option<String>,
+    /// Only the entries of this binder
+    #[ts(optional, type = "string")]
+    pub(crate) binder_id: Option<Uuid>,
+    /// Tags the entries must all carry, repeated for multiple values (e.g. `?tag=cube&tag=foil`)
+    #[serde(default)]
+    pub(crate) tag: Vec<String>,
+    /// Part of the storage location of the entries
+    #[ts(optional)]
+    pub(crate) location: Option<String>,
 }
 
 impl Default for CollectionParams {
@@ -159,10 +207,34 @@ impl Default for CollectionParams {
             sets: None,
             price_min: None,
             price_max: None,
+            color: Vec::new(),
+            type_line: None,
+            binder_id: None,
+            tag: Vec::new(),
+            location: None,
         }
     }
 }
 
+/// Binder targeted by an import, the default binder when omitted.
+#[derive(Deserialize, Default, TS)]
+#[ts(export, export_to = "BinderParams.ts")]
+pub(crate) struct BinderParams {
+    #[ts(optional, type = "string")]
+    pub(crate) binder_id: Option<Uuid>,
+}
+
+/// Entries described by the stats, every entry of every binder by default.
+#[derive(Deserialize, Default, TS)]
+#[ts(export, export_to = "CollectionStatsParams.ts")]
+pub(crate) struct CollectionStatsParams {
+    #[ts(optional, type = "string")]
+    pub(crate) binder_id: Option<Uuid>,
+    /// Tags the entries must all carry, repeated for multiple values
+    #[serde(default)]
+    pub(crate) tag: Vec<String>,
+}
+
 // --- Réponses ---
 #[derive(Serialize, TS, ToSchema)]
 #[serde(rename = "PriceGuide")]
@@ -181,6 +253,14 @@ pub struct CollectionEntryResponse {
     pub purchase_price: u32,
     /// RFC 3339 timestamp
     pub added_at: String,
+    /// Binder holding the copies
+    pub binder_id: Option<String>,
+    /// Copies other users can ask for, 0 outside of a tradable binder
+    pub tradable_quantity: Option<u8>,
+    pub tags: Vec<String>,
+    pub notes: Option<String>,
+    /// Where the copies are stored
+    pub location: Option<String>,
 }
 
 #[derive(Serialize, TS, ToSchema)]
@@ -195,6 +275,19 @@ pub struct CollectionCardResponse {
     pub rarity_code: String,
     pub scryfall_id: String,
     pub the_gatherer_id: Option<String>,
+    /// Gatherer id of the back face, only for transform and modal double-faced cards.
+    pub the_gatherer_back_id: Option<String>,
+    /// Shared by every printing of the card, absent until the card is enriched.
+    pub oracle_id: Option<String>,
+    pub mana_cost: Option<String>,
+    pub type_line: Option<String>,
+    /// Colour symbols in the WUBRG order, empty for a colourless or not yet enriched card.
+    pub colors: Vec<String>,
+    pub color_identity: Vec<String>,
+    pub oracle_text: Option<String>,
+    pub image_small_url: Option<String>,
+    pub image_normal_url: Option<String>,
+    pub image_large_url: Option<String>,
     /// Present only when the card is owned by the authenticated user.
     pub collection_entry: Option<CollectionEntryResponse>,
     /// Number of distinct users owning this card (search mode only).
@@ -223,11 +316,21 @@ impl From<Card> for CollectionCardResponse {
                 purchase_price,
                 added_at,
                 reserved,
+                binder_id,
+                tradable_quantity,
+                tags,
+                notes,
+                location,
             } => (
                 Some(CollectionEntryResponse {
                     quantity,
                     purchase_price,
                     added_at: added_at.to_rfc3339(),
+                    binder_id: binder_id.map(|id| id.to_string()),
+                    tradable_quantity,
+                    tags,
+                    notes,
+                    location,
                 }),
                 None,
                 reserved,
@@ -247,6 +350,21 @@ impl From<Card> for CollectionCardResponse {
             rarity_code: c.rarity_code.to_string(),
             scryfall_id: c.scryfall_id.to_string(),
             the_gatherer_id: c.the_gatherer_id,
+            the_gatherer_back_id: c.the_gatherer_back_id,
+            oracle_id: c.metadata.oracle_id.map(|id| id.to_string()),
+            mana_cost: c.metadata.mana_cost,
+            type_line: c.metadata.type_line,
+            colors: c.metadata.colors.iter().map(Color::to_string).collect(),
+            color_identity: c
+                .metadata
+                .color_identity
+                .iter()
+                .map(Color::to_string)
+                .collect(),
+            oracle_text: c.metadata.oracle_text,
+            image_small_url: c.metadata.image_small_url,
+            image_normal_url: c.metadata.image_normal_url,
+            image_large_url: c.metadata.image_large_url,
             collection_entry,
             owner_count,
             reserved,
@@ -258,3 +376,318 @@ impl From<Card> for CollectionCardResponse {
         }
     }
 }
+
+// --- Commander synergy ---
+#[derive(Deserialize, TS)]
+#[ts(export, export_to = "CommanderSynergyParams.ts")]
+pub(crate) struct CommanderSynergyParams {
+    /// Commander name, as written on the card (e.g. `Atraxa, Praetors' Voice`)
+    pub(crate) commander: String,
+}
+
+#[derive(Serialize, Debug, PartialEq, Eq, TS, ToSchema)]
+#[serde(rename = "RecommendationList", rename_all = "snake_case")]
+#[ts(export, export_to = "RecommendationList.ts")]
+pub enum RecommendationListResponse {
+    HighSynergy,
+    Top,
+}
+
+impl From<RecommendationList> for RecommendationListResponse {
+    fn from(list: RecommendationList) -> Self {
+        match list {
+            RecommendationList::HighSynergy => RecommendationListResponse::HighSynergy,
+            RecommendationList::Top => RecommendationListResponse::Top,
+        }
+    }
+}
+
+#[derive(Serialize, Debug, TS, ToSchema)]
+#[serde(rename = "OwnedPrinting")]
+#[ts(export, export_to = "OwnedPrinting.ts")]
+pub struct OwnedPrintingResponse {
+    pub set_code: String,
+    pub collector_number: String,
+    pub language_code: String,
+    pub foil: bool,
+    pub scryfall_id: String,
+    pub quantity: u8,
+    /// Price in cents, null when the printing has no price
+    pub trend: Option<u32>,
+}
+
+impl From<OwnedPrinting> for OwnedPrintingResponse {
+    fn from(p: OwnedPrinting) -> Self {
+        Self {
+            set_code: p.card_id.set_code.to_string(),
+            collector_number: p.card_id.collector_number,
+            language_code: p.card_id.language_code.to_string(),
+            foil: p.card_id.foil,
+            scryfall_id: p.scryfall_id.to_string(),
+            quantity: p.quantity,
+            trend: p.trend.value,
+        }
+    }
+}
+
+#[derive(Serialize, Debug, TS, ToSchema)]
+#[serde(rename = "OwnedRecommendation")]
+#[ts(export, export_to = "OwnedRecommendation.ts")]
+pub struct OwnedRecommendationResponse {
+    pub name: String,
+    pub list: RecommendationListResponse,
+    /// EDHREC synergy score, between -1 and 1
+    pub synergy: f64,
+    pub inclusion: u32,
+    pub potential_decks: u32,
+    /// Value of the owned copies in cents
+    pub value: u32,
+    pub printings: Vec<OwnedPrintingResponse>,
+}
+
+impl From<OwnedRecommendation> for OwnedRecommendationResponse {
+    fn from(owned: OwnedRecommendation) -> Self {
+        let value = owned.value();
+        let CommanderRecommendation {
+            name,
+            list,
+            synergy,
+            inclusion,
+            potential_decks,
+        } = owned.recommendation;
+
+        Self {
+            name,
+            list: list.into(),
+            synergy,
+            inclusion,
+            potential_decks,
+            value,
+            printings: owned
+                .printings
+                .into_iter()
+                .map(OwnedPrintingResponse::from)
+                .collect(),
+        }
+    }
+}
+
+#[derive(Serialize, Debug, TS, ToSchema)]
+#[serde(rename = "MissingRecommendation")]
+#[ts(export, export_to = "MissingRecommendation.ts")]
+pub struct MissingRecommendationResponse {
+    pub name: String,
+    pub list: RecommendationListResponse,
+    /// EDHREC synergy score, between -1 and 1
+    pub synergy: f64,
+    pub inclusion: u32,
+    pub potential_decks: u32,
+    /// Other users owning a printing of the card
+    pub owner_count: u32,
+}
+
+impl From<MissingRecommendation> for MissingRecommendationResponse {
+    fn from(missing: MissingRecommendation) -> Self {
+        let CommanderRecommendation {
+            name,
+            list,
+            synergy,
+            inclusion,
+            potential_decks,
+        } = missing.recommendation;
+
+        Self {
+            name,
+            list: list.into(),
+            synergy,
+            inclusion,
+            potential_decks,
+            owner_count: missing.owner_count,
+        }
+    }
+}
+
+/// EDHREC high synergy and top cards of a commander, split between the ones the user owns and
+/// the ones they miss.
+#[derive(Serialize, Debug, TS, ToSchema)]
+#[serde(rename = "CommanderSynergy")]
+#[ts(export, export_to = "CommanderSynergy.ts")]
+pub struct CommanderSynergyResponse {
+    pub commander: String,
+    /// Value of every owned recommended copy in cents
+    pub owned_value: u32,
+    pub owned: Vec<OwnedRecommendationResponse>,
+    pub missing: Vec<MissingRecommendationResponse>,
+}
+
+impl From<CommanderSynergyReport> for CommanderSynergyResponse {
+    fn from(report: CommanderSynergyReport) -> Self {
+        Self {
+            owned_value: report.owned_value(),
+            commander: report.commander,
+            owned: report
+                .owned
+                .into_iter()
+                .map(OwnedRecommendationResponse::from)
+                .collect(),
+            missing: report
+                .missing
+                .into_iter()
+                .map(MissingRecommendationResponse::from)
+                .collect(),
+        }
+    }
+}
+
+// --- Collection entries ---
+#[derive(Deserialize, Serialize, Default, Debug, Clone, Copy, PartialEq, Eq, TS, ToSchema)]
+#[serde(rename = "CardCondition", rename_all = "snake_case")]
+#[ts(export, export_to = "CardCondition.ts")]
+pub enum CardConditionParam {
+    Mint,
+    #[default]
+    NearMint,
+    Excellent,
+    Good,
+    LightPlayed,
+    Played,
+    Poor,
+}
+
+impl From<CardConditionParam> for CardCondition {
+    fn from(p: CardConditionParam) -> Self {
+        match p {
+            CardConditionParam::Mint => CardCondition::Mint,
+            CardConditionParam::NearMint => CardCondition::NearMint,
+            CardConditionParam::Excellent => CardCondition::Excellent,
+            CardConditionParam::Good => CardCondition::Good,
+            CardConditionParam::LightPlayed => CardCondition::LightPlayed,
+            CardConditionParam::Played => CardCondition::Played,
+            CardConditionParam::Poor => CardCondition::Poor,
+        }
+    }
+}
+
+impl From<CardCondition> for CardConditionParam {
+    fn from(c: CardCondition) -> Self {
+        match c {
+            CardCondition::Mint => CardConditionParam::Mint,
+            CardCondition::NearMint => CardConditionParam::NearMint,
+            CardCondition::Excellent => CardConditionParam::Excellent,
+            CardCondition::Good => CardConditionParam::Good,
+            CardCondition::LightPlayed => CardConditionParam::LightPlayed,
+            CardCondition::Played => CardConditionParam::Played,
+            CardCondition::Poor => CardConditionParam::Poor,
+        }
+    }
+}
+
+#[derive(Deserialize, ToSchema)]
+pub(crate) struct CreateCollectionEntryRequest {
+    pub(crate) set_code: String,
+    pub(crate) collector_number: String,
+    pub(crate) language_code: String,
+    pub(crate) foil: bool,
+    pub(crate) quantity: u8,
+    /// Price in cents, 0 when omitted
+    #[serde(default)]
+    pub(crate) purchase_price: u32,
+    #[serde(default)]
+    pub(crate) condition: CardConditionParam,
+    pub(crate) notes: Option<String>,
+    /// Free-form labels, lowercased
+    #[serde(default)]
+    pub(crate) tags: Vec<String>,
+    /// Where the copies are stored (e.g. `Box 3, row 2`)
+    pub(crate) location: Option<String>,
+    /// Binder of the entry, the default binder when omitted
+    pub(crate) binder_id: Option<Uuid>,
+    /// Copies offered for trade, the keep rule of the binder when omitted
+    pub(crate) tradable_quantity: Option<u8>,
+}
+
+/// Tells an explicit `null` (`Some(None)`) from an omitted field (`None`, via `#[serde(default)]`).
+pub(crate) fn deserialize_some<'de, T, D>(deserializer: D) -> Result<Option<T>, D::Error>
+where
+    T: Deserialize<'de>,
+    D: serde::Deserializer<'de>,
+{
+    T::deserialize(deserializer).map(Some)
+}
+
+#[derive(Deserialize, ToSchema)]
+pub(crate) struct UpdateCollectionEntryRequest {
+    pub(crate) set_code: String,
+    pub(crate) collector_number: String,
+    pub(crate) language_code: String,
+    pub(crate) foil: bool,
+    pub(crate) quantity: Option<u8>,
+    /// Price in cents
+    pub(crate) purchase_price: Option<u32>,
+    pub(crate) condition: Option<CardConditionParam>,
+    /// An empty string clears the notes
+    pub(crate) notes: Option<String>,
+    /// Replaces every tag, an empty list removing them
+    pub(crate) tags: Option<Vec<String>>,
+    /// An empty string clears the location
+    pub(crate) location: Option<String>,
+    /// Binder of the entry, the default binder when omitted
+    pub(crate) binder_id: Option<Uuid>,
+    /// Copies offered for trade, `null` goes back to the keep rule of the binder
+    #[serde(default, deserialize_with = "deserialize_some")]
+    #[schema(value_type = Option<u8>)]
+    pub(crate) tradable_quantity: Option<Option<u8>>,
+}
+
+#[derive(Deserialize, TS)]
+#[ts(export, export_to = "CollectionEntryKeyParams.ts")]
+pub(crate) struct CollectionEntryKeyParams {
+    pub(crate) set_code: String,
+    pub(crate) collector_number: String,
+    pub(crate) language_code: String,
+    pub(crate) foil: bool,
+    /// Binder of the entry, the default binder when omitted
+    #[ts(optional, type = "string")]
+    pub(crate) binder_id: Option<Uuid>,
+}
+
+#[derive(Serialize, Debug, TS, ToSchema)]
+#[serde(rename = "CollectionItem")]
+#[ts(export, export_to = "CollectionItem.ts")]
+pub struct CollectionItemResponse {
+    pub set_code: String,
+    pub collector_number: String,
+    pub language_code: String,
+    pub foil: bool,
+    pub quantity: u8,
+    /// Price in cents
+    pub purchase_price: u32,
+    pub condition: CardConditionParam,
+    pub notes: Option<String>,
+    pub tags: Vec<String>,
+    /// Where the copies are stored
+    pub location: Option<String>,
+    /// Copies offered for trade, `null` when the keep rule of the binder applies
+    pub tradable_quantity: Option<u8>,
+    /// RFC 3339 timestamp
+    pub added_at: String,
+}
+
+impl From<CollectionItem> for CollectionItemResponse {
+    fn from(item: CollectionItem) -> Self {
+        Self {
+            set_code: item.card_id.set_code.to_string(),
+            collector_number: item.card_id.collector_number,
+            language_code: item.card_id.language_code.to_string(),
+            foil: item.card_id.foil,
+            quantity: item.quantity,
+            purchase_price: item.purchase_price,
+            condition: item.condition.into(),
+            notes: item.notes,
+            tags: item.tags,
+            location: item.location,
+            tradable_quantity: item.tradable_quantity,
+            added_at: item.added_at.to_rfc3339(),
+        }
+    }
+}
@@ -2,7 +2,9 @@ use super::controller::*;
 use super::dto::*;
 use crate::application::error::{AppError, InfraError};
 use crate::application::use_case::MockGetCollectionUseCase;
+use crate::domain::binder::BinderId;
 use crate::domain::card::{Card, CollectionEntry};
+use crate::domain::card_metadata::{CardMetadata, Color};
 use crate::domain::collection::{CollectionSortField, PaginatedCollection, SortDirection};
 use crate::domain::error::FunctionalError;
 use crate::domain::language_code::LanguageCode;
@@ -315,6 +317,44 @@ async fn get_collection_masks_collection_entry_for_search_style_entries() {
     assert_eq!(item.owner_count, Some(1));
 }
 
+#[tokio::test]
+async fn get_collection_maps_card_metadata() {
+    let mut card = make_card("FDN", "42");
+    card.metadata = CardMetadata {
+        mana_cost: Some("{1}{W}{U}".to_string()),
+        type_line: Some("Creature — Human Wizard".to_string()),
+        colors: vec![Color::W, Color::U],
+        color_identity: vec![Color::W, Color::U],
+        image_normal_url: Some("https://cards.scryfall.io/normal.jpg".to_string()),
+        ..CardMetadata::default()
+    };
+
+    let mut mock = MockGetCollectionUseCase::new();
+    mock.expect_get_collection().returning(move |_, _| {
+        Box::pin({
+            let c = card.clone();
+            async move { Ok(make_paginated(vec![c], 0, 20)) }
+        })
+    });
+
+    let result = get_collection(
+        AuthenticatedUser(User::for_testing()),
+        State(make_app_state_with_collection(mock)),
+        Query(CollectionParams::default()),
+    )
+    .await;
+
+    let axum::Json(response) = result.unwrap();
+    let item = &response.items[0];
+    assert_eq!(item.mana_cost.as_deref(), Some("{1}{W}{U}"));
+    assert_eq!(item.colors, vec!["W".to_string(), "U".to_string()]);
+    assert_eq!(
+        item.image_normal_url.as_deref(),
+        Some("https://cards.scryfall.io/normal.jpg")
+    );
+    assert!(item.oracle_id.is_none());
+}
+
 #[tokio::test]
 async fn get_collection_maps_price_guide_fields_when_card_has_prices() {
     use crate::domain::price::{Price, PriceGuide};
@@ -482,6 +522,30 @@ async fn get_collection_passes_search_query_to_use_case() {
     assert!(result.is_ok());
 }
 
+#[tokio::test]
+async fn get_collection_passes_normalized_tags_and_location_to_use_case() {
+    let mut mock = MockGetCollectionUseCase::new();
+    mock.expect_get_collection()
+        .withf(|_, q| q.tags == ["cube", "foil"] && q.location.as_deref() == Some("Box 3"))
+        .times(1)
+        .returning(|_, _| Box::pin(async { Ok(make_paginated(vec![], 0, 20)) }));
+
+    let params = CollectionParams {
+        tag: vec!["Foil".to_string(), "cube".to_string(), "CUBE".to_string()],
+        location: Some("  Box 3 ".to_string()),
+        ..Default::default()
+    };
+
+    let result = get_collection(
+        AuthenticatedUser(User::for_testing()),
+        State(make_app_state_with_collection(mock)),
+        Query(params),
+    )
+    .await;
+
+    assert!(result.is_ok());
+}
+
 #[tokio::test]
 async fn get_collection_passes_none_search_query_when_q_is_absent() {
     let mut mock = MockGetCollectionUseCase::new();
@@ -522,6 +586,46 @@ async fn get_collection_parses_repeated_rarity_query_params_into_rarity_codes()
     assert!(result.is_ok());
 }
 
+#[tokio::test]
+async fn get_collection_parses_colour_and_type_line_filters() {
+    let mut mock = MockGetCollectionUseCase::new();
+    mock.expect_get_collection()
+        .withf(|_, q| {
+            q.colors == vec![Color::W, Color::U] && q.type_line == Some("Creature".to_string())
+        })
+        .returning(|_, _| Box::pin(async { Ok(make_paginated(vec![], 0, 20)) }));
+
+    let app_state = make_app_state_with_collection(mock);
+    let uri: axum::http::Uri = "/collection?color=W&color=U&type_line=%20Creature%20"
+        .parse()
+        .unwrap();
+    let params = Query::<CollectionParams>::try_from_uri(&uri).unwrap();
+
+    let result = get_collection(
+        AuthenticatedUser(User::for_testing()),
+        State(app_state),
+        params,
+    )
+    .await;
+
+    assert!(result.is_ok());
+}
+
+#[tokio::test]
+async fn get_collection_passes_binder_id_to_use_case() {
+    let mut mock = MockGetCollectionUseCase::new();
+    mock.expect_get_collection()
+        .withf(|_, q| q.binder_id == Some(BinderId(uuid::Uuid::nil())))
+        .returning(|_, _| Box::pin(async { Ok(make_paginated(vec![], 0, 20)) }));
+
+    let uri: axum::http::Uri = "/collection?binder_id=00000000-0000-0000-0000-000000000000"
+        .parse()
+        .unwrap();
+    let params = Query::<CollectionParams>::try_from_uri(&uri).unwrap();
+
+    assert!(call_get_collection_with(mock, params.0).await);
+}
+
 #[tokio::test]
 async fn get_collection_defaults_rarity_to_empty_when_absent() {
     let mut mock = MockGetCollectionUseCase::new();
@@ -560,6 +664,7 @@ async fn import_cards_succeeds_with_valid_csv() {
     let result = import_cards(
         AuthenticatedUser(test_user),
         State(app_state),
+        Query(BinderParams::default()),
         Body::from(csv_body),
     )
     .await;
@@ -583,6 +688,7 @@ async fn import_cards_succeeds_with_multiple_cards() {
     let result = import_cards(
         AuthenticatedUser(test_user),
         State(app_state),
+        Query(BinderParams::default()),
         Body::from(csv_body),
     )
     .await;
@@ -605,6 +711,7 @@ async fn import_cards_succeeds_with_foil_cards() {
     let result = import_cards(
         AuthenticatedUser(test_user),
         State(app_state),
+        Query(BinderParams::default()),
         Body::from(csv_body),
     )
     .await;
@@ -627,6 +734,7 @@ async fn import_cards_succeeds_with_special_characters_in_card_name() {
     let result = import_cards(
         AuthenticatedUser(test_user),
         State(app_state),
+        Query(BinderParams::default()),
         Body::from(csv_body),
     )
     .await;
@@ -649,6 +757,7 @@ async fn import_cards_fails_with_invalid_utf8() {
     let result = import_cards(
         AuthenticatedUser(test_user),
         State(app_state),
+        Query(BinderParams::default()),
         Body::from(invalid_bytes),
     )
     .await;
@@ -674,7 +783,7 @@ async fn get_collection_stats_returns_stats_from_use_case() {
     use crate::domain::set_name::{SetCode, SetName};
 
     let mut mock = MockGetCollectionStatsUseCase::new();
-    mock.expect_get_collection_stats().returning(|_| {
+    mock.expect_get_collection_stats().returning(|_, _, _| {
         Box::pin(async {
             Ok(CollectionStats {
                 total_cards: 42,
@@ -682,13 +791,18 @@ async fn get_collection_stats_returns_stats_from_use_case() {
                 price_trend_min: Price::from_cents(100),
                 price_trend_max: Price::from_cents(5000),
                 sets: vec![SetName::new(SetCode::new("FDN"), "Foundations")],
+                tags: vec!["cube".to_string()],
             })
         })
     });
 
     let app_state = make_app_state_with_stats(mock);
-    let result =
-        get_collection_stats(AuthenticatedUser(User::for_testing()), State(app_state)).await;
+    let result = get_collection_stats(
+        AuthenticatedUser(User::for_testing()),
+        State(app_state),
+        Query(CollectionStatsParams::default()),
+    )
+    .await;
 
     assert!(result.is_ok());
     let axum::Json(response) = result.unwrap();
@@ -699,6 +813,7 @@ async fn get_collection_stats_returns_stats_from_use_case() {
     assert_eq!(response.sets.len(), 1);
     assert_eq!(response.sets[0].code, "FDN");
     assert_eq!(response.sets[0].name, "Foundations");
+    assert_eq!(response.tags, vec!["cube"]);
 }
 
 #[tokio::test]
@@ -708,7 +823,7 @@ async fn get_collection_stats_returns_empty_for_empty_collection() {
     use crate::domain::price::Price;
 
     let mut mock = MockGetCollectionStatsUseCase::new();
-    mock.expect_get_collection_stats().returning(|_| {
+    mock.expect_get_collection_stats().returning(|_, _, _| {
         Box::pin(async {
             Ok(CollectionStats {
                 total_cards: 0,
@@ -716,13 +831,18 @@ async fn get_collection_stats_returns_empty_for_empty_collection() {
                 price_trend_min: Price::empty(),
                 price_trend_max: Price::empty(),
                 sets: vec![],
+                tags: vec![],
             })
         })
     });
 
     let app_state = make_app_state_with_stats(mock);
-    let result =
-        get_collection_stats(AuthenticatedUser(User::for_testing()), State(app_state)).await;
+    let result = get_collection_stats(
+        AuthenticatedUser(User::for_testing()),
+        State(app_state),
+        Query(CollectionStatsParams::default()),
+    )
+    .await;
 
     assert!(result.is_ok());
     let axum::Json(response) = result.unwrap();
@@ -733,12 +853,50 @@ async fn get_collection_stats_returns_empty_for_empty_collection() {
     assert!(response.sets.is_empty());
 }
 
+#[tokio::test]
+async fn get_collection_stats_passes_binder_id_and_tags_to_use_case() {
+    use crate::application::use_case::MockGetCollectionStatsUseCase;
+    use crate::domain::collection_stats::CollectionStats;
+    use crate::domain::price::Price;
+
+    let mut mock = MockGetCollectionStatsUseCase::new();
+    mock.expect_get_collection_stats()
+        .withf(|_, binder_id, tags| {
+            *binder_id == Some(BinderId(uuid::Uuid::nil())) && tags == &["cube"]
+        })
+        .times(1)
+        .returning(|_, _, _| {
+            Box::pin(async {
+                Ok(CollectionStats {
+                    total_cards: 3,
+                    unique_cards: 1,
+                    price_trend_min: Price::empty(),
+                    price_trend_max: Price::empty(),
+                    sets: vec![],
+                    tags: vec![],
+                })
+            })
+        });
+
+    let result = get_collection_stats(
+        AuthenticatedUser(User::for_testing()),
+        State(make_app_state_with_stats(mock)),
+        Query(CollectionStatsParams {
+            binder_id: Some(uuid::Uuid::nil()),
+            tag: vec![" Cube".to_string()],
+        }),
+    )
+    .await;
+
+    assert_eq!(result.unwrap().0.total_cards, 3);
+}
+
 #[tokio::test]
 async fn get_collection_stats_propagates_error_from_use_case() {
     use crate::application::use_case::MockGetCollectionStatsUseCase;
 
     let mut mock = MockGetCollectionStatsUseCase::new();
-    mock.expect_get_collection_stats().returning(|_| {
+    mock.expect_get_collection_stats().returning(|_, _, _| {
         Box::pin(async {
             Err(AppError::Infra(InfraError::RepositoryError(
                 "db failure".to_string(),
@@ -747,8 +905,12 @@ async fn get_collection_stats_propagates_error_from_use_case() {
     });
 
     let app_state = make_app_state_with_stats(mock);
-    let result =
-        get_collection_stats(AuthenticatedUser(User::for_testing()), State(app_state)).await;
+    let result = get_collection_stats(
+        AuthenticatedUser(User::for_testing()),
+        State(app_state),
+        Query(CollectionStatsParams::default()),
+    )
+    .await;
 
     assert!(result.is_err());
     match result.unwrap_err() {
@@ -768,7 +930,7 @@ async fn get_collection_price_history_returns_entries() {
 
     let mut mock = MockGetCollectionPriceHistoryUseCase::new();
     mock.expect_get_collection_price_history()
-        .returning(|_, _, _| {
+        .returning(|_, _, _, _| {
             Box::pin(async {
                 Ok(vec![PriceHistoryEntry {
                     date: NaiveDate::from_ymd_opt(2025, 1, 15).unwrap(),
@@ -777,6 +939,11 @@ async fn get_collection_price_history_returns_entries() {
                         trend: Price { value: Some(150) },
                         avg: Price { value: Some(130) },
                     },
+                    trend_open: Price { value: Some(120) },
+                    trend_high: Price { value: Some(160) },
+                    trend_low: Price { value: Some(110) },
+                    trend_ma7: Price::empty(),
+                    trend_ma30: Price::empty(),
                 }])
             })
         });
@@ -785,6 +952,8 @@ async fn get_collection_price_history_returns_entries() {
     let params = PriceHistoryParams {
         start_date: Some(NaiveDate::from_ymd_opt(2025, 1, 1).unwrap()),
         end_date: Some(NaiveDate::from_ymd_opt(2025, 1, 31).unwrap()),
+        granularity: None,
+        moving_averages: None,
     };
 
     let result = get_collection_price_history(
@@ -808,12 +977,14 @@ async fn get_collection_price_history_returns_empty_list() {
 
     let mut mock = MockGetCollectionPriceHistoryUseCase::new();
     mock.expect_get_collection_price_history()
-        .returning(|_, _, _| Box::pin(async { Ok(vec![]) }));
+        .returning(|_, _, _, _| Box::pin(async { Ok(vec![]) }));
 
     let app_state = make_app_state_with_price_history(mock);
     let params = PriceHistoryParams {
         start_date: Some(NaiveDate::from_ymd_opt(2025, 1, 1).unwrap()),
         end_date: Some(NaiveDate::from_ymd_opt(2025, 1, 31).unwrap()),
+        granularity: None,
+        moving_averages: None,
     };
 
     let result = get_collection_price_history(
@@ -834,7 +1005,7 @@ async fn get_collection_price_history_propagates_use_case_error() {
 
     let mut mock = MockGetCollectionPriceHistoryUseCase::new();
     mock.expect_get_collection_price_history()
-        .returning(|_, _, _| {
+        .returning(|_, _, _, _| {
             Box::pin(async {
                 Err(AppError::Functional(FunctionalError::WrongFormat(
                     "start_date must be before or equal to end_date".to_string(),
@@ -846,6 +1017,8 @@ async fn get_collection_price_history_propagates_use_case_error() {
     let params = PriceHistoryParams {
         start_date: Some(NaiveDate::from_ymd_opt(2025, 2, 1).unwrap()),
         end_date: Some(NaiveDate::from_ymd_opt(2025, 1, 1).unwrap()),
+        granularity: None,
+        moving_averages: None,
     };
 
     let result = get_collection_price_history(
@@ -870,13 +1043,51 @@ async fn get_collection_price_history_passes_missing_dates_through_to_use_case()
 
     let mut mock = MockGetCollectionPriceHistoryUseCase::new();
     mock.expect_get_collection_price_history()
-        .withf(|_, s, e| s.is_none() && e.is_none())
-        .returning(|_, _, _| Box::pin(async { Ok(vec![]) }));
+        .withf(|_, s, e, _| s.is_none() && e.is_none())
+        .returning(|_, _, _, _| Box::pin(async { Ok(vec![]) }));
+
+    let app_state = make_app_state_with_price_history(mock);
+    let params = PriceHistoryParams {
+        start_date: None,
+        end_date: None,
+        granularity: None,
+        moving_averages: None,
+    };
+
+    let result = get_collection_price_history(
+        AuthenticatedUser(User::for_testing()),
+        State(app_state),
+        Query(params),
+    )
+    .await;
+
+    assert!(result.is_ok());
+}
+
+#[tokio::test]
+async fn get_collection_price_history_passes_granularity_and_moving_averages_to_use_case() {
+    use crate::application::use_case::MockGetCollectionPriceHistoryUseCase;
+    use crate::domain::price::{PriceHistoryGranularity, PriceHistoryOptions};
+    use crate::infrastructure::adapter_in::card::dto::PriceHistoryGranularityParam;
+
+    let mut mock = MockGetCollectionPriceHistoryUseCase::new();
+    mock.expect_get_collection_price_history()
+        .withf(|_, _, _, options| {
+            *options
+                == PriceHistoryOptions {
+                    granularity: PriceHistoryGranularity::Week,
+                    moving_averages: true,
+                }
+        })
+        .times(1)
+        .returning(|_, _, _, _| Box::pin(async { Ok(vec![]) }));
 
     let app_state = make_app_state_with_price_history(mock);
     let params = PriceHistoryParams {
         start_date: None,
         end_date: None,
+        granularity: Some(PriceHistoryGranularityParam::Week),
+        moving_averages: Some(true),
     };
 
     let result = get_collection_price_history(
@@ -928,6 +1139,166 @@ fn rarity_code_param_from_mapping_is_complete() {
     assert_eq!(RarityCode::from(RarityCodeParam::S), RarityCode::S);
 }
 
+#[test]
+fn color_param_from_mapping_is_complete() {
+    assert_eq!(Color::from(ColorParam::W), Color::W);
+    assert_eq!(Color::from(ColorParam::U), Color::U);
+    assert_eq!(Color::from(ColorParam::B), Color::B);
+    assert_eq!(Color::from(ColorParam::R), Color::R);
+    assert_eq!(Color::from(ColorParam::G), Color::G);
+}
+
+// --- Tests for get_commander_synergy ---
+
+fn make_app_state_with_commander_synergy(
+    mock: crate::application::use_case::MockGetCommanderSynergyUseCase,
+) -> AppState {
+    AppState::for_testing_with_commander_synergy(
+        Arc::new(crate::application::use_case::MockStatsUseCase::new()),
+        Arc::new(mock),
+    )
+}
+
+#[tokio::test]
+async fn get_commander_synergy_returns_owned_and_missing_cards() {
+    use crate::application::use_case::MockGetCommanderSynergyUseCase;
+    use crate::domain::card::CardId;
+    use crate::domain::commander_synergy::{
+        CommanderRecommendation, CommanderSynergyReport, MissingRecommendation, OwnedPrinting,
+        OwnedRecommendation, RecommendationList,
+    };
+    use crate::domain::price::Price;
+    use uuid::Uuid;
+
+    let scryfall_id = Uuid::parse_str("7a79190f-de60-4eb6-b925-594eb76ca8c3").unwrap();
+
+    let mut mock = MockGetCommanderSynergyUseCase::new();
+    mock.expect_get_commander_synergy()
+        .withf(|_, commander| commander == "Atraxa, Praetors' Voice")
+        .returning(move |_, _| {
+            Box::pin(async move {
+                Ok(CommanderSynergyReport {
+                    commander: "Atraxa, Praetors' Voice".to_string(),
+                    owned: vec![OwnedRecommendation {
+                        recommendation: CommanderRecommendation {
+                            name: "Sol Ring".to_string(),
+                            list: RecommendationList::Top,
+                            synergy: 0.0,
+                            inclusion: 900,
+                            potential_decks: 1000,
+                        },
+                        printings: vec![OwnedPrinting {
+                            card_id: CardId::new("FDN", "1", LanguageCode::EN, false),
+                            name: "Sol Ring".to_string(),
+                            scryfall_id,
+                            quantity: 2,
+                            trend: Price { value: Some(150) },
+                        }],
+                    }],
+                    missing: vec![MissingRecommendation {
+                        recommendation: CommanderRecommendation {
+                            name: "Doubling Season".to_string(),
+                            list: RecommendationList::HighSynergy,
+                            synergy: 0.5,
+                            inclusion: 500,
+                            potential_decks: 1000,
+                        },
+                        owner_count: 3,
+                    }],
+                })
+            })
+        });
+
+    let result = get_commander_synergy(
+        AuthenticatedUser(User::for_testing()),
+        State(make_app_state_with_commander_synergy(mock)),
+        Query(CommanderSynergyParams {
+            commander: "  Atraxa, Praetors' Voice ".to_string(),
+        }),
+    )
+    .await;
+
+    let axum::Json(response) = result.unwrap();
+    assert_eq!(
+        serde_json::to_value(&response).unwrap(),
+        serde_json::json!({
+            "commander": "Atraxa, Praetors' Voice",
+            "owned_value": 300,
+            "owned": [{
+                "name": "Sol Ring",
+                "list": "top",
+                "synergy": 0.0,
+                "inclusion": 900,
+                "potential_decks": 1000,
+                "value": 300,
+                "printings": [{
+                    "set_code": "FDN",
+                    "collector_number": "1",
+                    "language_code": "EN",
+                    "foil": false,
+                    "scryfall_id": "7a79190f-de60-4eb6-b925-594eb76ca8c3",
+                    "quantity": 2,
+                    "trend": 150
+                }]
+            }],
+            "missing": [{
+                "name": "Doubling Season",
+                "list": "high_synergy",
+                "synergy": 0.5,
+                "inclusion": 500,
+                "potential_decks": 1000,
+                "owner_count": 3
+            }]
+        })
+    );
+}
+
+#[tokio::test]
+async fn get_commander_synergy_rejects_empty_commander() {
+    use crate::application::use_case::MockGetCommanderSynergyUseCase;
+
+    let mut mock = MockGetCommanderSynergyUseCase::new();
+    mock.expect_get_commander_synergy().never();
+
+    let result = get_commander_synergy(
+        AuthenticatedUser(User::for_testing()),
+        State(make_app_state_with_commander_synergy(mock)),
+        Query(CommanderSynergyParams {
+            commander: "   ".to_string(),
+        }),
+    )
+    .await;
+
+    assert!(matches!(
+        result,
+        Err(AppError::Functional(FunctionalError::WrongFormat(_)))
+    ));
+}
+
+#[tokio::test]
+async fn get_commander_synergy_returns_404_when_commander_unknown() {
+    use crate::application::use_case::MockGetCommanderSynergyUseCase;
+
+    let mut mock = MockGetCommanderSynergyUseCase::new();
+    mock.expect_get_commander_synergy().returning(|_, _| {
+        Box::pin(async { Err(AppError::Functional(FunctionalError::CommanderNotFound)) })
+    });
+
+    let result = get_commander_synergy(
+        AuthenticatedUser(User::for_testing()),
+        State(make_app_state_with_commander_synergy(mock)),
+        Query(CommanderSynergyParams {
+            commander: "Unknown".to_string(),
+        }),
+    )
+    .await;
+
+    assert!(matches!(
+        result,
+        Err(AppError::Functional(FunctionalError::CommanderNotFound))
+    ));
+}
+
 #[test]
 fn default_page_size_returns_20() {
     assert_eq!(default_page_size(), 20);
@@ -945,6 +1316,8 @@ fn collection_params_default_values() {
     assert!(params.sets.is_none());
     assert!(params.price_min.is_none());
     assert!(params.price_max.is_none());
+    assert!(params.color.is_empty());
+    assert!(params.type_line.is_none());
 }
 
 #[test]
@@ -999,3 +1372,429 @@ fn collection_params_serde_partial_fields() {
     assert_eq!(params.0.price_min, Some(1000));
     assert!(params.0.price_max.is_none());
 }
+
+// --- Tests for collection entries ---
+
+fn make_app_state_with_collection_entries(
+    mock: crate::application::use_case::MockManageCollectionEntryUseCase,
+) -> AppState {
+    AppState::for_testing_with_manage_collection_entry(
+        Arc::new(crate::application::use_case::MockStatsUseCase::new()),
+        Arc::new(mock),
+    )
+}
+
+fn make_create_entry_request() -> CreateCollectionEntryRequest {
+    CreateCollectionEntryRequest {
+        set_code: "FDN".to_string(),
+        collector_number: "87".to_string(),
+        language_code: "FR".to_string(),
+        foil: false,
+        quantity: 2,
+        purchase_price: 15,
+        condition: CardConditionParam::Excellent,
+        notes: Some("  From the prerelease  ".to_string()),
+        tags: vec![" Cube ".to_string()],
+        location: Some("Box 3, row 2".to_string()),
+        binder_id: None,
+        tradable_quantity: None,
+    }
+}
+
+fn make_update_entry_request() -> UpdateCollectionEntryRequest {
+    UpdateCollectionEntryRequest {
+        set_code: "FDN".to_string(),
+        collector_number: "87".to_string(),
+        language_code: "FR".to_string(),
+        foil: false,
+        quantity: None,
+        purchase_price: None,
+        condition: None,
+        notes: None,
+        tags: None,
+        location: None,
+        binder_id: None,
+        tradable_quantity: None,
+    }
+}
+
+#[tokio::test]
+async fn add_collection_entry_returns_created_entry() {
+    use crate::application::use_case::MockManageCollectionEntryUseCase;
+    use crate::domain::collection_item::CardCondition;
+
+    let mut mock = MockManageCollectionEntryUseCase::new();
+    mock.expect_add_entry()
+        .withf(|user_id, binder_id, item| {
+            user_id.as_str() == "test-user-id"
+                && binder_id.is_none()
+                && item.quantity == 2
+                && item.condition == CardCondition::Excellent
+                && item.notes.as_deref() == Some("From the prerelease")
+                && item.tags == ["cube"]
+                && item.location.as_deref() == Some("Box 3, row 2")
+        })
+        .times(1)
+        .returning(|_, _, _| Box::pin(async { Ok(()) }));
+
+    let (status, axum::Json(response)) = add_collection_entry(
+        AuthenticatedUser(User::for_testing()),
+        State(make_app_state_with_collection_entries(mock)),
+        axum::Json(make_create_entry_request()),
+    )
+    .await
+    .unwrap();
+
+    assert_eq!(status, axum::http::StatusCode::CREATED);
+    assert_eq!(response.set_code, "FDN");
+    assert_eq!(response.quantity, 2);
+    assert_eq!(response.condition, CardConditionParam::Excellent);
+    assert_eq!(response.notes.as_deref(), Some("From the prerelease"));
+    assert_eq!(response.tags, vec!["cube"]);
+    assert_eq!(response.location.as_deref(), Some("Box 3, row 2"));
+}
+
+#[tokio::test]
+async fn add_collection_entry_rejects_zero_quantity() {
+    use crate::application::use_case::MockManageCollectionEntryUseCase;
+
+    let mut mock = MockManageCollectionEntryUseCase::new();
+    mock.expect_add_entry().never();
+    let mut request = make_create_entry_request();
+    request.quantity = 0;
+
+    let result = add_collection_entry(
+        AuthenticatedUser(User::for_testing()),
+        State(make_app_state_with_collection_entries(mock)),
+        axum::Json(request),
+    )
+    .await;
+
+    match result.unwrap_err() {
+        AppError::Functional(FunctionalError::WrongFormat(msg)) => {
+            assert_eq!(msg, "quantity must be at least 1")
+        }
+        _ => panic!("Expected WrongFormat"),
+    }
+}
+
+#[tokio::test]
+async fn add_collection_entry_rejects_tradable_quantity_above_quantity() {
+    use crate::application::use_case::MockManageCollectionEntryUseCase;
+
+    let mut mock = MockManageCollectionEntryUseCase::new();
+    mock.expect_add_entry().never();
+    let mut request = make_create_entry_request();
+    request.tradable_quantity = Some(3);
+
+    let result = add_collection_entry(
+        AuthenticatedUser(User::for_testing()),
+        State(make_app_state_with_collection_entries(mock)),
+        axum::Json(request),
+    )
+    .await;
+
+    match result.unwrap_err() {
+        AppError::Functional(FunctionalError::WrongFormat(msg)) => {
+            assert_eq!(msg, "tradable_quantity must not exceed quantity")
+        }
+        _ => panic!("Expected WrongFormat"),
+    }
+}
+
+#[test]
+fn update_entry_request_tells_a_null_tradable_quantity_from_an_omitted_one() {
+    let key = r#""set_code":"FDN","collector_number":"87","language_code":"FR","foil":false"#;
+    let parse = |extra: &str| {
+        serde_json::from_str::<UpdateCollectionEntryRequest>(&format!("{{{key}{extra}}}"))
+            .unwrap()
+            .tradable_quantity
+    };
+
+    assert_eq!(parse(""), None);
+    assert_eq!(parse(r#","tradable_quantity":null"#), Some(None));
+    assert_eq!(parse(r#","tradable_quantity":2"#), Some(Some(2)));
+}
+
+#[tokio::test]
+async fn add_collection_entry_rejects_invalid_language_code() {
+    use crate::application::use_case::MockManageCollectionEntryUseCase;
+
+    let mut request = make_create_entry_request();
+    request.language_code = "XX".to_string();
+
+    let result = add_collection_entry(
+        AuthenticatedUser(User::for_testing()),
+        State(make_app_state_with_collection_entries(
+            MockManageCollectionEntryUseCase::new(),
+        )),
+        axum::Json(request),
+    )
+    .await;
+
+    assert!(matches!(
+        result,
+        Err(AppError::Functional(FunctionalError::InvalidLanguageCode(
+            _
+        )))
+    ));
+}
+
+#[tokio::test]
+async fn add_collection_entry_rejects_too_long_notes() {
+    use crate::application::use_case::MockManageCollectionEntryUseCase;
+
+    let mut request = make_create_entry_request();
+    request.notes = Some("a".repeat(1001));
+
+    let result = add_collection_entry(
+        AuthenticatedUser(User::for_testing()),
+        State(make_app_state_with_collection_entries(
+            MockManageCollectionEntryUseCase::new(),
+        )),
+        axum::Json(request),
+    )
+    .await;
+
+    assert!(matches!(
+        result,
+        Err(AppError::Functional(FunctionalError::WrongFormat(_)))
+    ));
+}
+
+#[tokio::test]
+async fn add_collection_entry_rejects_too_many_tags() {
+    use crate::application::use_case::MockManageCollectionEntryUseCase;
+
+    let mut request = make_create_entry_request();
+    request.tags = (0..21).map(|i| format!("tag{i}")).collect();
+
+    let result = add_collection_entry(
+        AuthenticatedUser(User::for_testing()),
+        State(make_app_state_with_collection_entries(
+            MockManageCollectionEntryUseCase::new(),
+        )),
+        axum::Json(request),
+    )
+    .await;
+
+    assert!(matches!(
+        result,
+        Err(AppError::Functional(FunctionalError::WrongFormat(_)))
+    ));
+}
+
+#[tokio::test]
+async fn add_collection_entry_propagates_conflict_from_use_case() {
+    use crate::application::use_case::MockManageCollectionEntryUseCase;
+
+    let mut mock = MockManageCollectionEntryUseCase::new();
+    mock.expect_add_entry().returning(|_, _, _| {
+        Box::pin(async {
+            Err(AppError::Functional(
+                FunctionalError::CollectionEntryAlreadyExists,
+            ))
+        })
+    });
+
+    let result = add_collection_entry(
+        AuthenticatedUser(User::for_testing()),
+        State(make_app_state_with_collection_entries(mock)),
+        axum::Json(make_create_entry_request()),
+    )
+    .await;
+
+    assert!(matches!(
+        result,
+        Err(AppError::Functional(
+            FunctionalError::CollectionEntryAlreadyExists
+        ))
+    ));
+}
+
+#[tokio::test]
+async fn update_collection_entry_clears_notes_on_empty_string() {
+    use crate::application::use_case::MockManageCollectionEntryUseCase;
+    use crate::domain::card::CardId;
+    use crate::domain::collection_item::{CardCondition, CollectionItem};
+
+    let mut mock = MockManageCollectionEntryUseCase::new();
+    mock.expect_update_entry()
+        .withf(|_, _, card_id, update| {
+            *card_id == CardId::new("FDN", "87", LanguageCode::FR, false)
+                && update.quantity == Some(3)
+                && update.condition == Some(CardCondition::Played)
+                && update.notes == Some(None)
+                && update.tags.is_none()
+                && update.location.is_none()
+                && update.purchase_price.is_none()
+        })
+        .times(1)
+        .returning(|_, _, card_id, _| {
+            Box::pin(async move {
+                Ok(CollectionItem {
+                    card_id,
+                    quantity: 3,
+                    purchase_price: 15,
+                    condition: CardCondition::Played,
+                    notes: None,
+                    tags: vec![],
+                    location: None,
+                    tradable_quantity: None,
+                    added_at: chrono::Utc::now(),
+                })
+            })
+        });
+    let mut request = make_update_entry_request();
+    request.quantity = Some(3);
+    request.condition = Some(CardConditionParam::Played);
+    request.notes = Some("   ".to_string());
+
+    let axum::Json(response) = update_collection_entry(
+        AuthenticatedUser(User::for_testing()),
+        State(make_app_state_with_collection_entries(mock)),
+        axum::Json(request),
+    )
+    .await
+    .unwrap();
+
+    assert_eq!(response.quantity, 3);
+    assert_eq!(response.condition, CardConditionParam::Played);
+    assert_eq!(response.notes, None);
+}
+
+#[tokio::test]
+async fn update_collection_entry_replaces_tags_and_clears_location_on_empty_string() {
+    use crate::application::use_case::MockManageCollectionEntryUseCase;
+    use crate::domain::collection_item::{CardCondition, CollectionItem};
+
+    let mut mock = MockManageCollectionEntryUseCase::new();
+    mock.expect_update_entry()
+        .withf(|_, _, _, update| {
+            update.tags == Some(vec!["cube".to_string(), "trade".to_string()])
+                && update.location == Some(None)
+                && update.notes.is_none()
+        })
+        .times(1)
+        .returning(|_, _, card_id, _| {
+            Box::pin(async move {
+                Ok(CollectionItem {
+                    card_id,
+                    quantity: 2,
+                    purchase_price: 15,
+                    condition: CardCondition::NearMint,
+                    notes: None,
+                    tags: vec!["cube".to_string(), "trade".to_string()],
+                    location: None,
+                    tradable_quantity: None,
+                    added_at: chrono::Utc::now(),
+                })
+            })
+        });
+    let mut request = make_update_entry_request();
+    request.tags = Some(vec![
+        "Trade".to_string(),
+        "CUBE".to_string(),
+        " ".to_string(),
+    ]);
+    request.location = Some(String::new());
+
+    let axum::Json(response) = update_collection_entry(
+        AuthenticatedUser(User::for_testing()),
+        State(make_app_state_with_collection_entries(mock)),
+        axum::Json(request),
+    )
+    .await
+    .unwrap();
+
+    assert_eq!(response.tags, vec!["cube", "trade"]);
+    assert_eq!(response.location, None);
+}
+
+#[tokio::test]
+async fn update_collection_entry_rejects_zero_quantity() {
+    use crate::application::use_case::MockManageCollectionEntryUseCase;
+
+    let mut mock = MockManageCollectionEntryUseCase::new();
+    mock.expect_update_entry().never();
+    let mut request = make_update_entry_request();
+    request.quantity = Some(0);
+
+    let result = update_collection_entry(
+        AuthenticatedUser(User::for_testing()),
+        State(make_app_state_with_collection_entries(mock)),
+        axum::Json(request),
+    )
+    .await;
+
+    assert!(matches!(
+        result,
+        Err(AppError::Functional(FunctionalError::WrongFormat(_)))
+    ));
+}
+
+#[tokio::test]
+async fn remove_collection_entry_returns_no_content() {
+    use crate::application::use_case::MockManageCollectionEntryUseCase;
+    use crate::domain::card::CardId;
+
+    let mut mock = MockManageCollectionEntryUseCase::new();
+    mock.expect_remove_entry()
+        .withf(|user_id, binder_id, card_id| {
+            user_id.as_str() == "test-user-id"
+                && *binder_id == Some(BinderId(uuid::Uuid::nil()))
+                && *card_id == CardId::new("FDN", "87", LanguageCode::FR, true)
+        })
+        .times(1)
+        .returning(|_, _, _| Box::pin(async { Ok(()) }));
+
+    let status = remove_collection_entry(
+        AuthenticatedUser(User::for_testing()),
+        State(make_app_state_with_collection_entries(mock)),
+        Query(CollectionEntryKeyParams {
+            set_code: "FDN".to_string(),
+            collector_number: "87".to_string(),
+            language_code: "FR".to_string(),
+            foil: true,
+            binder_id: Some(uuid::Uuid::nil()),
+        }),
+    )
+    .await
+    .unwrap();
+
+    assert_eq!(status, axum::http::StatusCode::NO_CONTENT);
+}
+
+#[tokio::test]
+async fn remove_collection_entry_propagates_not_found_from_use_case() {
+    use crate::application::use_case::MockManageCollectionEntryUseCase;
+
+    let mut mock = MockManageCollectionEntryUseCase::new();
+    mock.expect_remove_entry().returning(|_, _, _| {
+        Box::pin(async {
+            Err(AppError::Functional(
+                FunctionalError::CollectionEntryNotFound,
+            ))
+        })
+    });
+
+    let result = remove_collection_entry(
+        AuthenticatedUser(User::for_testing()),
+        State(make_app_state_with_collection_entries(mock)),
+        Query(CollectionEntryKeyParams {
+            set_code: "FDN".to_string(),
+            collector_number: "87".to_string(),
+            language_code: "FR".to_string(),
+            foil: false,
+            binder_id: None,
+        }),
+    )
+    .await;
+
+    assert!(matches!(
+        result,
+        Err(AppError::Functional(
+            FunctionalError::CollectionEntryNotFound
+        ))
+    ));
+}
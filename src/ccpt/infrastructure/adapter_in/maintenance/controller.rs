@@ -1,10 +1,15 @@
-use super::dto::{EnqueueResponse, StatsResponse};
+use super::dto::{
+    CardPricesRefreshResponse, EnqueueResponse, HttpCachePurgeParams, HttpCachePurgeResponse,
+    HttpCacheSourceResponse, HttpCacheStatsResponse, PriceAnomalyResponse,
+    ScryfallCatalogImportResponse, StatsResponse, UnresolvedCardResponse, WorkerStatusResponse,
+};
 use crate::application::error::AppError;
 use crate::infrastructure::AppState;
 use axum::Json;
-use axum::extract::State;
+use axum::extract::{Path, Query, State};
 use axum::http::StatusCode;
 use axum::routing::{get, post};
+use chrono::NaiveDate;
 
 pub fn create_maintenance_router() -> axum::Router<AppState> {
     axum::Router::new()
@@ -12,6 +17,20 @@ pub fn create_maintenance_router() -> axum::Router<AppState> {
         .route("/trigger-price-update", post(trigger_price_update))
         .route("/update-cardmarket-ids", post(update_cardmarket_ids))
         .route("/update-gatherer-ids", post(update_gatherer_ids))
+        .route("/price-anomalies", get(get_price_anomalies))
+        .route(
+            "/price-anomalies/{id_product}/{date}/{foil}/confirm",
+            post(confirm_price_anomaly),
+        )
+        .route(
+            "/price-anomalies/{id_product}/{date}/{foil}/reject",
+            post(reject_price_anomaly),
+        )
+        .route("/card-prices-refresh", get(get_card_prices_refresh))
+        .route("/import-scryfall-catalog", post(import_scryfall_catalog))
+        .route("/unresolved-cards", get(get_unresolved_cards))
+        .route("/workers", get(get_workers))
+        .route("/http-cache", get(get_http_cache).delete(purge_http_cache))
 }
 
 #[utoipa::path(
@@ -34,6 +53,7 @@ pub(crate) async fn get_stats(
     path = "/maintenance/trigger-price-update",
     responses(
         (status = 204, description = "Price update triggered successfully"),
+        (status = 409, description = "A price import is already in progress"),
     ),
     tag = "maintenance",
 )]
@@ -85,3 +105,187 @@ pub(crate) async fn update_gatherer_ids(
 
     Ok((StatusCode::ACCEPTED, Json(EnqueueResponse { enqueued })))
 }
+
+#[utoipa::path(
+    get,
+    path = "/maintenance/price-anomalies",
+    responses(
+        (status = 200, description = "Suspicious prices waiting for review, most recent first", body = Vec<PriceAnomalyResponse>),
+    ),
+    tag = "maintenance",
+)]
+pub(crate) async fn get_price_anomalies(
+    State(state): State<AppState>,
+) -> Result<Json<Vec<PriceAnomalyResponse>>, AppError> {
+    let anomalies = state
+        .review_price_anomaly_use_case
+        .get_pending_anomalies()
+        .await?;
+
+    Ok(Json(anomalies.into_iter().map(Into::into).collect()))
+}
+
+#[utoipa::path(
+    post,
+    path = "/maintenance/price-anomalies/{id_product}/{date}/{foil}/confirm",
+    params(
+        ("id_product" = u32, Path, description = "Cardmarket product id"),
+        ("date" = String, Path, description = "Price date (YYYY-MM-DD)"),
+        ("foil" = bool, Path, description = "Whether the anomaly is on the foil price"),
+    ),
+    responses(
+        (status = 204, description = "Price confirmed and used again in aggregates"),
+        (status = 404, description = "No pending anomaly for this product, date and finish"),
+    ),
+    tag = "maintenance",
+)]
+pub(crate) async fn confirm_price_anomaly(
+    State(state): State<AppState>,
+    Path((id_product, date, foil)): Path<(u32, NaiveDate, bool)>,
+) -> Result<StatusCode, AppError> {
+    state
+        .review_price_anomaly_use_case
+        .confirm(id_product, date, foil)
+        .await?;
+
+    Ok(StatusCode::NO_CONTENT)
+}
+
+#[utoipa::path(
+    post,
+    path = "/maintenance/price-anomalies/{id_product}/{date}/{foil}/reject",
+    params(
+        ("id_product" = u32, Path, description = "Cardmarket product id"),
+        ("date" = String, Path, description = "Price date (YYYY-MM-DD)"),
+        ("foil" = bool, Path, description = "Whether the anomaly is on the foil price"),
+    ),
+    responses(
+        (status = 204, description = "Price rejected, it stays out of aggregates"),
+        (status = 404, description = "No pending anomaly for this product, date and finish"),
+    ),
+    tag = "maintenance",
+)]
+pub(crate) async fn reject_price_anomaly(
+    State(state): State<AppState>,
+    Path((id_product, date, foil)): Path<(u32, NaiveDate, bool)>,
+) -> Result<StatusCode, AppError> {
+    state
+        .review_price_anomaly_use_case
+        .reject(id_product, date, foil)
+        .await?;
+
+    Ok(StatusCode::NO_CONTENT)
+}
+
+#[utoipa::path(
+    get,
+    path = "/maintenance/card-prices-refresh",
+    responses(
+        (status = 200, description = "State of the card prices refresh", body = CardPricesRefreshResponse),
+    ),
+    tag = "maintenance",
+)]
+pub(crate) async fn get_card_prices_refresh(
+    State(state): State<AppState>,
+) -> Json<CardPricesRefreshResponse> {
+    Json(CardPricesRefreshResponse {
+        last_refreshed_at: state
+            .card_prices_refresh_use_case
+            .last_refreshed_at()
+            .map(|at| at.to_rfc3339()),
+    })
+}
+
+#[utoipa::path(
+    post,
+    path = "/maintenance/import-scryfall-catalog",
+    responses(
+        (status = 200, description = "Scryfall catalogue imported and CardMarket IDs resolved from it", body = ScryfallCatalogImportResponse),
+    ),
+    tag = "maintenance",
+)]
+pub(crate) async fn import_scryfall_catalog(
+    State(state): State<AppState>,
+) -> Result<Json<ScryfallCatalogImportResponse>, AppError> {
+    let import = state.scryfall_catalog_use_case.import_catalog().await?;
+
+    Ok(Json(import.into()))
+}
+
+#[utoipa::path(
+    get,
+    path = "/maintenance/unresolved-cards",
+    responses(
+        (status = 200, description = "Cards whose CardMarket or Gatherer ID could not be found, most recent attempt first", body = Vec<UnresolvedCardResponse>),
+    ),
+    tag = "maintenance",
+)]
+pub(crate) async fn get_unresolved_cards(
+    State(state): State<AppState>,
+) -> Result<Json<Vec<UnresolvedCardResponse>>, AppError> {
+    let cards = state
+        .get_unresolved_cards_use_case
+        .get_unresolved_cards()
+        .await?;
+
+    Ok(Json(cards.into_iter().map(Into::into).collect()))
+}
+
+#[utoipa::path(
+    get,
+    path = "/maintenance/workers",
+    responses(
+        (status = 200, description = "State of the enrichment workers of this instance and of their queues", body = Vec<WorkerStatusResponse>),
+    ),
+    tag = "maintenance",
+)]
+pub(crate) async fn get_workers(
+    State(state): State<AppState>,
+) -> Result<Json<Vec<WorkerStatusResponse>>, AppError> {
+    let statuses = state
+        .get_workers_status_use_case
+        .get_workers_status()
+        .await?;
+
+    Ok(Json(statuses.into_iter().map(Into::into).collect()))
+}
+
+#[utoipa::path(
+    get,
+    path = "/maintenance/http-cache",
+    responses(
+        (status = 200, description = "Responses of Scryfall, Gatherer and EDHREC kept in the HTTP cache, by source", body = Vec<HttpCacheStatsResponse>),
+    ),
+    tag = "maintenance",
+)]
+pub(crate) async fn get_http_cache(
+    State(state): State<AppState>,
+) -> Result<Json<Vec<HttpCacheStatsResponse>>, AppError> {
+    let stats = state.http_cache_use_case.get_stats().await?;
+
+    Ok(Json(stats.into_iter().map(Into::into).collect()))
+}
+
+#[utoipa::path(
+    delete,
+    path = "/maintenance/http-cache",
+    params(
+        ("source" = Option<HttpCacheSourceResponse>, Query, description = "Source to purge, every source if absent"),
+    ),
+    responses(
+        (status = 200, description = "Cached responses deleted", body = HttpCachePurgeResponse),
+        (status = 400, description = "Unknown source"),
+    ),
+    tag = "maintenance",
+)]
+pub(crate) async fn purge_http_cache(
+    State(state): State<AppState>,
+    Query(params): Query<HttpCachePurgeParams>,
+) -> Result<Json<HttpCachePurgeResponse>, AppError> {
+    let purged = state
+        .http_cache_use_case
+        .purge(params.source.map(Into::into))
+        .await?;
+
+    Ok(Json(HttpCachePurgeResponse { purged }))
+}
@@ -1,5 +1,10 @@
+use crate::domain::enrichment_job::{EnrichmentKind, EnrichmentOutcome, UnresolvedCard};
+use crate::domain::http_cache::{HttpCacheSource, HttpCacheStats};
+use crate::domain::price_anomaly::{PriceAnomaly, PriceAnomalyKind};
+use crate::domain::scryfall_card::ScryfallCatalogImport;
 use crate::domain::stats::Stats;
-use serde::Serialize;
+use crate::domain::worker_status::WorkerStatus;
+use serde::{Deserialize, Serialize};
 use utoipa::ToSchema;
 
 #[derive(Serialize, Debug, ToSchema)]
@@ -23,3 +28,249 @@ impl From<Stats> for StatsResponse {
 pub struct EnqueueResponse {
     pub enqueued: usize,
 }
+
+#[derive(Serialize, Debug, PartialEq, Eq, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum PriceAnomalyKindResponse {
+    Spike,
+    Drop,
+    Zero,
+}
+
+impl From<PriceAnomalyKind> for PriceAnomalyKindResponse {
+    fn from(kind: PriceAnomalyKind) -> Self {
+        match kind {
+            PriceAnomalyKind::Spike => PriceAnomalyKindResponse::Spike,
+            PriceAnomalyKind::Drop => PriceAnomalyKindResponse::Drop,
+            PriceAnomalyKind::Zero => PriceAnomalyKindResponse::Zero,
+        }
+    }
+}
+
+#[derive(Serialize, Debug, ToSchema)]
+pub struct PriceAnomalyResponse {
+    pub id_product: u32,
+    /// ISO 8601 date string (YYYY-MM-DD)
+    pub date: String,
+    pub foil: bool,
+    pub kind: PriceAnomalyKindResponse,
+    /// Median trend of the previous days, in cents
+    pub reference: u32,
+    /// Imported trend, in cents
+    pub observed: u32,
+    pub detected_at: String,
+}
+
+impl From<PriceAnomaly> for PriceAnomalyResponse {
+    fn from(anomaly: PriceAnomaly) -> Self {
+        Self {
+            id_product: anomaly.id_product,
+            date: anomaly.date.to_string(),
+            foil: anomaly.foil,
+            kind: anomaly.kind.into(),
+            reference: anomaly.reference,
+            observed: anomaly.observed,
+            detected_at: anomaly.detected_at.to_rfc3339(),
+        }
+    }
+}
+
+#[derive(Serialize, Debug, ToSchema)]
+pub struct CardPricesRefreshResponse {
+    /// Last successful refresh, null when none ran since startup
+    pub last_refreshed_at: Option<String>,
+}
+
+#[derive(Serialize, Debug, ToSchema)]
+pub struct ScryfallCatalogImportResponse {
+    /// Printings read from the bulk file
+    pub card_count: usize,
+    /// Cards whose CardMarket ID was found in the catalogue
+    pub resolved: usize,
+}
+
+impl From<ScryfallCatalogImport> for ScryfallCatalogImportResponse {
+    fn from(import: ScryfallCatalogImport) -> Self {
+        ScryfallCatalogImportResponse {
+            card_count: import.card_count,
+            resolved: import.resolved,
+        }
+    }
+}
+
+#[derive(Serialize, Debug, PartialEq, Eq, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum EnrichmentKindResponse {
+    CardmarketId,
+    GathererId,
+}
+
+impl From<EnrichmentKind> for EnrichmentKindResponse {
+    fn from(kind: EnrichmentKind) -> Self {
+        match kind {
+            EnrichmentKind::CardMarketId => EnrichmentKindResponse::CardmarketId,
+            EnrichmentKind::GathererId => EnrichmentKindResponse::GathererId,
+        }
+    }
+}
+
+#[derive(Serialize, Debug, PartialEq, Eq, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum EnrichmentOutcomeResponse {
+    Resolved,
+    NotFound,
+    Failed,
+}
+
+#[derive(Serialize, Debug, ToSchema)]
+pub struct UnresolvedCardResponse {
+    pub set_code: String,
+    pub collector_number: String,
+    pub language_code: String,
+    pub foil: bool,
+    pub name: String,
+    pub kind: EnrichmentKindResponse,
+    pub attempts: u32,
+    pub last_attempt_at: String,
+    pub outcome: EnrichmentOutcomeResponse,
+    pub reason: Option<String>,
+    /// Next lookup of the card, null once it is no longer looked up
+    pub next_attempt_at: Option<String>,
+}
+
+impl From<UnresolvedCard> for UnresolvedCardResponse {
+    fn from(card: UnresolvedCard) -> Self {
+        let outcome = match card.outcome {
+            EnrichmentOutcome::Resolved => EnrichmentOutcomeResponse::Resolved,
+            EnrichmentOutcome::NotFound(_) => EnrichmentOutcomeResponse::NotFound,
+            EnrichmentOutcome::Failed(_) => EnrichmentOutcomeResponse::Failed,
+        };
+
+        Self {
+            set_code: card.card_id.set_code.to_string(),
+            collector_number: card.card_id.collector_number,
+            language_code: card.card_id.language_code.to_string(),
+            foil: card.card_id.foil,
+            name: card.name,
+            kind: card.kind.into(),
+            attempts: card.attempts,
+            last_attempt_at: card.last_attempt_at.to_rfc3339(),
+            outcome,
+            reason: card.outcome.reason().map(str::to_string),
+            next_attempt_at: card.next_attempt_at.map(|at| at.to_rfc3339()),
+        }
+    }
+}
+
+#[derive(Serialize, Debug, ToSchema)]
+pub struct WorkerStatusResponse {
+    pub kind: EnrichmentKindResponse,
+    /// False once the task of the worker ended, nothing is processed until the next restart
+    pub alive: bool,
+    /// Jobs waiting for a worker, including the ones scheduled for a retry
+    pub pending: u64,
+    /// Jobs claimed by a worker
+    pub running: u64,
+    /// Jobs out of attempts
+    pub dead: u64,
+    /// Cards a new enqueue skips because their job is pending, running or dead
+    pub dedup_set_size: u64,
+    /// Jobs processed by this instance since startup, failed ones included
+    pub processed: u64,
+    pub failed: u64,
+    pub last_error: Option<String>,
+    pub last_error_at: Option<String>,
+    /// Card of the last job processed, as `SET/NUMBER/LANGUAGE[/foil]`
+    pub last_processed: Option<String>,
+    pub last_processed_at: Option<String>,
+}
+
+impl From<WorkerStatus> for WorkerStatusResponse {
+    fn from(status: WorkerStatus) -> Self {
+        let activity = status.activity;
+
+        Self {
+            kind: status.kind.into(),
+            alive: activity.alive,
+            pending: status.queue.pending,
+            running: status.queue.running,
+            dead: status.queue.dead,
+            dedup_set_size: status.queue.dedup_set_size(),
+            processed: activity.processed,
+            failed: activity.failed,
+            last_error: activity.last_error,
+            last_error_at: activity.last_error_at.map(|at| at.to_rfc3339()),
+            last_processed: activity.last_processed.map(|card_id| {
+                format!(
+                    "{}/{}/{}{}",
+                    card_id.set_code,
+                    card_id.collector_number,
+                    card_id.language_code,
+                    if card_id.foil { "/foil" } else { "" }
+                )
+            }),
+            last_processed_at: activity.last_processed_at.map(|at| at.to_rfc3339()),
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum HttpCacheSourceResponse {
+    Scryfall,
+    Gatherer,
+    Edhrec,
+}
+
+impl From<HttpCacheSource> for HttpCacheSourceResponse {
+    fn from(source: HttpCacheSource) -> Self {
+        match source {
+            HttpCacheSource::Scryfall => HttpCacheSourceResponse::Scryfall,
+            HttpCacheSource::Gatherer => HttpCacheSourceResponse::Gatherer,
+            HttpCacheSource::EdhRec => HttpCacheSourceResponse::Edhrec,
+        }
+    }
+}
+
+impl From<HttpCacheSourceResponse> for HttpCacheSource {
+    fn from(source: HttpCacheSourceResponse) -> Self {
+        match source {
+            HttpCacheSourceResponse::Scryfall => HttpCacheSource::Scryfall,
+            HttpCacheSourceResponse::Gatherer => HttpCacheSource::Gatherer,
+            HttpCacheSourceResponse::Edhrec => HttpCacheSource::EdhRec,
+        }
+    }
+}
+
+#[derive(Serialize, Debug, ToSchema)]
+pub struct HttpCacheStatsResponse {
+    pub source: HttpCacheSourceResponse,
+    pub entries: u64,
+    /// Size of the cached bodies
+    pub size_bytes: u64,
+    pub oldest_fetched_at: Option<String>,
+    pub newest_fetched_at: Option<String>,
+}
+
+impl From<HttpCacheStats> for HttpCacheStatsResponse {
+    fn from(stats: HttpCacheStats) -> Self {
+        Self {
+            source: stats.source.into(),
+            entries: stats.entries,
+            size_bytes: stats.size_bytes,
+            oldest_fetched_at: stats.oldest_fetched_at.map(|at| at.to_rfc3339()),
+            newest_fetched_at: stats.newest_fetched_at.map(|at| at.to_rfc3339()),
+        }
+    }
+}
+
+#[derive(Deserialize, Debug)]
+pub struct HttpCachePurgeParams {
+    /// Source to purge, every source if absent
+    pub source: Option<HttpCacheSourceResponse>,
+}
+
+#[derive(Serialize, Debug, ToSchema)]
+pub struct HttpCachePurgeResponse {
+    pub purged: u64,
+}
@@ -2,14 +2,26 @@ use super::controller::*;
 use super::dto::*;
 use crate::application::error::{AppError, InfraError};
 use crate::application::use_case::{
-    MockEnqueueCardMarketIdUpdateUseCase, MockImportPriceUseCase, MockStatsUseCase,
+    MockEnqueueCardMarketIdUpdateUseCase, MockGetUnresolvedCardsUseCase,
+    MockGetWorkersStatusUseCase, MockHttpCacheUseCase, MockImportPriceUseCase,
+    MockReviewPriceAnomalyUseCase, MockScheduleCardPricesRefreshUseCase,
+    MockScryfallCatalogUseCase, MockStatsUseCase,
 };
+use crate::domain::card::CardId;
+use crate::domain::enrichment_job::{EnrichmentKind, EnrichmentOutcome, UnresolvedCard};
 use crate::domain::error::FunctionalError;
+use crate::domain::http_cache::{HttpCacheSource, HttpCacheStats};
+use crate::domain::language_code::LanguageCode;
+use crate::domain::price_anomaly::{PriceAnomaly, PriceAnomalyKind};
+use crate::domain::scryfall_card::ScryfallCatalogImport;
+use crate::domain::set_name::SetCode;
 use crate::domain::stats::Stats;
+use crate::domain::worker_status::{EnrichmentQueueDepth, WorkerActivity, WorkerStatus};
 use crate::infrastructure::AppState;
 use axum::Json;
-use axum::extract::State;
+use axum::extract::{Path, Query, State};
 use axum::http::StatusCode;
+use chrono::{NaiveDate, TimeZone, Utc};
 use std::sync::Arc;
 
 // --- Stats ---
@@ -377,3 +389,432 @@ async fn test_update_gatherer_ids_returns_error_on_repository_error() {
         _ => panic!("Expected RepositoryError"),
     }
 }
+
+// --- Price anomalies ---
+
+fn anomaly_date() -> NaiveDate {
+    NaiveDate::from_ymd_opt(2025, 3, 1).unwrap()
+}
+
+fn review_state(mock: MockReviewPriceAnomalyUseCase) -> AppState {
+    AppState::for_testing_with_review_price_anomaly(
+        Arc::new(MockStatsUseCase::new()),
+        Arc::new(mock),
+    )
+}
+
+#[test]
+fn price_anomaly_response_from_price_anomaly() {
+    let response: PriceAnomalyResponse = PriceAnomaly {
+        id_product: 42,
+        date: anomaly_date(),
+        foil: true,
+        kind: PriceAnomalyKind::Zero,
+        reference: 1500,
+        observed: 0,
+        detected_at: Utc.with_ymd_and_hms(2025, 3, 1, 12, 0, 0).unwrap(),
+    }
+    .into();
+
+    assert_eq!(response.id_product, 42);
+    assert_eq!(response.date, "2025-03-01");
+    assert!(response.foil);
+    assert_eq!(response.kind, PriceAnomalyKindResponse::Zero);
+    assert_eq!(response.reference, 1500);
+    assert_eq!(response.observed, 0);
+    assert_eq!(response.detected_at, "2025-03-01T12:00:00+00:00");
+}
+
+#[tokio::test]
+async fn get_price_anomalies_returns_pending_anomalies() {
+    let mut mock = MockReviewPriceAnomalyUseCase::new();
+    mock.expect_get_pending_anomalies().times(1).returning(|| {
+        Box::pin(async {
+            Ok(vec![PriceAnomaly {
+                id_product: 42,
+                date: anomaly_date(),
+                foil: false,
+                kind: PriceAnomalyKind::Spike,
+                reference: 1000,
+                observed: 100_000,
+                detected_at: Utc::now(),
+            }])
+        })
+    });
+
+    let Json(body) = get_price_anomalies(State(review_state(mock)))
+        .await
+        .unwrap();
+
+    assert_eq!(body.len(), 1);
+    assert_eq!(body[0].id_product, 42);
+    assert_eq!(body[0].kind, PriceAnomalyKindResponse::Spike);
+}
+
+#[tokio::test]
+async fn confirm_price_anomaly_returns_no_content() {
+    let mut mock = MockReviewPriceAnomalyUseCase::new();
+    mock.expect_confirm()
+        .withf(|id_product, date, foil| *id_product == 42 && *date == anomaly_date() && *foil)
+        .times(1)
+        .returning(|_, _, _| Box::pin(async { Ok(()) }));
+
+    let result =
+        confirm_price_anomaly(State(review_state(mock)), Path((42, anomaly_date(), true))).await;
+
+    assert_eq!(result.unwrap(), StatusCode::NO_CONTENT);
+}
+
+#[tokio::test]
+async fn confirm_price_anomaly_returns_error_when_nothing_pending() {
+    let mut mock = MockReviewPriceAnomalyUseCase::new();
+    mock.expect_confirm().times(1).returning(|_, _, _| {
+        Box::pin(async { Err(AppError::Functional(FunctionalError::PriceAnomalyNotFound)) })
+    });
+
+    let result =
+        confirm_price_anomaly(State(review_state(mock)), Path((42, anomaly_date(), true))).await;
+
+    assert!(matches!(
+        result,
+        Err(AppError::Functional(FunctionalError::PriceAnomalyNotFound))
+    ));
+}
+
+#[tokio::test]
+async fn reject_price_anomaly_returns_no_content() {
+    let mut mock = MockReviewPriceAnomalyUseCase::new();
+    mock.expect_reject()
+        .withf(|id_product, date, foil| *id_product == 42 && *date == anomaly_date() && *foil)
+        .times(1)
+        .returning(|_, _, _| Box::pin(async { Ok(()) }));
+
+    let result =
+        reject_price_anomaly(State(review_state(mock)), Path((42, anomaly_date(), true))).await;
+
+    assert_eq!(result.unwrap(), StatusCode::NO_CONTENT);
+}
+
+// --- Card prices refresh ---
+
+#[tokio::test]
+async fn get_card_prices_refresh_returns_last_refresh_time() {
+    let mut mock = MockScheduleCardPricesRefreshUseCase::new();
+    mock.expect_last_refreshed_at()
+        .times(1)
+        .return_const(Some(Utc.with_ymd_and_hms(2025, 3, 1, 12, 0, 0).unwrap()));
+    let state = AppState::for_testing_with_card_prices_refresh(
+        Arc::new(MockStatsUseCase::new()),
+        Arc::new(mock),
+    );
+
+    let Json(body) = get_card_prices_refresh(State(state)).await;
+
+    assert_eq!(
+        body.last_refreshed_at.as_deref(),
+        Some("2025-03-01T12:00:00+00:00")
+    );
+}
+
+#[tokio::test]
+async fn get_card_prices_refresh_returns_null_before_first_refresh() {
+    let mut mock = MockScheduleCardPricesRefreshUseCase::new();
+    mock.expect_last_refreshed_at().times(1).return_const(None);
+    let state = AppState::for_testing_with_card_prices_refresh(
+        Arc::new(MockStatsUseCase::new()),
+        Arc::new(mock),
+    );
+
+    let Json(body) = get_card_prices_refresh(State(state)).await;
+
+    assert!(body.last_refreshed_at.is_none());
+}
+
+// --- Scryfall catalogue ---
+
+#[tokio::test]
+async fn import_scryfall_catalog_returns_import_summary() {
+    let mut mock = MockScryfallCatalogUseCase::new();
+    mock.expect_import_catalog().times(1).returning(|| {
+        Box::pin(async {
+            Ok(ScryfallCatalogImport {
+                card_count: 110_000,
+                resolved: 12,
+            })
+        })
+    });
+    let state = AppState::for_testing_with_scryfall_catalog(
+        Arc::new(MockStatsUseCase::new()),
+        Arc::new(mock),
+    );
+
+    let Json(body) = import_scryfall_catalog(State(state)).await.unwrap();
+
+    assert_eq!(body.card_count, 110_000);
+    assert_eq!(body.resolved, 12);
+}
+
+#[tokio::test]
+async fn import_scryfall_catalog_returns_error_when_download_fails() {
+    let mut mock = MockScryfallCatalogUseCase::new();
+    mock.expect_import_catalog().times(1).returning(|| {
+        Box::pin(async {
+            Err(AppError::Infra(InfraError::CallError(
+                "503 Service Unavailable".to_string(),
+            )))
+        })
+    });
+    let state = AppState::for_testing_with_scryfall_catalog(
+        Arc::new(MockStatsUseCase::new()),
+        Arc::new(mock),
+    );
+
+    let result = import_scryfall_catalog(State(state)).await;
+
+    assert!(matches!(
+        result,
+        Err(AppError::Infra(InfraError::CallError(_)))
+    ));
+}
+
+// --- Unresolved cards ---
+
+fn unresolved_card() -> UnresolvedCard {
+    UnresolvedCard {
+        card_id: CardId::new(SetCode::new("FDN"), "87", LanguageCode::FR, false),
+        name: "Goblin Boarders".to_string(),
+        kind: EnrichmentKind::CardMarketId,
+        attempts: 3,
+        last_attempt_at: Utc.with_ymd_and_hms(2025, 3, 1, 12, 0, 0).unwrap(),
+        outcome: EnrichmentOutcome::NotFound("No Cardmarket product".to_string()),
+        next_attempt_at: Some(Utc.with_ymd_and_hms(2025, 3, 5, 12, 0, 0).unwrap()),
+    }
+}
+
+#[test]
+fn unresolved_card_response_from_unresolved_card() {
+    let response: UnresolvedCardResponse = unresolved_card().into();
+
+    assert_eq!(response.set_code, "FDN");
+    assert_eq!(response.collector_number, "87");
+    assert_eq!(response.language_code, "FR");
+    assert!(!response.foil);
+    assert_eq!(response.kind, EnrichmentKindResponse::CardmarketId);
+    assert_eq!(response.attempts, 3);
+    assert_eq!(response.last_attempt_at, "2025-03-01T12:00:00+00:00");
+    assert_eq!(response.outcome, EnrichmentOutcomeResponse::NotFound);
+    assert_eq!(response.reason.as_deref(), Some("No Cardmarket product"));
+    assert_eq!(
+        response.next_attempt_at.as_deref(),
+        Some("2025-03-05T12:00:00+00:00")
+    );
+}
+
+#[tokio::test]
+async fn get_unresolved_cards_returns_the_cards_and_their_reason() {
+    let mut mock = MockGetUnresolvedCardsUseCase::new();
+    mock.expect_get_unresolved_cards()
+        .times(1)
+        .returning(|| Box::pin(async { Ok(vec![unresolved_card()]) }));
+    let state = AppState::for_testing_with_unresolved_cards(
+        Arc::new(MockStatsUseCase::new()),
+        Arc::new(mock),
+    );
+
+    let Json(body) = get_unresolved_cards(State(state)).await.unwrap();
+
+    assert_eq!(body.len(), 1);
+    assert_eq!(body[0].name, "Goblin Boarders");
+    assert_eq!(body[0].reason.as_deref(), Some("No Cardmarket product"));
+}
+
+#[tokio::test]
+async fn get_unresolved_cards_returns_error_on_repository_error() {
+    let mut mock = MockGetUnresolvedCardsUseCase::new();
+    mock.expect_get_unresolved_cards().times(1).returning(|| {
+        Box::pin(async {
+            Err(AppError::Infra(InfraError::RepositoryError(
+                "DB error".to_string(),
+            )))
+        })
+    });
+    let state = AppState::for_testing_with_unresolved_cards(
+        Arc::new(MockStatsUseCase::new()),
+        Arc::new(mock),
+    );
+
+    let result = get_unresolved_cards(State(state)).await;
+
+    assert!(matches!(
+        result,
+        Err(AppError::Infra(InfraError::RepositoryError(_)))
+    ));
+}
+
+// --- Workers ---
+
+fn worker_status() -> WorkerStatus {
+    WorkerStatus {
+        kind: EnrichmentKind::GathererId,
+        queue: EnrichmentQueueDepth {
+            pending: 12,
+            running: 3,
+            dead: 1,
+        },
+        activity: WorkerActivity {
+            alive: false,
+            processed: 40,
+            failed: 2,
+            last_error: Some("Gatherer error".to_string()),
+            last_error_at: Some(Utc.with_ymd_and_hms(2025, 3, 1, 12, 0, 0).unwrap()),
+            last_processed: Some(CardId::new(
+                SetCode::new("FDN"),
+                "87",
+                LanguageCode::FR,
+                true,
+            )),
+            last_processed_at: Some(Utc.with_ymd_and_hms(2025, 3, 1, 12, 0, 0).unwrap()),
+        },
+    }
+}
+
+#[test]
+fn worker_status_response_from_worker_status() {
+    let response: WorkerStatusResponse = worker_status().into();
+
+    assert_eq!(response.kind, EnrichmentKindResponse::GathererId);
+    assert!(!response.alive);
+    assert_eq!(response.pending, 12);
+    assert_eq!(response.running, 3);
+    assert_eq!(response.dead, 1);
+    assert_eq!(response.dedup_set_size, 16);
+    assert_eq!(response.processed, 40);
+    assert_eq!(response.failed, 2);
+    assert_eq!(response.last_error.as_deref(), Some("Gatherer error"));
+    assert_eq!(
+        response.last_error_at.as_deref(),
+        Some("2025-03-01T12:00:00+00:00")
+    );
+    assert_eq!(response.last_processed.as_deref(), Some("FDN/87/FR/foil"));
+}
+
+#[tokio::test]
+async fn get_workers_returns_the_status_of_each_worker() {
+    let mut mock = MockGetWorkersStatusUseCase::new();
+    mock.expect_get_workers_status()
+        .times(1)
+        .returning(|| Box::pin(async { Ok(vec![worker_status()]) }));
+    let state = AppState::for_testing_with_workers_status(
+        Arc::new(MockStatsUseCase::new()),
+        Arc::new(mock),
+    );
+
+    let Json(body) = get_workers(State(state)).await.unwrap();
+
+    assert_eq!(body.len(), 1);
+    assert_eq!(body[0].pending, 12);
+    assert!(!body[0].alive);
+}
+
+#[tokio::test]
+async fn get_workers_returns_error_on_repository_error() {
+    let mut mock = MockGetWorkersStatusUseCase::new();
+    mock.expect_get_workers_status().times(1).returning(|| {
+        Box::pin(async {
+            Err(AppError::Infra(InfraError::RepositoryError(
+                "DB error".to_string(),
+            )))
+        })
+    });
+    let state = AppState::for_testing_with_workers_status(
+        Arc::new(MockStatsUseCase::new()),
+        Arc::new(mock),
+    );
+
+    let result = get_workers(State(state)).await;
+
+    assert!(result.is_err());
+}
+
+// --- HTTP cache ---
+
+fn http_cache_state(mock: MockHttpCacheUseCase) -> AppState {
+    AppState::for_testing_with_http_cache(Arc::new(MockStatsUseCase::new()), Arc::new(mock))
+}
+
+#[test]
+fn http_cache_stats_response_from_http_cache_stats() {
+    let response: HttpCacheStatsResponse = HttpCacheStats {
+        source: HttpCacheSource::EdhRec,
+        entries: 4,
+        size_bytes: 2048,
+        oldest_fetched_at: Some(Utc.with_ymd_and_hms(2025, 3, 1, 12, 0, 0).unwrap()),
+        newest_fetched_at: None,
+    }
+    .into();
+
+    assert_eq!(response.source, HttpCacheSourceResponse::Edhrec);
+    assert_eq!(response.entries, 4);
+    assert_eq!(response.size_bytes, 2048);
+    assert_eq!(
+        response.oldest_fetched_at.as_deref(),
+        Some("2025-03-01T12:00:00+00:00")
+    );
+    assert_eq!(response.newest_fetched_at, None);
+}
+
+#[tokio::test]
+async fn get_http_cache_returns_the_stats_of_each_source() {
+    let mut mock = MockHttpCacheUseCase::new();
+    mock.expect_get_stats().times(1).returning(|| {
+        Box::pin(async {
+            Ok(vec![
+                HttpCacheStats::empty(HttpCacheSource::Scryfall),
+                HttpCacheStats::empty(HttpCacheSource::Gatherer),
+            ])
+        })
+    });
+
+    let Json(body) = get_http_cache(State(http_cache_state(mock))).await.unwrap();
+
+    assert_eq!(body.len(), 2);
+    assert_eq!(body[1].source, HttpCacheSourceResponse::Gatherer);
+}
+
+#[tokio::test]
+async fn purge_http_cache_purges_the_given_source() {
+    let mut mock = MockHttpCacheUseCase::new();
+    mock.expect_purge()
+        .withf(|source| *source == Some(HttpCacheSource::Scryfall))
+        .times(1)
+        .returning(|_| Box::pin(async { Ok(7) }));
+
+    let Json(body) = purge_http_cache(
+        State(http_cache_state(mock)),
+        Query(HttpCachePurgeParams {
+            source: Some(HttpCacheSourceResponse::Scryfall),
+        }),
+    )
+    .await
+    .unwrap();
+
+    assert_eq!(body.purged, 7);
+}
+
+#[tokio::test]
+async fn purge_http_cache_purges_every_source_without_source() {
+    let mut mock = MockHttpCacheUseCase::new();
+    mock.expect_purge()
+        .withf(|source| source.is_none())
+        .times(1)
+        .returning(|_| Box::pin(async { Ok(0) }));
+
+    let Json(body) = purge_http_cache(
+        State(http_cache_state(mock)),
+        Query(HttpCachePurgeParams { source: None }),
+    )
+    .await
+    .unwrap();
+
+    assert_eq!(body.purged, 0);
+}
@@ -1,14 +1,31 @@
 use super::autocomplete::dto::UserSuggestionResponse;
+use super::binder::dto::{BinderResponse, CreateBinderRequest, UpdateBinderRequest};
 use super::card::dto::{
-    CardOfferResponse, CardOffersSortByParam, PaginatedCardOffersResponse,
-    PriceHistoryEntryResponse,
+    CardEdhRecResponse, CardOfferResponse, CardOffersSortByParam, PaginatedCardOffersResponse,
+    PriceHistoryEntryResponse, PriceHistoryGranularityParam,
 };
 use super::collection::dto::{
-    CollectionCardResponse, CollectionStatsResponse, MessageResponse, PaginatedCollectionResponse,
-    PriceGuideResponse, RarityCodeParam, SetInfoResponse, SortByParam, SortDirParam,
+    CardConditionParam, CollectionCardResponse, CollectionItemResponse, CollectionStatsResponse,
+    ColorParam, CommanderSynergyResponse, CreateCollectionEntryRequest, MessageResponse,
+    MissingRecommendationResponse, OwnedPrintingResponse, OwnedRecommendationResponse,
+    PaginatedCollectionResponse, PriceGuideResponse, RarityCodeParam, RecommendationListResponse,
+    SetInfoResponse, SortByParam, SortDirParam, UpdateCollectionEntryRequest,
+};
+use super::maintenance::dto::{
+    CardPricesRefreshResponse, EnqueueResponse, EnrichmentKindResponse, EnrichmentOutcomeResponse,
+    HttpCachePurgeResponse, HttpCacheSourceResponse, HttpCacheStatsResponse,
+    PriceAnomalyKindResponse, PriceAnomalyResponse, ScryfallCatalogImportResponse, StatsResponse,
+    UnresolvedCardResponse, WorkerStatusResponse,
+};
+use super::search::dto::{OracleCardResponse, PaginatedOracleCardsResponse};
+use super::set::dto::{SetPriceIndexEntryResponse, SetPriceIndexResponse};
+use super::trade::dto::{
+    CreateTradeRequest, RateTradeRequest, TradeMatchCardResponse, TradeMatchResponse,
+};
+use super::wishlist::dto::{
+    CreateWishlistEntryRequest, FinishParam, UpdateWishlistEntryRequest, WishPriorityParam,
+    WishlistEntryResponse, WishlistImportResponse,
 };
-use super::maintenance::dto::{EnqueueResponse, StatsResponse};
-use super::trade::dto::{CreateTradeRequest, RateTradeRequest};
 use utoipa::OpenApi;
 
 #[derive(OpenApi)]
@@ -18,40 +35,103 @@ use utoipa::OpenApi;
         super::collection::controller::import_cards,
         super::collection::controller::get_collection_stats,
         super::collection::controller::get_collection_price_history,
+        super::collection::controller::get_commander_synergy,
+        super::collection::controller::add_collection_entry,
+        super::collection::controller::update_collection_entry,
+        super::collection::controller::remove_collection_entry,
         super::search::controller::search_cards,
-        super::card::controller::get_card_info,
+        super::search::controller::search_oracle_cards,
+        super::set::controller::get_set_price_index,
+        super::card::controller::get_card_edhrec,
         super::card::controller::get_card_price_history,
         super::card::controller::get_card_offers,
         super::maintenance::controller::get_stats,
         super::maintenance::controller::trigger_price_update,
         super::maintenance::controller::update_cardmarket_ids,
+        super::maintenance::controller::get_price_anomalies,
+        super::maintenance::controller::confirm_price_anomaly,
+        super::maintenance::controller::reject_price_anomaly,
+        super::maintenance::controller::get_card_prices_refresh,
+        super::maintenance::controller::import_scryfall_catalog,
+        super::maintenance::controller::get_unresolved_cards,
+        super::maintenance::controller::get_workers,
+        super::maintenance::controller::get_http_cache,
+        super::maintenance::controller::purge_http_cache,
         super::user::controller::register,
         super::trade::controller::create_trade,
         super::trade::controller::accept_trade,
         super::trade::controller::abandon_trade,
         super::trade::controller::confirm_trade,
         super::trade::controller::rate_trade,
+        super::trade::controller::get_trade_matches,
         super::autocomplete::controller::autocomplete_user,
+        super::binder::controller::list_binders,
+        super::binder::controller::create_binder,
+        super::binder::controller::update_binder,
+        super::binder::controller::delete_binder,
+        super::wishlist::controller::list_wishlist_entries,
+        super::wishlist::controller::add_wishlist_entry,
+        super::wishlist::controller::update_wishlist_entry,
+        super::wishlist::controller::remove_wishlist_entry,
+        super::wishlist::controller::import_decklist,
     ),
     components(schemas(
         PriceGuideResponse,
         CollectionCardResponse,
         MessageResponse,
         PaginatedCollectionResponse,
+        OracleCardResponse,
+        PaginatedOracleCardsResponse,
         PriceHistoryEntryResponse,
+        PriceHistoryGranularityParam,
         SortByParam,
         SortDirParam,
         RarityCodeParam,
+        ColorParam,
         CollectionStatsResponse,
+        CommanderSynergyResponse,
+        OwnedRecommendationResponse,
+        OwnedPrintingResponse,
+        MissingRecommendationResponse,
+        RecommendationListResponse,
+        CreateCollectionEntryRequest,
+        UpdateCollectionEntryRequest,
+        CollectionItemResponse,
+        CardConditionParam,
         SetInfoResponse,
+        SetPriceIndexResponse,
+        SetPriceIndexEntryResponse,
         StatsResponse,
         EnqueueResponse,
+        PriceAnomalyResponse,
+        PriceAnomalyKindResponse,
+        CardPricesRefreshResponse,
+        ScryfallCatalogImportResponse,
+        UnresolvedCardResponse,
+        EnrichmentKindResponse,
+        EnrichmentOutcomeResponse,
+        WorkerStatusResponse,
+        HttpCacheSourceResponse,
+        HttpCacheStatsResponse,
+        HttpCachePurgeResponse,
         CreateTradeRequest,
         RateTradeRequest,
+        TradeMatchResponse,
+        TradeMatchCardResponse,
+        CardEdhRecResponse,
         CardOfferResponse,
         PaginatedCardOffersResponse,
         CardOffersSortByParam,
         UserSuggestionResponse,
+        BinderResponse,
+        CreateBinderRequest,
+        UpdateBinderRequest,
+        WishlistEntryResponse,
+        CreateWishlistEntryRequest,
+        UpdateWishlistEntryRequest,
+        WishlistImportResponse,
+        FinishParam,
+        WishPriorityParam,
     )),
     modifiers(&SecurityAddon),
     info(
@@ -61,13 +141,16 @@ use utoipa::OpenApi;
         license(name = "MIT", url = "https://opensource.org/licenses/MIT")
     ),
     tags(
-        (name = "card", description = "Single card lookup, price history and sale offers (authentication required)"),
+        (name = "card", description = "Single card lookup, EDHREC statistics, price history and sale offers (authentication required)"),
         (name = "collection", description = "Player's private collection (authentication required, no public catalog)"),
         (name = "search", description = "Public card search across all users' collections (authentication required)"),
+        (name = "set", description = "Set-level price index and the player's owned share (authentication required)"),
         (name = "maintenance", description = "Maintenance operations (public)"),
         (name = "auth", description = "Authentication and user registration (authentication required)"),
         (name = "trades", description = "Trade requests between two collectors (authentication required)"),
         (name = "autocomplete", description = "Public username autocomplete (no authentication)"),
+        (name = "binders", description = "Named binders splitting the player's collection, tradable or not (authentication required)"),
+        (name = "wishlist", description = "Cards the player wants, with the matching offers of other users (authentication required)"),
     )
 )]
 pub struct ApiDoc;
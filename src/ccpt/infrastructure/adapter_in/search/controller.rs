@@ -1,4 +1,4 @@
-use super::dto::SearchParams;
+use super::dto::{OracleCardResponse, PaginatedOracleCardsResponse, SearchParams};
 use crate::application::error::AppError;
 use crate::domain::collection::{CollectionQuery, SearchQuery};
 use crate::infrastructure::AppState;
@@ -11,38 +11,16 @@ use axum::routing::get;
 use axum_extra::extract::Query;
 
 pub fn create_search_router() -> axum::Router<AppState> {
-    axum::Router::new().nest("/card", axum::Router::new().route("/", get(search_cards)))
+    axum::Router::new()
+        .nest("/card", axum::Router::new().route("/", get(search_cards)))
+        .nest(
+            "/oracle-card",
+            axum::Router::new().route("/", get(search_oracle_cards)),
+        )
 }
 
-#[utoipa::path(
-    get,
-    path = "/search/card",
-    params(
-        ("page" = Option<u32>, Query, description = "Page number (starts at 0)"),
-        ("page_size" = Option<u32>, Query, description = "Items per page (max 100)"),
-        ("sort_by" = Option<super::dto::SortByParam>, Query, description = "Sort field"),
-        ("sort_dir" = Option<super::dto::SortDirParam>, Query, description = "Sort direction"),
-        ("q" = Option<String>, Query, description = "Fuzzy search on card name or set"),
-        ("rarity" = Option<Vec<super::dto::RarityCodeParam>>, Query, description = "Rarity codes, repeated for multiple values (e.g. rarity=C&rarity=U)"),
-        ("sets" = Option<String>, Query, description = "Comma-separated set codes"),
-        ("price_min" = Option<u32>, Query, description = "Minimum trend price in cents"),
-        ("price_max" = Option<u32>, Query, description = "Maximum trend price in cents"),
-        ("player_username" = Option<String>, Query, description = "Exact username of the owner to filter by (case-insensitive, no partial match)"),
-    ),
-    responses(
-        (status = 200, description = "Paginated card search results", body = PaginatedCollectionResponse),
-        (status = 401, description = "Missing or invalid token"),
-    ),
-    security(("bearer_auth" = [])),
-    tag = "search",
-)]
-pub(crate) async fn search_cards(
-    AuthenticatedUser(_user): AuthenticatedUser,
-    State(state): State<AppState>,
-    Query(params): Query<SearchParams>,
-) -> Result<axum::Json<PaginatedCollectionResponse>, AppError> {
-    let page_size = params.page_size.min(state.max_page_size);
-
+/// Query shared by `/search/card` and `/search/oracle-card`.
+fn to_search_query(params: SearchParams, max_page_size: u32) -> SearchQuery {
     let rarity = params.rarity.into_iter().map(Into::into).collect();
 
     let sets = params
@@ -62,10 +40,10 @@ pub(crate) async fn search_cards(
         .filter(|s| !s.is_empty())
         .map(str::to_string);
 
-    let query = SearchQuery {
+    SearchQuery {
         collection_query: CollectionQuery {
             page: params.page,
-            page_size,
+            page_size: params.page_size.min(max_page_size),
             sort_by: params.sort_by.into(),
             sort_dir: params.sort_dir.into(),
             search_query: params.q,
@@ -73,9 +51,51 @@ pub(crate) async fn search_cards(
             sets,
             price_min: params.price_min,
             price_max: params.price_max,
+            colors: params.color.into_iter().map(Into::into).collect(),
+            type_line: params
+                .type_line
+                .map(|t| t.trim().to_string())
+                .filter(|t| !t.is_empty()),
+            oracle_id: params.oracle_id,
+            binder_id: None,
+            tags: Vec::new(),
+            location: None,
         },
         player_username,
-    };
+    }
+}
+
+#[utoipa::path(
+    get,
+    path = "/search/card",
+    params(
+        ("page" = Option<u32>, Query, description = "Page number (starts at 0)"),
+        ("page_size" = Option<u32>, Query, description = "Items per page (max 100)"),
+        ("sort_by" = Option<super::dto::SortByParam>, Query, description = "Sort field"),
+        ("sort_dir" = Option<super::dto::SortDirParam>, Query, description = "Sort direction"),
+        ("q" = Option<String>, Query, description = "Fuzzy search on card name or set"),
+        ("rarity" = Option<Vec<super::dto::RarityCodeParam>>, Query, description = "Rarity codes, repeated for multiple values (e.g. rarity=C&rarity=U)"),
+        ("sets" = Option<String>, Query, description = "Comma-separated set codes"),
+        ("price_min" = Option<u32>, Query, description = "Minimum trend price in cents"),
+        ("price_max" = Option<u32>, Query, description = "Maximum trend price in cents"),
+        ("color" = Option<Vec<super::dto::ColorParam>>, Query, description = "Colours the card must all have, repeated for multiple values (e.g. color=W&color=U)"),
+        ("type_line" = Option<String>, Query, description = "Part of the type line (e.g. Creature, Goblin)"),
+        ("oracle_id" = Option<uuid::Uuid>, Query, description = "Only the printings of this oracle card"),
+        ("player_username" = Option<String>, Query, description = "Exact username of the owner to filter by (case-insensitive, no partial match)"),
+    ),
+    responses(
+        (status = 200, description = "Paginated card search results", body = PaginatedCollectionResponse),
+        (status = 401, description = "Missing or invalid token"),
+    ),
+    security(("bearer_auth" = [])),
+    tag = "search",
+)]
+pub(crate) async fn search_cards(
+    AuthenticatedUser(_user): AuthenticatedUser,
+    State(state): State<AppState>,
+    Query(params): Query<SearchParams>,
+) -> Result<axum::Json<PaginatedCollectionResponse>, AppError> {
+    let query = to_search_query(params, state.max_page_size);
 
     let result = state.search_cards_use_case.search_cards(query).await?;
 
@@ -90,3 +110,50 @@ pub(crate) async fn search_cards(
         page_size: result.page_size,
     }))
 }
+
+#[utoipa::path(
+    get,
+    path = "/search/oracle-card",
+    params(
+        ("page" = Option<u32>, Query, description = "Page number (starts at 0)"),
+        ("page_size" = Option<u32>, Query, description = "Items per page (max 100)"),
+        ("sort_dir" = Option<super::dto::SortDirParam>, Query, description = "Direction of the sort on the cheapest trend of the printings"),
+        ("q" = Option<String>, Query, description = "Fuzzy search on card name or set"),
+        ("rarity" = Option<Vec<super::dto::RarityCodeParam>>, Query, description = "Rarity codes, repeated for multiple values (e.g. rarity=C&rarity=U)"),
+        ("sets" = Option<String>, Query, description = "Comma-separated set codes"),
+        ("price_min" = Option<u32>, Query, description = "Minimum trend price in cents"),
+        ("price_max" = Option<u32>, Query, description = "Maximum trend price in cents"),
+        ("color" = Option<Vec<super::dto::ColorParam>>, Query, description = "Colours the card must all have, repeated for multiple values (e.g. color=W&color=U)"),
+        ("type_line" = Option<String>, Query, description = "Part of the type line (e.g. Creature, Goblin)"),
+        ("player_username" = Option<String>, Query, description = "Exact username of the owner to filter by (case-insensitive, no partial match)"),
+    ),
+    responses(
+        (status = 200, description = "Paginated search results, the printings being grouped by oracle card", body = PaginatedOracleCardsResponse),
+        (status = 401, description = "Missing or invalid token"),
+    ),
+    security(("bearer_auth" = [])),
+    tag = "search",
+)]
+pub(crate) async fn search_oracle_cards(
+    AuthenticatedUser(_user): AuthenticatedUser,
+    State(state): State<AppState>,
+    Query(params): Query<SearchParams>,
+) -> Result<axum::Json<PaginatedOracleCardsResponse>, AppError> {
+    let query = to_search_query(params, state.max_page_size);
+
+    let result = state
+        .search_cards_use_case
+        .search_oracle_cards(query)
+        .await?;
+
+    Ok(axum::Json(PaginatedOracleCardsResponse {
+        items: result
+            .items
+            .into_iter()
+            .map(OracleCardResponse::from)
+            .collect(),
+        total: result.total,
+        page: result.page,
+        page_size: result.page_size,
+    }))
+}
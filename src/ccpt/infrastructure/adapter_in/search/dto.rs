@@ -1,8 +1,12 @@
+use crate::domain::card_metadata::Color;
+use crate::domain::oracle_card::OracleCard;
 pub(crate) use crate::infrastructure::adapter_in::collection::dto::{
-    RarityCodeParam, SortByParam, SortDirParam, default_page_size,
+    ColorParam, RarityCodeParam, SortByParam, SortDirParam, default_page_size,
 };
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 use ts_rs::TS;
+use utoipa::ToSchema;
+use uuid::Uuid;
 
 #[derive(Deserialize, TS)]
 #[ts(export, export_to = "SearchParams.ts")]
@@ -29,6 +33,15 @@ pub(crate) struct SearchParams {
     /// Maximum trend price in cents
     #[ts(optional)]
     pub(crate) price_max: Option<u32>,
+    /// Colours the card must all have, repeated for multiple values (e.g. `?color=W&color=U`)
+    #[serde(default)]
+    pub(crate) color: Vec<ColorParam>,
+    /// Part of the type line (e.g. `Creature`, `Goblin`)
+    #[ts(optional)]
+    pub(crate) type_line: Option<String>,
+    /// Only the printings of this oracle card
+    #[ts(optional, type = "string")]
+    pub(crate) oracle_id: Option<Uuid>,
     /// Exact username of the owner to filter by (case-insensitive, no partial match)
     #[ts(optional)]
     pub(crate) player_username: Option<String>,
@@ -46,7 +59,70 @@ impl Default for SearchParams {
             sets: None,
             price_min: None,
             price_max: None,
+            color: Vec::new(),
+            type_line: None,
+            oracle_id: None,
             player_username: None,
         }
     }
 }
+
+/// Owned printings of a card grouped under their Scryfall `oracle_id`, shown through one of
+/// them (English and non-foil first).
+#[derive(Serialize, Debug, TS, ToSchema)]
+#[serde(rename = "OracleCard")]
+#[ts(export, export_to = "OracleCard.ts")]
+pub struct OracleCardResponse {
+    pub oracle_id: String,
+    pub name: String,
+    pub mana_cost: Option<String>,
+    pub type_line: Option<String>,
+    /// Colour symbols in the WUBRG order, empty for a colourless card.
+    pub colors: Vec<String>,
+    pub color_identity: Vec<String>,
+    pub oracle_text: Option<String>,
+    pub image_small_url: Option<String>,
+    pub image_normal_url: Option<String>,
+    pub image_large_url: Option<String>,
+    /// Number of distinct printings (set, collector number, language, foil) owned by someone
+    pub printing_count: u64,
+    /// Number of distinct users owning at least one printing
+    pub owner_count: u64,
+    /// Cheapest trend among the printings, in cents
+    pub lowest_trend: Option<u32>,
+}
+
+impl From<OracleCard> for OracleCardResponse {
+    fn from(card: OracleCard) -> Self {
+        Self {
+            oracle_id: card.oracle_id.to_string(),
+            name: card.name,
+            mana_cost: card.metadata.mana_cost,
+            type_line: card.metadata.type_line,
+            colors: card.metadata.colors.iter().map(Color::to_string).collect(),
+            color_identity: card
+                .metadata
+                .color_identity
+                .iter()
+                .map(Color::to_string)
+                .collect(),
+            oracle_text: card.metadata.oracle_text,
+            image_small_url: card.metadata.image_small_url,
+            image_normal_url: card.metadata.image_normal_url,
+            image_large_url: card.metadata.image_large_url,
+            printing_count: card.printing_count,
+            owner_count: card.owner_count,
+            lowest_trend: card.lowest_trend.value,
+        }
+    }
+}
+
+#[derive(Serialize, Debug, TS, ToSchema)]
+#[serde(rename = "PaginatedOracleCards")]
+#[ts(export, export_to = "PaginatedOracleCards.ts")]
+pub struct PaginatedOracleCardsResponse {
+    pub items: Vec<OracleCardResponse>,
+    pub total: u64,
+    pub page: u32,
+    pub page_size: u32,
+}
@@ -3,8 +3,11 @@ use super::dto::*;
 use crate::application::error::{AppError, InfraError};
 use crate::application::use_case::MockSearchCardsUseCase;
 use crate::domain::card::{Card, CollectionEntry};
+use crate::domain::card_metadata::{CardMetadata, Color};
 use crate::domain::collection::{CollectionSortField, PaginatedCollection, SortDirection};
 use crate::domain::language_code::LanguageCode;
+use crate::domain::oracle_card::{OracleCard, PaginatedOracleCards};
+use crate::domain::price::Price;
 use crate::domain::rarity_code::RarityCode;
 use crate::domain::user::User;
 use crate::infrastructure::AppState;
@@ -12,6 +15,7 @@ use crate::infrastructure::adapter_in::auth_extractor::AuthenticatedUser;
 use axum::extract::State;
 use axum_extra::extract::Query;
 use std::sync::Arc;
+use uuid::Uuid;
 
 fn make_app_state_with_search(mock: MockSearchCardsUseCase) -> AppState {
     AppState {
@@ -518,3 +522,114 @@ async fn search_cards_defaults_player_username_to_none_when_absent() {
 
     assert!(result.is_ok());
 }
+
+#[tokio::test]
+async fn search_cards_passes_oracle_id_to_use_case() {
+    let oracle_id = Uuid::from_u128(42);
+    let mut mock = MockSearchCardsUseCase::new();
+    mock.expect_search_cards()
+        .withf(move |q| q.collection_query.oracle_id == Some(oracle_id))
+        .returning(|_| Box::pin(async { Ok(make_paginated(vec![], 0, 20)) }));
+
+    let app_state = make_app_state_with_search(mock);
+    let params = SearchParams {
+        oracle_id: Some(oracle_id),
+        ..Default::default()
+    };
+
+    let result = search_cards(
+        AuthenticatedUser(User::for_testing()),
+        State(app_state),
+        Query(params),
+    )
+    .await;
+
+    assert!(result.is_ok());
+}
+
+#[tokio::test]
+async fn search_oracle_cards_maps_grouped_cards() {
+    let oracle_id = Uuid::from_u128(42);
+    let mut mock = MockSearchCardsUseCase::new();
+    mock.expect_search_oracle_cards()
+        .withf(|q| q.player_username == Some("Alice".to_string()))
+        .returning(move |q| {
+            Box::pin(async move {
+                Ok(PaginatedOracleCards {
+                    items: vec![OracleCard {
+                        oracle_id,
+                        name: "Lightning Bolt".to_string(),
+                        metadata: CardMetadata {
+                            oracle_id: Some(oracle_id),
+                            mana_cost: Some("{R}".to_string()),
+                            colors: vec![Color::R],
+                            color_identity: vec![Color::R],
+                            ..CardMetadata::default()
+                        },
+                        printing_count: 3,
+                        owner_count: 2,
+                        lowest_trend: Price { value: Some(150) },
+                    }],
+                    total: 1,
+                    page: q.collection_query.page,
+                    page_size: q.collection_query.page_size,
+                })
+            })
+        });
+
+    let app_state = make_app_state_with_search(mock);
+    let params = SearchParams {
+        player_username: Some(" Alice ".to_string()),
+        ..Default::default()
+    };
+
+    let axum::Json(response) = search_oracle_cards(
+        AuthenticatedUser(User::for_testing()),
+        State(app_state),
+        Query(params),
+    )
+    .await
+    .unwrap();
+
+    assert_eq!(response.total, 1);
+    let card = &response.items[0];
+    assert_eq!(card.oracle_id, oracle_id.to_string());
+    assert_eq!(card.name, "Lightning Bolt");
+    assert_eq!(card.mana_cost.as_deref(), Some("{R}"));
+    assert_eq!(card.colors, vec!["R".to_string()]);
+    assert_eq!(card.printing_count, 3);
+    assert_eq!(card.owner_count, 2);
+    assert_eq!(card.lowest_trend, Some(150));
+}
+
+#[tokio::test]
+async fn search_oracle_cards_caps_page_size() {
+    let mut mock = MockSearchCardsUseCase::new();
+    mock.expect_search_oracle_cards()
+        .withf(|q| q.collection_query.page_size == 100)
+        .returning(|q| {
+            Box::pin(async move {
+                Ok(PaginatedOracleCards {
+                    items: vec![],
+                    total: 0,
+                    page: q.collection_query.page,
+                    page_size: q.collection_query.page_size,
+                })
+            })
+        });
+
+    let app_state = make_app_state_with_search(mock);
+    let params = SearchParams {
+        page_size: 500,
+        ..Default::default()
+    };
+
+    let result = search_oracle_cards(
+        AuthenticatedUser(User::for_testing()),
+        State(app_state),
+        Query(params),
+    )
+    .await;
+
+    assert_eq!(result.unwrap().0.page_size, 100);
+}
@@ -0,0 +1,44 @@
+use super::dto::{SetPriceIndexParams, SetPriceIndexResponse};
+use crate::application::error::AppError;
+use crate::domain::set_name::SetCode;
+use crate::infrastructure::AppState;
+use crate::infrastructure::adapter_in::auth_extractor::AuthenticatedUser;
+use axum::extract::{Path, Query, State};
+use axum::routing::get;
+
+pub fn create_set_router() -> axum::Router<AppState> {
+    axum::Router::new().route("/{set_code}/price-index", get(get_set_price_index))
+}
+
+#[utoipa::path(
+    get,
+    path = "/set/{set_code}/price-index",
+    params(
+        ("set_code" = String, Path, description = "Set code (3 to 5 characters)"),
+        ("start_date" = Option<String>, Query, description = "Start date (ISO 8601: YYYY-MM-DD, inclusive). Defaults to end_date minus 30 days"),
+        ("end_date" = Option<String>, Query, description = "End date (ISO 8601: YYYY-MM-DD, inclusive). Defaults to today"),
+    ),
+    responses(
+        (status = 200, description = "Daily price index of the set and the user's owned share", body = SetPriceIndexResponse),
+        (status = 400, description = "Invalid set code or date range (start_date > end_date)"),
+        (status = 401, description = "Missing or invalid token"),
+        (status = 404, description = "Unknown set code"),
+    ),
+    security(("bearer_auth" = [])),
+    tag = "set",
+)]
+pub(crate) async fn get_set_price_index(
+    AuthenticatedUser(user): AuthenticatedUser,
+    State(state): State<AppState>,
+    Path(set_code): Path<String>,
+    Query(params): Query<SetPriceIndexParams>,
+) -> Result<axum::Json<SetPriceIndexResponse>, AppError> {
+    let set_code = SetCode::try_new(set_code)?;
+
+    let index = state
+        .get_set_price_index_use_case
+        .get_set_price_index(&user.id, set_code, params.start_date, params.end_date)
+        .await?;
+
+    Ok(axum::Json(SetPriceIndexResponse::from(index)))
+}
@@ -0,0 +1,70 @@
+use crate::domain::set_price_index::{SetPriceIndex, SetPriceIndexEntry};
+use chrono::NaiveDate;
+use serde::{Deserialize, Serialize};
+use ts_rs::TS;
+use utoipa::ToSchema;
+
+/// Both dates are optional and defaulted by the use case (last 30 days when absent).
+#[derive(Deserialize, TS)]
+#[ts(export, export_to = "SetPriceIndexParams.ts")]
+pub(crate) struct SetPriceIndexParams {
+    /// ISO 8601 date string (YYYY-MM-DD)
+    #[ts(optional, type = "string")]
+    pub(crate) start_date: Option<NaiveDate>,
+    /// ISO 8601 date string (YYYY-MM-DD)
+    #[ts(optional, type = "string")]
+    pub(crate) end_date: Option<NaiveDate>,
+}
+
+/// Value of the tracked printings of a set on one day, based on their trend price.
+#[derive(Serialize, Debug, TS, ToSchema)]
+#[serde(rename = "SetPriceIndexEntry")]
+#[ts(export, export_to = "SetPriceIndexEntry.ts")]
+pub struct SetPriceIndexEntryResponse {
+    /// ISO 8601 date string (YYYY-MM-DD)
+    pub date: String,
+    /// Printings (card and finish) with a trend price that day
+    pub printings: u32,
+    pub total: i64,
+    pub median: i64,
+    /// Value of the printings owned by the user, each counted once
+    pub owned: i64,
+    /// `owned / total`, between 0 and 1
+    pub owned_share: f64,
+}
+
+impl From<SetPriceIndexEntry> for SetPriceIndexEntryResponse {
+    fn from(e: SetPriceIndexEntry) -> Self {
+        Self {
+            date: e.date.to_string(),
+            printings: e.printings,
+            total: e.total.value.unwrap_or(0) as i64,
+            median: e.median.value.unwrap_or(0) as i64,
+            owned: e.owned.value.unwrap_or(0) as i64,
+            owned_share: e.owned_share(),
+        }
+    }
+}
+
+#[derive(Serialize, Debug, TS, ToSchema)]
+#[serde(rename = "SetPriceIndex")]
+#[ts(export, export_to = "SetPriceIndex.ts")]
+pub struct SetPriceIndexResponse {
+    pub set_code: String,
+    pub set_name: String,
+    pub entries: Vec<SetPriceIndexEntryResponse>,
+}
+
+impl From<SetPriceIndex> for SetPriceIndexResponse {
+    fn from(index: SetPriceIndex) -> Self {
+        Self {
+            set_code: index.set.code.to_string(),
+            set_name: index.set.name,
+            entries: index
+                .entries
+                .into_iter()
+                .map(SetPriceIndexEntryResponse::from)
+                .collect(),
+        }
+    }
+}
@@ -0,0 +1,126 @@
+use super::controller::*;
+use super::dto::*;
+use crate::application::error::AppError;
+use crate::application::use_case::MockGetSetPriceIndexUseCase;
+use crate::domain::error::FunctionalError;
+use crate::domain::price::Price;
+use crate::domain::set_name::{SetCode, SetName};
+use crate::domain::set_price_index::{SetPriceIndex, SetPriceIndexEntry};
+use crate::domain::user::User;
+use crate::infrastructure::AppState;
+use crate::infrastructure::adapter_in::auth_extractor::AuthenticatedUser;
+use axum::extract::{Path, Query, State};
+use chrono::NaiveDate;
+use mockall::predicate::eq;
+use std::sync::Arc;
+
+fn make_app_state(mock: MockGetSetPriceIndexUseCase) -> AppState {
+    AppState {
+        get_set_price_index_use_case: Arc::new(mock),
+        ..AppState::for_testing(Arc::new(
+            crate::application::use_case::MockStatsUseCase::new(),
+        ))
+    }
+}
+
+fn no_dates() -> SetPriceIndexParams {
+    SetPriceIndexParams {
+        start_date: None,
+        end_date: None,
+    }
+}
+
+#[tokio::test]
+async fn get_set_price_index_returns_index_with_owned_share() {
+    let start = NaiveDate::from_ymd_opt(2025, 3, 1).unwrap();
+    let end = NaiveDate::from_ymd_opt(2025, 3, 31).unwrap();
+
+    let mut mock = MockGetSetPriceIndexUseCase::new();
+    mock.expect_get_set_price_index()
+        .withf(move |_, set_code, start_date, end_date| {
+            *set_code == SetCode::new("FDN") && *start_date == Some(start) && *end_date == Some(end)
+        })
+        .returning(|_, _, _, _| {
+            Box::pin(async {
+                Ok(SetPriceIndex {
+                    set: SetName::new("FDN", "Foundations"),
+                    entries: vec![SetPriceIndexEntry {
+                        date: NaiveDate::from_ymd_opt(2025, 3, 1).unwrap(),
+                        printings: 3,
+                        total: Price { value: Some(1400) },
+                        median: Price { value: Some(300) },
+                        owned: Price { value: Some(350) },
+                    }],
+                })
+            })
+        });
+
+    let result = get_set_price_index(
+        AuthenticatedUser(User::for_testing()),
+        State(make_app_state(mock)),
+        Path("fdn".to_string()),
+        Query(SetPriceIndexParams {
+            start_date: Some(start),
+            end_date: Some(end),
+        }),
+    )
+    .await;
+
+    let axum::Json(index) = result.unwrap();
+    assert_eq!(index.set_code, "FDN");
+    assert_eq!(index.set_name, "Foundations");
+    assert_eq!(index.entries.len(), 1);
+    assert_eq!(index.entries[0].date, "2025-03-01");
+    assert_eq!(index.entries[0].printings, 3);
+    assert_eq!(index.entries[0].total, 1400);
+    assert_eq!(index.entries[0].median, 300);
+    assert_eq!(index.entries[0].owned, 350);
+    assert_eq!(index.entries[0].owned_share, 0.25);
+}
+
+#[tokio::test]
+async fn get_set_price_index_rejects_invalid_set_code() {
+    let mut mock = MockGetSetPriceIndexUseCase::new();
+    mock.expect_get_set_price_index().never();
+
+    let result = get_set_price_index(
+        AuthenticatedUser(User::for_testing()),
+        State(make_app_state(mock)),
+        Path("AB".to_string()),
+        Query(no_dates()),
+    )
+    .await;
+
+    match result.unwrap_err() {
+        AppError::Functional(FunctionalError::InvalidSetCode(_)) => {}
+        other => panic!("Expected InvalidSetCode, got {:?}", other),
+    }
+}
+
+#[tokio::test]
+async fn get_set_price_index_propagates_set_not_found() {
+    let mut mock = MockGetSetPriceIndexUseCase::new();
+    mock.expect_get_set_price_index()
+        .with(
+            eq(User::for_testing().id),
+            eq(SetCode::new("XYZ")),
+            eq(None),
+            eq(None),
+        )
+        .returning(|_, _, _, _| {
+            Box::pin(async { Err(AppError::Functional(FunctionalError::SetNotFound)) })
+        });
+
+    let result = get_set_price_index(
+        AuthenticatedUser(User::for_testing()),
+        State(make_app_state(mock)),
+        Path("XYZ".to_string()),
+        Query(no_dates()),
+    )
+    .await;
+
+    match result.unwrap_err() {
+        AppError::Functional(FunctionalError::SetNotFound) => {}
+        other => panic!("Expected SetNotFound, got {:?}", other),
+    }
+}
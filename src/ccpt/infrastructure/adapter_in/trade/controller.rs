@@ -1,4 +1,4 @@
-use super::dto::{CreateTradeRequest, RateTradeRequest};
+use super::dto::{CreateTradeRequest, RateTradeRequest, TradeMatchResponse};
 use crate::application::error::AppError;
 use crate::domain::card::CardId;
 use crate::domain::error::FunctionalError;
@@ -9,11 +9,12 @@ use crate::infrastructure::AppState;
 use crate::infrastructure::adapter_in::auth_extractor::AuthenticatedUser;
 use axum::extract::{Path, State};
 use axum::http::StatusCode;
-use axum::routing::post;
+use axum::routing::{get, post};
 
 pub fn create_trade_router() -> axum::Router<AppState> {
     axum::Router::new()
         .route("/", post(create_trade))
+        .route("/matches", get(get_trade_matches))
         .route("/{trade_id}/accept", post(accept_trade))
         .route("/{trade_id}/abandon", post(abandon_trade))
         .route("/{trade_id}/confirm", post(confirm_trade))
@@ -67,6 +68,29 @@ pub(crate) async fn create_trade(
     Ok(StatusCode::CREATED)
 }
 
+#[utoipa::path(
+    get,
+    path = "/trades/matches",
+    responses(
+        (status = 200, description = "Users owning wished cards and wishing for owned ones, the most balanced first", body = Vec<TradeMatchResponse>),
+        (status = 401, description = "Missing or invalid token"),
+    ),
+    security(("bearer_auth" = [])),
+    tag = "trades",
+)]
+pub(crate) async fn get_trade_matches(
+    AuthenticatedUser(user): AuthenticatedUser,
+    State(state): State<AppState>,
+) -> Result<axum::Json<Vec<TradeMatchResponse>>, AppError> {
+    let matches = state
+        .get_trade_matches_use_case
+        .get_matches(&user.id)
+        .await?;
+    Ok(axum::Json(
+        matches.into_iter().map(TradeMatchResponse::from).collect(),
+    ))
+}
+
 #[utoipa::path(
     post,
     path = "/trades/{trade_id}/accept",
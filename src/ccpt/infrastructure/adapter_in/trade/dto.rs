@@ -1,4 +1,6 @@
-use serde::Deserialize;
+use crate::domain::trade_match::{TradeMatch, TradeMatchCard};
+use serde::{Deserialize, Serialize};
+use ts_rs::TS;
 use utoipa::ToSchema;
 
 #[derive(Deserialize, ToSchema)]
@@ -16,3 +18,69 @@ pub(crate) struct RateTradeRequest {
     /// Rating given to the other party, from 0 to 5 inclusive.
     pub(crate) rating: u8,
 }
+
+/// Copies of one printing a side of a match can give.
+#[derive(Serialize, Debug, TS, ToSchema)]
+#[serde(rename = "TradeMatchCard")]
+#[ts(export, export_to = "TradeMatchCard.ts")]
+pub struct TradeMatchCardResponse {
+    pub set_code: String,
+    pub collector_number: String,
+    pub language_code: String,
+    pub foil: bool,
+    pub name: String,
+    pub quantity: u8,
+    /// Current trend of one copy, in cents
+    pub trend: Option<u32>,
+}
+
+impl From<TradeMatchCard> for TradeMatchCardResponse {
+    fn from(card: TradeMatchCard) -> Self {
+        Self {
+            set_code: card.card_id.set_code.to_string(),
+            collector_number: card.card_id.collector_number,
+            language_code: card.card_id.language_code.to_string(),
+            foil: card.card_id.foil,
+            name: card.name,
+            quantity: card.quantity,
+            trend: card.trend,
+        }
+    }
+}
+
+/// Another user with whom a trade would go both ways, the cards of `wanted` being ready to
+/// propose with `POST /trades`.
+#[derive(Serialize, Debug, TS, ToSchema)]
+#[serde(rename = "TradeMatch")]
+#[ts(export, export_to = "TradeMatch.ts")]
+pub struct TradeMatchResponse {
+    pub respondent_user_id: String,
+    pub respondent_username: String,
+    /// Cards of the other user matching the wishlist
+    pub wanted: Vec<TradeMatchCardResponse>,
+    /// Cards of the user matching the other user's wishlist
+    pub offered: Vec<TradeMatchCardResponse>,
+    /// In cents
+    pub wanted_value: u64,
+    /// In cents
+    pub offered_value: u64,
+    /// Smaller value over larger one, 1 for an even trade
+    pub balance: f64,
+}
+
+impl From<TradeMatch> for TradeMatchResponse {
+    fn from(m: TradeMatch) -> Self {
+        let wanted_value = m.wanted_value();
+        let offered_value = m.offered_value();
+        let balance = m.balance();
+        Self {
+            respondent_user_id: m.other_user_id.to_string(),
+            respondent_username: m.other_username,
+            wanted: m.wanted.into_iter().map(Into::into).collect(),
+            offered: m.offered.into_iter().map(Into::into).collect(),
+            wanted_value,
+            offered_value,
+            balance,
+        }
+    }
+}
@@ -3,11 +3,14 @@ use super::dto::{CreateTradeRequest, RateTradeRequest};
 use crate::application::error::AppError;
 use crate::application::use_case::{
     MockAbandonTradeUseCase, MockAcceptTradeUseCase, MockConfirmTradeUseCase,
-    MockCreateTradeUseCase, MockRateTradeUseCase, MockStatsUseCase,
+    MockCreateTradeUseCase, MockGetTradeMatchesUseCase, MockRateTradeUseCase, MockStatsUseCase,
 };
+use crate::domain::card::CardId;
 use crate::domain::error::FunctionalError;
+use crate::domain::language_code::LanguageCode;
 use crate::domain::trade::TradeId;
-use crate::domain::user::User;
+use crate::domain::trade_match::{TradeMatch, TradeMatchCard};
+use crate::domain::user::{User, UserId};
 use crate::infrastructure::AppState;
 use crate::infrastructure::adapter_in::auth_extractor::AuthenticatedUser;
 use axum::extract::{Path, State};
@@ -648,3 +651,52 @@ async fn rate_trade_propagates_trade_not_completed_from_use_case() {
         Err(AppError::Functional(FunctionalError::TradeNotCompleted))
     ));
 }
+
+#[tokio::test]
+async fn get_trade_matches_returns_the_matches_with_their_values() {
+    let mut mock_use_case = MockGetTradeMatchesUseCase::new();
+    mock_use_case
+        .expect_get_matches()
+        .withf(|user_id| user_id.as_str() == "test-user-id")
+        .times(1)
+        .returning(|_| {
+            Box::pin(async {
+                Ok(vec![TradeMatch {
+                    other_user_id: UserId::new("user_respondent"),
+                    other_username: "Bob".to_string(),
+                    wanted: vec![TradeMatchCard {
+                        card_id: CardId::new("FDN", "87", LanguageCode::FR, false),
+                        name: "Card X".to_string(),
+                        quantity: 2,
+                        trend: Some(150),
+                    }],
+                    offered: vec![TradeMatchCard {
+                        card_id: CardId::new("FDN", "12", LanguageCode::EN, true),
+                        name: "Card Y".to_string(),
+                        quantity: 1,
+                        trend: Some(400),
+                    }],
+                }])
+            })
+        });
+    let state = AppState::for_testing_with_trade_matches(
+        Arc::new(MockStatsUseCase::new()),
+        Arc::new(mock_use_case),
+    );
+
+    let result = get_trade_matches(AuthenticatedUser(User::for_testing()), State(state))
+        .await
+        .unwrap();
+
+    assert_eq!(result.0.len(), 1);
+    let trade_match = &result.0[0];
+    assert_eq!(trade_match.respondent_user_id, "user_respondent");
+    assert_eq!(trade_match.respondent_username, "Bob");
+    assert_eq!(trade_match.wanted[0].set_code, "FDN");
+    assert_eq!(trade_match.wanted[0].language_code, "FR");
+    assert_eq!(trade_match.wanted[0].quantity, 2);
+    assert!(trade_match.offered[0].foil);
+    assert_eq!(trade_match.wanted_value, 300);
+    assert_eq!(trade_match.offered_value, 400);
+    assert_eq!(trade_match.balance, 0.75);
+}
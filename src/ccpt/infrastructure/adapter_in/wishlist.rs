@@ -0,0 +1,5 @@
+pub mod controller;
+pub mod dto;
+
+#[cfg(test)]
+mod tests;
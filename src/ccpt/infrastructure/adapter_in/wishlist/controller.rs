@@ -0,0 +1,211 @@
+use super::dto::{
+    CreateWishlistEntryRequest, UpdateWishlistEntryRequest, WishlistEntryResponse,
+    WishlistImportResponse,
+};
+use crate::application::error::AppError;
+use crate::domain::error::FunctionalError;
+use crate::domain::language_code::LanguageCode;
+use crate::domain::wishlist::{NewWishlistEntry, WishlistEntryId, WishlistEntryUpdate};
+use crate::infrastructure::AppState;
+use crate::infrastructure::adapter_in::auth_extractor::AuthenticatedUser;
+use crate::infrastructure::adapter_in::card::dto::card_selector;
+use axum::body::to_bytes;
+use axum::extract::{Path, State};
+use axum::http::StatusCode;
+use axum::routing::{get, patch, post};
+
+pub fn create_wishlist_router() -> axum::Router<AppState> {
+    axum::Router::new()
+        .route("/", get(list_wishlist_entries).post(add_wishlist_entry))
+        .route("/import", post(import_decklist))
+        .route(
+            "/{entry_id}",
+            patch(update_wishlist_entry).delete(remove_wishlist_entry),
+        )
+}
+
+fn check_quantity(quantity: u8) -> Result<(), AppError> {
+    if quantity == 0 {
+        return Err(AppError::Functional(FunctionalError::WrongFormat(
+            "quantity must be at least 1".to_string(),
+        )));
+    }
+    Ok(())
+}
+
+fn parse_languages(languages: Vec<String>) -> Result<Vec<LanguageCode>, AppError> {
+    languages
+        .iter()
+        .map(|code| LanguageCode::try_new(code).map_err(AppError::from))
+        .collect()
+}
+
+#[utoipa::path(
+    get,
+    path = "/wishlist",
+    responses(
+        (status = 200, description = "Wishes of the caller, the highest priority first", body = Vec<WishlistEntryResponse>),
+        (status = 401, description = "Missing or invalid token"),
+    ),
+    security(("bearer_auth" = [])),
+    tag = "wishlist",
+)]
+pub(crate) async fn list_wishlist_entries(
+    AuthenticatedUser(user): AuthenticatedUser,
+    State(state): State<AppState>,
+) -> Result<axum::Json<Vec<WishlistEntryResponse>>, AppError> {
+    let items = state
+        .manage_wishlist_use_case
+        .list_entries(&user.id)
+        .await?;
+
+    Ok(axum::Json(
+        items.into_iter().map(WishlistEntryResponse::from).collect(),
+    ))
+}
+
+#[utoipa::path(
+    post,
+    path = "/wishlist",
+    request_body = CreateWishlistEntryRequest,
+    responses(
+        (status = 201, description = "Card added to the wishlist", body = WishlistEntryResponse),
+        (status = 400, description = "Invalid payload, or filters on a wish of one printing"),
+        (status = 401, description = "Missing or invalid token"),
+        (status = 404, description = "Unknown card"),
+        (status = 409, description = "The card is already in the wishlist"),
+    ),
+    security(("bearer_auth" = [])),
+    tag = "wishlist",
+)]
+pub(crate) async fn add_wishlist_entry(
+    AuthenticatedUser(user): AuthenticatedUser,
+    State(state): State<AppState>,
+    axum::Json(payload): axum::Json<CreateWishlistEntryRequest>,
+) -> Result<(StatusCode, axum::Json<WishlistEntryResponse>), AppError> {
+    let card = card_selector(
+        payload.oracle_id,
+        payload.set_code.as_deref(),
+        payload.collector_number.as_deref(),
+        payload.language_code.as_deref(),
+        payload.foil,
+    )?;
+    check_quantity(payload.quantity)?;
+
+    let entry = NewWishlistEntry {
+        card,
+        quantity: payload.quantity,
+        max_price: payload.max_price,
+        languages: parse_languages(payload.languages)?,
+        finishes: payload.finishes.into_iter().map(Into::into).collect(),
+        priority: payload.priority.into(),
+    };
+    let item = state
+        .manage_wishlist_use_case
+        .add_entry(&user.id, entry)
+        .await?;
+
+    Ok((StatusCode::CREATED, axum::Json(item.into())))
+}
+
+#[utoipa::path(
+    patch,
+    path = "/wishlist/{entry_id}",
+    params(("entry_id" = uuid::Uuid, Path, description = "Wishlist entry id")),
+    request_body = UpdateWishlistEntryRequest,
+    responses(
+        (status = 200, description = "Wish updated", body = WishlistEntryResponse),
+        (status = 400, description = "Invalid payload, or filters on a wish of one printing"),
+        (status = 401, description = "Missing or invalid token"),
+        (status = 404, description = "Wishlist entry not found"),
+    ),
+    security(("bearer_auth" = [])),
+    tag = "wishlist",
+)]
+pub(crate) async fn update_wishlist_entry(
+    AuthenticatedUser(user): AuthenticatedUser,
+    State(state): State<AppState>,
+    Path(entry_id): Path<uuid::Uuid>,
+    axum::Json(payload): axum::Json<UpdateWishlistEntryRequest>,
+) -> Result<axum::Json<WishlistEntryResponse>, AppError> {
+    if let Some(quantity) = payload.quantity {
+        check_quantity(quantity)?;
+    }
+
+    let update = WishlistEntryUpdate {
+        quantity: payload.quantity,
+        max_price: payload.max_price,
+        languages: payload.languages.map(parse_languages).transpose()?,
+        finishes: payload
+            .finishes
+            .map(|finishes| finishes.into_iter().map(Into::into).collect()),
+        priority: payload.priority.map(Into::into),
+    };
+    let item = state
+        .manage_wishlist_use_case
+        .update_entry(&user.id, WishlistEntryId(entry_id), update)
+        .await?;
+
+    Ok(axum::Json(item.into()))
+}
+
+#[utoipa::path(
+    delete,
+    path = "/wishlist/{entry_id}",
+    params(("entry_id" = uuid::Uuid, Path, description = "Wishlist entry id")),
+    responses(
+        (status = 204, description = "Wish removed"),
+        (status = 401, description = "Missing or invalid token"),
+        (status = 404, description = "Wishlist entry not found"),
+    ),
+    security(("bearer_auth" = [])),
+    tag = "wishlist",
+)]
+pub(crate) async fn remove_wishlist_entry(
+    AuthenticatedUser(user): AuthenticatedUser,
+    State(state): State<AppState>,
+    Path(entry_id): Path<uuid::Uuid>,
+) -> Result<StatusCode, AppError> {
+    state
+        .manage_wishlist_use_case
+        .remove_entry(&user.id, WishlistEntryId(entry_id))
+        .await?;
+
+    Ok(StatusCode::NO_CONTENT)
+}
+
+#[utoipa::path(
+    post,
+    path = "/wishlist/import",
+    request_body(
+        content = String,
+        content_type = "text/plain",
+        description = "Plain text decklist, one `4 Lightning Bolt` line per card (max 10 MB)",
+    ),
+    responses(
+        (status = 200, description = "Cards of the decklist wished, with the names matching no card", body = WishlistImportResponse),
+        (status = 400, description = "Invalid body (non UTF-8, no card, malformed line, ...)"),
+        (status = 401, description = "Missing or invalid token"),
+    ),
+    security(("bearer_auth" = [])),
+    tag = "wishlist",
+)]
+pub(crate) async fn import_decklist(
+    AuthenticatedUser(user): AuthenticatedUser,
+    State(state): State<AppState>,
+    body: axum::body::Body,
+) -> Result<axum::Json<WishlistImportResponse>, AppError> {
+    let bytes = to_bytes(body, 10 * 1024 * 1024)
+        .await
+        .map_err(|e| FunctionalError::WrongFormat(format!("Failed to read body: {}", e)))?;
+
+    let decklist = String::from_utf8(bytes.to_vec())
+        .map_err(|_| FunctionalError::WrongFormat("Body is not valid UTF-8".to_string()))?;
+
+    let import = state
+        .manage_wishlist_use_case
+        .import_decklist(&user.id, &decklist)
+        .await?;
+
+    Ok(axum::Json(import.into()))
+}
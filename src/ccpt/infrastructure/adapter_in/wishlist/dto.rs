@@ -0,0 +1,187 @@
+use crate::domain::card::CardSelector;
+use crate::domain::wishlist::{Finish, WishPriority, WishlistImport, WishlistItem};
+use crate::infrastructure::adapter_in::collection::dto::deserialize_some;
+use serde::{Deserialize, Serialize};
+use ts_rs::TS;
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+#[derive(Deserialize, Serialize, Debug, Clone, Copy, PartialEq, Eq, TS, ToSchema)]
+#[serde(rename = "Finish", rename_all = "snake_case")]
+#[ts(export, export_to = "Finish.ts")]
+pub enum FinishParam {
+    NonFoil,
+    Foil,
+}
+
+impl From<FinishParam> for Finish {
+    fn from(p: FinishParam) -> Self {
+        match p {
+            FinishParam::NonFoil => Finish::NonFoil,
+            FinishParam::Foil => Finish::Foil,
+        }
+    }
+}
+
+impl From<Finish> for FinishParam {
+    fn from(f: Finish) -> Self {
+        match f {
+            Finish::NonFoil => FinishParam::NonFoil,
+            Finish::Foil => FinishParam::Foil,
+        }
+    }
+}
+
+#[derive(Deserialize, Serialize, Default, Debug, Clone, Copy, PartialEq, Eq, TS, ToSchema)]
+#[serde(rename = "WishPriority", rename_all = "snake_case")]
+#[ts(export, export_to = "WishPriority.ts")]
+pub enum WishPriorityParam {
+    Low,
+    #[default]
+    Normal,
+    High,
+}
+
+impl From<WishPriorityParam> for WishPriority {
+    fn from(p: WishPriorityParam) -> Self {
+        match p {
+            WishPriorityParam::Low => WishPriority::Low,
+            WishPriorityParam::Normal => WishPriority::Normal,
+            WishPriorityParam::High => WishPriority::High,
+        }
+    }
+}
+
+impl From<WishPriority> for WishPriorityParam {
+    fn from(p: WishPriority) -> Self {
+        match p {
+            WishPriority::Low => WishPriorityParam::Low,
+            WishPriority::Normal => WishPriorityParam::Normal,
+            WishPriority::High => WishPriorityParam::High,
+        }
+    }
+}
+
+/// A wish with the offers of other users matching it. The printing fields are set for a wish of
+/// one printing, `oracle_id` for a wish of any printing of the card.
+#[derive(Serialize, Debug, TS, ToSchema)]
+#[serde(rename = "WishlistEntry")]
+#[ts(export, export_to = "WishlistEntry.ts")]
+pub struct WishlistEntryResponse {
+    pub id: String,
+    pub name: String,
+    pub oracle_id: Option<String>,
+    pub set_code: Option<String>,
+    pub collector_number: Option<String>,
+    pub language_code: Option<String>,
+    pub foil: Option<bool>,
+    pub quantity: u8,
+    /// Highest trend price accepted, in cents
+    pub max_price: Option<u32>,
+    /// Languages accepted, empty for every language
+    pub languages: Vec<String>,
+    /// Finishes accepted, empty for both
+    pub finishes: Vec<FinishParam>,
+    pub priority: WishPriorityParam,
+    /// RFC 3339 timestamp
+    pub created_at: String,
+    /// Number of other users offering a matching printing for trade
+    pub offer_count: u64,
+    /// Lowest trend among the matching offers, in cents
+    pub cheapest_offer: Option<u32>,
+}
+
+impl From<WishlistItem> for WishlistEntryResponse {
+    fn from(item: WishlistItem) -> Self {
+        let entry = item.entry;
+        let (oracle_id, set_code, collector_number, language_code, foil) = match entry.card {
+            CardSelector::Printing(card_id) => (
+                None,
+                Some(card_id.set_code.to_string()),
+                Some(card_id.collector_number),
+                Some(card_id.language_code.to_string()),
+                Some(card_id.foil),
+            ),
+            CardSelector::AnyPrinting { oracle_id } => {
+                (Some(oracle_id.to_string()), None, None, None, None)
+            }
+        };
+        Self {
+            id: entry.id.to_string(),
+            name: entry.name,
+            oracle_id,
+            set_code,
+            collector_number,
+            language_code,
+            foil,
+            quantity: entry.quantity,
+            max_price: entry.max_price,
+            languages: entry.languages.iter().map(|l| l.to_string()).collect(),
+            finishes: entry.finishes.into_iter().map(FinishParam::from).collect(),
+            priority: entry.priority.into(),
+            created_at: entry.created_at.to_rfc3339(),
+            offer_count: item.offer_count,
+            cheapest_offer: item.cheapest_offer,
+        }
+    }
+}
+
+#[derive(Deserialize, ToSchema)]
+pub(crate) struct CreateWishlistEntryRequest {
+    /// Any printing of the card, takes precedence over the printing fields
+    pub(crate) oracle_id: Option<Uuid>,
+    pub(crate) set_code: Option<String>,
+    pub(crate) collector_number: Option<String>,
+    pub(crate) language_code: Option<String>,
+    pub(crate) foil: Option<bool>,
+    /// 1 when omitted
+    #[serde(default = "default_quantity")]
+    pub(crate) quantity: u8,
+    /// Highest trend price accepted, in cents
+    pub(crate) max_price: Option<u32>,
+    /// Languages accepted for any printing of the card, every language when omitted
+    #[serde(default)]
+    pub(crate) languages: Vec<String>,
+    /// Finishes accepted for any printing of the card, both when omitted
+    #[serde(default)]
+    pub(crate) finishes: Vec<FinishParam>,
+    #[serde(default)]
+    pub(crate) priority: WishPriorityParam,
+}
+
+fn default_quantity() -> u8 {
+    1
+}
+
+#[derive(Deserialize, ToSchema)]
+pub(crate) struct UpdateWishlistEntryRequest {
+    pub(crate) quantity: Option<u8>,
+    /// `null` removes the price limit
+    #[serde(default, deserialize_with = "deserialize_some")]
+    #[schema(value_type = Option<u32>)]
+    pub(crate) max_price: Option<Option<u32>>,
+    /// An empty list accepts every language again
+    pub(crate) languages: Option<Vec<String>>,
+    /// An empty list accepts both finishes again
+    pub(crate) finishes: Option<Vec<FinishParam>>,
+    pub(crate) priority: Option<WishPriorityParam>,
+}
+
+#[derive(Serialize, Debug, TS, ToSchema)]
+#[serde(rename = "WishlistImport")]
+#[ts(export, export_to = "WishlistImport.ts")]
+pub struct WishlistImportResponse {
+    /// Number of cards added or whose wished quantity was raised
+    pub imported: u32,
+    /// Names of the decklist matching no known card, as written
+    pub unknown_names: Vec<String>,
+}
+
+impl From<WishlistImport> for WishlistImportResponse {
+    fn from(import: WishlistImport) -> Self {
+        Self {
+            imported: import.imported,
+            unknown_names: import.unknown_names,
+        }
+    }
+}
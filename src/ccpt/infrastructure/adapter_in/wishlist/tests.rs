@@ -0,0 +1,362 @@
+use super::controller::*;
+use super::dto::{CreateWishlistEntryRequest, FinishParam, UpdateWishlistEntryRequest};
+use crate::application::error::AppError;
+use crate::application::use_case::{MockManageWishlistUseCase, MockStatsUseCase};
+use crate::domain::card::{CardId, CardSelector};
+use crate::domain::error::FunctionalError;
+use crate::domain::language_code::LanguageCode;
+use crate::domain::user::{User, UserId};
+use crate::domain::wishlist::{
+    Finish, WishPriority, WishlistEntry, WishlistEntryId, WishlistImport, WishlistItem,
+};
+use crate::infrastructure::AppState;
+use crate::infrastructure::adapter_in::auth_extractor::AuthenticatedUser;
+use axum::body::Body;
+use axum::extract::{Path, State};
+use axum::http::StatusCode;
+use chrono::{TimeZone, Utc};
+use std::sync::Arc;
+use uuid::Uuid;
+
+fn make_app_state(manage_wishlist_use_case: MockManageWishlistUseCase) -> AppState {
+    AppState::for_testing_with_manage_wishlist(
+        Arc::new(MockStatsUseCase::new()),
+        Arc::new(manage_wishlist_use_case),
+    )
+}
+
+fn oracle_id() -> Uuid {
+    Uuid::parse_str("44623693-51d6-49ad-8cd7-140505caf02f").unwrap()
+}
+
+fn item(card: CardSelector) -> WishlistItem {
+    WishlistItem {
+        entry: WishlistEntry {
+            id: WishlistEntryId(Uuid::nil()),
+            user_id: UserId::new("test-user-id"),
+            card,
+            name: "Lightning Bolt".to_string(),
+            quantity: 4,
+            max_price: Some(150),
+            languages: vec![LanguageCode::EN],
+            finishes: vec![Finish::NonFoil],
+            priority: WishPriority::High,
+            created_at: Utc.with_ymd_and_hms(2025, 3, 1, 10, 0, 0).unwrap(),
+        },
+        offer_count: 2,
+        cheapest_offer: Some(90),
+    }
+}
+
+fn create_request() -> CreateWishlistEntryRequest {
+    CreateWishlistEntryRequest {
+        oracle_id: Some(oracle_id()),
+        set_code: None,
+        collector_number: None,
+        language_code: None,
+        foil: None,
+        quantity: 4,
+        max_price: Some(150),
+        languages: vec!["en".to_string()],
+        finishes: vec![FinishParam::NonFoil],
+        priority: Default::default(),
+    }
+}
+
+#[tokio::test]
+async fn list_wishlist_entries_returns_the_wishes_with_their_offers() {
+    let mut mock_use_case = MockManageWishlistUseCase::new();
+    mock_use_case
+        .expect_list_entries()
+        .withf(|user_id| user_id.as_str() == "test-user-id")
+        .returning(|_| {
+            Box::pin(async {
+                Ok(vec![item(CardSelector::AnyPrinting {
+                    oracle_id: oracle_id(),
+                })])
+            })
+        });
+
+    let result = list_wishlist_entries(
+        AuthenticatedUser(User::for_testing()),
+        State(make_app_state(mock_use_case)),
+    )
+    .await
+    .unwrap();
+
+    assert_eq!(result.0.len(), 1);
+    assert_eq!(result.0[0].oracle_id, Some(oracle_id().to_string()));
+    assert_eq!(result.0[0].set_code, None);
+    assert_eq!(result.0[0].languages, vec!["EN".to_string()]);
+    assert_eq!(result.0[0].finishes, vec![FinishParam::NonFoil]);
+    assert_eq!(result.0[0].offer_count, 2);
+    assert_eq!(result.0[0].cheapest_offer, Some(90));
+    assert_eq!(result.0[0].created_at, "2025-03-01T10:00:00+00:00");
+}
+
+#[tokio::test]
+async fn add_wishlist_entry_returns_created_with_the_wish() {
+    let mut mock_use_case = MockManageWishlistUseCase::new();
+    mock_use_case
+        .expect_add_entry()
+        .withf(|_, entry| {
+            entry.card
+                == CardSelector::AnyPrinting {
+                    oracle_id: oracle_id(),
+                }
+                && entry.quantity == 4
+                && entry.languages == vec![LanguageCode::EN]
+                && entry.finishes == vec![Finish::NonFoil]
+                && entry.priority == WishPriority::Normal
+        })
+        .times(1)
+        .returning(|_, entry| Box::pin(async move { Ok(item(entry.card)) }));
+
+    let (status, body) = add_wishlist_entry(
+        AuthenticatedUser(User::for_testing()),
+        State(make_app_state(mock_use_case)),
+        axum::Json(create_request()),
+    )
+    .await
+    .unwrap();
+
+    assert_eq!(status, StatusCode::CREATED);
+    assert_eq!(body.0.name, "Lightning Bolt");
+}
+
+#[tokio::test]
+async fn add_wishlist_entry_accepts_one_printing() {
+    let mut mock_use_case = MockManageWishlistUseCase::new();
+    mock_use_case
+        .expect_add_entry()
+        .withf(|_, entry| {
+            entry.card == CardSelector::Printing(CardId::new("LEA", "161", LanguageCode::FR, true))
+        })
+        .times(1)
+        .returning(|_, entry| Box::pin(async move { Ok(item(entry.card)) }));
+
+    let (_, body) = add_wishlist_entry(
+        AuthenticatedUser(User::for_testing()),
+        State(make_app_state(mock_use_case)),
+        axum::Json(CreateWishlistEntryRequest {
+            oracle_id: None,
+            set_code: Some("lea".to_string()),
+            collector_number: Some("161".to_string()),
+            language_code: Some("fr".to_string()),
+            foil: Some(true),
+            languages: vec![],
+            finishes: vec![],
+            ..create_request()
+        }),
+    )
+    .await
+    .unwrap();
+
+    assert_eq!(body.0.oracle_id, None);
+    assert_eq!(body.0.set_code.as_deref(), Some("LEA"));
+    assert_eq!(body.0.foil, Some(true));
+}
+
+#[tokio::test]
+async fn add_wishlist_entry_rejects_a_missing_card() {
+    let result = add_wishlist_entry(
+        AuthenticatedUser(User::for_testing()),
+        State(make_app_state(MockManageWishlistUseCase::new())),
+        axum::Json(CreateWishlistEntryRequest {
+            oracle_id: None,
+            set_code: Some("LEA".to_string()),
+            ..create_request()
+        }),
+    )
+    .await;
+
+    assert!(matches!(
+        result,
+        Err(AppError::Functional(FunctionalError::WrongFormat(_)))
+    ));
+}
+
+#[tokio::test]
+async fn add_wishlist_entry_rejects_a_zero_quantity() {
+    let result = add_wishlist_entry(
+        AuthenticatedUser(User::for_testing()),
+        State(make_app_state(MockManageWishlistUseCase::new())),
+        axum::Json(CreateWishlistEntryRequest {
+            quantity: 0,
+            ..create_request()
+        }),
+    )
+    .await;
+
+    assert!(matches!(
+        result,
+        Err(AppError::Functional(FunctionalError::WrongFormat(_)))
+    ));
+}
+
+#[tokio::test]
+async fn add_wishlist_entry_rejects_an_unknown_language() {
+    let result = add_wishlist_entry(
+        AuthenticatedUser(User::for_testing()),
+        State(make_app_state(MockManageWishlistUseCase::new())),
+        axum::Json(CreateWishlistEntryRequest {
+            languages: vec!["xx".to_string()],
+            ..create_request()
+        }),
+    )
+    .await;
+
+    assert!(matches!(
+        result,
+        Err(AppError::Functional(FunctionalError::InvalidLanguageCode(
+            _
+        )))
+    ));
+}
+
+#[tokio::test]
+async fn add_wishlist_entry_propagates_already_exists() {
+    let mut mock_use_case = MockManageWishlistUseCase::new();
+    mock_use_case.expect_add_entry().returning(|_, _| {
+        Box::pin(async {
+            Err(AppError::Functional(
+                FunctionalError::WishlistEntryAlreadyExists,
+            ))
+        })
+    });
+
+    let result = add_wishlist_entry(
+        AuthenticatedUser(User::for_testing()),
+        State(make_app_state(mock_use_case)),
+        axum::Json(create_request()),
+    )
+    .await;
+
+    assert!(matches!(
+        result,
+        Err(AppError::Functional(
+            FunctionalError::WishlistEntryAlreadyExists
+        ))
+    ));
+}
+
+#[tokio::test]
+async fn update_wishlist_entry_passes_the_changes_to_use_case() {
+    let id = Uuid::new_v4();
+    let mut mock_use_case = MockManageWishlistUseCase::new();
+    mock_use_case
+        .expect_update_entry()
+        .withf(move |_, entry_id, update| {
+            *entry_id == WishlistEntryId(id)
+                && update.quantity.is_none()
+                && update.max_price == Some(None)
+                && update.languages == Some(vec![])
+                && update.priority == Some(WishPriority::Low)
+        })
+        .times(1)
+        .returning(|_, _, _| {
+            Box::pin(async {
+                Ok(item(CardSelector::AnyPrinting {
+                    oracle_id: oracle_id(),
+                }))
+            })
+        });
+
+    let result = update_wishlist_entry(
+        AuthenticatedUser(User::for_testing()),
+        State(make_app_state(mock_use_case)),
+        Path(id),
+        axum::Json(
+            serde_json::from_str::<UpdateWishlistEntryRequest>(
+                r#"{"max_price": null, "languages": [], "priority": "low"}"#,
+            )
+            .unwrap(),
+        ),
+    )
+    .await;
+
+    assert!(result.is_ok());
+}
+
+#[tokio::test]
+async fn remove_wishlist_entry_returns_no_content() {
+    let id = Uuid::new_v4();
+    let mut mock_use_case = MockManageWishlistUseCase::new();
+    mock_use_case
+        .expect_remove_entry()
+        .withf(move |_, entry_id| *entry_id == WishlistEntryId(id))
+        .times(1)
+        .returning(|_, _| Box::pin(async { Ok(()) }));
+
+    let result = remove_wishlist_entry(
+        AuthenticatedUser(User::for_testing()),
+        State(make_app_state(mock_use_case)),
+        Path(id),
+    )
+    .await;
+
+    assert_eq!(result.unwrap(), StatusCode::NO_CONTENT);
+}
+
+#[tokio::test]
+async fn remove_wishlist_entry_propagates_not_found() {
+    let mut mock_use_case = MockManageWishlistUseCase::new();
+    mock_use_case.expect_remove_entry().returning(|_, _| {
+        Box::pin(async { Err(AppError::Functional(FunctionalError::WishlistEntryNotFound)) })
+    });
+
+    let result = remove_wishlist_entry(
+        AuthenticatedUser(User::for_testing()),
+        State(make_app_state(mock_use_case)),
+        Path(Uuid::new_v4()),
+    )
+    .await;
+
+    assert!(matches!(
+        result,
+        Err(AppError::Functional(FunctionalError::WishlistEntryNotFound))
+    ));
+}
+
+#[tokio::test]
+async fn import_decklist_returns_the_unknown_names() {
+    let mut mock_use_case = MockManageWishlistUseCase::new();
+    mock_use_case
+        .expect_import_decklist()
+        .withf(|_, decklist| decklist == "4 Lightning Bolt\n1 Nope\n")
+        .times(1)
+        .returning(|_, _| {
+            Box::pin(async {
+                Ok(WishlistImport {
+                    imported: 1,
+                    unknown_names: vec!["Nope".to_string()],
+                })
+            })
+        });
+
+    let result = import_decklist(
+        AuthenticatedUser(User::for_testing()),
+        State(make_app_state(mock_use_case)),
+        Body::from("4 Lightning Bolt\n1 Nope\n"),
+    )
+    .await
+    .unwrap();
+
+    assert_eq!(result.0.imported, 1);
+    assert_eq!(result.0.unknown_names, vec!["Nope".to_string()]);
+}
+
+#[tokio::test]
+async fn import_decklist_rejects_a_body_that_is_not_utf8() {
+    let result = import_decklist(
+        AuthenticatedUser(User::for_testing()),
+        State(make_app_state(MockManageWishlistUseCase::new())),
+        Body::from(vec![0xff, 0xfe]),
+    )
+    .await;
+
+    assert!(matches!(
+        result,
+        Err(AppError::Functional(FunctionalError::WrongFormat(_)))
+    ));
+}
@@ -4,6 +4,11 @@ pub mod cardmarket_caller_adapter;
 mod dto;
 pub mod edhrec_caller_adapter;
 pub mod gatherer_caller_adapter;
+pub mod http_client;
+mod json_scanner;
+mod price_guide_parser;
+pub mod scryfall_bulk_caller_adapter;
+mod scryfall_bulk_parser;
 pub mod scryfall_caller_adapter;
 
 impl From<reqwest::Error> for AppError {
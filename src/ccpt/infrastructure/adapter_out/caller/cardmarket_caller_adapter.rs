@@ -1,48 +1,82 @@
 use crate::application::caller::CardMarketCaller;
 use crate::application::error::AppError;
-use crate::domain::price::FullPriceGuide;
-use crate::infrastructure::adapter_out::caller::dto::CardmarketPriceGuides;
+use crate::domain::price::{FullPriceGuide, PriceGuideChunk};
+use crate::infrastructure::adapter_out::caller::http_client::HttpClient;
+use crate::infrastructure::adapter_out::caller::price_guide_parser::{
+    PriceGuideParser, invalid_price_guide_file,
+};
 use async_trait::async_trait;
 use chrono::NaiveDate;
+use std::sync::Arc;
+use tokio::sync::mpsc::Sender;
+
+/// Price guides sent per chunk
+const CHUNK_SIZE: usize = 1000;
 
 pub struct CardMarketCallerAdapter {
-    pub client: reqwest::Client,
+    pub client: Arc<HttpClient>,
     pub url: String,
 }
 
 impl CardMarketCallerAdapter {
-    pub fn new(url: impl Into<String>) -> Self {
+    pub fn new(url: impl Into<String>, client: Arc<HttpClient>) -> Self {
         Self {
-            client: reqwest::Client::new(),
+            client,
             url: url.into(),
         }
     }
 }
 
+async fn send_chunk(
+    sender: &Sender<PriceGuideChunk>,
+    date: NaiveDate,
+    price_guides: Vec<FullPriceGuide>,
+) -> Result<(), AppError> {
+    sender
+        .send(PriceGuideChunk { date, price_guides })
+        .await
+        .map_err(|_| invalid_price_guide_file("the import stopped reading the price guides"))
+}
+
 #[async_trait]
 impl CardMarketCaller for CardMarketCallerAdapter {
-    async fn get_price_guides(&self) -> Result<(NaiveDate, Vec<FullPriceGuide>), AppError> {
-        let price_guides: CardmarketPriceGuides = self
+    async fn stream_price_guides(
+        &self,
+        sender: Sender<PriceGuideChunk>,
+    ) -> Result<NaiveDate, AppError> {
+        let mut response = self
             .client
-            .get(self.url.as_str())
-            .send()
+            .send(self.client.get(self.url.as_str()))
             .await?
-            .json()
-            .await?;
+            .error_for_status()?;
 
-        let domain = price_guides
-            .price_guides
-            .into_iter()
-            .map(|pg| pg.into())
-            .collect();
+        let mut parser = PriceGuideParser::default();
+        let mut price_guides = Vec::with_capacity(CHUNK_SIZE);
+
+        while let Some(bytes) = response.chunk().await? {
+            price_guides.extend(parser.push(&bytes)?);
+
+            if price_guides.len() >= CHUNK_SIZE {
+                let date = parser.date().ok_or_else(|| {
+                    invalid_price_guide_file("createdAt must come before priceGuides")
+                })?;
+                send_chunk(&sender, date, std::mem::take(&mut price_guides)).await?;
+            }
+        }
+
+        let date = parser.finish()?;
+        if !price_guides.is_empty() {
+            send_chunk(&sender, date, price_guides).await?;
+        }
 
-        Ok((price_guides.created_at.date_naive(), domain))
+        Ok(date)
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use tokio::sync::mpsc::channel;
     use wiremock::matchers::{method, path};
     use wiremock::{Mock, MockServer, ResponseTemplate};
 
@@ -86,20 +120,28 @@ mod tests {
             .mount(&mock_server)
             .await;
 
-        let adapter =
-            CardMarketCallerAdapter::new(format!("{}/price_guide.json", mock_server.uri()));
+        let adapter = CardMarketCallerAdapter::new(
+            format!("{}/price_guide.json", mock_server.uri()),
+            Arc::new(HttpClient::for_testing()),
+        );
+
+        let (sender, mut receiver) = channel(10);
 
         // Act
-        let result = adapter.get_price_guides().await;
+        let result = adapter.stream_price_guides(sender).await;
 
         // Assert
         assert!(result.is_ok());
 
-        let (date, price_guides) = result.unwrap();
         // Assertions racine
+        let date = result.unwrap();
         assert_eq!(date, NaiveDate::from_ymd_opt(2025, 12, 23).unwrap(),);
 
-        let actual: Vec<FullPriceGuide> = price_guides;
+        let chunk = receiver.recv().await.unwrap();
+        assert_eq!(chunk.date, date);
+        assert!(receiver.recv().await.is_none());
+
+        let actual: Vec<FullPriceGuide> = chunk.price_guides;
 
         // Assertions collection
         assert_eq!(actual.len(), 2);
@@ -128,9 +170,12 @@ mod tests {
             .mount(&mock_server)
             .await;
 
-        let adapter = CardMarketCallerAdapter::new(mock_server.uri());
+        let adapter =
+            CardMarketCallerAdapter::new(mock_server.uri(), Arc::new(HttpClient::for_testing()));
+
+        let (sender, _receiver) = channel(10);
 
-        let result = adapter.get_price_guides().await;
+        let result = adapter.stream_price_guides(sender).await;
 
         assert!(result.is_err());
     }
@@ -146,10 +191,64 @@ mod tests {
             .mount(&mock_server)
             .await;
 
-        let adapter = CardMarketCallerAdapter::new(mock_server.uri());
+        let adapter =
+            CardMarketCallerAdapter::new(mock_server.uri(), Arc::new(HttpClient::for_testing()));
+
+        let (sender, _receiver) = channel(10);
+
+        let result = adapter.stream_price_guides(sender).await;
+
+        assert!(result.is_err());
+    }
+
+    #[tokio::test]
+    async fn should_fail_when_download_is_truncated() {
+        let mock_server = MockServer::start().await;
+
+        Mock::given(method("GET"))
+            .respond_with(ResponseTemplate::new(200).set_body_raw(
+                r#"{"createdAt": "2025-12-23T02:47:26+0100", "priceGuides": [{"idProduct": 1}"#,
+                "application/json",
+            ))
+            .mount(&mock_server)
+            .await;
+
+        let adapter =
+            CardMarketCallerAdapter::new(mock_server.uri(), Arc::new(HttpClient::for_testing()));
+        let (sender, _receiver) = channel(10);
 
-        let result = adapter.get_price_guides().await;
+        let result = adapter.stream_price_guides(sender).await;
 
         assert!(result.is_err());
     }
+
+    #[tokio::test]
+    async fn should_send_every_price_guide_of_a_large_file() {
+        let mock_server = MockServer::start().await;
+
+        let price_guides: Vec<String> = (1..=CHUNK_SIZE + 1)
+            .map(|id| format!(r#"{{"idProduct": {}, "trend": 0.1}}"#, id))
+            .collect();
+        let json_body = format!(
+            r#"{{"createdAt": "2025-12-23T02:47:26+0100", "priceGuides": [{}]}}"#,
+            price_guides.join(",")
+        );
+
+        Mock::given(method("GET"))
+            .respond_with(ResponseTemplate::new(200).set_body_raw(json_body, "application/json"))
+            .mount(&mock_server)
+            .await;
+
+        let adapter =
+            CardMarketCallerAdapter::new(mock_server.uri(), Arc::new(HttpClient::for_testing()));
+        let (sender, mut receiver) = channel(10);
+
+        adapter.stream_price_guides(sender).await.unwrap();
+
+        let mut ids = Vec::new();
+        while let Some(chunk) = receiver.recv().await {
+            ids.extend(chunk.price_guides.iter().map(|guide| guide.id_product));
+        }
+        assert_eq!(ids, (1..=CHUNK_SIZE as u32 + 1).collect::<Vec<_>>());
+    }
 }
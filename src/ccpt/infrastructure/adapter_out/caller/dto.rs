@@ -1,17 +1,15 @@
+use crate::domain::card_metadata::{CardMetadata, Color};
 use crate::domain::price::{FullPriceGuide, Price, PriceGuide};
-use chrono::{DateTime, Utc};
-
-#[derive(Debug, serde::Deserialize)]
-#[serde(rename_all = "camelCase")]
-pub struct CardmarketPriceGuides {
-    pub created_at: DateTime<Utc>,
-    pub price_guides: Vec<CardmarketPriceGuide>,
-}
+use crate::domain::rarity_code::RarityCode;
+use crate::domain::scryfall_card::ScryfallCard;
+use uuid::Uuid;
 
 #[derive(Debug, serde::Deserialize)]
 pub struct CardmarketPriceGuide {
     #[serde(rename(deserialize = "idProduct"))]
     pub id_product: u32,
+    #[serde(rename(deserialize = "idCategory"))]
+    pub id_category: Option<u32>,
     pub avg: Option<f32>,
     pub low: Option<f32>,
     pub trend: Option<f32>,
@@ -36,6 +34,7 @@ impl From<CardmarketPriceGuide> for FullPriceGuide {
     fn from(value: CardmarketPriceGuide) -> Self {
         FullPriceGuide {
             id_product: value.id_product,
+            id_category: value.id_category,
             normal: PriceGuide::new(value.low, value.trend, value.avg),
             foil: PriceGuide::new(value.low_foil, value.trend_foil, value.avg_foil),
         }
@@ -72,9 +71,254 @@ pub struct EdhRecJsonDict {
 pub struct EdhRecCard {
     pub inclusion: i32,
     pub potential_decks: i32,
+    // Absent pour les cartes non classées
+    #[serde(default)]
+    pub rank: Option<i32>,
+}
+
+#[derive(Debug, serde::Deserialize)]
+pub struct EdhRecCommanderPage {
+    // Absent quand EDHREC redirige vers une autre page
+    #[serde(rename(deserialize = "pageProps"), default)]
+    pub page_props: Option<EdhRecCommanderProps>,
+}
+
+#[derive(Debug, serde::Deserialize)]
+pub struct EdhRecCommanderProps {
+    #[serde(default)]
+    pub data: Option<EdhRecCommanderData>,
+}
+
+#[derive(Debug, serde::Deserialize)]
+pub struct EdhRecCommanderData {
+    pub container: EdhRecCommanderContainer,
+}
+
+#[derive(Debug, serde::Deserialize)]
+pub struct EdhRecCommanderContainer {
+    pub json_dict: EdhRecCommanderJsonDict,
+}
+
+#[derive(Debug, serde::Deserialize)]
+pub struct EdhRecCommanderJsonDict {
+    #[serde(default)]
+    pub cardlists: Vec<EdhRecCardList>,
+}
+
+#[derive(Debug, serde::Deserialize)]
+pub struct EdhRecCardList {
+    pub tag: String,
+    #[serde(default)]
+    pub cardviews: Vec<EdhRecCardView>,
+}
+
+#[derive(Debug, serde::Deserialize)]
+pub struct EdhRecCardView {
+    pub name: String,
+    #[serde(default)]
+    pub synergy: f64,
+    #[serde(default)]
+    pub inclusion: i32,
+    #[serde(default)]
+    pub potential_decks: i32,
+}
+
+#[derive(Debug, serde::Serialize)]
+pub struct ScryfallCollectionRequest {
+    pub identifiers: Vec<ScryfallIdentifier>,
+}
+
+#[derive(Debug, serde::Serialize)]
+pub struct ScryfallIdentifier {
+    pub id: Uuid,
+}
+
+#[derive(Debug, serde::Deserialize)]
+pub struct ScryfallCollectionResponse {
+    /// Card objects, kept as is to be cached
+    pub data: Vec<serde_json::Value>,
+}
+
+#[derive(Debug, serde::Deserialize)]
+pub struct ScryfallBulkData {
+    pub download_uri: String,
+}
+
+/// Card object of Scryfall, as found in the bulk data files and the `/cards/collection` results.
+#[derive(Debug, serde::Deserialize)]
+pub struct ScryfallCardObject {
+    pub id: Uuid,
+    pub oracle_id: Option<Uuid>,
+    pub name: String,
+    pub set: String,
+    pub collector_number: String,
+    pub lang: String,
+    pub cardmarket_id: Option<u32>,
+    pub rarity: Option<String>,
+    pub mana_cost: Option<String>,
+    pub type_line: Option<String>,
+    pub oracle_text: Option<String>,
+    pub colors: Option<Vec<String>>,
+    #[serde(default)]
+    pub color_identity: Vec<String>,
+    pub image_uris: Option<ScryfallImageUris>,
+    #[serde(default)]
+    pub card_faces: Vec<ScryfallCardFace>,
 }
 
+/// Face of a multi-faced card, which carries what the card object leaves out.
 #[derive(Debug, serde::Deserialize)]
-pub struct ScryfallCardInfo {
-    pub cardmarket_id: Option<i32>,
+pub struct ScryfallCardFace {
+    pub oracle_id: Option<Uuid>,
+    pub mana_cost: Option<String>,
+    pub oracle_text: Option<String>,
+    pub colors: Option<Vec<String>>,
+    pub image_uris: Option<ScryfallImageUris>,
+}
+
+#[derive(Debug, Clone, serde::Deserialize)]
+pub struct ScryfallImageUris {
+    pub small: Option<String>,
+    pub normal: Option<String>,
+    pub large: Option<String>,
+}
+
+impl ScryfallCardObject {
+    /// Joins the non-empty values of the faces, `None` if no face has one.
+    fn join_faces(
+        &self,
+        value: fn(&ScryfallCardFace) -> Option<&String>,
+        separator: &str,
+    ) -> Option<String> {
+        let values: Vec<&str> = self
+            .card_faces
+            .iter()
+            .filter_map(value)
+            .map(String::as_str)
+            .filter(|v| !v.is_empty())
+            .collect();
+        (!values.is_empty()).then(|| values.join(separator))
+    }
+
+    fn metadata(&self) -> CardMetadata {
+        // Les cartes recto-verso n'ont ni coût ni texte ni image au niveau de la carte, seulement sur leurs faces
+        let image_uris = self.image_uris.clone().or_else(|| {
+            self.card_faces
+                .iter()
+                .find_map(|face| face.image_uris.clone())
+        });
+        let colors = match &self.colors {
+            Some(colors) => Color::parse_all(colors),
+            None => Color::parse_all(
+                self.card_faces
+                    .iter()
+                    .flat_map(|face| face.colors.iter().flatten()),
+            ),
+        };
+
+        CardMetadata {
+            oracle_id: self
+                .oracle_id
+                .or_else(|| self.card_faces.iter().find_map(|face| face.oracle_id)),
+            mana_cost: self
+                .mana_cost
+                .clone()
+                .filter(|cost| !cost.is_empty())
+                .or_else(|| self.join_faces(|face| face.mana_cost.as_ref(), " // ")),
+            type_line: self.type_line.clone(),
+            colors,
+            color_identity: Color::parse_all(&self.color_identity),
+            oracle_text: self
+                .oracle_text
+                .clone()
+                .or_else(|| self.join_faces(|face| face.oracle_text.as_ref(), "\n//\n")),
+            image_small_url: image_uris.as_ref().and_then(|uris| uris.small.clone()),
+            image_normal_url: image_uris.as_ref().and_then(|uris| uris.normal.clone()),
+            image_large_url: image_uris.and_then(|uris| uris.large),
+        }
+    }
+}
+
+impl From<ScryfallCardObject> for ScryfallCard {
+    fn from(value: ScryfallCardObject) -> Self {
+        let metadata = value.metadata();
+        ScryfallCard {
+            scryfall_id: value.id,
+            name: value.name,
+            set_code: value.set,
+            collector_number: value.collector_number,
+            lang: value.lang,
+            cardmarket_id: value.cardmarket_id,
+            rarity: value
+                .rarity
+                .and_then(|rarity| RarityCode::try_new(rarity).ok()),
+            metadata,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn scryfall_card_reads_the_metadata_of_the_card() {
+        let json = r#"{
+          "id": "0000579f-7b35-4ed3-b44c-db2a538066fe", "oracle_id": "44623693-51d6-49ad-8cd7-140505caf02f",
+          "name": "Fury Sliver", "set": "tsp", "collector_number": "157", "lang": "en",
+          "cardmarket_id": 15018, "rarity": "uncommon", "mana_cost": "{5}{R}", "type_line": "Creature — Sliver",
+          "oracle_text": "All Sliver creatures have double strike.", "colors": ["R"],
+          "color_identity": ["R"],
+          "image_uris": {"small": "https://img/small.jpg", "normal": "https://img/normal.jpg", "large": "https://img/large.jpg"}
+        }"#;
+
+        let card: ScryfallCard = serde_json::from_str::<ScryfallCardObject>(json)
+            .unwrap()
+            .into();
+
+        assert_eq!(card.cardmarket_id, Some(15018));
+        assert_eq!(card.rarity, Some(RarityCode::U));
+        assert_eq!(card.metadata.mana_cost.as_deref(), Some("{5}{R}"));
+        assert_eq!(
+            card.metadata.type_line.as_deref(),
+            Some("Creature — Sliver")
+        );
+        assert_eq!(card.metadata.colors, vec![Color::R]);
+        assert_eq!(card.metadata.color_identity, vec![Color::R]);
+        assert_eq!(
+            card.metadata.image_normal_url.as_deref(),
+            Some("https://img/normal.jpg")
+        );
+        assert!(card.metadata.oracle_id.is_some());
+    }
+
+    #[test]
+    fn scryfall_card_reads_the_metadata_of_a_double_faced_card_from_its_faces() {
+        let json = r#"{
+          "id": "00006596-1166-4a79-8443-ca9f82e6db4e", "name": "Delver of Secrets // Insectile Aberration",
+          "set": "isd", "collector_number": "51", "lang": "en",
+          "type_line": "Creature — Human Wizard // Creature — Human Insect", "color_identity": ["U"],
+          "card_faces": [
+            {"oracle_id": "11bf83bb-c95b-4b4f-9a56-ce7a1816307a", "mana_cost": "{U}", "oracle_text": "Transform it.",
+             "colors": ["U"], "image_uris": {"small": "https://img/front.jpg"}},
+            {"mana_cost": "", "oracle_text": "Flying", "colors": ["U"], "image_uris": {"small": "https://img/back.jpg"}}
+          ]
+        }"#;
+
+        let card: ScryfallCard = serde_json::from_str::<ScryfallCardObject>(json)
+            .unwrap()
+            .into();
+
+        assert_eq!(card.metadata.mana_cost.as_deref(), Some("{U}"));
+        assert_eq!(
+            card.metadata.oracle_text.as_deref(),
+            Some("Transform it.\n//\nFlying")
+        );
+        assert_eq!(card.metadata.colors, vec![Color::U]);
+        assert_eq!(
+            card.metadata.image_small_url.as_deref(),
+            Some("https://img/front.jpg")
+        );
+        assert!(card.metadata.oracle_id.is_some());
+    }
 }
@@ -1,10 +1,18 @@
 use crate::application::caller::EdhRecCaller;
 use crate::application::error::{AppError, InfraError};
 use crate::domain::card::CardInfo;
-use crate::infrastructure::adapter_out::caller::dto::EdhRecCardInfo;
+use crate::domain::commander_synergy::{CommanderRecommendation, RecommendationList};
+use crate::domain::http_cache::HttpCacheSource;
+use crate::infrastructure::adapter_out::caller::dto::{
+    EdhRecCardInfo, EdhRecCardList, EdhRecCommanderPage,
+};
+use crate::infrastructure::adapter_out::caller::http_client::HttpClient;
 use async_trait::async_trait;
 use chrono::{Duration, NaiveDateTime, Utc};
+use reqwest::StatusCode;
+use std::collections::HashSet;
 use std::option::Option;
+use std::sync::Arc;
 use tokio::sync::RwLock;
 
 struct BuildIdCache {
@@ -13,15 +21,15 @@ struct BuildIdCache {
 }
 
 pub struct EdhRecCallerAdapter {
-    client: reqwest::Client,
+    client: Arc<HttpClient>,
     edh_rec_base_url: String,
     cache: RwLock<BuildIdCache>,
 }
 
 impl EdhRecCallerAdapter {
-    pub fn new(edh_rec_base_url: impl Into<String>) -> Self {
+    pub fn new(edh_rec_base_url: impl Into<String>, client: Arc<HttpClient>) -> Self {
         Self {
-            client: reqwest::Client::new(),
+            client,
             edh_rec_base_url: edh_rec_base_url.into(),
             cache: RwLock::new(BuildIdCache {
                 id: None,
@@ -32,14 +40,12 @@ impl EdhRecCallerAdapter {
 
     async fn get_build_id(&self) -> Result<String, AppError> {
         let url = self.edh_rec_base_url.clone() + "/faq";
-        println!("Fetching build ID from {}", url);
+        tracing::debug!("Fetching build ID from {}", url);
 
         let html = self
             .client
-            .get(url)
-            .send()
-            .await
-            .map_err(|e| InfraError::CallError(format!("edhrec request error: {e}")))?
+            .send(self.client.get(url))
+            .await?
             .text()
             .await
             .map_err(|e| InfraError::CallError(format!("edhrec response read error: {e}")))?;
@@ -65,7 +71,7 @@ impl EdhRecCallerAdapter {
             .ok_or_else(|| InfraError::CallError("buildId not found in __NEXT_DATA__".to_string()))?
             .to_string();
 
-        println!("Build ID: {build_id}");
+        tracing::debug!("Build ID: {build_id}");
 
         Ok(build_id)
     }
@@ -101,6 +107,36 @@ impl EdhRecCallerAdapter {
     }
 }
 
+// Les listes "high synergy" puis "top" de la page ; une carte présente dans les deux n'est gardée
+// qu'une fois
+fn to_recommendations(cardlists: Vec<EdhRecCardList>) -> Vec<CommanderRecommendation> {
+    let mut seen = HashSet::new();
+    let mut recommendations = Vec::new();
+
+    for (tag, list) in [
+        ("highsynergycards", RecommendationList::HighSynergy),
+        ("topcards", RecommendationList::Top),
+    ] {
+        let cards = cardlists
+            .iter()
+            .filter(|cardlist| cardlist.tag == tag)
+            .flat_map(|cardlist| &cardlist.cardviews);
+        for card in cards {
+            if seen.insert(card.name.as_str()) {
+                recommendations.push(CommanderRecommendation {
+                    name: card.name.clone(),
+                    list,
+                    synergy: card.synergy,
+                    inclusion: card.inclusion as u32,
+                    potential_decks: card.potential_decks as u32,
+                });
+            }
+        }
+    }
+
+    recommendations
+}
+
 #[async_trait]
 impl EdhRecCaller for EdhRecCallerAdapter {
     async fn get_card_info(&self, card_name: String) -> Result<CardInfo, AppError> {
@@ -113,19 +149,55 @@ impl EdhRecCaller for EdhRecCallerAdapter {
             self.get_card_id_from_name(&card_name)
         );
 
-        println!("Fetching cardinfo from {}", url);
+        tracing::debug!("Fetching card info from {}", url);
 
-        let card_info: EdhRecCardInfo = self.client.get(url).send().await?.json().await?;
+        let card_info: EdhRecCardInfo = self
+            .client
+            .get_cached(HttpCacheSource::EdhRec, &url)
+            .await?
+            .json()?;
 
         let edh_rec_card = card_info.page_props.data.container.json_dict.card;
 
-        println!("edh rec card info: {:?}", edh_rec_card);
+        tracing::debug!("EDHREC card info: {:?}", edh_rec_card);
 
         Ok(CardInfo {
             inclusion: edh_rec_card.inclusion as u32,
             total_decks: edh_rec_card.potential_decks as u32,
+            rank: edh_rec_card.rank.map(|rank| rank as u32),
         })
     }
+
+    async fn get_commander_recommendations(
+        &self,
+        commander_name: String,
+    ) -> Result<Option<Vec<CommanderRecommendation>>, AppError> {
+        self.update_build_id().await?;
+
+        let url = format!(
+            "{}/_next/data/{}/commanders/{}.json",
+            self.edh_rec_base_url,
+            self.cache.read().await.id.as_ref().unwrap(),
+            self.get_card_id_from_name(&commander_name)
+        );
+
+        tracing::debug!("Fetching commander page from {}", url);
+
+        let response = self
+            .client
+            .get_cached(HttpCacheSource::EdhRec, &url)
+            .await?;
+        if response.status == StatusCode::NOT_FOUND {
+            return Ok(None);
+        }
+
+        let page: EdhRecCommanderPage = response.error_for_status()?.json()?;
+
+        Ok(page
+            .page_props
+            .and_then(|props| props.data)
+            .map(|data| to_recommendations(data.container.json_dict.cardlists)))
+    }
 }
 
 #[cfg(test)]
@@ -135,7 +207,7 @@ mod tests {
     use wiremock::{Mock, MockServer, ResponseTemplate};
 
     fn create_adapter(edh_rec_base_url: impl Into<String>) -> EdhRecCallerAdapter {
-        EdhRecCallerAdapter::new(edh_rec_base_url)
+        EdhRecCallerAdapter::new(edh_rec_base_url, Arc::new(HttpClient::for_testing()))
     }
 
     #[tokio::test]
@@ -248,7 +320,7 @@ mod tests {
             .mount(&mock_server)
             .await;
 
-        let adapter = EdhRecCallerAdapter::new(mock_server.uri());
+        let adapter = create_adapter(mock_server.uri());
         let result = adapter.get_build_id().await;
 
         assert!(result.is_err());
@@ -444,7 +516,8 @@ mod tests {
                         "json_dict": {
                             "card": {
                                 "inclusion": 1500,
-                                "potential_decks": 50000
+                                "potential_decks": 50000,
+                                "rank": 42
                             }
                         }
                     }
@@ -473,6 +546,7 @@ mod tests {
         let card_info = result.unwrap();
         assert_eq!(card_info.inclusion, 1500);
         assert_eq!(card_info.total_decks, 50000);
+        assert_eq!(card_info.rank, Some(42));
     }
 
     #[tokio::test]
@@ -723,5 +797,155 @@ mod tests {
         let card_info = result.unwrap();
         assert_eq!(card_info.inclusion, 0);
         assert_eq!(card_info.total_decks, 0);
+        assert_eq!(card_info.rank, None);
+    }
+
+    async fn mount_build_id(mock_server: &MockServer) {
+        let build_id_response = r#"
+            <html>
+                <script id="__NEXT_DATA__">
+                {"buildId":"test-build-id","other":"data"}
+                </script>
+            </html>
+            "#;
+
+        Mock::given(path("/faq"))
+            .respond_with(ResponseTemplate::new(200).set_body_string(build_id_response))
+            .mount(mock_server)
+            .await;
+    }
+
+    #[tokio::test]
+    async fn get_commander_recommendations_returns_high_synergy_then_top_cards() {
+        let mock_server = MockServer::start().await;
+        mount_build_id(&mock_server).await;
+
+        let commander_response = r#"
+            {
+                "pageProps": {
+                    "data": {
+                        "container": {
+                            "json_dict": {
+                                "cardlists": [
+                                    {
+                                        "tag": "newcards",
+                                        "cardviews": [{ "name": "New Card", "synergy": 0.1 }]
+                                    },
+                                    {
+                                        "tag": "topcards",
+                                        "cardviews": [
+                                            { "name": "Sol Ring", "synergy": 0.0, "inclusion": 900, "potential_decks": 1000 },
+                                            { "name": "Doubling Season", "synergy": 0.4, "inclusion": 500, "potential_decks": 1000 }
+                                        ]
+                                    },
+                                    {
+                                        "tag": "highsynergycards",
+                                        "cardviews": [
+                                            { "name": "Doubling Season", "synergy": 0.4, "inclusion": 500, "potential_decks": 1000 }
+                                        ]
+                                    }
+                                ]
+                            }
+                        }
+                    }
+                }
+            }
+            "#;
+
+        Mock::given(path(
+            "/_next/data/test-build-id/commanders/atraxa-praetors-voice.json",
+        ))
+        .respond_with(ResponseTemplate::new(200).set_body_string(commander_response))
+        .mount(&mock_server)
+        .await;
+
+        let adapter = create_adapter(mock_server.uri());
+        let result = adapter
+            .get_commander_recommendations("Atraxa, Praetors' Voice".to_string())
+            .await
+            .unwrap()
+            .unwrap();
+
+        assert_eq!(
+            result,
+            vec![
+                CommanderRecommendation {
+                    name: "Doubling Season".to_string(),
+                    list: RecommendationList::HighSynergy,
+                    synergy: 0.4,
+                    inclusion: 500,
+                    potential_decks: 1000,
+                },
+                CommanderRecommendation {
+                    name: "Sol Ring".to_string(),
+                    list: RecommendationList::Top,
+                    synergy: 0.0,
+                    inclusion: 900,
+                    potential_decks: 1000,
+                },
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn get_commander_recommendations_returns_none_when_page_not_found() {
+        let mock_server = MockServer::start().await;
+        mount_build_id(&mock_server).await;
+
+        Mock::given(path("/_next/data/test-build-id/commanders/unknown.json"))
+            .respond_with(ResponseTemplate::new(404))
+            .mount(&mock_server)
+            .await;
+
+        let adapter = create_adapter(mock_server.uri());
+        let result = adapter
+            .get_commander_recommendations("Unknown".to_string())
+            .await;
+
+        assert_eq!(result.unwrap(), None);
+    }
+
+    #[tokio::test]
+    async fn get_commander_recommendations_returns_none_when_page_redirects() {
+        let mock_server = MockServer::start().await;
+        mount_build_id(&mock_server).await;
+
+        Mock::given(path("/_next/data/test-build-id/commanders/sol-ring.json"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .set_body_string(r#"{"pageProps":{"__N_REDIRECT":"/cards/sol-ring"}}"#),
+            )
+            .mount(&mock_server)
+            .await;
+
+        let adapter = create_adapter(mock_server.uri());
+        let result = adapter
+            .get_commander_recommendations("Sol Ring".to_string())
+            .await;
+
+        assert_eq!(result.unwrap(), None);
+    }
+
+    #[tokio::test]
+    async fn get_commander_recommendations_returns_call_error_on_server_error() {
+        let mock_server = MockServer::start().await;
+        mount_build_id(&mock_server).await;
+
+        Mock::given(path(
+            "/_next/data/test-build-id/commanders/atraxa-praetors-voice.json",
+        ))
+        .respond_with(ResponseTemplate::new(503))
+        .mount(&mock_server)
+        .await;
+
+        let adapter = create_adapter(mock_server.uri());
+        let result = adapter
+            .get_commander_recommendations("Atraxa, Praetors' Voice".to_string())
+            .await;
+
+        assert!(matches!(
+            result,
+            Err(AppError::Infra(InfraError::CallError(_)))
+        ));
     }
 }
@@ -1,42 +1,43 @@
 use crate::application::caller::GathererCaller;
 use crate::application::error::{AppError, InfraError};
+use crate::domain::card::GathererIds;
+use crate::domain::http_cache::HttpCacheSource;
 use crate::domain::language_code::LanguageCode;
 use crate::domain::set_name::SetCode;
+use crate::infrastructure::adapter_out::caller::http_client::HttpClient;
 use async_trait::async_trait;
-use ratelimit::{Ratelimiter, TryWaitError};
+use reqwest::StatusCode;
+use std::sync::Arc;
 
 pub struct GathererCallerAdapter {
-    client: reqwest::Client,
+    client: Arc<HttpClient>,
     gatherer_base_url: String,
-    ratelimiter: Ratelimiter,
 }
 
 impl GathererCallerAdapter {
-    pub fn new(gatherer_base_url: impl Into<String>) -> Self {
+    pub fn new(gatherer_base_url: impl Into<String>, client: Arc<HttpClient>) -> Self {
         Self {
-            client: reqwest::Client::builder()
-                .user_agent("reqwest")
-                .build()
-                .unwrap(),
+            client,
             gatherer_base_url: gatherer_base_url.into(),
-            ratelimiter: Ratelimiter::builder(2).max_tokens(2).build().unwrap(),
         }
     }
 }
 
 /// Converts a card name into the dash-separated slug used in Gatherer URLs
 /// (e.g. "Felothar, Dawn of the Abzan" -> "felothar-dawn-of-the-abzan").
-/// Double-faced / split card names ("Fire // Ice") only keep the first face.
+/// Accented letters are folded to ASCII, as Gatherer does ("Lim-Dûl's Vault" -> "lim-duls-vault").
 fn slugify(name: &str) -> String {
-    let name = name.split("//").next().unwrap_or(name);
     let mut slug = String::new();
     let mut last_was_dash = false;
-    for c in name.chars() {
-        if c == '\'' || c == '.' {
+    for c in name.chars().flat_map(char::to_lowercase) {
+        if c == '\'' || c == '’' || c == '.' {
             continue;
         }
         if c.is_ascii_alphanumeric() {
-            slug.push(c.to_ascii_lowercase());
+            slug.push(c);
+            last_was_dash = false;
+        } else if let Some(folded) = fold_diacritic(c) {
+            slug.push_str(folded);
             last_was_dash = false;
         } else if !last_was_dash {
             slug.push('-');
@@ -46,6 +47,37 @@ fn slugify(name: &str) -> String {
     slug.trim_matches('-').to_string()
 }
 
+/// ASCII form of the lowercase Latin letters found in card names.
+fn fold_diacritic(c: char) -> Option<&'static str> {
+    Some(match c {
+        'à' | 'á' | 'â' | 'ã' | 'ä' | 'å' => "a",
+        'æ' => "ae",
+        'ç' => "c",
+        'è' | 'é' | 'ê' | 'ë' => "e",
+        'ì' | 'í' | 'î' | 'ï' => "i",
+        'ñ' => "n",
+        'ò' | 'ó' | 'ô' | 'õ' | 'ö' | 'ø' => "o",
+        'œ' => "oe",
+        'ù' | 'ú' | 'û' | 'ü' => "u",
+        'ý' | 'ÿ' => "y",
+        'ß' => "ss",
+        _ => return None,
+    })
+}
+
+/// Slugs to try for a card name, in order. ManaBox writes every multi-face card as
+/// "Front // Back", whereas Gatherer names a split card page after both halves
+/// ("Fire // Ice" -> "fire-ice") and a double-faced or adventure card page after its front face.
+fn candidate_slugs(name: &str) -> Vec<String> {
+    let mut slugs = vec![slugify(name)];
+    if let Some((front, _)) = name.split_once("//") {
+        slugs.push(slugify(front));
+    }
+    slugs.retain(|slug| !slug.is_empty());
+    slugs.dedup();
+    slugs
+}
+
 /// Extracts the Gatherer image id from an `og:image` URL, e.g.
 /// `https://gatherer-static.wizards.com/Cards/medium/<ID>.webp` -> `<ID>`.
 fn extract_image_id(image_url: &str) -> Option<String> {
@@ -58,6 +90,31 @@ fn extract_image_id(image_url: &str) -> Option<String> {
     }
 }
 
+/// Reads the face ids of a Gatherer page: one `og:image` per face, the front face first.
+/// Split cards share a single image for both halves.
+fn extract_gatherer_ids(html: &str) -> Result<Option<GathererIds>, AppError> {
+    let document = scraper::Html::parse_document(html);
+    let selector = scraper::Selector::parse(r#"meta[property="og:image"]"#)
+        .map_err(|e| InfraError::CallError(format!("invalid selector: {e}")))?;
+
+    let mut ids: Vec<String> = Vec::new();
+    for id in document
+        .select(&selector)
+        .filter_map(|el| el.value().attr("content"))
+        .filter_map(extract_image_id)
+    {
+        if !ids.contains(&id) {
+            ids.push(id);
+        }
+    }
+
+    let mut ids = ids.into_iter();
+    Ok(ids.next().map(|front| GathererIds {
+        front,
+        back: ids.next(),
+    }))
+}
+
 #[async_trait]
 impl GathererCaller for GathererCallerAdapter {
     async fn get_gatherer_id(
@@ -66,66 +123,48 @@ impl GathererCaller for GathererCallerAdapter {
         collector_number: String,
         language_code: LanguageCode,
         name: String,
-    ) -> Result<Option<String>, AppError> {
-        let url = format!(
-            "{}/{}/{}/{}/{}",
-            self.gatherer_base_url,
-            set_code,
-            language_code.gatherer_locale(),
-            collector_number,
-            slugify(&name),
-        );
+    ) -> Result<Option<GathererIds>, AppError> {
+        for slug in candidate_slugs(&name) {
+            let url = format!(
+                "{}/{}/{}/{}/{}",
+                self.gatherer_base_url,
+                set_code,
+                language_code.gatherer_locale(),
+                collector_number,
+                slug,
+            );
 
-        if let Err(err) = self.ratelimiter.try_wait() {
-            match err {
-                TryWaitError::Insufficient(duration) => {
-                    tokio::time::sleep(duration).await;
-                }
-                TryWaitError::ExceedsCapacity => {
-                    return Err(InfraError::CallError(
-                        "Gatherer rate limiter overflow".to_string(),
-                    )
-                    .into());
-                }
-                _ => {
-                    return Err(
-                        InfraError::CallError("Gatherer rate limiter error".to_string()).into(),
-                    );
-                }
+            // Une erreur serveur persistante n'indique pas que la carte est absente de Gatherer
+            let response = self
+                .client
+                .get_cached(HttpCacheSource::Gatherer, &url)
+                .await?;
+            let status = response.status;
+            if status.is_server_error() || status == StatusCode::TOO_MANY_REQUESTS {
+                return Err(
+                    InfraError::CallError(format!("Gatherer answered {status} for {url}")).into(),
+                );
             }
-        }
 
-        let response = self.client.get(&url).send().await?;
+            if !status.is_success() {
+                tracing::debug!("Gatherer page not found for {url} (status {status})");
+                continue;
+            }
 
-        if !response.status().is_success() {
-            tracing::warn!(
-                "Gatherer page not found for {url} (status {})",
-                response.status()
-            );
-            return Ok(None);
+            match extract_gatherer_ids(&response.body)? {
+                Some(ids) => return Ok(Some(ids)),
+                None => tracing::warn!("Gatherer page for {url} has no usable og:image meta tag"),
+            }
         }
 
-        let html = response.text().await?;
-
-        let document = scraper::Html::parse_document(&html);
-        let selector = scraper::Selector::parse(r#"meta[property="og:image"]"#)
-            .map_err(|e| InfraError::CallError(format!("invalid selector: {e}")))?;
-
-        let Some(content) = document
-            .select(&selector)
-            .next()
-            .and_then(|el| el.value().attr("content"))
-        else {
-            tracing::warn!("Gatherer page for {url} has no og:image meta tag");
-            return Ok(None);
-        };
-
-        let Some(id) = extract_image_id(content) else {
-            tracing::warn!("Gatherer og:image URL for {url} has no extractable id: {content}");
-            return Ok(None);
-        };
-
-        Ok(Some(id))
+        tracing::warn!(
+            "No Gatherer page found for {} {} ({}) \"{}\"",
+            set_code,
+            collector_number,
+            language_code,
+            name
+        );
+        Ok(None)
     }
 }
 
@@ -136,7 +175,27 @@ mod tests {
     use wiremock::{Mock, MockServer, ResponseTemplate};
 
     fn og_image_html(content: &str) -> String {
-        format!(r#"<html><head><meta property="og:image" content="{content}"/></head></html>"#)
+        og_images_html(&[content])
+    }
+
+    /// Gatherer page with one `og:image` per entry of `contents`.
+    fn og_images_html(contents: &[&str]) -> String {
+        let metas: String = contents
+            .iter()
+            .map(|content| format!(r#"<meta property="og:image" content="{content}"/>"#))
+            .collect();
+        format!("<html><head>{metas}</head></html>")
+    }
+
+    fn image_url(id: &str) -> String {
+        format!("https://gatherer-static.wizards.com/Cards/medium/{id}.webp")
+    }
+
+    fn front_only(id: &str) -> Option<GathererIds> {
+        Some(GathererIds {
+            front: id.to_string(),
+            back: None,
+        })
     }
 
     #[test]
@@ -166,16 +225,57 @@ mod tests {
     }
 
     #[test]
-    fn slugify_keeps_only_first_face_of_double_faced_card() {
+    fn slugify_folds_accented_letters() {
+        assert_eq!(slugify("Lim-Dûl's Vault"), "lim-duls-vault");
+        assert_eq!(slugify("Æther Vial"), "aether-vial");
+        assert_eq!(slugify("Jötun Grunt"), "jotun-grunt");
+    }
+
+    #[test]
+    fn slugify_removes_typographic_apostrophes() {
+        assert_eq!(slugify("Urza’s Saga"), "urzas-saga");
+    }
+
+    #[test]
+    fn candidate_slugs_tries_both_faces_then_the_front_face() {
         assert_eq!(
-            slugify("Delver of Secrets // Insectile Aberration"),
-            "delver-of-secrets"
+            candidate_slugs("Delver of Secrets // Insectile Aberration"),
+            vec![
+                "delver-of-secrets-insectile-aberration",
+                "delver-of-secrets"
+            ]
         );
     }
 
     #[test]
-    fn slugify_keeps_only_first_face_without_surrounding_spaces() {
-        assert_eq!(slugify("Fire//Ice"), "fire");
+    fn candidate_slugs_handles_faces_without_surrounding_spaces() {
+        assert_eq!(candidate_slugs("Fire//Ice"), vec!["fire-ice", "fire"]);
+    }
+
+    #[test]
+    fn candidate_slugs_has_a_single_slug_for_a_single_faced_card() {
+        assert_eq!(
+            candidate_slugs("Wanderbrine Preacher"),
+            vec!["wanderbrine-preacher"]
+        );
+    }
+
+    #[test]
+    fn extract_gatherer_ids_reads_one_id_per_face_without_duplicates() {
+        let html = og_images_html(&[&image_url("FRONT"), &image_url("FRONT"), &image_url("BACK")]);
+
+        assert_eq!(
+            extract_gatherer_ids(&html).unwrap(),
+            Some(GathererIds {
+                front: "FRONT".to_string(),
+                back: Some("BACK".to_string()),
+            })
+        );
+    }
+
+    #[test]
+    fn extract_gatherer_ids_returns_none_without_og_image() {
+        assert_eq!(extract_gatherer_ids("<html></html>").unwrap(), None);
     }
 
     #[test]
@@ -203,7 +303,8 @@ mod tests {
             .mount(&mock_server)
             .await;
 
-        let adapter = GathererCallerAdapter::new(mock_server.uri());
+        let adapter =
+            GathererCallerAdapter::new(mock_server.uri(), Arc::new(HttpClient::for_testing()));
         let result = adapter
             .get_gatherer_id(
                 SetCode::new("ECL"),
@@ -214,7 +315,7 @@ mod tests {
             .await;
 
         assert!(result.is_ok());
-        assert_eq!(result.unwrap(), Some("ABC123".to_string()));
+        assert_eq!(result.unwrap(), front_only("ABC123"));
     }
 
     #[tokio::test]
@@ -226,7 +327,8 @@ mod tests {
             .mount(&mock_server)
             .await;
 
-        let adapter = GathererCallerAdapter::new(mock_server.uri());
+        let adapter =
+            GathererCallerAdapter::new(mock_server.uri(), Arc::new(HttpClient::for_testing()));
         let result = adapter
             .get_gatherer_id(
                 SetCode::new("ECL"),
@@ -249,7 +351,8 @@ mod tests {
             .mount(&mock_server)
             .await;
 
-        let adapter = GathererCallerAdapter::new(mock_server.uri());
+        let adapter =
+            GathererCallerAdapter::new(mock_server.uri(), Arc::new(HttpClient::for_testing()));
         let result = adapter
             .get_gatherer_id(
                 SetCode::new("ECL"),
@@ -274,7 +377,8 @@ mod tests {
             .mount(&mock_server)
             .await;
 
-        let adapter = GathererCallerAdapter::new(mock_server.uri());
+        let adapter =
+            GathererCallerAdapter::new(mock_server.uri(), Arc::new(HttpClient::for_testing()));
         let result = adapter
             .get_gatherer_id(
                 SetCode::new("FDN"),
@@ -285,6 +389,174 @@ mod tests {
             .await;
 
         assert!(result.is_ok());
-        assert_eq!(result.unwrap(), Some("XYZ789".to_string()));
+        assert_eq!(result.unwrap(), front_only("XYZ789"));
+    }
+
+    #[tokio::test]
+    async fn get_gatherer_id_returns_call_error_when_gatherer_keeps_failing() {
+        let mock_server = MockServer::start().await;
+
+        Mock::given(path("/ECL/en-us/41/wanderbrine-preacher"))
+            .respond_with(ResponseTemplate::new(503))
+            .mount(&mock_server)
+            .await;
+
+        let adapter =
+            GathererCallerAdapter::new(mock_server.uri(), Arc::new(HttpClient::for_testing()));
+        let result = adapter
+            .get_gatherer_id(
+                SetCode::new("ECL"),
+                "41".to_string(),
+                LanguageCode::EN,
+                "Wanderbrine Preacher".to_string(),
+            )
+            .await;
+
+        assert!(matches!(
+            result,
+            Err(AppError::Infra(InfraError::CallError(_)))
+        ));
+    }
+
+    #[tokio::test]
+    async fn get_gatherer_id_returns_both_faces_of_a_transform_card() {
+        let mock_server = MockServer::start().await;
+
+        Mock::given(path("/ISD/en-us/51/delver-of-secrets"))
+            .respond_with(ResponseTemplate::new(200).set_body_string(og_images_html(&[
+                &image_url("DELVER"),
+                &image_url("INSECTILE"),
+            ])))
+            .mount(&mock_server)
+            .await;
+
+        let adapter =
+            GathererCallerAdapter::new(mock_server.uri(), Arc::new(HttpClient::for_testing()));
+        let result = adapter
+            .get_gatherer_id(
+                SetCode::new("ISD"),
+                "51".to_string(),
+                LanguageCode::EN,
+                "Delver of Secrets // Insectile Aberration".to_string(),
+            )
+            .await;
+
+        assert_eq!(
+            result.unwrap(),
+            Some(GathererIds {
+                front: "DELVER".to_string(),
+                back: Some("INSECTILE".to_string()),
+            })
+        );
+    }
+
+    #[tokio::test]
+    async fn get_gatherer_id_returns_both_faces_of_a_modal_double_faced_card() {
+        let mock_server = MockServer::start().await;
+
+        Mock::given(path("/KHM/fr-fr/114/valki-god-of-lies"))
+            .respond_with(ResponseTemplate::new(200).set_body_string(og_images_html(&[
+                &image_url("VALKI"),
+                &image_url("VALKI"),
+                &image_url("TIBALT"),
+            ])))
+            .mount(&mock_server)
+            .await;
+
+        let adapter =
+            GathererCallerAdapter::new(mock_server.uri(), Arc::new(HttpClient::for_testing()));
+        let result = adapter
+            .get_gatherer_id(
+                SetCode::new("KHM"),
+                "114".to_string(),
+                LanguageCode::FR,
+                "Valki, God of Lies // Tibalt, Cosmic Impostor".to_string(),
+            )
+            .await;
+
+        assert_eq!(
+            result.unwrap(),
+            Some(GathererIds {
+                front: "VALKI".to_string(),
+                back: Some("TIBALT".to_string()),
+            })
+        );
+    }
+
+    #[tokio::test]
+    async fn get_gatherer_id_finds_a_split_card_under_both_halves() {
+        let mock_server = MockServer::start().await;
+
+        Mock::given(path("/APC/en-us/128/fire-ice"))
+            .respond_with(
+                ResponseTemplate::new(200).set_body_string(og_image_html(&image_url("FIREICE"))),
+            )
+            .mount(&mock_server)
+            .await;
+        Mock::given(path("/APC/en-us/128/fire"))
+            .respond_with(
+                ResponseTemplate::new(200).set_body_string(og_image_html(&image_url("WRONG"))),
+            )
+            .expect(0)
+            .mount(&mock_server)
+            .await;
+
+        let adapter =
+            GathererCallerAdapter::new(mock_server.uri(), Arc::new(HttpClient::for_testing()));
+        let result = adapter
+            .get_gatherer_id(
+                SetCode::new("APC"),
+                "128".to_string(),
+                LanguageCode::EN,
+                "Fire // Ice".to_string(),
+            )
+            .await;
+
+        assert_eq!(result.unwrap(), front_only("FIREICE"));
+    }
+
+    #[tokio::test]
+    async fn get_gatherer_id_finds_a_card_with_an_accented_name() {
+        let mock_server = MockServer::start().await;
+
+        Mock::given(path("/ALL/en-us/45/lim-duls-vault"))
+            .respond_with(
+                ResponseTemplate::new(200).set_body_string(og_image_html(&image_url("LIMDUL"))),
+            )
+            .mount(&mock_server)
+            .await;
+
+        let adapter =
+            GathererCallerAdapter::new(mock_server.uri(), Arc::new(HttpClient::for_testing()));
+        let result = adapter
+            .get_gatherer_id(
+                SetCode::new("ALL"),
+                "45".to_string(),
+                LanguageCode::EN,
+                "Lim-Dûl's Vault".to_string(),
+            )
+            .await;
+
+        assert_eq!(result.unwrap(), front_only("LIMDUL"));
+    }
+
+    #[tokio::test]
+    async fn get_gatherer_id_returns_none_when_no_face_has_a_page() {
+        let mock_server = MockServer::start().await;
+
+        let adapter =
+            GathererCallerAdapter::new(mock_server.uri(), Arc::new(HttpClient::for_testing()));
+        let result = adapter
+            .get_gatherer_id(
+                SetCode::new("ISD"),
+                "51".to_string(),
+                LanguageCode::EN,
+                "Delver of Secrets // Insectile Aberration".to_string(),
+            )
+            .await;
+
+        assert_eq!(result.unwrap(), None);
+        let requests = mock_server.received_requests().await.unwrap();
+        assert_eq!(requests.len(), 2);
     }
 }
@@ -0,0 +1,861 @@
+use crate::application::error::{AppError, InfraError};
+use crate::application::repository::HttpResponseCacheRepository;
+use crate::domain::http_cache::{CachedHttpResponse, HttpCacheSource};
+use chrono::Utc;
+use ratelimit::{Ratelimiter, TryWaitError};
+use reqwest::header::{
+    ETAG, HeaderName, IF_MODIFIED_SINCE, IF_NONE_MATCH, LAST_MODIFIED, RETRY_AFTER,
+};
+use reqwest::{RequestBuilder, Response, StatusCode, Url};
+use serde::de::DeserializeOwned;
+use std::collections::HashMap;
+use std::collections::hash_map::RandomState;
+use std::hash::{BuildHasher, Hasher};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+/// Retry, timeout and circuit breaker settings shared by every outbound caller.
+#[derive(Debug, Clone)]
+pub struct HttpClientSettings {
+    pub connect_timeout: Duration,
+    /// Maximum time between two reads of the response, so long downloads are not cut
+    pub read_timeout: Duration,
+    pub max_retries: u32,
+    pub retry_base_delay: Duration,
+    /// Longest wait between two attempts; a `Retry-After` asking for more gives up instead
+    pub retry_max_delay: Duration,
+    /// Consecutive failed calls to a host before its circuit opens
+    pub circuit_breaker_threshold: u32,
+    pub circuit_breaker_cooldown: Duration,
+}
+
+/// Status and body of a response read by [`HttpClient::get_cached`].
+#[derive(Debug)]
+pub struct TextResponse {
+    pub url: String,
+    pub status: StatusCode,
+    pub body: String,
+}
+
+impl TextResponse {
+    async fn read(response: Response) -> Result<Self, AppError> {
+        Ok(Self {
+            url: response.url().to_string(),
+            status: response.status(),
+            body: response.text().await?,
+        })
+    }
+
+    pub fn error_for_status(self) -> Result<Self, AppError> {
+        if self.status.is_success() {
+            Ok(self)
+        } else {
+            Err(InfraError::CallError(format!("{} answered {}", self.url, self.status)).into())
+        }
+    }
+
+    pub fn json<T: DeserializeOwned>(&self) -> Result<T, AppError> {
+        serde_json::from_str(&self.body).map_err(|e| {
+            InfraError::CallError(format!(
+                "error decoding response body from {}: {e}",
+                self.url
+            ))
+            .into()
+        })
+    }
+}
+
+/// Successful responses kept by URL, fresh for the TTL of their source.
+struct ResponseCache {
+    repository: Arc<dyn HttpResponseCacheRepository>,
+    ttls: HashMap<HttpCacheSource, chrono::Duration>,
+}
+
+// Le cache n'est qu'une optimisation : une erreur du repository ne fait pas échouer l'appel
+impl ResponseCache {
+    async fn find(&self, url: &str) -> Option<CachedHttpResponse> {
+        self.repository
+            .find_by_url(url)
+            .await
+            .inspect_err(|err| tracing::warn!("Cannot read the cached response of {url}: {err:?}"))
+            .ok()
+            .flatten()
+    }
+
+    async fn save(&self, response: &CachedHttpResponse) {
+        if let Err(err) = self.repository.save(response).await {
+            tracing::warn!("Cannot cache the response of {}: {err:?}", response.url);
+        }
+    }
+
+    async fn mark_revalidated(&self, url: &str, at: chrono::DateTime<Utc>) {
+        if let Err(err) = self.repository.mark_revalidated(url, at).await {
+            tracing::warn!("Cannot mark the cached response of {url} as revalidated: {err:?}");
+        }
+    }
+
+    fn ttl(&self, source: HttpCacheSource) -> chrono::Duration {
+        self.ttls.get(&source).copied().unwrap_or_default()
+    }
+}
+
+#[derive(Default)]
+struct CircuitBreaker {
+    consecutive_failures: u32,
+    open_until: Option<Instant>,
+    /// Start of the single call probing the host once the cooldown elapsed
+    probe_started_at: Option<Instant>,
+}
+
+/// HTTP client used by the caller adapters. Requests are rate limited per host, retried with a
+/// jittered exponential backoff on 429, 5xx and transport errors, and fail fast while the circuit
+/// of their host is open.
+pub struct HttpClient {
+    client: reqwest::Client,
+    settings: HttpClientSettings,
+    rate_limiters: HashMap<String, Ratelimiter>,
+    circuit_breakers: Mutex<HashMap<String, CircuitBreaker>>,
+    cache: Option<ResponseCache>,
+}
+
+impl HttpClient {
+    pub fn new(settings: HttpClientSettings) -> Self {
+        Self {
+            client: reqwest::Client::builder()
+                .user_agent("reqwest")
+                .connect_timeout(settings.connect_timeout)
+                .read_timeout(settings.read_timeout)
+                .build()
+                .unwrap(),
+            settings,
+            rate_limiters: HashMap::new(),
+            circuit_breakers: Mutex::new(HashMap::new()),
+            cache: None,
+        }
+    }
+
+    /// Keeps the responses read by [`HttpClient::get_cached`] or given to [`HttpClient::cache`],
+    /// each source for its own TTL.
+    pub fn with_cache(
+        mut self,
+        repository: Arc<dyn HttpResponseCacheRepository>,
+        ttls: HashMap<HttpCacheSource, chrono::Duration>,
+    ) -> Self {
+        self.cache = Some(ResponseCache { repository, ttls });
+        self
+    }
+
+    /// Limits the requests sent to the host of `base_url` to `tokens` per second.
+    pub fn with_rate_limit(mut self, base_url: &str, tokens: u32) -> Self {
+        let tokens = u64::from(tokens);
+        let host = Url::parse(base_url)
+            .map(|url| host_key(&url))
+            .unwrap_or_else(|_| panic!("Invalid base URL: {base_url}"));
+        self.rate_limiters.insert(
+            host,
+            Ratelimiter::builder(tokens)
+                .max_tokens(tokens)
+                .build()
+                .unwrap(),
+        );
+        self
+    }
+
+    pub fn get(&self, url: impl AsRef<str>) -> RequestBuilder {
+        self.client.get(url.as_ref())
+    }
+
+    pub fn post(&self, url: impl AsRef<str>) -> RequestBuilder {
+        self.client.post(url.as_ref())
+    }
+
+    /// Sends the request and returns its response, whatever its status. A 429 or 5xx status is
+    /// only returned once the retries are exhausted.
+    pub async fn send(&self, request: RequestBuilder) -> Result<Response, AppError> {
+        let request = request.build()?;
+        let host = host_key(request.url());
+        self.check_circuit(&host)?;
+
+        let mut attempt = 0;
+        loop {
+            let retry_request = request.try_clone().ok_or_else(|| {
+                InfraError::CallError(format!("request to {host} cannot be retried"))
+            })?;
+            self.wait_for_token(&host).await?;
+
+            let (outcome, retry_after) = match self.client.execute(retry_request).await {
+                Ok(response) if !is_retryable(response.status()) => {
+                    self.record_success(&host);
+                    return Ok(response);
+                }
+                Ok(response) => {
+                    let retry_after = retry_after(&response);
+                    (Ok(response), retry_after)
+                }
+                Err(err) => (Err(err), None),
+            };
+
+            let delay = retry_after.unwrap_or_else(|| self.backoff(attempt));
+            if attempt >= self.settings.max_retries || delay > self.settings.retry_max_delay {
+                self.record_failure(&host);
+                return Ok(outcome?);
+            }
+
+            match &outcome {
+                Ok(response) => tracing::warn!(
+                    "{} answered {}, retrying in {:?}",
+                    host,
+                    response.status(),
+                    delay
+                ),
+                Err(err) => {
+                    tracing::warn!("Call to {} failed ({}), retrying in {:?}", host, err, delay)
+                }
+            }
+            tokio::time::sleep(delay).await;
+            attempt += 1;
+        }
+    }
+
+    /// Sends a GET whose successful body is cached by URL. A fresh cached body is returned
+    /// without calling the server, a stale one is revalidated with its `ETag` / `Last-Modified`.
+    pub async fn get_cached(
+        &self,
+        source: HttpCacheSource,
+        url: &str,
+    ) -> Result<TextResponse, AppError> {
+        let Some(cache) = &self.cache else {
+            return TextResponse::read(self.send(self.get(url)).await?).await;
+        };
+
+        let now = Utc::now();
+        let cached = cache.find(url).await;
+        let mut request = self.get(url);
+        if let Some(cached) = &cached {
+            if cached.is_fresh(cache.ttl(source), now) {
+                return Ok(TextResponse {
+                    url: url.to_string(),
+                    status: StatusCode::OK,
+                    body: cached.body.clone(),
+                });
+            }
+            if let Some(etag) = &cached.etag {
+                request = request.header(IF_NONE_MATCH, etag);
+            }
+            if let Some(last_modified) = &cached.last_modified {
+                request = request.header(IF_MODIFIED_SINCE, last_modified);
+            }
+        }
+
+        let response = self.send(request).await?;
+        if response.status() == StatusCode::NOT_MODIFIED
+            && let Some(cached) = cached
+        {
+            cache.mark_revalidated(url, now).await;
+            return Ok(TextResponse {
+                url: url.to_string(),
+                status: StatusCode::OK,
+                body: cached.body,
+            });
+        }
+
+        let etag = header_value(&response, ETAG);
+        let last_modified = header_value(&response, LAST_MODIFIED);
+        let response = TextResponse::read(response).await?;
+        if response.status == StatusCode::OK {
+            cache
+                .save(&CachedHttpResponse {
+                    url: url.to_string(),
+                    source,
+                    body: response.body.clone(),
+                    etag,
+                    last_modified,
+                    fetched_at: now,
+                })
+                .await;
+        }
+        Ok(response)
+    }
+
+    /// Body cached for `url` if still fresh. For the responses [`HttpClient::cache`] keeps,
+    /// which have no validators to be revalidated with.
+    pub async fn find_fresh(&self, source: HttpCacheSource, url: &str) -> Option<String> {
+        let cache = self.cache.as_ref()?;
+        cache
+            .find(url)
+            .await
+            .filter(|cached| cached.is_fresh(cache.ttl(source), Utc::now()))
+            .map(|cached| cached.body)
+    }
+
+    /// Caches `body` as the response of `url`, e.g. one item of a batched POST response.
+    pub async fn cache(&self, source: HttpCacheSource, url: &str, body: String) {
+        if let Some(cache) = &self.cache {
+            cache
+                .save(&CachedHttpResponse {
+                    url: url.to_string(),
+                    source,
+                    body,
+                    etag: None,
+                    last_modified: None,
+                    fetched_at: Utc::now(),
+                })
+                .await;
+        }
+    }
+
+    async fn wait_for_token(&self, host: &str) -> Result<(), AppError> {
+        let Some(ratelimiter) = self.rate_limiters.get(host) else {
+            return Ok(());
+        };
+        loop {
+            match ratelimiter.try_wait() {
+                Ok(()) => return Ok(()),
+                Err(TryWaitError::Insufficient(duration)) => tokio::time::sleep(duration).await,
+                Err(_) => {
+                    return Err(InfraError::CallError(format!("{host} rate limiter error")).into());
+                }
+            }
+        }
+    }
+
+    fn backoff(&self, attempt: u32) -> Duration {
+        let delay = self
+            .settings
+            .retry_base_delay
+            .saturating_mul(2u32.saturating_pow(attempt))
+            .min(self.settings.retry_max_delay);
+        jitter(delay)
+    }
+
+    /// Lets the call through unless the circuit of `host` is open. Once the cooldown elapsed the
+    /// circuit is half-open: a single call probes the host and the others fail fast until it
+    /// settles.
+    fn check_circuit(&self, host: &str) -> Result<(), AppError> {
+        let mut breakers = self.circuit_breakers.lock().unwrap();
+        let Some(breaker) = breakers.get_mut(host) else {
+            return Ok(());
+        };
+        let Some(open_until) = breaker.open_until else {
+            return Ok(());
+        };
+
+        let now = Instant::now();
+        // Une sonde annulée avant d'aboutir ne doit pas laisser le circuit ouvert pour toujours
+        let probing = breaker
+            .probe_started_at
+            .is_some_and(|started_at| now < started_at + self.settings.circuit_breaker_cooldown);
+        if now < open_until || probing {
+            return Err(InfraError::CallError(format!("circuit open for {host}")).into());
+        }
+
+        breaker.probe_started_at = Some(now);
+        Ok(())
+    }
+
+    fn record_success(&self, host: &str) {
+        self.circuit_breakers.lock().unwrap().remove(host);
+    }
+
+    fn record_failure(&self, host: &str) {
+        let mut breakers = self.circuit_breakers.lock().unwrap();
+        let breaker = breakers.entry(host.to_string()).or_default();
+        // L'échec de la sonde rouvre le circuit pour un nouveau délai
+        breaker.probe_started_at = None;
+        breaker.consecutive_failures += 1;
+        if breaker.consecutive_failures >= self.settings.circuit_breaker_threshold {
+            tracing::warn!(
+                "{} failed {} times in a row, opening its circuit for {:?}",
+                host,
+                breaker.consecutive_failures,
+                self.settings.circuit_breaker_cooldown
+            );
+            breaker.open_until = Some(Instant::now() + self.settings.circuit_breaker_cooldown);
+        }
+    }
+}
+
+fn host_key(url: &Url) -> String {
+    format!(
+        "{}:{}",
+        url.host_str().unwrap_or_default(),
+        url.port_or_known_default().unwrap_or_default()
+    )
+}
+
+fn header_value(response: &Response, name: HeaderName) -> Option<String> {
+    response
+        .headers()
+        .get(name)?
+        .to_str()
+        .ok()
+        .map(str::to_string)
+}
+
+fn is_retryable(status: StatusCode) -> bool {
+    status == StatusCode::TOO_MANY_REQUESTS || status.is_server_error()
+}
+
+/// Reads a `Retry-After` header given either in seconds or as an HTTP date.
+fn retry_after(response: &Response) -> Option<Duration> {
+    let value = response.headers().get(RETRY_AFTER)?.to_str().ok()?;
+    if let Ok(seconds) = value.trim().parse::<u64>() {
+        return Some(Duration::from_secs(seconds));
+    }
+    let date = chrono::DateTime::parse_from_rfc2822(value).ok()?;
+    Some(
+        (date.to_utc() - chrono::Utc::now())
+            .to_std()
+            .unwrap_or_default(),
+    )
+}
+
+// Entre la moitié et la totalité du délai, pour que les appels en échec ne repartent pas ensemble
+fn jitter(delay: Duration) -> Duration {
+    let half = delay / 2;
+    let random = RandomState::new().build_hasher().finish();
+    half + Duration::from_nanos(random % (half.as_nanos() as u64 + 1))
+}
+
+#[cfg(test)]
+impl HttpClient {
+    pub fn for_testing() -> Self {
+        Self::new(HttpClientSettings {
+            connect_timeout: Duration::from_secs(5),
+            read_timeout: Duration::from_secs(5),
+            max_retries: 2,
+            retry_base_delay: Duration::from_millis(1),
+            retry_max_delay: Duration::from_millis(10),
+            circuit_breaker_threshold: 100,
+            circuit_breaker_cooldown: Duration::from_secs(60),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::application::repository::MockHttpResponseCacheRepository;
+    use wiremock::matchers::{header, path};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    fn client_with(max_retries: u32, circuit_breaker_threshold: u32) -> HttpClient {
+        HttpClient::new(HttpClientSettings {
+            max_retries,
+            circuit_breaker_threshold,
+            ..HttpClient::for_testing().settings
+        })
+    }
+
+    async fn received(mock_server: &MockServer) -> usize {
+        mock_server.received_requests().await.unwrap().len()
+    }
+
+    fn cached_client(repository: MockHttpResponseCacheRepository) -> HttpClient {
+        HttpClient::for_testing().with_cache(
+            Arc::new(repository),
+            HashMap::from([(HttpCacheSource::Scryfall, chrono::Duration::hours(24))]),
+        )
+    }
+
+    fn cached(url: &str, age: chrono::Duration) -> CachedHttpResponse {
+        CachedHttpResponse {
+            url: url.to_string(),
+            source: HttpCacheSource::Scryfall,
+            body: "cached body".to_string(),
+            etag: Some("\"v1\"".to_string()),
+            last_modified: None,
+            fetched_at: Utc::now() - age,
+        }
+    }
+
+    #[tokio::test]
+    async fn send_retries_server_errors_until_success() {
+        let mock_server = MockServer::start().await;
+
+        Mock::given(path("/cards"))
+            .respond_with(ResponseTemplate::new(503))
+            .up_to_n_times(2)
+            .mount(&mock_server)
+            .await;
+        Mock::given(path("/cards"))
+            .respond_with(ResponseTemplate::new(200))
+            .mount(&mock_server)
+            .await;
+
+        let client = HttpClient::for_testing();
+        let response = client
+            .send(client.get(format!("{}/cards", mock_server.uri())))
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::OK);
+        assert_eq!(received(&mock_server).await, 3);
+    }
+
+    #[tokio::test]
+    async fn send_returns_last_response_when_retries_are_exhausted() {
+        let mock_server = MockServer::start().await;
+
+        Mock::given(path("/cards"))
+            .respond_with(ResponseTemplate::new(500))
+            .mount(&mock_server)
+            .await;
+
+        let client = HttpClient::for_testing();
+        let response = client
+            .send(client.get(format!("{}/cards", mock_server.uri())))
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::INTERNAL_SERVER_ERROR);
+        assert_eq!(received(&mock_server).await, 3);
+    }
+
+    #[tokio::test]
+    async fn send_does_not_retry_client_errors() {
+        let mock_server = MockServer::start().await;
+
+        Mock::given(path("/cards"))
+            .respond_with(ResponseTemplate::new(404))
+            .mount(&mock_server)
+            .await;
+
+        let client = HttpClient::for_testing();
+        let response = client
+            .send(client.get(format!("{}/cards", mock_server.uri())))
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::NOT_FOUND);
+        assert_eq!(received(&mock_server).await, 1);
+    }
+
+    #[tokio::test]
+    async fn send_retries_after_the_delay_asked_by_the_server() {
+        let mock_server = MockServer::start().await;
+
+        Mock::given(path("/cards"))
+            .respond_with(ResponseTemplate::new(429).insert_header("Retry-After", "0"))
+            .up_to_n_times(1)
+            .mount(&mock_server)
+            .await;
+        Mock::given(path("/cards"))
+            .respond_with(ResponseTemplate::new(200))
+            .mount(&mock_server)
+            .await;
+
+        let client = HttpClient::for_testing();
+        let response = client
+            .send(client.get(format!("{}/cards", mock_server.uri())))
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::OK);
+        assert_eq!(received(&mock_server).await, 2);
+    }
+
+    #[tokio::test]
+    async fn send_gives_up_when_retry_after_exceeds_the_max_delay() {
+        let mock_server = MockServer::start().await;
+
+        Mock::given(path("/cards"))
+            .respond_with(ResponseTemplate::new(429).insert_header("Retry-After", "120"))
+            .mount(&mock_server)
+            .await;
+
+        let client = HttpClient::for_testing();
+        let response = client
+            .send(client.get(format!("{}/cards", mock_server.uri())))
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::TOO_MANY_REQUESTS);
+        assert_eq!(received(&mock_server).await, 1);
+    }
+
+    #[tokio::test]
+    async fn send_fails_fast_while_the_circuit_is_open() {
+        let mock_server = MockServer::start().await;
+
+        Mock::given(path("/cards"))
+            .respond_with(ResponseTemplate::new(500))
+            .mount(&mock_server)
+            .await;
+
+        let client = client_with(0, 2);
+        let url = format!("{}/cards", mock_server.uri());
+        client.send(client.get(&url)).await.unwrap();
+        client.send(client.get(&url)).await.unwrap();
+        let result = client.send(client.get(&url)).await;
+
+        match result {
+            Err(AppError::Infra(InfraError::CallError(msg))) => {
+                assert!(msg.contains("circuit open"))
+            }
+            _ => panic!("Expected CallError"),
+        }
+        assert_eq!(received(&mock_server).await, 2);
+    }
+
+    #[tokio::test]
+    async fn send_lets_a_single_probe_through_once_the_cooldown_elapsed() {
+        let mock_server = MockServer::start().await;
+
+        Mock::given(path("/cards"))
+            .respond_with(ResponseTemplate::new(500))
+            .up_to_n_times(1)
+            .mount(&mock_server)
+            .await;
+        Mock::given(path("/cards"))
+            .respond_with(ResponseTemplate::new(200).set_delay(Duration::from_millis(200)))
+            .mount(&mock_server)
+            .await;
+
+        let client = HttpClient::new(HttpClientSettings {
+            circuit_breaker_cooldown: Duration::from_millis(100),
+            ..client_with(0, 1).settings
+        });
+        let url = format!("{}/cards", mock_server.uri());
+        client.send(client.get(&url)).await.unwrap();
+        tokio::time::sleep(Duration::from_millis(110)).await;
+
+        let (probe, during_probe) = tokio::join!(client.send(client.get(&url)), async {
+            tokio::time::sleep(Duration::from_millis(20)).await;
+            client.send(client.get(&url)).await
+        });
+        let after_probe = client.send(client.get(&url)).await;
+
+        assert_eq!(probe.unwrap().status(), StatusCode::OK);
+        assert!(matches!(
+            during_probe,
+            Err(AppError::Infra(InfraError::CallError(msg))) if msg.contains("circuit open")
+        ));
+        assert_eq!(after_probe.unwrap().status(), StatusCode::OK);
+        assert_eq!(received(&mock_server).await, 3);
+    }
+
+    #[tokio::test]
+    async fn send_resets_failures_after_a_success() {
+        let mock_server = MockServer::start().await;
+
+        Mock::given(path("/cards"))
+            .respond_with(ResponseTemplate::new(500))
+            .up_to_n_times(1)
+            .mount(&mock_server)
+            .await;
+        Mock::given(path("/cards"))
+            .respond_with(ResponseTemplate::new(200))
+            .up_to_n_times(1)
+            .mount(&mock_server)
+            .await;
+        Mock::given(path("/cards"))
+            .respond_with(ResponseTemplate::new(500))
+            .mount(&mock_server)
+            .await;
+
+        let client = client_with(0, 2);
+        let url = format!("{}/cards", mock_server.uri());
+        for _ in 0..3 {
+            client.send(client.get(&url)).await.unwrap();
+        }
+
+        assert_eq!(received(&mock_server).await, 3);
+    }
+
+    #[test]
+    fn jitter_stays_between_half_and_full_delay() {
+        let delay = Duration::from_millis(100);
+        for _ in 0..100 {
+            let jittered = jitter(delay);
+            assert!(jittered >= delay / 2 && jittered <= delay);
+        }
+    }
+
+    #[test]
+    fn host_key_includes_the_port() {
+        let url = Url::parse("https://api.scryfall.com/cards").unwrap();
+        assert_eq!(host_key(&url), "api.scryfall.com:443");
+    }
+
+    #[tokio::test]
+    async fn get_cached_returns_a_fresh_response_without_calling_the_server() {
+        let mock_server = MockServer::start().await;
+        let url = format!("{}/cards/1", mock_server.uri());
+
+        let mut repository = MockHttpResponseCacheRepository::new();
+        let response = cached(&url, chrono::Duration::hours(1));
+        repository.expect_find_by_url().returning(move |_| {
+            let response = response.clone();
+            Box::pin(async move { Ok(Some(response)) })
+        });
+
+        let response = cached_client(repository)
+            .get_cached(HttpCacheSource::Scryfall, &url)
+            .await
+            .unwrap();
+
+        assert_eq!(response.status, StatusCode::OK);
+        assert_eq!(response.body, "cached body");
+        assert_eq!(received(&mock_server).await, 0);
+    }
+
+    #[tokio::test]
+    async fn get_cached_revalidates_a_stale_response_with_its_etag() {
+        let mock_server = MockServer::start().await;
+        let url = format!("{}/cards/1", mock_server.uri());
+
+        Mock::given(path("/cards/1"))
+            .and(header("If-None-Match", "\"v1\""))
+            .respond_with(ResponseTemplate::new(304))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        let mut repository = MockHttpResponseCacheRepository::new();
+        let response = cached(&url, chrono::Duration::hours(48));
+        repository.expect_find_by_url().returning(move |_| {
+            let response = response.clone();
+            Box::pin(async move { Ok(Some(response)) })
+        });
+        repository
+            .expect_mark_revalidated()
+            .times(1)
+            .returning(|_, _| Box::pin(async { Ok(()) }));
+        repository.expect_save().never();
+
+        let response = cached_client(repository)
+            .get_cached(HttpCacheSource::Scryfall, &url)
+            .await
+            .unwrap();
+
+        assert_eq!(response.status, StatusCode::OK);
+        assert_eq!(response.body, "cached body");
+    }
+
+    #[tokio::test]
+    async fn get_cached_stores_a_successful_response_with_its_validators() {
+        let mock_server = MockServer::start().await;
+        let url = format!("{}/cards/1", mock_server.uri());
+
+        Mock::given(path("/cards/1"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .insert_header("ETag", "\"v2\"")
+                    .set_body_string("fresh body"),
+            )
+            .mount(&mock_server)
+            .await;
+
+        let mut repository = MockHttpResponseCacheRepository::new();
+        repository
+            .expect_find_by_url()
+            .returning(|_| Box::pin(async { Ok(None) }));
+        repository
+            .expect_save()
+            .withf(|response| {
+                response.body == "fresh body"
+                    && response.etag.as_deref() == Some("\"v2\"")
+                    && response.source == HttpCacheSource::Scryfall
+            })
+            .times(1)
+            .returning(|_| Box::pin(async { Ok(()) }));
+
+        let response = cached_client(repository)
+            .get_cached(HttpCacheSource::Scryfall, &url)
+            .await
+            .unwrap();
+
+        assert_eq!(response.body, "fresh body");
+    }
+
+    #[tokio::test]
+    async fn get_cached_does_not_store_unsuccessful_responses() {
+        let mock_server = MockServer::start().await;
+        let url = format!("{}/cards/1", mock_server.uri());
+
+        Mock::given(path("/cards/1"))
+            .respond_with(ResponseTemplate::new(404))
+            .mount(&mock_server)
+            .await;
+
+        let mut repository = MockHttpResponseCacheRepository::new();
+        repository
+            .expect_find_by_url()
+            .returning(|_| Box::pin(async { Ok(None) }));
+        repository.expect_save().never();
+
+        let response = cached_client(repository)
+            .get_cached(HttpCacheSource::Scryfall, &url)
+            .await
+            .unwrap();
+
+        assert_eq!(response.status, StatusCode::NOT_FOUND);
+    }
+
+    #[tokio::test]
+    async fn get_cached_calls_the_server_when_the_cache_cannot_be_read() {
+        let mock_server = MockServer::start().await;
+        let url = format!("{}/cards/1", mock_server.uri());
+
+        Mock::given(path("/cards/1"))
+            .respond_with(ResponseTemplate::new(200).set_body_string("fresh body"))
+            .mount(&mock_server)
+            .await;
+
+        let mut repository = MockHttpResponseCacheRepository::new();
+        repository.expect_find_by_url().returning(|_| {
+            Box::pin(async { Err(InfraError::RepositoryError("db down".to_string()).into()) })
+        });
+        repository
+            .expect_save()
+            .returning(|_| Box::pin(async { Ok(()) }));
+
+        let response = cached_client(repository)
+            .get_cached(HttpCacheSource::Scryfall, &url)
+            .await
+            .unwrap();
+
+        assert_eq!(response.body, "fresh body");
+    }
+
+    #[tokio::test]
+    async fn find_fresh_ignores_a_stale_response() {
+        let url = "https://api.scryfall.com/cards/1";
+        let mut repository = MockHttpResponseCacheRepository::new();
+        let response = cached(url, chrono::Duration::hours(48));
+        repository.expect_find_by_url().returning(move |_| {
+            let response = response.clone();
+            Box::pin(async move { Ok(Some(response)) })
+        });
+
+        let body = cached_client(repository)
+            .find_fresh(HttpCacheSource::Scryfall, url)
+            .await;
+
+        assert_eq!(body, None);
+    }
+
+    #[tokio::test]
+    async fn cache_stores_the_body_without_validators() {
+        let mut repository = MockHttpResponseCacheRepository::new();
+        repository
+            .expect_save()
+            .withf(|response| {
+                response.url == "https://api.scryfall.com/cards/1"
+                    && response.body == "card body"
+                    && response.etag.is_none()
+                    && response.source == HttpCacheSource::Scryfall
+            })
+            .times(1)
+            .returning(|_| Box::pin(async { Ok(()) }));
+
+        cached_client(repository)
+            .cache(
+                HttpCacheSource::Scryfall,
+                "https://api.scryfall.com/cards/1",
+                "card body".to_string(),
+            )
+            .await;
+    }
+}
@@ -0,0 +1,212 @@
+use serde::de::DeserializeOwned;
+
+/// Byte of a JSON file read outside of a string, with the depth of the value it belongs to. The
+/// root value is at depth 1.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(super) enum JsonToken {
+    /// `{` or `[` opening a value at `depth`
+    Open { byte: u8, depth: usize },
+    /// `}` or `]` closing the value at `depth`
+    Close { depth: usize },
+    /// Quote opening a string inside the value at `depth`
+    StringStart { depth: usize },
+    /// Quote closing a string inside the value at `depth`
+    StringEnd { depth: usize },
+    /// Any other byte, at depth 0 outside of the root value
+    Other { byte: u8, depth: usize },
+}
+
+/// Incremental scanner of a JSON file read in chunks, for the files too large to be held in
+/// memory. Only the bytes of the value being read are kept, so memory does not depend on the size
+/// of the file.
+#[derive(Default)]
+pub(super) struct JsonScanner {
+    buffer: Vec<u8>,
+    /// Next byte of `buffer` to scan
+    position: usize,
+    /// Start in `buffer` of the value being read
+    value_start: Option<usize>,
+    depth: usize,
+    in_string: bool,
+    escaped: bool,
+}
+
+impl JsonScanner {
+    /// Appends the next bytes of the file.
+    pub(super) fn push(&mut self, bytes: &[u8]) {
+        self.buffer.extend_from_slice(bytes);
+    }
+
+    /// Scans the bytes pushed so far up to the next token. Once they are all scanned, drops the
+    /// ones before the value being read and returns `None`.
+    pub(super) fn next_token(&mut self) -> Result<Option<JsonToken>, &'static str> {
+        while self.position < self.buffer.len() {
+            let byte = self.buffer[self.position];
+            self.position += 1;
+            if let Some(token) = self.scan(byte)? {
+                return Ok(Some(token));
+            }
+        }
+
+        let consumed = self.value_start.unwrap_or(self.buffer.len());
+        self.buffer.drain(..consumed);
+        self.position -= consumed;
+        self.value_start = self.value_start.map(|start| start - consumed);
+
+        Ok(None)
+    }
+
+    /// Starts the value being read at the last token.
+    pub(super) fn start_value(&mut self) {
+        self.value_start = Some(self.position - 1);
+    }
+
+    /// Forgets the value being read.
+    pub(super) fn skip_value(&mut self) {
+        self.value_start = None;
+    }
+
+    /// Deserializes the value being read, up to the last token.
+    pub(super) fn take_value<T: DeserializeOwned>(&mut self) -> Result<T, String> {
+        let start = self.value_start.take().unwrap_or(self.position - 1);
+        serde_json::from_slice(&self.buffer[start..self.position]).map_err(|e| e.to_string())
+    }
+
+    /// Whether every value and string opened was closed.
+    pub(super) fn is_complete(&self) -> bool {
+        self.depth == 0 && !self.in_string
+    }
+
+    #[cfg(test)]
+    pub(super) fn buffered_len(&self) -> usize {
+        self.buffer.len()
+    }
+
+    fn scan(&mut self, byte: u8) -> Result<Option<JsonToken>, &'static str> {
+        if self.in_string {
+            match byte {
+                _ if self.escaped => self.escaped = false,
+                b'\\' => self.escaped = true,
+                b'"' => {
+                    self.in_string = false;
+                    return Ok(Some(JsonToken::StringEnd { depth: self.depth }));
+                }
+                _ => {}
+            }
+            return Ok(None);
+        }
+
+        let token = match byte {
+            b'"' => {
+                self.in_string = true;
+                JsonToken::StringStart { depth: self.depth }
+            }
+            b'{' | b'[' => {
+                self.depth += 1;
+                JsonToken::Open {
+                    byte,
+                    depth: self.depth,
+                }
+            }
+            b'}' | b']' => {
+                let depth = self.depth;
+                self.depth = depth.checked_sub(1).ok_or("unbalanced brackets")?;
+                JsonToken::Close { depth }
+            }
+            _ => JsonToken::Other {
+                byte,
+                depth: self.depth,
+            },
+        };
+        Ok(Some(token))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn tokens(json: &str) -> Vec<JsonToken> {
+        let mut scanner = JsonScanner::default();
+        scanner.push(json.as_bytes());
+        std::iter::from_fn(|| scanner.next_token().unwrap())
+            .filter(|token| !matches!(token, JsonToken::Other { byte: b' ', .. }))
+            .collect()
+    }
+
+    #[test]
+    fn reports_the_depth_of_each_token() {
+        assert_eq!(
+            tokens(r#"[{"a": 1}]"#),
+            vec![
+                JsonToken::Open {
+                    byte: b'[',
+                    depth: 1
+                },
+                JsonToken::Open {
+                    byte: b'{',
+                    depth: 2
+                },
+                JsonToken::StringStart { depth: 2 },
+                JsonToken::StringEnd { depth: 2 },
+                JsonToken::Other {
+                    byte: b':',
+                    depth: 2
+                },
+                JsonToken::Other {
+                    byte: b'1',
+                    depth: 2
+                },
+                JsonToken::Close { depth: 2 },
+                JsonToken::Close { depth: 1 },
+            ]
+        );
+    }
+
+    #[test]
+    fn ignores_the_brackets_and_escaped_quotes_of_strings() {
+        assert_eq!(
+            tokens(r#""a \" } ]""#),
+            vec![
+                JsonToken::StringStart { depth: 0 },
+                JsonToken::StringEnd { depth: 0 },
+            ]
+        );
+    }
+
+    #[test]
+    fn takes_the_value_started_in_a_previous_chunk() {
+        let mut scanner = JsonScanner::default();
+        scanner.push(br#"[{"a": "#);
+        while let Some(token) = scanner.next_token().unwrap() {
+            if token
+                == (JsonToken::Open {
+                    byte: b'{',
+                    depth: 2,
+                })
+            {
+                scanner.start_value();
+            }
+        }
+        assert_eq!(scanner.buffered_len(), 6);
+
+        scanner.push(b"1}]");
+        let mut values = Vec::new();
+        while let Some(token) = scanner.next_token().unwrap() {
+            if token == (JsonToken::Close { depth: 2 }) {
+                values.push(scanner.take_value::<serde_json::Value>().unwrap());
+            }
+        }
+
+        assert_eq!(values, vec![serde_json::json!({"a": 1})]);
+        assert!(scanner.is_complete());
+    }
+
+    #[test]
+    fn fails_on_unbalanced_brackets() {
+        let mut scanner = JsonScanner::default();
+        scanner.push(b"]");
+
+        assert!(scanner.next_token().is_err());
+    }
+}
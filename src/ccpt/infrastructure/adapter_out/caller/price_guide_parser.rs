@@ -0,0 +1,177 @@
+use crate::application::error::{AppError, InfraError};
+use crate::domain::price::FullPriceGuide;
+use crate::infrastructure::adapter_out::caller::dto::CardmarketPriceGuide;
+use crate::infrastructure::adapter_out::caller::json_scanner::{JsonScanner, JsonToken};
+use chrono::{DateTime, NaiveDate, Utc};
+use serde::de::DeserializeOwned;
+
+const CREATED_AT_KEY: &str = "createdAt";
+const PRICE_GUIDES_KEY: &str = "priceGuides";
+
+pub(super) fn invalid_price_guide_file(reason: impl Into<String>) -> AppError {
+    InfraError::CallError(format!("Invalid price guide file: {}", reason.into())).into()
+}
+
+/// Incremental parser of the Cardmarket price guide file, on top of a [`JsonScanner`].
+#[derive(Default)]
+pub(super) struct PriceGuideParser {
+    scanner: JsonScanner,
+    expects_key: bool,
+    key: Option<String>,
+    date: Option<NaiveDate>,
+}
+
+impl PriceGuideParser {
+    /// Date of the file, known once `createdAt` is read.
+    pub(super) fn date(&self) -> Option<NaiveDate> {
+        self.date
+    }
+
+    /// Parses the next bytes of the file and returns the price guides they complete.
+    pub(super) fn push(&mut self, bytes: &[u8]) -> Result<Vec<FullPriceGuide>, AppError> {
+        self.scanner.push(bytes);
+
+        let mut price_guides = Vec::new();
+        while let Some(token) = self
+            .scanner
+            .next_token()
+            .map_err(invalid_price_guide_file)?
+        {
+            match token {
+                JsonToken::StringStart { depth: 1 } => self.scanner.start_value(),
+                JsonToken::StringEnd { depth: 1 } => self.read_root_string()?,
+                JsonToken::Open { depth: 1, .. } => self.expects_key = true,
+                JsonToken::Open { depth: 3, .. } if self.in_price_guides() => {
+                    self.scanner.start_value()
+                }
+                JsonToken::Close { depth: 3 } if self.in_price_guides() => {
+                    let price_guide: CardmarketPriceGuide = self.take_value()?;
+                    price_guides.push(price_guide.into());
+                }
+                JsonToken::Other {
+                    byte: b':',
+                    depth: 1,
+                } => self.expects_key = false,
+                JsonToken::Other {
+                    byte: b',',
+                    depth: 1,
+                } => self.expects_key = true,
+                _ => {}
+            }
+        }
+
+        Ok(price_guides)
+    }
+
+    /// Checks that the whole file was read and returns its date.
+    pub(super) fn finish(self) -> Result<NaiveDate, AppError> {
+        if !self.scanner.is_complete() {
+            return Err(invalid_price_guide_file("the file is truncated"));
+        }
+
+        self.date
+            .ok_or_else(|| invalid_price_guide_file(format!("{} is missing", CREATED_AT_KEY)))
+    }
+
+    fn in_price_guides(&self) -> bool {
+        self.key.as_deref() == Some(PRICE_GUIDES_KEY)
+    }
+
+    fn take_value<T: DeserializeOwned>(&mut self) -> Result<T, AppError> {
+        self.scanner.take_value().map_err(invalid_price_guide_file)
+    }
+
+    fn read_root_string(&mut self) -> Result<(), AppError> {
+        if self.expects_key {
+            self.key = Some(self.take_value()?);
+        } else if self.key.as_deref() == Some(CREATED_AT_KEY) {
+            let created_at: DateTime<Utc> = self.take_value()?;
+            self.date = Some(created_at.date_naive());
+        } else {
+            self.scanner.skip_value();
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const FILE: &str = r#"{
+      "version": 1,
+      "createdAt": "2025-12-23T02:47:26+0100",
+      "priceGuides": [
+        {"idProduct": 1, "idCategory": 1, "avg": 0.06, "low": 0.02, "trend": 0.09,
+         "avg-foil": 0.5, "low-foil": 0.04, "trend-foil": 0.42},
+        {"idProduct": 2, "idCategory": 1, "avg": null, "low": 0.02, "trend": 0.07,
+         "avg-foil": null, "low-foil": 0.05, "trend-foil": 0.28, "comment": "a \"quoted\" } ]"}
+      ]
+    }"#;
+
+    fn parse_in_chunks(file: &str, chunk_size: usize) -> (Vec<FullPriceGuide>, NaiveDate) {
+        let mut parser = PriceGuideParser::default();
+        let mut price_guides = Vec::new();
+        for bytes in file.as_bytes().chunks(chunk_size) {
+            price_guides.extend(parser.push(bytes).unwrap());
+        }
+        (price_guides, parser.finish().unwrap())
+    }
+
+    #[test]
+    fn parses_price_guides_whatever_the_chunk_boundaries() {
+        for chunk_size in 1..=FILE.len() {
+            let (price_guides, date) = parse_in_chunks(FILE, chunk_size);
+
+            assert_eq!(date, NaiveDate::from_ymd_opt(2025, 12, 23).unwrap());
+            assert_eq!(price_guides.len(), 2);
+            assert_eq!(price_guides[0].id_product, 1);
+            assert_eq!(price_guides[0].foil.trend, Some(0.42).into());
+            assert_eq!(price_guides[1].id_product, 2);
+            assert_eq!(price_guides[1].normal.avg, None::<f32>.into());
+            assert_eq!(price_guides[1].normal.trend, Some(0.07).into());
+        }
+    }
+
+    #[test]
+    fn keeps_only_the_price_guide_being_read() {
+        let mut parser = PriceGuideParser::default();
+        let split = FILE.find("{\"idProduct\": 2").unwrap() + 10;
+
+        let price_guides = parser.push(&FILE.as_bytes()[..split]).unwrap();
+
+        assert_eq!(price_guides.len(), 1);
+        assert_eq!(parser.scanner.buffered_len(), 10);
+        assert_eq!(parser.date(), NaiveDate::from_ymd_opt(2025, 12, 23));
+    }
+
+    #[test]
+    fn fails_when_file_is_truncated() {
+        let mut parser = PriceGuideParser::default();
+        let truncated = &FILE[..FILE.len() / 2];
+
+        parser.push(truncated.as_bytes()).unwrap();
+
+        assert!(parser.finish().is_err());
+    }
+
+    #[test]
+    fn fails_when_created_at_is_missing() {
+        let mut parser = PriceGuideParser::default();
+
+        parser
+            .push(br#"{"version": 1, "priceGuides": []}"#)
+            .unwrap();
+
+        assert!(parser.finish().is_err());
+    }
+
+    #[test]
+    fn fails_when_price_guide_is_invalid() {
+        let mut parser = PriceGuideParser::default();
+
+        let result = parser.push(br#"{"priceGuides": [{"idProduct": "abc"}]}"#);
+
+        assert!(result.is_err());
+    }
+}
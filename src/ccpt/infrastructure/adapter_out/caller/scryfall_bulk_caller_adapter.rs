@@ -0,0 +1,273 @@
+use crate::application::caller::ScryfallBulkCaller;
+use crate::application::error::AppError;
+use crate::domain::scryfall_card::ScryfallCard;
+use crate::infrastructure::adapter_out::caller::dto::ScryfallBulkData;
+use crate::infrastructure::adapter_out::caller::http_client::HttpClient;
+use crate::infrastructure::adapter_out::caller::scryfall_bulk_parser::{
+    ScryfallBulkParser, invalid_bulk_file,
+};
+use async_trait::async_trait;
+use std::sync::Arc;
+use tokio::io::AsyncReadExt;
+use tokio::sync::mpsc::Sender;
+
+/// Cards sent per chunk
+const CHUNK_SIZE: usize = 1000;
+/// Bytes read at once from a local file
+const READ_BUFFER_SIZE: usize = 64 * 1024;
+
+pub struct ScryfallBulkCallerAdapter {
+    client: Arc<HttpClient>,
+    scryfall_base_url: String,
+    /// Local path or URL of the file, the latest `default-cards` file of Scryfall if `None`
+    source: Option<String>,
+}
+
+impl ScryfallBulkCallerAdapter {
+    pub fn new(
+        scryfall_base_url: impl Into<String>,
+        source: Option<String>,
+        client: Arc<HttpClient>,
+    ) -> Self {
+        Self {
+            client,
+            scryfall_base_url: scryfall_base_url.into(),
+            source,
+        }
+    }
+
+    async fn default_cards_uri(&self) -> Result<String, AppError> {
+        let url = format!("{}/bulk-data/default-cards", self.scryfall_base_url);
+        let bulk_data: ScryfallBulkData = self
+            .client
+            .send(self.client.get(url.as_str()))
+            .await?
+            .error_for_status()?
+            .json()
+            .await?;
+
+        Ok(bulk_data.download_uri)
+    }
+
+    async fn download(&self, url: &str, chunker: &mut CardChunker<'_>) -> Result<(), AppError> {
+        let mut response = self
+            .client
+            .send(self.client.get(url))
+            .await?
+            .error_for_status()?;
+
+        while let Some(bytes) = response.chunk().await? {
+            chunker.push(&bytes).await?;
+        }
+        Ok(())
+    }
+
+    async fn read_file(&self, path: &str, chunker: &mut CardChunker<'_>) -> Result<(), AppError> {
+        let cannot_read = |e: std::io::Error| invalid_bulk_file(format!("{}: {}", path, e));
+        let mut file = tokio::fs::File::open(path).await.map_err(cannot_read)?;
+        let mut buffer = vec![0; READ_BUFFER_SIZE];
+
+        loop {
+            let read = file.read(&mut buffer).await.map_err(cannot_read)?;
+            if read == 0 {
+                return Ok(());
+            }
+            chunker.push(&buffer[..read]).await?;
+        }
+    }
+}
+
+/// Groups the parsed cards in chunks before sending them.
+struct CardChunker<'a> {
+    sender: &'a Sender<Vec<ScryfallCard>>,
+    parser: ScryfallBulkParser,
+    cards: Vec<ScryfallCard>,
+    count: usize,
+}
+
+impl<'a> CardChunker<'a> {
+    fn new(sender: &'a Sender<Vec<ScryfallCard>>) -> Self {
+        Self {
+            sender,
+            parser: ScryfallBulkParser::default(),
+            cards: Vec::with_capacity(CHUNK_SIZE),
+            count: 0,
+        }
+    }
+
+    async fn push(&mut self, bytes: &[u8]) -> Result<(), AppError> {
+        self.cards.extend(self.parser.push(bytes)?);
+        if self.cards.len() >= CHUNK_SIZE {
+            self.send().await?;
+        }
+        Ok(())
+    }
+
+    async fn finish(mut self) -> Result<usize, AppError> {
+        self.parser.finish()?;
+        if !self.cards.is_empty() {
+            self.send().await?;
+        }
+        Ok(self.count)
+    }
+
+    async fn send(&mut self) -> Result<(), AppError> {
+        let cards = std::mem::take(&mut self.cards);
+        self.count += cards.len();
+        self.sender
+            .send(cards)
+            .await
+            .map_err(|_| invalid_bulk_file("the import stopped reading the cards"))
+    }
+}
+
+fn is_url(source: &str) -> bool {
+    source.starts_with("http://") || source.starts_with("https://")
+}
+
+#[async_trait]
+impl ScryfallBulkCaller for ScryfallBulkCallerAdapter {
+    async fn stream_default_cards(
+        &self,
+        sender: Sender<Vec<ScryfallCard>>,
+    ) -> Result<usize, AppError> {
+        let mut chunker = CardChunker::new(&sender);
+
+        match self.source.as_deref() {
+            Some(path) if !is_url(path) => self.read_file(path, &mut chunker).await?,
+            Some(url) => self.download(url, &mut chunker).await?,
+            None => {
+                let url = self.default_cards_uri().await?;
+                self.download(&url, &mut chunker).await?
+            }
+        }
+
+        chunker.finish().await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tokio::sync::mpsc::channel;
+    use wiremock::matchers::{method, path};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    const FILE: &str = r#"[
+      {"id": "0000579f-7b35-4ed3-b44c-db2a538066fe", "lang": "en", "name": "Fury Sliver",
+       "set": "tsp", "collector_number": "157", "cardmarket_id": 15018},
+      {"id": "00006596-1166-4a79-8443-ca9f82e6db4e", "lang": "fr", "name": "Kor Outfitter",
+       "set": "zen", "collector_number": "21"}
+    ]"#;
+
+    fn create_adapter(
+        scryfall_base_url: impl Into<String>,
+        source: Option<String>,
+    ) -> ScryfallBulkCallerAdapter {
+        ScryfallBulkCallerAdapter::new(
+            scryfall_base_url,
+            source,
+            Arc::new(HttpClient::for_testing()),
+        )
+    }
+
+    async fn read_all(adapter: &ScryfallBulkCallerAdapter) -> (Vec<ScryfallCard>, usize) {
+        let (sender, mut receiver) = channel(10);
+        let count = adapter.stream_default_cards(sender).await.unwrap();
+
+        let mut cards = Vec::new();
+        while let Some(chunk) = receiver.recv().await {
+            cards.extend(chunk);
+        }
+        (cards, count)
+    }
+
+    #[tokio::test]
+    async fn streams_the_latest_default_cards_file_of_scryfall() {
+        let mock_server = MockServer::start().await;
+        let metadata = format!(
+            r#"{{"object": "bulk_data", "type": "default_cards", "download_uri": "{}/file/default-cards.json"}}"#,
+            mock_server.uri()
+        );
+
+        Mock::given(method("GET"))
+            .and(path("/bulk-data/default-cards"))
+            .respond_with(ResponseTemplate::new(200).set_body_raw(metadata, "application/json"))
+            .mount(&mock_server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/file/default-cards.json"))
+            .respond_with(ResponseTemplate::new(200).set_body_raw(FILE, "application/json"))
+            .mount(&mock_server)
+            .await;
+
+        let adapter = create_adapter(mock_server.uri(), None);
+        let (cards, count) = read_all(&adapter).await;
+
+        assert_eq!(count, 2);
+        assert_eq!(cards.len(), 2);
+        assert_eq!(cards[0].cardmarket_id, Some(15018));
+        assert_eq!(cards[1].name, "Kor Outfitter");
+    }
+
+    #[tokio::test]
+    async fn streams_the_file_of_the_configured_url() {
+        let mock_server = MockServer::start().await;
+
+        Mock::given(method("GET"))
+            .and(path("/mirror/cards.json"))
+            .respond_with(ResponseTemplate::new(200).set_body_raw(FILE, "application/json"))
+            .mount(&mock_server)
+            .await;
+
+        let adapter = create_adapter(
+            "http://unused",
+            Some(format!("{}/mirror/cards.json", mock_server.uri())),
+        );
+        let (cards, count) = read_all(&adapter).await;
+
+        assert_eq!(count, 2);
+        assert_eq!(cards[0].set_code, "tsp");
+    }
+
+    #[tokio::test]
+    async fn reads_the_file_of_the_configured_path() {
+        let path =
+            std::env::temp_dir().join(format!("default-cards-{}.json", uuid::Uuid::new_v4()));
+        tokio::fs::write(&path, FILE).await.unwrap();
+
+        let adapter = create_adapter("http://unused", Some(path.display().to_string()));
+        let (cards, count) = read_all(&adapter).await;
+        tokio::fs::remove_file(&path).await.unwrap();
+
+        assert_eq!(count, 2);
+        assert_eq!(cards[1].lang, "fr");
+    }
+
+    #[tokio::test]
+    async fn fails_when_the_configured_path_does_not_exist() {
+        let adapter = create_adapter(
+            "http://unused",
+            Some("/nonexistent/default-cards.json".to_string()),
+        );
+        let (sender, _receiver) = channel(10);
+
+        assert!(adapter.stream_default_cards(sender).await.is_err());
+    }
+
+    #[tokio::test]
+    async fn fails_when_the_download_fails() {
+        let mock_server = MockServer::start().await;
+
+        Mock::given(method("GET"))
+            .and(path("/bulk-data/default-cards"))
+            .respond_with(ResponseTemplate::new(503))
+            .mount(&mock_server)
+            .await;
+
+        let adapter = create_adapter(mock_server.uri(), None);
+        let (sender, _receiver) = channel(10);
+
+        assert!(adapter.stream_default_cards(sender).await.is_err());
+    }
+}
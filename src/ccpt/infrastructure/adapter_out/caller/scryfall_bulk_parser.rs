@@ -0,0 +1,143 @@
+use crate::application::error::{AppError, InfraError};
+use crate::domain::scryfall_card::ScryfallCard;
+use crate::infrastructure::adapter_out::caller::dto::ScryfallCardObject;
+use crate::infrastructure::adapter_out::caller::json_scanner::{JsonScanner, JsonToken};
+
+pub(super) fn invalid_bulk_file(reason: impl Into<String>) -> AppError {
+    InfraError::CallError(format!(
+        "Invalid Scryfall bulk data file: {}",
+        reason.into()
+    ))
+    .into()
+}
+
+/// Incremental parser of a Scryfall bulk data file, a JSON array of cards, on top of a
+/// [`JsonScanner`].
+#[derive(Default)]
+pub(super) struct ScryfallBulkParser {
+    scanner: JsonScanner,
+    started: bool,
+}
+
+impl ScryfallBulkParser {
+    /// Parses the next bytes of the file and returns the cards they complete.
+    pub(super) fn push(&mut self, bytes: &[u8]) -> Result<Vec<ScryfallCard>, AppError> {
+        self.scanner.push(bytes);
+
+        let mut cards = Vec::new();
+        while let Some(token) = self.scanner.next_token().map_err(invalid_bulk_file)? {
+            match token {
+                JsonToken::Open {
+                    byte: b'[',
+                    depth: 1,
+                } => self.started = true,
+                JsonToken::Open { depth: 1, .. } | JsonToken::StringStart { depth: 0 } => {
+                    return Err(invalid_bulk_file("the file is not an array of cards"));
+                }
+                JsonToken::Other { byte, depth: 0 } if !byte.is_ascii_whitespace() => {
+                    return Err(invalid_bulk_file("the file is not an array of cards"));
+                }
+                JsonToken::Open { depth: 2, .. } => self.scanner.start_value(),
+                JsonToken::Close { depth: 2 } => {
+                    let card: ScryfallCardObject =
+                        self.scanner.take_value().map_err(invalid_bulk_file)?;
+                    cards.push(card.into());
+                }
+                _ => {}
+            }
+        }
+
+        Ok(cards)
+    }
+
+    /// Checks that the whole file was read.
+    pub(super) fn finish(&self) -> Result<(), AppError> {
+        if !self.started {
+            return Err(invalid_bulk_file("the file is empty"));
+        }
+        if !self.scanner.is_complete() {
+            return Err(invalid_bulk_file("the file is truncated"));
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const FILE: &str = r#"[
+      {"object": "card", "id": "0000579f-7b35-4ed3-b44c-db2a538066fe", "lang": "en",
+       "name": "Fury Sliver", "set": "tsp", "collector_number": "157", "cardmarket_id": 15018,
+       "prices": {"eur": "0.12"}, "games": ["paper", "mtgo"]},
+      {"object": "card", "id": "00006596-1166-4a79-8443-ca9f82e6db4e", "lang": "fr",
+       "name": "Kor Outfitter // \"Équipeur\" } ]", "set": "zen", "collector_number": "21",
+       "card_faces": [{"name": "a"}, {"name": "b"}]}
+    ]"#;
+
+    fn parse_in_chunks(file: &str, chunk_size: usize) -> Vec<ScryfallCard> {
+        let mut parser = ScryfallBulkParser::default();
+        let mut cards = Vec::new();
+        for bytes in file.as_bytes().chunks(chunk_size) {
+            cards.extend(parser.push(bytes).unwrap());
+        }
+        parser.finish().unwrap();
+        cards
+    }
+
+    #[test]
+    fn parses_cards_whatever_the_chunk_boundaries() {
+        for chunk_size in 1..=FILE.len() {
+            let cards = parse_in_chunks(FILE, chunk_size);
+
+            assert_eq!(cards.len(), 2);
+            assert_eq!(cards[0].name, "Fury Sliver");
+            assert_eq!(cards[0].set_code, "tsp");
+            assert_eq!(cards[0].cardmarket_id, Some(15018));
+            assert_eq!(cards[1].lang, "fr");
+            assert_eq!(cards[1].collector_number, "21");
+            assert_eq!(cards[1].cardmarket_id, None);
+        }
+    }
+
+    #[test]
+    fn keeps_only_the_card_being_read() {
+        let mut parser = ScryfallBulkParser::default();
+        let split = FILE
+            .find("{\"object\": \"card\", \"id\": \"00006596")
+            .unwrap()
+            + 10;
+
+        let cards = parser.push(&FILE.as_bytes()[..split]).unwrap();
+
+        assert_eq!(cards.len(), 1);
+        assert_eq!(parser.scanner.buffered_len(), 10);
+    }
+
+    #[test]
+    fn fails_when_file_is_truncated() {
+        let mut parser = ScryfallBulkParser::default();
+
+        parser.push(&FILE.as_bytes()[..FILE.len() / 2]).unwrap();
+
+        assert!(parser.finish().is_err());
+    }
+
+    #[test]
+    fn fails_when_file_is_not_an_array() {
+        let mut parser = ScryfallBulkParser::default();
+
+        let result = parser.push(br#"{"object": "error"}"#);
+
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn fails_when_card_is_invalid() {
+        let mut parser = ScryfallBulkParser::default();
+
+        let result = parser.push(br#"[{"id": "not-a-uuid"}]"#);
+
+        assert!(result.is_err());
+    }
+}
@@ -1,128 +1,260 @@
 use crate::application::caller::ScryfallCaller;
 use crate::application::error::{AppError, InfraError};
-use crate::infrastructure::adapter_out::caller::dto::ScryfallCardInfo;
+use crate::domain::http_cache::HttpCacheSource;
+use crate::domain::scryfall_card::ScryfallCard;
+use crate::infrastructure::adapter_out::caller::dto::{
+    ScryfallCardObject, ScryfallCollectionRequest, ScryfallCollectionResponse, ScryfallIdentifier,
+};
+use crate::infrastructure::adapter_out::caller::http_client::HttpClient;
 use async_trait::async_trait;
-use ratelimit::{Ratelimiter, TryWaitError};
+use std::collections::HashMap;
+use std::sync::Arc;
 use uuid::Uuid;
 
+/// Identifiers accepted by one call to `/cards/collection`
+const MAX_COLLECTION_IDENTIFIERS: usize = 75;
+
 pub struct ScryfallCallerAdapter {
-    client: reqwest::Client,
+    client: Arc<HttpClient>,
     scryfall_base_url: String,
-    ratelimiter: Ratelimiter,
 }
 
 impl ScryfallCallerAdapter {
-    pub fn new(scryfall_base_url: impl Into<String>, rate_limit_tokens: u32) -> Self {
-        let rate_limit_tokens = u64::from(rate_limit_tokens);
+    pub fn new(scryfall_base_url: impl Into<String>, client: Arc<HttpClient>) -> Self {
         Self {
-            client: reqwest::Client::builder()
-                .user_agent("reqwest")
-                .build()
-                .unwrap(),
+            client,
             scryfall_base_url: scryfall_base_url.into(),
-            ratelimiter: Ratelimiter::builder(rate_limit_tokens)
-                .max_tokens(rate_limit_tokens)
-                .build()
-                .unwrap(),
         }
     }
+
+    /// URL of the card, under which its object is cached.
+    fn card_url(&self, id: Uuid) -> String {
+        format!("{}/cards/{}", self.scryfall_base_url, id)
+    }
 }
 
 #[async_trait]
 impl ScryfallCaller for ScryfallCallerAdapter {
-    async fn get_card_market_id(&self, id: Uuid) -> Result<Option<u32>, AppError> {
-        let url = format!("{}/cards/{}?format=json", self.scryfall_base_url, id);
+    async fn get_cards(&self, ids: Vec<Uuid>) -> Result<HashMap<Uuid, ScryfallCard>, AppError> {
+        let url = format!("{}/cards/collection", self.scryfall_base_url);
+        let mut cards = HashMap::with_capacity(ids.len());
 
-        if let Err(err) = self.ratelimiter.try_wait() {
-            match err {
-                TryWaitError::Insufficient(duration) => {
-                    tokio::time::sleep(duration).await;
-                }
-                TryWaitError::ExceedsCapacity => {
-                    return Err(InfraError::CallError(
-                        "Scryfall rate limiter overflow".to_string(),
-                    )
-                    .into());
-                }
-                _ => {
-                    return Err(
-                        InfraError::CallError("Scryfall rate limiter error".to_string()).into(),
-                    );
+        // Seules les cartes absentes du cache (ou périmées) sont demandées à Scryfall
+        let mut missing = Vec::new();
+        for id in ids {
+            let cached = self
+                .client
+                .find_fresh(HttpCacheSource::Scryfall, &self.card_url(id))
+                .await
+                .and_then(|body| serde_json::from_str::<ScryfallCardObject>(&body).ok());
+            match cached {
+                Some(card) => {
+                    cards.insert(id, ScryfallCard::from(card));
                 }
+                None => missing.push(id),
             }
         }
 
-        let card_info: ScryfallCardInfo =
-            self.client.get(url.as_str()).send().await?.json().await?;
+        for chunk in missing.chunks(MAX_COLLECTION_IDENTIFIERS) {
+            let request = ScryfallCollectionRequest {
+                identifiers: chunk.iter().map(|&id| ScryfallIdentifier { id }).collect(),
+            };
+            let response: ScryfallCollectionResponse = self
+                .client
+                .send(self.client.post(url.as_str()).json(&request))
+                .await?
+                .error_for_status()?
+                .json()
+                .await?;
+
+            // Les cartes inconnues de Scryfall sont listées dans `not_found` et absentes de `data`
+            for object in response.data {
+                let card: ScryfallCardObject = serde_json::from_value(object.clone())
+                    .map_err(|e| InfraError::CallError(format!("Invalid Scryfall card: {e}")))?;
+                self.client
+                    .cache(
+                        HttpCacheSource::Scryfall,
+                        &self.card_url(card.id),
+                        object.to_string(),
+                    )
+                    .await;
+                cards.insert(card.id, ScryfallCard::from(card));
+            }
+        }
 
-        Ok(card_info.cardmarket_id.map(|id| id as u32))
+        Ok(cards)
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::application::repository::MockHttpResponseCacheRepository;
+    use crate::domain::http_cache::CachedHttpResponse;
+    use chrono::Utc;
     use uuid::Uuid;
-    use wiremock::matchers::path;
+    use wiremock::matchers::{body_partial_json, method, path};
     use wiremock::{Mock, MockServer, ResponseTemplate};
 
+    fn card_object(id: Uuid, cardmarket_id: Option<u32>) -> serde_json::Value {
+        serde_json::json!({
+            "id": id, "name": "Fury Sliver", "set": "tsp", "collector_number": "157",
+            "lang": "en", "cardmarket_id": cardmarket_id, "type_line": "Creature — Sliver"
+        })
+    }
+
+    fn collection_response(cards: &[(Uuid, Option<u32>)], not_found: &[Uuid]) -> String {
+        serde_json::json!({
+            "object": "list",
+            "not_found": not_found.iter().map(|id| serde_json::json!({ "id": id })).collect::<Vec<_>>(),
+            "data": cards
+                .iter()
+                .map(|&(id, cardmarket_id)| card_object(id, cardmarket_id))
+                .collect::<Vec<_>>(),
+        })
+        .to_string()
+    }
+
+    fn cached_client(repository: MockHttpResponseCacheRepository) -> Arc<HttpClient> {
+        Arc::new(HttpClient::for_testing().with_cache(
+            Arc::new(repository),
+            HashMap::from([(HttpCacheSource::Scryfall, chrono::Duration::hours(24))]),
+        ))
+    }
+
     #[tokio::test]
-    async fn get_card_market_id_returns_cardmarket_id() {
+    async fn get_cards_returns_the_found_cards() {
         let mock_server = MockServer::start().await;
+        let with_product = Uuid::new_v4();
+        let without_product = Uuid::new_v4();
+        let unknown = Uuid::new_v4();
 
-        let card_id = Uuid::default();
-        let cardmarket_id = 12345;
-        let response_body = format!(r#"{{ "cardmarket_id": {} }}"#, cardmarket_id);
-
-        Mock::given(path(format!("/cards/{}", card_id)))
-            .respond_with(ResponseTemplate::new(200).set_body_string(response_body))
+        Mock::given(method("POST"))
+            .and(path("/cards/collection"))
+            .and(body_partial_json(serde_json::json!({
+                "identifiers": [{ "id": with_product }, { "id": without_product }, { "id": unknown }]
+            })))
+            .respond_with(ResponseTemplate::new(200).set_body_string(collection_response(
+                &[(with_product, Some(42)), (without_product, None)],
+                &[unknown],
+            )))
+            .expect(1)
             .mount(&mock_server)
             .await;
 
-        let adapter = ScryfallCallerAdapter::new(mock_server.uri(), 8);
-        let result = adapter.get_card_market_id(card_id).await;
+        let adapter =
+            ScryfallCallerAdapter::new(mock_server.uri(), Arc::new(HttpClient::for_testing()));
+        let result = adapter
+            .get_cards(vec![with_product, without_product, unknown])
+            .await
+            .unwrap();
 
-        assert!(result.is_ok());
-        assert_eq!(result.unwrap(), Some(cardmarket_id));
+        assert_eq!(result.len(), 2);
+        assert_eq!(result[&with_product].cardmarket_id, Some(42));
+        assert_eq!(result[&without_product].cardmarket_id, None);
+        assert_eq!(
+            result[&with_product].metadata.type_line.as_deref(),
+            Some("Creature — Sliver")
+        );
+        assert!(!result.contains_key(&unknown));
     }
 
     #[tokio::test]
-    async fn get_card_market_id_returns_none_for_missing_cardmarket_id() {
+    async fn get_cards_splits_identifiers_in_batches_of_75() {
         let mock_server = MockServer::start().await;
+        let ids: Vec<Uuid> = (0..100).map(|_| Uuid::new_v4()).collect();
+        let (first, second) = ids.split_at(MAX_COLLECTION_IDENTIFIERS);
 
-        let card_id = Uuid::default();
-        let response_body = r#"{ "cardmarket_id": null }"#;
+        for batch in [first, second] {
+            let identifiers: Vec<_> = batch
+                .iter()
+                .map(|id| serde_json::json!({ "id": id }))
+                .collect();
+            let cards: Vec<_> = batch.iter().map(|&id| (id, Some(1))).collect();
+            Mock::given(method("POST"))
+                .and(path("/cards/collection"))
+                .and(body_partial_json(
+                    serde_json::json!({ "identifiers": identifiers }),
+                ))
+                .respond_with(
+                    ResponseTemplate::new(200).set_body_string(collection_response(&cards, &[])),
+                )
+                .expect(1)
+                .mount(&mock_server)
+                .await;
+        }
+
+        let adapter =
+            ScryfallCallerAdapter::new(mock_server.uri(), Arc::new(HttpClient::for_testing()));
+        let result = adapter.get_cards(ids).await.unwrap();
+
+        assert_eq!(result.len(), 100);
+    }
 
-        Mock::given(path(format!("/cards/{}", card_id)))
-            .respond_with(ResponseTemplate::new(200).set_body_string(response_body))
+    #[tokio::test]
+    async fn get_cards_returns_error_when_scryfall_fails() {
+        let mock_server = MockServer::start().await;
+
+        Mock::given(method("POST"))
+            .and(path("/cards/collection"))
+            .respond_with(ResponseTemplate::new(429))
             .mount(&mock_server)
             .await;
 
-        let adapter = ScryfallCallerAdapter::new(mock_server.uri(), 8);
-        let result = adapter.get_card_market_id(card_id).await;
+        let adapter =
+            ScryfallCallerAdapter::new(mock_server.uri(), Arc::new(HttpClient::for_testing()));
+        let result = adapter.get_cards(vec![Uuid::new_v4()]).await;
 
-        assert!(result.is_ok());
-        assert_eq!(result.unwrap(), None);
+        assert!(result.is_err());
     }
 
     #[tokio::test]
-    async fn get_card_market_id_returns_none_for_invalid_response() {
+    async fn get_cards_only_asks_scryfall_for_the_cards_not_cached() {
         let mock_server = MockServer::start().await;
+        let cached = Uuid::new_v4();
+        let fetched = Uuid::new_v4();
+        let cached_url = format!("{}/cards/{}", mock_server.uri(), cached);
+        let fetched_url = format!("{}/cards/{}", mock_server.uri(), fetched);
 
-        let card_id = Uuid::default();
-        let response_body = r#"{ "invalid_field": "invalid_value" }"#;
-
-        Mock::given(path(format!("/cards/{}", card_id)))
-            .respond_with(ResponseTemplate::new(200).set_body_string(response_body))
+        Mock::given(method("POST"))
+            .and(path("/cards/collection"))
+            .and(body_partial_json(
+                serde_json::json!({ "identifiers": [{ "id": fetched }] }),
+            ))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .set_body_string(collection_response(&[(fetched, Some(7))], &[])),
+            )
+            .expect(1)
             .mount(&mock_server)
             .await;
 
-        let adapter = ScryfallCallerAdapter::new(mock_server.uri(), 8);
-        let result = adapter.get_card_market_id(card_id).await;
+        let mut repository = MockHttpResponseCacheRepository::new();
+        let body = card_object(cached, Some(42)).to_string();
+        repository.expect_find_by_url().returning(move |url| {
+            let response = (url == cached_url).then(|| CachedHttpResponse {
+                url: url.to_string(),
+                source: HttpCacheSource::Scryfall,
+                body: body.clone(),
+                etag: None,
+                last_modified: None,
+                fetched_at: Utc::now(),
+            });
+            Box::pin(async move { Ok(response) })
+        });
+        repository
+            .expect_save()
+            .withf(move |response| {
+                response.url == fetched_url && response.source == HttpCacheSource::Scryfall
+            })
+            .times(1)
+            .returning(|_| Box::pin(async { Ok(()) }));
 
-        assert!(result.is_ok());
-        if let Ok(id) = result {
-            assert!(id.is_none());
-        }
+        let adapter = ScryfallCallerAdapter::new(mock_server.uri(), cached_client(repository));
+        let result = adapter.get_cards(vec![cached, fetched]).await.unwrap();
+
+        assert_eq!(result.len(), 2);
+        assert_eq!(result[&cached].cardmarket_id, Some(42));
+        assert_eq!(result[&fetched].cardmarket_id, Some(7));
     }
 }
@@ -2,6 +2,7 @@ use crate::application::error::{AppError, InfraError};
 use crate::application::repository::PersistenceError;
 use sqlx::Error;
 
+pub mod binder_repository_adapter;
 pub mod card_prices_view_repository_adapter;
 pub mod card_repository_adapter;
 pub mod cardmarket_price_repository_adapter;
@@ -9,11 +10,19 @@ pub mod collection_price_history_repository_adapter;
 pub mod collection_stats_repository_adapter;
 #[cfg(test)]
 pub mod common_repository_tests;
+pub mod edhrec_card_info_repository_adapter;
+pub mod enrichment_job_repository_adapter;
 pub mod entities;
+pub mod http_response_cache_repository_adapter;
+pub mod price_anomaly_repository_adapter;
+pub mod scryfall_catalog_repository_adapter;
 pub mod set_names_repository_adapter;
+pub mod set_price_index_repository_adapter;
 pub mod stats_repository_adapter;
+pub mod trade_match_repository_adapter;
 pub mod trade_repository_adapter;
 pub mod user_repository_adapter;
+pub mod wishlist_repository_adapter;
 
 impl From<Error> for AppError {
     fn from(err: Error) -> Self {
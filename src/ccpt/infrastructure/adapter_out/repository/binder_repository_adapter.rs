@@ -0,0 +1,423 @@
+use crate::application::error::AppError;
+use crate::application::repository::BinderRepository;
+use crate::domain::binder::{Binder, BinderId, BinderSummary, BinderUpdate, DEFAULT_BINDER_NAME};
+use crate::domain::user::UserId;
+use crate::infrastructure::adapter_out::repository::entities::{BinderEntity, BinderSummaryEntity};
+use async_trait::async_trait;
+use sqlx::{Pool, Postgres};
+
+pub struct BinderRepositoryAdapter {
+    pool: Pool<Postgres>,
+}
+
+impl BinderRepositoryAdapter {
+    pub fn new(pool: Pool<Postgres>) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl BinderRepository for BinderRepositoryAdapter {
+    async fn find_all(&self, user_id: &UserId) -> Result<Vec<BinderSummary>, AppError> {
+        let entities = sqlx::query_as!(
+            BinderSummaryEntity,
+            r#"SELECT b.id, b.user_id, b.name, b.tradable, b.keep_quantity, b.is_default, b.created_at,
+                      COALESCE(SUM(ce.quantity), 0)::BIGINT AS "card_count!"
+               FROM binder b
+                        LEFT JOIN collection_entry ce ON ce.binder_id = b.id
+               WHERE b.user_id = $1
+               GROUP BY b.id
+               ORDER BY b.is_default DESC, b.name"#,
+            user_id.as_str()
+        )
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(entities.into_iter().map(BinderSummary::from).collect())
+    }
+
+    async fn find_by_id(&self, user_id: &UserId, id: BinderId) -> Result<Option<Binder>, AppError> {
+        let entity = sqlx::query_as!(
+            BinderEntity,
+            r#"SELECT id, user_id, name, tradable, keep_quantity, is_default, created_at
+               FROM binder
+               WHERE id = $1 AND user_id = $2"#,
+            id.0,
+            user_id.as_str()
+        )
+        .fetch_optional(&self.pool)
+        .await?;
+
+        Ok(entity.map(Binder::from))
+    }
+
+    async fn find_or_create_default(&self, user_id: &UserId) -> Result<Binder, AppError> {
+        let existing = sqlx::query_as!(
+            BinderEntity,
+            r#"SELECT id, user_id, name, tradable, keep_quantity, is_default, created_at
+               FROM binder
+               WHERE user_id = $1 AND is_default"#,
+            user_id.as_str()
+        )
+        .fetch_optional(&self.pool)
+        .await?;
+        if let Some(entity) = existing {
+            return Ok(entity.into());
+        }
+
+        // Échangeable comme l'était toute la collection avant les classeurs ; un classeur du même
+        // nom (ou créé en parallèle) devient le classeur par défaut
+        let entity = sqlx::query_as!(
+            BinderEntity,
+            r#"INSERT INTO binder (id, user_id, name, tradable, is_default)
+               VALUES ($1, $2, $3, TRUE, TRUE)
+               ON CONFLICT (user_id, name) DO UPDATE SET is_default = TRUE
+               RETURNING id, user_id, name, tradable, keep_quantity, is_default, created_at"#,
+            BinderId::new().0,
+            user_id.as_str(),
+            DEFAULT_BINDER_NAME
+        )
+        .fetch_one(&self.pool)
+        .await?;
+
+        Ok(entity.into())
+    }
+
+    async fn name_exists(
+        &self,
+        user_id: &UserId,
+        name: &str,
+        except: Option<BinderId>,
+    ) -> Result<bool, AppError> {
+        let exists = sqlx::query_scalar!(
+            r#"SELECT EXISTS (SELECT 1 FROM binder
+                              WHERE user_id = $1 AND name = $2
+                                AND id IS DISTINCT FROM $3) AS "exists!""#,
+            user_id.as_str(),
+            name,
+            except.map(|id| id.0)
+        )
+        .fetch_one(&self.pool)
+        .await?;
+
+        Ok(exists)
+    }
+
+    async fn create(&self, binder: Binder) -> Result<(), AppError> {
+        sqlx::query!(
+            r#"INSERT INTO binder (id, user_id, name, tradable, keep_quantity, is_default, created_at)
+               VALUES ($1, $2, $3, $4, $5, $6, $7)"#,
+            binder.id.0,
+            binder.user_id.as_str(),
+            binder.name,
+            binder.tradable,
+            binder.keep_quantity as i32,
+            binder.is_default,
+            binder.created_at
+        )
+        .execute(&self.pool)
+        .await?;
+
+        Ok(())
+    }
+
+    async fn update(
+        &self,
+        user_id: &UserId,
+        id: BinderId,
+        update: BinderUpdate,
+    ) -> Result<Option<Binder>, AppError> {
+        let entity = sqlx::query_as!(
+            BinderEntity,
+            r#"UPDATE binder
+               SET name          = COALESCE($1, name),
+                   tradable      = COALESCE($2, tradable),
+                   keep_quantity = COALESCE($3, keep_quantity)
+               WHERE id = $4 AND user_id = $5
+               RETURNING id, user_id, name, tradable, keep_quantity, is_default, created_at"#,
+            update.name,
+            update.tradable,
+            update.keep_quantity.map(|q| q as i32),
+            id.0,
+            user_id.as_str()
+        )
+        .fetch_optional(&self.pool)
+        .await?;
+
+        Ok(entity.map(Binder::from))
+    }
+
+    async fn delete(&self, user_id: &UserId, id: BinderId) -> Result<bool, AppError> {
+        let deleted = sqlx::query!(
+            "DELETE FROM binder WHERE id = $1 AND user_id = $2 AND NOT is_default",
+            id.0,
+            user_id.as_str()
+        )
+        .execute(&self.pool)
+        .await?;
+
+        Ok(deleted.rows_affected() > 0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::infrastructure::adapter_out::repository::common_repository_tests::{
+        insert_binder, insert_binder_entry, insert_card, insert_collection_entry,
+    };
+    use chrono::Utc;
+    use sqlx::PgPool;
+
+    #[sqlx::test]
+    async fn find_all_counts_the_copies_of_each_binder_default_first(pool: PgPool) {
+        insert_card(&pool, "FDN", "87", "FR", false, "Goblin Boarders", 1).await;
+        insert_card(&pool, "FDN", "88", "FR", false, "Goblin Boarders", 2).await;
+        insert_collection_entry(&pool, "FDN", "87", "FR", false, "user1", 2, 10, Utc::now()).await;
+        let deck_box = insert_binder(&pool, "user1", "Deck box", false).await;
+        insert_binder_entry(
+            &pool,
+            deck_box,
+            "FDN",
+            "87",
+            "FR",
+            false,
+            "user1",
+            1,
+            10,
+            Utc::now(),
+        )
+        .await;
+        insert_binder_entry(
+            &pool,
+            deck_box,
+            "FDN",
+            "88",
+            "FR",
+            false,
+            "user1",
+            3,
+            10,
+            Utc::now(),
+        )
+        .await;
+        insert_binder(&pool, "user1", "Accessories", false).await;
+        insert_binder(&pool, "user2", "Others", true).await;
+        let adapter = BinderRepositoryAdapter::new(pool);
+
+        let binders = adapter.find_all(&UserId::new("user1")).await.unwrap();
+
+        let summary: Vec<(&str, u64)> = binders
+            .iter()
+            .map(|b| (b.binder.name.as_str(), b.card_count))
+            .collect();
+        assert_eq!(
+            summary,
+            vec![("Collection", 2), ("Accessories", 0), ("Deck box", 4)]
+        );
+        assert!(binders[0].binder.is_default);
+    }
+
+    #[sqlx::test]
+    async fn find_by_id_ignores_other_users_binders(pool: PgPool) {
+        let id = insert_binder(&pool, "user2", "Trades", true).await;
+        let adapter = BinderRepositoryAdapter::new(pool);
+
+        let own = adapter
+            .find_by_id(&UserId::new("user2"), BinderId(id))
+            .await
+            .unwrap();
+        let other = adapter
+            .find_by_id(&UserId::new("user1"), BinderId(id))
+            .await
+            .unwrap();
+
+        assert_eq!(own.unwrap().name, "Trades");
+        assert!(other.is_none());
+    }
+
+    #[sqlx::test]
+    async fn find_or_create_default_creates_a_tradable_binder_once(pool: PgPool) {
+        let adapter = BinderRepositoryAdapter::new(pool);
+
+        let created = adapter
+            .find_or_create_default(&UserId::new("user1"))
+            .await
+            .unwrap();
+        let found = adapter
+            .find_or_create_default(&UserId::new("user1"))
+            .await
+            .unwrap();
+
+        assert_eq!(created, found);
+        assert_eq!(created.name, DEFAULT_BINDER_NAME);
+        assert!(created.tradable);
+        assert!(created.is_default);
+    }
+
+    #[sqlx::test]
+    async fn find_or_create_default_keeps_a_renamed_default_binder(pool: PgPool) {
+        let adapter = BinderRepositoryAdapter::new(pool);
+        let default = adapter
+            .find_or_create_default(&UserId::new("user1"))
+            .await
+            .unwrap();
+        adapter
+            .update(
+                &UserId::new("user1"),
+                default.id,
+                BinderUpdate {
+                    name: Some("Everything".to_string()),
+                    ..BinderUpdate::default()
+                },
+            )
+            .await
+            .unwrap();
+
+        let found = adapter
+            .find_or_create_default(&UserId::new("user1"))
+            .await
+            .unwrap();
+
+        assert_eq!(found.id, default.id);
+        assert_eq!(found.name, "Everything");
+    }
+
+    #[sqlx::test]
+    async fn find_or_create_default_adopts_a_binder_named_like_the_default(pool: PgPool) {
+        let id = insert_binder(&pool, "user1", DEFAULT_BINDER_NAME, false).await;
+        let adapter = BinderRepositoryAdapter::new(pool);
+
+        let default = adapter
+            .find_or_create_default(&UserId::new("user1"))
+            .await
+            .unwrap();
+
+        assert_eq!(default.id, BinderId(id));
+        assert!(default.is_default);
+        assert!(!default.tradable);
+    }
+
+    #[sqlx::test]
+    async fn name_exists_leaves_out_the_excepted_binder(pool: PgPool) {
+        let id = insert_binder(&pool, "user1", "Trades", true).await;
+        let adapter = BinderRepositoryAdapter::new(pool);
+        let user_id = UserId::new("user1");
+
+        assert!(adapter.name_exists(&user_id, "Trades", None).await.unwrap());
+        assert!(
+            !adapter
+                .name_exists(&user_id, "Trades", Some(BinderId(id)))
+                .await
+                .unwrap()
+        );
+        assert!(
+            !adapter
+                .name_exists(&UserId::new("user2"), "Trades", None)
+                .await
+                .unwrap()
+        );
+    }
+
+    #[sqlx::test]
+    async fn create_then_update_only_changes_the_given_fields(pool: PgPool) {
+        let adapter = BinderRepositoryAdapter::new(pool);
+        let binder = Binder {
+            id: BinderId::new(),
+            user_id: UserId::new("user1"),
+            name: "Trades".to_string(),
+            tradable: false,
+            keep_quantity: 0,
+            is_default: false,
+            created_at: Utc::now(),
+        };
+        adapter.create(binder.clone()).await.unwrap();
+
+        let updated = adapter
+            .update(
+                &UserId::new("user1"),
+                binder.id,
+                BinderUpdate {
+                    tradable: Some(true),
+                    keep_quantity: Some(4),
+                    ..BinderUpdate::default()
+                },
+            )
+            .await
+            .unwrap()
+            .unwrap();
+
+        assert_eq!(updated.name, "Trades");
+        assert!(updated.tradable);
+        assert_eq!(updated.keep_quantity, 4);
+        assert!(
+            adapter
+                .update(&UserId::new("user2"), binder.id, BinderUpdate::default())
+                .await
+                .unwrap()
+                .is_none()
+        );
+    }
+
+    #[sqlx::test]
+    async fn delete_removes_the_binder_and_its_entries(pool: PgPool) {
+        insert_card(&pool, "FDN", "87", "FR", false, "Goblin Boarders", 1).await;
+        let id = insert_binder(&pool, "user1", "Trades", true).await;
+        insert_binder_entry(
+            &pool,
+            id,
+            "FDN",
+            "87",
+            "FR",
+            false,
+            "user1",
+            1,
+            10,
+            Utc::now(),
+        )
+        .await;
+        let adapter = BinderRepositoryAdapter::new(pool.clone());
+
+        assert!(
+            !adapter
+                .delete(&UserId::new("user2"), BinderId(id))
+                .await
+                .unwrap()
+        );
+        assert!(
+            adapter
+                .delete(&UserId::new("user1"), BinderId(id))
+                .await
+                .unwrap()
+        );
+
+        let entries: i64 = sqlx::query_scalar("SELECT COUNT(*) FROM collection_entry")
+            .fetch_one(&pool)
+            .await
+            .unwrap();
+        assert_eq!(entries, 0);
+    }
+
+    #[sqlx::test]
+    async fn delete_keeps_the_default_binder(pool: PgPool) {
+        let adapter = BinderRepositoryAdapter::new(pool);
+        let default = adapter
+            .find_or_create_default(&UserId::new("user1"))
+            .await
+            .unwrap();
+
+        assert!(
+            !adapter
+                .delete(&UserId::new("user1"), default.id)
+                .await
+                .unwrap()
+        );
+        assert!(
+            adapter
+                .find_by_id(&UserId::new("user1"), default.id)
+                .await
+                .unwrap()
+                .is_some()
+        );
+    }
+}
@@ -1,21 +1,27 @@
 use crate::application::error::{AppError, InfraError};
 use crate::application::repository::CardPricesViewRepository;
-use crate::domain::card::{Card, CardId, CollectionEntry};
+use crate::domain::card::{Card, CardId, CardSelector, CollectionEntry};
 use crate::domain::card_offer::{CardOfferSortField, PaginatedCardOffers};
 use crate::domain::collection::{CollectionQuery, PaginatedCollection, SearchQuery};
+use crate::domain::commander_synergy::OwnedPrinting;
+use crate::domain::oracle_card::{OracleCard, PaginatedOracleCards};
 use crate::domain::user::UserId;
 use crate::infrastructure::adapter_out::repository::entities::{
-    CardOfferEntity, CardWithPriceEntity,
+    CardOfferEntity, CardWithPriceEntity, OracleCardEntity, OwnedPrintingEntity, to_db_colors,
 };
 use async_trait::async_trait;
 use sqlx::{AssertSqlSafe, Pool, Postgres, query_as, query_scalar};
+use std::collections::HashMap;
 
-/// Builds the "AND ..." filter clause (search, rarity, sets, price range, player
-/// username) for the collection query, starting bind placeholders at `start_idx`.
+/// Builds the "AND ..." filter clause (search, rarity, sets, price range, colours, type line,
+/// oracle id, binder, tags, location, player username) for the collection query, starting bind
+/// placeholders at `start_idx`. The search also looks into the notes, tags and location of the
+/// entries when `own_collection` is set, these being private.
 /// Returns (filter_clause, order_prefix, next_idx).
 fn build_filter_clause(
     query: &CollectionQuery,
     player_username: Option<&str>,
+    own_collection: bool,
     start_idx: u32,
 ) -> (String, String, u32) {
     let mut idx = start_idx;
@@ -23,8 +29,16 @@ fn build_filter_clause(
     let mut order_prefix = String::new();
 
     if query.search_query.is_some() {
+        let private_fields = if own_collection {
+            format!(
+                " OR cp.notes ILIKE '%' || ${idx} || '%' OR cp.location ILIKE '%' || ${idx} || '%'
+                  OR array_to_string(cp.tags, ' ') ILIKE '%' || ${idx} || '%'"
+            )
+        } else {
+            String::new()
+        };
         conditions.push(format!(
-            "(cp.name ILIKE '%' || ${idx} || '%' OR ${idx} <% cp.name)"
+            "(cp.name ILIKE '%' || ${idx} || '%' OR ${idx} <% cp.name{private_fields})"
         ));
         order_prefix = format!("word_similarity(${idx}, cp.name) DESC,");
         idx += 1;
@@ -45,6 +59,30 @@ fn build_filter_clause(
         conditions.push(format!("cp.trend <= ${idx}"));
         idx += 1;
     }
+    if !query.colors.is_empty() {
+        conditions.push(format!("c.colors @> ${idx}"));
+        idx += 1;
+    }
+    if query.type_line.is_some() {
+        conditions.push(format!("c.type_line ILIKE '%' || ${idx} || '%'"));
+        idx += 1;
+    }
+    if query.oracle_id.is_some() {
+        conditions.push(format!("c.oracle_id = ${idx}"));
+        idx += 1;
+    }
+    if query.binder_id.is_some() {
+        conditions.push(format!("cp.binder_id = ${idx}"));
+        idx += 1;
+    }
+    if !query.tags.is_empty() {
+        conditions.push(format!("cp.tags @> ${idx}::varchar[]"));
+        idx += 1;
+    }
+    if query.location.is_some() {
+        conditions.push(format!("cp.location ILIKE '%' || ${idx} || '%'"));
+        idx += 1;
+    }
     if player_username.is_some() {
         conditions.push(format!(
             "cp.user_id IN (SELECT id FROM users WHERE LOWER(username) = LOWER(${idx}))"
@@ -61,6 +99,62 @@ fn build_filter_clause(
     (filter_clause, order_prefix, idx)
 }
 
+/// Binds the values of the placeholders of [`build_filter_clause`], in the same order.
+macro_rules! bind_filters {
+    ($query:expr, $collection_query:expr, $player_username:expr) => {{
+        let mut query = $query;
+        let collection_query: &CollectionQuery = $collection_query;
+        if let Some(q) = &collection_query.search_query {
+            query = query.bind(q.clone());
+        }
+        if !collection_query.rarity.is_empty() {
+            query = query.bind(
+                collection_query
+                    .rarity
+                    .iter()
+                    .map(|r| r.to_string())
+                    .collect::<Vec<_>>(),
+            );
+        }
+        if !collection_query.sets.is_empty() {
+            query = query.bind(collection_query.sets.clone());
+        }
+        if let Some(v) = collection_query.price_min {
+            query = query.bind(v as i64);
+        }
+        if let Some(v) = collection_query.price_max {
+            query = query.bind(v as i64);
+        }
+        if !collection_query.colors.is_empty() {
+            query = query.bind(to_db_colors(&collection_query.colors));
+        }
+        if let Some(type_line) = &collection_query.type_line {
+            query = query.bind(type_line.clone());
+        }
+        if let Some(oracle_id) = collection_query.oracle_id {
+            query = query.bind(oracle_id);
+        }
+        if let Some(binder_id) = collection_query.binder_id {
+            query = query.bind(binder_id.0);
+        }
+        if !collection_query.tags.is_empty() {
+            query = query.bind(collection_query.tags.clone());
+        }
+        if let Some(location) = &collection_query.location {
+            query = query.bind(location.clone());
+        }
+        if let Some(username) = $player_username {
+            query = query.bind(username.to_string());
+        }
+        query
+    }};
+}
+
+/// The metadata of the cards is read from `card` rather than copied into `card_prices`.
+const CARD_JOIN: &str =
+    "JOIN card c ON c.set_code = cp.set_code AND c.collector_number = cp.collector_number
+                        AND c.language_code = cp.language_code AND c.foil = cp.foil";
+
 pub struct CardPricesViewRepositoryAdapter {
     pool: Pool<Postgres>,
 }
@@ -75,8 +169,9 @@ impl CardPricesViewRepositoryAdapter {
     ///
     /// `user_id` is `Some` for the private "my collection" view (filtered to that user,
     /// real `purchase_price`/`added_at`, no `owner_count`) and `None` for the public
-    /// search across every user's cards (no user filter, masked `purchase_price`/`added_at`,
-    /// rows grouped by card with `owner_count` = number of distinct owners).
+    /// search across every user's tradable binders (no user filter, masked
+    /// `purchase_price`/`added_at`, rows grouped by card with `owner_count` = number of
+    /// distinct owners).
     async fn fetch_paginated(
         &self,
         user_id: Option<&UserId>,
@@ -85,12 +180,14 @@ impl CardPricesViewRepositoryAdapter {
     ) -> Result<PaginatedCollection, AppError> {
         let limit_idx = if user_id.is_some() { 2 } else { 1 };
         let offset_idx = limit_idx + 1;
+        let own_collection = user_id.is_some();
         let (filter_clause, order_prefix, _) =
-            build_filter_clause(&query, player_username, offset_idx + 1);
+            build_filter_clause(&query, player_username, own_collection, offset_idx + 1);
         let (count_filter_clause, _, _) = build_filter_clause(
             &query,
             player_username,
-            if user_id.is_some() { 2 } else { 1 },
+            own_collection,
+            if own_collection { 2 } else { 1 },
         );
 
         let (where_clause, owned_columns, group_by_clause) = if user_id.is_some() {
@@ -99,6 +196,11 @@ impl CardPricesViewRepositoryAdapter {
                 r#"cp.quantity,
                  cp.purchase_price,
                  cp.added_at,
+                 cp.binder_id,
+                 cp.tradable_quantity,
+                 cp.tags,
+                 cp.notes,
+                 cp.location,
                  0::bigint AS owner_count,
                  EXISTS (
                      SELECT 1 FROM trade_card tc
@@ -112,15 +214,21 @@ impl CardPricesViewRepositoryAdapter {
             )
         } else {
             (
-                "WHERE 1 = 1",
+                "WHERE cp.tradable_quantity > 0",
                 r#"0::integer AS quantity,
                  NULL::integer AS purchase_price,
                  NULL::timestamptz AS added_at,
+                 NULL::uuid AS binder_id,
+                 0::integer AS tradable_quantity,
+                 '{}'::varchar[] AS tags,
+                 NULL::text AS notes,
+                 NULL::varchar AS location,
                  COUNT(DISTINCT cp.user_id) AS owner_count,
                  false AS reserved"#,
                 r#"GROUP BY cp.set_code, sn.name, cp.collector_number, cp.language_code,
                             cp.foil, cp.name, cp.rarity, cp.scryfall_id, cp.the_gatherer_id,
-                            cp.avg, cp.low, cp.trend"#,
+                            cp.avg, cp.low, cp.trend,
+                            c.set_code, c.collector_number, c.language_code, c.foil"#,
             )
         };
 
@@ -135,12 +243,23 @@ impl CardPricesViewRepositoryAdapter {
                  cp.rarity,
                  cp.scryfall_id,
                  cp.the_gatherer_id,
+                 c.the_gatherer_back_id,
                  {owned_columns},
+                 c.oracle_id,
+                 c.mana_cost,
+                 c.type_line,
+                 c.colors,
+                 c.color_identity,
+                 c.oracle_text,
+                 c.image_small_url,
+                 c.image_normal_url,
+                 c.image_large_url,
                  cp.avg,
                  cp.low,
                  cp.trend
-               FROM mv_card_prices cp
+               FROM card_prices cp
                JOIN set_name sn ON sn.set_code = cp.set_code
+               {CARD_JOIN}
                {where_clause}
                {filter_clause}
                {group_by_clause}
@@ -157,30 +276,7 @@ impl CardPricesViewRepositoryAdapter {
             base_query = base_query.bind(uid.as_str());
         }
         base_query = base_query.bind(limit).bind(offset);
-        if let Some(q) = &query.search_query {
-            base_query = base_query.bind(q.clone());
-        }
-        if !query.rarity.is_empty() {
-            base_query = base_query.bind(
-                query
-                    .rarity
-                    .iter()
-                    .map(|r| r.to_string())
-                    .collect::<Vec<_>>(),
-            );
-        }
-        if !query.sets.is_empty() {
-            base_query = base_query.bind(query.sets.clone());
-        }
-        if let Some(v) = query.price_min {
-            base_query = base_query.bind(v as i64);
-        }
-        if let Some(v) = query.price_max {
-            base_query = base_query.bind(v as i64);
-        }
-        if let Some(username) = player_username {
-            base_query = base_query.bind(username.to_string());
-        }
+        let base_query = bind_filters!(base_query, &query, player_username);
 
         let entities = base_query
             .fetch_all(&self.pool)
@@ -188,11 +284,13 @@ impl CardPricesViewRepositoryAdapter {
             .map_err(|e| AppError::Infra(InfraError::RepositoryError(e.to_string())))?;
 
         let count_sql = if user_id.is_some() {
-            format!("SELECT COUNT(*) FROM mv_card_prices cp {where_clause} {count_filter_clause}")
+            format!(
+                "SELECT COUNT(*) FROM card_prices cp {CARD_JOIN} {where_clause} {count_filter_clause}"
+            )
         } else {
             format!(
                 r#"SELECT COUNT(*) FROM (
-                     SELECT 1 FROM mv_card_prices cp {where_clause} {count_filter_clause}
+                     SELECT 1 FROM card_prices cp {CARD_JOIN} {where_clause} {count_filter_clause}
                      GROUP BY cp.set_code, cp.collector_number, cp.language_code, cp.foil
                    ) sub"#
             )
@@ -202,30 +300,7 @@ impl CardPricesViewRepositoryAdapter {
         if let Some(uid) = user_id {
             base_count = base_count.bind(uid.as_str());
         }
-        if let Some(q) = &query.search_query {
-            base_count = base_count.bind(q.clone());
-        }
-        if !query.rarity.is_empty() {
-            base_count = base_count.bind(
-                query
-                    .rarity
-                    .iter()
-                    .map(|r| r.to_string())
-                    .collect::<Vec<_>>(),
-            );
-        }
-        if !query.sets.is_empty() {
-            base_count = base_count.bind(query.sets.clone());
-        }
-        if let Some(v) = query.price_min {
-            base_count = base_count.bind(v as i64);
-        }
-        if let Some(v) = query.price_max {
-            base_count = base_count.bind(v as i64);
-        }
-        if let Some(username) = player_username {
-            base_count = base_count.bind(username.to_string());
-        }
+        let base_count = bind_filters!(base_count, &query, player_username);
 
         let total: i64 = base_count
             .fetch_one(&self.pool)
@@ -239,15 +314,160 @@ impl CardPricesViewRepositoryAdapter {
             page_size: query.page_size,
         })
     }
+
+    /// Implementation of [`CardPricesViewRepository::search_oracle_paginated`]: the matching
+    /// rows are grouped by `oracle_id`, each group being shown through one representative
+    /// printing (English and non-foil first) and sorted by its cheapest trend.
+    async fn fetch_oracle_paginated(
+        &self,
+        query: CollectionQuery,
+        player_username: Option<&str>,
+    ) -> Result<PaginatedOracleCards, AppError> {
+        let (filter_clause, order_prefix, _) =
+            build_filter_clause(&query, player_username, false, 3);
+        let (count_filter_clause, _, _) = build_filter_clause(&query, player_username, false, 1);
+
+        let sql = format!(
+            r#"WITH matching AS (
+                 SELECT cp.set_code, cp.collector_number, cp.language_code, cp.foil, cp.user_id,
+                        cp.name, cp.trend,
+                        c.oracle_id, c.mana_cost, c.type_line, c.colors, c.color_identity,
+                        c.oracle_text, c.image_small_url, c.image_normal_url, c.image_large_url
+                 FROM card_prices cp
+                 {CARD_JOIN}
+                 WHERE c.oracle_id IS NOT NULL AND cp.tradable_quantity > 0
+                 {filter_clause}
+               )
+               SELECT
+                 g.oracle_id,
+                 cp.name,
+                 cp.mana_cost,
+                 cp.type_line,
+                 cp.colors,
+                 cp.color_identity,
+                 cp.oracle_text,
+                 cp.image_small_url,
+                 cp.image_normal_url,
+                 cp.image_large_url,
+                 g.printing_count,
+                 g.owner_count,
+                 g.lowest_trend
+               FROM (
+                 SELECT oracle_id,
+                        COUNT(DISTINCT (set_code, collector_number, language_code, foil)) AS printing_count,
+                        COUNT(DISTINCT user_id) AS owner_count,
+                        MIN(trend) AS lowest_trend
+                 FROM matching
+                 GROUP BY oracle_id
+               ) g
+               JOIN LATERAL (
+                 SELECT * FROM matching m
+                 WHERE m.oracle_id = g.oracle_id
+                 ORDER BY m.language_code = 'EN' DESC, m.foil, m.set_code, m.collector_number
+                 LIMIT 1
+               ) cp ON true
+               ORDER BY {order_prefix} g.lowest_trend {} NULLS LAST, cp.name
+               LIMIT $1 OFFSET $2"#,
+            query.sort_dir,
+        );
+
+        let offset = (query.page * query.page_size) as i64;
+        let limit = query.page_size as i64;
+
+        let base_query = query_as::<_, OracleCardEntity>(AssertSqlSafe(sql.as_str()))
+            .bind(limit)
+            .bind(offset);
+        let entities = bind_filters!(base_query, &query, player_username)
+            .fetch_all(&self.pool)
+            .await?;
+
+        let count_sql = format!(
+            "SELECT COUNT(DISTINCT c.oracle_id) FROM card_prices cp {CARD_JOIN}
+             WHERE c.oracle_id IS NOT NULL AND cp.tradable_quantity > 0 {count_filter_clause}"
+        );
+        let base_count = query_scalar::<_, i64>(AssertSqlSafe(count_sql.as_str()));
+        let total = bind_filters!(base_count, &query, player_username)
+            .fetch_one(&self.pool)
+            .await?;
+
+        Ok(PaginatedOracleCards {
+            items: entities.into_iter().map(OracleCard::from).collect(),
+            total: total as u64,
+            page: query.page,
+            page_size: query.page_size,
+        })
+    }
 }
 
 #[async_trait]
 impl CardPricesViewRepository for CardPricesViewRepositoryAdapter {
-    async fn refresh(&self) -> Result<(), AppError> {
-        sqlx::query("REFRESH MATERIALIZED VIEW CONCURRENTLY mv_card_prices")
-            .execute(&self.pool)
-            .await?;
+    async fn refresh_user(&self, user_id: &UserId) -> Result<(), AppError> {
+        let mut tx = self.pool.begin().await?;
 
+        sqlx::query!(
+            "DELETE FROM card_prices WHERE user_id = $1",
+            user_id.as_str()
+        )
+        .execute(&mut *tx)
+        .await?;
+        sqlx::query!(
+            "INSERT INTO card_prices SELECT * FROM card_prices_source WHERE user_id = $1",
+            user_id.as_str()
+        )
+        .execute(&mut *tx)
+        .await?;
+
+        tx.commit().await?;
+        Ok(())
+    }
+
+    async fn refresh_products(&self, id_products: Vec<u32>) -> Result<(), AppError> {
+        let id_products: Vec<i32> = id_products.into_iter().map(|id| id as i32).collect();
+        let mut tx = self.pool.begin().await?;
+
+        sqlx::query!(
+            "DELETE FROM card_prices WHERE cardmarket_id = ANY($1)",
+            &id_products
+        )
+        .execute(&mut *tx)
+        .await?;
+        sqlx::query!(
+            "INSERT INTO card_prices SELECT * FROM card_prices_source WHERE cardmarket_id = ANY($1)",
+            &id_products
+        )
+        .execute(&mut *tx)
+        .await?;
+
+        tx.commit().await?;
+        Ok(())
+    }
+
+    async fn refresh_card(&self, card_id: &CardId) -> Result<(), AppError> {
+        let mut tx = self.pool.begin().await?;
+
+        sqlx::query!(
+            "DELETE FROM card_prices
+             WHERE set_code = $1 AND collector_number = $2 AND language_code = $3 AND foil = $4",
+            card_id.set_code.to_string(),
+            card_id.collector_number,
+            card_id.language_code.to_string(),
+            card_id.foil
+        )
+        .execute(&mut *tx)
+        .await?;
+        sqlx::query!(
+            "INSERT INTO card_prices
+             SELECT * FROM card_prices_source
+             WHERE set_code = $1 AND collector_number = $2 AND language_code = $3 AND foil = $4",
+            card_id.set_code.to_string(),
+            card_id.collector_number,
+            card_id.language_code.to_string(),
+            card_id.foil
+        )
+        .execute(&mut *tx)
+        .await?;
+
+        tx.commit().await?;
         Ok(())
     }
 
@@ -268,17 +488,37 @@ impl CardPricesViewRepository for CardPricesViewRepositoryAdapter {
         .await
     }
 
-    async fn exists(&self, card_id: &CardId) -> Result<bool, AppError> {
-        let exists = sqlx::query_scalar!(
-            r#"SELECT EXISTS(SELECT 1 FROM mv_card_prices
-                 WHERE set_code = $1 AND collector_number = $2 AND language_code = $3 AND foil = $4)"#,
-            card_id.set_code.to_string(),
-            card_id.collector_number,
-            card_id.language_code.to_string(),
-            card_id.foil
-        )
-        .fetch_one(&self.pool)
-        .await
+    async fn search_oracle_paginated(
+        &self,
+        query: SearchQuery,
+    ) -> Result<PaginatedOracleCards, AppError> {
+        self.fetch_oracle_paginated(query.collection_query, query.player_username.as_deref())
+            .await
+    }
+
+    async fn exists(&self, selector: &CardSelector) -> Result<bool, AppError> {
+        let exists = match selector {
+            CardSelector::Printing(card_id) => sqlx::query_scalar!(
+                r#"SELECT EXISTS(SELECT 1 FROM card_prices
+                     WHERE set_code = $1 AND collector_number = $2 AND language_code = $3 AND foil = $4
+                       AND tradable_quantity > 0)"#,
+                card_id.set_code.to_string(),
+                card_id.collector_number,
+                card_id.language_code.to_string(),
+                card_id.foil
+            )
+            .fetch_one(&self.pool)
+            .await,
+            CardSelector::AnyPrinting { oracle_id } => sqlx::query_scalar!(
+                r#"SELECT EXISTS(SELECT 1 FROM card_prices cp
+                     JOIN card c ON c.set_code = cp.set_code AND c.collector_number = cp.collector_number
+                                AND c.language_code = cp.language_code AND c.foil = cp.foil
+                     WHERE c.oracle_id = $1 AND cp.tradable_quantity > 0)"#,
+                oracle_id
+            )
+            .fetch_one(&self.pool)
+            .await,
+        }
         .map_err(|e| AppError::Infra(InfraError::RepositoryError(e.to_string())))?;
 
         Ok(exists.unwrap_or(false))
@@ -287,7 +527,7 @@ impl CardPricesViewRepository for CardPricesViewRepositoryAdapter {
     async fn get_offers(
         &self,
         user_id: &UserId,
-        card_id: &CardId,
+        selector: &CardSelector,
         sort_by: CardOfferSortField,
         page: u32,
         page_size: u32,
@@ -295,41 +535,85 @@ impl CardPricesViewRepository for CardPricesViewRepositoryAdapter {
         let offset = (page * page_size) as i64;
         let limit = page_size as i64;
 
-        let entities = match sort_by {
-            CardOfferSortField::SellingPrice => sqlx::query_as!(
-                CardOfferEntity,
-                r#"SELECT u.username AS owner_username, cp.quantity AS "quantity!", cp.trend AS selling_price
-                     FROM mv_card_prices cp
-                     JOIN users u ON u.id = cp.user_id
+        let entities = match (sort_by, selector) {
+            (CardOfferSortField::SellingPrice, CardSelector::Printing(card_id)) => {
+                sqlx::query_as!(
+                    CardOfferEntity,
+                    r#"SELECT cp.set_code, cp.collector_number, cp.language_code, cp.foil,
+                              u.username AS owner_username, SUM(cp.tradable_quantity)::INTEGER AS "quantity!",
+                              cp.trend AS selling_price
+                         FROM card_prices cp
+                         JOIN users u ON u.id = cp.user_id
+                         WHERE cp.set_code = $1 AND cp.collector_number = $2 AND cp.language_code = $3
+                           AND cp.foil = $4 AND cp.user_id != $5 AND cp.tradable_quantity > 0
+                         GROUP BY cp.set_code, cp.collector_number, cp.language_code, cp.foil, u.id,
+                                  cp.trend
+                         ORDER BY cp.trend ASC NULLS LAST, u.username
+                         LIMIT $6 OFFSET $7"#,
+                    card_id.set_code.to_string(),
+                    card_id.collector_number,
+                    card_id.language_code.to_string(),
+                    card_id.foil,
+                    user_id.as_str(),
+                    limit,
+                    offset
+                )
+                .fetch_all(&self.pool)
+                .await
+            }
+            (CardOfferSortField::SellingPrice, CardSelector::AnyPrinting { oracle_id }) => {
+                sqlx::query_as!(
+                    CardOfferEntity,
+                    r#"SELECT cp.set_code, cp.collector_number, cp.language_code, cp.foil,
+                              u.username AS owner_username, SUM(cp.tradable_quantity)::INTEGER AS "quantity!",
+                              cp.trend AS selling_price
+                         FROM card_prices cp
+                         JOIN card c ON c.set_code = cp.set_code AND c.collector_number = cp.collector_number
+                                    AND c.language_code = cp.language_code AND c.foil = cp.foil
+                         JOIN users u ON u.id = cp.user_id
+                         WHERE c.oracle_id = $1 AND cp.user_id != $2 AND cp.tradable_quantity > 0
+                         GROUP BY cp.set_code, cp.collector_number, cp.language_code, cp.foil, u.id,
+                                  cp.trend
+                         ORDER BY cp.trend ASC NULLS LAST, u.username, cp.set_code, cp.collector_number,
+                                  cp.language_code, cp.foil
+                         LIMIT $3 OFFSET $4"#,
+                    oracle_id,
+                    user_id.as_str(),
+                    limit,
+                    offset
+                )
+                .fetch_all(&self.pool)
+                .await
+            }
+        }
+        .map_err(|e| AppError::Infra(InfraError::RepositoryError(e.to_string())))?;
+
+        let total = match selector {
+            CardSelector::Printing(card_id) => sqlx::query_scalar!(
+                r#"SELECT COUNT(DISTINCT cp.user_id) FROM card_prices cp
                      WHERE cp.set_code = $1 AND cp.collector_number = $2 AND cp.language_code = $3
-                       AND cp.foil = $4 AND cp.user_id != $5
-                     ORDER BY cp.trend ASC NULLS LAST, u.username
-                     LIMIT $6 OFFSET $7"#,
+                       AND cp.foil = $4 AND cp.user_id != $5 AND cp.tradable_quantity > 0"#,
                 card_id.set_code.to_string(),
                 card_id.collector_number,
                 card_id.language_code.to_string(),
                 card_id.foil,
-                user_id.as_str(),
-                limit,
-                offset
+                user_id.as_str()
             )
-            .fetch_all(&self.pool)
-            .await
-            .map_err(|e| AppError::Infra(InfraError::RepositoryError(e.to_string())))?,
-        };
-
-        let total = sqlx::query_scalar!(
-            r#"SELECT COUNT(*) FROM mv_card_prices cp
-                 WHERE cp.set_code = $1 AND cp.collector_number = $2 AND cp.language_code = $3
-                   AND cp.foil = $4 AND cp.user_id != $5"#,
-            card_id.set_code.to_string(),
-            card_id.collector_number,
-            card_id.language_code.to_string(),
-            card_id.foil,
-            user_id.as_str()
-        )
-        .fetch_one(&self.pool)
-        .await
+            .fetch_one(&self.pool)
+            .await,
+            CardSelector::AnyPrinting { oracle_id } => sqlx::query_scalar!(
+                r#"SELECT COUNT(DISTINCT (cp.user_id, cp.set_code, cp.collector_number, cp.language_code,
+                                         cp.foil))
+                     FROM card_prices cp
+                     JOIN card c ON c.set_code = cp.set_code AND c.collector_number = cp.collector_number
+                                AND c.language_code = cp.language_code AND c.foil = cp.foil
+                     WHERE c.oracle_id = $1 AND cp.user_id != $2 AND cp.tradable_quantity > 0"#,
+                oracle_id,
+                user_id.as_str()
+            )
+            .fetch_one(&self.pool)
+            .await,
+        }
         .map_err(|e| AppError::Infra(InfraError::RepositoryError(e.to_string())))?
         .unwrap_or(0);
 
@@ -340,21 +624,70 @@ impl CardPricesViewRepository for CardPricesViewRepositoryAdapter {
             page_size,
         })
     }
+
+    async fn get_owned_printings_by_names(
+        &self,
+        user_id: &UserId,
+        names: Vec<String>,
+    ) -> Result<Vec<OwnedPrinting>, AppError> {
+        let entities = sqlx::query_as!(
+            OwnedPrintingEntity,
+            r#"SELECT set_code, collector_number, language_code, foil, name, scryfall_id,
+                      SUM(quantity)::INTEGER AS "quantity!", trend
+                 FROM card_prices
+                 WHERE user_id = $1 AND LOWER(name) = ANY($2)
+                 GROUP BY set_code, collector_number, language_code, foil, name, scryfall_id, trend
+                 ORDER BY name, set_code, collector_number, language_code, foil"#,
+            user_id.as_str(),
+            &names
+        )
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(entities.into_iter().map(OwnedPrinting::from).collect())
+    }
+
+    async fn count_other_owners_by_names(
+        &self,
+        user_id: &UserId,
+        names: Vec<String>,
+    ) -> Result<HashMap<String, u32>, AppError> {
+        let rows = sqlx::query!(
+            r#"SELECT LOWER(name) AS "name!", COUNT(DISTINCT user_id) AS "owner_count!"
+                 FROM card_prices
+                 WHERE user_id != $1 AND LOWER(name) = ANY($2) AND tradable_quantity > 0
+                 GROUP BY LOWER(name)"#,
+            user_id.as_str(),
+            &names
+        )
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(rows
+            .into_iter()
+            .map(|row| (row.name, row.owner_count as u32))
+            .collect())
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::domain::binder::BinderId;
+    use crate::domain::card_metadata::Color;
     use crate::domain::collection::{CollectionSortField, SortDirection};
     use crate::domain::rarity_code::RarityCode;
     use crate::infrastructure::adapter_out::repository::common_repository_tests::{
-        insert_card, insert_collection_entry, insert_price, insert_set, insert_user, refresh_view,
+        insert_binder, insert_binder_entry, insert_card, insert_card_without_cardmarket_id,
+        insert_collection_entry, insert_default_binder, insert_price, insert_set, insert_user,
+        quarantine_price, refresh_view, set_keep_quantity, set_oracle_id, set_tradable_quantity,
     };
     use crate::infrastructure::adapter_out::repository::entities::{
         CardMarketPriceEntity, PriceGuideEntity,
     };
     use chrono::{NaiveDate, Utc};
     use sqlx::{PgPool, Pool, Postgres};
+    use uuid::Uuid;
 
     impl CardMarketPriceEntity {
         pub fn simple(id_produit: i32, avg: i32) -> Self {
@@ -365,6 +698,7 @@ mod tests {
             Self {
                 id_produit,
                 date,
+                id_category: None,
                 normal: PriceGuideEntity {
                     low: Some(avg / 2),
                     avg: Some(avg),
@@ -382,6 +716,7 @@ mod tests {
             Self {
                 id_produit,
                 date,
+                id_category: None,
                 normal: PriceGuideEntity {
                     low: Some(avg / 2),
                     avg: Some(avg),
@@ -396,13 +731,129 @@ mod tests {
         }
     }
 
+    async fn total_of(adapter: &CardPricesViewRepositoryAdapter, user_id: &str) -> u64 {
+        adapter
+            .get_paginated(&UserId::new(user_id), CollectionQuery::default())
+            .await
+            .unwrap()
+            .total
+    }
+
+    async fn trend_of(adapter: &CardPricesViewRepositoryAdapter, user_id: &str) -> Option<u32> {
+        adapter
+            .get_paginated(&UserId::new(user_id), CollectionQuery::default())
+            .await
+            .unwrap()
+            .items
+            .into_iter()
+            .next()
+            .and_then(|card| card.price_guide)
+            .and_then(|price_guide| price_guide.trend.value)
+    }
+
     #[sqlx::test]
-    async fn test_refresh_materialized_view(pool: Pool<Postgres>) {
+    async fn refresh_user_only_rebuilds_rows_of_the_user(pool: Pool<Postgres>) {
+        insert_set(&pool, "TST").await;
+        insert_card(&pool, "TST", "1", "EN", false, "Test Card", 1).await;
+        insert_collection_entry(&pool, "TST", "1", "EN", false, "user1", 1, 100, Utc::now()).await;
+        insert_collection_entry(&pool, "TST", "1", "EN", false, "user2", 1, 100, Utc::now()).await;
+        refresh_view(&pool).await;
+        sqlx::query("DELETE FROM collection_entry")
+            .execute(&pool)
+            .await
+            .unwrap();
+        insert_collection_entry(&pool, "TST", "1", "EN", false, "user3", 1, 100, Utc::now()).await;
+
         let adapter = CardPricesViewRepositoryAdapter::new(pool);
+        adapter.refresh_user(&UserId::new("user1")).await.unwrap();
+        adapter.refresh_user(&UserId::new("user3")).await.unwrap();
+
+        assert_eq!(total_of(&adapter, "user1").await, 0);
+        assert_eq!(total_of(&adapter, "user2").await, 1);
+        assert_eq!(total_of(&adapter, "user3").await, 1);
+    }
 
-        let result = adapter.refresh().await;
+    #[sqlx::test]
+    async fn refresh_products_updates_prices_of_the_products_only(pool: Pool<Postgres>) {
+        let day = NaiveDate::from_ymd_opt(2025, 3, 1).unwrap();
+        let next_day = NaiveDate::from_ymd_opt(2025, 3, 2).unwrap();
+        insert_set(&pool, "TST").await;
+        insert_card(&pool, "TST", "1", "EN", false, "Card 1", 1).await;
+        insert_card(&pool, "TST", "2", "EN", false, "Card 2", 2).await;
+        insert_collection_entry(&pool, "TST", "1", "EN", false, "user1", 1, 100, Utc::now()).await;
+        insert_collection_entry(&pool, "TST", "2", "EN", false, "user2", 1, 100, Utc::now()).await;
+        insert_price(&pool, CardMarketPriceEntity::simple_at(1, day, 200)).await;
+        insert_price(&pool, CardMarketPriceEntity::simple_at(2, day, 200)).await;
+        refresh_view(&pool).await;
+        insert_price(&pool, CardMarketPriceEntity::simple_at(1, next_day, 900)).await;
+        insert_price(&pool, CardMarketPriceEntity::simple_at(2, next_day, 900)).await;
+
+        let adapter = CardPricesViewRepositoryAdapter::new(pool);
+        adapter.refresh_products(vec![1]).await.unwrap();
 
-        assert!(result.is_ok());
+        assert_eq!(trend_of(&adapter, "user1").await, Some(900));
+        assert_eq!(trend_of(&adapter, "user2").await, Some(200));
+    }
+
+    #[sqlx::test]
+    async fn refresh_products_ignores_quarantined_prices(pool: Pool<Postgres>) {
+        let day = NaiveDate::from_ymd_opt(2025, 3, 1).unwrap();
+        let next_day = NaiveDate::from_ymd_opt(2025, 3, 2).unwrap();
+        insert_set(&pool, "TST").await;
+        insert_card(&pool, "TST", "1", "EN", false, "Card 1", 1).await;
+        insert_collection_entry(&pool, "TST", "1", "EN", false, "user1", 1, 100, Utc::now()).await;
+        insert_price(&pool, CardMarketPriceEntity::simple_at(1, day, 200)).await;
+        insert_price(&pool, CardMarketPriceEntity::simple_at(1, next_day, 90_000)).await;
+        quarantine_price(&pool, 1, next_day).await;
+
+        let adapter = CardPricesViewRepositoryAdapter::new(pool);
+        adapter.refresh_products(vec![1]).await.unwrap();
+
+        assert_eq!(trend_of(&adapter, "user1").await, Some(200));
+    }
+
+    #[sqlx::test]
+    async fn refresh_products_keeps_the_price_of_the_sane_finish(pool: Pool<Postgres>) {
+        let day = NaiveDate::from_ymd_opt(2025, 3, 1).unwrap();
+        let next_day = NaiveDate::from_ymd_opt(2025, 3, 2).unwrap();
+        insert_set(&pool, "TST").await;
+        insert_card(&pool, "TST", "1", "EN", false, "Card 1", 1).await;
+        insert_collection_entry(&pool, "TST", "1", "EN", false, "user1", 1, 100, Utc::now()).await;
+        insert_price(&pool, CardMarketPriceEntity::simple_at(1, day, 200)).await;
+        insert_price(&pool, CardMarketPriceEntity::simple_at(1, next_day, 300)).await;
+        sqlx::query("UPDATE cardmarket_price SET quarantined_foil = TRUE WHERE date = $1")
+            .bind(next_day)
+            .execute(&pool)
+            .await
+            .unwrap();
+
+        let adapter = CardPricesViewRepositoryAdapter::new(pool);
+        adapter.refresh_products(vec![1]).await.unwrap();
+
+        assert_eq!(trend_of(&adapter, "user1").await, Some(300));
+    }
+
+    #[sqlx::test]
+    async fn refresh_card_picks_up_its_new_cardmarket_id(pool: Pool<Postgres>) {
+        insert_set(&pool, "TST").await;
+        insert_card_without_cardmarket_id(&pool, "TST", "1", "EN", false, "Card 1").await;
+        insert_collection_entry(&pool, "TST", "1", "EN", false, "user1", 1, 100, Utc::now()).await;
+        insert_collection_entry(&pool, "TST", "1", "EN", false, "user2", 1, 100, Utc::now()).await;
+        insert_price(&pool, CardMarketPriceEntity::simple(7, 300)).await;
+        refresh_view(&pool).await;
+        sqlx::query("UPDATE card SET cardmarket_id = 7")
+            .execute(&pool)
+            .await
+            .unwrap();
+
+        let adapter = CardPricesViewRepositoryAdapter::new(pool);
+        adapter
+            .refresh_card(&card_id("TST", "1", "EN", false))
+            .await
+            .unwrap();
+
+        assert_eq!(trend_of(&adapter, "user1").await, Some(300));
+        assert_eq!(trend_of(&adapter, "user2").await, Some(300));
     }
 
     #[sqlx::test]
@@ -757,10 +1208,191 @@ mod tests {
             .await
             .unwrap();
 
-        assert_eq!(result_a.total, 1);
-        assert_eq!(result_b.total, 1);
-        assert_eq!(result_a.items[0].id.set_code.to_string(), "TS1");
-        assert_eq!(result_b.items[0].id.set_code.to_string(), "TS2");
+        assert_eq!(result_a.total, 1);
+        assert_eq!(result_b.total, 1);
+        assert_eq!(result_a.items[0].id.set_code.to_string(), "TS1");
+        assert_eq!(result_b.items[0].id.set_code.to_string(), "TS2");
+    }
+
+    #[sqlx::test]
+    async fn get_paginated_filters_by_binder(pool: PgPool) {
+        insert_set(&pool, "TST").await;
+        insert_card(&pool, "TST", "1", "EN", false, "Test Card", 1).await;
+        insert_card(&pool, "TST", "2", "EN", false, "Other Card", 2).await;
+        insert_collection_entry(&pool, "TST", "1", "EN", false, "userA", 1, 100, Utc::now()).await;
+        let deck_box = insert_binder(&pool, "userA", "Deck box", false).await;
+        insert_binder_entry(
+            &pool,
+            deck_box,
+            "TST",
+            "1",
+            "EN",
+            false,
+            "userA",
+            2,
+            100,
+            Utc::now(),
+        )
+        .await;
+        insert_binder_entry(
+            &pool,
+            deck_box,
+            "TST",
+            "2",
+            "EN",
+            false,
+            "userA",
+            1,
+            100,
+            Utc::now(),
+        )
+        .await;
+        refresh_view(&pool).await;
+
+        let adapter = CardPricesViewRepositoryAdapter::new(pool);
+        let everything = adapter
+            .get_paginated(&UserId::new("userA"), CollectionQuery::default())
+            .await
+            .unwrap();
+        let in_deck_box = adapter
+            .get_paginated(
+                &UserId::new("userA"),
+                CollectionQuery {
+                    binder_id: Some(BinderId(deck_box)),
+                    ..CollectionQuery::default()
+                },
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(everything.total, 3);
+        assert_eq!(in_deck_box.total, 2);
+        assert!(in_deck_box.items.iter().all(|card| matches!(
+            card.collection_entry,
+            CollectionEntry::Mine { binder_id: Some(id), .. } if id == BinderId(deck_box)
+        )));
+    }
+
+    /// Two entries of `userA`: Test Card tagged `cube` and `foil` in `Box 3`, Other Card tagged
+    /// `cube` with notes and no location.
+    async fn insert_annotated_entries(pool: &PgPool) {
+        insert_set(pool, "TST").await;
+        insert_card(pool, "TST", "1", "EN", false, "Test Card", 1).await;
+        insert_card(pool, "TST", "2", "EN", false, "Other Card", 2).await;
+        insert_user(pool, "userA", "Alice").await;
+        insert_collection_entry(pool, "TST", "1", "EN", false, "userA", 1, 100, Utc::now()).await;
+        insert_collection_entry(pool, "TST", "2", "EN", false, "userA", 1, 100, Utc::now()).await;
+        sqlx::query(
+            "UPDATE collection_entry SET tags = '{cube,foil}', location = 'Box 3, row 2'
+             WHERE collector_number = '1'",
+        )
+        .execute(pool)
+        .await
+        .unwrap();
+        sqlx::query(
+            "UPDATE collection_entry SET tags = '{cube}', notes = 'Signed by the artist'
+             WHERE collector_number = '2'",
+        )
+        .execute(pool)
+        .await
+        .unwrap();
+        refresh_view(pool).await;
+    }
+
+    #[sqlx::test]
+    async fn get_paginated_filters_by_every_tag_and_location(pool: PgPool) {
+        insert_annotated_entries(&pool).await;
+
+        let adapter = CardPricesViewRepositoryAdapter::new(pool);
+        let cube = adapter
+            .get_paginated(
+                &UserId::new("userA"),
+                CollectionQuery {
+                    tags: vec!["cube".to_string()],
+                    ..CollectionQuery::default()
+                },
+            )
+            .await
+            .unwrap();
+        let cube_and_foil = adapter
+            .get_paginated(
+                &UserId::new("userA"),
+                CollectionQuery {
+                    tags: vec!["cube".to_string(), "foil".to_string()],
+                    ..CollectionQuery::default()
+                },
+            )
+            .await
+            .unwrap();
+        let in_box_3 = adapter
+            .get_paginated(
+                &UserId::new("userA"),
+                CollectionQuery {
+                    location: Some("box 3".to_string()),
+                    ..CollectionQuery::default()
+                },
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(cube.total, 2);
+        assert_eq!(cube_and_foil.total, 1);
+        assert_eq!(cube_and_foil.items[0].name, "Test Card");
+        assert_eq!(in_box_3.total, 1);
+        assert!(matches!(
+            &in_box_3.items[0].collection_entry,
+            CollectionEntry::Mine { tags, location: Some(location), .. }
+                if tags == &["cube", "foil"] && location == "Box 3, row 2"
+        ));
+    }
+
+    #[sqlx::test]
+    async fn get_paginated_searches_the_notes_tags_and_location(pool: PgPool) {
+        insert_annotated_entries(&pool).await;
+
+        let adapter = CardPricesViewRepositoryAdapter::new(pool);
+        let search = |q: &str| CollectionQuery {
+            search_query: Some(q.to_string()),
+            ..CollectionQuery::default()
+        };
+        let by_notes = adapter
+            .get_paginated(&UserId::new("userA"), search("artist"))
+            .await
+            .unwrap();
+        let by_tag = adapter
+            .get_paginated(&UserId::new("userA"), search("foil"))
+            .await
+            .unwrap();
+        let by_location = adapter
+            .get_paginated(&UserId::new("userA"), search("row 2"))
+            .await
+            .unwrap();
+
+        assert_eq!(by_notes.total, 1);
+        assert_eq!(by_notes.items[0].name, "Other Card");
+        assert_eq!(by_tag.total, 1);
+        assert_eq!(by_tag.items[0].name, "Test Card");
+        assert_eq!(by_location.total, 1);
+        assert_eq!(by_location.items[0].name, "Test Card");
+    }
+
+    #[sqlx::test]
+    async fn search_paginated_does_not_search_the_notes_of_other_users(pool: PgPool) {
+        insert_annotated_entries(&pool).await;
+
+        let adapter = CardPricesViewRepositoryAdapter::new(pool);
+        let result = adapter
+            .search_paginated(
+                CollectionQuery {
+                    search_query: Some("artist".to_string()),
+                    ..CollectionQuery::default()
+                }
+                .into(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(result.total, 0);
     }
 
     #[sqlx::test]
@@ -787,6 +1419,67 @@ mod tests {
         assert_eq!(result.items.len(), 2);
     }
 
+    #[sqlx::test]
+    async fn search_paginated_leaves_out_the_owners_keeping_every_copy(pool: PgPool) {
+        insert_set(&pool, "TST").await;
+        insert_card(&pool, "TST", "1", "EN", false, "Test Card", 1).await;
+        insert_collection_entry(&pool, "TST", "1", "EN", false, "user1", 4, 100, Utc::now()).await;
+        insert_collection_entry(&pool, "TST", "1", "EN", false, "user2", 5, 100, Utc::now()).await;
+        insert_collection_entry(&pool, "TST", "1", "EN", false, "user3", 2, 100, Utc::now()).await;
+        set_keep_quantity(&pool, insert_default_binder(&pool, "user1").await, 4).await;
+        set_keep_quantity(&pool, insert_default_binder(&pool, "user2").await, 4).await;
+        set_tradable_quantity(&pool, insert_default_binder(&pool, "user3").await, 0).await;
+        refresh_view(&pool).await;
+
+        let adapter = CardPricesViewRepositoryAdapter::new(pool);
+        let result = adapter
+            .search_paginated(SearchQuery {
+                collection_query: CollectionQuery::default(),
+                player_username: None,
+            })
+            .await
+            .unwrap();
+
+        assert_eq!(result.total, 1);
+        assert!(matches!(
+            result.items[0].collection_entry,
+            CollectionEntry::Public { owner_count: 1 }
+        ));
+    }
+
+    #[sqlx::test]
+    async fn search_paginated_leaves_out_the_cards_of_untradable_binders(pool: PgPool) {
+        insert_set(&pool, "TST").await;
+        insert_card(&pool, "TST", "1", "EN", false, "Test Card", 1).await;
+        insert_card(&pool, "TST", "2", "EN", false, "Other Card", 2).await;
+        insert_user(&pool, "userA", "Alice").await;
+        insert_collection_entry(&pool, "TST", "1", "EN", false, "userA", 1, 100, Utc::now()).await;
+        let deck_box = insert_binder(&pool, "userA", "Deck box", false).await;
+        insert_binder_entry(
+            &pool,
+            deck_box,
+            "TST",
+            "2",
+            "EN",
+            false,
+            "userA",
+            1,
+            100,
+            Utc::now(),
+        )
+        .await;
+        refresh_view(&pool).await;
+
+        let adapter = CardPricesViewRepositoryAdapter::new(pool);
+        let result = adapter
+            .search_paginated(CollectionQuery::default().into())
+            .await
+            .unwrap();
+
+        assert_eq!(result.total, 1);
+        assert_eq!(result.items[0].name, "Test Card");
+    }
+
     #[sqlx::test]
     async fn search_paginated_returns_empty_when_no_cards(pool: PgPool) {
         let adapter = CardPricesViewRepositoryAdapter::new(pool);
@@ -1355,6 +2048,60 @@ mod tests {
         assert_eq!(result.items[0].name, "Expensive Card");
     }
 
+    async fn set_metadata(pool: &PgPool, collector_number: &str, type_line: &str, colors: &[&str]) {
+        sqlx::query("UPDATE card SET type_line = $1, colors = $2 WHERE collector_number = $3")
+            .bind(type_line)
+            .bind(colors.iter().map(|c| c.to_string()).collect::<Vec<_>>())
+            .bind(collector_number)
+            .execute(pool)
+            .await
+            .unwrap();
+    }
+
+    #[sqlx::test]
+    async fn get_paginated_filters_by_colors_and_type_line(pool: PgPool) {
+        insert_set(&pool, "TST").await;
+        insert_card(&pool, "TST", "1", "EN", false, "Azorius Wizard", 1).await;
+        insert_card(&pool, "TST", "2", "EN", false, "White Wizard", 2).await;
+        insert_card(&pool, "TST", "3", "EN", false, "Azorius Signet", 3).await;
+        for n in ["1", "2", "3"] {
+            insert_collection_entry(&pool, "TST", n, "EN", false, "user1", 1, 100, Utc::now())
+                .await;
+        }
+        set_metadata(&pool, "1", "Creature — Human Wizard", &["W", "U"]).await;
+        set_metadata(&pool, "2", "Creature — Human Wizard", &["W"]).await;
+        set_metadata(&pool, "3", "Artifact", &["W", "U"]).await;
+        refresh_view(&pool).await;
+
+        let adapter = CardPricesViewRepositoryAdapter::new(pool);
+        let query = CollectionQuery {
+            colors: vec![Color::W, Color::U],
+            type_line: Some("wizard".to_string()),
+            ..CollectionQuery::default()
+        };
+        let result = adapter
+            .get_paginated(&UserId::new("user1"), query.clone())
+            .await
+            .unwrap();
+
+        assert_eq!(result.total, 1);
+        assert_eq!(result.items[0].name, "Azorius Wizard");
+        assert_eq!(result.items[0].metadata.colors, vec![Color::W, Color::U]);
+
+        let searched = adapter
+            .search_paginated(SearchQuery {
+                collection_query: query,
+                player_username: None,
+            })
+            .await
+            .unwrap();
+        assert_eq!(searched.total, 1);
+        assert_eq!(
+            searched.items[0].metadata.type_line.as_deref(),
+            Some("Creature — Human Wizard")
+        );
+    }
+
     fn card_id(set_code: &str, collector_number: &str, language_code: &str, foil: bool) -> CardId {
         use crate::domain::language_code::LanguageCode;
         CardId::new(
@@ -1375,7 +2122,7 @@ mod tests {
 
         let adapter = CardPricesViewRepositoryAdapter::new(pool);
         let result = adapter
-            .exists(&card_id("TST", "1", "EN", false))
+            .exists(&CardSelector::Printing(card_id("TST", "1", "EN", false)))
             .await
             .unwrap();
 
@@ -1392,7 +2139,7 @@ mod tests {
 
         let adapter = CardPricesViewRepositoryAdapter::new(pool);
         let result = adapter
-            .exists(&card_id("TST", "1", "EN", false))
+            .exists(&CardSelector::Printing(card_id("TST", "1", "EN", false)))
             .await
             .unwrap();
 
@@ -1403,7 +2150,7 @@ mod tests {
     async fn exists_returns_false_when_no_one_owns_the_card(pool: PgPool) {
         let adapter = CardPricesViewRepositoryAdapter::new(pool);
         let result = adapter
-            .exists(&card_id("TST", "1", "EN", false))
+            .exists(&CardSelector::Printing(card_id("TST", "1", "EN", false)))
             .await
             .unwrap();
 
@@ -1427,7 +2174,7 @@ mod tests {
         let result = adapter
             .get_offers(
                 &UserId::new("userA"),
-                &card_id("TST", "1", "EN", false),
+                &CardSelector::Printing(card_id("TST", "1", "EN", false)),
                 CardOfferSortField::SellingPrice,
                 0,
                 20,
@@ -1456,6 +2203,101 @@ mod tests {
         }
     }
 
+    #[sqlx::test]
+    async fn get_offers_sums_the_copies_of_the_tradable_binders_of_each_owner(pool: PgPool) {
+        insert_set(&pool, "TST").await;
+        insert_card(&pool, "TST", "1", "EN", false, "Test Card", 1).await;
+        insert_user(&pool, "userA", "Alice").await;
+        insert_user(&pool, "userB", "Bob").await;
+        insert_collection_entry(&pool, "TST", "1", "EN", false, "userB", 2, 100, Utc::now()).await;
+        for (name, tradable, quantity) in [("Trades", true, 3), ("Deck box", false, 4)] {
+            let binder = insert_binder(&pool, "userB", name, tradable).await;
+            insert_binder_entry(
+                &pool,
+                binder,
+                "TST",
+                "1",
+                "EN",
+                false,
+                "userB",
+                quantity,
+                100,
+                Utc::now(),
+            )
+            .await;
+        }
+        refresh_view(&pool).await;
+
+        let adapter = CardPricesViewRepositoryAdapter::new(pool);
+        let result = adapter
+            .get_offers(
+                &UserId::new("userA"),
+                &CardSelector::Printing(card_id("TST", "1", "EN", false)),
+                CardOfferSortField::SellingPrice,
+                0,
+                20,
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(result.total, 1);
+        assert_eq!(result.items.len(), 1);
+        assert!(matches!(
+            result.items[0],
+            CollectionEntry::Owned { quantity: 5, .. }
+        ));
+    }
+
+    #[sqlx::test]
+    async fn get_offers_counts_only_the_copies_offered_for_trade(pool: PgPool) {
+        insert_set(&pool, "TST").await;
+        insert_card(&pool, "TST", "1", "EN", false, "Test Card", 1).await;
+        insert_user(&pool, "userA", "Alice").await;
+        insert_user(&pool, "userB", "Bob").await;
+        insert_user(&pool, "userC", "Carol").await;
+        // Bob : 1 exemplaire proposé sur 2, plus 3 au-delà des 2 gardés dans « Trades »
+        insert_collection_entry(&pool, "TST", "1", "EN", false, "userB", 2, 100, Utc::now()).await;
+        set_tradable_quantity(&pool, insert_default_binder(&pool, "userB").await, 1).await;
+        let trades = insert_binder(&pool, "userB", "Trades", true).await;
+        set_keep_quantity(&pool, trades, 2).await;
+        insert_binder_entry(
+            &pool,
+            trades,
+            "TST",
+            "1",
+            "EN",
+            false,
+            "userB",
+            5,
+            100,
+            Utc::now(),
+        )
+        .await;
+        // Carol garde ses 3 exemplaires
+        insert_collection_entry(&pool, "TST", "1", "EN", false, "userC", 3, 100, Utc::now()).await;
+        set_keep_quantity(&pool, insert_default_binder(&pool, "userC").await, 4).await;
+        refresh_view(&pool).await;
+
+        let adapter = CardPricesViewRepositoryAdapter::new(pool);
+        let result = adapter
+            .get_offers(
+                &UserId::new("userA"),
+                &CardSelector::Printing(card_id("TST", "1", "EN", false)),
+                CardOfferSortField::SellingPrice,
+                0,
+                20,
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(result.total, 1);
+        assert_eq!(result.items.len(), 1);
+        assert!(matches!(
+            &result.items[0],
+            CollectionEntry::Owned { quantity: 4, owner_username, .. } if owner_username == "Bob"
+        ));
+    }
+
     #[sqlx::test]
     async fn get_offers_breaks_ties_by_owner_username_ascending(pool: PgPool) {
         // All offers of a given card share the same selling_price today (derived from the
@@ -1475,7 +2317,7 @@ mod tests {
         let result = adapter
             .get_offers(
                 &UserId::new("userA"),
-                &card_id("TST", "1", "EN", false),
+                &CardSelector::Printing(card_id("TST", "1", "EN", false)),
                 CardOfferSortField::SellingPrice,
                 0,
                 20,
@@ -1509,7 +2351,7 @@ mod tests {
         let result = adapter
             .get_offers(
                 &UserId::new("userA"),
-                &card_id("TST", "1", "EN", false),
+                &CardSelector::Printing(card_id("TST", "1", "EN", false)),
                 CardOfferSortField::SellingPrice,
                 0,
                 20,
@@ -1536,7 +2378,7 @@ mod tests {
         let result = adapter
             .get_offers(
                 &UserId::new("userA"),
-                &card_id("TST", "1", "EN", false),
+                &CardSelector::Printing(card_id("TST", "1", "EN", false)),
                 CardOfferSortField::SellingPrice,
                 0,
                 2,
@@ -1549,4 +2391,241 @@ mod tests {
         assert_eq!(result.page, 0);
         assert_eq!(result.page_size, 2);
     }
+
+    const BOLT_ORACLE_ID: Uuid = Uuid::from_u128(0x4457ed35_7c10_48c8_9776_456485fdf070);
+    const SHOCK_ORACLE_ID: Uuid = Uuid::from_u128(0x9d1ffeb1_6c31_45f7_8140_913c397022a3);
+
+    /// Two printings of Lightning Bolt (one French and foil) and one Shock.
+    async fn insert_bolt_printings(pool: &PgPool) {
+        insert_set(pool, "TST").await;
+        insert_set(pool, "OTH").await;
+        insert_card(pool, "TST", "1", "EN", false, "Lightning Bolt", 1).await;
+        insert_card(pool, "OTH", "7", "FR", true, "Foudre", 2).await;
+        insert_card(pool, "TST", "2", "EN", false, "Shock", 3).await;
+        set_oracle_id(pool, "TST", "1", BOLT_ORACLE_ID).await;
+        set_oracle_id(pool, "OTH", "7", BOLT_ORACLE_ID).await;
+        set_oracle_id(pool, "TST", "2", SHOCK_ORACLE_ID).await;
+        insert_price(pool, CardMarketPriceEntity::simple(1, 300)).await;
+        insert_price(pool, CardMarketPriceEntity::with_foil(2, 500, 200)).await;
+    }
+
+    #[sqlx::test]
+    async fn exists_returns_true_when_any_printing_of_the_oracle_card_is_owned(pool: PgPool) {
+        insert_bolt_printings(&pool).await;
+        insert_collection_entry(&pool, "OTH", "7", "FR", true, "user1", 1, 100, Utc::now()).await;
+        refresh_view(&pool).await;
+
+        let adapter = CardPricesViewRepositoryAdapter::new(pool);
+
+        assert!(
+            adapter
+                .exists(&CardSelector::AnyPrinting {
+                    oracle_id: BOLT_ORACLE_ID
+                })
+                .await
+                .unwrap()
+        );
+        assert!(
+            !adapter
+                .exists(&CardSelector::AnyPrinting {
+                    oracle_id: SHOCK_ORACLE_ID
+                })
+                .await
+                .unwrap()
+        );
+    }
+
+    #[sqlx::test]
+    async fn get_offers_for_any_printing_returns_offers_of_every_printing(pool: PgPool) {
+        insert_bolt_printings(&pool).await;
+        insert_user(&pool, "userA", "Alice").await;
+        insert_user(&pool, "userB", "Bob").await;
+        insert_user(&pool, "userC", "Carol").await;
+        insert_collection_entry(&pool, "TST", "1", "EN", false, "userA", 1, 100, Utc::now()).await;
+        insert_collection_entry(&pool, "TST", "1", "EN", false, "userB", 2, 100, Utc::now()).await;
+        insert_collection_entry(&pool, "OTH", "7", "FR", true, "userC", 1, 100, Utc::now()).await;
+        insert_collection_entry(&pool, "TST", "2", "EN", false, "userC", 4, 100, Utc::now()).await;
+        refresh_view(&pool).await;
+
+        let adapter = CardPricesViewRepositoryAdapter::new(pool);
+        let result = adapter
+            .get_offers(
+                &UserId::new("userA"),
+                &CardSelector::AnyPrinting {
+                    oracle_id: BOLT_ORACLE_ID,
+                },
+                CardOfferSortField::SellingPrice,
+                0,
+                20,
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(result.total, 2);
+        assert_eq!(
+            result.items,
+            vec![
+                CollectionEntry::Owned {
+                    card_id: card_id("OTH", "7", "FR", true),
+                    owner_username: "Carol".to_string(),
+                    quantity: 1,
+                    selling_price: Some(200),
+                },
+                CollectionEntry::Owned {
+                    card_id: card_id("TST", "1", "EN", false),
+                    owner_username: "Bob".to_string(),
+                    quantity: 2,
+                    selling_price: Some(300),
+                },
+            ]
+        );
+    }
+
+    #[sqlx::test]
+    async fn search_paginated_filters_by_oracle_id(pool: PgPool) {
+        insert_bolt_printings(&pool).await;
+        for (set_code, number, language, foil) in [
+            ("TST", "1", "EN", false),
+            ("OTH", "7", "FR", true),
+            ("TST", "2", "EN", false),
+        ] {
+            insert_collection_entry(
+                &pool,
+                set_code,
+                number,
+                language,
+                foil,
+                "user1",
+                1,
+                100,
+                Utc::now(),
+            )
+            .await;
+        }
+        refresh_view(&pool).await;
+
+        let adapter = CardPricesViewRepositoryAdapter::new(pool);
+        let query = CollectionQuery {
+            oracle_id: Some(BOLT_ORACLE_ID),
+            ..CollectionQuery::default()
+        };
+        let result = adapter.search_paginated(query.into()).await.unwrap();
+
+        assert_eq!(result.total, 2);
+        assert!(
+            result
+                .items
+                .iter()
+                .all(|card| card.metadata.oracle_id == Some(BOLT_ORACLE_ID))
+        );
+    }
+
+    #[sqlx::test]
+    async fn search_oracle_paginated_groups_printings_by_oracle_id(pool: PgPool) {
+        insert_bolt_printings(&pool).await;
+        insert_card(&pool, "TST", "3", "EN", false, "Not Enriched", 4).await;
+        insert_collection_entry(&pool, "TST", "1", "EN", false, "user1", 1, 100, Utc::now()).await;
+        insert_collection_entry(&pool, "TST", "1", "EN", false, "user2", 1, 100, Utc::now()).await;
+        insert_collection_entry(&pool, "OTH", "7", "FR", true, "user2", 1, 100, Utc::now()).await;
+        insert_collection_entry(&pool, "TST", "2", "EN", false, "user1", 1, 100, Utc::now()).await;
+        insert_collection_entry(&pool, "TST", "3", "EN", false, "user1", 1, 100, Utc::now()).await;
+        refresh_view(&pool).await;
+
+        let adapter = CardPricesViewRepositoryAdapter::new(pool);
+        let result = adapter
+            .search_oracle_paginated(CollectionQuery::default().into())
+            .await
+            .unwrap();
+
+        assert_eq!(result.total, 2);
+        assert_eq!(result.items.len(), 2);
+        let bolt = &result.items[0];
+        assert_eq!(bolt.oracle_id, BOLT_ORACLE_ID);
+        assert_eq!(bolt.name, "Lightning Bolt");
+        assert_eq!(bolt.printing_count, 2);
+        assert_eq!(bolt.owner_count, 2);
+        assert_eq!(bolt.lowest_trend.value, Some(200));
+        assert_eq!(result.items[1].oracle_id, SHOCK_ORACLE_ID);
+        assert_eq!(result.items[1].lowest_trend.value, None);
+    }
+
+    #[sqlx::test]
+    async fn search_oracle_paginated_applies_the_search_filters(pool: PgPool) {
+        insert_bolt_printings(&pool).await;
+        insert_collection_entry(&pool, "OTH", "7", "FR", true, "user1", 1, 100, Utc::now()).await;
+        insert_collection_entry(&pool, "TST", "2", "EN", false, "user1", 1, 100, Utc::now()).await;
+        refresh_view(&pool).await;
+
+        let adapter = CardPricesViewRepositoryAdapter::new(pool);
+        let query = CollectionQuery {
+            search_query: Some("foudre".to_string()),
+            ..CollectionQuery::default()
+        };
+        let result = adapter.search_oracle_paginated(query.into()).await.unwrap();
+
+        assert_eq!(result.total, 1);
+        assert_eq!(result.items[0].oracle_id, BOLT_ORACLE_ID);
+        assert_eq!(result.items[0].name, "Foudre");
+        assert_eq!(result.items[0].printing_count, 1);
+    }
+
+    #[sqlx::test]
+    async fn get_owned_printings_by_names_matches_names_case_insensitively(pool: PgPool) {
+        insert_set(&pool, "TST").await;
+        insert_card(&pool, "TST", "1", "EN", false, "Sol Ring", 1).await;
+        insert_card(&pool, "TST", "2", "EN", false, "Sol Ring", 2).await;
+        insert_card(&pool, "TST", "3", "EN", false, "Doubling Season", 3).await;
+        insert_user(&pool, "userA", "Alice").await;
+        insert_user(&pool, "userB", "Bob").await;
+        insert_collection_entry(&pool, "TST", "1", "EN", false, "userA", 2, 100, Utc::now()).await;
+        insert_collection_entry(&pool, "TST", "2", "EN", false, "userA", 1, 100, Utc::now()).await;
+        insert_collection_entry(&pool, "TST", "3", "EN", false, "userB", 1, 100, Utc::now()).await;
+        insert_price(&pool, CardMarketPriceEntity::simple(1, 150)).await;
+        refresh_view(&pool).await;
+
+        let adapter = CardPricesViewRepositoryAdapter::new(pool);
+        let result = adapter
+            .get_owned_printings_by_names(
+                &UserId::new("userA"),
+                vec!["sol ring".to_string(), "doubling season".to_string()],
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(result.len(), 2);
+        assert_eq!(result[0].card_id, card_id("TST", "1", "EN", false));
+        assert_eq!(result[0].name, "Sol Ring");
+        assert_eq!(result[0].quantity, 2);
+        assert_eq!(result[0].trend.value, Some(150));
+        assert_eq!(result[1].card_id, card_id("TST", "2", "EN", false));
+        assert_eq!(result[1].trend.value, None);
+    }
+
+    #[sqlx::test]
+    async fn count_other_owners_by_names_counts_distinct_other_users(pool: PgPool) {
+        insert_set(&pool, "TST").await;
+        insert_card(&pool, "TST", "1", "EN", false, "Sol Ring", 1).await;
+        insert_card(&pool, "TST", "2", "EN", false, "Sol Ring", 2).await;
+        insert_card(&pool, "TST", "3", "EN", false, "Doubling Season", 3).await;
+        insert_user(&pool, "userA", "Alice").await;
+        insert_user(&pool, "userB", "Bob").await;
+        insert_user(&pool, "userC", "Carol").await;
+        insert_collection_entry(&pool, "TST", "1", "EN", false, "userA", 1, 100, Utc::now()).await;
+        insert_collection_entry(&pool, "TST", "1", "EN", false, "userB", 1, 100, Utc::now()).await;
+        insert_collection_entry(&pool, "TST", "2", "EN", false, "userB", 1, 100, Utc::now()).await;
+        insert_collection_entry(&pool, "TST", "2", "EN", false, "userC", 1, 100, Utc::now()).await;
+        insert_collection_entry(&pool, "TST", "3", "EN", false, "userA", 1, 100, Utc::now()).await;
+        refresh_view(&pool).await;
+
+        let adapter = CardPricesViewRepositoryAdapter::new(pool);
+        let result = adapter
+            .count_other_owners_by_names(
+                &UserId::new("userA"),
+                vec!["sol ring".to_string(), "doubling season".to_string()],
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(result, HashMap::from([("sol ring".to_string(), 2)]));
+    }
 }
@@ -1,9 +1,12 @@
 use crate::application::error::AppError;
 use crate::application::repository::CardRepository;
-use crate::domain::card::{Card, CardId, CollectionEntry};
-use crate::domain::user::User;
+use crate::domain::binder::BinderId;
+use crate::domain::card::{Card, CardId, CollectionEntry, GathererIds};
+use crate::domain::card_metadata::CardMetadata;
+use crate::domain::collection_item::{CollectionItem, CollectionItemUpdate};
+use crate::domain::user::{User, UserId};
 use crate::infrastructure::adapter_out::repository::entities::{
-    CardEntity, CardIdEntity, CardNameEntity,
+    CardEntity, CardIdEntity, CardNameEntity, CollectionItemEntity, to_db_colors,
 };
 use async_trait::async_trait;
 use sqlx::{Pool, Postgres};
@@ -28,7 +31,8 @@ impl CardRepository for CardRepositoryAdapter {
                 set_name.name as set_name,
                 collection_entry.quantity,
                 collection_entry.purchase_price,
-                collection_entry.added_at
+                collection_entry.added_at,
+                collection_entry.binder_id
             FROM card
             JOIN set_name ON card.set_code = set_name.set_code
             JOIN collection_entry ON
@@ -58,7 +62,16 @@ impl CardRepository for CardRepositoryAdapter {
                 card.scryfall_id
             FROM card
             JOIN set_name ON card.set_code = set_name.set_code
-            WHERE card.cardmarket_id IS NULL"
+            WHERE card.cardmarket_id IS NULL
+              AND NOT EXISTS (SELECT 1
+                              FROM enrichment_attempt ea
+                              WHERE ea.kind = 'CARDMARKET_ID'
+                                AND ea.set_code = card.set_code
+                                AND ea.collector_number = card.collector_number
+                                AND ea.language_code = card.language_code
+                                AND ea.foil = card.foil
+                                AND ea.outcome = 'NOT_FOUND'
+                                AND (ea.next_attempt_at IS NULL OR ea.next_attempt_at > NOW()))"
         )
         .fetch_all(&self.pool)
         .await?
@@ -77,7 +90,16 @@ impl CardRepository for CardRepositoryAdapter {
                 card.foil,
                 card.name
             FROM card
-            WHERE card.the_gatherer_id IS NULL"
+            WHERE card.the_gatherer_id IS NULL
+              AND NOT EXISTS (SELECT 1
+                              FROM enrichment_attempt ea
+                              WHERE ea.kind = 'GATHERER_ID'
+                                AND ea.set_code = card.set_code
+                                AND ea.collector_number = card.collector_number
+                                AND ea.language_code = card.language_code
+                                AND ea.foil = card.foil
+                                AND ea.outcome = 'NOT_FOUND'
+                                AND (ea.next_attempt_at IS NULL OR ea.next_attempt_at > NOW()))"
         )
         .fetch_all(&self.pool)
         .await?
@@ -100,7 +122,19 @@ impl CardRepository for CardRepositoryAdapter {
         Ok(record.map(|r| (r.cardmarket_id.map(|id| id as u32), r.foil)))
     }
 
-    async fn save(&self, user: User, card: Card) -> Result<(), AppError> {
+    async fn find_name_by_scryfall_id(
+        &self,
+        scryfall_id: uuid::Uuid,
+    ) -> Result<Option<String>, AppError> {
+        Ok(sqlx::query_scalar!(
+            "SELECT name FROM card WHERE scryfall_id = $1 LIMIT 1",
+            scryfall_id
+        )
+        .fetch_optional(&self.pool)
+        .await?)
+    }
+
+    async fn save(&self, user: User, binder_id: BinderId, card: Card) -> Result<(), AppError> {
         let CollectionEntry::Mine {
             quantity,
             purchase_price,
@@ -131,13 +165,14 @@ impl CardRepository for CardRepositoryAdapter {
         .await?;
 
         sqlx::query!(
-        r#"INSERT INTO collection_entry (set_code, collector_number, language_code, foil, user_id, quantity, purchase_price, added_at)
-            VALUES ($1, $2, $3, $4, $5, $6, $7, $8)
-            ON CONFLICT(set_code, collector_number, language_code, foil, user_id)
+        r#"INSERT INTO collection_entry (binder_id, set_code, collector_number, language_code, foil, user_id, quantity, purchase_price, added_at)
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9)
+            ON CONFLICT(binder_id, set_code, collector_number, language_code, foil)
                 DO UPDATE
-                SET quantity       = $6,
-                    purchase_price = $7,
-                    added_at       = $8"#,
+                SET quantity       = $7,
+                    purchase_price = $8,
+                    added_at       = $9"#,
+            binder_id.0,
             card.id.set_code.to_string(),
             card.id.collector_number,
             card.id.language_code.to_string(),
@@ -176,13 +211,18 @@ impl CardRepository for CardRepositoryAdapter {
     async fn update_gatherer_id(
         &self,
         id: CardId,
-        gatherer_id: Option<String>,
+        gatherer_ids: Option<GathererIds>,
     ) -> Result<(), AppError> {
+        let (front, back) = match gatherer_ids {
+            Some(ids) => (Some(ids.front), ids.back),
+            None => (None, None),
+        };
         sqlx::query!(
             r#"UPDATE card
-                SET the_gatherer_id = $1
-                WHERE set_code = $2 AND collector_number = $3 AND language_code = $4 AND foil = $5;"#,
-            gatherer_id,
+                SET the_gatherer_id = $1, the_gatherer_back_id = $2
+                WHERE set_code = $3 AND collector_number = $4 AND language_code = $5 AND foil = $6;"#,
+            front,
+            back,
             id.set_code.to_string(),
             id.collector_number,
             id.language_code.to_string(),
@@ -193,26 +233,250 @@ impl CardRepository for CardRepositoryAdapter {
         Ok(())
     }
 
-    async fn delete_all(&self, user: User) -> Result<(), AppError> {
+    async fn update_metadata(&self, id: CardId, metadata: CardMetadata) -> Result<(), AppError> {
         sqlx::query!(
-            "DELETE FROM collection_entry WHERE user_id = $1",
-            user.id.as_str()
+            r#"UPDATE card
+                SET oracle_id        = $1,
+                    mana_cost        = $2,
+                    type_line        = $3,
+                    colors           = $4,
+                    color_identity   = $5,
+                    oracle_text      = $6,
+                    image_small_url  = $7,
+                    image_normal_url = $8,
+                    image_large_url  = $9
+                WHERE set_code = $10 AND collector_number = $11 AND language_code = $12 AND foil = $13;"#,
+            metadata.oracle_id,
+            metadata.mana_cost,
+            metadata.type_line,
+            &to_db_colors(&metadata.colors),
+            &to_db_colors(&metadata.color_identity),
+            metadata.oracle_text,
+            metadata.image_small_url,
+            metadata.image_normal_url,
+            metadata.image_large_url,
+            id.set_code.to_string(),
+            id.collector_number,
+            id.language_code.to_string(),
+            id.foil)
+            .execute(&self.pool)
+            .await?;
+
+        Ok(())
+    }
+
+    async fn delete_missing(
+        &self,
+        user: User,
+        binder_id: BinderId,
+        kept: Vec<CardId>,
+    ) -> Result<(), AppError> {
+        let mut set_codes = Vec::with_capacity(kept.len());
+        let mut collector_numbers = Vec::with_capacity(kept.len());
+        let mut language_codes = Vec::with_capacity(kept.len());
+        let mut foils = Vec::with_capacity(kept.len());
+        for id in kept {
+            set_codes.push(id.set_code.to_string());
+            collector_numbers.push(id.collector_number);
+            language_codes.push(id.language_code.to_string());
+            foils.push(id.foil);
+        }
+        sqlx::query!(
+            r#"DELETE FROM collection_entry
+               WHERE user_id = $1 AND binder_id = $2
+                 AND (set_code, collector_number, language_code, foil) NOT IN (
+                   SELECT * FROM UNNEST($3::varchar[], $4::varchar[], $5::varchar[], $6::bool[]))"#,
+            user.id.as_str(),
+            binder_id.0,
+            &set_codes,
+            &collector_numbers,
+            &language_codes,
+            &foils
         )
         .execute(&self.pool)
         .await?;
 
         Ok(())
     }
+
+    async fn ensure_printing(&self, id: CardId) -> Result<bool, AppError> {
+        let exists = sqlx::query_scalar!(
+            r#"SELECT EXISTS (SELECT 1 FROM card
+                              WHERE set_code = $1 AND collector_number = $2
+                                AND language_code = $3 AND foil = $4) AS "exists!""#,
+            id.set_code.to_string(),
+            id.collector_number,
+            id.language_code.to_string(),
+            id.foil
+        )
+        .fetch_one(&self.pool)
+        .await?;
+        if exists {
+            return Ok(true);
+        }
+
+        // Les finitions d'une même impression partagent nom, identifiants et métadonnées
+        let created = sqlx::query!(
+            r#"INSERT INTO card (set_code, collector_number, language_code, foil, name, rarity, scryfall_id,
+                                 cardmarket_id, the_gatherer_id, the_gatherer_back_id, oracle_id, mana_cost,
+                                 type_line, colors, color_identity, oracle_text, image_small_url,
+                                 image_normal_url, image_large_url)
+               SELECT set_code, collector_number, language_code, $4, name, rarity, scryfall_id,
+                      cardmarket_id, the_gatherer_id, the_gatherer_back_id, oracle_id, mana_cost,
+                      type_line, colors, color_identity, oracle_text, image_small_url,
+                      image_normal_url, image_large_url
+               FROM card
+               WHERE set_code = $1 AND collector_number = $2 AND language_code = $3 AND foil <> $4
+               ON CONFLICT (set_code, collector_number, language_code, foil) DO NOTHING"#,
+            id.set_code.to_string(),
+            id.collector_number,
+            id.language_code.to_string(),
+            id.foil
+        )
+        .execute(&self.pool)
+        .await?;
+        if created.rows_affected() > 0 {
+            return Ok(true);
+        }
+
+        // Impression encore jamais importée : le catalogue Scryfall les liste toutes
+        let created = sqlx::query!(
+            r#"INSERT INTO card (set_code, collector_number, language_code, foil, name, rarity, scryfall_id,
+                                 cardmarket_id, oracle_id, mana_cost, type_line, colors, color_identity,
+                                 oracle_text, image_small_url, image_normal_url, image_large_url)
+               SELECT sn.set_code, $2::varchar, $3::varchar, $4, sc.name, sc.rarity, sc.scryfall_id,
+                      sc.cardmarket_id, sc.oracle_id, sc.mana_cost, sc.type_line, sc.colors, sc.color_identity,
+                      sc.oracle_text, sc.image_small_url, sc.image_normal_url, sc.image_large_url
+               FROM scryfall_card sc
+               JOIN set_name sn ON sn.set_code = UPPER(sc.set_code)
+               WHERE UPPER(sc.set_code) = $1 AND sc.collector_number = $2
+                 AND UPPER(CASE sc.lang WHEN 'es' THEN 'sp' ELSE sc.lang END) = $3
+                 AND sc.rarity IS NOT NULL
+               LIMIT 1
+               ON CONFLICT (set_code, collector_number, language_code, foil) DO NOTHING"#,
+            id.set_code.to_string(),
+            id.collector_number,
+            id.language_code.to_string(),
+            id.foil
+        )
+        .execute(&self.pool)
+        .await?;
+
+        Ok(created.rows_affected() > 0)
+    }
+
+    async fn add_entry(
+        &self,
+        user_id: &UserId,
+        binder_id: BinderId,
+        item: CollectionItem,
+    ) -> Result<bool, AppError> {
+        let inserted = sqlx::query!(
+            r#"INSERT INTO collection_entry (binder_id, set_code, collector_number, language_code, foil,
+                                             user_id, quantity, purchase_price, added_at, condition, notes,
+                                             tradable_quantity, tags, location)
+               VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13, $14)
+               ON CONFLICT (binder_id, set_code, collector_number, language_code, foil) DO NOTHING"#,
+            binder_id.0,
+            item.card_id.set_code.to_string(),
+            item.card_id.collector_number,
+            item.card_id.language_code.to_string(),
+            item.card_id.foil,
+            user_id.as_str(),
+            item.quantity as i32,
+            item.purchase_price as i32,
+            item.added_at,
+            item.condition.as_db_str(),
+            item.notes,
+            item.tradable_quantity.map(|q| q as i32),
+            &item.tags,
+            item.location,
+        )
+        .execute(&self.pool)
+        .await?;
+
+        Ok(inserted.rows_affected() > 0)
+    }
+
+    async fn update_entry(
+        &self,
+        user_id: &UserId,
+        binder_id: BinderId,
+        id: CardId,
+        update: CollectionItemUpdate,
+    ) -> Result<Option<CollectionItem>, AppError> {
+        let entity = sqlx::query_as!(
+            CollectionItemEntity,
+            r#"UPDATE collection_entry
+               SET quantity          = COALESCE($1, quantity),
+                   purchase_price    = COALESCE($2, purchase_price),
+                   condition         = COALESCE($3, condition),
+                   notes             = CASE WHEN $4 THEN $5 ELSE notes END,
+                   tradable_quantity = CASE WHEN $6 THEN $7 ELSE tradable_quantity END,
+                   tags              = COALESCE($8, tags),
+                   location          = CASE WHEN $9 THEN $10 ELSE location END
+               WHERE set_code = $11 AND collector_number = $12 AND language_code = $13 AND foil = $14
+                 AND user_id = $15 AND binder_id = $16
+               RETURNING set_code, collector_number, language_code, foil, quantity, purchase_price,
+                         condition, notes, tags, location, tradable_quantity, added_at"#,
+            update.quantity.map(|q| q as i32),
+            update.purchase_price.map(|p| p as i32),
+            update.condition.map(|c| c.as_db_str()),
+            update.notes.is_some(),
+            update.notes.flatten(),
+            update.tradable_quantity.is_some(),
+            update.tradable_quantity.flatten().map(|q| q as i32),
+            update.tags.as_deref(),
+            update.location.is_some(),
+            update.location.flatten(),
+            id.set_code.to_string(),
+            id.collector_number,
+            id.language_code.to_string(),
+            id.foil,
+            user_id.as_str(),
+            binder_id.0,
+        )
+        .fetch_optional(&self.pool)
+        .await?;
+
+        Ok(entity.map(CollectionItem::from))
+    }
+
+    async fn delete_entry(
+        &self,
+        user_id: &UserId,
+        binder_id: BinderId,
+        id: CardId,
+    ) -> Result<bool, AppError> {
+        let deleted = sqlx::query!(
+            r#"DELETE FROM collection_entry
+               WHERE set_code = $1 AND collector_number = $2 AND language_code = $3 AND foil = $4
+                 AND user_id = $5 AND binder_id = $6"#,
+            id.set_code.to_string(),
+            id.collector_number,
+            id.language_code.to_string(),
+            id.foil,
+            user_id.as_str(),
+            binder_id.0,
+        )
+        .execute(&self.pool)
+        .await?;
+
+        Ok(deleted.rows_affected() > 0)
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::domain::card_metadata::Color;
+    use crate::domain::collection_item::CardCondition;
     use crate::domain::language_code::LanguageCode;
     use crate::domain::rarity_code::RarityCode;
     use crate::infrastructure::adapter_out::repository::common_repository_tests::{
-        insert_card, insert_card_with_scryfall_id, insert_card_without_cardmarket_id,
-        insert_collection_entry,
+        insert_binder, insert_card, insert_card_with_scryfall_id,
+        insert_card_without_cardmarket_id, insert_collection_entry, insert_default_binder,
+        insert_set,
     };
     use chrono::Utc;
     use sqlx::PgPool;
@@ -269,6 +533,7 @@ mod tests {
 
     #[sqlx::test]
     async fn save_card_updates_existing_card(pool: PgPool) {
+        let binder_id = BinderId(insert_default_binder(&pool, "test-user-id").await);
         let repository = CardRepositoryAdapter::new(pool);
 
         let card = Card::new(
@@ -283,7 +548,7 @@ mod tests {
             500,
         );
         repository
-            .save(User::for_testing(), card.clone())
+            .save(User::for_testing(), binder_id, card.clone())
             .await
             .unwrap();
 
@@ -299,7 +564,7 @@ mod tests {
             1500,
         );
         repository
-            .save(User::for_testing(), updated_card.clone())
+            .save(User::for_testing(), binder_id, updated_card.clone())
             .await
             .unwrap();
 
@@ -326,7 +591,7 @@ mod tests {
     }
 
     #[sqlx::test]
-    async fn delete_all_removes_all_cards(pool: PgPool) {
+    async fn delete_missing_without_kept_cards_removes_all_cards(pool: PgPool) {
         insert_card_without_cardmarket_id(&pool, "FDN", "87", "FR", false, "Goblin Boarders").await;
         insert_card_without_cardmarket_id(&pool, "FDN", "12", "EN", true, "Goblin Boarders").await;
         insert_collection_entry(
@@ -354,8 +619,12 @@ mod tests {
         )
         .await;
 
+        let binder_id = BinderId(insert_default_binder(&pool, "test-user-id").await);
         let repository = CardRepositoryAdapter::new(pool);
-        repository.delete_all(User::for_testing()).await.unwrap();
+        repository
+            .delete_missing(User::for_testing(), binder_id, vec![])
+            .await
+            .unwrap();
 
         let cards = repository.get_all(User::for_testing()).await.unwrap();
         assert!(
@@ -364,6 +633,33 @@ mod tests {
         );
     }
 
+    #[sqlx::test]
+    async fn delete_missing_keeps_the_other_binders(pool: PgPool) {
+        insert_card_without_cardmarket_id(&pool, "FDN", "87", "FR", false, "Goblin Boarders").await;
+        insert_collection_entry(
+            &pool,
+            "FDN",
+            "87",
+            "FR",
+            false,
+            "test-user-id",
+            3,
+            500,
+            Utc::now(),
+        )
+        .await;
+        let other = BinderId(insert_binder(&pool, "test-user-id", "Trades", true).await);
+
+        let repository = CardRepositoryAdapter::new(pool);
+        repository
+            .delete_missing(User::for_testing(), other, vec![])
+            .await
+            .unwrap();
+
+        let cards = repository.get_all(User::for_testing()).await.unwrap();
+        assert_eq!(cards.len(), 1);
+    }
+
     #[sqlx::test]
     async fn get_all_returns_multiple_cards(pool: PgPool) {
         insert_card_without_cardmarket_id(&pool, "FDN", "87", "FR", false, "Goblin Boarders").await;
@@ -454,7 +750,10 @@ mod tests {
         repository
             .update_gatherer_id(
                 CardId::new("FDN", "12", LanguageCode::EN, true),
-                Some("ABC123".to_string()),
+                Some(GathererIds {
+                    front: "ABC123".to_string(),
+                    back: None,
+                }),
             )
             .await
             .unwrap();
@@ -469,6 +768,101 @@ mod tests {
         assert_eq!(cards[0].1, "Goblin Boarders");
     }
 
+    #[sqlx::test]
+    async fn get_all_without_ids_skip_cards_not_found_until_their_next_attempt(pool: PgPool) {
+        insert_card_without_cardmarket_id(&pool, "FDN", "1", "EN", false, "Not due").await;
+        insert_card_without_cardmarket_id(&pool, "FDN", "2", "EN", false, "Due").await;
+        insert_card_without_cardmarket_id(&pool, "FDN", "3", "EN", false, "Given up").await;
+        insert_card_without_cardmarket_id(&pool, "FDN", "4", "EN", false, "Failed").await;
+        sqlx::query(
+            "INSERT INTO enrichment_attempt
+                 (kind, set_code, collector_number, language_code, foil, attempts, last_attempt_at,
+                  outcome, reason, next_attempt_at)
+             VALUES ('CARDMARKET_ID', 'FDN', '1', 'EN', false, 1, NOW(), 'NOT_FOUND', 'none', NOW() + INTERVAL '1 day'),
+                    ('CARDMARKET_ID', 'FDN', '2', 'EN', false, 1, NOW(), 'NOT_FOUND', 'none', NOW() - INTERVAL '1 hour'),
+                    ('CARDMARKET_ID', 'FDN', '3', 'EN', false, 8, NOW(), 'NOT_FOUND', 'none', NULL),
+                    ('CARDMARKET_ID', 'FDN', '4', 'EN', false, 1, NOW(), 'FAILED', 'timeout', NULL),
+                    ('GATHERER_ID', 'FDN', '2', 'EN', false, 1, NOW(), 'NOT_FOUND', 'none', NULL)",
+        )
+        .execute(&pool)
+        .await
+        .unwrap();
+        let repository = CardRepositoryAdapter::new(pool);
+
+        let mut without_cardmarket_id: Vec<String> = repository
+            .get_all_without_cardmarket_id()
+            .await
+            .unwrap()
+            .into_iter()
+            .map(|(id, _)| id.collector_number)
+            .collect();
+        without_cardmarket_id.sort();
+        let mut without_gatherer_id: Vec<String> = repository
+            .get_all_without_gatherer_id()
+            .await
+            .unwrap()
+            .into_iter()
+            .map(|(id, _)| id.collector_number)
+            .collect();
+        without_gatherer_id.sort();
+
+        assert_eq!(without_cardmarket_id, vec!["2", "4"]);
+        assert_eq!(without_gatherer_id, vec!["1", "3", "4"]);
+    }
+
+    #[sqlx::test]
+    async fn gatherer_back_id_migration_requeues_the_given_up_split_cards(pool: PgPool) {
+        insert_set(&pool, "DMR").await;
+        insert_card_without_cardmarket_id(&pool, "DMR", "213", "EN", false, "Fire // Ice").await;
+        insert_card_without_cardmarket_id(&pool, "FDN", "1", "EN", false, "Given up").await;
+        sqlx::query(
+            "INSERT INTO enrichment_attempt
+                 (kind, set_code, collector_number, language_code, foil, attempts, last_attempt_at,
+                  outcome, reason, next_attempt_at)
+             VALUES ('GATHERER_ID', 'DMR', '213', 'EN', false, 8, NOW(), 'NOT_FOUND', 'none', NULL),
+                    ('GATHERER_ID', 'FDN', '1', 'EN', false, 8, NOW(), 'NOT_FOUND', 'none', NULL)",
+        )
+        .execute(&pool)
+        .await
+        .unwrap();
+        sqlx::query(
+            "INSERT INTO enrichment_job (kind, set_code, collector_number, language_code, foil, status)
+             VALUES ('GATHERER_ID', 'DMR', '213', 'EN', false, 'DEAD')",
+        )
+        .execute(&pool)
+        .await
+        .unwrap();
+        let repository = CardRepositoryAdapter::new(pool.clone());
+        assert!(
+            repository
+                .get_all_without_gatherer_id()
+                .await
+                .unwrap()
+                .is_empty()
+        );
+
+        // Rejoue la migration sur une base qui contient déjà les cartes abandonnées
+        sqlx::query("ALTER TABLE card DROP COLUMN the_gatherer_back_id")
+            .execute(&pool)
+            .await
+            .unwrap();
+        sqlx::raw_sql(include_str!(
+            "../../../../../migrations/0028_add_card_gatherer_back_id.sql"
+        ))
+        .execute(&pool)
+        .await
+        .unwrap();
+
+        let cards = repository.get_all_without_gatherer_id().await.unwrap();
+        let dead_jobs: i64 = sqlx::query_scalar("SELECT COUNT(*) FROM enrichment_job")
+            .fetch_one(&pool)
+            .await
+            .unwrap();
+        assert_eq!(cards.len(), 1);
+        assert_eq!(cards[0].1, "Fire // Ice");
+        assert_eq!(dead_jobs, 0);
+    }
+
     #[sqlx::test]
     async fn update_gatherer_id_sets_the_value(pool: PgPool) {
         insert_card_without_cardmarket_id(&pool, "FDN", "87", "FR", false, "Goblin Boarders").await;
@@ -476,7 +870,13 @@ mod tests {
         let repository = CardRepositoryAdapter::new(pool);
         let card_id = CardId::new("FDN", "87", LanguageCode::FR, false);
         repository
-            .update_gatherer_id(card_id.clone(), Some("ABC123".to_string()))
+            .update_gatherer_id(
+                card_id.clone(),
+                Some(GathererIds {
+                    front: "ABC123".to_string(),
+                    back: None,
+                }),
+            )
             .await
             .unwrap();
 
@@ -484,6 +884,80 @@ mod tests {
         assert!(remaining.is_empty());
     }
 
+    #[sqlx::test]
+    async fn update_gatherer_id_stores_the_back_face_of_a_double_faced_card(pool: PgPool) {
+        insert_card_without_cardmarket_id(
+            &pool,
+            "FDN",
+            "51",
+            "EN",
+            false,
+            "Delver of Secrets // Insectile Aberration",
+        )
+        .await;
+
+        let repository = CardRepositoryAdapter::new(pool.clone());
+        repository
+            .update_gatherer_id(
+                CardId::new("FDN", "51", LanguageCode::EN, false),
+                Some(GathererIds {
+                    front: "FRONT1".to_string(),
+                    back: Some("BACK1".to_string()),
+                }),
+            )
+            .await
+            .unwrap();
+
+        let (front, back): (Option<String>, Option<String>) = sqlx::query_as(
+            "SELECT the_gatherer_id, the_gatherer_back_id FROM card WHERE collector_number = '51'",
+        )
+        .fetch_one(&pool)
+        .await
+        .unwrap();
+        assert_eq!(front.as_deref(), Some("FRONT1"));
+        assert_eq!(back.as_deref(), Some("BACK1"));
+    }
+
+    #[sqlx::test]
+    async fn update_metadata_sets_the_values(pool: PgPool) {
+        insert_card(&pool, "FDN", "87", "FR", false, "Goblin Boarders", 42).await;
+        insert_collection_entry(
+            &pool,
+            "FDN",
+            "87",
+            "FR",
+            false,
+            "test-user-id",
+            1,
+            100,
+            Utc::now(),
+        )
+        .await;
+
+        let repository = CardRepositoryAdapter::new(pool);
+        let metadata = CardMetadata {
+            oracle_id: Some(Uuid::new_v4()),
+            mana_cost: Some("{2}{R}".to_string()),
+            type_line: Some("Creature — Goblin".to_string()),
+            colors: vec![Color::R],
+            color_identity: vec![Color::R],
+            oracle_text: Some("Goblin Boarders has menace.".to_string()),
+            image_small_url: Some("https://cards.scryfall.io/small.jpg".to_string()),
+            image_normal_url: Some("https://cards.scryfall.io/normal.jpg".to_string()),
+            image_large_url: None,
+        };
+        repository
+            .update_metadata(
+                CardId::new("FDN", "87", LanguageCode::FR, false),
+                metadata.clone(),
+            )
+            .await
+            .unwrap();
+
+        let cards = repository.get_all(User::for_testing()).await.unwrap();
+        assert_eq!(cards[0].metadata, metadata);
+    }
+
     #[sqlx::test]
     async fn find_by_scryfall_id_returns_cardmarket_id_and_foil_when_present(pool: PgPool) {
         let scryfall_id = Uuid::new_v4();
@@ -539,4 +1013,394 @@ mod tests {
 
         assert_eq!(result, None);
     }
+
+    #[sqlx::test]
+    async fn find_name_by_scryfall_id_returns_the_card_name(pool: PgPool) {
+        let scryfall_id = Uuid::new_v4();
+        insert_card_with_scryfall_id(
+            &pool,
+            "FDN",
+            "87",
+            "FR",
+            false,
+            "Goblin Boarders",
+            scryfall_id,
+            None,
+        )
+        .await;
+
+        let repository = CardRepositoryAdapter::new(pool);
+
+        assert_eq!(
+            repository
+                .find_name_by_scryfall_id(scryfall_id)
+                .await
+                .unwrap(),
+            Some("Goblin Boarders".to_string())
+        );
+        assert_eq!(
+            repository
+                .find_name_by_scryfall_id(Uuid::new_v4())
+                .await
+                .unwrap(),
+            None
+        );
+    }
+
+    fn goblin_boarders_item(foil: bool) -> CollectionItem {
+        CollectionItem {
+            card_id: CardId::new("FDN", "87", LanguageCode::FR, foil),
+            quantity: 2,
+            purchase_price: 15,
+            condition: CardCondition::Excellent,
+            notes: Some("Signed".to_string()),
+            tags: vec!["cube".to_string()],
+            location: Some("Box 3, row 2".to_string()),
+            tradable_quantity: None,
+            added_at: Utc::now(),
+        }
+    }
+
+    #[sqlx::test]
+    async fn ensure_printing_creates_a_finish_from_another_one(pool: PgPool) {
+        insert_card(&pool, "FDN", "87", "FR", false, "Goblin Boarders", 42).await;
+        let repository = CardRepositoryAdapter::new(pool.clone());
+
+        let created = repository
+            .ensure_printing(CardId::new("FDN", "87", LanguageCode::FR, true))
+            .await
+            .unwrap();
+
+        assert!(created);
+        let (name, cardmarket_id): (String, Option<i32>) = sqlx::query_as(
+            "SELECT name, cardmarket_id FROM card WHERE set_code = 'FDN' AND collector_number = '87' AND foil",
+        )
+        .fetch_one(&pool)
+        .await
+        .unwrap();
+        assert_eq!(name, "Goblin Boarders");
+        assert_eq!(cardmarket_id, Some(42));
+    }
+
+    #[sqlx::test]
+    async fn ensure_printing_creates_a_printing_never_imported_from_the_scryfall_catalogue(
+        pool: PgPool,
+    ) {
+        sqlx::query(
+            "INSERT INTO scryfall_card (scryfall_id, name, set_code, collector_number, lang, cardmarket_id, rarity)
+             VALUES (gen_random_uuid(), 'Goblin Boarders', 'fdn', '87', 'es', 42, 'C')",
+        )
+        .execute(&pool)
+        .await
+        .unwrap();
+        let repository = CardRepositoryAdapter::new(pool.clone());
+
+        let created = repository
+            .ensure_printing(CardId::new("FDN", "87", LanguageCode::SP, true))
+            .await
+            .unwrap();
+        let other_language = repository
+            .ensure_printing(CardId::new("FDN", "87", LanguageCode::DE, false))
+            .await
+            .unwrap();
+
+        assert!(created);
+        assert!(!other_language);
+        let (name, rarity, cardmarket_id): (String, String, Option<i32>) = sqlx::query_as(
+            "SELECT name, rarity, cardmarket_id FROM card WHERE set_code = 'FDN' AND language_code = 'SP' AND foil",
+        )
+        .fetch_one(&pool)
+        .await
+        .unwrap();
+        assert_eq!(name, "Goblin Boarders");
+        assert_eq!(rarity, "C");
+        assert_eq!(cardmarket_id, Some(42));
+    }
+
+    #[sqlx::test]
+    async fn ensure_printing_returns_false_for_an_unknown_printing(pool: PgPool) {
+        insert_card(&pool, "FDN", "87", "FR", false, "Goblin Boarders", 42).await;
+        let repository = CardRepositoryAdapter::new(pool);
+
+        let created = repository
+            .ensure_printing(CardId::new("FDN", "87", LanguageCode::EN, false))
+            .await
+            .unwrap();
+
+        assert!(!created);
+    }
+
+    #[sqlx::test]
+    async fn add_entry_refuses_a_printing_already_in_the_binder(pool: PgPool) {
+        insert_card(&pool, "FDN", "87", "FR", false, "Goblin Boarders", 42).await;
+        let binder_id = BinderId(insert_default_binder(&pool, "user1").await);
+        let other = BinderId(insert_binder(&pool, "user1", "Trades", true).await);
+        let repository = CardRepositoryAdapter::new(pool);
+        let user_id = UserId::new("user1");
+
+        let first = repository
+            .add_entry(&user_id, binder_id, goblin_boarders_item(false))
+            .await
+            .unwrap();
+        let second = repository
+            .add_entry(&user_id, binder_id, goblin_boarders_item(false))
+            .await
+            .unwrap();
+        let in_other_binder = repository
+            .add_entry(&user_id, other, goblin_boarders_item(false))
+            .await
+            .unwrap();
+
+        assert!(first);
+        assert!(!second);
+        assert!(in_other_binder);
+    }
+
+    #[sqlx::test]
+    async fn update_entry_changes_only_the_given_fields(pool: PgPool) {
+        insert_card(&pool, "FDN", "87", "FR", false, "Goblin Boarders", 42).await;
+        let binder_id = BinderId(insert_default_binder(&pool, "user1").await);
+        let repository = CardRepositoryAdapter::new(pool);
+        let user_id = UserId::new("user1");
+        repository
+            .add_entry(&user_id, binder_id, goblin_boarders_item(false))
+            .await
+            .unwrap();
+
+        let updated = repository
+            .update_entry(
+                &user_id,
+                binder_id,
+                CardId::new("FDN", "87", LanguageCode::FR, false),
+                CollectionItemUpdate {
+                    quantity: Some(4),
+                    notes: Some(None),
+                    ..CollectionItemUpdate::default()
+                },
+            )
+            .await
+            .unwrap()
+            .unwrap();
+
+        assert_eq!(updated.quantity, 4);
+        assert_eq!(updated.purchase_price, 15);
+        assert_eq!(updated.condition, CardCondition::Excellent);
+        assert_eq!(updated.notes, None);
+        assert_eq!(updated.tags, vec!["cube"]);
+        assert_eq!(updated.location.as_deref(), Some("Box 3, row 2"));
+    }
+
+    #[sqlx::test]
+    async fn update_entry_replaces_the_tags_and_clears_the_location(pool: PgPool) {
+        insert_card(&pool, "FDN", "87", "FR", false, "Goblin Boarders", 42).await;
+        let binder_id = BinderId(insert_default_binder(&pool, "user1").await);
+        let repository = CardRepositoryAdapter::new(pool);
+        let user_id = UserId::new("user1");
+        repository
+            .add_entry(&user_id, binder_id, goblin_boarders_item(false))
+            .await
+            .unwrap();
+
+        let updated = repository
+            .update_entry(
+                &user_id,
+                binder_id,
+                CardId::new("FDN", "87", LanguageCode::FR, false),
+                CollectionItemUpdate {
+                    tags: Some(vec!["commander staples".to_string(), "trade".to_string()]),
+                    location: Some(None),
+                    ..CollectionItemUpdate::default()
+                },
+            )
+            .await
+            .unwrap()
+            .unwrap();
+
+        assert_eq!(updated.tags, vec!["commander staples", "trade"]);
+        assert_eq!(updated.location, None);
+        assert_eq!(updated.notes.as_deref(), Some("Signed"));
+    }
+
+    #[sqlx::test]
+    async fn reimport_keeps_the_hand_set_fields_of_the_entries_still_present(pool: PgPool) {
+        insert_card(&pool, "FDN", "87", "FR", false, "Goblin Boarders", 42).await;
+        insert_card(&pool, "FDN", "87", "FR", true, "Goblin Boarders", 42).await;
+        let binder_id = BinderId(insert_default_binder(&pool, "test-user-id").await);
+        let repository = CardRepositoryAdapter::new(pool.clone());
+        let user_id = UserId::new("test-user-id");
+        for foil in [false, true] {
+            repository
+                .add_entry(&user_id, binder_id, goblin_boarders_item(foil))
+                .await
+                .unwrap();
+        }
+
+        // Réimport ne contenant plus que l'impression non foil, en 5 exemplaires
+        let card = Card::new(
+            "FDN",
+            "Foundations",
+            "87",
+            LanguageCode::FR,
+            false,
+            "Goblin Boarders",
+            RarityCode::C,
+            5,
+            20,
+        );
+        let kept = vec![card.id.clone()];
+        repository
+            .save(User::for_testing(), binder_id, card)
+            .await
+            .unwrap();
+        repository
+            .delete_missing(User::for_testing(), binder_id, kept)
+            .await
+            .unwrap();
+
+        type EntryRow = (
+            bool,
+            i32,
+            Vec<String>,
+            Option<String>,
+            Option<String>,
+            String,
+        );
+        let rows: Vec<EntryRow> = sqlx::query_as(
+            "SELECT foil, quantity, tags, notes, location, condition FROM collection_entry",
+        )
+        .fetch_all(&pool)
+        .await
+        .unwrap();
+        assert_eq!(
+            rows,
+            vec![(
+                false,
+                5,
+                vec!["cube".to_string()],
+                Some("Signed".to_string()),
+                Some("Box 3, row 2".to_string()),
+                "EXCELLENT".to_string()
+            )]
+        );
+    }
+
+    #[sqlx::test]
+    async fn update_entry_sets_then_clears_the_tradable_quantity(pool: PgPool) {
+        insert_card(&pool, "FDN", "87", "FR", false, "Goblin Boarders", 42).await;
+        let binder_id = BinderId(insert_default_binder(&pool, "user1").await);
+        let repository = CardRepositoryAdapter::new(pool);
+        let user_id = UserId::new("user1");
+        let card_id = CardId::new("FDN", "87", LanguageCode::FR, false);
+        repository
+            .add_entry(&user_id, binder_id, goblin_boarders_item(false))
+            .await
+            .unwrap();
+
+        let set = repository
+            .update_entry(
+                &user_id,
+                binder_id,
+                card_id.clone(),
+                CollectionItemUpdate {
+                    tradable_quantity: Some(Some(1)),
+                    ..CollectionItemUpdate::default()
+                },
+            )
+            .await
+            .unwrap()
+            .unwrap();
+        let kept = repository
+            .update_entry(
+                &user_id,
+                binder_id,
+                card_id.clone(),
+                CollectionItemUpdate {
+                    quantity: Some(3),
+                    ..CollectionItemUpdate::default()
+                },
+            )
+            .await
+            .unwrap()
+            .unwrap();
+        let cleared = repository
+            .update_entry(
+                &user_id,
+                binder_id,
+                card_id,
+                CollectionItemUpdate {
+                    tradable_quantity: Some(None),
+                    ..CollectionItemUpdate::default()
+                },
+            )
+            .await
+            .unwrap()
+            .unwrap();
+
+        assert_eq!(set.tradable_quantity, Some(1));
+        assert_eq!(kept.tradable_quantity, Some(1));
+        assert_eq!(cleared.tradable_quantity, None);
+    }
+
+    #[sqlx::test]
+    async fn update_entry_returns_none_for_another_users_entry(pool: PgPool) {
+        insert_card(&pool, "FDN", "87", "FR", false, "Goblin Boarders", 42).await;
+        let binder_id = BinderId(insert_default_binder(&pool, "user1").await);
+        let repository = CardRepositoryAdapter::new(pool);
+        repository
+            .add_entry(
+                &UserId::new("user1"),
+                binder_id,
+                goblin_boarders_item(false),
+            )
+            .await
+            .unwrap();
+
+        let updated = repository
+            .update_entry(
+                &UserId::new("user2"),
+                binder_id,
+                CardId::new("FDN", "87", LanguageCode::FR, false),
+                CollectionItemUpdate {
+                    quantity: Some(4),
+                    ..CollectionItemUpdate::default()
+                },
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(updated, None);
+    }
+
+    #[sqlx::test]
+    async fn delete_entry_removes_only_the_users_entry(pool: PgPool) {
+        insert_card(&pool, "FDN", "87", "FR", false, "Goblin Boarders", 42).await;
+        let repository = CardRepositoryAdapter::new(pool.clone());
+        let card_id = CardId::new("FDN", "87", LanguageCode::FR, false);
+        let mut binder_ids = Vec::new();
+        for user in ["user1", "user2"] {
+            let binder_id = BinderId(insert_default_binder(&pool, user).await);
+            repository
+                .add_entry(&UserId::new(user), binder_id, goblin_boarders_item(false))
+                .await
+                .unwrap();
+            binder_ids.push(binder_id);
+        }
+
+        let deleted = repository
+            .delete_entry(&UserId::new("user1"), binder_ids[0], card_id.clone())
+            .await
+            .unwrap();
+        let deleted_again = repository
+            .delete_entry(&UserId::new("user1"), binder_ids[0], card_id)
+            .await
+            .unwrap();
+
+        assert!(deleted);
+        assert!(!deleted_again);
+        let remaining: i64 = sqlx::query_scalar("SELECT COUNT(*) FROM collection_entry")
+            .fetch_one(&pool)
+            .await
+            .unwrap();
+        assert_eq!(remaining, 1);
+    }
 }
@@ -1,46 +1,79 @@
 use crate::application::error::AppError;
 use crate::application::repository::CardMarketPriceRepository;
-use crate::domain::price::{FullPriceGuide, PriceHistoryEntry};
+use crate::domain::price::{
+    FullPriceGuide, PriceHistoryEntry, PriceHistoryOptions, PriceImportScope, SINGLES_CATEGORY,
+};
+use crate::domain::price_anomaly::{PriceAnomaly, ReferencePrice};
 use crate::infrastructure::adapter_out::repository::entities::{
-    CardMarketPriceEntity, CardMarketPriceHistoryEntity, CardMarketPriceRaw,
+    CardMarketPriceEntity, CardMarketPriceRaw, PriceHistoryBucketEntity, ReferencePriceEntity,
 };
+use crate::infrastructure::adapter_out::repository::price_anomaly_repository_adapter;
 use async_trait::async_trait;
 use chrono::NaiveDate;
+use sqlx::pool::PoolConnection;
 use sqlx::{Pool, Postgres, QueryBuilder};
+use std::sync::Mutex;
 
 pub struct CardMarketPriceRepositoryAdapter {
     pool: Pool<Postgres>,
+    /// Connection holding the staging lock, kept out of the pool while the import runs
+    staging_lock: Mutex<Option<PoolConnection<Postgres>>>,
 }
 
 impl CardMarketPriceRepositoryAdapter {
     pub fn new(pool: Pool<Postgres>) -> Self {
-        Self { pool }
+        Self {
+            pool,
+            staging_lock: Mutex::new(None),
+        }
     }
 }
 
 #[async_trait]
 impl CardMarketPriceRepository for CardMarketPriceRepositoryAdapter {
-    async fn save(
-        &self,
-        date: NaiveDate,
-        price_guides: Vec<FullPriceGuide>,
-    ) -> Result<(), AppError> {
-        let mut tx = self.pool.begin().await?;
+    async fn lock_staging(&self) -> Result<bool, AppError> {
+        let mut conn = self.pool.acquire().await?;
 
+        // Verrou de session : il tient tant que la connexion est gardée, et tombe avec elle
+        let locked = sqlx::query_scalar!(
+            r#"SELECT pg_try_advisory_lock(hashtext('cardmarket_price_staging')) AS "locked!""#
+        )
+        .fetch_one(&mut *conn)
+        .await?;
+
+        if locked {
+            *self.staging_lock.lock().unwrap() = Some(conn);
+        }
+
+        Ok(locked)
+    }
+
+    async fn unlock_staging(&self) -> Result<(), AppError> {
+        let conn = self.staging_lock.lock().unwrap().take();
+
+        // Fermer la session libère le verrou, même si la connexion est en erreur
+        if let Some(conn) = conn {
+            conn.close().await?;
+        }
+
+        Ok(())
+    }
+
+    async fn stage(&self, price_guides: Vec<FullPriceGuide>) -> Result<(), AppError> {
         const CHUNK_SIZE: usize = 1000;
 
         for chunk in price_guides.chunks(CHUNK_SIZE) {
             let mut qb: QueryBuilder<Postgres> = QueryBuilder::new(
                 "
-                INSERT INTO cardmarket_price
-                    (id_produit, date, low, trend, avg,
+                INSERT INTO cardmarket_price_staging
+                    (id_produit, id_category, low, trend, avg,
                      low_foil, trend_foil, avg_foil)
                 ",
             );
 
             qb.push_values(chunk, |mut b, price_guide| {
                 b.push_bind(price_guide.id_product as i32)
-                    .push_bind(date)
+                    .push_bind(price_guide.id_category.map(|c| c as i32))
                     .push_bind(price_guide.normal.low.as_i32())
                     .push_bind(price_guide.normal.trend.as_i32())
                     .push_bind(price_guide.normal.avg.as_i32())
@@ -51,8 +84,9 @@ impl CardMarketPriceRepository for CardMarketPriceRepositoryAdapter {
 
             qb.push(
                 "
-                ON CONFLICT (id_produit, date)
+                ON CONFLICT (id_produit)
                 DO UPDATE SET
+                    id_category = EXCLUDED.id_category,
                     low        = EXCLUDED.low,
                     trend      = EXCLUDED.trend,
                     avg        = EXCLUDED.avg,
@@ -62,13 +96,117 @@ impl CardMarketPriceRepository for CardMarketPriceRepositoryAdapter {
                 ",
             );
 
-            qb.build().execute(&mut *tx).await?;
+            qb.build().execute(&self.pool).await?;
         }
 
+        Ok(())
+    }
+
+    async fn publish_staged(
+        &self,
+        date: NaiveDate,
+        scope: PriceImportScope,
+        anomalies: Vec<PriceAnomaly>,
+    ) -> Result<(), AppError> {
+        let mut tx = self.pool.begin().await?;
+
+        // Même filtre que ImportPriceService::in_scope, appliqué ici au guide complet
+        sqlx::query!(
+            "INSERT INTO cardmarket_price
+                 (id_produit, date, id_category, low, trend, avg,
+                  low_foil, trend_foil, avg_foil)
+             SELECT s.id_produit, $1, s.id_category, s.low, s.trend, s.avg,
+                    s.low_foil, s.trend_foil, s.avg_foil
+             FROM cardmarket_price_staging s
+             WHERE $2 = 'ALL'
+                OR ($2 = 'SINGLES' AND s.id_category = $3)
+                OR ($2 = 'REFERENCED' AND EXISTS (SELECT 1
+                                                   FROM card c
+                                                   WHERE c.cardmarket_id = s.id_produit))
+             ON CONFLICT (id_produit, date)
+             DO UPDATE SET
+                 id_category = EXCLUDED.id_category,
+                 low        = EXCLUDED.low,
+                 trend      = EXCLUDED.trend,
+                 avg        = EXCLUDED.avg,
+                 low_foil   = EXCLUDED.low_foil,
+                 trend_foil = EXCLUDED.trend_foil,
+                 avg_foil   = EXCLUDED.avg_foil",
+            date,
+            scope.as_db_str(),
+            SINGLES_CATEGORY as i32,
+        )
+        .execute(&mut *tx)
+        .await?;
+
+        sqlx::query!("TRUNCATE cardmarket_price_guide_latest")
+            .execute(&mut *tx)
+            .await?;
+
+        sqlx::query!(
+            "INSERT INTO cardmarket_price_guide_latest
+                 (id_produit, date, id_category, low, trend, avg,
+                  low_foil, trend_foil, avg_foil)
+             SELECT id_produit, $1, id_category, low, trend, avg,
+                    low_foil, trend_foil, avg_foil
+             FROM cardmarket_price_staging",
+            date
+        )
+        .execute(&mut *tx)
+        .await?;
+
+        sqlx::query!("TRUNCATE cardmarket_price_staging")
+            .execute(&mut *tx)
+            .await?;
+
+        // Les prix suspects ne doivent jamais être visibles sans leur quarantaine
+        price_anomaly_repository_adapter::replace_pending(&mut tx, date, anomalies).await?;
+
         tx.commit().await?;
         Ok(())
     }
 
+    async fn discard_staged(&self) -> Result<(), AppError> {
+        sqlx::query!("TRUNCATE cardmarket_price_staging")
+            .execute(&self.pool)
+            .await?;
+
+        Ok(())
+    }
+
+    async fn backfill_from_latest_guide(&self, id_product: u32) -> Result<(), AppError> {
+        sqlx::query!(
+            "INSERT INTO cardmarket_price
+                 (id_produit, date, id_category, low, trend, avg,
+                  low_foil, trend_foil, avg_foil)
+             SELECT id_produit, date, id_category, low, trend, avg,
+                    low_foil, trend_foil, avg_foil
+             FROM cardmarket_price_guide_latest
+             WHERE id_produit = $1
+             ON CONFLICT (id_produit, date) DO NOTHING",
+            id_product as i32
+        )
+        .execute(&self.pool)
+        .await?;
+
+        Ok(())
+    }
+
+    async fn find_referenced_products(&self, id_products: Vec<u32>) -> Result<Vec<u32>, AppError> {
+        let id_products: Vec<i32> = id_products.into_iter().map(|id| id as i32).collect();
+
+        let referenced = sqlx::query_scalar!(
+            r#"SELECT DISTINCT cardmarket_id AS "cardmarket_id!"
+               FROM card
+               WHERE cardmarket_id = ANY($1)"#,
+            &id_products,
+        )
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(referenced.into_iter().map(|id| id as u32).collect())
+    }
+
     async fn find_by_id_and_date(
         &self,
         id_product: u32,
@@ -76,7 +214,7 @@ impl CardMarketPriceRepository for CardMarketPriceRepositoryAdapter {
     ) -> Result<Option<FullPriceGuide>, AppError> {
         let record = sqlx::query_as!(
             CardMarketPriceRaw,
-            "SELECT id_produit, date, low, trend, avg,
+            "SELECT id_produit, date, id_category, low, trend, avg,
                     low_foil, trend_foil, avg_foil
              FROM cardmarket_price
              WHERE id_produit = $1 AND date = $2",
@@ -97,35 +235,88 @@ impl CardMarketPriceRepository for CardMarketPriceRepositoryAdapter {
         foil: bool,
         start_date: NaiveDate,
         end_date: NaiveDate,
+        options: PriceHistoryOptions,
     ) -> Result<Vec<PriceHistoryEntry>, AppError> {
+        // Les moyennes mobiles ont besoin des 29 jours précédant le début de la période
         let entities = sqlx::query_as!(
-            CardMarketPriceHistoryEntity,
-            r#"SELECT date,
-                      CASE WHEN $2::boolean THEN low_foil ELSE low END as "low",
-                      CASE WHEN $2::boolean THEN trend_foil ELSE trend END as "trend",
-                      CASE WHEN $2::boolean THEN avg_foil ELSE avg END as "avg"
-               FROM cardmarket_price
-               WHERE id_produit = $1 AND date >= $3 AND date <= $4
-               ORDER BY date"#,
+            PriceHistoryBucketEntity,
+            r#"WITH daily AS (SELECT date,
+                                     CASE WHEN $2::boolean THEN low_foil ELSE low END     AS low,
+                                     CASE WHEN $2::boolean THEN trend_foil ELSE trend END AS trend,
+                                     CASE WHEN $2::boolean THEN avg_foil ELSE avg END     AS avg
+                              FROM cardmarket_price
+                              WHERE id_produit = $1
+                                AND date >= $3::date - CASE WHEN $6::boolean THEN 29 ELSE 0 END
+                                AND date <= $4
+                                AND NOT CASE WHEN $2::boolean THEN quarantined_foil ELSE quarantined END),
+                    windowed AS (SELECT *,
+                                        AVG(trend) OVER (ORDER BY date RANGE BETWEEN INTERVAL '6 days' PRECEDING AND CURRENT ROW)  AS ma7,
+                                        AVG(trend) OVER (ORDER BY date RANGE BETWEEN INTERVAL '29 days' PRECEDING AND CURRENT ROW) AS ma30
+                                 FROM daily)
+               SELECT date_trunc($5, date::timestamp)::date                                        AS "date!",
+                      (array_agg(low ORDER BY date DESC) FILTER (WHERE low IS NOT NULL))[1]        AS "low",
+                      (array_agg(trend ORDER BY date DESC) FILTER (WHERE trend IS NOT NULL))[1]    AS "trend",
+                      (array_agg(avg ORDER BY date DESC) FILTER (WHERE avg IS NOT NULL))[1]        AS "avg",
+                      (array_agg(trend ORDER BY date) FILTER (WHERE trend IS NOT NULL))[1]         AS "trend_open",
+                      MAX(trend)                                                                   AS "trend_high",
+                      MIN(trend)                                                                   AS "trend_low",
+                      CASE WHEN $6 THEN ROUND((array_agg(ma7 ORDER BY date DESC))[1])::integer END  AS "trend_ma7",
+                      CASE WHEN $6 THEN ROUND((array_agg(ma30 ORDER BY date DESC))[1])::integer END AS "trend_ma30"
+               FROM windowed
+               WHERE date >= $3
+               GROUP BY 1
+               ORDER BY 1"#,
             id_product as i32,
             foil,
             start_date,
             end_date,
+            options.granularity.as_date_trunc_unit(),
+            options.moving_averages,
         )
         .fetch_all(&self.pool)
         .await?;
 
         Ok(entities.into_iter().map(PriceHistoryEntry::from).collect())
     }
+
+    async fn find_reference_trends(
+        &self,
+        date: NaiveDate,
+        window_days: u32,
+        id_products: Vec<u32>,
+    ) -> Result<Vec<ReferencePrice>, AppError> {
+        let id_products: Vec<i32> = id_products.into_iter().map(|id| id as i32).collect();
+        let entities = sqlx::query_as!(
+            ReferencePriceEntity,
+            r#"SELECT id_produit,
+                      percentile_disc(0.5) WITHIN GROUP (ORDER BY trend) FILTER (WHERE NOT quarantined)           AS "trend",
+                      percentile_disc(0.5) WITHIN GROUP (ORDER BY trend_foil) FILTER (WHERE NOT quarantined_foil) AS "trend_foil"
+               FROM cardmarket_price
+               WHERE id_produit = ANY($3)
+                 AND date < $1
+                 AND date >= $1 - $2::integer
+               GROUP BY id_produit"#,
+            date,
+            window_days as i32,
+            &id_products,
+        )
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(entities.into_iter().map(ReferencePrice::from).collect())
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::domain::price::{Price, PriceGuide};
-    use crate::infrastructure::adapter_out::repository::common_repository_tests::fetch_cardmarket_price;
+    use crate::domain::price::{Price, PriceGuide, PriceHistoryGranularity};
+    use crate::domain::price_anomaly::PriceAnomalyKind;
+    use crate::infrastructure::adapter_out::repository::common_repository_tests::{
+        fetch_cardmarket_price, fetch_quarantined, insert_card, insert_price, quarantine_price,
+    };
     use crate::infrastructure::adapter_out::repository::entities::PriceGuideEntity;
-    use chrono::NaiveDate;
+    use chrono::{NaiveDate, Utc};
 
     impl PriceGuide {
         pub fn empty() -> Self {
@@ -145,6 +336,7 @@ mod tests {
         ) -> Self {
             FullPriceGuide {
                 id_product,
+                id_category: None,
                 normal: PriceGuide::from(PriceGuideEntity {
                     low: Some(normal_values.0),
                     trend: Some(normal_values.1),
@@ -159,17 +351,28 @@ mod tests {
         }
     }
 
+    /// Saves `price_guides` at `date` the way an import does.
+    async fn publish(
+        repository: &CardMarketPriceRepositoryAdapter,
+        date: NaiveDate,
+        price_guides: Vec<FullPriceGuide>,
+    ) {
+        repository.stage(price_guides).await.unwrap();
+        repository
+            .publish_staged(date, PriceImportScope::All, vec![])
+            .await
+            .unwrap();
+    }
+
     #[sqlx::test]
-    async fn test_save_new_cardmarket_price(pool: Pool<Postgres>) {
+    async fn test_publish_new_cardmarket_price(pool: Pool<Postgres>) {
         let repository = CardMarketPriceRepositoryAdapter::new(pool.clone());
         let date = NaiveDate::from_ymd_opt(2024, 1, 15).unwrap();
         let id_produit = 12345u32;
         let price_guides =
             FullPriceGuide::from_values(id_produit, (100, 150, 125), (200, 250, 225));
 
-        let result = repository.save(date, vec![price_guides]).await;
-
-        assert!(result.is_ok());
+        publish(&repository, date, vec![price_guides]).await;
 
         let record = fetch_cardmarket_price(&pool, id_produit as i32, date).await;
 
@@ -184,23 +387,18 @@ mod tests {
     }
 
     #[sqlx::test]
-    async fn test_save_updates_existing_cardmarket_price(pool: Pool<Postgres>) {
+    async fn test_publish_updates_existing_cardmarket_price(pool: Pool<Postgres>) {
         let repository = CardMarketPriceRepositoryAdapter::new(pool.clone());
         let date = NaiveDate::from_ymd_opt(2024, 1, 15).unwrap();
         let id_produit = 12346u32;
 
         let initial_price_guides =
             FullPriceGuide::from_values(id_produit, (100, 150, 125), (200, 250, 225));
-        repository
-            .save(date, vec![initial_price_guides])
-            .await
-            .unwrap();
+        publish(&repository, date, vec![initial_price_guides]).await;
 
         let updated_price_guides =
             FullPriceGuide::from_values(id_produit, (110, 160, 135), (210, 260, 235));
-        let result = repository.save(date, vec![updated_price_guides]).await;
-
-        assert!(result.is_ok());
+        publish(&repository, date, vec![updated_price_guides]).await;
 
         let record = fetch_cardmarket_price(&pool, id_produit as i32, date).await;
 
@@ -213,20 +411,19 @@ mod tests {
     }
 
     #[sqlx::test]
-    async fn test_save_with_empty_price_values(pool: Pool<Postgres>) {
+    async fn test_publish_with_empty_price_values(pool: Pool<Postgres>) {
         let repository = CardMarketPriceRepositoryAdapter::new(pool.clone());
         let date = NaiveDate::from_ymd_opt(2024, 1, 15).unwrap();
         let id_produit = 12347u32;
 
         let price_guides = FullPriceGuide {
             id_product: id_produit,
+            id_category: None,
             normal: PriceGuide::empty(),
             foil: PriceGuide::empty(),
         };
 
-        let result = repository.save(date, vec![price_guides]).await;
-
-        assert!(result.is_ok());
+        publish(&repository, date, vec![price_guides]).await;
 
         let record = fetch_cardmarket_price(&pool, id_produit as i32, date).await;
 
@@ -241,18 +438,15 @@ mod tests {
     }
 
     #[sqlx::test]
-    async fn test_save_handles_multiple_products_same_date(pool: Pool<Postgres>) {
+    async fn test_publish_handles_multiple_products_same_date(pool: Pool<Postgres>) {
         let repository = CardMarketPriceRepositoryAdapter::new(pool.clone());
         let date = NaiveDate::from_ymd_opt(2024, 1, 15).unwrap();
 
         let price_guides_1 = FullPriceGuide::from_values(12348, (100, 150, 125), (200, 250, 225));
         let price_guides_2 = FullPriceGuide::from_values(12349, (300, 350, 325), (400, 450, 425));
 
-        let result1 = repository.save(date, vec![price_guides_1]).await;
-        let result2 = repository.save(date, vec![price_guides_2]).await;
-
-        assert!(result1.is_ok());
-        assert!(result2.is_ok());
+        publish(&repository, date, vec![price_guides_1]).await;
+        publish(&repository, date, vec![price_guides_2]).await;
 
         let count =
             sqlx::query_scalar::<_, i64>("SELECT COUNT(*) FROM cardmarket_price WHERE date = $1")
@@ -272,7 +466,7 @@ mod tests {
 
         let price_guides =
             FullPriceGuide::from_values(id_produit, (100, 150, 125), (200, 250, 225));
-        repository.save(date, vec![price_guides]).await.unwrap();
+        publish(&repository, date, vec![price_guides]).await;
 
         let result = repository.find_by_id_and_date(id_produit, date).await;
 
@@ -307,31 +501,35 @@ mod tests {
         let date1 = NaiveDate::from_ymd_opt(2024, 1, 15).unwrap();
         let date2 = NaiveDate::from_ymd_opt(2024, 1, 16).unwrap();
 
-        repository
-            .save(
-                date2,
-                vec![FullPriceGuide::from_values(
-                    id_produit,
-                    (12, 17, 22),
-                    (120, 170, 220),
-                )],
-            )
-            .await
-            .unwrap();
-        repository
-            .save(
-                date1,
-                vec![FullPriceGuide::from_values(
-                    id_produit,
-                    (10, 15, 20),
-                    (100, 150, 200),
-                )],
-            )
-            .await
-            .unwrap();
+        publish(
+            &repository,
+            date2,
+            vec![FullPriceGuide::from_values(
+                id_produit,
+                (12, 17, 22),
+                (120, 170, 220),
+            )],
+        )
+        .await;
+        publish(
+            &repository,
+            date1,
+            vec![FullPriceGuide::from_values(
+                id_produit,
+                (10, 15, 20),
+                (100, 150, 200),
+            )],
+        )
+        .await;
 
         let result = repository
-            .find_by_id_and_date_range(id_produit, false, date1, date2)
+            .find_by_id_and_date_range(
+                id_produit,
+                false,
+                date1,
+                date2,
+                PriceHistoryOptions::default(),
+            )
             .await
             .unwrap();
 
@@ -350,20 +548,19 @@ mod tests {
         let id_produit = 20002u32;
         let date = NaiveDate::from_ymd_opt(2024, 1, 15).unwrap();
 
-        repository
-            .save(
-                date,
-                vec![FullPriceGuide::from_values(
-                    id_produit,
-                    (10, 15, 20),
-                    (100, 150, 200),
-                )],
-            )
-            .await
-            .unwrap();
+        publish(
+            &repository,
+            date,
+            vec![FullPriceGuide::from_values(
+                id_produit,
+                (10, 15, 20),
+                (100, 150, 200),
+            )],
+        )
+        .await;
 
         let result = repository
-            .find_by_id_and_date_range(id_produit, true, date, date)
+            .find_by_id_and_date_range(id_produit, true, date, date, PriceHistoryOptions::default())
             .await
             .unwrap();
 
@@ -379,67 +576,571 @@ mod tests {
         let id_produit = 20003u32;
         let date = NaiveDate::from_ymd_opt(2024, 1, 15).unwrap();
 
-        repository
-            .save(
+        publish(
+            &repository,
+            date,
+            vec![FullPriceGuide::from_values(
+                id_produit,
+                (10, 15, 20),
+                (100, 150, 200),
+            )],
+        )
+        .await;
+
+        let result = repository
+            .find_by_id_and_date_range(
+                id_produit,
+                false,
+                date,
+                date,
+                PriceHistoryOptions::default(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(result.len(), 1);
+    }
+
+    #[sqlx::test]
+    async fn find_by_id_and_date_range_returns_empty_when_no_price_in_range(pool: Pool<Postgres>) {
+        let repository = CardMarketPriceRepositoryAdapter::new(pool.clone());
+        let id_produit = 20004u32;
+        let date = NaiveDate::from_ymd_opt(2024, 1, 15).unwrap();
+
+        publish(
+            &repository,
+            date,
+            vec![FullPriceGuide::from_values(
+                id_produit,
+                (10, 15, 20),
+                (100, 150, 200),
+            )],
+        )
+        .await;
+
+        let result = repository
+            .find_by_id_and_date_range(
+                id_produit,
+                false,
+                NaiveDate::from_ymd_opt(2024, 2, 1).unwrap(),
+                NaiveDate::from_ymd_opt(2024, 2, 28).unwrap(),
+                PriceHistoryOptions::default(),
+            )
+            .await
+            .unwrap();
+
+        assert!(result.is_empty());
+    }
+
+    #[sqlx::test]
+    async fn find_by_id_and_date_range_returns_empty_when_product_unknown(pool: Pool<Postgres>) {
+        let repository = CardMarketPriceRepositoryAdapter::new(pool.clone());
+        let date = NaiveDate::from_ymd_opt(2024, 1, 15).unwrap();
+
+        let result = repository
+            .find_by_id_and_date_range(99999u32, false, date, date, PriceHistoryOptions::default())
+            .await
+            .unwrap();
+
+        assert!(result.is_empty());
+    }
+
+    #[sqlx::test]
+    async fn find_by_id_and_date_range_skips_quarantined_prices(pool: Pool<Postgres>) {
+        let repository = CardMarketPriceRepositoryAdapter::new(pool.clone());
+        let id_produit = 20005u32;
+        let date1 = NaiveDate::from_ymd_opt(2024, 1, 15).unwrap();
+        let date2 = NaiveDate::from_ymd_opt(2024, 1, 16).unwrap();
+
+        for (date, trend) in [(date1, 15), (date2, 150_000)] {
+            publish(
+                &repository,
                 date,
                 vec![FullPriceGuide::from_values(
                     id_produit,
-                    (10, 15, 20),
+                    (10, trend, 20),
                     (100, 150, 200),
                 )],
             )
-            .await
-            .unwrap();
+            .await;
+        }
+        quarantine_price(&pool, id_produit as i32, date2).await;
 
         let result = repository
-            .find_by_id_and_date_range(id_produit, false, date, date)
+            .find_by_id_and_date_range(
+                id_produit,
+                false,
+                date1,
+                date2,
+                PriceHistoryOptions::default(),
+            )
             .await
             .unwrap();
 
         assert_eq!(result.len(), 1);
+        assert_eq!(result[0].date, date1);
     }
 
     #[sqlx::test]
-    async fn find_by_id_and_date_range_returns_empty_when_no_price_in_range(pool: Pool<Postgres>) {
+    async fn find_by_id_and_date_range_aggregates_weeks_as_ohlc(pool: Pool<Postgres>) {
         let repository = CardMarketPriceRepositoryAdapter::new(pool.clone());
-        let id_produit = 20004u32;
-        let date = NaiveDate::from_ymd_opt(2024, 1, 15).unwrap();
+        let id_produit = 20006u32;
 
-        repository
-            .save(
-                date,
+        // Du lundi 15 au jeudi 18, puis le lundi suivant
+        for (day, trend) in [(15, 100), (16, 300), (17, 50), (18, 200), (22, 120)] {
+            publish(
+                &repository,
+                NaiveDate::from_ymd_opt(2024, 1, day).unwrap(),
                 vec![FullPriceGuide::from_values(
                     id_produit,
-                    (10, 15, 20),
+                    (day as i32, trend, 20),
                     (100, 150, 200),
                 )],
             )
+            .await;
+        }
+
+        let result = repository
+            .find_by_id_and_date_range(
+                id_produit,
+                false,
+                NaiveDate::from_ymd_opt(2024, 1, 15).unwrap(),
+                NaiveDate::from_ymd_opt(2024, 1, 22).unwrap(),
+                PriceHistoryOptions {
+                    granularity: PriceHistoryGranularity::Week,
+                    moving_averages: false,
+                },
+            )
             .await
             .unwrap();
 
+        assert_eq!(result.len(), 2);
+        assert_eq!(
+            result[0].date,
+            NaiveDate::from_ymd_opt(2024, 1, 15).unwrap()
+        );
+        assert_eq!(result[0].trend_open.value, Some(100));
+        assert_eq!(result[0].trend_high.value, Some(300));
+        assert_eq!(result[0].trend_low.value, Some(50));
+        assert_eq!(result[0].price_guide.trend.value, Some(200));
+        assert_eq!(result[0].price_guide.low.value, Some(18));
+        assert_eq!(result[0].trend_ma7.value, None);
+        assert_eq!(
+            result[1].date,
+            NaiveDate::from_ymd_opt(2024, 1, 22).unwrap()
+        );
+        assert_eq!(result[1].trend_open.value, Some(120));
+        assert_eq!(result[1].price_guide.trend.value, Some(120));
+    }
+
+    #[sqlx::test]
+    async fn find_by_id_and_date_range_computes_moving_averages_from_days_before_start(
+        pool: Pool<Postgres>,
+    ) {
+        let repository = CardMarketPriceRepositoryAdapter::new(pool.clone());
+        let id_produit = 20007u32;
+
+        for day in 1..=10u32 {
+            publish(
+                &repository,
+                NaiveDate::from_ymd_opt(2024, 1, day).unwrap(),
+                vec![FullPriceGuide::from_values(
+                    id_produit,
+                    (10, day as i32 * 10, 20),
+                    (100, 150, 200),
+                )],
+            )
+            .await;
+        }
+        let date = NaiveDate::from_ymd_opt(2024, 1, 10).unwrap();
+
         let result = repository
             .find_by_id_and_date_range(
                 id_produit,
                 false,
-                NaiveDate::from_ymd_opt(2024, 2, 1).unwrap(),
-                NaiveDate::from_ymd_opt(2024, 2, 28).unwrap(),
+                date,
+                date,
+                PriceHistoryOptions {
+                    granularity: PriceHistoryGranularity::Day,
+                    moving_averages: true,
+                },
             )
             .await
             .unwrap();
 
-        assert!(result.is_empty());
+        assert_eq!(result.len(), 1);
+        assert_eq!(result[0].trend_ma7.value, Some(70));
+        assert_eq!(result[0].trend_ma30.value, Some(55));
     }
 
     #[sqlx::test]
-    async fn find_by_id_and_date_range_returns_empty_when_product_unknown(pool: Pool<Postgres>) {
+    async fn find_reference_trends_returns_median_over_window(pool: Pool<Postgres>) {
         let repository = CardMarketPriceRepositoryAdapter::new(pool.clone());
-        let date = NaiveDate::from_ymd_opt(2024, 1, 15).unwrap();
+        let id_produit = 20006u32;
+        let date = NaiveDate::from_ymd_opt(2024, 1, 20).unwrap();
+
+        for (day, trend) in [(10, 9999), (17, 100), (18, 120), (19, 5000), (20, 7)] {
+            publish(
+                &repository,
+                NaiveDate::from_ymd_opt(2024, 1, day).unwrap(),
+                vec![FullPriceGuide::from_values(
+                    id_produit,
+                    (10, trend, 20),
+                    (100, 150, 200),
+                )],
+            )
+            .await;
+        }
 
         let result = repository
-            .find_by_id_and_date_range(99999u32, false, date, date)
+            .find_reference_trends(date, 7, vec![id_produit])
             .await
             .unwrap();
 
-        assert!(result.is_empty());
+        // le 10 est hors fenêtre et le 20 est le jour importé
+        assert_eq!(result.len(), 1);
+        assert_eq!(result[0].id_product, id_produit);
+        assert_eq!(result[0].normal_trend.value, Some(120));
+        assert_eq!(result[0].foil_trend.value, Some(150));
+    }
+
+    #[sqlx::test]
+    async fn find_reference_trends_ignores_quarantined_prices(pool: Pool<Postgres>) {
+        let repository = CardMarketPriceRepositoryAdapter::new(pool.clone());
+        let id_produit = 20007u32;
+        let date = NaiveDate::from_ymd_opt(2024, 1, 20).unwrap();
+        let quarantined_day = NaiveDate::from_ymd_opt(2024, 1, 19).unwrap();
+
+        for (day, trend) in [(18, 100), (19, 150_000)] {
+            publish(
+                &repository,
+                NaiveDate::from_ymd_opt(2024, 1, day).unwrap(),
+                vec![FullPriceGuide::from_values(
+                    id_produit,
+                    (10, trend, 20),
+                    (100, 150, 200),
+                )],
+            )
+            .await;
+        }
+        quarantine_price(&pool, id_produit as i32, quarantined_day).await;
+
+        let result = repository
+            .find_reference_trends(date, 7, vec![id_produit])
+            .await
+            .unwrap();
+
+        assert_eq!(result.len(), 1);
+        assert_eq!(result[0].normal_trend.value, Some(100));
+    }
+
+    #[sqlx::test]
+    async fn find_reference_trends_only_returns_requested_products(pool: Pool<Postgres>) {
+        let repository = CardMarketPriceRepositoryAdapter::new(pool.clone());
+        let date = NaiveDate::from_ymd_opt(2024, 1, 20).unwrap();
+
+        publish(
+            &repository,
+            NaiveDate::from_ymd_opt(2024, 1, 19).unwrap(),
+            vec![
+                FullPriceGuide::from_values(20008, (10, 100, 20), (100, 150, 200)),
+                FullPriceGuide::from_values(20009, (10, 200, 20), (100, 150, 200)),
+            ],
+        )
+        .await;
+
+        let result = repository
+            .find_reference_trends(date, 7, vec![20009])
+            .await
+            .unwrap();
+
+        assert_eq!(result.len(), 1);
+        assert_eq!(result[0].id_product, 20009);
+    }
+
+    #[sqlx::test]
+    async fn publish_staged_saves_staged_prices_at_date(pool: Pool<Postgres>) {
+        let repository = CardMarketPriceRepositoryAdapter::new(pool.clone());
+        let date = NaiveDate::from_ymd_opt(2024, 1, 15).unwrap();
+
+        repository
+            .stage(vec![FullPriceGuide::from_values(
+                20010,
+                (10, 15, 12),
+                (20, 25, 22),
+            )])
+            .await
+            .unwrap();
+        repository
+            .stage(vec![FullPriceGuide::from_values(
+                20011,
+                (30, 35, 32),
+                (40, 45, 42),
+            )])
+            .await
+            .unwrap();
+
+        let before_publish = repository.find_by_id_and_date(20010, date).await.unwrap();
+        assert!(before_publish.is_none());
+
+        repository
+            .publish_staged(date, PriceImportScope::All, vec![])
+            .await
+            .unwrap();
+
+        let first = fetch_cardmarket_price(&pool, 20010, date).await;
+        assert_eq!(first.normal.trend, Some(15));
+        assert_eq!(first.foil.avg, Some(22));
+        let second = fetch_cardmarket_price(&pool, 20011, date).await;
+        assert_eq!(second.normal.low, Some(30));
+
+        // la zone de transit est vidée après publication
+        let next_date = NaiveDate::from_ymd_opt(2024, 1, 16).unwrap();
+        repository
+            .publish_staged(next_date, PriceImportScope::All, vec![])
+            .await
+            .unwrap();
+        let republished = repository
+            .find_by_id_and_date(20010, next_date)
+            .await
+            .unwrap();
+        assert!(republished.is_none());
+    }
+
+    #[sqlx::test]
+    async fn lock_staging_is_refused_until_the_holder_unlocks(pool: Pool<Postgres>) {
+        let first_import = CardMarketPriceRepositoryAdapter::new(pool.clone());
+        let second_import = CardMarketPriceRepositoryAdapter::new(pool.clone());
+
+        assert!(first_import.lock_staging().await.unwrap());
+        assert!(!second_import.lock_staging().await.unwrap());
+
+        first_import.unlock_staging().await.unwrap();
+
+        assert!(second_import.lock_staging().await.unwrap());
+        second_import.unlock_staging().await.unwrap();
+    }
+
+    #[sqlx::test]
+    async fn discard_staged_drops_staged_prices(pool: Pool<Postgres>) {
+        let repository = CardMarketPriceRepositoryAdapter::new(pool.clone());
+        let date = NaiveDate::from_ymd_opt(2024, 1, 15).unwrap();
+
+        repository
+            .stage(vec![FullPriceGuide::from_values(
+                20012,
+                (10, 15, 12),
+                (20, 25, 22),
+            )])
+            .await
+            .unwrap();
+
+        repository.discard_staged().await.unwrap();
+        repository
+            .publish_staged(date, PriceImportScope::All, vec![])
+            .await
+            .unwrap();
+
+        let published = repository.find_by_id_and_date(20012, date).await.unwrap();
+        assert!(published.is_none());
+    }
+
+    #[sqlx::test]
+    async fn publish_staged_quarantines_the_anomalies_with_the_prices(pool: Pool<Postgres>) {
+        let repository = CardMarketPriceRepositoryAdapter::new(pool.clone());
+        let date = NaiveDate::from_ymd_opt(2024, 1, 15).unwrap();
+
+        repository
+            .stage(vec![
+                FullPriceGuide::from_values(20013, (10, 15, 12), (20, 25, 22)),
+                FullPriceGuide::from_values(20014, (10, 15, 12), (20, 25, 22)),
+            ])
+            .await
+            .unwrap();
+        repository
+            .publish_staged(
+                date,
+                PriceImportScope::All,
+                vec![PriceAnomaly {
+                    id_product: 20013,
+                    date,
+                    foil: false,
+                    kind: PriceAnomalyKind::Spike,
+                    reference: 1,
+                    observed: 15,
+                    detected_at: Utc::now(),
+                }],
+            )
+            .await
+            .unwrap();
+
+        assert!(fetch_quarantined(&pool, 20013, date, false).await);
+        assert!(!fetch_quarantined(&pool, 20013, date, true).await);
+        assert!(!fetch_quarantined(&pool, 20014, date, false).await);
+    }
+
+    #[sqlx::test]
+    async fn publish_staged_keeps_category_of_the_price(pool: Pool<Postgres>) {
+        let repository = CardMarketPriceRepositoryAdapter::new(pool.clone());
+        let date = NaiveDate::from_ymd_opt(2024, 1, 15).unwrap();
+
+        repository
+            .stage(vec![FullPriceGuide {
+                id_category: Some(SINGLES_CATEGORY),
+                ..FullPriceGuide::from_values(20020, (10, 15, 12), (20, 25, 22))
+            }])
+            .await
+            .unwrap();
+        repository
+            .publish_staged(date, PriceImportScope::All, vec![])
+            .await
+            .unwrap();
+
+        let record = fetch_cardmarket_price(&pool, 20020, date).await;
+        assert_eq!(record.id_category, Some(SINGLES_CATEGORY as i32));
+    }
+
+    #[sqlx::test]
+    async fn publish_staged_only_saves_singles_with_singles_scope(pool: Pool<Postgres>) {
+        let repository = CardMarketPriceRepositoryAdapter::new(pool.clone());
+        let date = NaiveDate::from_ymd_opt(2024, 1, 15).unwrap();
+
+        repository
+            .stage(vec![
+                FullPriceGuide {
+                    id_category: Some(SINGLES_CATEGORY),
+                    ..FullPriceGuide::from_values(20021, (10, 15, 12), (20, 25, 22))
+                },
+                FullPriceGuide {
+                    id_category: Some(6),
+                    ..FullPriceGuide::from_values(20022, (10, 15, 12), (20, 25, 22))
+                },
+                FullPriceGuide::from_values(20023, (10, 15, 12), (20, 25, 22)),
+            ])
+            .await
+            .unwrap();
+        repository
+            .publish_staged(date, PriceImportScope::Singles, vec![])
+            .await
+            .unwrap();
+
+        let single = repository.find_by_id_and_date(20021, date).await.unwrap();
+        assert!(single.is_some());
+        let sealed = repository.find_by_id_and_date(20022, date).await.unwrap();
+        assert!(sealed.is_none());
+        let unknown = repository.find_by_id_and_date(20023, date).await.unwrap();
+        assert!(unknown.is_none());
+    }
+
+    #[sqlx::test]
+    async fn publish_staged_only_saves_referenced_products_with_referenced_scope(
+        pool: Pool<Postgres>,
+    ) {
+        let repository = CardMarketPriceRepositoryAdapter::new(pool.clone());
+        let date = NaiveDate::from_ymd_opt(2024, 1, 15).unwrap();
+        insert_card(&pool, "FDN", "1", "FR", false, "Card 1", 20024).await;
+
+        repository
+            .stage(vec![
+                FullPriceGuide::from_values(20024, (10, 15, 12), (20, 25, 22)),
+                FullPriceGuide::from_values(20025, (10, 15, 12), (20, 25, 22)),
+            ])
+            .await
+            .unwrap();
+        repository
+            .publish_staged(date, PriceImportScope::Referenced, vec![])
+            .await
+            .unwrap();
+
+        let referenced = repository.find_by_id_and_date(20024, date).await.unwrap();
+        assert!(referenced.is_some());
+        let unreferenced = repository.find_by_id_and_date(20025, date).await.unwrap();
+        assert!(unreferenced.is_none());
+    }
+
+    #[sqlx::test]
+    async fn backfill_from_latest_guide_saves_skipped_product(pool: Pool<Postgres>) {
+        let repository = CardMarketPriceRepositoryAdapter::new(pool.clone());
+        let date = NaiveDate::from_ymd_opt(2024, 1, 15).unwrap();
+
+        repository
+            .stage(vec![FullPriceGuide::from_values(
+                20026,
+                (10, 15, 12),
+                (20, 25, 22),
+            )])
+            .await
+            .unwrap();
+        repository
+            .publish_staged(date, PriceImportScope::Referenced, vec![])
+            .await
+            .unwrap();
+        assert!(
+            repository
+                .find_by_id_and_date(20026, date)
+                .await
+                .unwrap()
+                .is_none()
+        );
+
+        repository.backfill_from_latest_guide(20026).await.unwrap();
+
+        let record = fetch_cardmarket_price(&pool, 20026, date).await;
+        assert_eq!(record.normal.trend, Some(15));
+        assert_eq!(record.foil.avg, Some(22));
+    }
+
+    #[sqlx::test]
+    async fn backfill_from_latest_guide_keeps_existing_price(pool: Pool<Postgres>) {
+        let repository = CardMarketPriceRepositoryAdapter::new(pool.clone());
+        let date = NaiveDate::from_ymd_opt(2024, 1, 15).unwrap();
+
+        repository
+            .stage(vec![FullPriceGuide::from_values(
+                20027,
+                (10, 15, 12),
+                (20, 25, 22),
+            )])
+            .await
+            .unwrap();
+        repository
+            .publish_staged(date, PriceImportScope::Referenced, vec![])
+            .await
+            .unwrap();
+        insert_price(
+            &pool,
+            CardMarketPriceEntity {
+                id_produit: 20027,
+                date,
+                id_category: None,
+                normal: PriceGuideEntity {
+                    low: Some(30),
+                    trend: Some(35),
+                    avg: Some(32),
+                },
+                foil: PriceGuideEntity::empty(),
+            },
+        )
+        .await;
+
+        repository.backfill_from_latest_guide(20027).await.unwrap();
+
+        let record = fetch_cardmarket_price(&pool, 20027, date).await;
+        assert_eq!(record.normal.trend, Some(35));
+    }
+
+    #[sqlx::test]
+    async fn find_referenced_products_returns_products_of_cards(pool: Pool<Postgres>) {
+        let repository = CardMarketPriceRepositoryAdapter::new(pool.clone());
+        insert_card(&pool, "FDN", "1", "FR", false, "Card 1", 20028).await;
+        insert_card(&pool, "FDN", "1", "FR", true, "Card 1", 20028).await;
+        insert_card(&pool, "FDN", "2", "FR", false, "Card 2", 20029).await;
+
+        let referenced = repository
+            .find_referenced_products(vec![20028, 20030])
+            .await
+            .unwrap();
+
+        assert_eq!(referenced, vec![20028]);
     }
 }
@@ -1,8 +1,8 @@
 use crate::application::error::AppError;
 use crate::application::repository::CollectionPriceHistoryRepository;
-use crate::domain::price::PriceHistoryEntry;
+use crate::domain::price::{PriceHistoryEntry, PriceHistoryOptions};
 use crate::domain::user::{User, UserId};
-use crate::infrastructure::adapter_out::repository::entities::CollectionPriceHistoryEntity;
+use crate::infrastructure::adapter_out::repository::entities::PriceHistoryBucketEntity;
 use async_trait::async_trait;
 use chrono::NaiveDate;
 use sqlx::{Pool, Postgres};
@@ -48,14 +48,22 @@ impl CollectionPriceHistoryRepository for CollectionPriceHistoryRepositoryAdapte
                 FROM (SELECT ce.user_id,
                              ce.added_at,
                              cmp.date,
-                             CASE WHEN c.foil THEN cmp.low_foil ELSE cmp.low END * ce.quantity     AS low,
-                             CASE WHEN c.foil THEN cmp.avg_foil ELSE cmp.avg END * ce.quantity     AS avg,
-                             CASE WHEN c.foil THEN cmp.trend_foil ELSE cmp.trend END * ce.quantity AS trend
+                             CASE WHEN c.foil THEN sane.low_foil ELSE sane.low END * ce.quantity     AS low,
+                             CASE WHEN c.foil THEN sane.avg_foil ELSE sane.avg END * ce.quantity     AS avg,
+                             CASE WHEN c.foil THEN sane.trend_foil ELSE sane.trend END * ce.quantity AS trend
                        FROM card c
                                JOIN collection_entry ce
                                     ON c.set_code = ce.set_code AND c.collector_number = ce.collector_number AND
                                        c.language_code = ce.language_code AND c.foil = ce.foil
-                               JOIN cardmarket_price cmp ON cardmarket_id = cmp.id_produit) AS prices
+                               JOIN cardmarket_price cmp ON cardmarket_id = cmp.id_produit
+                               -- a quarantined price is replaced by the last sane one of the product finish
+                               JOIN LATERAL (SELECT p.low, p.avg, p.trend, p.low_foil, p.avg_foil, p.trend_foil
+                                             FROM cardmarket_price p
+                                             WHERE p.id_produit = cmp.id_produit
+                                               AND p.date <= cmp.date
+                                               AND NOT CASE WHEN c.foil THEN p.quarantined_foil ELSE p.quarantined END
+                                             ORDER BY p.date DESC
+                                             LIMIT 1) sane ON TRUE) AS prices
                 WHERE prices.user_id = $1
                   AND prices.date = $2
                   AND CAST(prices.added_at AS DATE) <= to_timestamp(prices.date::text, 'YYYY-MM-DD')
@@ -78,18 +86,39 @@ impl CollectionPriceHistoryRepository for CollectionPriceHistoryRepositoryAdapte
         user_id: &UserId,
         start_date: NaiveDate,
         end_date: NaiveDate,
+        options: PriceHistoryOptions,
     ) -> Result<Vec<PriceHistoryEntry>, AppError> {
+        // Les moyennes mobiles ont besoin des 29 jours précédant le début de la période
         let entities = sqlx::query_as!(
-            CollectionPriceHistoryEntity,
-            r#"SELECT date, low, trend, avg
-                FROM collection_price_history
-                WHERE user_id = $1
-                  AND date >= $2
-                  AND date <= $3
-                ORDER BY date"#,
+            PriceHistoryBucketEntity,
+            r#"WITH daily AS (SELECT date,
+                                     low,
+                                     trend,
+                                     avg,
+                                     AVG(trend) OVER (ORDER BY date RANGE BETWEEN INTERVAL '6 days' PRECEDING AND CURRENT ROW)  AS ma7,
+                                     AVG(trend) OVER (ORDER BY date RANGE BETWEEN INTERVAL '29 days' PRECEDING AND CURRENT ROW) AS ma30
+                              FROM collection_price_history
+                              WHERE user_id = $1
+                                AND date >= $2::date - CASE WHEN $5::boolean THEN 29 ELSE 0 END
+                                AND date <= $3)
+               SELECT date_trunc($4, date::timestamp)::date                                        AS "date!",
+                      (array_agg(low ORDER BY date DESC))[1]                                       AS "low",
+                      (array_agg(trend ORDER BY date DESC))[1]                                     AS "trend",
+                      (array_agg(avg ORDER BY date DESC))[1]                                       AS "avg",
+                      (array_agg(trend ORDER BY date))[1]                                          AS "trend_open",
+                      MAX(trend)                                                                   AS "trend_high",
+                      MIN(trend)                                                                   AS "trend_low",
+                      CASE WHEN $5 THEN ROUND((array_agg(ma7 ORDER BY date DESC))[1])::integer END  AS "trend_ma7",
+                      CASE WHEN $5 THEN ROUND((array_agg(ma30 ORDER BY date DESC))[1])::integer END AS "trend_ma30"
+               FROM daily
+               WHERE date >= $2
+               GROUP BY 1
+               ORDER BY 1"#,
             user_id.as_str(),
             start_date,
             end_date,
+            options.granularity.as_date_trunc_unit(),
+            options.moving_averages,
         )
         .fetch_all(&self.pool)
         .await?;
@@ -101,9 +130,10 @@ impl CollectionPriceHistoryRepository for CollectionPriceHistoryRepositoryAdapte
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::domain::price::PriceHistoryGranularity;
     use crate::infrastructure::adapter_out::repository::common_repository_tests::{
         fetch_collection_price_history, insert_card, insert_collection_entry,
-        insert_collection_price_history, insert_price, insert_set,
+        insert_collection_price_history, insert_price, insert_set, quarantine_price,
     };
     use crate::infrastructure::adapter_out::repository::entities::{
         CardMarketPriceEntity, PriceGuideEntity,
@@ -131,6 +161,7 @@ mod tests {
             CardMarketPriceEntity {
                 id_produit: 1,
                 date: NaiveDate::from_ymd_opt(2025, 12, 25).unwrap(),
+                id_category: None,
                 normal: PriceGuideEntity {
                     low: Some(10),
                     avg: Some(20),
@@ -162,6 +193,7 @@ mod tests {
             CardMarketPriceEntity {
                 id_produit: 1,
                 date,
+                id_category: None,
                 normal: PriceGuideEntity {
                     low: Some(10),
                     avg: Some(20),
@@ -197,6 +229,7 @@ mod tests {
             CardMarketPriceEntity {
                 id_produit: 1,
                 date: date1,
+                id_category: None,
                 normal: PriceGuideEntity {
                     low: Some(10),
                     avg: Some(20),
@@ -211,6 +244,7 @@ mod tests {
             CardMarketPriceEntity {
                 id_produit: 1,
                 date: date2,
+                id_category: None,
                 normal: PriceGuideEntity {
                     low: Some(12),
                     avg: Some(22),
@@ -243,6 +277,7 @@ mod tests {
             CardMarketPriceEntity {
                 id_produit: 5,
                 date,
+                id_category: None,
                 normal: PriceGuideEntity {
                     low: Some(10),
                     avg: Some(20),
@@ -265,4 +300,118 @@ mod tests {
         assert_eq!(rows_user1[0].low, 20i32); // 10 * 2
         assert_eq!(rows_user2.len(), 0);
     }
+
+    #[sqlx::test]
+    async fn update_for_date_and_user_replaces_quarantined_price_by_last_sane_one(pool: PgPool) {
+        let adapter = CollectionPriceHistoryRepositoryAdapter::new(pool.clone());
+        let user1 = User::from_id(UserId::new("user1"));
+        let previous_day = NaiveDate::from_ymd_opt(2025, 12, 24).unwrap();
+        let date = NaiveDate::from_ymd_opt(2025, 12, 25).unwrap();
+
+        let added_at = previous_day.and_hms_opt(0, 0, 0).unwrap().and_utc();
+
+        insert_set(&pool, "SET7").await;
+        insert_card(&pool, "SET7", "1", "EN", false, "Card 1", 6).await;
+        insert_collection_entry(&pool, "SET7", "1", "EN", false, "user1", 2, 100, added_at).await;
+        for (day, trend) in [(previous_day, 15), (date, 150_000)] {
+            insert_price(
+                &pool,
+                CardMarketPriceEntity {
+                    id_produit: 6,
+                    date: day,
+                    id_category: None,
+                    normal: PriceGuideEntity {
+                        low: Some(10),
+                        avg: Some(20),
+                        trend: Some(trend),
+                    },
+                    foil: PriceGuideEntity::empty(),
+                },
+            )
+            .await;
+        }
+        quarantine_price(&pool, 6, date).await;
+
+        adapter.update_for_date_and_user(date, user1).await.unwrap();
+
+        let rows = fetch_collection_price_history(&pool, date, "user1").await;
+
+        assert_eq!(rows.len(), 1);
+        assert_eq!(rows[0].trend, 30i32); // 15 * 2, the spike is ignored
+    }
+
+    #[sqlx::test]
+    async fn get_price_history_returns_one_entry_per_day_by_default(pool: PgPool) {
+        let repository = CollectionPriceHistoryRepositoryAdapter::new(pool.clone());
+        let day1 = NaiveDate::from_ymd_opt(2025, 3, 1).unwrap();
+        let day2 = NaiveDate::from_ymd_opt(2025, 3, 2).unwrap();
+        insert_collection_price_history(&pool, day1, "user1", 100, 150, 200).await;
+        insert_collection_price_history(&pool, day2, "user1", 110, 160, 210).await;
+        insert_collection_price_history(&pool, day2, "user2", 1, 1, 1).await;
+
+        let entries = repository
+            .get_price_history(
+                &UserId::new("user1"),
+                day1,
+                day2,
+                PriceHistoryOptions::default(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(entries.len(), 2);
+        assert_eq!(entries[0].date, day1);
+        assert_eq!(entries[0].price_guide.low.value, Some(100));
+        assert_eq!(entries[0].price_guide.avg.value, Some(150));
+        assert_eq!(entries[0].price_guide.trend.value, Some(200));
+        assert_eq!(entries[0].trend_open.value, Some(200));
+        assert_eq!(entries[0].trend_ma30.value, None);
+        assert_eq!(entries[1].date, day2);
+        assert_eq!(entries[1].price_guide.trend.value, Some(210));
+    }
+
+    #[sqlx::test]
+    async fn get_price_history_aggregates_months_with_moving_averages(pool: PgPool) {
+        let repository = CollectionPriceHistoryRepositoryAdapter::new(pool.clone());
+        // Du 20 janvier au 10 février, tendance croissante de 10 en 10
+        let first_day = NaiveDate::from_ymd_opt(2025, 1, 20).unwrap();
+        for i in 0..22 {
+            let date = first_day + chrono::Days::new(i as u64);
+            insert_collection_price_history(&pool, date, "user1", 1, 1, (i + 1) * 10).await;
+        }
+
+        let entries = repository
+            .get_price_history(
+                &UserId::new("user1"),
+                NaiveDate::from_ymd_opt(2025, 1, 25).unwrap(),
+                NaiveDate::from_ymd_opt(2025, 2, 10).unwrap(),
+                PriceHistoryOptions {
+                    granularity: PriceHistoryGranularity::Month,
+                    moving_averages: true,
+                },
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(entries.len(), 2);
+        assert_eq!(
+            entries[0].date,
+            NaiveDate::from_ymd_opt(2025, 1, 1).unwrap()
+        );
+        assert_eq!(entries[0].trend_open.value, Some(60));
+        assert_eq!(entries[0].trend_high.value, Some(120));
+        assert_eq!(entries[0].trend_low.value, Some(60));
+        assert_eq!(entries[0].price_guide.trend.value, Some(120));
+        assert_eq!(entries[0].trend_ma7.value, Some(90));
+        // Seuls 12 jours d'historique existent avant le 31 janvier
+        assert_eq!(entries[0].trend_ma30.value, Some(65));
+        assert_eq!(
+            entries[1].date,
+            NaiveDate::from_ymd_opt(2025, 2, 1).unwrap()
+        );
+        assert_eq!(entries[1].trend_open.value, Some(130));
+        assert_eq!(entries[1].price_guide.trend.value, Some(220));
+        assert_eq!(entries[1].trend_ma7.value, Some(190));
+        assert_eq!(entries[1].trend_ma30.value, Some(115));
+    }
 }
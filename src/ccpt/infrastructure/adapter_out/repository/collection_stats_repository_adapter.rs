@@ -1,5 +1,6 @@
 use crate::application::error::AppError;
 use crate::application::repository::CollectionStatsRepository;
+use crate::domain::binder::BinderId;
 use crate::domain::collection_stats::CollectionStats;
 use crate::domain::price::Price;
 use crate::domain::set_name::{SetCode, SetName};
@@ -19,16 +20,28 @@ impl CollectionStatsRepositoryAdapter {
 
 #[async_trait]
 impl CollectionStatsRepository for CollectionStatsRepositoryAdapter {
-    async fn get_collection_stats(&self, user_id: &UserId) -> Result<CollectionStats, AppError> {
+    async fn get_collection_stats(
+        &self,
+        user_id: &UserId,
+        binder_id: Option<BinderId>,
+        tags: Vec<String>,
+    ) -> Result<CollectionStats, AppError> {
+        let binder_id = binder_id.map(|id| id.0);
+        // Une impression rangée dans plusieurs classeurs ne compte qu'une fois
         let totals = sqlx::query!(
             r#"
             SELECT
                 COALESCE(SUM(ce.quantity), 0)::BIGINT AS "total_cards!",
-                COUNT(*)::BIGINT                       AS "unique_cards!"
+                COUNT(DISTINCT (ce.set_code, ce.collector_number, ce.language_code, ce.foil))::BIGINT
+                                                       AS "unique_cards!"
             FROM collection_entry ce
             WHERE ce.user_id = $1
+              AND ($2::uuid IS NULL OR ce.binder_id = $2)
+              AND ce.tags @> $3::varchar[]
             "#,
-            user_id.as_str()
+            user_id.as_str(),
+            binder_id,
+            &tags
         )
         .fetch_one(&self.pool)
         .await?;
@@ -39,15 +52,20 @@ impl CollectionStatsRepository for CollectionStatsRepositoryAdapter {
                 MIN(cp.trend)::INT AS price_trend_min,
                 MAX(cp.trend)::INT AS price_trend_max
             FROM collection_entry ce
-            LEFT JOIN mv_card_prices cp
+            LEFT JOIN card_prices cp
                 ON  cp.set_code         = ce.set_code
                 AND cp.collector_number = ce.collector_number
                 AND cp.language_code    = ce.language_code
                 AND cp.foil             = ce.foil
                 AND cp.user_id          = ce.user_id
+                AND cp.binder_id        = ce.binder_id
             WHERE ce.user_id = $1
+              AND ($2::uuid IS NULL OR ce.binder_id = $2)
+              AND ce.tags @> $3::varchar[]
             "#,
-            user_id.as_str()
+            user_id.as_str(),
+            binder_id,
+            &tags
         )
         .fetch_one(&self.pool)
         .await?;
@@ -63,9 +81,29 @@ impl CollectionStatsRepository for CollectionStatsRepositoryAdapter {
                 AND c.foil             = ce.foil
             JOIN set_name sn ON sn.set_code = c.set_code
             WHERE ce.user_id = $1
+              AND ($2::uuid IS NULL OR ce.binder_id = $2)
+              AND ce.tags @> $3::varchar[]
             ORDER BY sn.name
             "#,
-            user_id.as_str()
+            user_id.as_str(),
+            binder_id,
+            &tags
+        )
+        .fetch_all(&self.pool)
+        .await?;
+
+        // Toutes les étiquettes du classeur, quel que soit le filtre, pour pouvoir en choisir d'autres
+        let all_tags = sqlx::query_scalar!(
+            r#"
+            SELECT DISTINCT tag AS "tag!"
+            FROM collection_entry ce
+            CROSS JOIN LATERAL UNNEST(ce.tags) AS tag
+            WHERE ce.user_id = $1
+              AND ($2::uuid IS NULL OR ce.binder_id = $2)
+            ORDER BY 1
+            "#,
+            user_id.as_str(),
+            binder_id
         )
         .fetch_all(&self.pool)
         .await?;
@@ -85,6 +123,7 @@ impl CollectionStatsRepository for CollectionStatsRepositoryAdapter {
                 .into_iter()
                 .map(|r| SetName::new(SetCode::new(r.set_code), r.name))
                 .collect(),
+            tags: all_tags,
         })
     }
 }
@@ -93,7 +132,8 @@ impl CollectionStatsRepository for CollectionStatsRepositoryAdapter {
 mod tests {
     use super::*;
     use crate::infrastructure::adapter_out::repository::common_repository_tests::{
-        insert_card_without_cardmarket_id, insert_collection_entry, insert_set,
+        insert_binder, insert_binder_entry, insert_card_without_cardmarket_id,
+        insert_collection_entry, insert_set,
     };
     use chrono::Utc;
     use sqlx::PgPool;
@@ -102,7 +142,7 @@ mod tests {
     async fn returns_zeros_for_empty_collection(pool: PgPool) {
         let adapter = CollectionStatsRepositoryAdapter::new(pool);
         let result = adapter
-            .get_collection_stats(&UserId::new("unknown-user"))
+            .get_collection_stats(&UserId::new("unknown-user"), None, vec![])
             .await;
         assert!(result.is_ok());
         let stats = result.unwrap();
@@ -111,6 +151,7 @@ mod tests {
         assert!(stats.price_trend_min.value.is_none());
         assert!(stats.price_trend_max.value.is_none());
         assert!(stats.sets.is_empty());
+        assert!(stats.tags.is_empty());
     }
 
     #[sqlx::test]
@@ -122,7 +163,9 @@ mod tests {
         insert_collection_entry(&pool, "TST", "2", "en", false, "user-1", 2, 200, Utc::now()).await;
 
         let adapter = CollectionStatsRepositoryAdapter::new(pool);
-        let result = adapter.get_collection_stats(&UserId::new("user-1")).await;
+        let result = adapter
+            .get_collection_stats(&UserId::new("user-1"), None, vec![])
+            .await;
         assert!(result.is_ok());
         let stats = result.unwrap();
         assert_eq!(stats.total_cards, 5);
@@ -150,10 +193,96 @@ mod tests {
         .await;
 
         let adapter = CollectionStatsRepositoryAdapter::new(pool);
-        let result = adapter.get_collection_stats(&UserId::new("user-1")).await;
+        let result = adapter
+            .get_collection_stats(&UserId::new("user-1"), None, vec![])
+            .await;
         assert!(result.is_ok());
         let stats = result.unwrap();
         assert_eq!(stats.total_cards, 0);
         assert_eq!(stats.unique_cards, 0);
     }
+
+    #[sqlx::test]
+    async fn counts_a_printing_once_across_binders_or_only_the_given_binder(pool: PgPool) {
+        insert_set(&pool, "TST").await;
+        insert_card_without_cardmarket_id(&pool, "TST", "1", "en", false, "Card A").await;
+        insert_card_without_cardmarket_id(&pool, "TST", "2", "en", false, "Card B").await;
+        insert_collection_entry(&pool, "TST", "1", "en", false, "user-1", 3, 100, Utc::now()).await;
+        let deck_box = insert_binder(&pool, "user-1", "Deck box", false).await;
+        insert_binder_entry(
+            &pool,
+            deck_box,
+            "TST",
+            "1",
+            "en",
+            false,
+            "user-1",
+            1,
+            100,
+            Utc::now(),
+        )
+        .await;
+        insert_binder_entry(
+            &pool,
+            deck_box,
+            "TST",
+            "2",
+            "en",
+            false,
+            "user-1",
+            2,
+            100,
+            Utc::now(),
+        )
+        .await;
+
+        let adapter = CollectionStatsRepositoryAdapter::new(pool);
+        let all = adapter
+            .get_collection_stats(&UserId::new("user-1"), None, vec![])
+            .await
+            .unwrap();
+        let in_deck_box = adapter
+            .get_collection_stats(&UserId::new("user-1"), Some(BinderId(deck_box)), vec![])
+            .await
+            .unwrap();
+
+        assert_eq!((all.total_cards, all.unique_cards), (6, 2));
+        assert_eq!((in_deck_box.total_cards, in_deck_box.unique_cards), (3, 2));
+    }
+
+    #[sqlx::test]
+    async fn filters_on_every_given_tag_but_lists_all_the_tags(pool: PgPool) {
+        insert_set(&pool, "TST").await;
+        insert_card_without_cardmarket_id(&pool, "TST", "1", "en", false, "Card A").await;
+        insert_card_without_cardmarket_id(&pool, "TST", "2", "en", false, "Card B").await;
+        insert_collection_entry(&pool, "TST", "1", "en", false, "user-1", 3, 100, Utc::now()).await;
+        insert_collection_entry(&pool, "TST", "2", "en", false, "user-1", 2, 100, Utc::now()).await;
+        sqlx::query(
+            "UPDATE collection_entry SET tags = CASE collector_number WHEN '1' THEN '{cube,foil}'::varchar[] ELSE '{cube}' END",
+        )
+        .execute(&pool)
+        .await
+        .unwrap();
+
+        let adapter = CollectionStatsRepositoryAdapter::new(pool);
+        let cube = adapter
+            .get_collection_stats(&UserId::new("user-1"), None, vec!["cube".to_string()])
+            .await
+            .unwrap();
+        let cube_and_foil = adapter
+            .get_collection_stats(
+                &UserId::new("user-1"),
+                None,
+                vec!["cube".to_string(), "foil".to_string()],
+            )
+            .await
+            .unwrap();
+
+        assert_eq!((cube.total_cards, cube.unique_cards), (5, 2));
+        assert_eq!(
+            (cube_and_foil.total_cards, cube_and_foil.unique_cards),
+            (3, 1)
+        );
+        assert_eq!(cube_and_foil.tags, vec!["cube", "foil"]);
+    }
 }
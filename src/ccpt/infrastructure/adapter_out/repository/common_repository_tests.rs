@@ -79,10 +79,40 @@ pub async fn insert_collection_entry(
     quantity: i32,
     purchase_price: i32,
     date: chrono::DateTime<chrono::Utc>,
+) {
+    let binder_id = insert_default_binder(pool, user_id).await;
+    insert_binder_entry(
+        pool,
+        binder_id,
+        set_code,
+        collector_number,
+        language_code,
+        foil,
+        user_id,
+        quantity,
+        purchase_price,
+        date,
+    )
+    .await;
+}
+
+#[allow(clippy::too_many_arguments)]
+pub async fn insert_binder_entry(
+    pool: &PgPool,
+    binder_id: Uuid,
+    set_code: &str,
+    collector_number: &str,
+    language_code: &str,
+    foil: bool,
+    user_id: &str,
+    quantity: i32,
+    purchase_price: i32,
+    date: chrono::DateTime<chrono::Utc>,
 ) {
     sqlx::query(
-        r#"INSERT INTO collection_entry (set_code, collector_number, language_code, foil, user_id, quantity, purchase_price, added_at)
-            VALUES ($1, $2, $3, $4, $5, $6, $7, $8)"#)
+        r#"INSERT INTO collection_entry (binder_id, set_code, collector_number, language_code, foil, user_id, quantity, purchase_price, added_at)
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9)"#)
+        .bind(binder_id)
         .bind(set_code)
         .bind(collector_number)
         .bind(language_code)
@@ -96,6 +126,53 @@ pub async fn insert_collection_entry(
     .unwrap();
 }
 
+/// The user's default binder, created tradable if needed.
+pub async fn insert_default_binder(pool: &PgPool, user_id: &str) -> Uuid {
+    sqlx::query_scalar(
+        r#"INSERT INTO binder (id, user_id, name, tradable, is_default)
+            VALUES ($1, $2, 'Collection', TRUE, TRUE)
+            ON CONFLICT (user_id) WHERE is_default DO UPDATE SET is_default = TRUE
+            RETURNING id"#,
+    )
+    .bind(Uuid::new_v4())
+    .bind(user_id)
+    .fetch_one(pool)
+    .await
+    .unwrap()
+}
+
+pub async fn insert_binder(pool: &PgPool, user_id: &str, name: &str, tradable: bool) -> Uuid {
+    let id = Uuid::new_v4();
+    sqlx::query("INSERT INTO binder (id, user_id, name, tradable) VALUES ($1, $2, $3, $4)")
+        .bind(id)
+        .bind(user_id)
+        .bind(name)
+        .bind(tradable)
+        .execute(pool)
+        .await
+        .unwrap();
+    id
+}
+
+pub async fn set_keep_quantity(pool: &PgPool, binder_id: Uuid, keep_quantity: i32) {
+    sqlx::query("UPDATE binder SET keep_quantity = $1 WHERE id = $2")
+        .bind(keep_quantity)
+        .bind(binder_id)
+        .execute(pool)
+        .await
+        .unwrap();
+}
+
+/// Sets the tradable quantity of every entry of the binder.
+pub async fn set_tradable_quantity(pool: &PgPool, binder_id: Uuid, tradable_quantity: i32) {
+    sqlx::query("UPDATE collection_entry SET tradable_quantity = $1 WHERE binder_id = $2")
+        .bind(tradable_quantity)
+        .bind(binder_id)
+        .execute(pool)
+        .await
+        .unwrap();
+}
+
 #[allow(clippy::too_many_arguments)]
 pub async fn insert_card_with_scryfall_id(
     pool: &PgPool,
@@ -147,13 +224,48 @@ pub async fn insert_card_without_cardmarket_id(
     .unwrap();
 }
 
+/// Gives every language and finish of the printing `set_code`/`collector_number` the
+/// `oracle_id`, as Scryfall enrichment would.
+pub async fn set_oracle_id(pool: &PgPool, set_code: &str, collector_number: &str, oracle_id: Uuid) {
+    sqlx::query("UPDATE card SET oracle_id = $1 WHERE set_code = $2 AND collector_number = $3")
+        .bind(oracle_id)
+        .bind(set_code)
+        .bind(collector_number)
+        .execute(pool)
+        .await
+        .unwrap();
+}
+
+/// Adds a printing to the Scryfall catalogue, as its daily import would.
+pub async fn insert_scryfall_card(
+    pool: &PgPool,
+    name: &str,
+    set_code: &str,
+    collector_number: &str,
+    oracle_id: Uuid,
+) {
+    sqlx::query(
+        r#"INSERT INTO scryfall_card (scryfall_id, name, set_code, collector_number, lang, oracle_id)
+           VALUES ($1, $2, $3, $4, 'en', $5)"#,
+    )
+    .bind(Uuid::new_v4())
+    .bind(name)
+    .bind(set_code)
+    .bind(collector_number)
+    .bind(oracle_id)
+    .execute(pool)
+    .await
+    .unwrap();
+}
+
 pub async fn insert_price(pool: &PgPool, entity: CardMarketPriceEntity) {
     sqlx::query(
-        r#"INSERT INTO cardmarket_price (id_produit, date, low, avg, trend, low_foil, avg_foil, trend_foil)
-           VALUES ($1, $2, $3, $4, $5, $6, $7, $8)"#,
+        r#"INSERT INTO cardmarket_price (id_produit, date, id_category, low, avg, trend, low_foil, avg_foil, trend_foil)
+           VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9)"#,
     )
     .bind(entity.id_produit)
     .bind(entity.date)
+    .bind(entity.id_category)
     .bind(entity.normal.low)
     .bind(entity.normal.avg)
     .bind(entity.normal.trend)
@@ -194,7 +306,7 @@ pub async fn fetch_cardmarket_price(
 ) -> CardMarketPriceEntity {
     sqlx::query_as!(
         CardMarketPriceRaw,
-        "SELECT id_produit, date, low, trend, avg,
+        "SELECT id_produit, date, id_category, low, trend, avg,
                     low_foil, trend_foil, avg_foil
              FROM cardmarket_price
              WHERE id_produit = $1 AND date = $2",
@@ -361,8 +473,40 @@ pub async fn insert_trade_card(
 }
 
 pub async fn refresh_view(pool: &PgPool) {
-    sqlx::query("REFRESH MATERIALIZED VIEW mv_card_prices")
+    sqlx::query("DELETE FROM card_prices")
+        .execute(pool)
+        .await
+        .unwrap();
+    sqlx::query("INSERT INTO card_prices SELECT * FROM card_prices_source")
         .execute(pool)
         .await
         .unwrap();
 }
+
+pub async fn fetch_quarantined(
+    pool: &PgPool,
+    id_produit: i32,
+    date: chrono::NaiveDate,
+    foil: bool,
+) -> bool {
+    sqlx::query_scalar::<_, bool>(
+        "SELECT CASE WHEN $3 THEN quarantined_foil ELSE quarantined END FROM cardmarket_price WHERE id_produit = $1 AND date = $2",
+    )
+    .bind(id_produit)
+    .bind(date)
+    .bind(foil)
+    .fetch_one(pool)
+    .await
+    .unwrap()
+}
+
+pub async fn quarantine_price(pool: &PgPool, id_produit: i32, date: chrono::NaiveDate) {
+    sqlx::query(
+        "UPDATE cardmarket_price SET quarantined = TRUE, quarantined_foil = TRUE WHERE id_produit = $1 AND date = $2",
+    )
+    .bind(id_produit)
+    .bind(date)
+    .execute(pool)
+    .await
+    .unwrap();
+}
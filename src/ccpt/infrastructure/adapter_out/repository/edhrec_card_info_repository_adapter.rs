@@ -0,0 +1,111 @@
+use crate::application::error::AppError;
+use crate::application::repository::EdhRecCardInfoRepository;
+use crate::domain::card::EdhRecCardInfo;
+use crate::infrastructure::adapter_out::repository::entities::EdhRecCardInfoEntity;
+use async_trait::async_trait;
+use sqlx::{Pool, Postgres};
+
+pub struct EdhRecCardInfoRepositoryAdapter {
+    pool: Pool<Postgres>,
+}
+
+impl EdhRecCardInfoRepositoryAdapter {
+    pub fn new(pool: Pool<Postgres>) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl EdhRecCardInfoRepository for EdhRecCardInfoRepositoryAdapter {
+    async fn find_by_name(&self, name: &str) -> Result<Option<EdhRecCardInfo>, AppError> {
+        Ok(sqlx::query_as!(
+            EdhRecCardInfoEntity,
+            "SELECT name, inclusion, potential_decks, rank, fetched_at
+             FROM edhrec_card_info
+             WHERE name = $1",
+            name
+        )
+        .fetch_optional(&self.pool)
+        .await?
+        .map(EdhRecCardInfo::from))
+    }
+
+    async fn save(&self, info: &EdhRecCardInfo) -> Result<(), AppError> {
+        sqlx::query!(
+            "INSERT INTO edhrec_card_info (name, inclusion, potential_decks, rank, fetched_at)
+             VALUES ($1, $2, $3, $4, $5)
+             ON CONFLICT(name)
+             DO UPDATE
+                SET inclusion = $2,
+                    potential_decks = $3,
+                    rank = $4,
+                    fetched_at = $5",
+            info.name,
+            info.info.inclusion as i32,
+            info.info.total_decks as i32,
+            info.info.rank.map(|rank| rank as i32),
+            info.fetched_at
+        )
+        .execute(&self.pool)
+        .await?;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::card::CardInfo;
+    use chrono::{TimeZone, Utc};
+    use sqlx::PgPool;
+
+    fn sol_ring(inclusion: u32, rank: Option<u32>) -> EdhRecCardInfo {
+        EdhRecCardInfo {
+            name: "Sol Ring".to_string(),
+            info: CardInfo {
+                inclusion,
+                total_decks: 100_000,
+                rank,
+            },
+            fetched_at: Utc.with_ymd_and_hms(2025, 3, 1, 12, 0, 0).unwrap(),
+        }
+    }
+
+    #[sqlx::test]
+    async fn find_by_name_returns_none_when_not_cached(pool: PgPool) {
+        let result = EdhRecCardInfoRepositoryAdapter::new(pool)
+            .find_by_name("Sol Ring")
+            .await
+            .unwrap();
+
+        assert_eq!(result, None);
+    }
+
+    #[sqlx::test]
+    async fn save_then_find_by_name_returns_saved_info(pool: PgPool) {
+        let repository = EdhRecCardInfoRepositoryAdapter::new(pool);
+        let info = sol_ring(85_000, None);
+
+        repository.save(&info).await.unwrap();
+
+        assert_eq!(
+            repository.find_by_name("Sol Ring").await.unwrap(),
+            Some(info)
+        );
+    }
+
+    #[sqlx::test]
+    async fn save_replaces_existing_info(pool: PgPool) {
+        let repository = EdhRecCardInfoRepositoryAdapter::new(pool);
+        repository.save(&sol_ring(85_000, None)).await.unwrap();
+
+        let refreshed = sol_ring(90_000, Some(1));
+        repository.save(&refreshed).await.unwrap();
+
+        assert_eq!(
+            repository.find_by_name("Sol Ring").await.unwrap(),
+            Some(refreshed)
+        );
+    }
+}
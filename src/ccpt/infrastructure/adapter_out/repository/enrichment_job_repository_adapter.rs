@@ -0,0 +1,583 @@
+use crate::application::error::AppError;
+use crate::application::repository::EnrichmentJobRepository;
+use crate::domain::card::CardId;
+use crate::domain::enrichment_job::{
+    EnrichmentJob, EnrichmentKind, EnrichmentOutcome, UnresolvedCard,
+};
+use crate::domain::worker_status::EnrichmentQueueDepth;
+use crate::infrastructure::adapter_out::repository::entities::{
+    EnrichmentJobEntity, UnresolvedCardEntity,
+};
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use sqlx::{Pool, Postgres, QueryBuilder};
+use std::time::Duration;
+
+pub struct EnrichmentJobRepositoryAdapter {
+    pool: Pool<Postgres>,
+}
+
+impl EnrichmentJobRepositoryAdapter {
+    pub fn new(pool: Pool<Postgres>) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl EnrichmentJobRepository for EnrichmentJobRepositoryAdapter {
+    async fn enqueue(
+        &self,
+        kind: EnrichmentKind,
+        card_ids: Vec<CardId>,
+    ) -> Result<usize, AppError> {
+        const CHUNK_SIZE: usize = 1000;
+
+        let mut enqueued = 0;
+        for chunk in card_ids.chunks(CHUNK_SIZE) {
+            let mut qb: QueryBuilder<Postgres> = QueryBuilder::new(
+                "
+                INSERT INTO enrichment_job
+                    (kind, set_code, collector_number, language_code, foil)
+                ",
+            );
+
+            qb.push_values(chunk, |mut b, card_id| {
+                b.push_bind(kind.as_db_str())
+                    .push_bind(card_id.set_code.to_string())
+                    .push_bind(&card_id.collector_number)
+                    .push_bind(card_id.language_code.to_string())
+                    .push_bind(card_id.foil);
+            });
+
+            // Une carte déjà en file (ou en dead letter) n'est pas ajoutée une seconde fois
+            qb.push(
+                " ON CONFLICT (kind, set_code, collector_number, language_code, foil) DO NOTHING",
+            );
+
+            enqueued += qb.build().execute(&self.pool).await?.rows_affected() as usize;
+        }
+
+        Ok(enqueued)
+    }
+
+    async fn claim(
+        &self,
+        kind: EnrichmentKind,
+        limit: u32,
+        lease: Duration,
+    ) -> Result<Vec<EnrichmentJob>, AppError> {
+        let entities = sqlx::query_as!(
+            EnrichmentJobEntity,
+            r#"WITH claimable AS (SELECT id
+                                  FROM enrichment_job
+                                  WHERE kind = $1
+                                    AND ((status = 'PENDING' AND run_after <= NOW())
+                                      OR (status = 'RUNNING' AND locked_until < NOW()))
+                                  ORDER BY run_after, id
+                                  LIMIT $2
+                                  FOR UPDATE SKIP LOCKED)
+               UPDATE enrichment_job ej
+               SET status       = 'RUNNING',
+                   attempts     = ej.attempts + 1,
+                   locked_until = NOW() + make_interval(secs => $3)
+               FROM claimable,
+                    card c
+               WHERE ej.id = claimable.id
+                 AND c.set_code = ej.set_code
+                 AND c.collector_number = ej.collector_number
+                 AND c.language_code = ej.language_code
+                 AND c.foil = ej.foil
+               RETURNING ej.id,
+                         ej.set_code,
+                         ej.collector_number,
+                         ej.language_code,
+                         ej.foil,
+                         ej.attempts,
+                         COALESCE((SELECT ea.attempts
+                                   FROM enrichment_attempt ea
+                                   WHERE ea.kind = ej.kind
+                                     AND ea.set_code = ej.set_code
+                                     AND ea.collector_number = ej.collector_number
+                                     AND ea.language_code = ej.language_code
+                                     AND ea.foil = ej.foil), 0) AS "card_attempts!",
+                         c.scryfall_id AS "scryfall_id!",
+                         c.name AS "name!""#,
+            kind.as_db_str(),
+            limit as i64,
+            lease.as_secs_f64(),
+        )
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(entities.into_iter().map(EnrichmentJob::from).collect())
+    }
+
+    async fn complete(&self, id: i64) -> Result<(), AppError> {
+        sqlx::query!("DELETE FROM enrichment_job WHERE id = $1", id)
+            .execute(&self.pool)
+            .await?;
+
+        Ok(())
+    }
+
+    async fn fail(
+        &self,
+        id: i64,
+        error: String,
+        retry_at: Option<DateTime<Utc>>,
+    ) -> Result<(), AppError> {
+        sqlx::query!(
+            r#"UPDATE enrichment_job
+               SET status       = CASE WHEN $3::TIMESTAMPTZ IS NULL THEN 'DEAD' ELSE 'PENDING' END,
+                   run_after    = COALESCE($3, run_after),
+                   locked_until = NULL,
+                   last_error   = $2
+               WHERE id = $1"#,
+            id,
+            error,
+            retry_at,
+        )
+        .execute(&self.pool)
+        .await?;
+
+        Ok(())
+    }
+
+    async fn record_attempt(
+        &self,
+        kind: EnrichmentKind,
+        card_id: CardId,
+        outcome: EnrichmentOutcome,
+        next_attempt_at: Option<DateTime<Utc>>,
+    ) -> Result<(), AppError> {
+        sqlx::query!(
+            r#"INSERT INTO enrichment_attempt
+                   (kind, set_code, collector_number, language_code, foil, attempts, last_attempt_at,
+                    outcome, reason, next_attempt_at)
+               VALUES ($1, $2, $3, $4, $5, 1, NOW(), $6, $7, $8)
+               ON CONFLICT (kind, set_code, collector_number, language_code, foil) DO UPDATE
+               SET attempts        = enrichment_attempt.attempts + 1,
+                   last_attempt_at = EXCLUDED.last_attempt_at,
+                   outcome         = EXCLUDED.outcome,
+                   reason          = EXCLUDED.reason,
+                   next_attempt_at = EXCLUDED.next_attempt_at"#,
+            kind.as_db_str(),
+            card_id.set_code.to_string(),
+            card_id.collector_number,
+            card_id.language_code.to_string(),
+            card_id.foil,
+            outcome.as_db_str(),
+            outcome.reason(),
+            next_attempt_at,
+        )
+        .execute(&self.pool)
+        .await?;
+
+        Ok(())
+    }
+
+    async fn find_unresolved(&self) -> Result<Vec<UnresolvedCard>, AppError> {
+        let entities = sqlx::query_as!(
+            UnresolvedCardEntity,
+            r#"SELECT ea.kind,
+                      ea.set_code,
+                      ea.collector_number,
+                      ea.language_code,
+                      ea.foil,
+                      c.name,
+                      ea.attempts,
+                      ea.last_attempt_at,
+                      ea.outcome,
+                      ea.reason,
+                      ea.next_attempt_at
+               FROM enrichment_attempt ea
+               JOIN card c ON c.set_code = ea.set_code
+                   AND c.collector_number = ea.collector_number
+                   AND c.language_code = ea.language_code
+                   AND c.foil = ea.foil
+               WHERE ea.outcome <> 'RESOLVED'
+                 AND ((ea.kind = 'CARDMARKET_ID' AND c.cardmarket_id IS NULL)
+                   OR (ea.kind = 'GATHERER_ID' AND c.the_gatherer_id IS NULL))
+               ORDER BY ea.last_attempt_at DESC"#,
+        )
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(entities.into_iter().map(UnresolvedCard::from).collect())
+    }
+
+    async fn count_by_status(
+        &self,
+        kind: EnrichmentKind,
+    ) -> Result<EnrichmentQueueDepth, AppError> {
+        let record = sqlx::query!(
+            r#"SELECT COUNT(*) FILTER (WHERE status = 'PENDING') AS "pending!",
+                      COUNT(*) FILTER (WHERE status = 'RUNNING') AS "running!",
+                      COUNT(*) FILTER (WHERE status = 'DEAD')    AS "dead!"
+               FROM enrichment_job
+               WHERE kind = $1"#,
+            kind.as_db_str(),
+        )
+        .fetch_one(&self.pool)
+        .await?;
+
+        Ok(EnrichmentQueueDepth {
+            pending: record.pending as u64,
+            running: record.running as u64,
+            dead: record.dead as u64,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::language_code::LanguageCode;
+    use crate::domain::set_name::SetCode;
+    use crate::infrastructure::adapter_out::repository::common_repository_tests::{
+        insert_card_without_cardmarket_id, insert_set,
+    };
+    use sqlx::PgPool;
+
+    const LEASE: Duration = Duration::from_secs(300);
+
+    fn card_id(collector_number: &str) -> CardId {
+        CardId::new(
+            SetCode::new("TST"),
+            collector_number,
+            LanguageCode::EN,
+            false,
+        )
+    }
+
+    async fn insert_cards(pool: &PgPool, count: u32) {
+        insert_set(pool, "TST").await;
+        for n in 1..=count {
+            insert_card_without_cardmarket_id(
+                pool,
+                "TST",
+                &n.to_string(),
+                "EN",
+                false,
+                &format!("Card {}", n),
+            )
+            .await;
+        }
+    }
+
+    async fn fetch_status(pool: &PgPool, id: i64) -> Option<(String, Option<String>)> {
+        sqlx::query_as("SELECT status, last_error FROM enrichment_job WHERE id = $1")
+            .bind(id)
+            .fetch_optional(pool)
+            .await
+            .unwrap()
+    }
+
+    #[sqlx::test]
+    async fn enqueue_skips_cards_already_queued(pool: PgPool) {
+        insert_cards(&pool, 2).await;
+        let repository = EnrichmentJobRepositoryAdapter::new(pool);
+
+        let first = repository
+            .enqueue(EnrichmentKind::CardMarketId, vec![card_id("1")])
+            .await
+            .unwrap();
+        let second = repository
+            .enqueue(
+                EnrichmentKind::CardMarketId,
+                vec![card_id("1"), card_id("2")],
+            )
+            .await
+            .unwrap();
+        let other_kind = repository
+            .enqueue(EnrichmentKind::GathererId, vec![card_id("1")])
+            .await
+            .unwrap();
+
+        assert_eq!(first, 1);
+        assert_eq!(second, 1);
+        assert_eq!(other_kind, 1);
+    }
+
+    #[sqlx::test]
+    async fn claim_returns_the_card_details_and_counts_the_attempt(pool: PgPool) {
+        insert_cards(&pool, 1).await;
+        let repository = EnrichmentJobRepositoryAdapter::new(pool);
+        repository
+            .enqueue(EnrichmentKind::GathererId, vec![card_id("1")])
+            .await
+            .unwrap();
+
+        let jobs = repository
+            .claim(EnrichmentKind::GathererId, 10, LEASE)
+            .await
+            .unwrap();
+
+        assert_eq!(jobs.len(), 1);
+        assert_eq!(jobs[0].card_id, card_id("1"));
+        assert_eq!(jobs[0].name, "Card 1");
+        assert_eq!(jobs[0].attempts, 1);
+        assert!(
+            repository
+                .claim(EnrichmentKind::CardMarketId, 10, LEASE)
+                .await
+                .unwrap()
+                .is_empty()
+        );
+    }
+
+    #[sqlx::test]
+    async fn claim_does_not_return_a_job_twice_while_leased(pool: PgPool) {
+        insert_cards(&pool, 3).await;
+        let repository = EnrichmentJobRepositoryAdapter::new(pool);
+        repository
+            .enqueue(
+                EnrichmentKind::CardMarketId,
+                vec![card_id("1"), card_id("2"), card_id("3")],
+            )
+            .await
+            .unwrap();
+
+        let first = repository
+            .claim(EnrichmentKind::CardMarketId, 2, LEASE)
+            .await
+            .unwrap();
+        let second = repository
+            .claim(EnrichmentKind::CardMarketId, 2, LEASE)
+            .await
+            .unwrap();
+
+        assert_eq!(first.len(), 2);
+        assert_eq!(second.len(), 1);
+        assert!(first.iter().all(|job| job.id != second[0].id));
+    }
+
+    #[sqlx::test]
+    async fn claim_takes_back_jobs_whose_lease_expired(pool: PgPool) {
+        insert_cards(&pool, 1).await;
+        let repository = EnrichmentJobRepositoryAdapter::new(pool);
+        repository
+            .enqueue(EnrichmentKind::CardMarketId, vec![card_id("1")])
+            .await
+            .unwrap();
+        repository
+            .claim(EnrichmentKind::CardMarketId, 10, Duration::ZERO)
+            .await
+            .unwrap();
+
+        let jobs = repository
+            .claim(EnrichmentKind::CardMarketId, 10, LEASE)
+            .await
+            .unwrap();
+
+        assert_eq!(jobs.len(), 1);
+        assert_eq!(jobs[0].attempts, 2);
+    }
+
+    #[sqlx::test]
+    async fn complete_removes_the_job(pool: PgPool) {
+        insert_cards(&pool, 1).await;
+        let repository = EnrichmentJobRepositoryAdapter::new(pool.clone());
+        repository
+            .enqueue(EnrichmentKind::CardMarketId, vec![card_id("1")])
+            .await
+            .unwrap();
+        let job = repository
+            .claim(EnrichmentKind::CardMarketId, 10, LEASE)
+            .await
+            .unwrap()
+            .remove(0);
+
+        repository.complete(job.id).await.unwrap();
+
+        assert_eq!(fetch_status(&pool, job.id).await, None);
+    }
+
+    #[sqlx::test]
+    async fn fail_delays_the_job_until_retry_at(pool: PgPool) {
+        insert_cards(&pool, 1).await;
+        let repository = EnrichmentJobRepositoryAdapter::new(pool.clone());
+        repository
+            .enqueue(EnrichmentKind::CardMarketId, vec![card_id("1")])
+            .await
+            .unwrap();
+        let job = repository
+            .claim(EnrichmentKind::CardMarketId, 10, LEASE)
+            .await
+            .unwrap()
+            .remove(0);
+
+        repository
+            .fail(
+                job.id,
+                "timeout".to_string(),
+                Some(Utc::now() + chrono::Duration::hours(1)),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(
+            fetch_status(&pool, job.id).await,
+            Some(("PENDING".to_string(), Some("timeout".to_string())))
+        );
+        assert!(
+            repository
+                .claim(EnrichmentKind::CardMarketId, 10, LEASE)
+                .await
+                .unwrap()
+                .is_empty()
+        );
+    }
+
+    #[sqlx::test]
+    async fn fail_without_retry_dead_letters_the_job(pool: PgPool) {
+        insert_cards(&pool, 1).await;
+        let repository = EnrichmentJobRepositoryAdapter::new(pool.clone());
+        repository
+            .enqueue(EnrichmentKind::CardMarketId, vec![card_id("1")])
+            .await
+            .unwrap();
+        let job = repository
+            .claim(EnrichmentKind::CardMarketId, 10, LEASE)
+            .await
+            .unwrap()
+            .remove(0);
+
+        repository
+            .fail(job.id, "not found".to_string(), None)
+            .await
+            .unwrap();
+
+        assert_eq!(
+            fetch_status(&pool, job.id).await,
+            Some(("DEAD".to_string(), Some("not found".to_string())))
+        );
+        assert_eq!(
+            repository
+                .enqueue(EnrichmentKind::CardMarketId, vec![card_id("1")])
+                .await
+                .unwrap(),
+            0
+        );
+    }
+
+    #[sqlx::test]
+    async fn claim_returns_the_attempts_recorded_for_the_card(pool: PgPool) {
+        insert_cards(&pool, 1).await;
+        let repository = EnrichmentJobRepositoryAdapter::new(pool);
+        for _ in 0..2 {
+            repository
+                .record_attempt(
+                    EnrichmentKind::CardMarketId,
+                    card_id("1"),
+                    EnrichmentOutcome::NotFound("No Cardmarket product".to_string()),
+                    None,
+                )
+                .await
+                .unwrap();
+        }
+        repository
+            .enqueue(EnrichmentKind::CardMarketId, vec![card_id("1")])
+            .await
+            .unwrap();
+
+        let jobs = repository
+            .claim(EnrichmentKind::CardMarketId, 10, LEASE)
+            .await
+            .unwrap();
+
+        assert_eq!(jobs[0].attempts, 1);
+        assert_eq!(jobs[0].card_attempts, 2);
+    }
+
+    #[sqlx::test]
+    async fn find_unresolved_lists_the_cards_still_missing_their_id(pool: PgPool) {
+        insert_cards(&pool, 3).await;
+        let repository = EnrichmentJobRepositoryAdapter::new(pool.clone());
+        let retry_at = Utc::now() + chrono::Duration::days(1);
+        repository
+            .record_attempt(
+                EnrichmentKind::CardMarketId,
+                card_id("1"),
+                EnrichmentOutcome::NotFound("No Cardmarket product".to_string()),
+                Some(retry_at),
+            )
+            .await
+            .unwrap();
+        repository
+            .record_attempt(
+                EnrichmentKind::GathererId,
+                card_id("2"),
+                EnrichmentOutcome::Resolved,
+                None,
+            )
+            .await
+            .unwrap();
+        // Résolue depuis par le catalogue Scryfall
+        repository
+            .record_attempt(
+                EnrichmentKind::CardMarketId,
+                card_id("3"),
+                EnrichmentOutcome::Failed("timeout".to_string()),
+                None,
+            )
+            .await
+            .unwrap();
+        sqlx::query("UPDATE card SET cardmarket_id = 3 WHERE collector_number = '3'")
+            .execute(&pool)
+            .await
+            .unwrap();
+
+        let unresolved = repository.find_unresolved().await.unwrap();
+
+        assert_eq!(unresolved.len(), 1);
+        assert_eq!(unresolved[0].card_id, card_id("1"));
+        assert_eq!(unresolved[0].name, "Card 1");
+        assert_eq!(unresolved[0].kind, EnrichmentKind::CardMarketId);
+        assert_eq!(unresolved[0].attempts, 1);
+        assert_eq!(
+            unresolved[0].outcome,
+            EnrichmentOutcome::NotFound("No Cardmarket product".to_string())
+        );
+        assert!(unresolved[0].next_attempt_at.is_some());
+    }
+
+    #[sqlx::test]
+    async fn count_by_status_counts_the_jobs_of_the_kind(pool: PgPool) {
+        insert_cards(&pool, 3).await;
+        let repository = EnrichmentJobRepositoryAdapter::new(pool);
+        repository
+            .enqueue(
+                EnrichmentKind::CardMarketId,
+                vec![card_id("1"), card_id("2"), card_id("3")],
+            )
+            .await
+            .unwrap();
+        repository
+            .enqueue(EnrichmentKind::GathererId, vec![card_id("1")])
+            .await
+            .unwrap();
+        let claimed = repository
+            .claim(EnrichmentKind::CardMarketId, 2, LEASE)
+            .await
+            .unwrap();
+        repository
+            .fail(claimed[0].id, "error".to_string(), None)
+            .await
+            .unwrap();
+
+        let depth = repository
+            .count_by_status(EnrichmentKind::CardMarketId)
+            .await
+            .unwrap();
+
+        assert_eq!(
+            depth,
+            EnrichmentQueueDepth {
+                pending: 1,
+                running: 1,
+                dead: 1,
+            }
+        );
+    }
+}
@@ -1,10 +1,23 @@
-use crate::domain::card::{Card, CardId, CollectionEntry};
+use crate::domain::binder::{Binder, BinderId, BinderSummary};
+use crate::domain::card::{Card, CardId, CardInfo, CardSelector, CollectionEntry, EdhRecCardInfo};
+use crate::domain::card_metadata::{CardMetadata, Color};
+use crate::domain::collection_item::{CardCondition, CollectionItem};
+use crate::domain::commander_synergy::OwnedPrinting;
+use crate::domain::enrichment_job::{
+    EnrichmentJob, EnrichmentKind, EnrichmentOutcome, UnresolvedCard,
+};
+use crate::domain::http_cache::{CachedHttpResponse, HttpCacheSource, HttpCacheStats};
 use crate::domain::language_code::LanguageCode;
+use crate::domain::oracle_card::OracleCard;
 use crate::domain::price::{FullPriceGuide, Price, PriceGuide, PriceHistoryEntry};
+use crate::domain::price_anomaly::{PriceAnomaly, PriceAnomalyKind, ReferencePrice};
 use crate::domain::rarity_code::RarityCode;
 use crate::domain::set_name::{SetCode, SetName};
+use crate::domain::set_price_index::SetPriceIndexEntry;
 use crate::domain::trade::{Trade, TradeCard, TradeId, TradeStatus};
+use crate::domain::trade_match::TradeMatchCard;
 use crate::domain::user::{User, UserId, UserSuggestion};
+use crate::domain::wishlist::{Finish, WishPriority, WishlistEntry, WishlistEntryId, WishlistItem};
 use chrono::{DateTime, NaiveDate, Utc};
 use uuid::Uuid;
 
@@ -21,9 +34,54 @@ pub struct CardEntity {
     /// Price in cents
     pub purchase_price: i32,
     pub added_at: Option<DateTime<Utc>>,
+    pub binder_id: Uuid,
     pub scryfall_id: Uuid,
     pub cardmarket_id: Option<i32>,
     pub the_gatherer_id: Option<String>,
+    pub the_gatherer_back_id: Option<String>,
+    pub oracle_id: Option<Uuid>,
+    pub mana_cost: Option<String>,
+    pub type_line: Option<String>,
+    pub colors: Vec<String>,
+    pub color_identity: Vec<String>,
+    pub oracle_text: Option<String>,
+    pub image_small_url: Option<String>,
+    pub image_normal_url: Option<String>,
+    pub image_large_url: Option<String>,
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq, sqlx::FromRow)]
+pub struct CardMetadataEntity {
+    pub oracle_id: Option<Uuid>,
+    pub mana_cost: Option<String>,
+    pub type_line: Option<String>,
+    pub colors: Vec<String>,
+    pub color_identity: Vec<String>,
+    pub oracle_text: Option<String>,
+    pub image_small_url: Option<String>,
+    pub image_normal_url: Option<String>,
+    pub image_large_url: Option<String>,
+}
+
+impl From<CardMetadataEntity> for CardMetadata {
+    fn from(entity: CardMetadataEntity) -> Self {
+        CardMetadata {
+            oracle_id: entity.oracle_id,
+            mana_cost: entity.mana_cost,
+            type_line: entity.type_line,
+            colors: Color::parse_all(entity.colors),
+            color_identity: Color::parse_all(entity.color_identity),
+            oracle_text: entity.oracle_text,
+            image_small_url: entity.image_small_url,
+            image_normal_url: entity.image_normal_url,
+            image_large_url: entity.image_large_url,
+        }
+    }
+}
+
+/// Colours as stored in the `TEXT[]` columns, one symbol per colour.
+pub fn to_db_colors(colors: &[Color]) -> Vec<String> {
+    colors.iter().map(Color::to_string).collect()
 }
 
 #[derive(Clone, Debug, PartialEq, Eq)]
@@ -59,12 +117,176 @@ impl From<CardNameEntity> for CardId {
     }
 }
 
+pub struct EnrichmentJobEntity {
+    pub id: i64,
+    pub set_code: String,
+    pub collector_number: String,
+    pub language_code: String,
+    pub foil: bool,
+    pub attempts: i32,
+    pub card_attempts: i32,
+    pub scryfall_id: Uuid,
+    pub name: String,
+}
+
+impl From<EnrichmentJobEntity> for EnrichmentJob {
+    fn from(entity: EnrichmentJobEntity) -> Self {
+        EnrichmentJob {
+            id: entity.id,
+            card_id: CardId {
+                set_code: SetCode::try_new(entity.set_code)
+                    .expect("database contains invalid set_code"),
+                collector_number: entity.collector_number,
+                language_code: LanguageCode::try_new(entity.language_code)
+                    .expect("database contains invalid language_code"),
+                foil: entity.foil,
+            },
+            scryfall_id: entity.scryfall_id,
+            name: entity.name,
+            attempts: entity.attempts as u32,
+            card_attempts: entity.card_attempts as u32,
+        }
+    }
+}
+
+pub struct UnresolvedCardEntity {
+    pub kind: String,
+    pub set_code: String,
+    pub collector_number: String,
+    pub language_code: String,
+    pub foil: bool,
+    pub name: String,
+    pub attempts: i32,
+    pub last_attempt_at: DateTime<Utc>,
+    pub outcome: String,
+    pub reason: Option<String>,
+    pub next_attempt_at: Option<DateTime<Utc>>,
+}
+
+impl From<UnresolvedCardEntity> for UnresolvedCard {
+    fn from(entity: UnresolvedCardEntity) -> Self {
+        UnresolvedCard {
+            card_id: CardId {
+                set_code: SetCode::try_new(entity.set_code)
+                    .expect("database contains invalid set_code"),
+                collector_number: entity.collector_number,
+                language_code: LanguageCode::try_new(entity.language_code)
+                    .expect("database contains invalid language_code"),
+                foil: entity.foil,
+            },
+            name: entity.name,
+            kind: EnrichmentKind::from_db_str(&entity.kind),
+            attempts: entity.attempts as u32,
+            last_attempt_at: entity.last_attempt_at,
+            outcome: EnrichmentOutcome::from_db_str(&entity.outcome, entity.reason),
+            next_attempt_at: entity.next_attempt_at,
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ResolvedCardMarketIdEntity {
+    pub set_code: String,
+    pub collector_number: String,
+    pub language_code: String,
+    pub foil: bool,
+    pub cardmarket_id: i32,
+}
+
+impl From<ResolvedCardMarketIdEntity> for (CardId, u32) {
+    fn from(entity: ResolvedCardMarketIdEntity) -> Self {
+        let card_id = CardId {
+            set_code: SetCode::try_new(entity.set_code)
+                .expect("database contains invalid set_code"),
+            collector_number: entity.collector_number,
+            language_code: LanguageCode::try_new(entity.language_code)
+                .expect("database contains invalid language_code"),
+            foil: entity.foil,
+        };
+        (card_id, entity.cardmarket_id as u32)
+    }
+}
+
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct SetNameEntity {
     pub set_code: String,
     pub name: String,
 }
 
+impl From<SetNameEntity> for SetName {
+    fn from(entity: SetNameEntity) -> Self {
+        SetName {
+            code: SetCode::try_new(entity.set_code).expect("database contains invalid set_code"),
+            name: entity.name,
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct EdhRecCardInfoEntity {
+    pub name: String,
+    pub inclusion: i32,
+    pub potential_decks: i32,
+    pub rank: Option<i32>,
+    pub fetched_at: DateTime<Utc>,
+}
+
+impl From<EdhRecCardInfoEntity> for EdhRecCardInfo {
+    fn from(entity: EdhRecCardInfoEntity) -> Self {
+        EdhRecCardInfo {
+            name: entity.name,
+            info: CardInfo {
+                inclusion: entity.inclusion as u32,
+                total_decks: entity.potential_decks as u32,
+                rank: entity.rank.map(|rank| rank as u32),
+            },
+            fetched_at: entity.fetched_at,
+        }
+    }
+}
+
+pub struct HttpResponseCacheEntity {
+    pub url: String,
+    pub source: String,
+    pub body: String,
+    pub etag: Option<String>,
+    pub last_modified: Option<String>,
+    pub fetched_at: DateTime<Utc>,
+}
+
+impl From<HttpResponseCacheEntity> for CachedHttpResponse {
+    fn from(entity: HttpResponseCacheEntity) -> Self {
+        CachedHttpResponse {
+            url: entity.url,
+            source: HttpCacheSource::from_db_str(&entity.source),
+            body: entity.body,
+            etag: entity.etag,
+            last_modified: entity.last_modified,
+            fetched_at: entity.fetched_at,
+        }
+    }
+}
+
+pub struct HttpCacheStatsEntity {
+    pub source: String,
+    pub entries: i64,
+    pub size_bytes: i64,
+    pub oldest_fetched_at: DateTime<Utc>,
+    pub newest_fetched_at: DateTime<Utc>,
+}
+
+impl From<HttpCacheStatsEntity> for HttpCacheStats {
+    fn from(entity: HttpCacheStatsEntity) -> Self {
+        HttpCacheStats {
+            source: HttpCacheSource::from_db_str(&entity.source),
+            entries: entity.entries as u64,
+            size_bytes: entity.size_bytes as u64,
+            oldest_fetched_at: Some(entity.oldest_fetched_at),
+            newest_fetched_at: Some(entity.newest_fetched_at),
+        }
+    }
+}
+
 impl From<CardEntity> for Card {
     fn from(entity: CardEntity) -> Card {
         let set_code =
@@ -90,10 +312,27 @@ impl From<CardEntity> for Card {
                     "collection_entry.added_at should always be set (ManaBox import guarantee)",
                 ),
                 reserved: false,
+                binder_id: Some(BinderId(entity.binder_id)),
+                tradable_quantity: None,
+                tags: vec![],
+                notes: None,
+                location: None,
             },
             scryfall_id: entity.scryfall_id,
             cardmarket_id: entity.cardmarket_id.map(|id| id as u32),
             the_gatherer_id: entity.the_gatherer_id,
+            the_gatherer_back_id: entity.the_gatherer_back_id,
+            metadata: CardMetadata::from(CardMetadataEntity {
+                oracle_id: entity.oracle_id,
+                mana_cost: entity.mana_cost,
+                type_line: entity.type_line,
+                colors: entity.colors,
+                color_identity: entity.color_identity,
+                oracle_text: entity.oracle_text,
+                image_small_url: entity.image_small_url,
+                image_normal_url: entity.image_normal_url,
+                image_large_url: entity.image_large_url,
+            }),
             price_guide: None,
         }
     }
@@ -125,6 +364,198 @@ impl From<CardIdEntity> for CardId {
     }
 }
 
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CollectionItemEntity {
+    pub set_code: String,
+    pub collector_number: String,
+    pub language_code: String,
+    pub foil: bool,
+    pub quantity: i32,
+    pub purchase_price: i32,
+    pub condition: String,
+    pub notes: Option<String>,
+    pub tags: Vec<String>,
+    pub location: Option<String>,
+    pub tradable_quantity: Option<i32>,
+    pub added_at: Option<DateTime<Utc>>,
+}
+
+impl From<CollectionItemEntity> for CollectionItem {
+    fn from(entity: CollectionItemEntity) -> Self {
+        let set_code =
+            SetCode::try_new(entity.set_code).expect("database contains invalid set_code");
+        CollectionItem {
+            card_id: CardId::new(
+                set_code,
+                entity.collector_number,
+                LanguageCode::try_new(entity.language_code)
+                    .expect("database contains invalid language_code"),
+                entity.foil,
+            ),
+            quantity: entity.quantity as u8,
+            purchase_price: entity.purchase_price as u32,
+            condition: CardCondition::from_db_str(&entity.condition),
+            notes: entity.notes,
+            tags: entity.tags,
+            location: entity.location,
+            tradable_quantity: entity.tradable_quantity.map(|q| q as u8),
+            added_at: entity
+                .added_at
+                .expect("collection_entry.added_at should always be set"),
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct BinderEntity {
+    pub id: Uuid,
+    pub user_id: String,
+    pub name: String,
+    pub tradable: bool,
+    pub keep_quantity: i32,
+    pub is_default: bool,
+    pub created_at: DateTime<Utc>,
+}
+
+impl From<BinderEntity> for Binder {
+    fn from(entity: BinderEntity) -> Self {
+        Binder {
+            id: BinderId(entity.id),
+            user_id: UserId::new(entity.user_id),
+            name: entity.name,
+            tradable: entity.tradable,
+            keep_quantity: entity.keep_quantity as u8,
+            is_default: entity.is_default,
+            created_at: entity.created_at,
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct BinderSummaryEntity {
+    pub id: Uuid,
+    pub user_id: String,
+    pub name: String,
+    pub tradable: bool,
+    pub keep_quantity: i32,
+    pub is_default: bool,
+    pub created_at: DateTime<Utc>,
+    pub card_count: i64,
+}
+
+impl From<BinderSummaryEntity> for BinderSummary {
+    fn from(entity: BinderSummaryEntity) -> Self {
+        BinderSummary {
+            binder: Binder {
+                id: BinderId(entity.id),
+                user_id: UserId::new(entity.user_id),
+                name: entity.name,
+                tradable: entity.tradable,
+                keep_quantity: entity.keep_quantity as u8,
+                is_default: entity.is_default,
+                created_at: entity.created_at,
+            },
+            card_count: entity.card_count as u64,
+        }
+    }
+}
+
+/// A wish with its matching offers, the printing columns being `NULL` for any printing of a
+/// card.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct WishlistItemEntity {
+    pub id: Uuid,
+    pub user_id: String,
+    pub set_code: Option<String>,
+    pub collector_number: Option<String>,
+    pub language_code: Option<String>,
+    pub foil: Option<bool>,
+    pub oracle_id: Option<Uuid>,
+    pub name: String,
+    pub quantity: i32,
+    pub max_price: Option<i32>,
+    pub languages: Vec<String>,
+    pub finishes: Vec<String>,
+    pub priority: String,
+    pub created_at: DateTime<Utc>,
+    pub offer_count: i64,
+    pub cheapest_offer: Option<i32>,
+}
+
+impl From<WishlistItemEntity> for WishlistItem {
+    fn from(entity: WishlistItemEntity) -> Self {
+        let card = match entity.oracle_id {
+            Some(oracle_id) => CardSelector::AnyPrinting { oracle_id },
+            None => CardSelector::Printing(CardId::new(
+                SetCode::try_new(entity.set_code.expect("printing wish without set code"))
+                    .expect("database contains invalid set_code"),
+                entity
+                    .collector_number
+                    .expect("printing wish without collector number"),
+                LanguageCode::new(
+                    entity
+                        .language_code
+                        .expect("printing wish without language code"),
+                ),
+                entity.foil.expect("printing wish without foil"),
+            )),
+        };
+        WishlistItem {
+            entry: WishlistEntry {
+                id: WishlistEntryId(entity.id),
+                user_id: UserId::new(entity.user_id),
+                card,
+                name: entity.name,
+                quantity: entity.quantity as u8,
+                max_price: entity.max_price.map(|p| p as u32),
+                languages: entity.languages.iter().map(LanguageCode::new).collect(),
+                finishes: entity
+                    .finishes
+                    .iter()
+                    .map(|f| Finish::from_db_str(f))
+                    .collect(),
+                priority: WishPriority::from_db_str(&entity.priority),
+                created_at: entity.created_at,
+            },
+            offer_count: entity.offer_count as u64,
+            cheapest_offer: entity.cheapest_offer.map(|p| p as u32),
+        }
+    }
+}
+
+/// Copies of one printing matching a wish between the user and another user, `wanted` when the
+/// other user owns them.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TradeMatchCardEntity {
+    pub other_user_id: String,
+    pub other_username: String,
+    pub wanted: bool,
+    pub set_code: String,
+    pub collector_number: String,
+    pub language_code: String,
+    pub foil: bool,
+    pub quantity: i32,
+    pub name: String,
+    pub trend: Option<i32>,
+}
+
+impl From<TradeMatchCardEntity> for TradeMatchCard {
+    fn from(entity: TradeMatchCardEntity) -> Self {
+        TradeMatchCard {
+            card_id: CardId::new(
+                SetCode::try_new(entity.set_code).expect("database contains invalid set_code"),
+                entity.collector_number,
+                LanguageCode::new(entity.language_code),
+                entity.foil,
+            ),
+            name: entity.name,
+            // Deux souhaits sur la même impression peuvent dépasser un u8
+            quantity: u8::try_from(entity.quantity).unwrap_or(u8::MAX),
+            trend: entity.trend.map(|t| t as u32),
+        }
+    }
+}
+
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct UserEntity {
     pub id: String,
@@ -257,6 +688,7 @@ impl From<PriceGuideEntity> for PriceGuide {
 pub(crate) struct CardMarketPriceRaw {
     pub id_produit: i32,
     pub date: NaiveDate,
+    pub id_category: Option<i32>,
     pub low: Option<i32>,
     pub avg: Option<i32>,
     pub trend: Option<i32>,
@@ -270,6 +702,7 @@ impl From<CardMarketPriceRaw> for CardMarketPriceEntity {
         CardMarketPriceEntity {
             id_produit: r.id_produit,
             date: r.date,
+            id_category: r.id_category,
             normal: PriceGuideEntity {
                 low: r.low,
                 avg: r.avg,
@@ -288,6 +721,7 @@ impl From<CardMarketPriceRaw> for CardMarketPriceEntity {
 pub struct CardMarketPriceEntity {
     pub id_produit: i32,
     pub date: NaiveDate,
+    pub id_category: Option<i32>,
     pub normal: PriceGuideEntity,
     pub foil: PriceGuideEntity,
 }
@@ -296,6 +730,7 @@ impl From<CardMarketPriceEntity> for FullPriceGuide {
     fn from(e: CardMarketPriceEntity) -> Self {
         FullPriceGuide {
             id_product: e.id_produit as u32,
+            id_category: e.id_category.map(|c| c as u32),
             normal: PriceGuide::from(e.normal),
             foil: PriceGuide::from(e.foil),
         }
@@ -318,6 +753,8 @@ impl User {
     }
 }
 
+/// Raw `collection_price_history` row, only read back by the repository tests.
+#[cfg(test)]
 #[derive(sqlx::FromRow)]
 pub struct CollectionPriceHistoryEntity {
     pub date: NaiveDate,
@@ -326,8 +763,21 @@ pub struct CollectionPriceHistoryEntity {
     pub avg: i32,
 }
 
-impl From<CollectionPriceHistoryEntity> for PriceHistoryEntry {
-    fn from(e: CollectionPriceHistoryEntity) -> Self {
+#[derive(sqlx::FromRow)]
+pub struct PriceHistoryBucketEntity {
+    pub date: NaiveDate,
+    pub low: Option<i32>,
+    pub trend: Option<i32>,
+    pub avg: Option<i32>,
+    pub trend_open: Option<i32>,
+    pub trend_high: Option<i32>,
+    pub trend_low: Option<i32>,
+    pub trend_ma7: Option<i32>,
+    pub trend_ma30: Option<i32>,
+}
+
+impl From<PriceHistoryBucketEntity> for PriceHistoryEntry {
+    fn from(e: PriceHistoryBucketEntity) -> Self {
         PriceHistoryEntry {
             date: e.date,
             price_guide: PriceGuide {
@@ -335,27 +785,77 @@ impl From<CollectionPriceHistoryEntity> for PriceHistoryEntry {
                 trend: e.trend.into(),
                 avg: e.avg.into(),
             },
+            trend_open: e.trend_open.into(),
+            trend_high: e.trend_high.into(),
+            trend_low: e.trend_low.into(),
+            trend_ma7: e.trend_ma7.into(),
+            trend_ma30: e.trend_ma30.into(),
         }
     }
 }
 
 #[derive(sqlx::FromRow)]
-pub struct CardMarketPriceHistoryEntity {
+pub struct SetPriceIndexEntity {
     pub date: NaiveDate,
-    pub low: Option<i32>,
+    pub printings: i64,
+    pub total: Option<i64>,
+    pub median: Option<i32>,
+    pub owned: i64,
+}
+
+impl From<SetPriceIndexEntity> for SetPriceIndexEntry {
+    fn from(e: SetPriceIndexEntity) -> Self {
+        SetPriceIndexEntry {
+            date: e.date,
+            printings: e.printings as u32,
+            total: e
+                .total
+                .map(|v| Price::from_cents(v as u32))
+                .unwrap_or_else(Price::empty),
+            median: e.median.into(),
+            owned: Price::from_cents(e.owned as u32),
+        }
+    }
+}
+
+#[derive(sqlx::FromRow)]
+pub struct ReferencePriceEntity {
+    pub id_produit: i32,
     pub trend: Option<i32>,
-    pub avg: Option<i32>,
+    pub trend_foil: Option<i32>,
 }
 
-impl From<CardMarketPriceHistoryEntity> for PriceHistoryEntry {
-    fn from(e: CardMarketPriceHistoryEntity) -> Self {
-        PriceHistoryEntry {
+impl From<ReferencePriceEntity> for ReferencePrice {
+    fn from(e: ReferencePriceEntity) -> Self {
+        ReferencePrice {
+            id_product: e.id_produit as u32,
+            normal_trend: e.trend.into(),
+            foil_trend: e.trend_foil.into(),
+        }
+    }
+}
+
+#[derive(sqlx::FromRow)]
+pub struct PriceAnomalyEntity {
+    pub id_produit: i32,
+    pub date: NaiveDate,
+    pub foil: bool,
+    pub kind: String,
+    pub reference: i32,
+    pub observed: i32,
+    pub detected_at: DateTime<Utc>,
+}
+
+impl From<PriceAnomalyEntity> for PriceAnomaly {
+    fn from(e: PriceAnomalyEntity) -> Self {
+        PriceAnomaly {
+            id_product: e.id_produit as u32,
             date: e.date,
-            price_guide: PriceGuide {
-                low: e.low.into(),
-                trend: e.trend.into(),
-                avg: e.avg.into(),
-            },
+            foil: e.foil,
+            kind: PriceAnomalyKind::from_db_str(&e.kind),
+            reference: e.reference as u32,
+            observed: e.observed as u32,
+            detected_at: e.detected_at,
         }
     }
 }
@@ -381,6 +881,7 @@ pub struct CardWithPriceEntity {
     pub rarity: String,
     pub scryfall_id: Uuid,
     pub the_gatherer_id: Option<String>,
+    pub the_gatherer_back_id: Option<String>,
     /// Always present: no longer masked for other users' rows.
     pub quantity: i32,
     /// `NULL` when the row belongs to another user (masked in SQL).
@@ -393,6 +894,16 @@ pub struct CardWithPriceEntity {
     /// `true` if this card is engaged in one of its owner's trades in `ONE_ACCEPTED` or
     /// `FULLY_ACCEPTED` status. Always `false` in search mode.
     pub reserved: bool,
+    /// `NULL` in search mode, where the rows are grouped by card.
+    pub binder_id: Option<Uuid>,
+    /// Copies other users can ask for; `0` and unused in search mode.
+    pub tradable_quantity: i32,
+    /// Empty in search mode, like `notes` and `location` which are `NULL`.
+    pub tags: Vec<String>,
+    pub notes: Option<String>,
+    pub location: Option<String>,
+    #[sqlx(flatten)]
+    pub metadata: CardMetadataEntity,
     #[sqlx(flatten)]
     pub price: PriceGuideEntity,
 }
@@ -426,6 +937,11 @@ impl From<CardWithPriceEntity> for Card {
                 purchase_price: purchase_price as u32,
                 added_at,
                 reserved: e.reserved,
+                binder_id: e.binder_id.map(BinderId),
+                tradable_quantity: Some(e.tradable_quantity as u8),
+                tags: e.tags,
+                notes: e.notes,
+                location: e.location,
             },
             _ => CollectionEntry::Public {
                 owner_count: e.owner_count as u64,
@@ -447,14 +963,52 @@ impl From<CardWithPriceEntity> for Card {
             scryfall_id: e.scryfall_id,
             cardmarket_id: None,
             the_gatherer_id: e.the_gatherer_id,
+            the_gatherer_back_id: e.the_gatherer_back_id,
+            metadata: e.metadata.into(),
             collection_entry,
             price_guide,
         }
     }
 }
 
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct OwnedPrintingEntity {
+    pub set_code: String,
+    pub collector_number: String,
+    pub language_code: String,
+    pub foil: bool,
+    pub name: String,
+    pub scryfall_id: Uuid,
+    pub quantity: i32,
+    pub trend: Option<i32>,
+}
+
+impl From<OwnedPrintingEntity> for OwnedPrinting {
+    fn from(entity: OwnedPrintingEntity) -> Self {
+        let set_code =
+            SetCode::try_new(entity.set_code).expect("database contains invalid set_code");
+        OwnedPrinting {
+            card_id: CardId {
+                set_code,
+                collector_number: entity.collector_number,
+                language_code: LanguageCode::try_new(entity.language_code)
+                    .expect("database contains invalid language_code"),
+                foil: entity.foil,
+            },
+            name: entity.name,
+            scryfall_id: entity.scryfall_id,
+            quantity: entity.quantity as u8,
+            trend: Price::from(entity.trend),
+        }
+    }
+}
+
 #[derive(sqlx::FromRow)]
 pub struct CardOfferEntity {
+    pub set_code: String,
+    pub collector_number: String,
+    pub language_code: String,
+    pub foil: bool,
     pub owner_username: String,
     pub quantity: i32,
     pub selling_price: Option<i32>,
@@ -463,6 +1017,13 @@ pub struct CardOfferEntity {
 impl From<CardOfferEntity> for CollectionEntry {
     fn from(e: CardOfferEntity) -> Self {
         CollectionEntry::Owned {
+            card_id: CardId {
+                set_code: SetCode::try_new(e.set_code).expect("database contains invalid set_code"),
+                collector_number: e.collector_number,
+                language_code: LanguageCode::try_new(e.language_code)
+                    .expect("database contains invalid language_code"),
+                foil: e.foil,
+            },
             owner_username: e.owner_username,
             quantity: e.quantity as u8,
             selling_price: e.selling_price.map(|v| v as u32),
@@ -470,6 +1031,46 @@ impl From<CardOfferEntity> for CollectionEntry {
     }
 }
 
+#[derive(sqlx::FromRow)]
+pub struct OracleCardEntity {
+    pub oracle_id: Uuid,
+    pub name: String,
+    pub mana_cost: Option<String>,
+    pub type_line: Option<String>,
+    pub colors: Vec<String>,
+    pub color_identity: Vec<String>,
+    pub oracle_text: Option<String>,
+    pub image_small_url: Option<String>,
+    pub image_normal_url: Option<String>,
+    pub image_large_url: Option<String>,
+    pub printing_count: i64,
+    pub owner_count: i64,
+    pub lowest_trend: Option<i32>,
+}
+
+impl From<OracleCardEntity> for OracleCard {
+    fn from(entity: OracleCardEntity) -> Self {
+        OracleCard {
+            oracle_id: entity.oracle_id,
+            name: entity.name,
+            metadata: CardMetadata::from(CardMetadataEntity {
+                oracle_id: Some(entity.oracle_id),
+                mana_cost: entity.mana_cost,
+                type_line: entity.type_line,
+                colors: entity.colors,
+                color_identity: entity.color_identity,
+                oracle_text: entity.oracle_text,
+                image_small_url: entity.image_small_url,
+                image_normal_url: entity.image_normal_url,
+                image_large_url: entity.image_large_url,
+            }),
+            printing_count: entity.printing_count as u64,
+            owner_count: entity.owner_count as u64,
+            lowest_trend: Price::from(entity.lowest_trend),
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -485,10 +1086,21 @@ mod tests {
             rarity: rarity.to_string(),
             quantity: 2,
             purchase_price: 350,
+            binder_id: Uuid::nil(),
             scryfall_id: Uuid::parse_str("4409a063-bf2a-4a49-803e-3ce6bd474353").unwrap(),
             cardmarket_id,
             the_gatherer_id: None,
+            the_gatherer_back_id: None,
             added_at: Some(chrono::Utc::now()),
+            oracle_id: None,
+            mana_cost: Some("{R}".to_string()),
+            type_line: Some("Creature — Goblin Scout".to_string()),
+            colors: vec!["R".to_string()],
+            color_identity: vec!["R".to_string()],
+            oracle_text: None,
+            image_small_url: None,
+            image_normal_url: None,
+            image_large_url: None,
         }
     }
 
@@ -527,6 +1139,8 @@ mod tests {
             _ => panic!("expected CollectionEntry::Mine"),
         }
         assert_eq!(card.cardmarket_id, Some(42));
+        assert_eq!(card.metadata.mana_cost.as_deref(), Some("{R}"));
+        assert_eq!(card.metadata.colors, vec![Color::R]);
     }
 
     #[test]
@@ -644,6 +1258,7 @@ mod tests {
         let raw = CardMarketPriceRaw {
             id_produit: 42,
             date: NaiveDate::from_ymd_opt(2024, 1, 15).unwrap(),
+            id_category: Some(1),
             low: Some(10),
             avg: Some(20),
             trend: Some(15),
@@ -789,11 +1404,18 @@ mod tests {
             rarity: "C".to_string(),
             scryfall_id: Uuid::new_v4(),
             the_gatherer_id: None,
+            the_gatherer_back_id: None,
             quantity: 2,
             purchase_price: Some(350),
             added_at: Some(chrono::Utc::now()),
             owner_count: 0,
             reserved: true,
+            binder_id: None,
+            tradable_quantity: 0,
+            tags: vec!["cube".to_string()],
+            notes: None,
+            location: Some("Box 3".to_string()),
+            metadata: CardMetadataEntity::default(),
             price: PriceGuideEntity {
                 low: Some(300),
                 avg: Some(350),
@@ -806,7 +1428,16 @@ mod tests {
         assert_eq!(card.name, "Sol Ring");
         assert!(card.price_guide.is_some());
         match card.collection_entry {
-            CollectionEntry::Mine { reserved, .. } => assert!(reserved),
+            CollectionEntry::Mine {
+                reserved,
+                tags,
+                location,
+                ..
+            } => {
+                assert!(reserved);
+                assert_eq!(tags, vec!["cube"]);
+                assert_eq!(location.as_deref(), Some("Box 3"));
+            }
             _ => panic!("expected CollectionEntry::Mine"),
         }
     }
@@ -823,11 +1454,18 @@ mod tests {
             rarity: "C".to_string(),
             scryfall_id: Uuid::new_v4(),
             the_gatherer_id: None,
+            the_gatherer_back_id: None,
             quantity: 0,
             purchase_price: None,
             added_at: None,
             owner_count: 5,
             reserved: false,
+            binder_id: None,
+            tradable_quantity: 0,
+            tags: vec![],
+            notes: None,
+            location: None,
+            metadata: CardMetadataEntity::default(),
             price: PriceGuideEntity {
                 low: None,
                 avg: None,
@@ -857,11 +1495,18 @@ mod tests {
             rarity: "C".to_string(),
             scryfall_id: Uuid::new_v4(),
             the_gatherer_id: None,
+            the_gatherer_back_id: None,
             quantity: 0,
             purchase_price: Some(350),
             added_at: None,
             owner_count: 3,
             reserved: false,
+            binder_id: None,
+            tradable_quantity: 0,
+            tags: vec![],
+            notes: None,
+            location: None,
+            metadata: CardMetadataEntity::default(),
             price: PriceGuideEntity {
                 low: None,
                 avg: None,
@@ -891,11 +1536,18 @@ mod tests {
             rarity: "C".to_string(),
             scryfall_id: Uuid::new_v4(),
             the_gatherer_id: None,
+            the_gatherer_back_id: None,
             quantity: 1,
             purchase_price: Some(100),
             added_at: Some(chrono::Utc::now()),
             owner_count: 0,
             reserved: false,
+            binder_id: None,
+            tradable_quantity: 0,
+            tags: vec![],
+            notes: None,
+            location: None,
+            metadata: CardMetadataEntity::default(),
             price: PriceGuideEntity {
                 low: None,
                 avg: None,
@@ -915,6 +1567,7 @@ mod tests {
         let entity = CardMarketPriceEntity {
             id_produit: 42,
             date: NaiveDate::from_ymd_opt(2024, 6, 15).unwrap(),
+            id_category: None,
             normal: PriceGuideEntity {
                 low: Some(100),
                 avg: Some(200),
@@ -938,42 +1591,33 @@ mod tests {
         assert_eq!(full.foil.trend.value, Some(1500));
     }
 
-    // --- CollectionPriceHistoryEntity ---
-
-    #[test]
-    fn collection_price_history_entity_converts_to_price_history_entry() {
-        let entity = CollectionPriceHistoryEntity {
-            date: NaiveDate::from_ymd_opt(2025, 3, 1).unwrap(),
-            low: 100,
-            trend: 200,
-            avg: 150,
-        };
-
-        let entry: PriceHistoryEntry = entity.into();
-
-        assert_eq!(entry.date, NaiveDate::from_ymd_opt(2025, 3, 1).unwrap());
-        assert_eq!(entry.price_guide.low.value, Some(100));
-        assert_eq!(entry.price_guide.trend.value, Some(200));
-        assert_eq!(entry.price_guide.avg.value, Some(150));
-    }
-
-    // --- CardMarketPriceHistoryEntity ---
+    // --- PriceHistoryBucketEntity ---
 
     #[test]
-    fn card_market_price_history_entity_converts_to_price_history_entry() {
-        let entity = CardMarketPriceHistoryEntity {
+    fn price_history_bucket_entity_converts_to_price_history_entry() {
+        let entity = PriceHistoryBucketEntity {
             date: NaiveDate::from_ymd_opt(2025, 3, 1).unwrap(),
             low: Some(100),
-            trend: None,
+            trend: Some(200),
             avg: Some(150),
+            trend_open: Some(180),
+            trend_high: Some(250),
+            trend_low: Some(170),
+            trend_ma7: Some(210),
+            trend_ma30: None,
         };
 
         let entry: PriceHistoryEntry = entity.into();
 
         assert_eq!(entry.date, NaiveDate::from_ymd_opt(2025, 3, 1).unwrap());
         assert_eq!(entry.price_guide.low.value, Some(100));
-        assert_eq!(entry.price_guide.trend.value, None);
+        assert_eq!(entry.price_guide.trend.value, Some(200));
         assert_eq!(entry.price_guide.avg.value, Some(150));
+        assert_eq!(entry.trend_open.value, Some(180));
+        assert_eq!(entry.trend_high.value, Some(250));
+        assert_eq!(entry.trend_low.value, Some(170));
+        assert_eq!(entry.trend_ma7.value, Some(210));
+        assert_eq!(entry.trend_ma30.value, None);
     }
 
     // --- CardOfferEntity ---
@@ -981,6 +1625,10 @@ mod tests {
     #[test]
     fn card_offer_entity_converts_to_owned_entry() {
         let entity = CardOfferEntity {
+            set_code: "FDN".to_string(),
+            collector_number: "123".to_string(),
+            language_code: "FR".to_string(),
+            foil: true,
             owner_username: "bob".to_string(),
             quantity: 5,
             selling_price: Some(2500),
@@ -990,10 +1638,12 @@ mod tests {
 
         match entry {
             CollectionEntry::Owned {
+                card_id,
                 owner_username,
                 quantity,
                 selling_price,
             } => {
+                assert_eq!(card_id, CardId::new("FDN", "123", LanguageCode::FR, true));
                 assert_eq!(owner_username, "bob");
                 assert_eq!(quantity, 5);
                 assert_eq!(selling_price, Some(2500));
@@ -1005,6 +1655,10 @@ mod tests {
     #[test]
     fn card_offer_entity_converts_to_owned_entry_with_no_selling_price() {
         let entity = CardOfferEntity {
+            set_code: "FDN".to_string(),
+            collector_number: "123".to_string(),
+            language_code: "EN".to_string(),
+            foil: false,
             owner_username: "carol".to_string(),
             quantity: 1,
             selling_price: None,
@@ -1025,6 +1679,37 @@ mod tests {
         }
     }
 
+    // --- OracleCardEntity ---
+
+    #[test]
+    fn oracle_card_entity_converts_to_oracle_card() {
+        let oracle_id = Uuid::parse_str("44623693-51d6-49ad-8cd7-140505caf02f").unwrap();
+        let entity = OracleCardEntity {
+            oracle_id,
+            name: "Lightning Bolt".to_string(),
+            mana_cost: Some("{R}".to_string()),
+            type_line: Some("Instant".to_string()),
+            colors: vec!["R".to_string()],
+            color_identity: vec!["R".to_string()],
+            oracle_text: None,
+            image_small_url: None,
+            image_normal_url: None,
+            image_large_url: None,
+            printing_count: 3,
+            owner_count: 2,
+            lowest_trend: Some(150),
+        };
+
+        let card = OracleCard::from(entity);
+
+        assert_eq!(card.oracle_id, oracle_id);
+        assert_eq!(card.metadata.oracle_id, Some(oracle_id));
+        assert_eq!(card.metadata.colors, vec![Color::R]);
+        assert_eq!(card.printing_count, 3);
+        assert_eq!(card.owner_count, 2);
+        assert_eq!(card.lowest_trend.value, Some(150));
+    }
+
     // --- Price From<i32> ---
 
     #[test]
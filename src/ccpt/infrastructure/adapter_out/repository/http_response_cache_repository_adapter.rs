@@ -0,0 +1,236 @@
+use crate::application::error::AppError;
+use crate::application::repository::HttpResponseCacheRepository;
+use crate::domain::http_cache::{CachedHttpResponse, HttpCacheSource, HttpCacheStats};
+use crate::infrastructure::adapter_out::repository::entities::{
+    HttpCacheStatsEntity, HttpResponseCacheEntity,
+};
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use sqlx::{Pool, Postgres};
+
+pub struct HttpResponseCacheRepositoryAdapter {
+    pool: Pool<Postgres>,
+}
+
+impl HttpResponseCacheRepositoryAdapter {
+    pub fn new(pool: Pool<Postgres>) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl HttpResponseCacheRepository for HttpResponseCacheRepositoryAdapter {
+    async fn find_by_url(&self, url: &str) -> Result<Option<CachedHttpResponse>, AppError> {
+        Ok(sqlx::query_as!(
+            HttpResponseCacheEntity,
+            "SELECT url, source, body, etag, last_modified, fetched_at
+             FROM http_response_cache
+             WHERE url = $1",
+            url
+        )
+        .fetch_optional(&self.pool)
+        .await?
+        .map(CachedHttpResponse::from))
+    }
+
+    async fn save(&self, response: &CachedHttpResponse) -> Result<(), AppError> {
+        sqlx::query!(
+            "INSERT INTO http_response_cache (url, source, body, etag, last_modified, fetched_at)
+             VALUES ($1, $2, $3, $4, $5, $6)
+             ON CONFLICT(url)
+             DO UPDATE
+                SET source = $2,
+                    body = $3,
+                    etag = $4,
+                    last_modified = $5,
+                    fetched_at = $6",
+            response.url,
+            response.source.as_db_str(),
+            response.body,
+            response.etag,
+            response.last_modified,
+            response.fetched_at
+        )
+        .execute(&self.pool)
+        .await?;
+
+        Ok(())
+    }
+
+    async fn mark_revalidated(&self, url: &str, fetched_at: DateTime<Utc>) -> Result<(), AppError> {
+        sqlx::query!(
+            "UPDATE http_response_cache SET fetched_at = $2 WHERE url = $1",
+            url,
+            fetched_at
+        )
+        .execute(&self.pool)
+        .await?;
+
+        Ok(())
+    }
+
+    async fn get_stats(&self) -> Result<Vec<HttpCacheStats>, AppError> {
+        Ok(sqlx::query_as!(
+            HttpCacheStatsEntity,
+            r#"SELECT source,
+                      COUNT(*)                        AS "entries!",
+                      SUM(OCTET_LENGTH(body))::BIGINT AS "size_bytes!",
+                      MIN(fetched_at)                 AS "oldest_fetched_at!",
+                      MAX(fetched_at)                 AS "newest_fetched_at!"
+               FROM http_response_cache
+               GROUP BY source
+               ORDER BY source"#
+        )
+        .fetch_all(&self.pool)
+        .await?
+        .into_iter()
+        .map(HttpCacheStats::from)
+        .collect())
+    }
+
+    async fn purge(&self, source: Option<HttpCacheSource>) -> Result<u64, AppError> {
+        let result = sqlx::query!(
+            "DELETE FROM http_response_cache WHERE $1::TEXT IS NULL OR source = $1",
+            source.map(|source| source.as_db_str())
+        )
+        .execute(&self.pool)
+        .await?;
+
+        Ok(result.rows_affected())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+    use sqlx::PgPool;
+
+    fn response(url: &str, source: HttpCacheSource, body: &str) -> CachedHttpResponse {
+        CachedHttpResponse {
+            url: url.to_string(),
+            source,
+            body: body.to_string(),
+            etag: Some("\"v1\"".to_string()),
+            last_modified: None,
+            fetched_at: Utc.with_ymd_and_hms(2025, 3, 1, 12, 0, 0).unwrap(),
+        }
+    }
+
+    #[sqlx::test]
+    async fn find_by_url_returns_none_when_not_cached(pool: PgPool) {
+        let result = HttpResponseCacheRepositoryAdapter::new(pool)
+            .find_by_url("https://api.scryfall.com/cards/1")
+            .await
+            .unwrap();
+
+        assert_eq!(result, None);
+    }
+
+    #[sqlx::test]
+    async fn save_replaces_the_response_of_the_url(pool: PgPool) {
+        let repository = HttpResponseCacheRepositoryAdapter::new(pool);
+        let url = "https://api.scryfall.com/cards/1";
+        repository
+            .save(&response(url, HttpCacheSource::Scryfall, "old"))
+            .await
+            .unwrap();
+
+        let refreshed = response(url, HttpCacheSource::Scryfall, "new");
+        repository.save(&refreshed).await.unwrap();
+
+        assert_eq!(repository.find_by_url(url).await.unwrap(), Some(refreshed));
+    }
+
+    #[sqlx::test]
+    async fn mark_revalidated_only_updates_fetched_at(pool: PgPool) {
+        let repository = HttpResponseCacheRepositoryAdapter::new(pool);
+        let url = "https://api.scryfall.com/cards/1";
+        let cached = response(url, HttpCacheSource::Scryfall, "body");
+        repository.save(&cached).await.unwrap();
+
+        let revalidated_at = Utc.with_ymd_and_hms(2025, 3, 8, 12, 0, 0).unwrap();
+        repository
+            .mark_revalidated(url, revalidated_at)
+            .await
+            .unwrap();
+
+        assert_eq!(
+            repository.find_by_url(url).await.unwrap(),
+            Some(CachedHttpResponse {
+                fetched_at: revalidated_at,
+                ..cached
+            })
+        );
+    }
+
+    #[sqlx::test]
+    async fn get_stats_groups_entries_by_source(pool: PgPool) {
+        let repository = HttpResponseCacheRepositoryAdapter::new(pool);
+        repository
+            .save(&response("https://a/1", HttpCacheSource::Scryfall, "1234"))
+            .await
+            .unwrap();
+        repository
+            .save(&response("https://a/2", HttpCacheSource::Scryfall, "12"))
+            .await
+            .unwrap();
+        repository
+            .save(&response("https://b/1", HttpCacheSource::Gatherer, "1"))
+            .await
+            .unwrap();
+
+        let stats = repository.get_stats().await.unwrap();
+
+        assert_eq!(stats.len(), 2);
+        assert_eq!(stats[0].source, HttpCacheSource::Gatherer);
+        assert_eq!(stats[0].entries, 1);
+        assert_eq!(stats[1].source, HttpCacheSource::Scryfall);
+        assert_eq!(stats[1].entries, 2);
+        assert_eq!(stats[1].size_bytes, 6);
+    }
+
+    #[sqlx::test]
+    async fn purge_deletes_only_the_given_source(pool: PgPool) {
+        let repository = HttpResponseCacheRepositoryAdapter::new(pool);
+        repository
+            .save(&response("https://a/1", HttpCacheSource::Scryfall, "a"))
+            .await
+            .unwrap();
+        repository
+            .save(&response("https://b/1", HttpCacheSource::EdhRec, "b"))
+            .await
+            .unwrap();
+
+        let purged = repository
+            .purge(Some(HttpCacheSource::Scryfall))
+            .await
+            .unwrap();
+
+        assert_eq!(purged, 1);
+        assert_eq!(repository.find_by_url("https://a/1").await.unwrap(), None);
+        assert!(
+            repository
+                .find_by_url("https://b/1")
+                .await
+                .unwrap()
+                .is_some()
+        );
+    }
+
+    #[sqlx::test]
+    async fn purge_without_source_deletes_everything(pool: PgPool) {
+        let repository = HttpResponseCacheRepositoryAdapter::new(pool);
+        repository
+            .save(&response("https://a/1", HttpCacheSource::Scryfall, "a"))
+            .await
+            .unwrap();
+        repository
+            .save(&response("https://b/1", HttpCacheSource::EdhRec, "b"))
+            .await
+            .unwrap();
+
+        assert_eq!(repository.purge(None).await.unwrap(), 2);
+        assert!(repository.get_stats().await.unwrap().is_empty());
+    }
+}
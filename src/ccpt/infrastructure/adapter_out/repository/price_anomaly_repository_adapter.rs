@@ -0,0 +1,401 @@
+use crate::application::error::AppError;
+use crate::application::repository::PriceAnomalyRepository;
+use crate::domain::price_anomaly::{PriceAnomaly, PriceAnomalyReview};
+use crate::infrastructure::adapter_out::repository::entities::PriceAnomalyEntity;
+use async_trait::async_trait;
+use chrono::NaiveDate;
+use sqlx::{PgConnection, Pool, Postgres, QueryBuilder};
+
+pub struct PriceAnomalyRepositoryAdapter {
+    pool: Pool<Postgres>,
+}
+
+impl PriceAnomalyRepositoryAdapter {
+    pub fn new(pool: Pool<Postgres>) -> Self {
+        Self { pool }
+    }
+}
+
+/// Replaces the pending anomalies of `date` with `anomalies` and quarantines the matching finish
+/// of the `cardmarket_price` rows, within the caller's transaction. Reviewed anomalies are kept
+/// as is, except rejected ones the new prices no longer trigger.
+pub(crate) async fn replace_pending(
+    conn: &mut PgConnection,
+    date: NaiveDate,
+    anomalies: Vec<PriceAnomaly>,
+) -> Result<(), AppError> {
+    let (id_products, foils): (Vec<i32>, Vec<bool>) = anomalies
+        .iter()
+        .map(|anomaly| (anomaly.id_product as i32, anomaly.foil))
+        .unzip();
+
+    // Le prix rejeté a été remplacé par le nouvel import : s'il est sain, il sort de quarantaine
+    sqlx::query!(
+        r#"DELETE FROM price_anomaly
+           WHERE date = $1
+             AND (status = 'PENDING'
+                  OR (status = 'REJECTED'
+                      AND (id_produit, foil) NOT IN (SELECT * FROM UNNEST($2::integer[], $3::boolean[]))))"#,
+        date,
+        &id_products,
+        &foils,
+    )
+    .execute(&mut *conn)
+    .await?;
+
+    const CHUNK_SIZE: usize = 1000;
+
+    for chunk in anomalies.chunks(CHUNK_SIZE) {
+        let mut qb: QueryBuilder<Postgres> = QueryBuilder::new(
+            "
+            INSERT INTO price_anomaly
+                (id_produit, date, foil, kind, reference, observed, detected_at)
+            ",
+        );
+
+        qb.push_values(chunk, |mut b, anomaly| {
+            b.push_bind(anomaly.id_product as i32)
+                .push_bind(anomaly.date)
+                .push_bind(anomaly.foil)
+                .push_bind(anomaly.kind.as_db_str())
+                .push_bind(anomaly.reference as i32)
+                .push_bind(anomaly.observed as i32)
+                .push_bind(anomaly.detected_at);
+        });
+
+        // Une anomalie déjà revue pour ce produit et cette date n'est pas remise en attente
+        qb.push(" ON CONFLICT (id_produit, date, foil) DO NOTHING");
+
+        qb.build().execute(&mut *conn).await?;
+    }
+
+    // Seules les lignes dont l'état change sont réécrites, pas tout le guide du jour
+    sqlx::query!(
+        r#"UPDATE cardmarket_price cmp
+           SET quarantined      = flags.normal,
+               quarantined_foil = flags.foil
+           FROM (SELECT p.id_produit,
+                        COALESCE(BOOL_OR(NOT pa.foil), FALSE) AS normal,
+                        COALESCE(BOOL_OR(pa.foil), FALSE)     AS foil
+                 FROM cardmarket_price p
+                          LEFT JOIN price_anomaly pa
+                                    ON pa.id_produit = p.id_produit
+                                        AND pa.date = p.date
+                                        AND pa.status <> 'CONFIRMED'
+                 WHERE p.date = $1
+                 GROUP BY p.id_produit) flags
+           WHERE cmp.id_produit = flags.id_produit
+             AND cmp.date = $1
+             AND (cmp.quarantined, cmp.quarantined_foil) <> (flags.normal, flags.foil)"#,
+        date
+    )
+    .execute(&mut *conn)
+    .await?;
+
+    Ok(())
+}
+
+#[async_trait]
+impl PriceAnomalyRepository for PriceAnomalyRepositoryAdapter {
+    async fn find_pending(&self) -> Result<Vec<PriceAnomaly>, AppError> {
+        let entities = sqlx::query_as!(
+            PriceAnomalyEntity,
+            r#"SELECT id_produit, date, foil, kind, reference, observed, detected_at
+               FROM price_anomaly
+               WHERE status = 'PENDING'
+               ORDER BY date DESC, id_produit, foil"#
+        )
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(entities.into_iter().map(PriceAnomaly::from).collect())
+    }
+
+    async fn review(
+        &self,
+        id_product: u32,
+        date: NaiveDate,
+        foil: bool,
+        review: PriceAnomalyReview,
+    ) -> Result<bool, AppError> {
+        let mut tx = self.pool.begin().await?;
+
+        let result = sqlx::query!(
+            r#"UPDATE price_anomaly
+               SET status      = $4,
+                   reviewed_at = NOW()
+               WHERE id_produit = $1
+                 AND date = $2
+                 AND foil = $3
+                 AND status = 'PENDING'"#,
+            id_product as i32,
+            date,
+            foil,
+            review.as_db_str(),
+        )
+        .execute(&mut *tx)
+        .await?;
+
+        if result.rows_affected() == 0 {
+            return Ok(false);
+        }
+
+        sqlx::query!(
+            r#"UPDATE cardmarket_price cmp
+               SET quarantined      = EXISTS (SELECT 1
+                                              FROM price_anomaly pa
+                                              WHERE pa.id_produit = cmp.id_produit
+                                                AND pa.date = cmp.date
+                                                AND NOT pa.foil
+                                                AND pa.status <> 'CONFIRMED'),
+                   quarantined_foil = EXISTS (SELECT 1
+                                              FROM price_anomaly pa
+                                              WHERE pa.id_produit = cmp.id_produit
+                                                AND pa.date = cmp.date
+                                                AND pa.foil
+                                                AND pa.status <> 'CONFIRMED')
+               WHERE cmp.id_produit = $1
+                 AND cmp.date = $2"#,
+            id_product as i32,
+            date,
+        )
+        .execute(&mut *tx)
+        .await?;
+
+        tx.commit().await?;
+        Ok(true)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::price_anomaly::PriceAnomalyKind;
+    use crate::infrastructure::adapter_out::repository::common_repository_tests::{
+        fetch_quarantined, insert_price,
+    };
+    use crate::infrastructure::adapter_out::repository::entities::{
+        CardMarketPriceEntity, PriceGuideEntity,
+    };
+    use chrono::Utc;
+    use sqlx::PgPool;
+
+    fn date() -> NaiveDate {
+        NaiveDate::from_ymd_opt(2025, 3, 1).unwrap()
+    }
+
+    fn anomaly(id_product: u32, foil: bool) -> PriceAnomaly {
+        PriceAnomaly {
+            id_product,
+            date: date(),
+            foil,
+            kind: PriceAnomalyKind::Spike,
+            reference: 1000,
+            observed: 100_000,
+            detected_at: Utc::now(),
+        }
+    }
+
+    async fn insert_price_for(pool: &PgPool, id_produit: i32) {
+        insert_price(
+            pool,
+            CardMarketPriceEntity {
+                id_produit,
+                date: date(),
+                id_category: None,
+                normal: PriceGuideEntity {
+                    low: Some(90_000),
+                    avg: Some(95_000),
+                    trend: Some(100_000),
+                },
+                foil: PriceGuideEntity::empty(),
+            },
+        )
+        .await;
+    }
+
+    #[sqlx::test]
+    async fn replace_pending_quarantines_flagged_prices_only(pool: PgPool) {
+        insert_price_for(&pool, 1).await;
+        insert_price_for(&pool, 2).await;
+        let repository = PriceAnomalyRepositoryAdapter::new(pool.clone());
+
+        replace_pending(
+            &mut pool.acquire().await.unwrap(),
+            date(),
+            vec![anomaly(1, false)],
+        )
+        .await
+        .unwrap();
+
+        assert!(fetch_quarantined(&pool, 1, date(), false).await);
+        assert!(!fetch_quarantined(&pool, 1, date(), true).await);
+        assert!(!fetch_quarantined(&pool, 2, date(), false).await);
+
+        let pending = repository.find_pending().await.unwrap();
+        assert_eq!(pending.len(), 1);
+        assert_eq!(pending[0].id_product, 1);
+        assert_eq!(pending[0].kind, PriceAnomalyKind::Spike);
+        assert_eq!(pending[0].reference, 1000);
+        assert_eq!(pending[0].observed, 100_000);
+    }
+
+    #[sqlx::test]
+    async fn replace_pending_drops_anomalies_no_longer_detected(pool: PgPool) {
+        insert_price_for(&pool, 1).await;
+        let repository = PriceAnomalyRepositoryAdapter::new(pool.clone());
+
+        replace_pending(
+            &mut pool.acquire().await.unwrap(),
+            date(),
+            vec![anomaly(1, false)],
+        )
+        .await
+        .unwrap();
+        replace_pending(&mut pool.acquire().await.unwrap(), date(), vec![])
+            .await
+            .unwrap();
+
+        assert!(!fetch_quarantined(&pool, 1, date(), false).await);
+        assert!(repository.find_pending().await.unwrap().is_empty());
+    }
+
+    #[sqlx::test]
+    async fn replace_pending_does_not_flag_again_a_confirmed_anomaly(pool: PgPool) {
+        insert_price_for(&pool, 1).await;
+        let repository = PriceAnomalyRepositoryAdapter::new(pool.clone());
+
+        replace_pending(
+            &mut pool.acquire().await.unwrap(),
+            date(),
+            vec![anomaly(1, false)],
+        )
+        .await
+        .unwrap();
+        repository
+            .review(1, date(), false, PriceAnomalyReview::Confirmed)
+            .await
+            .unwrap();
+        replace_pending(
+            &mut pool.acquire().await.unwrap(),
+            date(),
+            vec![anomaly(1, false)],
+        )
+        .await
+        .unwrap();
+
+        assert!(!fetch_quarantined(&pool, 1, date(), false).await);
+        assert!(repository.find_pending().await.unwrap().is_empty());
+    }
+
+    #[sqlx::test]
+    async fn review_confirmed_releases_the_quarantine_of_the_finish_only(pool: PgPool) {
+        insert_price_for(&pool, 1).await;
+        let repository = PriceAnomalyRepositoryAdapter::new(pool.clone());
+        replace_pending(
+            &mut pool.acquire().await.unwrap(),
+            date(),
+            vec![anomaly(1, false), anomaly(1, true)],
+        )
+        .await
+        .unwrap();
+
+        let reviewed = repository
+            .review(1, date(), false, PriceAnomalyReview::Confirmed)
+            .await
+            .unwrap();
+
+        assert!(reviewed);
+        assert!(!fetch_quarantined(&pool, 1, date(), false).await);
+        assert!(fetch_quarantined(&pool, 1, date(), true).await);
+        let pending = repository.find_pending().await.unwrap();
+        assert_eq!(pending.len(), 1);
+        assert!(pending[0].foil);
+    }
+
+    #[sqlx::test]
+    async fn review_rejected_keeps_the_quarantine(pool: PgPool) {
+        insert_price_for(&pool, 1).await;
+        let repository = PriceAnomalyRepositoryAdapter::new(pool.clone());
+        replace_pending(
+            &mut pool.acquire().await.unwrap(),
+            date(),
+            vec![anomaly(1, false)],
+        )
+        .await
+        .unwrap();
+
+        let reviewed = repository
+            .review(1, date(), false, PriceAnomalyReview::Rejected)
+            .await
+            .unwrap();
+
+        assert!(reviewed);
+        assert!(fetch_quarantined(&pool, 1, date(), false).await);
+        assert!(repository.find_pending().await.unwrap().is_empty());
+    }
+
+    #[sqlx::test]
+    async fn replace_pending_releases_a_rejected_anomaly_no_longer_detected(pool: PgPool) {
+        insert_price_for(&pool, 1).await;
+        let repository = PriceAnomalyRepositoryAdapter::new(pool.clone());
+        replace_pending(
+            &mut pool.acquire().await.unwrap(),
+            date(),
+            vec![anomaly(1, false)],
+        )
+        .await
+        .unwrap();
+        repository
+            .review(1, date(), false, PriceAnomalyReview::Rejected)
+            .await
+            .unwrap();
+
+        replace_pending(&mut pool.acquire().await.unwrap(), date(), vec![])
+            .await
+            .unwrap();
+
+        assert!(!fetch_quarantined(&pool, 1, date(), false).await);
+    }
+
+    #[sqlx::test]
+    async fn replace_pending_keeps_a_rejected_anomaly_detected_again(pool: PgPool) {
+        insert_price_for(&pool, 1).await;
+        let repository = PriceAnomalyRepositoryAdapter::new(pool.clone());
+        replace_pending(
+            &mut pool.acquire().await.unwrap(),
+            date(),
+            vec![anomaly(1, false)],
+        )
+        .await
+        .unwrap();
+        repository
+            .review(1, date(), false, PriceAnomalyReview::Rejected)
+            .await
+            .unwrap();
+
+        replace_pending(
+            &mut pool.acquire().await.unwrap(),
+            date(),
+            vec![anomaly(1, false)],
+        )
+        .await
+        .unwrap();
+
+        assert!(fetch_quarantined(&pool, 1, date(), false).await);
+        assert!(repository.find_pending().await.unwrap().is_empty());
+    }
+
+    #[sqlx::test]
+    async fn review_returns_false_when_nothing_is_pending(pool: PgPool) {
+        insert_price_for(&pool, 1).await;
+        let repository = PriceAnomalyRepositoryAdapter::new(pool.clone());
+
+        let reviewed = repository
+            .review(1, date(), false, PriceAnomalyReview::Confirmed)
+            .await
+            .unwrap();
+
+        assert!(!reviewed);
+    }
+}
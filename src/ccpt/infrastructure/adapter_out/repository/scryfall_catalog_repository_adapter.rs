@@ -0,0 +1,289 @@
+use crate::application::error::AppError;
+use crate::application::repository::ScryfallCatalogRepository;
+use crate::domain::card::CardId;
+use crate::domain::scryfall_card::ScryfallCard;
+use crate::infrastructure::adapter_out::repository::entities::{
+    ResolvedCardMarketIdEntity, to_db_colors,
+};
+use async_trait::async_trait;
+use sqlx::{Pool, Postgres, QueryBuilder};
+
+pub struct ScryfallCatalogRepositoryAdapter {
+    pool: Pool<Postgres>,
+}
+
+impl ScryfallCatalogRepositoryAdapter {
+    pub fn new(pool: Pool<Postgres>) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl ScryfallCatalogRepository for ScryfallCatalogRepositoryAdapter {
+    async fn save_all(&self, cards: Vec<ScryfallCard>) -> Result<(), AppError> {
+        const CHUNK_SIZE: usize = 1000;
+
+        for chunk in cards.chunks(CHUNK_SIZE) {
+            let mut qb: QueryBuilder<Postgres> = QueryBuilder::new(
+                "
+                INSERT INTO scryfall_card
+                    (scryfall_id, name, set_code, collector_number, lang, cardmarket_id, rarity,
+                     oracle_id, mana_cost, type_line, colors, color_identity, oracle_text,
+                     image_small_url, image_normal_url, image_large_url)
+                ",
+            );
+
+            qb.push_values(chunk, |mut b, card| {
+                b.push_bind(card.scryfall_id)
+                    .push_bind(&card.name)
+                    .push_bind(&card.set_code)
+                    .push_bind(&card.collector_number)
+                    .push_bind(&card.lang)
+                    .push_bind(card.cardmarket_id.map(|id| id as i32))
+                    .push_bind(card.rarity.as_ref().map(|rarity| rarity.to_string()))
+                    .push_bind(card.metadata.oracle_id)
+                    .push_bind(&card.metadata.mana_cost)
+                    .push_bind(&card.metadata.type_line)
+                    .push_bind(to_db_colors(&card.metadata.colors))
+                    .push_bind(to_db_colors(&card.metadata.color_identity))
+                    .push_bind(&card.metadata.oracle_text)
+                    .push_bind(&card.metadata.image_small_url)
+                    .push_bind(&card.metadata.image_normal_url)
+                    .push_bind(&card.metadata.image_large_url);
+            });
+
+            qb.push(
+                "
+                ON CONFLICT (scryfall_id) DO UPDATE
+                SET name             = EXCLUDED.name,
+                    set_code         = EXCLUDED.set_code,
+                    collector_number = EXCLUDED.collector_number,
+                    lang             = EXCLUDED.lang,
+                    cardmarket_id    = EXCLUDED.cardmarket_id,
+                    rarity           = EXCLUDED.rarity,
+                    oracle_id        = EXCLUDED.oracle_id,
+                    mana_cost        = EXCLUDED.mana_cost,
+                    type_line        = EXCLUDED.type_line,
+                    colors           = EXCLUDED.colors,
+                    color_identity   = EXCLUDED.color_identity,
+                    oracle_text      = EXCLUDED.oracle_text,
+                    image_small_url  = EXCLUDED.image_small_url,
+                    image_normal_url = EXCLUDED.image_normal_url,
+                    image_large_url  = EXCLUDED.image_large_url,
+                    updated_at       = NOW()
+                ",
+            );
+
+            qb.build().execute(&self.pool).await?;
+        }
+
+        Ok(())
+    }
+
+    async fn resolve_cardmarket_ids(&self) -> Result<Vec<(CardId, u32)>, AppError> {
+        let entities = sqlx::query_as!(
+            ResolvedCardMarketIdEntity,
+            r#"WITH resolved AS (UPDATE card c
+                                 SET cardmarket_id = sc.cardmarket_id
+                                 FROM scryfall_card sc
+                                 WHERE c.scryfall_id = sc.scryfall_id
+                                   AND c.cardmarket_id IS NULL
+                                   AND sc.cardmarket_id IS NOT NULL
+                                 RETURNING c.set_code,
+                                           c.collector_number,
+                                           c.language_code,
+                                           c.foil,
+                                           c.cardmarket_id),
+                    dropped AS (DELETE FROM enrichment_job ej
+                                USING resolved r
+                                WHERE ej.kind = 'CARDMARKET_ID'
+                                  AND ej.set_code = r.set_code
+                                  AND ej.collector_number = r.collector_number
+                                  AND ej.language_code = r.language_code
+                                  AND ej.foil = r.foil)
+               SELECT set_code         AS "set_code!",
+                      collector_number AS "collector_number!",
+                      language_code    AS "language_code!",
+                      foil             AS "foil!",
+                      cardmarket_id    AS "cardmarket_id!"
+               FROM resolved"#,
+        )
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(entities.into_iter().map(<(CardId, u32)>::from).collect())
+    }
+
+    async fn sync_metadata(&self) -> Result<usize, AppError> {
+        let result = sqlx::query!(
+            r#"UPDATE card c
+               SET oracle_id        = sc.oracle_id,
+                   mana_cost        = sc.mana_cost,
+                   type_line        = sc.type_line,
+                   colors           = sc.colors,
+                   color_identity   = sc.color_identity,
+                   oracle_text      = sc.oracle_text,
+                   image_small_url  = sc.image_small_url,
+                   image_normal_url = sc.image_normal_url,
+                   image_large_url  = sc.image_large_url
+               FROM scryfall_card sc
+               WHERE c.scryfall_id = sc.scryfall_id
+                 AND (c.oracle_id, c.mana_cost, c.type_line, c.colors, c.color_identity, c.oracle_text,
+                      c.image_small_url, c.image_normal_url, c.image_large_url)
+                     IS DISTINCT FROM
+                     (sc.oracle_id, sc.mana_cost, sc.type_line, sc.colors, sc.color_identity, sc.oracle_text,
+                      sc.image_small_url, sc.image_normal_url, sc.image_large_url)"#,
+        )
+        .execute(&self.pool)
+        .await?;
+
+        Ok(result.rows_affected() as usize)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::application::repository::EnrichmentJobRepository;
+    use crate::domain::card_metadata::{CardMetadata, Color};
+    use crate::domain::enrichment_job::EnrichmentKind;
+    use crate::domain::language_code::LanguageCode;
+    use crate::domain::set_name::SetCode;
+    use crate::infrastructure::adapter_out::repository::common_repository_tests::{
+        insert_card, insert_card_without_cardmarket_id, insert_set,
+    };
+    use crate::infrastructure::adapter_out::repository::enrichment_job_repository_adapter::EnrichmentJobRepositoryAdapter;
+    use sqlx::PgPool;
+    use uuid::Uuid;
+
+    fn card_id(collector_number: &str) -> CardId {
+        CardId::new(
+            SetCode::new("TST"),
+            collector_number,
+            LanguageCode::EN,
+            false,
+        )
+    }
+
+    async fn scryfall_id_of(pool: &PgPool, collector_number: &str) -> Uuid {
+        sqlx::query_scalar("SELECT scryfall_id FROM card WHERE collector_number = $1")
+            .bind(collector_number)
+            .fetch_one(pool)
+            .await
+            .unwrap()
+    }
+
+    fn scryfall_card(scryfall_id: Uuid, cardmarket_id: Option<u32>) -> ScryfallCard {
+        ScryfallCard {
+            scryfall_id,
+            name: "Card".to_string(),
+            set_code: "tst".to_string(),
+            collector_number: "1".to_string(),
+            lang: "en".to_string(),
+            cardmarket_id,
+            rarity: None,
+            metadata: CardMetadata::default(),
+        }
+    }
+
+    #[sqlx::test]
+    async fn save_all_updates_known_printings(pool: PgPool) {
+        let repository = ScryfallCatalogRepositoryAdapter::new(pool.clone());
+        let scryfall_id = Uuid::new_v4();
+
+        repository
+            .save_all(vec![scryfall_card(scryfall_id, None)])
+            .await
+            .unwrap();
+        repository
+            .save_all(vec![
+                scryfall_card(scryfall_id, Some(42)),
+                scryfall_card(Uuid::new_v4(), None),
+            ])
+            .await
+            .unwrap();
+
+        let count: i64 = sqlx::query_scalar("SELECT COUNT(*) FROM scryfall_card")
+            .fetch_one(&pool)
+            .await
+            .unwrap();
+        let cardmarket_id: Option<i32> =
+            sqlx::query_scalar("SELECT cardmarket_id FROM scryfall_card WHERE scryfall_id = $1")
+                .bind(scryfall_id)
+                .fetch_one(&pool)
+                .await
+                .unwrap();
+        assert_eq!(count, 2);
+        assert_eq!(cardmarket_id, Some(42));
+    }
+
+    #[sqlx::test]
+    async fn resolve_cardmarket_ids_only_fills_missing_ids_and_drops_their_jobs(pool: PgPool) {
+        insert_set(&pool, "TST").await;
+        insert_card_without_cardmarket_id(&pool, "TST", "1", "EN", false, "Unresolved").await;
+        insert_card_without_cardmarket_id(&pool, "TST", "2", "EN", false, "Not in Cardmarket")
+            .await;
+        insert_card(&pool, "TST", "3", "EN", false, "Resolved", 7).await;
+        let repository = ScryfallCatalogRepositoryAdapter::new(pool.clone());
+        let job_repository = EnrichmentJobRepositoryAdapter::new(pool.clone());
+        job_repository
+            .enqueue(
+                EnrichmentKind::CardMarketId,
+                vec![card_id("1"), card_id("2")],
+            )
+            .await
+            .unwrap();
+        repository
+            .save_all(vec![
+                scryfall_card(scryfall_id_of(&pool, "1").await, Some(42)),
+                scryfall_card(scryfall_id_of(&pool, "2").await, None),
+                scryfall_card(scryfall_id_of(&pool, "3").await, Some(99)),
+            ])
+            .await
+            .unwrap();
+
+        let resolved = repository.resolve_cardmarket_ids().await.unwrap();
+
+        assert_eq!(resolved, vec![(card_id("1"), 42)]);
+        let cardmarket_id: Option<i32> =
+            sqlx::query_scalar("SELECT cardmarket_id FROM card WHERE collector_number = '3'")
+                .fetch_one(&pool)
+                .await
+                .unwrap();
+        assert_eq!(cardmarket_id, Some(7));
+        let pending: Vec<String> = sqlx::query_scalar(
+            "SELECT collector_number FROM enrichment_job WHERE kind = 'CARDMARKET_ID'",
+        )
+        .fetch_all(&pool)
+        .await
+        .unwrap();
+        assert_eq!(pending, vec!["2".to_string()]);
+    }
+
+    #[sqlx::test]
+    async fn sync_metadata_copies_the_metadata_of_changed_printings_only(pool: PgPool) {
+        insert_set(&pool, "TST").await;
+        insert_card(&pool, "TST", "1", "EN", false, "Llanowar Elves", 1).await;
+        insert_card(&pool, "TST", "2", "EN", false, "Not in catalogue", 2).await;
+        let repository = ScryfallCatalogRepositoryAdapter::new(pool.clone());
+        let mut card = scryfall_card(scryfall_id_of(&pool, "1").await, Some(1));
+        card.metadata = CardMetadata {
+            mana_cost: Some("{G}".to_string()),
+            type_line: Some("Creature — Elf Druid".to_string()),
+            colors: vec![Color::G],
+            color_identity: vec![Color::G],
+            ..CardMetadata::default()
+        };
+        repository.save_all(vec![card]).await.unwrap();
+
+        assert_eq!(repository.sync_metadata().await.unwrap(), 1);
+        assert_eq!(repository.sync_metadata().await.unwrap(), 0);
+        let (type_line, colors): (Option<String>, Vec<String>) =
+            sqlx::query_as("SELECT type_line, colors FROM card WHERE collector_number = '1'")
+                .fetch_one(&pool)
+                .await
+                .unwrap();
+        assert_eq!(type_line.as_deref(), Some("Creature — Elf Druid"));
+        assert_eq!(colors, vec!["G".to_string()]);
+    }
+}
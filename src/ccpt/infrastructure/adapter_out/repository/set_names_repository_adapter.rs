@@ -28,6 +28,17 @@ impl SetNameRepository for SetNameRepositoryAdapter {
         .is_some())
     }
 
+    async fn find_by_code(&self, code: SetCode) -> Result<Option<SetName>, AppError> {
+        Ok(sqlx::query_as!(
+            SetNameEntity,
+            "SELECT * FROM set_name WHERE set_code = $1",
+            code.to_string()
+        )
+        .fetch_optional(&self.pool)
+        .await?
+        .map(SetName::from))
+    }
+
     async fn save(&self, set: SetName) -> Result<(), AppError> {
         sqlx::query!(
             "INSERT INTO set_name (set_code, name)
@@ -66,6 +77,29 @@ mod tests {
         assert!(exists, "set should exist in the database");
     }
 
+    #[sqlx::test]
+    async fn find_by_code_returns_the_set_name(pool: PgPool) {
+        let adapter = SetNameRepositoryAdapter::new(pool.clone());
+
+        adapter
+            .save(SetName::new("ECC", "Lorwyn Eclipsed Commander"))
+            .await
+            .unwrap();
+
+        let set = adapter.find_by_code(SetCode::new("ECC")).await.unwrap();
+
+        assert_eq!(set, Some(SetName::new("ECC", "Lorwyn Eclipsed Commander")));
+    }
+
+    #[sqlx::test]
+    async fn find_by_code_returns_none_for_unknown_set_code(pool: PgPool) {
+        let adapter = SetNameRepositoryAdapter::new(pool.clone());
+
+        let set = adapter.find_by_code(SetCode::new("ZZZ")).await.unwrap();
+
+        assert!(set.is_none());
+    }
+
     #[sqlx::test]
     async fn save_does_not_insert_duplicate_set_code(pool: PgPool) {
         let adapter = SetNameRepositoryAdapter::new(pool.clone());
@@ -0,0 +1,205 @@
+use crate::application::error::AppError;
+use crate::application::repository::SetPriceIndexRepository;
+use crate::domain::set_name::SetCode;
+use crate::domain::set_price_index::SetPriceIndexEntry;
+use crate::domain::user::UserId;
+use crate::infrastructure::adapter_out::repository::entities::SetPriceIndexEntity;
+use async_trait::async_trait;
+use chrono::NaiveDate;
+use sqlx::{Pool, Postgres};
+
+pub struct SetPriceIndexRepositoryAdapter {
+    pool: Pool<Postgres>,
+}
+
+impl SetPriceIndexRepositoryAdapter {
+    pub fn new(pool: Pool<Postgres>) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl SetPriceIndexRepository for SetPriceIndexRepositoryAdapter {
+    async fn find_by_set_and_date_range(
+        &self,
+        set_code: SetCode,
+        user_id: &UserId,
+        start_date: NaiveDate,
+        end_date: NaiveDate,
+    ) -> Result<Vec<SetPriceIndexEntry>, AppError> {
+        // Une impression = un produit Cardmarket et une finition, toutes langues confondues
+        let entities = sqlx::query_as!(
+            SetPriceIndexEntity,
+            r#"WITH printings AS (SELECT c.cardmarket_id,
+                                         c.foil,
+                                         BOOL_OR(ce.user_id IS NOT NULL) AS owned
+                                  FROM card c
+                                           LEFT JOIN collection_entry ce
+                                                     ON ce.set_code = c.set_code
+                                                         AND ce.collector_number = c.collector_number
+                                                         AND ce.language_code = c.language_code
+                                                         AND ce.foil = c.foil
+                                                         AND ce.user_id = $2
+                                                         AND ce.quantity > 0
+                                  WHERE c.set_code = $1
+                                    AND c.cardmarket_id IS NOT NULL
+                                  GROUP BY c.cardmarket_id, c.foil),
+                    daily AS (SELECT cmp.date,
+                                     p.owned,
+                                     CASE WHEN p.foil THEN cmp.trend_foil ELSE cmp.trend END AS trend
+                              FROM printings p
+                                       JOIN cardmarket_price cmp ON cmp.id_produit = p.cardmarket_id
+                              WHERE cmp.date >= $3
+                                AND cmp.date <= $4
+                                AND NOT CASE WHEN p.foil THEN cmp.quarantined_foil ELSE cmp.quarantined END)
+               SELECT date                                               AS "date!",
+                      COUNT(trend)                                       AS "printings!",
+                      SUM(trend)                                         AS "total",
+                      percentile_disc(0.5) WITHIN GROUP (ORDER BY trend) AS "median",
+                      COALESCE(SUM(trend) FILTER (WHERE owned), 0)       AS "owned!"
+               FROM daily
+               GROUP BY date
+               ORDER BY date"#,
+            set_code.to_string(),
+            user_id.as_str(),
+            start_date,
+            end_date,
+        )
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(entities.into_iter().map(SetPriceIndexEntry::from).collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::infrastructure::adapter_out::repository::common_repository_tests::{
+        insert_card, insert_collection_entry, insert_price, quarantine_price,
+    };
+    use crate::infrastructure::adapter_out::repository::entities::{
+        CardMarketPriceEntity, PriceGuideEntity,
+    };
+    use chrono::Utc;
+    use sqlx::PgPool;
+
+    fn date(day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(2025, 3, day).unwrap()
+    }
+
+    async fn insert_trend(pool: &PgPool, id_produit: i32, day: u32, trend: i32, trend_foil: i32) {
+        insert_price(
+            pool,
+            CardMarketPriceEntity {
+                id_produit,
+                date: date(day),
+                id_category: None,
+                normal: PriceGuideEntity {
+                    low: Some(1),
+                    avg: Some(1),
+                    trend: Some(trend),
+                },
+                foil: PriceGuideEntity {
+                    low: Some(1),
+                    avg: Some(1),
+                    trend: Some(trend_foil),
+                },
+            },
+        )
+        .await;
+    }
+
+    /// Set FDN : la carte 1 en anglais, en français et en foil (2 produits Cardmarket), la
+    /// carte 2 en anglais. user1 possède la carte 1 non foil en deux langues.
+    async fn insert_set_fixture(pool: &PgPool) {
+        insert_card(pool, "FDN", "1", "EN", false, "Card 1", 9001).await;
+        insert_card(pool, "FDN", "1", "FR", false, "Card 1", 9001).await;
+        insert_card(pool, "FDN", "1", "EN", true, "Card 1", 9001).await;
+        insert_card(pool, "FDN", "2", "EN", false, "Card 2", 9002).await;
+        for language_code in ["EN", "FR"] {
+            insert_collection_entry(
+                pool,
+                "FDN",
+                "1",
+                language_code,
+                false,
+                "user1",
+                2,
+                0,
+                Utc::now(),
+            )
+            .await;
+        }
+        insert_trend(pool, 9001, 1, 100, 300).await;
+        insert_trend(pool, 9002, 1, 1000, 5000).await;
+        insert_trend(pool, 9001, 2, 110, 310).await;
+        insert_trend(pool, 9002, 2, 2000, 5000).await;
+    }
+
+    #[sqlx::test]
+    async fn find_by_set_and_date_range_sums_each_printing_once(pool: PgPool) {
+        insert_set_fixture(&pool).await;
+        let repository = SetPriceIndexRepositoryAdapter::new(pool.clone());
+
+        let entries = repository
+            .find_by_set_and_date_range(
+                SetCode::new("FDN"),
+                &UserId::new("user1"),
+                date(1),
+                date(2),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(entries.len(), 2);
+        assert_eq!(entries[0].date, date(1));
+        assert_eq!(entries[0].printings, 3);
+        assert_eq!(entries[0].total.value, Some(1400));
+        assert_eq!(entries[0].median.value, Some(300));
+        assert_eq!(entries[0].owned.value, Some(100));
+        assert_eq!(entries[1].total.value, Some(2420));
+        assert_eq!(entries[1].owned.value, Some(110));
+    }
+
+    #[sqlx::test]
+    async fn find_by_set_and_date_range_returns_zero_owned_for_another_user(pool: PgPool) {
+        insert_set_fixture(&pool).await;
+        let repository = SetPriceIndexRepositoryAdapter::new(pool.clone());
+
+        let entries = repository
+            .find_by_set_and_date_range(
+                SetCode::new("FDN"),
+                &UserId::new("user2"),
+                date(1),
+                date(1),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(entries.len(), 1);
+        assert_eq!(entries[0].total.value, Some(1400));
+        assert_eq!(entries[0].owned.value, Some(0));
+    }
+
+    #[sqlx::test]
+    async fn find_by_set_and_date_range_skips_quarantined_prices(pool: PgPool) {
+        insert_set_fixture(&pool).await;
+        quarantine_price(&pool, 9002, date(2)).await;
+        let repository = SetPriceIndexRepositoryAdapter::new(pool.clone());
+
+        let entries = repository
+            .find_by_set_and_date_range(
+                SetCode::new("FDN"),
+                &UserId::new("user1"),
+                date(2),
+                date(2),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(entries.len(), 1);
+        assert_eq!(entries[0].printings, 2);
+        assert_eq!(entries[0].total.value, Some(420));
+    }
+}
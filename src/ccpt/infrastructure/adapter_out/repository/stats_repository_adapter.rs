@@ -100,7 +100,7 @@ mod tests {
     async fn should_return_zero_card_price_count_when_no_prices_exist(pool: PgPool) {
         let adapter = StatsRepositoryAdapter::new(pool.clone());
 
-        sqlx::query("TRUNCATE cardmarket_price")
+        sqlx::query("TRUNCATE cardmarket_price CASCADE")
             .execute(&pool)
             .await
             .unwrap();
@@ -0,0 +1,341 @@
+use crate::application::error::AppError;
+use crate::application::repository::TradeMatchRepository;
+use crate::domain::trade_match::TradeMatch;
+use crate::domain::user::UserId;
+use crate::infrastructure::adapter_out::repository::entities::TradeMatchCardEntity;
+use async_trait::async_trait;
+use sqlx::{Pool, Postgres};
+
+pub struct TradeMatchRepositoryAdapter {
+    pool: Pool<Postgres>,
+}
+
+impl TradeMatchRepositoryAdapter {
+    pub fn new(pool: Pool<Postgres>) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl TradeMatchRepository for TradeMatchRepositoryAdapter {
+    async fn refresh_user(&self, user_id: &UserId) -> Result<(), AppError> {
+        let mut tx = self.pool.begin().await?;
+
+        sqlx::query!(
+            "DELETE FROM trade_match_card WHERE user_id = $1 OR owner_user_id = $1",
+            user_id.as_str()
+        )
+        .execute(&mut *tx)
+        .await?;
+        // Les souhaits de l'utilisateur face aux autres collections, et ceux des autres face à la
+        // sienne. Les impressions les moins chères d'un propriétaire servent un souhait en premier,
+        // sans jamais dépasser les exemplaires qu'il propose
+        sqlx::query!(
+            r#"INSERT INTO trade_match_card (user_id, owner_user_id, set_code, collector_number, language_code,
+                                             foil, quantity)
+               SELECT user_id, owner_user_id, set_code, collector_number, language_code, foil,
+                      LEAST(SUM(quantity), MAX(owner_quantity))
+               FROM (SELECT w.user_id, cp.user_id AS owner_user_id, cp.set_code, cp.collector_number,
+                            cp.language_code, cp.foil,
+                            LEAST(cp.tradable_quantity, w.quantity - COALESCE(SUM(cp.tradable_quantity) OVER (
+                                PARTITION BY w.id, cp.user_id
+                                ORDER BY cp.trend NULLS LAST, cp.set_code, cp.collector_number, cp.language_code,
+                                    cp.foil, cp.binder_id
+                                ROWS BETWEEN UNBOUNDED PRECEDING AND 1 PRECEDING), 0)) AS quantity,
+                            SUM(cp.tradable_quantity) OVER (
+                                PARTITION BY w.id, cp.user_id, cp.set_code, cp.collector_number, cp.language_code,
+                                    cp.foil) AS owner_quantity
+                     FROM wishlist_entry w
+                              JOIN card_prices cp ON cp.user_id != w.user_id
+                              JOIN card c ON c.set_code = cp.set_code AND c.collector_number = cp.collector_number
+                         AND c.language_code = cp.language_code AND c.foil = cp.foil
+                     WHERE (w.user_id = $1 OR cp.user_id = $1)
+                       AND cp.tradable_quantity > 0
+                       AND (c.oracle_id = w.oracle_id
+                         OR (cp.set_code, cp.collector_number, cp.language_code, cp.foil)
+                                = (w.set_code, w.collector_number, w.language_code, w.foil))
+                       AND (cardinality(w.languages) = 0 OR cp.language_code = ANY (w.languages))
+                       AND (cardinality(w.finishes) = 0
+                         OR CASE WHEN cp.foil THEN 'FOIL' ELSE 'NON_FOIL' END = ANY (w.finishes))
+                       AND (w.max_price IS NULL OR cp.trend <= w.max_price)) matched
+               WHERE quantity > 0
+               GROUP BY user_id, owner_user_id, set_code, collector_number, language_code, foil"#,
+            user_id.as_str()
+        )
+        .execute(&mut *tx)
+        .await?;
+
+        tx.commit().await?;
+        Ok(())
+    }
+
+    async fn find_mutual(&self, user_id: &UserId) -> Result<Vec<TradeMatch>, AppError> {
+        // Seuls les utilisateurs avec qui l'échange va dans les deux sens sont gardés ; les
+        // cartes sont valorisées à la tendance du jour
+        let entities = sqlx::query_as!(
+            TradeMatchCardEntity,
+            r#"SELECT m.other_user_id AS "other_user_id!", u.username AS other_username, m.wanted AS "wanted!",
+                      m.set_code AS "set_code!", m.collector_number AS "collector_number!",
+                      m.language_code AS "language_code!", m.foil AS "foil!", m.quantity AS "quantity!",
+                      c.name,
+                      (SELECT MAX(cp.trend)
+                       FROM card_prices cp
+                       WHERE cp.user_id = m.owner_user_id AND cp.set_code = m.set_code
+                         AND cp.collector_number = m.collector_number AND cp.language_code = m.language_code
+                         AND cp.foil = m.foil) AS trend
+               FROM (SELECT owner_user_id AS other_user_id, owner_user_id, TRUE AS wanted, set_code,
+                            collector_number, language_code, foil, quantity
+                     FROM trade_match_card
+                     WHERE user_id = $1
+                     UNION ALL
+                     SELECT user_id, owner_user_id, FALSE, set_code, collector_number, language_code, foil,
+                            quantity
+                     FROM trade_match_card
+                     WHERE owner_user_id = $1) m
+                        JOIN users u ON u.id = m.other_user_id
+                        JOIN card c ON c.set_code = m.set_code AND c.collector_number = m.collector_number
+                   AND c.language_code = m.language_code AND c.foil = m.foil
+               WHERE EXISTS (SELECT 1
+                             FROM trade_match_card r
+                             WHERE r.user_id = $1 AND r.owner_user_id = m.other_user_id)
+                 AND EXISTS (SELECT 1
+                             FROM trade_match_card r
+                             WHERE r.user_id = m.other_user_id AND r.owner_user_id = $1)
+               ORDER BY m.other_user_id, c.name, m.set_code, m.collector_number, m.language_code, m.foil"#,
+            user_id.as_str()
+        )
+        .fetch_all(&self.pool)
+        .await?;
+
+        let mut matches: Vec<TradeMatch> = Vec::new();
+        for entity in entities {
+            if matches
+                .last()
+                .is_none_or(|m| m.other_user_id.as_str() != entity.other_user_id)
+            {
+                matches.push(TradeMatch {
+                    other_user_id: UserId::new(entity.other_user_id.clone()),
+                    other_username: entity.other_username.clone(),
+                    wanted: vec![],
+                    offered: vec![],
+                });
+            }
+            let current = matches.last_mut().expect("match pushed above");
+            if entity.wanted {
+                current.wanted.push(entity.into());
+            } else {
+                current.offered.push(entity.into());
+            }
+        }
+        Ok(matches)
+    }
+
+    async fn find_price_capped_wishers(
+        &self,
+        id_products: Vec<u32>,
+    ) -> Result<Vec<UserId>, AppError> {
+        let id_products: Vec<i32> = id_products.into_iter().map(|id| id as i32).collect();
+
+        let user_ids = sqlx::query_scalar!(
+            r#"SELECT DISTINCT w.user_id
+               FROM wishlist_entry w
+                        JOIN card c ON c.oracle_id = w.oracle_id
+                   OR (c.set_code, c.collector_number, c.language_code, c.foil)
+                          = (w.set_code, w.collector_number, w.language_code, w.foil)
+               WHERE w.max_price IS NOT NULL
+                 AND c.cardmarket_id = ANY ($1)"#,
+            &id_products,
+        )
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(user_ids.into_iter().map(UserId::new).collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::card::CardId;
+    use crate::domain::language_code::LanguageCode;
+    use crate::domain::trade_match::TradeMatchCard;
+    use crate::infrastructure::adapter_out::repository::common_repository_tests::{
+        insert_card, insert_collection_entry, insert_price, insert_set, insert_user, refresh_view,
+        set_oracle_id,
+    };
+    use crate::infrastructure::adapter_out::repository::entities::CardMarketPriceEntity;
+    use chrono::Utc;
+    use sqlx::PgPool;
+    use uuid::Uuid;
+
+    const ORACLE_ID: &str = "44623693-51d6-49ad-8cd7-140505caf02f";
+
+    async fn insert_wish(pool: &PgPool, user_id: &str, quantity: i32, max_price: Option<i32>) {
+        sqlx::query(
+            r#"INSERT INTO wishlist_entry (id, user_id, oracle_id, name, quantity, max_price)
+               VALUES ($1, $2, $3, 'Card X', $4, $5)"#,
+        )
+        .bind(Uuid::new_v4())
+        .bind(user_id)
+        .bind(Uuid::parse_str(ORACLE_ID).unwrap())
+        .bind(quantity)
+        .bind(max_price)
+        .execute(pool)
+        .await
+        .unwrap();
+    }
+
+    async fn insert_printing_wish(pool: &PgPool, user_id: &str, collector_number: &str) {
+        sqlx::query(
+            r#"INSERT INTO wishlist_entry (id, user_id, set_code, collector_number, language_code, foil, name,
+                                           quantity)
+               VALUES ($1, $2, 'TST', $3, 'EN', FALSE, 'Card Y', 1)"#,
+        )
+        .bind(Uuid::new_v4())
+        .bind(user_id)
+        .bind(collector_number)
+        .execute(pool)
+        .await
+        .unwrap();
+    }
+
+    /// Bob offers 2 copies of two printings of card X (trends 100 and 50), Alice 4 copies of
+    /// card Y (trend 200).
+    async fn setup(pool: &PgPool) {
+        insert_set(pool, "TST").await;
+        insert_card(pool, "TST", "1", "EN", false, "Card X", 1).await;
+        insert_card(pool, "TST", "2", "EN", false, "Card X", 2).await;
+        insert_card(pool, "TST", "3", "EN", false, "Card Y", 3).await;
+        set_oracle_id(pool, "TST", "1", Uuid::parse_str(ORACLE_ID).unwrap()).await;
+        set_oracle_id(pool, "TST", "2", Uuid::parse_str(ORACLE_ID).unwrap()).await;
+        insert_price(pool, CardMarketPriceEntity::simple(1, 100)).await;
+        insert_price(pool, CardMarketPriceEntity::simple(2, 50)).await;
+        insert_price(pool, CardMarketPriceEntity::simple(3, 200)).await;
+        insert_user(pool, "alice", "Alice").await;
+        insert_user(pool, "bob", "Bob").await;
+        insert_collection_entry(pool, "TST", "1", "EN", false, "bob", 2, 0, Utc::now()).await;
+        insert_collection_entry(pool, "TST", "2", "EN", false, "bob", 2, 0, Utc::now()).await;
+        insert_collection_entry(pool, "TST", "3", "EN", false, "alice", 4, 0, Utc::now()).await;
+        refresh_view(pool).await;
+    }
+
+    type Printings = Vec<(String, u8)>;
+
+    fn printings(cards: &[TradeMatchCard]) -> Printings {
+        cards
+            .iter()
+            .map(|c| (c.card_id.collector_number.clone(), c.quantity))
+            .collect()
+    }
+
+    fn summary(m: &TradeMatch) -> (Printings, Printings) {
+        (printings(&m.wanted), printings(&m.offered))
+    }
+
+    #[sqlx::test]
+    async fn refresh_user_matches_both_directions_the_cheapest_printings_first(pool: PgPool) {
+        setup(&pool).await;
+        insert_wish(&pool, "alice", 3, None).await;
+        insert_printing_wish(&pool, "bob", "3").await;
+        let adapter = TradeMatchRepositoryAdapter::new(pool);
+
+        adapter.refresh_user(&UserId::new("alice")).await.unwrap();
+        let alice = adapter.find_mutual(&UserId::new("alice")).await.unwrap();
+        let bob = adapter.find_mutual(&UserId::new("bob")).await.unwrap();
+
+        assert_eq!(alice.len(), 1);
+        assert_eq!(alice[0].other_username, "Bob");
+        assert_eq!(
+            summary(&alice[0]),
+            (
+                vec![("1".to_string(), 1), ("2".to_string(), 2)],
+                vec![("3".to_string(), 1)]
+            )
+        );
+        assert_eq!(alice[0].wanted_value(), 200);
+        assert_eq!(alice[0].offered_value(), 200);
+        assert_eq!(bob[0].other_user_id, UserId::new("alice"));
+        assert_eq!(
+            summary(&bob[0]),
+            (
+                vec![("3".to_string(), 1)],
+                vec![("1".to_string(), 1), ("2".to_string(), 2)]
+            )
+        );
+    }
+
+    #[sqlx::test]
+    async fn find_mutual_leaves_out_one_way_matches(pool: PgPool) {
+        setup(&pool).await;
+        insert_wish(&pool, "alice", 3, None).await;
+        let adapter = TradeMatchRepositoryAdapter::new(pool);
+
+        adapter.refresh_user(&UserId::new("alice")).await.unwrap();
+
+        assert!(
+            adapter
+                .find_mutual(&UserId::new("alice"))
+                .await
+                .unwrap()
+                .is_empty()
+        );
+    }
+
+    #[sqlx::test]
+    async fn refresh_user_applies_the_max_price_and_replaces_previous_matches(pool: PgPool) {
+        setup(&pool).await;
+        insert_wish(&pool, "alice", 3, Some(60)).await;
+        insert_printing_wish(&pool, "bob", "3").await;
+        let adapter = TradeMatchRepositoryAdapter::new(pool.clone());
+        adapter.refresh_user(&UserId::new("bob")).await.unwrap();
+
+        sqlx::query("DELETE FROM wishlist_entry WHERE user_id = 'bob'")
+            .execute(&pool)
+            .await
+            .unwrap();
+        let before = adapter.find_mutual(&UserId::new("alice")).await.unwrap();
+        adapter.refresh_user(&UserId::new("bob")).await.unwrap();
+        let after = adapter.find_mutual(&UserId::new("alice")).await.unwrap();
+
+        assert_eq!(
+            summary(&before[0]),
+            (vec![("2".to_string(), 2)], vec![("3".to_string(), 1)])
+        );
+        assert!(after.is_empty());
+    }
+
+    #[sqlx::test]
+    async fn find_mutual_values_cards_at_the_current_trend(pool: PgPool) {
+        setup(&pool).await;
+        insert_wish(&pool, "alice", 1, None).await;
+        insert_printing_wish(&pool, "bob", "3").await;
+        let adapter = TradeMatchRepositoryAdapter::new(pool.clone());
+        adapter.refresh_user(&UserId::new("alice")).await.unwrap();
+
+        sqlx::query("UPDATE card_prices SET trend = 80 WHERE collector_number = '2'")
+            .execute(&pool)
+            .await
+            .unwrap();
+        let matches = adapter.find_mutual(&UserId::new("alice")).await.unwrap();
+
+        assert_eq!(
+            matches[0].wanted[0].card_id,
+            CardId::new("TST", "2", LanguageCode::EN, false)
+        );
+        assert_eq!(matches[0].wanted[0].trend, Some(80));
+    }
+
+    #[sqlx::test]
+    async fn find_price_capped_wishers_returns_users_capping_a_wish_on_the_products(pool: PgPool) {
+        setup(&pool).await;
+        insert_wish(&pool, "alice", 1, Some(60)).await;
+        insert_wish(&pool, "bob", 1, None).await;
+        let adapter = TradeMatchRepositoryAdapter::new(pool.clone());
+
+        let wishers = adapter.find_price_capped_wishers(vec![2]).await.unwrap();
+        let unrelated = adapter.find_price_capped_wishers(vec![3]).await.unwrap();
+
+        assert_eq!(wishers, vec![UserId::new("alice")]);
+        assert!(unrelated.is_empty());
+    }
+}
@@ -24,20 +24,22 @@ impl TradeRepository for TradeRepositoryAdapter {
         user_id: &UserId,
         card_id: &CardId,
     ) -> Result<Option<i32>, AppError> {
-        let row = sqlx::query!(
-            r#"SELECT quantity FROM collection_entry
+        // Exemplaires échangeables calculés par la vue source de card_prices, sans attendre son
+        // rafraîchissement ; NULL quand aucun n'est proposé
+        let quantity = sqlx::query_scalar!(
+            r#"SELECT SUM(tradable_quantity)::INTEGER FROM card_prices_source
                 WHERE user_id = $1 AND set_code = $2 AND collector_number = $3
-                  AND language_code = $4 AND foil = $5"#,
+                  AND language_code = $4 AND foil = $5 AND tradable_quantity > 0"#,
             user_id.as_str(),
             card_id.set_code.to_string(),
             card_id.collector_number,
             card_id.language_code.to_string(),
             card_id.foil,
         )
-        .fetch_optional(&self.pool)
+        .fetch_one(&self.pool)
         .await?;
 
-        Ok(row.map(|r| r.quantity))
+        Ok(quantity)
     }
 
     async fn find_active_trade(
@@ -303,9 +305,10 @@ mod tests {
     use super::*;
     use crate::domain::language_code::LanguageCode;
     use crate::infrastructure::adapter_out::repository::common_repository_tests::{
-        insert_card, insert_collection_entry, insert_trade, insert_trade_card, insert_user,
+        insert_binder, insert_binder_entry, insert_card, insert_collection_entry,
+        insert_default_binder, insert_trade, insert_trade_card, insert_user,
         mark_trade_accepted_by_both, mark_trade_party_accepted, mark_trade_party_confirmed,
-        mark_trade_party_rated,
+        mark_trade_party_rated, set_keep_quantity, set_tradable_quantity,
     };
     use sqlx::PgPool;
 
@@ -339,6 +342,94 @@ mod tests {
         assert_eq!(result, Some(3));
     }
 
+    #[sqlx::test]
+    async fn find_collection_entry_quantity_sums_the_tradable_binders_only(pool: PgPool) {
+        insert_user(&pool, "user_b", "bob").await;
+        insert_card(&pool, "FDN", "87", "FR", false, "Goblin Boarders", 1).await;
+        insert_collection_entry(
+            &pool,
+            "FDN",
+            "87",
+            "FR",
+            false,
+            "user_b",
+            3,
+            100,
+            chrono::Utc::now(),
+        )
+        .await;
+        for (name, tradable) in [("Trades", true), ("Deck box", false)] {
+            let binder = insert_binder(&pool, "user_b", name, tradable).await;
+            insert_binder_entry(
+                &pool,
+                binder,
+                "FDN",
+                "87",
+                "FR",
+                false,
+                "user_b",
+                2,
+                100,
+                chrono::Utc::now(),
+            )
+            .await;
+        }
+
+        let repository = TradeRepositoryAdapter::new(pool);
+        let result = repository
+            .find_collection_entry_quantity(&UserId::new("user_b"), &make_card_id())
+            .await
+            .unwrap();
+
+        assert_eq!(result, Some(5));
+    }
+
+    #[sqlx::test]
+    async fn find_collection_entry_quantity_applies_the_keep_rule_and_the_entry_override(
+        pool: PgPool,
+    ) {
+        insert_user(&pool, "user_b", "bob").await;
+        insert_card(&pool, "FDN", "87", "FR", false, "Goblin Boarders", 1).await;
+        insert_collection_entry(
+            &pool,
+            "FDN",
+            "87",
+            "FR",
+            false,
+            "user_b",
+            5,
+            100,
+            chrono::Utc::now(),
+        )
+        .await;
+        let collection = insert_default_binder(&pool, "user_b").await;
+        set_keep_quantity(&pool, collection, 4).await;
+        let trades = insert_binder(&pool, "user_b", "Trades", true).await;
+        insert_binder_entry(
+            &pool,
+            trades,
+            "FDN",
+            "87",
+            "FR",
+            false,
+            "user_b",
+            3,
+            100,
+            chrono::Utc::now(),
+        )
+        .await;
+        set_keep_quantity(&pool, trades, 4).await;
+        set_tradable_quantity(&pool, trades, 2).await;
+
+        let repository = TradeRepositoryAdapter::new(pool);
+        let result = repository
+            .find_collection_entry_quantity(&UserId::new("user_b"), &make_card_id())
+            .await
+            .unwrap();
+
+        assert_eq!(result, Some(3));
+    }
+
     #[sqlx::test]
     async fn find_collection_entry_quantity_returns_none_when_not_found(pool: PgPool) {
         let repository = TradeRepositoryAdapter::new(pool);
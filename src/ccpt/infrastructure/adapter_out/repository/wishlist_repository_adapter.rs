@@ -0,0 +1,567 @@
+use crate::application::error::AppError;
+use crate::application::repository::WishlistRepository;
+use crate::domain::card::CardSelector;
+use crate::domain::user::UserId;
+use crate::domain::wishlist::{WishlistEntry, WishlistEntryId, WishlistEntryUpdate, WishlistItem};
+use crate::infrastructure::adapter_out::repository::entities::WishlistItemEntity;
+use async_trait::async_trait;
+use sqlx::{Pool, Postgres, QueryBuilder};
+use std::collections::HashMap;
+use uuid::Uuid;
+
+pub struct WishlistRepositoryAdapter {
+    pool: Pool<Postgres>,
+}
+
+impl WishlistRepositoryAdapter {
+    pub fn new(pool: Pool<Postgres>) -> Self {
+        Self { pool }
+    }
+
+    /// The user's wishes, or only `id`, with the tradable copies of other users matching them.
+    async fn fetch_items(
+        &self,
+        user_id: &UserId,
+        id: Option<WishlistEntryId>,
+    ) -> Result<Vec<WishlistItem>, AppError> {
+        // Une offre correspond à l'impression souhaitée, ou à n'importe quelle impression de la
+        // carte dans les langues, finitions et prix acceptés
+        let entities = sqlx::query_as!(
+            WishlistItemEntity,
+            r#"SELECT w.id, w.user_id, w.set_code, w.collector_number, w.language_code, w.foil, w.oracle_id,
+                      w.name, w.quantity, w.max_price, w.languages, w.finishes, w.priority, w.created_at,
+                      o.offer_count AS "offer_count!", o.cheapest_offer
+               FROM wishlist_entry w
+                        CROSS JOIN LATERAL (
+                   SELECT COUNT(DISTINCT cp.user_id) AS offer_count, MIN(cp.trend) AS cheapest_offer
+                   FROM card_prices cp
+                            JOIN card c ON c.set_code = cp.set_code AND c.collector_number = cp.collector_number
+                       AND c.language_code = cp.language_code AND c.foil = cp.foil
+                   WHERE cp.user_id != w.user_id
+                     AND cp.tradable_quantity > 0
+                     AND (c.oracle_id = w.oracle_id
+                       OR (cp.set_code, cp.collector_number, cp.language_code, cp.foil)
+                          = (w.set_code, w.collector_number, w.language_code, w.foil))
+                     AND (cardinality(w.languages) = 0 OR cp.language_code = ANY (w.languages))
+                     AND (cardinality(w.finishes) = 0
+                       OR CASE WHEN cp.foil THEN 'FOIL' ELSE 'NON_FOIL' END = ANY (w.finishes))
+                     AND (w.max_price IS NULL OR cp.trend <= w.max_price)) o
+               WHERE w.user_id = $1
+                 AND ($2::UUID IS NULL OR w.id = $2)
+               ORDER BY CASE w.priority WHEN 'HIGH' THEN 0 WHEN 'NORMAL' THEN 1 ELSE 2 END, w.name,
+                        w.created_at"#,
+            user_id.as_str(),
+            id.map(|id| id.0)
+        )
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(entities.into_iter().map(WishlistItem::from).collect())
+    }
+}
+
+#[async_trait]
+impl WishlistRepository for WishlistRepositoryAdapter {
+    async fn find_all(&self, user_id: &UserId) -> Result<Vec<WishlistItem>, AppError> {
+        self.fetch_items(user_id, None).await
+    }
+
+    async fn find_by_id(
+        &self,
+        user_id: &UserId,
+        id: WishlistEntryId,
+    ) -> Result<Option<WishlistItem>, AppError> {
+        Ok(self.fetch_items(user_id, Some(id)).await?.pop())
+    }
+
+    async fn find_card_name(&self, card: &CardSelector) -> Result<Option<String>, AppError> {
+        // Le catalogue Scryfall couvre les cartes que personne ne possède encore
+        let name = match card {
+            CardSelector::Printing(card_id) => {
+                sqlx::query_scalar!(
+                    r#"SELECT name AS "name!"
+                       FROM (SELECT name::TEXT
+                             FROM card
+                             WHERE set_code = $1 AND collector_number = $2 AND language_code = $3
+                               AND foil = $4
+                             UNION ALL
+                             SELECT name
+                             FROM scryfall_card
+                             WHERE UPPER(set_code) = $1 AND collector_number = $2) names
+                       LIMIT 1"#,
+                    card_id.set_code.to_string(),
+                    card_id.collector_number,
+                    card_id.language_code.to_string(),
+                    card_id.foil
+                )
+                .fetch_optional(&self.pool)
+                .await?
+            }
+            CardSelector::AnyPrinting { oracle_id } => {
+                sqlx::query_scalar!(
+                    r#"SELECT name AS "name!"
+                       FROM (SELECT name::TEXT FROM card WHERE oracle_id = $1
+                             UNION ALL
+                             SELECT name FROM scryfall_card WHERE oracle_id = $1) names
+                       LIMIT 1"#,
+                    oracle_id
+                )
+                .fetch_optional(&self.pool)
+                .await?
+            }
+        };
+
+        Ok(name)
+    }
+
+    async fn find_oracle_ids_by_names(
+        &self,
+        names: Vec<String>,
+    ) -> Result<HashMap<String, (Uuid, String)>, AppError> {
+        // Les listes de cartes ne donnent souvent que la première face d'une carte double
+        let rows = sqlx::query!(
+            r#"SELECT DISTINCT ON (looked_up) looked_up AS "looked_up!", oracle_id AS "oracle_id!",
+                                              name AS "name!"
+               FROM (SELECT LOWER(name) AS looked_up, oracle_id, name
+                     FROM scryfall_card
+                     WHERE LOWER(name) = ANY ($1)
+                     UNION ALL
+                     SELECT LOWER(split_part(name, ' // ', 1)), oracle_id, name
+                     FROM scryfall_card
+                     WHERE LOWER(split_part(name, ' // ', 1)) = ANY ($1)
+                     UNION ALL
+                     SELECT LOWER(name), oracle_id, name::TEXT
+                     FROM card
+                     WHERE LOWER(name) = ANY ($1)
+                     UNION ALL
+                     SELECT LOWER(split_part(name, ' // ', 1)), oracle_id, name::TEXT
+                     FROM card
+                     WHERE LOWER(split_part(name, ' // ', 1)) = ANY ($1)) found
+               WHERE oracle_id IS NOT NULL
+               ORDER BY looked_up"#,
+            &names
+        )
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(rows
+            .into_iter()
+            .map(|row| (row.looked_up, (row.oracle_id, row.name)))
+            .collect())
+    }
+
+    async fn create(&self, entry: WishlistEntry) -> Result<bool, AppError> {
+        let mut qb: QueryBuilder<Postgres> = QueryBuilder::new(
+            "
+            INSERT INTO wishlist_entry
+                (id, user_id, set_code, collector_number, language_code, foil, oracle_id, name,
+                 quantity, max_price, languages, finishes, priority, created_at)
+            ",
+        );
+        push_entries(&mut qb, std::slice::from_ref(&entry));
+        qb.push(" ON CONFLICT DO NOTHING");
+
+        let inserted = qb.build().execute(&self.pool).await?;
+        Ok(inserted.rows_affected() > 0)
+    }
+
+    async fn merge_all(&self, entries: Vec<WishlistEntry>) -> Result<u32, AppError> {
+        const CHUNK_SIZE: usize = 1000;
+        let mut merged = 0;
+
+        for chunk in entries.chunks(CHUNK_SIZE) {
+            let mut qb: QueryBuilder<Postgres> = QueryBuilder::new(
+                "
+                INSERT INTO wishlist_entry
+                    (id, user_id, set_code, collector_number, language_code, foil, oracle_id, name,
+                     quantity, max_price, languages, finishes, priority, created_at)
+                ",
+            );
+            push_entries(&mut qb, chunk);
+            qb.push(
+                "
+                ON CONFLICT (user_id, oracle_id) DO UPDATE
+                SET quantity = EXCLUDED.quantity
+                WHERE wishlist_entry.quantity < EXCLUDED.quantity
+                ",
+            );
+
+            // Un souhait déjà assez élevé n'est pas réécrit, donc pas compté
+            merged += qb.build().execute(&self.pool).await?.rows_affected();
+        }
+
+        Ok(merged as u32)
+    }
+
+    async fn update(
+        &self,
+        user_id: &UserId,
+        id: WishlistEntryId,
+        update: WishlistEntryUpdate,
+    ) -> Result<bool, AppError> {
+        let languages = update
+            .languages
+            .map(|languages| languages.iter().map(|l| l.to_string()).collect::<Vec<_>>());
+        let finishes = update.finishes.map(|finishes| {
+            finishes
+                .iter()
+                .map(|f| f.as_db_str().to_string())
+                .collect::<Vec<_>>()
+        });
+        let updated = sqlx::query!(
+            r#"UPDATE wishlist_entry
+               SET quantity  = COALESCE($1, quantity),
+                   max_price = CASE WHEN $2 THEN $3 ELSE max_price END,
+                   languages = COALESCE($4, languages),
+                   finishes  = COALESCE($5, finishes),
+                   priority  = COALESCE($6, priority)
+               WHERE id = $7 AND user_id = $8"#,
+            update.quantity.map(|q| q as i32),
+            update.max_price.is_some(),
+            update.max_price.flatten().map(|p| p as i32),
+            languages.as_deref(),
+            finishes.as_deref(),
+            update.priority.map(|p| p.as_db_str()),
+            id.0,
+            user_id.as_str()
+        )
+        .execute(&self.pool)
+        .await?;
+
+        Ok(updated.rows_affected() > 0)
+    }
+
+    async fn delete(&self, user_id: &UserId, id: WishlistEntryId) -> Result<bool, AppError> {
+        let deleted = sqlx::query!(
+            "DELETE FROM wishlist_entry WHERE id = $1 AND user_id = $2",
+            id.0,
+            user_id.as_str()
+        )
+        .execute(&self.pool)
+        .await?;
+
+        Ok(deleted.rows_affected() > 0)
+    }
+}
+
+fn push_entries(qb: &mut QueryBuilder<Postgres>, entries: &[WishlistEntry]) {
+    qb.push_values(entries, |mut b, entry| {
+        let (printing, oracle_id) = match &entry.card {
+            CardSelector::Printing(card_id) => (Some(card_id), None),
+            CardSelector::AnyPrinting { oracle_id } => (None, Some(*oracle_id)),
+        };
+        b.push_bind(entry.id.0)
+            .push_bind(entry.user_id.as_str().to_string())
+            .push_bind(printing.map(|c| c.set_code.to_string()))
+            .push_bind(printing.map(|c| c.collector_number.clone()))
+            .push_bind(printing.map(|c| c.language_code.to_string()))
+            .push_bind(printing.map(|c| c.foil))
+            .push_bind(oracle_id)
+            .push_bind(entry.name.clone())
+            .push_bind(entry.quantity as i32)
+            .push_bind(entry.max_price.map(|p| p as i32))
+            .push_bind(
+                entry
+                    .languages
+                    .iter()
+                    .map(|l| l.to_string())
+                    .collect::<Vec<_>>(),
+            )
+            .push_bind(
+                entry
+                    .finishes
+                    .iter()
+                    .map(|f| f.as_db_str().to_string())
+                    .collect::<Vec<_>>(),
+            )
+            .push_bind(entry.priority.as_db_str())
+            .push_bind(entry.created_at);
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::card::CardId;
+    use crate::domain::language_code::LanguageCode;
+    use crate::domain::wishlist::{Finish, WishPriority};
+    use crate::infrastructure::adapter_out::repository::common_repository_tests::{
+        insert_card, insert_collection_entry, insert_price, insert_scryfall_card, insert_set,
+        refresh_view, set_oracle_id,
+    };
+    use crate::infrastructure::adapter_out::repository::entities::CardMarketPriceEntity;
+    use chrono::Utc;
+    use sqlx::PgPool;
+
+    fn oracle_id() -> Uuid {
+        Uuid::parse_str("44623693-51d6-49ad-8cd7-140505caf02f").unwrap()
+    }
+
+    fn wish(card: CardSelector) -> WishlistEntry {
+        WishlistEntry {
+            id: WishlistEntryId::new(),
+            user_id: UserId::new("user1"),
+            card,
+            name: "Test Card".to_string(),
+            quantity: 1,
+            max_price: None,
+            languages: vec![],
+            finishes: vec![],
+            priority: WishPriority::Normal,
+            created_at: Utc::now(),
+        }
+    }
+
+    fn any_printing() -> CardSelector {
+        CardSelector::AnyPrinting {
+            oracle_id: oracle_id(),
+        }
+    }
+
+    #[sqlx::test]
+    async fn find_all_counts_the_matching_offers_of_other_users(pool: PgPool) {
+        insert_set(&pool, "TST").await;
+        insert_card(&pool, "TST", "1", "EN", false, "Test Card", 1).await;
+        insert_card(&pool, "TST", "1", "FR", false, "Test Card", 2).await;
+        insert_card(&pool, "TST", "1", "FR", true, "Test Card", 2).await;
+        set_oracle_id(&pool, "TST", "1", oracle_id()).await;
+        insert_price(&pool, CardMarketPriceEntity::simple(1, 100)).await;
+        insert_price(&pool, CardMarketPriceEntity::with_foil(2, 300, 900)).await;
+        insert_collection_entry(&pool, "TST", "1", "EN", false, "user1", 1, 0, Utc::now()).await;
+        insert_collection_entry(&pool, "TST", "1", "EN", false, "userB", 1, 0, Utc::now()).await;
+        insert_collection_entry(&pool, "TST", "1", "FR", false, "userC", 1, 0, Utc::now()).await;
+        insert_collection_entry(&pool, "TST", "1", "FR", true, "userD", 1, 0, Utc::now()).await;
+        refresh_view(&pool).await;
+        let adapter = WishlistRepositoryAdapter::new(pool);
+        let printing = CardSelector::Printing(CardId::new("TST", "1", LanguageCode::FR, false));
+        adapter
+            .create(WishlistEntry {
+                priority: WishPriority::High,
+                ..wish(printing.clone())
+            })
+            .await
+            .unwrap();
+        adapter
+            .create(WishlistEntry {
+                max_price: Some(500),
+                finishes: vec![Finish::NonFoil],
+                ..wish(any_printing())
+            })
+            .await
+            .unwrap();
+
+        let items = adapter.find_all(&UserId::new("user1")).await.unwrap();
+
+        let summary: Vec<(&CardSelector, u64, Option<u32>)> = items
+            .iter()
+            .map(|i| (&i.entry.card, i.offer_count, i.cheapest_offer))
+            .collect();
+        assert_eq!(
+            summary,
+            vec![(&printing, 1, Some(300)), (&any_printing(), 2, Some(100)),]
+        );
+        assert_eq!(items[1].entry.finishes, vec![Finish::NonFoil]);
+    }
+
+    #[sqlx::test]
+    async fn create_refuses_a_card_already_wished(pool: PgPool) {
+        let adapter = WishlistRepositoryAdapter::new(pool);
+
+        assert!(adapter.create(wish(any_printing())).await.unwrap());
+        assert!(!adapter.create(wish(any_printing())).await.unwrap());
+        assert!(
+            adapter
+                .create(WishlistEntry {
+                    user_id: UserId::new("user2"),
+                    ..wish(any_printing())
+                })
+                .await
+                .unwrap()
+        );
+    }
+
+    #[sqlx::test]
+    async fn update_changes_the_given_fields_and_clears_the_max_price(pool: PgPool) {
+        let adapter = WishlistRepositoryAdapter::new(pool);
+        let entry = WishlistEntry {
+            max_price: Some(200),
+            ..wish(any_printing())
+        };
+        adapter.create(entry.clone()).await.unwrap();
+
+        let updated = adapter
+            .update(
+                &UserId::new("user1"),
+                entry.id,
+                WishlistEntryUpdate {
+                    quantity: Some(3),
+                    max_price: Some(None),
+                    languages: Some(vec![LanguageCode::EN, LanguageCode::FR]),
+                    ..WishlistEntryUpdate::default()
+                },
+            )
+            .await
+            .unwrap();
+
+        assert!(updated);
+        let item = adapter
+            .find_by_id(&UserId::new("user1"), entry.id)
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(item.entry.quantity, 3);
+        assert_eq!(item.entry.max_price, None);
+        assert_eq!(
+            item.entry.languages,
+            vec![LanguageCode::EN, LanguageCode::FR]
+        );
+        assert_eq!(item.entry.priority, WishPriority::Normal);
+        assert!(
+            !adapter
+                .update(
+                    &UserId::new("user2"),
+                    entry.id,
+                    WishlistEntryUpdate::default()
+                )
+                .await
+                .unwrap()
+        );
+    }
+
+    #[sqlx::test]
+    async fn delete_ignores_other_users_wishes(pool: PgPool) {
+        let adapter = WishlistRepositoryAdapter::new(pool);
+        let entry = wish(any_printing());
+        adapter.create(entry.clone()).await.unwrap();
+
+        assert!(
+            !adapter
+                .delete(&UserId::new("user2"), entry.id)
+                .await
+                .unwrap()
+        );
+        assert!(
+            adapter
+                .delete(&UserId::new("user1"), entry.id)
+                .await
+                .unwrap()
+        );
+        assert!(
+            adapter
+                .find_by_id(&UserId::new("user1"), entry.id)
+                .await
+                .unwrap()
+                .is_none()
+        );
+    }
+
+    #[sqlx::test]
+    async fn merge_all_only_raises_the_quantity_of_a_wish(pool: PgPool) {
+        let adapter = WishlistRepositoryAdapter::new(pool);
+        let entry = WishlistEntry {
+            quantity: 3,
+            priority: WishPriority::High,
+            ..wish(any_printing())
+        };
+        adapter.create(entry.clone()).await.unwrap();
+
+        let kept_count = adapter
+            .merge_all(vec![WishlistEntry {
+                quantity: 2,
+                ..wish(any_printing())
+            }])
+            .await
+            .unwrap();
+        let kept = adapter
+            .find_by_id(&UserId::new("user1"), entry.id)
+            .await
+            .unwrap()
+            .unwrap();
+        let raised_count = adapter
+            .merge_all(vec![WishlistEntry {
+                quantity: 4,
+                ..wish(any_printing())
+            }])
+            .await
+            .unwrap();
+        let raised = adapter
+            .find_by_id(&UserId::new("user1"), entry.id)
+            .await
+            .unwrap()
+            .unwrap();
+
+        assert_eq!((kept_count, kept.entry.quantity), (0, 3));
+        assert_eq!((raised_count, raised.entry.quantity), (1, 4));
+        assert_eq!(raised.entry.priority, WishPriority::High);
+    }
+
+    #[sqlx::test]
+    async fn find_oracle_ids_by_names_matches_the_front_face(pool: PgPool) {
+        insert_scryfall_card(
+            &pool,
+            "Delver of Secrets // Insectile Aberration",
+            "isd",
+            "51",
+            oracle_id(),
+        )
+        .await;
+        let adapter = WishlistRepositoryAdapter::new(pool);
+
+        let found = adapter
+            .find_oracle_ids_by_names(vec![
+                "delver of secrets".to_string(),
+                "insectile aberration".to_string(),
+            ])
+            .await
+            .unwrap();
+
+        assert_eq!(
+            found,
+            HashMap::from([(
+                "delver of secrets".to_string(),
+                (
+                    oracle_id(),
+                    "Delver of Secrets // Insectile Aberration".to_string()
+                )
+            )])
+        );
+    }
+
+    #[sqlx::test]
+    async fn find_card_name_falls_back_to_the_scryfall_catalogue(pool: PgPool) {
+        insert_card(&pool, "FDN", "87", "FR", false, "Goblin Boarders", 1).await;
+        insert_scryfall_card(&pool, "Sol Ring", "c21", "263", oracle_id()).await;
+        let adapter = WishlistRepositoryAdapter::new(pool);
+
+        let owned = adapter
+            .find_card_name(&CardSelector::Printing(CardId::new(
+                "FDN",
+                "87",
+                LanguageCode::FR,
+                false,
+            )))
+            .await
+            .unwrap();
+        let catalogue = adapter
+            .find_card_name(&CardSelector::Printing(CardId::new(
+                "C21",
+                "263",
+                LanguageCode::EN,
+                true,
+            )))
+            .await
+            .unwrap();
+        let oracle = adapter.find_card_name(&any_printing()).await.unwrap();
+        let unknown = adapter
+            .find_card_name(&CardSelector::AnyPrinting {
+                oracle_id: Uuid::new_v4(),
+            })
+            .await
+            .unwrap();
+
+        assert_eq!(owned.as_deref(), Some("Goblin Boarders"));
+        assert_eq!(catalogue.as_deref(), Some("Sol Ring"));
+        assert_eq!(oracle.as_deref(), Some("Sol Ring"));
+        assert_eq!(unknown, None);
+    }
+}